@@ -8,8 +8,9 @@ use crate::{
     failpoint::fail_point_poem,
     page::determine_limit,
     response::{
-        account_not_found, resource_not_found, struct_field_not_found, BadRequestError,
-        BasicErrorWith404, BasicResponse, BasicResponseStatus, BasicResultWith404, InternalError,
+        account_not_found, resource_not_found, state_key_not_tracked, struct_field_not_found,
+        BadRequestError, BasicErrorWith404, BasicResponse, BasicResponseStatus, BasicResultWith404,
+        InternalError,
     },
     ApiTags,
 };
@@ -309,6 +310,18 @@ impl Account {
         }
     }
 
+    /// Returns an error if the state of the account can't be iterated over,
+    /// i.e., if the node only tracks partial state.
+    fn verify_state_iteration_supported(&self) -> Result<(), BasicErrorWith404> {
+        if self.context.is_partial_state_node() {
+            return Err(state_key_not_tracked(
+                format!("The full state of Address({})", self.address),
+                &self.latest_ledger_info,
+            ));
+        }
+        Ok(())
+    }
+
     /// Retrieves the move resources associated with the account
     ///
     /// * JSON: Return a JSON encoded version of [`Vec<MoveResource>`]
@@ -317,6 +330,8 @@ impl Account {
     /// Note: For the BCS response, if results are being returned in pages, i.e. with the
     /// `start` and `limit` query parameters, the results will only be sorted within each page.
     pub fn resources(self, accept_type: &AcceptType) -> BasicResultWith404<Vec<MoveResource>> {
+        self.verify_state_iteration_supported()?;
+
         // check account exists
         self.verify_account_or_object_resource()?;
         let max_account_resources_page_size = self.context.max_account_resources_page_size();
@@ -392,6 +407,8 @@ impl Account {
     /// Note: For the BCS response, if results are being returned in pages, i.e. with the
     /// `start` and `limit` query parameters, the results will only be sorted within each page.
     pub fn modules(self, accept_type: &AcceptType) -> BasicResultWith404<Vec<MoveModuleBytecode>> {
+        self.verify_state_iteration_supported()?;
+
         // check account exists
         self.verify_account_or_object_resource()?;
         let max_account_modules_page_size = self.context.max_account_modules_page_size();
@@ -528,6 +545,15 @@ impl Account {
     ) -> Result<Vec<(Identifier, move_core_types::value::MoveValue)>, BasicErrorWith404> {
         let (ledger_info, ledger_version, state_view) =
             self.context.state_view(Some(self.ledger_version))?;
+
+        // The module ABIs required to decode the resource are not tracked
+        if self.context.is_partial_state_node() {
+            return Err(state_key_not_tracked(
+                format!("The module of {} (required to decode it)", resource_type),
+                &ledger_info,
+            ));
+        }
+
         let resolver = state_view.as_move_resolver();
 
         let bytes = resolver
@@ -7,8 +7,9 @@ use crate::{
     metrics,
     response::{
         bcs_api_disabled, block_not_found_by_height, block_not_found_by_version,
-        block_pruned_by_height, json_api_disabled, version_not_found, version_pruned,
-        ForbiddenError, InternalError, NotFoundError, ServiceUnavailableError, StdApiError,
+        block_pruned_by_height, json_api_disabled, state_key_not_tracked, version_not_found,
+        version_pruned, BadRequestError, ForbiddenError, InternalError, NotFoundError,
+        ServiceUnavailableError, StdApiError,
     },
 };
 use anyhow::{anyhow, bail, ensure, format_err, Context as AnyhowContext, Result};
@@ -16,7 +17,7 @@ use aptos_api_types::{
     AptosErrorCode, AsConverter, BcsBlock, GasEstimation, LedgerInfo, ResourceGroup,
     TransactionOnChainData,
 };
-use aptos_config::config::{NodeConfig, RoleType};
+use aptos_config::config::{NodeConfig, RoleType, TrackedStateFilter};
 use aptos_crypto::HashValue;
 use aptos_db_indexer::table_info_reader::TableInfoReader;
 use aptos_gas_schedule::{AptosGasParameters, FromOnChainGasSchedule};
@@ -79,6 +80,7 @@ pub struct Context {
     simulate_txn_stats: Arc<FunctionStats>,
     pub table_info_reader: Option<Arc<dyn TableInfoReader>>,
     pub wait_for_hash_active_connections: Arc<AtomicUsize>,
+    tracked_state_filter: Option<TrackedStateFilter>,
 }
 
 impl std::fmt::Debug for Context {
@@ -108,6 +110,11 @@ impl Context {
                 )),
             )
         };
+        // The filter is verified by the config sanitizer
+        let tracked_state_filter = node_config
+            .partial_state
+            .tracked_state_filter()
+            .expect("Failed to create the tracked state filter!");
         Self {
             chain_id,
             db,
@@ -132,6 +139,7 @@ impl Context {
             simulate_txn_stats,
             table_info_reader,
             wait_for_hash_active_connections: Arc::new(AtomicUsize::new(0)),
+            tracked_state_filter,
         }
     }
 
@@ -205,6 +213,10 @@ impl Context {
         self.node_config.api.max_submit_transaction_batch_size
     }
 
+    pub fn max_view_function_batch_size(&self) -> usize {
+        self.node_config.api.max_view_function_batch_size
+    }
+
     pub async fn submit_transaction(&self, txn: SignedTransaction) -> Result<SubmissionStatus> {
         let (req_sender, callback) = oneshot::channel();
         self.mp_sender
@@ -290,12 +302,33 @@ impl Context {
             .map(|val| val.to_vec()))
     }
 
-    pub fn get_state_value_poem<E: InternalError>(
+    /// Returns true iff the node only tracks partial state (i.e., it
+    /// runs as a partial state fullnode)
+    pub fn is_partial_state_node(&self) -> bool {
+        self.tracked_state_filter.is_some()
+    }
+
+    /// Returns an error if the given state key is not tracked by the node
+    pub fn check_state_key_tracked<E: BadRequestError>(
+        &self,
+        state_key: &StateKey,
+        ledger_info: &LedgerInfo,
+    ) -> Result<(), E> {
+        match &self.tracked_state_filter {
+            Some(tracked_state_filter) if !tracked_state_filter.is_tracked(state_key) => Err(
+                state_key_not_tracked(format!("StateKey({:?})", state_key), ledger_info),
+            ),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn get_state_value_poem<E: BadRequestError + InternalError>(
         &self,
         state_key: &StateKey,
         version: u64,
         ledger_info: &LedgerInfo,
     ) -> Result<Option<Vec<u8>>, E> {
+        self.check_state_key_tracked(state_key, ledger_info)?;
         self.get_state_value(state_key, version)
             .context("Failed to retrieve state value")
             .map_err(|e| E::internal_with_code(e, AptosErrorCode::InternalError, ledger_info))
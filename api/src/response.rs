@@ -728,3 +728,14 @@ pub fn block_pruned_by_height<E: GoneError>(block_height: u64, ledger_info: &Led
         ledger_info,
     )
 }
+
+pub fn state_key_not_tracked<S: Display, E: BadRequestError>(
+    identifier: S,
+    ledger_info: &LedgerInfo,
+) -> E {
+    E::bad_request_with_code(
+        format!("{} is not tracked by this partial state node", identifier),
+        AptosErrorCode::StateKeyNotTracked,
+        ledger_info,
+    )
+}
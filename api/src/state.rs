@@ -6,9 +6,9 @@ use crate::{
     context::api_spawn_blocking,
     failpoint::fail_point_poem,
     response::{
-        api_forbidden, build_not_found, module_not_found, resource_not_found, table_item_not_found,
-        BadRequestError, BasicErrorWith404, BasicResponse, BasicResponseStatus, BasicResultWith404,
-        InternalError,
+        api_forbidden, build_not_found, module_not_found, resource_not_found,
+        state_key_not_tracked, table_item_not_found, BadRequestError, BasicErrorWith404,
+        BasicResponse, BasicResponseStatus, BasicResultWith404, InternalError,
     },
     ApiTags, Context,
 };
@@ -293,13 +293,29 @@ impl StateApi {
             })?;
 
         let (ledger_info, ledger_version, state_view) = self.context.state_view(ledger_version)?;
-        let bytes = state_view
-            .as_move_resolver()
-            .get_resource(&address.into(), &resource_type)
-            .context(format!(
-                "Failed to query DB to check for {} at {}",
-                resource_type, address
-            ))
+
+        // Partial state nodes don't track the modules required to resolve
+        // resource groups, so the resource is read directly from its state key.
+        let bytes = if self.context.is_partial_state_node() {
+            let state_key = StateKey::resource(&address.into(), &resource_type);
+            self.context
+                .check_state_key_tracked(&state_key, &ledger_info)?;
+            state_view
+                .get_state_value_bytes(&state_key)
+                .context(format!(
+                    "Failed to query DB to check for {} at {}",
+                    resource_type, address
+                ))
+        } else {
+            state_view
+                .as_move_resolver()
+                .get_resource(&address.into(), &resource_type)
+                .context(format!(
+                    "Failed to query DB to check for {} at {}",
+                    resource_type, address
+                ))
+        };
+        let bytes = bytes
             .map_err(|err| {
                 BasicErrorWith404::internal_with_code(
                     err,
@@ -313,6 +329,14 @@ impl StateApi {
 
         match accept_type {
             AcceptType::Json => {
+                // The module ABIs required for JSON output are not tracked
+                if self.context.is_partial_state_node() {
+                    return Err(state_key_not_tracked(
+                        format!("The module of {} (required for JSON output)", resource_type),
+                        &ledger_info,
+                    ));
+                }
+
                 let resource = state_view
                     .as_move_resolver()
                     .as_converter(
@@ -356,6 +380,8 @@ impl StateApi {
         let (ledger_info, ledger_version, state_view) = self
             .context
             .state_view(ledger_version.map(|inner| inner.0))?;
+        self.context
+            .check_state_key_tracked(&state_key, &ledger_info)?;
         let bytes = state_view
             .get_state_value_bytes(&state_key)
             .context(format!("Failed to query DB to check for {:?}", state_key))
@@ -449,6 +475,8 @@ impl StateApi {
 
         // Retrieve value from the state key
         let state_key = StateKey::table_item(TableHandle(table_handle.into()), raw_key);
+        self.context
+            .check_state_key_tracked(&state_key, &ledger_info)?;
         let bytes = state_view
             .get_state_value_bytes(&state_key)
             .context(format!(
@@ -506,6 +534,8 @@ impl StateApi {
             TableHandle(table_handle.into()),
             table_item_request.key.0.clone(),
         );
+        self.context
+            .check_state_key_tracked(&state_key, &ledger_info)?;
         let bytes = state_view
             .get_state_value_bytes(&state_key)
             .context(format!(
@@ -568,6 +598,8 @@ impl StateApi {
                     &ledger_info,
                 )
             })?;
+        self.context
+            .check_state_key_tracked(&state_key, &ledger_info)?;
         let state_value = state_view
             .get_state_value(&state_key)
             .context(format!("Failed fetching state value. key: {}", request.key,))
@@ -0,0 +1,137 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, format_err, Result};
+use aptos_api_types::{
+    AccountBalanceOverride, ModuleOverride, MoveModuleBytecode, ResourceOverride, StateOverride,
+};
+use aptos_types::{
+    access_path::AccessPath,
+    account_config::CoinStoreResource,
+    state_store::{
+        errors::StateviewError,
+        state_key::{StateKey, StateKeyInner},
+        state_storage_usage::StateStorageUsage,
+        state_value::StateValue,
+        StateView, StateViewId, TStateView,
+    },
+};
+use move_core_types::{
+    language_storage::{ModuleId, StructTag, CORE_CODE_ADDRESS},
+    move_resource::MoveStructType,
+};
+use std::collections::HashMap;
+
+/// A state view that replaces parts of the underlying state, so that transactions can be
+/// simulated against hypothetical state.
+pub struct StateViewWithOverrides<'a, S> {
+    base_view: &'a S,
+    overrides: HashMap<StateKey, Option<StateValue>>,
+}
+
+impl<'a, S: StateView> StateViewWithOverrides<'a, S> {
+    /// Applies the overrides on top of `base_view`, in order.
+    pub fn new(base_view: &'a S, state_overrides: &[StateOverride]) -> Result<Self> {
+        let mut state_view = Self {
+            base_view,
+            overrides: HashMap::new(),
+        };
+        for state_override in state_overrides {
+            state_view.apply(state_override)?;
+        }
+        Ok(state_view)
+    }
+
+    /// Whether the overrides replace code, or any state of the core framework address such as
+    /// the package registry and features that the warm VM cache is keyed by. Transactions must
+    /// then be simulated on a VM of their own, so that neither the overridden code nor a
+    /// spurious cache entry is ever shared with regular execution.
+    pub fn requires_isolated_vm(&self) -> bool {
+        self.overrides
+            .keys()
+            .any(|state_key| match state_key.inner() {
+                StateKeyInner::AccessPath(path) => {
+                    path.is_code() || path.address == CORE_CODE_ADDRESS
+                },
+                StateKeyInner::TableItem { .. } | StateKeyInner::Raw(_) => false,
+            })
+    }
+
+    fn apply(&mut self, state_override: &StateOverride) -> Result<()> {
+        match state_override {
+            StateOverride::ResourceOverride(ResourceOverride {
+                address,
+                resource_type,
+                data,
+            }) => {
+                let struct_tag: StructTag = resource_type.clone().try_into()?;
+                self.set(
+                    StateKey::resource(address.inner(), &struct_tag),
+                    data.as_ref().map(|data| data.inner().to_vec()),
+                )
+            },
+            StateOverride::AccountBalanceOverride(AccountBalanceOverride { address, balance }) => {
+                let state_key = StateKey::resource_typed::<CoinStoreResource>(address.inner());
+                let coin_store: CoinStoreResource = match self.get_state_value_bytes(&state_key)? {
+                    Some(bytes) => bcs::from_bytes(&bytes)?,
+                    None => bail!(
+                        "Account {} has no {} to override the balance of",
+                        address,
+                        CoinStoreResource::struct_tag()
+                    ),
+                };
+                let coin_store = CoinStoreResource::new(
+                    balance.0,
+                    coin_store.frozen(),
+                    coin_store.deposit_events().clone(),
+                    coin_store.withdraw_events().clone(),
+                );
+                self.set(state_key, Some(bcs::to_bytes(&coin_store)?))
+            },
+            StateOverride::ModuleOverride(ModuleOverride { bytecode }) => {
+                let module = MoveModuleBytecode::new(bytecode.inner().to_vec())
+                    .try_parse_abi()?
+                    .abi
+                    .ok_or_else(|| format_err!("Invalid module bytecode"))?;
+                let module_id = ModuleId::new(module.address.into(), module.name.into());
+                self.set(
+                    StateKey::access_path(AccessPath::code_access_path(module_id)),
+                    Some(bytecode.inner().to_vec()),
+                )
+            },
+        }
+    }
+
+    /// Replaces the value at `state_key`, keeping the metadata (e.g. the storage deposit) of the
+    /// value it replaces, or deletes it if `bytes` is not set.
+    fn set(&mut self, state_key: StateKey, bytes: Option<Vec<u8>>) -> Result<()> {
+        let state_value = match bytes {
+            Some(bytes) => Some(match self.get_state_value(&state_key)? {
+                Some(state_value) => state_value.map_bytes(|_| Ok(bytes.into()))?,
+                None => StateValue::new_legacy(bytes.into()),
+            }),
+            None => None,
+        };
+        self.overrides.insert(state_key, state_value);
+        Ok(())
+    }
+}
+
+impl<'a, S: StateView> TStateView for StateViewWithOverrides<'a, S> {
+    type Key = StateKey;
+
+    fn id(&self) -> StateViewId {
+        self.base_view.id()
+    }
+
+    fn get_state_value(&self, state_key: &StateKey) -> Result<Option<StateValue>, StateviewError> {
+        match self.overrides.get(state_key) {
+            Some(state_value) => Ok(state_value.clone()),
+            None => self.base_view.get_state_value(state_key),
+        }
+    }
+
+    fn get_usage(&self) -> Result<StateStorageUsage, StateviewError> {
+        self.base_view.get_usage()
+    }
+}
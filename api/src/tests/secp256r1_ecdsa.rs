@@ -0,0 +1,53 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use super::new_test_context;
+use aptos_api_test_context::current_function_name;
+use aptos_sdk::types::{transaction::authenticator::AnySignature, LocalAccount};
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_secp256r1_ecdsa() {
+    let mut context = new_test_context(current_function_name!());
+    let other = context.create_account().await;
+    let mut account = LocalAccount::generate_secp256r1_ecdsa(context.rng());
+
+    let txn = context.mint_user_account(&account).await;
+    context.commit_block(&vec![txn]).await;
+
+    let secp256r1_ecdsa_txn = context.account_transfer(&mut account, &other, 5);
+    let authenticators = secp256r1_ecdsa_txn
+        .authenticator_ref()
+        .to_single_key_authenticators()
+        .unwrap();
+    assert!(matches!(
+        authenticators[0].signature(),
+        AnySignature::Secp256r1Ecdsa { .. }
+    ));
+    let balance_start = context.get_apt_balance(other.address()).await;
+    let bcs_txn = bcs::to_bytes(&secp256r1_ecdsa_txn).unwrap();
+    let resp = context
+        .expect_status_code(202)
+        .post_bcs_txn("/transactions", bcs_txn)
+        .await;
+    assert!(resp["signature"].to_string().contains("secp256r1_ecdsa"));
+
+    // A transaction with a different signed field is rejected
+    let mut invalid_json_txn = resp.clone();
+    invalid_json_txn["max_gas_amount"] = "1000".into();
+    context
+        .expect_status_code(400)
+        .post("/transactions", invalid_json_txn)
+        .await;
+
+    // The transaction can be submitted in JSON format as well
+    context
+        .expect_status_code(202)
+        .post("/transactions", resp)
+        .await;
+
+    context.commit_mempool_txns(1).await;
+    assert_eq!(
+        balance_start + 5,
+        context.get_apt_balance(other.address()).await
+    );
+}
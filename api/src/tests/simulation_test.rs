@@ -2,10 +2,16 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::new_test_context;
+use crate::state_overrides::StateViewWithOverrides;
 use aptos_api_test_context::{current_function_name, TestContext};
+use aptos_api_types::{AccountBalanceOverride, ModuleOverride, ResourceOverride, StateOverride};
 use aptos_crypto::ed25519::Ed25519Signature;
-use aptos_types::transaction::{
-    authenticator::TransactionAuthenticator, EntryFunction, TransactionPayload,
+use aptos_types::{
+    access_path::AccessPath,
+    account_address::AccountAddress,
+    on_chain_config::{Features, OnChainConfig},
+    state_store::{state_key::StateKey, TStateView},
+    transaction::{authenticator::TransactionAuthenticator, EntryFunction, TransactionPayload},
 };
 use move_core_types::{ident_str, language_storage::ModuleId};
 use serde_json::json;
@@ -16,6 +22,7 @@ async fn simulate_aptos_transfer(
     use_valid_signature: bool,
     transfer_amount: u64,
     expected_status: u16,
+    include_storage_fee_breakdown: bool,
 ) -> serde_json::Value {
     let alice = &mut context.gen_account();
     let bob = &mut context.gen_account();
@@ -35,7 +42,10 @@ async fn simulate_aptos_transfer(
         context
             .expect_status_code(expected_status)
             .post(
-                "/transactions/simulate",
+                &format!(
+                    "/transactions/simulate?include_storage_fee_breakdown={}",
+                    include_storage_fee_breakdown
+                ),
                 json!({
                     "sender": txn.sender().to_string(),
                     "sequence_number": txn.sequence_number().to_string(),
@@ -69,21 +79,23 @@ const LARGE_TRANSFER_AMOUNT: u64 = 1_000_000_000;
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_simulate_transaction_with_valid_signature() {
     let mut context = new_test_context(current_function_name!());
-    let resp = simulate_aptos_transfer(&mut context, true, SMALL_TRANSFER_AMOUNT, 400).await;
+    let resp = simulate_aptos_transfer(&mut context, true, SMALL_TRANSFER_AMOUNT, 400, false).await;
     context.check_golden_output(resp);
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_simulate_transaction_with_not_valid_signature() {
     let mut context = new_test_context(current_function_name!());
-    let resp = simulate_aptos_transfer(&mut context, false, SMALL_TRANSFER_AMOUNT, 200).await;
+    let resp =
+        simulate_aptos_transfer(&mut context, false, SMALL_TRANSFER_AMOUNT, 200, false).await;
     assert!(resp[0]["success"].as_bool().is_some_and(|v| v));
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_simulate_transaction_with_insufficient_balance() {
     let mut context = new_test_context(current_function_name!());
-    let resp = simulate_aptos_transfer(&mut context, false, LARGE_TRANSFER_AMOUNT, 200).await;
+    let resp =
+        simulate_aptos_transfer(&mut context, false, LARGE_TRANSFER_AMOUNT, 200, false).await;
     assert!(!resp[0]["success"].as_bool().is_some_and(|v| v));
 }
 
@@ -140,3 +152,192 @@ async fn test_simulate_txn_with_aggregator() {
         unreachable!("Simulation uses Ed25519 authenticator.");
     }
 }
+
+async fn simulate_aptos_transfer_with_overrides(
+    context: &mut TestContext,
+    transfer_amount: u64,
+    sequence_number_offset: u64,
+    balance_override: Option<u64>,
+    skip_sequence_number_check: bool,
+) -> serde_json::Value {
+    let alice = &mut context.gen_account();
+    let bob = &mut context.gen_account();
+    let txn = context.mint_user_account(alice).await;
+    context.commit_block(&vec![txn]).await;
+
+    let txn = context.account_transfer_to(alice, bob.address(), transfer_amount);
+    let public_key = match txn.authenticator_ref() {
+        TransactionAuthenticator::Ed25519 { public_key, .. } => public_key.to_string(),
+        _ => unreachable!("Simulation uses Ed25519 authenticator."),
+    };
+    let state_overrides: Vec<_> = balance_override
+        .map(|balance| {
+            json!({
+                "type": "account_balance_override",
+                "address": alice.address().to_standard_string(),
+                "balance": balance.to_string(),
+            })
+        })
+        .into_iter()
+        .collect();
+
+    context
+        .expect_status_code(200)
+        .post(
+            "/transactions/simulate_with_overrides",
+            json!({
+                "transaction": {
+                    "sender": txn.sender().to_string(),
+                    "sequence_number": (txn.sequence_number() + sequence_number_offset).to_string(),
+                    "max_gas_amount": txn.max_gas_amount().to_string(),
+                    "gas_unit_price": txn.gas_unit_price().to_string(),
+                    "expiration_timestamp_secs": txn.expiration_timestamp_secs().to_string(),
+                    "payload": {
+                        "type": "entry_function_payload",
+                        "function": "0x1::aptos_account::transfer",
+                        "type_arguments": [],
+                        "arguments": [
+                            bob.address().to_standard_string(), transfer_amount.to_string(),
+                        ]
+                    },
+                    "signature": {
+                        "type": "ed25519_signature",
+                        "public_key": public_key,
+                        "signature": Ed25519Signature::dummy_signature().to_string(),
+                    }
+                },
+                "state_overrides": state_overrides,
+                "skip_sequence_number_check": skip_sequence_number_check,
+            }),
+        )
+        .await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_simulate_transaction_with_balance_override() {
+    let mut context = new_test_context(current_function_name!());
+    let resp =
+        simulate_aptos_transfer_with_overrides(&mut context, LARGE_TRANSFER_AMOUNT, 0, None, false)
+            .await;
+    assert!(!resp["transaction"]["success"].as_bool().is_some_and(|v| v));
+
+    let resp = simulate_aptos_transfer_with_overrides(
+        &mut context,
+        LARGE_TRANSFER_AMOUNT,
+        0,
+        Some(2 * LARGE_TRANSFER_AMOUNT),
+        false,
+    )
+    .await;
+    assert!(resp["transaction"]["success"].as_bool().is_some_and(|v| v));
+    assert!(!resp["transaction"]["changes"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+    assert!(!resp["gas_profile"]["operations"]
+        .as_array()
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_simulate_transaction_skipping_sequence_number_check() {
+    let mut context = new_test_context(current_function_name!());
+    let resp =
+        simulate_aptos_transfer_with_overrides(&mut context, SMALL_TRANSFER_AMOUNT, 5, None, false)
+            .await;
+    assert!(!resp["transaction"]["success"].as_bool().is_some_and(|v| v));
+
+    let resp =
+        simulate_aptos_transfer_with_overrides(&mut context, SMALL_TRANSFER_AMOUNT, 5, None, true)
+            .await;
+    assert!(resp["transaction"]["success"].as_bool().is_some_and(|v| v));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_simulate_transaction_storage_fee_breakdown() {
+    let mut context = new_test_context(current_function_name!());
+    let resp =
+        simulate_aptos_transfer_with_overrides(&mut context, SMALL_TRANSFER_AMOUNT, 0, None, false)
+            .await;
+    assert!(resp["transaction"]["success"].as_bool().is_some_and(|v| v));
+
+    check_state_storage_fees(
+        &resp["transaction"],
+        &resp["gas_profile"]["state_storage_fees"],
+    );
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_simulate_transaction_with_storage_fee_breakdown() {
+    let mut context = new_test_context(current_function_name!());
+    let resp =
+        simulate_aptos_transfer(&mut context, false, SMALL_TRANSFER_AMOUNT, 200, false).await;
+    assert!(resp[0]["success"].as_bool().is_some_and(|v| v));
+    assert!(resp[0].get("state_storage_fees").is_none());
+
+    let resp = simulate_aptos_transfer(&mut context, false, SMALL_TRANSFER_AMOUNT, 200, true).await;
+    assert!(resp[0]["success"].as_bool().is_some_and(|v| v));
+    check_state_storage_fees(&resp[0], &resp[0]["state_storage_fees"]);
+}
+
+/// Checks the storage fees of a transfer to an account that doesn't exist yet
+fn check_state_storage_fees(
+    transaction: &serde_json::Value,
+    state_storage_fees: &serde_json::Value,
+) {
+    // The transfer creates the account of the receiver, which is charged a storage fee.
+    let state_storage_fees = state_storage_fees.as_array().unwrap();
+    assert!(state_storage_fees
+        .iter()
+        .any(|fee| fee["storage_fee"].as_str().unwrap() != "0"));
+
+    let changed_state_keys: Vec<_> = transaction["changes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|change| change["state_key_hash"].as_str().unwrap())
+        .collect();
+    for fee in state_storage_fees {
+        assert!(changed_state_keys.contains(&fee["state_key_hash"].as_str().unwrap()));
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_only_code_and_framework_overrides_require_isolated_vm() {
+    let mut context = new_test_context(current_function_name!());
+    let alice = &mut context.gen_account();
+    let txn = context.mint_user_account(alice).await;
+    context.commit_block(&vec![txn]).await;
+    let state_view = context.latest_state_view();
+
+    let balance_override = StateOverride::AccountBalanceOverride(AccountBalanceOverride {
+        address: alice.address().into(),
+        balance: 1.into(),
+    });
+    let state_view_with_overrides =
+        StateViewWithOverrides::new(&state_view, &[balance_override]).unwrap();
+    assert!(!state_view_with_overrides.requires_isolated_vm());
+
+    let features_override = StateOverride::ResourceOverride(ResourceOverride {
+        address: AccountAddress::ONE.into(),
+        resource_type: Features::struct_tag().into(),
+        data: Some(bcs::to_bytes(&Features::default()).unwrap().into()),
+    });
+    let state_view_with_overrides =
+        StateViewWithOverrides::new(&state_view, &[features_override]).unwrap();
+    assert!(state_view_with_overrides.requires_isolated_vm());
+
+    let bytecode = state_view
+        .get_state_value_bytes(&StateKey::access_path(AccessPath::code_access_path(
+            ModuleId::new(AccountAddress::ONE, ident_str!("aptos_coin").to_owned()),
+        )))
+        .unwrap()
+        .unwrap();
+    let module_override = StateOverride::ModuleOverride(ModuleOverride {
+        bytecode: bytecode.to_vec().into(),
+    });
+    let state_view_with_overrides =
+        StateViewWithOverrides::new(&state_view, &[module_override]).unwrap();
+    assert!(state_view_with_overrides.requires_isolated_vm());
+}
@@ -17,7 +17,7 @@ use aptos_types::{
     account_config::aptos_test_root_address,
     transaction::{
         authenticator::{AuthenticationKey, TransactionAuthenticator},
-        EntryFunction, Script, SignedTransaction,
+        EntryFunction, Script, SignedTransaction, ORDERLESS_TRANSACTION_SEQUENCE_NUMBER,
     },
     utility_coin::APTOS_COIN_TYPE,
 };
@@ -750,6 +750,43 @@ async fn test_signing_message_with_payload(
     assert_eq!(ledger["ledger_version"].as_str().unwrap(), "3"); // metadata + user txn + state checkpoint
 }
 
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_signing_message_orderless_requires_fixed_sequence_number() {
+    let mut context = new_test_context(current_function_name!());
+    let sender = context.root_account().await;
+    let mut body = json!({
+        "sender": sender.address().to_hex_literal(),
+        "sequence_number": sender.sequence_number().to_string(),
+        "gas_unit_price": "100",
+        "max_gas_amount": "1000000",
+        "expiration_timestamp_secs": u64::MAX.to_string(),
+        "payload": {
+            "type": "entry_function_payload",
+            "function": "0x1::aptos_account::create_account",
+            "type_arguments": [],
+            "arguments": [sender.address().to_hex_literal()]
+        },
+        "replay_protection_nonce": "42",
+    });
+
+    // The sequence number is signed, so it must not be silently replaced.
+    let resp = context
+        .expect_status_code(400)
+        .post("/transactions/encode_submission", body.clone())
+        .await;
+    assert_eq!(resp["error_code"], "invalid_input");
+    assert!(resp["message"]
+        .as_str()
+        .unwrap()
+        .contains("Orderless transactions must set sequence_number"));
+
+    body["sequence_number"] = json!(ORDERLESS_TRANSACTION_SEQUENCE_NUMBER.to_string());
+    context
+        .expect_status_code(200)
+        .post("/transactions/encode_submission", body)
+        .await;
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
 async fn test_get_account_transactions() {
     let mut context = new_test_context(current_function_name!());
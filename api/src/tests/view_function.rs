@@ -3,9 +3,15 @@
 
 use super::{new_test_context, new_test_context_with_config};
 use aptos_api_test_context::{current_function_name, TestContext};
+use aptos_api_types::{mime_types, AptosErrorCode, ViewFunction, ViewFunctionBcsResult};
 use aptos_cached_packages::aptos_stdlib;
 use aptos_config::config::{NodeConfig, ViewFilter, ViewFunctionId};
-use aptos_types::account_address::AccountAddress;
+use aptos_types::{account_address::AccountAddress, account_config::aptos_test_root_address};
+use move_core_types::{
+    ident_str,
+    identifier::Identifier,
+    language_storage::{ModuleId, TypeTag},
+};
 use serde_json::{json, Value};
 use std::{path::PathBuf, str::FromStr};
 
@@ -221,3 +227,155 @@ async fn test_view_aggregator() {
         .await;
     context.check_golden_output_no_prune(resp);
 }
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_view_batch() {
+    let mut context = new_test_context(current_function_name!());
+    let creator = &mut context.gen_account();
+    let owner = &mut context.gen_account();
+    let txn1 = context.mint_user_account(creator).await;
+    let txn2 = context.account_transfer(creator, owner, 100_000);
+
+    context.commit_block(&vec![txn1, txn2]).await;
+
+    let resp = context
+        .post(
+            "/view/batch",
+            json!([
+                build_coin_balance_request(&owner.address()),
+                {
+                    "function": "0x1::coin::missing_function",
+                    "arguments": [],
+                    "type_arguments": [],
+                },
+                build_coin_decimals_request(),
+            ]),
+        )
+        .await;
+
+    // The function that fails doesn't affect the others.
+    assert_eq!(resp[0]["values"], json!(["100000"]));
+    assert_eq!(resp[0]["error"], Value::Null);
+    assert_eq!(resp[1]["values"], Value::Null);
+    assert_eq!(resp[1]["error"]["error_code"], json!("invalid_input"));
+    assert_eq!(resp[2]["values"], json!([8]));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_view_batch_allowlist() {
+    let mut node_config = NodeConfig::default();
+
+    // Allowlist only the balance function.
+    node_config.api.view_filter = ViewFilter::Allowlist(vec![ViewFunctionId {
+        address: AccountAddress::from_str("0x1").unwrap(),
+        module: "coin".to_string(),
+        function_name: "balance".to_string(),
+    }]);
+
+    let mut context = new_test_context_with_config(current_function_name!(), node_config);
+    let creator = &mut context.gen_account();
+    let owner = &mut context.gen_account();
+    let txn1 = context.mint_user_account(creator).await;
+    let txn2 = context.account_transfer(creator, owner, 100_000);
+
+    context.commit_block(&vec![txn1, txn2]).await;
+
+    let resp = context
+        .post(
+            "/view/batch",
+            json!([
+                build_coin_decimals_request(),
+                build_coin_balance_request(&owner.address()),
+            ]),
+        )
+        .await;
+
+    assert_eq!(resp[0]["values"], Value::Null);
+    assert_eq!(resp[0]["error"]["error_code"], json!("invalid_input"));
+    assert_eq!(resp[1]["values"], json!(["100000"]));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_view_batch_too_large() {
+    let mut node_config = NodeConfig::default();
+    node_config.api.max_view_function_batch_size = 1;
+
+    let mut context = new_test_context_with_config(current_function_name!(), node_config);
+    let resp = context
+        .expect_status_code(400)
+        .post(
+            "/view/batch",
+            json!([build_coin_decimals_request(), build_coin_decimals_request()]),
+        )
+        .await;
+
+    assert_eq!(resp["error_code"], json!("invalid_input"));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_view_batch_bcs_too_large() {
+    let mut node_config = NodeConfig::default();
+    node_config.api.max_view_function_batch_size = 1;
+
+    let context = new_test_context_with_config(current_function_name!(), node_config);
+    // Only the length prefix is sent, so the batch is rejected before the rest is deserialized.
+    let mut body = vec![];
+    aptos_bcs_utils::serialize_uleb128(&mut body, 1_000_000).unwrap();
+    let req = warp::test::request()
+        .method("POST")
+        .path("/v1/view/batch")
+        .header("Content-Type", mime_types::BCS_VIEW_FUNCTION)
+        .body(body);
+    let resp = context.reply(req).await;
+    assert_eq!(resp.status(), 400);
+
+    let error: Value = serde_json::from_slice(resp.body()).unwrap();
+    assert_eq!(error["error_code"], json!("invalid_input"));
+    assert!(error["message"]
+        .as_str()
+        .unwrap()
+        .starts_with("Requested too many view functions: 1000000"));
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_view_batch_bcs() {
+    let context = new_test_context(current_function_name!());
+    let aptos_coin = TypeTag::from_str("0x1::aptos_coin::AptosCoin").unwrap();
+    let coin_function = |function: &str, args: Vec<Vec<u8>>| ViewFunction {
+        module: ModuleId::new(AccountAddress::ONE, ident_str!("coin").to_owned()),
+        function: Identifier::new(function).unwrap(),
+        ty_args: vec![aptos_coin.clone()],
+        args,
+    };
+    let view_functions = vec![
+        coin_function("balance", vec![
+            bcs::to_bytes(&aptos_test_root_address()).unwrap()
+        ]),
+        coin_function("decimals", vec![bcs::to_bytes(&0u64).unwrap()]),
+        coin_function("decimals", vec![]),
+    ];
+
+    let req = warp::test::request()
+        .method("POST")
+        .path("/v1/view/batch")
+        .header("Content-Type", mime_types::BCS_VIEW_FUNCTION)
+        .header("Accept", mime_types::BCS)
+        .body(bcs::to_bytes(&view_functions).unwrap());
+    let resp = context.reply(req).await;
+    assert_eq!(resp.status(), 200);
+
+    let results: Vec<ViewFunctionBcsResult> = bcs::from_bytes(resp.body()).unwrap();
+    assert_eq!(results.len(), 3);
+    let balance_values = results[0].as_ref().unwrap();
+    assert_eq!(balance_values.len(), 1);
+    assert!(bcs::from_bytes::<u64>(&balance_values[0]).unwrap() > 0);
+    // Too many arguments.
+    assert!(matches!(
+        results[1].as_ref().unwrap_err().error_code,
+        AptosErrorCode::InvalidInput
+    ));
+    assert_eq!(
+        bcs::from_bytes::<u8>(&results[2].as_ref().unwrap()[0]).unwrap(),
+        8
+    );
+}
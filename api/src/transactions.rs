@@ -13,28 +13,35 @@ use crate::{
     page::Page,
     response::{
         api_disabled, api_forbidden, transaction_not_found_by_hash,
-        transaction_not_found_by_version, version_pruned, BadRequestError, BasicError,
-        BasicErrorWith404, BasicResponse, BasicResponseStatus, BasicResult, BasicResultWith404,
-        ForbiddenError, InsufficientStorageError, InternalError,
+        transaction_not_found_by_version, version_not_found, version_pruned, BadRequestError,
+        BasicError, BasicErrorWith404, BasicResponse, BasicResponseStatus, BasicResult,
+        BasicResultWith404, ForbiddenError, InsufficientStorageError, InternalError,
     },
+    state_overrides::StateViewWithOverrides,
     ApiTags,
 };
 use anyhow::Context as AnyhowContext;
 use aptos_api_types::{
     verify_function_identifier, verify_module_identifier, Address, AptosError, AptosErrorCode,
-    AsConverter, EncodeSubmissionRequest, GasEstimation, GasEstimationBcs, HashValue,
-    HexEncodedBytes, LedgerInfo, MoveType, PendingTransaction, SubmitTransactionRequest,
-    Transaction, TransactionData, TransactionOnChainData, TransactionsBatchSingleSubmissionFailure,
-    TransactionsBatchSubmissionResult, UserTransaction, VerifyInput, VerifyInputWithRecursion,
-    MAX_RECURSIVE_TYPES_ALLOWED, U64,
+    AsConverter, EncodeSubmissionRequest, GasEstimation, GasEstimationBcs, GasProfile,
+    GasProfileEntry, HashValue, HexEncodedBytes, LedgerInfo, MoveType, PendingTransaction,
+    SimulateTransactionWithOverridesRequest, StateSlotStorageFee, SubmitTransactionRequest,
+    Transaction, TransactionData, TransactionOnChainData, TransactionSimulationResult,
+    TransactionsBatchSingleSubmissionFailure, TransactionsBatchSubmissionResult, UserTransaction,
+    VerifyInput, VerifyInputWithRecursion, MAX_RECURSIVE_TYPES_ALLOWED, U64,
 };
 use aptos_crypto::{hash::CryptoHash, signing_message};
+use aptos_gas_meter::{StandardGasAlgebra, StandardGasMeter};
+use aptos_gas_profiling::{GasProfiler, TransactionGasLog};
+use aptos_memory_usage_tracker::MemoryTrackedGasMeter;
 use aptos_types::{
-    account_config::CoinStoreResource,
+    account_config::{AccountResource, CoinStoreResource},
     mempool_status::MempoolStatusCode,
+    state_store::{state_key::StateKey, TStateView},
     transaction::{
-        EntryFunction, ExecutionStatus, MultisigTransactionPayload, RawTransaction,
-        RawTransactionWithData, SignedTransaction, TransactionPayload, TransactionStatus,
+        EntryFunction, ExecutionStatus, MultisigTransactionPayload, OrderlessPayload,
+        RawTransaction, RawTransactionWithData, Script, SignedTransaction, TransactionExecutable,
+        TransactionOutput, TransactionPayload, TransactionStatus,
     },
     vm_status::StatusCode,
 };
@@ -488,6 +495,9 @@ impl TransactionsApi {
         /// If set to true, the transaction will use a higher price than the original
         /// estimate.
         estimate_prioritized_gas_unit_price: Query<Option<bool>>,
+        /// If set to true, the storage fee charged and refunded per state slot is included
+        /// in the transaction. Only supported for JSON responses.
+        include_storage_fee_breakdown: Query<Option<bool>>,
         data: SubmitTransactionPost,
     ) -> SimulateTransactionResult<Vec<UserTransaction>> {
         data.verify()
@@ -593,11 +603,65 @@ impl TransactionsApi {
                 );
             }
 
-            api.simulate(&accept_type, ledger_info, signed_transaction)
+            api.simulate(
+                &accept_type,
+                ledger_info,
+                signed_transaction,
+                include_storage_fee_breakdown.0.unwrap_or_default(),
+            )
         })
         .await
     }
 
+    /// Simulate transaction with state overrides
+    ///
+    /// Simulates a transaction like `/transactions/simulate`, but against hypothetical state. The
+    /// transaction can be simulated against an older ledger version, and resources, APT balances
+    /// and modules can be overridden before it is executed. If requested, the sequence number of
+    /// the transaction is replaced by the current sequence number of the sender.
+    ///
+    /// The result contains the full write set and events of the transaction, along with a
+    /// breakdown of the gas it used.
+    ///
+    /// As with `/transactions/simulate`, the transaction must not have a valid signature.
+    /// This endpoint only supports JSON.
+    #[oai(
+        path = "/transactions/simulate_with_overrides",
+        method = "post",
+        operation_id = "simulate_transaction_with_overrides",
+        tag = "ApiTags::Transactions"
+    )]
+    async fn simulate_transaction_with_overrides(
+        &self,
+        accept_type: AcceptType,
+        data: Json<SimulateTransactionWithOverridesRequest>,
+    ) -> SimulateTransactionResult<TransactionSimulationResult> {
+        data.0
+            .verify()
+            .context("Simulated transaction invalid")
+            .map_err(|err| {
+                SubmitTransactionError::bad_request_with_code_no_info(
+                    err,
+                    AptosErrorCode::InvalidInput,
+                )
+            })?;
+        fail_point_poem("endpoint_simulate_transaction_with_overrides")?;
+        if !self.context.node_config.api.transaction_simulation_enabled {
+            return Err(api_disabled("Simulate transaction"));
+        }
+        if accept_type == AcceptType::Bcs {
+            return Err(SubmitTransactionError::bad_request_with_code_no_info(
+                "BCS is not supported for simulation with state overrides",
+                AptosErrorCode::BcsNotSupported,
+            ));
+        }
+        self.context
+            .check_api_output_enabled("Simulate transaction", &accept_type)?;
+
+        let api = self.clone();
+        api_spawn_blocking(move || api.simulate_with_overrides(data.0)).await
+    }
+
     /// Encode submission
     ///
     /// This endpoint accepts an EncodeSubmissionRequest, which internally is a
@@ -1018,26 +1082,7 @@ impl TransactionsApi {
                         )?;
                     },
                     TransactionPayload::Script(script) => {
-                        if script.code().is_empty() {
-                            return Err(SubmitTransactionError::bad_request_with_code(
-                                "Script payload bytecode must not be empty",
-                                AptosErrorCode::InvalidInput,
-                                ledger_info,
-                            ));
-                        }
-
-                        for arg in script.ty_args() {
-                            let arg = MoveType::from(arg);
-                            arg.verify(0)
-                                .context("Transaction script function type arg invalid")
-                                .map_err(|err| {
-                                    SubmitTransactionError::bad_request_with_code(
-                                        err,
-                                        AptosErrorCode::InvalidInput,
-                                        ledger_info,
-                                    )
-                                })?;
-                        }
+                        TransactionsApi::validate_script_payload_format(ledger_info, script)?;
                     },
                     TransactionPayload::Multisig(multisig) => {
                         if let Some(payload) = &multisig.transaction_payload {
@@ -1051,6 +1096,17 @@ impl TransactionsApi {
                             }
                         }
                     },
+                    TransactionPayload::Orderless(orderless) => match &orderless.executable {
+                        TransactionExecutable::Script(script) => {
+                            TransactionsApi::validate_script_payload_format(ledger_info, script)?;
+                        },
+                        TransactionExecutable::EntryFunction(entry_function) => {
+                            TransactionsApi::validate_entry_function_payload_format(
+                                ledger_info,
+                                entry_function,
+                            )?;
+                        },
+                    },
 
                     // Deprecated. To avoid panics when malicios users submit this
                     // payload, return an error.
@@ -1126,6 +1182,34 @@ impl TransactionsApi {
         Ok(())
     }
 
+    // Validates that the bytecode and type args in Script payload are correctly formatted.
+    fn validate_script_payload_format(
+        ledger_info: &LedgerInfo,
+        script: &Script,
+    ) -> Result<(), SubmitTransactionError> {
+        if script.code().is_empty() {
+            return Err(SubmitTransactionError::bad_request_with_code(
+                "Script payload bytecode must not be empty",
+                AptosErrorCode::InvalidInput,
+                ledger_info,
+            ));
+        }
+
+        for arg in script.ty_args() {
+            let arg = MoveType::from(arg);
+            arg.verify(0)
+                .context("Transaction script function type arg invalid")
+                .map_err(|err| {
+                    SubmitTransactionError::bad_request_with_code(
+                        err,
+                        AptosErrorCode::InvalidInput,
+                        ledger_info,
+                    )
+                })?;
+        }
+        Ok(())
+    }
+
     /// Parses a batch of signed transactions
     fn get_signed_transactions_batch(
         &self,
@@ -1329,7 +1413,8 @@ impl TransactionsApi {
     // TODO: This function leverages a lot of types from aptos_types, use the
     // local API types and just return those directly, instead of converting
     // from these types in render_transactions.
-    /// Simulate a transaction in the VM
+    /// Simulate a transaction in the VM, optionally profiling its gas to break down the storage
+    /// fee per state slot
     ///
     /// Note: this returns a `Vec<UserTransaction>`, but for backwards compatibility, this can't
     /// be removed even though, there is only one possible transaction
@@ -1338,6 +1423,7 @@ impl TransactionsApi {
         accept_type: &AcceptType,
         ledger_info: LedgerInfo,
         txn: SignedTransaction,
+        include_storage_fee_breakdown: bool,
     ) -> SimulateTransactionResult<Vec<UserTransaction>> {
         // The caller must ensure that the signature is not valid, as otherwise
         // a malicious actor could execute the transaction without their knowledge
@@ -1351,105 +1437,56 @@ impl TransactionsApi {
 
         // Simulate transaction
         let state_view = self.context.latest_state_view_poem(&ledger_info)?;
-        let (vm_status, output) =
-            AptosSimulationVM::create_vm_and_simulate_signed_transaction(&txn, &state_view);
-        let version = ledger_info.version();
-
-        // Ensure that all known statuses return their values in the output (even if they aren't supposed to)
-        let exe_status = match output.status().clone() {
-            TransactionStatus::Keep(exec_status) => exec_status,
-            TransactionStatus::Discard(status) => ExecutionStatus::MiscellaneousError(Some(status)),
-            _ => ExecutionStatus::MiscellaneousError(None),
+        let (vm_status, output, state_storage_fees) = if include_storage_fee_breakdown {
+            // Only the storage fees are taken from the gas log, so it doesn't need to be rooted
+            // at the function the transaction calls
+            let (vm_status, output, gas_profiler) =
+                AptosSimulationVM::create_vm_and_simulate_signed_transaction_with_gas_meter(
+                    &txn,
+                    &state_view,
+                    true,
+                    |gas_feature_version, gas_params, storage_gas_params, balance| {
+                        Ok(GasProfiler::new_script(MemoryTrackedGasMeter::new(
+                            StandardGasMeter::new(StandardGasAlgebra::new(
+                                gas_feature_version,
+                                gas_params,
+                                storage_gas_params,
+                                balance,
+                            )),
+                        )))
+                    },
+                )
+                .map_err(|status| {
+                    SubmitTransactionError::internal_with_code(
+                        format!("Failed to simulate transaction: {:?}", status),
+                        AptosErrorCode::InternalError,
+                        &ledger_info,
+                    )
+                })?;
+            let state_storage_fees = state_storage_fees(&gas_profiler.finish());
+            (vm_status, output, Some(state_storage_fees))
+        } else {
+            let (vm_status, output) =
+                AptosSimulationVM::create_vm_and_simulate_signed_transaction(&txn, &state_view);
+            (vm_status, output, None)
         };
+        let version = ledger_info.version();
 
-        let stats_key = match txn.payload() {
-            TransactionPayload::Script(_) => {
-                format!("Script::{}", txn.clone().committed_hash()).to_string()
-            },
-            TransactionPayload::ModuleBundle(_) => "ModuleBundle::unknown".to_string(),
-            TransactionPayload::EntryFunction(entry_function) => FunctionStats::function_to_key(
-                entry_function.module(),
-                &entry_function.function().into(),
-            ),
-            TransactionPayload::Multisig(multisig) => {
-                if let Some(payload) = &multisig.transaction_payload {
-                    match payload {
-                        MultisigTransactionPayload::EntryFunction(entry_function) => {
-                            FunctionStats::function_to_key(
-                                entry_function.module(),
-                                &entry_function.function().into(),
-                            )
-                        },
-                    }
-                } else {
-                    "Multisig::unknown".to_string()
-                }
-            },
-        };
         self.context
             .simulate_txn_stats()
-            .increment(stats_key, output.gas_used());
-
-        // Build up a transaction from the outputs
-        // All state hashes are invalid, and will be filled with 0s
-        let txn = aptos_types::transaction::Transaction::UserTransaction(txn);
-        let zero_hash = aptos_crypto::HashValue::zero();
-        let info = aptos_types::transaction::TransactionInfo::new(
-            txn.hash(),
-            zero_hash,
-            zero_hash,
-            None,
-            output.gas_used(),
-            exe_status,
-        );
-        let simulated_txn = TransactionOnChainData {
-            version,
-            transaction: txn,
-            info,
-            events: output.events().to_vec(),
-            accumulator_root_hash: zero_hash,
-            changes: output.write_set().clone(),
-        };
+            .increment(simulation_stats_key(&txn), output.gas_used());
+
+        let simulated_txn = simulated_transaction_on_chain_data(version, txn, &output);
 
         match accept_type {
             AcceptType::Json => {
-                let transactions = self
-                    .context
-                    .render_transactions_non_sequential(&ledger_info, vec![simulated_txn])?;
-
-                // Users can only make requests to simulate UserTransactions, so unpack
-                // the Vec<Transaction> into Vec<UserTransaction>.
-                let mut user_transactions = Vec::new();
-                for transaction in transactions.into_iter() {
-                    match transaction {
-                        Transaction::UserTransaction(user_txn) => {
-                            let mut txn = *user_txn;
-                            match &vm_status {
-                                VMStatus::Error {
-                                    message: Some(msg), ..
-                                }
-                                | VMStatus::ExecutionFailure {
-                                    message: Some(msg), ..
-                                } => {
-                                    txn.info.vm_status +=
-                                        format!("\nExecution failed with message: {}", msg)
-                                            .as_str();
-                                },
-                                _ => (),
-                            }
-                            user_transactions.push(txn);
-                        },
-                        _ => {
-                            return Err(SubmitTransactionError::internal_with_code(
-                                "Simulation transaction resulted in a non-UserTransaction",
-                                AptosErrorCode::InternalError,
-                                &ledger_info,
-                            ))
-                        },
-                    }
-                }
+                // Users can only make requests to simulate UserTransactions, so there is only
+                // one UserTransaction to return.
+                let mut user_transaction =
+                    self.render_simulated_transaction(&ledger_info, simulated_txn, &vm_status)?;
+                user_transaction.state_storage_fees = state_storage_fees;
                 BasicResponse::try_from_json((
-                    user_transactions,
+                    vec![user_transaction],
                     &ledger_info,
                     BasicResponseStatus::Ok,
                 ))
@@ -1460,6 +1497,225 @@ impl TransactionsApi {
         }
     }
 
+    /// Simulate a transaction in the VM against the state at the requested version with the
+    /// requested overrides applied, profiling the gas it uses
+    pub fn simulate_with_overrides(
+        &self,
+        request: SimulateTransactionWithOverridesRequest,
+    ) -> SimulateTransactionResult<TransactionSimulationResult> {
+        let ledger_info = self.context.get_latest_ledger_info()?;
+        let version = match request.ledger_version {
+            Some(version) => {
+                let version = version.0;
+                if version > ledger_info.version() {
+                    return Err(version_not_found(version, &ledger_info));
+                } else if version < ledger_info.oldest_ledger_version.0 {
+                    return Err(SubmitTransactionError::bad_request_with_code(
+                        format!("Ledger version({}) has been pruned", version),
+                        AptosErrorCode::VersionPruned,
+                        &ledger_info,
+                    ));
+                }
+                version
+            },
+            None => ledger_info.version(),
+        };
+
+        let mut txn = self.get_signed_transaction(
+            &ledger_info,
+            SubmitTransactionPost::Json(Json(request.transaction)),
+        )?;
+
+        // Same as for regular simulation, see simulate_transaction.
+        if !self.context.node_config.api.simulation_filter.allows(
+            aptos_crypto::HashValue::zero(),
+            ledger_info.timestamp(),
+            &txn,
+        ) {
+            return Err(SubmitTransactionError::forbidden_with_code(
+                "Transaction not allowed by simulation filter",
+                AptosErrorCode::InvalidInput,
+                &ledger_info,
+            ));
+        }
+
+        // The caller must ensure that the signature is not valid, as otherwise
+        // a malicious actor could execute the transaction without their knowledge
+        if txn.verify_signature().is_ok() {
+            return Err(SubmitTransactionError::bad_request_with_code(
+                "Simulated transactions must not have a valid signature",
+                AptosErrorCode::InvalidInput,
+                &ledger_info,
+            ));
+        }
+
+        // The gas profile is rooted at the function the transaction calls
+        let entry_function = match txn.payload() {
+            TransactionPayload::Script(_)
+            | TransactionPayload::Orderless(OrderlessPayload {
+                executable: TransactionExecutable::Script(_),
+                ..
+            }) => None,
+            TransactionPayload::EntryFunction(entry_function)
+            | TransactionPayload::Orderless(OrderlessPayload {
+                executable: TransactionExecutable::EntryFunction(entry_function),
+                ..
+            }) => Some(entry_function.clone()),
+            TransactionPayload::Multisig(multisig) => match &multisig.transaction_payload {
+                Some(MultisigTransactionPayload::EntryFunction(entry_function)) => {
+                    Some(entry_function.clone())
+                },
+                None => {
+                    return Err(SubmitTransactionError::bad_request_with_code(
+                        "Multisig transactions must include their payload to be simulated with overrides",
+                        AptosErrorCode::InvalidInput,
+                        &ledger_info,
+                    ))
+                },
+            },
+            TransactionPayload::ModuleBundle(_) => {
+                return Err(SubmitTransactionError::bad_request_with_code(
+                    "Module bundle payload has been removed",
+                    AptosErrorCode::InvalidInput,
+                    &ledger_info,
+                ))
+            },
+        };
+
+        let base_state_view = self
+            .context
+            .state_view_at_version(version)
+            .context("Failed to read state from DB")
+            .map_err(|err| {
+                SubmitTransactionError::internal_with_code(
+                    err,
+                    AptosErrorCode::InternalError,
+                    &ledger_info,
+                )
+            })?;
+        let state_view = StateViewWithOverrides::new(
+            &base_state_view,
+            request.state_overrides.as_deref().unwrap_or_default(),
+        )
+        .context("Invalid state override")
+        .map_err(|err| {
+            SubmitTransactionError::bad_request_with_code(
+                err,
+                AptosErrorCode::InvalidInput,
+                &ledger_info,
+            )
+        })?;
+
+        if request.skip_sequence_number_check.unwrap_or_default()
+            && !txn.replay_protector().is_nonce()
+        {
+            let account_resource = state_view
+                .get_state_value_bytes(&StateKey::resource_typed::<AccountResource>(&txn.sender()))
+                .context("Failed to read account resource")
+                .and_then(|bytes| {
+                    bytes
+                        .map(|bytes| bcs::from_bytes::<AccountResource>(&bytes))
+                        .transpose()
+                        .context("Failed to deserialize account resource")
+                })
+                .map_err(|err| {
+                    SubmitTransactionError::internal_with_code(
+                        err,
+                        AptosErrorCode::InternalError,
+                        &ledger_info,
+                    )
+                })?;
+            // Accounts that don't exist yet may still be created by the transaction
+            if let Some(account_resource) = account_resource {
+                txn = override_sequence_number(&txn, account_resource.sequence_number());
+            }
+        }
+
+        let (vm_status, output, gas_profiler) =
+            AptosSimulationVM::create_vm_and_simulate_signed_transaction_with_gas_meter(
+                &txn,
+                &state_view,
+                !state_view.requires_isolated_vm(),
+                |gas_feature_version, gas_params, storage_gas_params, balance| {
+                    let gas_meter =
+                        MemoryTrackedGasMeter::new(StandardGasMeter::new(StandardGasAlgebra::new(
+                            gas_feature_version,
+                            gas_params,
+                            storage_gas_params,
+                            balance,
+                        )));
+                    Ok(match entry_function {
+                        Some(entry_function) => GasProfiler::new_function(
+                            gas_meter,
+                            entry_function.module().clone(),
+                            entry_function.function().to_owned(),
+                            entry_function.ty_args().to_vec(),
+                        ),
+                        None => GasProfiler::new_script(gas_meter),
+                    })
+                },
+            )
+            .map_err(|status| {
+                SubmitTransactionError::internal_with_code(
+                    format!("Failed to simulate transaction: {:?}", status),
+                    AptosErrorCode::InternalError,
+                    &ledger_info,
+                )
+            })?;
+
+        self.context
+            .simulate_txn_stats()
+            .increment(simulation_stats_key(&txn), output.gas_used());
+
+        let simulated_txn = simulated_transaction_on_chain_data(version, txn, &output);
+        let transaction =
+            self.render_simulated_transaction(&ledger_info, simulated_txn, &vm_status)?;
+        BasicResponse::try_from_json((
+            TransactionSimulationResult {
+                transaction,
+                gas_profile: gas_profile(&gas_profiler.finish()),
+            },
+            &ledger_info,
+            BasicResponseStatus::Ok,
+        ))
+    }
+
+    /// Renders a simulated transaction, appending the message of a failed execution to its
+    /// VM status
+    fn render_simulated_transaction(
+        &self,
+        ledger_info: &LedgerInfo,
+        simulated_txn: TransactionOnChainData,
+        vm_status: &VMStatus,
+    ) -> Result<UserTransaction, SubmitTransactionError> {
+        let transactions = self
+            .context
+            .render_transactions_non_sequential(ledger_info, vec![simulated_txn])?;
+        match transactions.into_iter().next() {
+            Some(Transaction::UserTransaction(user_txn)) => {
+                let mut txn = *user_txn;
+                match vm_status {
+                    VMStatus::Error {
+                        message: Some(msg), ..
+                    }
+                    | VMStatus::ExecutionFailure {
+                        message: Some(msg), ..
+                    } => {
+                        txn.info.vm_status +=
+                            format!("\nExecution failed with message: {}", msg).as_str();
+                    },
+                    _ => (),
+                }
+                Ok(txn)
+            },
+            _ => Err(SubmitTransactionError::internal_with_code(
+                "Simulation transaction resulted in a non-UserTransaction",
+                AptosErrorCode::InternalError,
+                ledger_info,
+            )),
+        }
+    }
+
     /// Encode message as BCS
     pub fn get_signing_message(
         &self,
@@ -1543,6 +1799,134 @@ fn override_gas_parameters(
     SignedTransaction::new_with_authenticator(raw_txn, signed_txn.authenticator())
 }
 
+fn override_sequence_number(
+    signed_txn: &SignedTransaction,
+    sequence_number: u64,
+) -> SignedTransaction {
+    let raw_txn = RawTransaction::new(
+        signed_txn.sender(),
+        sequence_number,
+        signed_txn.payload().clone(),
+        signed_txn.max_gas_amount(),
+        signed_txn.gas_unit_price(),
+        signed_txn.expiration_timestamp_secs(),
+        signed_txn.chain_id(),
+    );
+    SignedTransaction::new_with_authenticator(raw_txn, signed_txn.authenticator())
+}
+
+fn simulation_stats_key(txn: &SignedTransaction) -> String {
+    match txn.payload() {
+        TransactionPayload::Script(_)
+        | TransactionPayload::Orderless(OrderlessPayload {
+            executable: TransactionExecutable::Script(_),
+            ..
+        }) => format!("Script::{}", txn.clone().committed_hash()).to_string(),
+        TransactionPayload::ModuleBundle(_) => "ModuleBundle::unknown".to_string(),
+        TransactionPayload::EntryFunction(entry_function)
+        | TransactionPayload::Orderless(OrderlessPayload {
+            executable: TransactionExecutable::EntryFunction(entry_function),
+            ..
+        }) => FunctionStats::function_to_key(
+            entry_function.module(),
+            &entry_function.function().into(),
+        ),
+        TransactionPayload::Multisig(multisig) => {
+            if let Some(payload) = &multisig.transaction_payload {
+                match payload {
+                    MultisigTransactionPayload::EntryFunction(entry_function) => {
+                        FunctionStats::function_to_key(
+                            entry_function.module(),
+                            &entry_function.function().into(),
+                        )
+                    },
+                }
+            } else {
+                "Multisig::unknown".to_string()
+            }
+        },
+    }
+}
+
+/// Builds up a transaction from the outputs of a simulation. All state hashes are invalid,
+/// and will be filled with 0s
+fn simulated_transaction_on_chain_data(
+    version: u64,
+    txn: SignedTransaction,
+    output: &TransactionOutput,
+) -> TransactionOnChainData {
+    // Ensure that all known statuses return their values in the output (even if they aren't supposed to)
+    let exe_status = match output.status().clone() {
+        TransactionStatus::Keep(exec_status) => exec_status,
+        TransactionStatus::Discard(status) => ExecutionStatus::MiscellaneousError(Some(status)),
+        _ => ExecutionStatus::MiscellaneousError(None),
+    };
+
+    let txn = aptos_types::transaction::Transaction::UserTransaction(txn);
+    let zero_hash = aptos_crypto::HashValue::zero();
+    let info = aptos_types::transaction::TransactionInfo::new(
+        txn.hash(),
+        zero_hash,
+        zero_hash,
+        None,
+        output.gas_used(),
+        exe_status,
+    );
+    TransactionOnChainData {
+        version,
+        transaction: txn,
+        info,
+        events: output.events().to_vec(),
+        accumulator_root_hash: zero_hash,
+        changes: output.write_set().clone(),
+    }
+}
+
+fn gas_profile(gas_log: &TransactionGasLog) -> GasProfile {
+    let aggregated = gas_log.exec_io.aggregate_gas_events();
+    GasProfile {
+        gas_scaling_factor: u64::from(aggregated.gas_scaling_factor).into(),
+        execution_and_io_cost: u64::from(aggregated.total).into(),
+        intrinsic_cost: u64::from(gas_log.exec_io.intrinsic_cost).into(),
+        transaction_write_cost: u64::from(aggregated.transaction_write).into(),
+        operations: gas_profile_entries(aggregated.ops),
+        storage_reads: gas_profile_entries(aggregated.storage_reads),
+        storage_writes: gas_profile_entries(aggregated.storage_writes),
+        event_writes: gas_profile_entries(aggregated.event_writes),
+        storage_fee: u64::from(gas_log.storage.total).into(),
+        storage_fee_refund: u64::from(gas_log.storage.total_refund).into(),
+        state_storage_fees: state_storage_fees(gas_log),
+    }
+}
+
+/// Storage fee charged and refunded per state slot, ordered from most to least expensive
+fn state_storage_fees(gas_log: &TransactionGasLog) -> Vec<StateSlotStorageFee> {
+    let mut writes = gas_log.storage.write_set_storage.iter().collect::<Vec<_>>();
+    writes.sort_by(|lhs, rhs| rhs.cost.cmp(&lhs.cost));
+    writes
+        .into_iter()
+        .map(|write| StateSlotStorageFee {
+            state_key_hash: write.key.hash().to_hex_literal(),
+            storage_fee: u64::from(write.cost).into(),
+            storage_fee_refund: u64::from(write.refund).into(),
+        })
+        .collect()
+}
+
+fn gas_profile_entries<N>(events: Vec<(String, usize, N)>) -> Vec<GasProfileEntry>
+where
+    u64: From<N>,
+{
+    events
+        .into_iter()
+        .map(|(name, count, cost)| GasProfileEntry {
+            name,
+            count: (count as u64).into(),
+            cost: u64::from(cost).into(),
+        })
+        .collect()
+}
+
 enum GetByVersionResponse {
     VersionTooNew,
     VersionTooOld,
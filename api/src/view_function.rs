@@ -14,13 +14,13 @@ use crate::{
 };
 use anyhow::Context as anyhowContext;
 use aptos_api_types::{
-    AptosErrorCode, AsConverter, MoveValue, ViewFunction, ViewRequest, MAX_RECURSIVE_TYPES_ALLOWED,
-    U64,
+    AptosError, AptosErrorCode, AsConverter, MoveConverter, MoveValue, ViewFunction,
+    ViewFunctionBcsResult, ViewFunctionResult, ViewRequest, MAX_RECURSIVE_TYPES_ALLOWED, U64,
 };
-use aptos_bcs_utils::serialize_uleb128;
+use aptos_bcs_utils::{deserialize_uleb128, serialize_uleb128};
 use aptos_vm::{data_cache::AsMoveResolver, AptosVM};
 use itertools::Itertools;
-use move_core_types::language_storage::TypeTag;
+use move_core_types::{language_storage::TypeTag, resolver::ModuleResolver};
 use poem_openapi::{param::Query, payload::Json, ApiRequest, OpenApi};
 use std::sync::Arc;
 
@@ -39,6 +39,15 @@ pub enum ViewFunctionRequest {
     Bcs(Bcs),
 }
 
+#[derive(ApiRequest, Debug)]
+pub enum ViewFunctionBatchRequest {
+    #[oai(content_type = "application/json")]
+    Json(Json<Vec<ViewRequest>>),
+
+    #[oai(content_type = "application/x.aptos.view_function+bcs")]
+    Bcs(Bcs),
+}
+
 #[OpenApi]
 impl ViewFunctionApi {
     /// Execute view function of a module
@@ -71,6 +80,44 @@ impl ViewFunctionApi {
         api_spawn_blocking(move || view_request(context, accept_type, request, ledger_version))
             .await
     }
+
+    /// Execute a batch of view functions
+    ///
+    /// Execute several Move view functions against the same ledger version, and return the
+    /// result of each of them, in order. A function that fails does not fail the whole
+    /// request, its error is returned in place of its return values instead.
+    ///
+    /// When BCS is requested, the response is a vector with, for each function, either the
+    /// vector of its BCS encoded return values or its error.
+    ///
+    /// The Aptos nodes prune account state history, via a configurable time window.
+    /// If the requested ledger version has been pruned, the server responds with a 410.
+    #[oai(
+        path = "/view/batch",
+        method = "post",
+        operation_id = "view_batch",
+        tag = "ApiTags::View"
+    )]
+    async fn view_function_batch(
+        &self,
+        accept_type: AcceptType,
+        /// View function requests with type and position arguments
+        request: ViewFunctionBatchRequest,
+        /// Ledger version to get state of account
+        ///
+        /// If not provided, it will be the latest version
+        ledger_version: Query<Option<U64>>,
+    ) -> BasicResultWith404<Vec<ViewFunctionResult>> {
+        fail_point_poem("endpoint_view_function_batch")?;
+        self.context
+            .check_api_output_enabled("View function batch", &accept_type)?;
+
+        let context = self.context.clone();
+        api_spawn_blocking(move || {
+            view_batch_request(context, accept_type, request, ledger_version)
+        })
+        .await
+    }
 }
 
 fn view_request(
@@ -121,16 +168,9 @@ fn view_request(
     };
 
     // Reject the request if it's not allowed by the filter.
-    if !context.node_config.api.view_filter.allows(
-        view_function.module.address(),
-        view_function.module.name().as_str(),
-        view_function.function.as_str(),
-    ) {
+    if let Err(err) = check_view_filter(&context, &view_function) {
         return Err(BasicErrorWith404::forbidden_with_code_no_info(
-            format!(
-                "Function {}::{} is not allowed",
-                view_function.module, view_function.function
-            ),
+            err,
             AptosErrorCode::InvalidInput,
         ));
     }
@@ -170,15 +210,10 @@ fn view_request(
         },
         AcceptType::Json => {
             let resolver = state_view.as_move_resolver();
-            let return_types = resolver
-                .as_converter(context.db.clone(), context.table_info_reader.clone())
-                .function_return_types(&view_function)
-                .and_then(|tys| {
-                    tys.into_iter()
-                        .map(TypeTag::try_from)
-                        .collect::<anyhow::Result<Vec<_>>>()
-                })
-                .map_err(|err| {
+            let converter =
+                resolver.as_converter(context.db.clone(), context.table_info_reader.clone());
+            let move_vals =
+                convert_return_values(&converter, &view_function, values).map_err(|err| {
                     BasicErrorWith404::bad_request_with_code(
                         err,
                         AptosErrorCode::InternalError,
@@ -186,29 +221,207 @@ fn view_request(
                     )
                 })?;
 
-            let move_vals = values
+            BasicResponse::try_from_json((move_vals, &ledger_info, BasicResponseStatus::Ok))
+        },
+    };
+    context.view_function_stats().increment(
+        FunctionStats::function_to_key(&view_function.module, &view_function.function),
+        output.gas_used,
+    );
+    result
+}
+
+fn view_batch_request(
+    context: Arc<Context>,
+    accept_type: AcceptType,
+    request: ViewFunctionBatchRequest,
+    ledger_version: Query<Option<U64>>,
+) -> BasicResultWith404<Vec<ViewFunctionResult>> {
+    // Retrieve the current state of the chain, all the functions are executed against it
+    let (ledger_info, requested_version) = context
+        .get_latest_ledger_info_and_verify_lookup_version(ledger_version.map(|inner| inner.0))?;
+
+    let state_view = context
+        .state_view_at_version(requested_version)
+        .map_err(|err| {
+            BasicErrorWith404::bad_request_with_code(
+                err,
+                AptosErrorCode::InternalError,
+                &ledger_info,
+            )
+        })?;
+
+    let check_batch_size = |batch_size: usize| {
+        if context.max_view_function_batch_size() < batch_size {
+            return Err(BasicErrorWith404::bad_request_with_code(
+                format!(
+                    "Requested too many view functions: {}, while limit is {}",
+                    batch_size,
+                    context.max_view_function_batch_size(),
+                ),
+                AptosErrorCode::InvalidInput,
+                &ledger_info,
+            ));
+        }
+        Ok(())
+    };
+
+    // Functions that can't be converted fail on their own, rather than failing the whole batch
+    let view_functions: Vec<Result<ViewFunction, AptosError>> = match request {
+        ViewFunctionBatchRequest::Json(data) => {
+            check_batch_size(data.0.len())?;
+            let resolver = state_view.as_move_resolver();
+            let converter =
+                resolver.as_converter(context.db.clone(), context.table_info_reader.clone());
+            data.0
                 .into_iter()
-                .zip(return_types.into_iter())
-                .map(|(v, ty)| {
-                    resolver
-                        .as_converter(context.db.clone(), context.table_info_reader.clone())
-                        .try_into_move_value(&ty, &v)
+                .map(|view_request| {
+                    converter
+                        .convert_view_function(view_request)
+                        .map_err(|err| {
+                            AptosError::new_with_error_code(err, AptosErrorCode::InvalidInput)
+                        })
                 })
-                .collect::<anyhow::Result<Vec<_>>>()
+                .collect()
+        },
+        ViewFunctionBatchRequest::Bcs(data) => {
+            // Check the length prefix before deserializing the whole batch
+            let (batch_size, _) = deserialize_uleb128(data.0.as_slice())
+                .context("Failed to deserialize input into Vec<ViewFunction>")
                 .map_err(|err| {
                     BasicErrorWith404::bad_request_with_code(
                         err,
-                        AptosErrorCode::InternalError,
+                        AptosErrorCode::InvalidInput,
                         &ledger_info,
                     )
                 })?;
-
-            BasicResponse::try_from_json((move_vals, &ledger_info, BasicResponseStatus::Ok))
+            check_batch_size(usize::try_from(batch_size).unwrap_or(usize::MAX))?;
+            let view_functions: Vec<ViewFunction> =
+                bcs::from_bytes_with_limit(data.0.as_slice(), MAX_RECURSIVE_TYPES_ALLOWED as usize)
+                    .context("Failed to deserialize input into Vec<ViewFunction>")
+                    .map_err(|err| {
+                        BasicErrorWith404::bad_request_with_code(
+                            err,
+                            AptosErrorCode::InvalidInput,
+                            &ledger_info,
+                        )
+                    })?;
+            view_functions.into_iter().map(Ok).collect()
         },
     };
-    context.view_function_stats().increment(
-        FunctionStats::function_to_key(&view_function.module, &view_function.function),
-        output.gas_used,
-    );
-    result
+
+    // Functions that are not allowed by the filter are not executed
+    let view_functions: Vec<Result<ViewFunction, AptosError>> = view_functions
+        .into_iter()
+        .map(|view_function| {
+            let view_function = view_function?;
+            check_view_filter(&context, &view_function).map_err(|err| {
+                AptosError::new_with_error_code(err, AptosErrorCode::InvalidInput)
+            })?;
+            Ok(view_function)
+        })
+        .collect();
+
+    let mut outputs = AptosVM::execute_view_functions(
+        &state_view,
+        view_functions.iter().flatten().map(|view_function| {
+            (
+                view_function.module.clone(),
+                view_function.function.clone(),
+                view_function.ty_args.clone(),
+                view_function.args.clone(),
+            )
+        }),
+        context.node_config.api.max_gas_view_function,
+    )
+    .into_iter();
+    let results: Vec<Result<(ViewFunction, Vec<Vec<u8>>), AptosError>> = view_functions
+        .into_iter()
+        .map(|view_function| {
+            let view_function = view_function?;
+            let output = outputs
+                .next()
+                .expect("One output is returned per executed view function");
+            context.view_function_stats().increment(
+                FunctionStats::function_to_key(&view_function.module, &view_function.function),
+                output.gas_used,
+            );
+            let values = output.values.map_err(|err| {
+                AptosError::new_with_error_code(err, AptosErrorCode::InvalidInput)
+            })?;
+            Ok((view_function, values))
+        })
+        .collect();
+
+    match accept_type {
+        AcceptType::Bcs => {
+            let results: Vec<ViewFunctionBcsResult> = results
+                .into_iter()
+                .map(|result| result.map(|(_, values)| values))
+                .collect();
+            BasicResponse::try_from_bcs((results, &ledger_info, BasicResponseStatus::Ok))
+        },
+        AcceptType::Json => {
+            let resolver = state_view.as_move_resolver();
+            let converter =
+                resolver.as_converter(context.db.clone(), context.table_info_reader.clone());
+            let results = results
+                .into_iter()
+                .map(|result| {
+                    let values = result.and_then(|(view_function, values)| {
+                        convert_return_values(&converter, &view_function, values).map_err(|err| {
+                            AptosError::new_with_error_code(err, AptosErrorCode::InternalError)
+                        })
+                    });
+                    match values {
+                        Ok(values) => ViewFunctionResult {
+                            values: Some(values),
+                            error: None,
+                        },
+                        Err(error) => ViewFunctionResult {
+                            values: None,
+                            error: Some(error),
+                        },
+                    }
+                })
+                .collect();
+            BasicResponse::try_from_json((results, &ledger_info, BasicResponseStatus::Ok))
+        },
+    }
+}
+
+/// Returns an error if the view function is not allowed by the filter of the node.
+fn check_view_filter(context: &Context, view_function: &ViewFunction) -> Result<(), String> {
+    if context.node_config.api.view_filter.allows(
+        view_function.module.address(),
+        view_function.module.name().as_str(),
+        view_function.function.as_str(),
+    ) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Function {}::{} is not allowed",
+            view_function.module, view_function.function
+        ))
+    }
+}
+
+/// Converts the BCS encoded return values of a view function to JSON.
+fn convert_return_values<R: ModuleResolver>(
+    converter: &MoveConverter<R>,
+    view_function: &ViewFunction,
+    values: Vec<Vec<u8>>,
+) -> anyhow::Result<Vec<MoveValue>> {
+    let return_types = converter
+        .function_return_types(view_function)
+        .and_then(|tys| {
+            tys.into_iter()
+                .map(TypeTag::try_from)
+                .collect::<anyhow::Result<Vec<_>>>()
+        })?;
+    values
+        .into_iter()
+        .zip(return_types)
+        .map(|(v, ty)| converter.try_into_move_value(&ty, &v))
+        .collect()
 }
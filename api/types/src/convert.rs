@@ -9,11 +9,12 @@ use crate::{
         UserTransactionRequestInner, WriteModule, WriteResource, WriteTableItem,
     },
     view::{ViewFunction, ViewRequest},
-    Bytecode, DirectWriteSet, EntryFunctionId, EntryFunctionPayload, Event, HexEncodedBytes,
-    MoveFunction, MoveModuleBytecode, MoveResource, MoveScriptBytecode, MoveType, MoveValue,
-    PendingTransaction, ResourceGroup, ScriptPayload, ScriptWriteSet, SubmitTransactionRequest,
-    Transaction, TransactionInfo, TransactionOnChainData, TransactionPayload,
-    UserTransactionRequest, VersionedEvent, WriteSet, WriteSetChange, WriteSetPayload,
+    Address, Bytecode, DirectWriteSet, EntryFunctionId, EntryFunctionPayload, Event,
+    HexEncodedBytes, MoveFunction, MoveModuleBytecode, MoveResource, MoveScriptBytecode, MoveType,
+    MoveValue, PendingTransaction, ResourceGroup, ScriptPayload, ScriptWriteSet,
+    SubmitTransactionRequest, Transaction, TransactionInfo, TransactionOnChainData,
+    TransactionPayload, UserTransactionRequest, VersionedEvent, WriteSet, WriteSetChange,
+    WriteSetPayload, U64,
 };
 use anyhow::{bail, ensure, format_err, Context as AnyhowContext, Result};
 use aptos_crypto::{hash::CryptoHash, HashValue};
@@ -28,7 +29,8 @@ use aptos_types::{
         table::{TableHandle, TableInfo},
     },
     transaction::{
-        EntryFunction, ExecutionStatus, Multisig, RawTransaction, Script, SignedTransaction,
+        EntryFunction, ExecutionStatus, Multisig, OrderlessPayload, RawTransaction, Script,
+        SignedTransaction, TransactionExecutable, ORDERLESS_TRANSACTION_SEQUENCE_NUMBER,
     },
     vm_status::AbortLocation,
     write_set::WriteOp,
@@ -266,6 +268,15 @@ impl<'a, R: ModuleResolver + ?Sized> MoveConverter<'a, R> {
                     transaction_payload,
                 })
             },
+            // The nonce is surfaced on the request, the payload is the code it executes.
+            Orderless(OrderlessPayload { executable, .. }) => match executable {
+                TransactionExecutable::Script(script) => {
+                    self.try_into_transaction_payload(Script(script))?
+                },
+                TransactionExecutable::EntryFunction(entry_function) => {
+                    self.try_into_transaction_payload(EntryFunction(entry_function))?
+                },
+            },
 
             // Deprecated.
             ModuleBundle(_) => bail!("Module bundle payload has been removed"),
@@ -535,16 +546,18 @@ impl<'a, R: ModuleResolver + ?Sized> MoveConverter<'a, R> {
             expiration_timestamp_secs,
             payload,
             signature: _,
+            replay_protection_nonce,
         } = txn;
-        Ok(RawTransaction::new(
-            sender.into(),
-            sequence_number.into(),
+        self.try_into_raw_transaction_with_replay_protection(
+            sender,
+            sequence_number,
+            replay_protection_nonce,
             self.try_into_aptos_core_transaction_payload(payload)?,
-            max_gas_amount.into(),
-            gas_unit_price.into(),
-            expiration_timestamp_secs.into(),
+            max_gas_amount,
+            gas_unit_price,
+            expiration_timestamp_secs,
             chain_id,
-        ))
+        )
     }
 
     pub fn try_into_raw_transaction_poem(
@@ -559,12 +572,67 @@ impl<'a, R: ModuleResolver + ?Sized> MoveConverter<'a, R> {
             gas_unit_price,
             expiration_timestamp_secs,
             payload,
+            replay_protection_nonce,
         } = user_transaction_request;
-        Ok(RawTransaction::new(
-            sender.into(),
-            sequence_number.into(),
+        self.try_into_raw_transaction_with_replay_protection(
+            sender,
+            sequence_number,
+            replay_protection_nonce,
             self.try_into_aptos_core_transaction_payload(payload)
                 .context("Failed to parse transaction payload")?,
+            max_gas_amount,
+            gas_unit_price,
+            expiration_timestamp_secs,
+            chain_id,
+        )
+    }
+
+    /// Builds an orderless transaction if a replay protection nonce is given, and a regular
+    /// sequence number based one otherwise. Orderless transactions must carry
+    /// `ORDERLESS_TRANSACTION_SEQUENCE_NUMBER`, since the sequence number is part of what is
+    /// signed and silently replacing it would make the signature mismatch.
+    #[allow(clippy::too_many_arguments)]
+    fn try_into_raw_transaction_with_replay_protection(
+        &self,
+        sender: Address,
+        sequence_number: U64,
+        replay_protection_nonce: Option<U64>,
+        payload: aptos_types::transaction::TransactionPayload,
+        max_gas_amount: U64,
+        gas_unit_price: U64,
+        expiration_timestamp_secs: U64,
+        chain_id: ChainId,
+    ) -> Result<RawTransaction> {
+        use aptos_types::transaction::TransactionPayload as Target;
+
+        let Some(nonce) = replay_protection_nonce else {
+            return Ok(RawTransaction::new(
+                sender.into(),
+                sequence_number.into(),
+                payload,
+                max_gas_amount.into(),
+                gas_unit_price.into(),
+                expiration_timestamp_secs.into(),
+                chain_id,
+            ));
+        };
+        ensure!(
+            sequence_number.0 == ORDERLESS_TRANSACTION_SEQUENCE_NUMBER,
+            "Orderless transactions must set sequence_number to {}, got {}",
+            ORDERLESS_TRANSACTION_SEQUENCE_NUMBER,
+            sequence_number.0
+        );
+        let executable = match payload {
+            Target::Script(script) => TransactionExecutable::Script(script),
+            Target::EntryFunction(entry_function) => {
+                TransactionExecutable::EntryFunction(entry_function)
+            },
+            _ => bail!("Orderless transactions only support script and entry function payloads"),
+        };
+        Ok(RawTransaction::new_orderless(
+            sender.into(),
+            nonce.into(),
+            executable,
             max_gas_amount.into(),
             gas_unit_price.into(),
             expiration_timestamp_secs.into(),
@@ -81,6 +81,8 @@ pub enum AptosErrorCode {
     BlockNotFound = 108,
     ///  StateValue not found at the requested version
     StateValueNotFound = 109,
+    /// State key is not tracked by this (partial state) node
+    StateKeyNotTracked = 110,
 
     /// Ledger version is pruned
     VersionPruned = 200,
@@ -17,6 +17,7 @@ mod index;
 mod ledger_info;
 pub mod mime_types;
 mod move_types;
+mod simulation;
 mod state;
 mod table;
 pub mod transaction;
@@ -41,6 +42,11 @@ pub use move_types::{
     ResourceGroup, MAX_RECURSIVE_TYPES_ALLOWED, U128, U256, U64,
 };
 use serde::{Deserialize, Deserializer};
+pub use simulation::{
+    AccountBalanceOverride, GasProfile, GasProfileEntry, ModuleOverride, ResourceOverride,
+    SimulateTransactionWithOverridesRequest, StateOverride, StateSlotStorageFee,
+    TransactionSimulationResult,
+};
 pub use state::RawStateValueRequest;
 use std::str::FromStr;
 pub use table::{RawTableItemRequest, TableItemRequest};
@@ -57,7 +63,7 @@ pub use transaction::{
     UserTransactionRequest, VersionedEvent, WriteModule, WriteResource, WriteSet, WriteSetChange,
     WriteSetPayload, WriteTableItem,
 };
-pub use view::{ViewFunction, ViewRequest};
+pub use view::{ViewFunction, ViewFunctionBcsResult, ViewFunctionResult, ViewRequest};
 pub use wrappers::{EventGuid, IdentifierWrapper, StateKeyWrapper};
 
 pub fn deserialize_from_string<'de, D, T>(deserializer: D) -> Result<T, D::Error>
@@ -0,0 +1,122 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    Address, HexEncodedBytes, MoveStructTag, SubmitTransactionRequest, UserTransaction,
+    VerifyInput, U64,
+};
+use poem_openapi::{Object, Union};
+use serde::{Deserialize, Serialize};
+
+/// A request to simulate a transaction against hypothetical state
+///
+/// Signatures are never checked when simulating, and as with regular simulation the
+/// transaction must not carry a valid signature.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct SimulateTransactionWithOverridesRequest {
+    pub transaction: SubmitTransactionRequest,
+    /// Ledger version to simulate against, defaults to the latest ledger version
+    pub ledger_version: Option<U64>,
+    /// Changes applied to the state before simulating, in order
+    pub state_overrides: Option<Vec<StateOverride>>,
+    /// If set to true, the sequence number of the transaction is replaced by the
+    /// sequence number of the sender
+    pub skip_sequence_number_check: Option<bool>,
+}
+
+impl VerifyInput for SimulateTransactionWithOverridesRequest {
+    fn verify(&self) -> anyhow::Result<()> {
+        self.transaction.verify()
+    }
+}
+
+/// A change to the state a transaction is simulated against
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Union)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[oai(one_of, discriminator_name = "type", rename_all = "snake_case")]
+pub enum StateOverride {
+    ResourceOverride(ResourceOverride),
+    AccountBalanceOverride(AccountBalanceOverride),
+    ModuleOverride(ModuleOverride),
+}
+
+/// Replaces or deletes a resource
+///
+/// Resources that are part of a resource group are stored in the group, so they have to
+/// be overridden by replacing the whole group.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct ResourceOverride {
+    pub address: Address,
+    pub resource_type: MoveStructTag,
+    /// BCS encoded value of the resource, the resource is deleted if not set
+    pub data: Option<HexEncodedBytes>,
+}
+
+/// Sets the APT balance of an account, which must already have a `0x1::coin::CoinStore`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct AccountBalanceOverride {
+    pub address: Address,
+    pub balance: U64,
+}
+
+/// Replaces or adds a module at the address given in its bytecode
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct ModuleOverride {
+    pub bytecode: HexEncodedBytes,
+}
+
+/// The outcome of simulating a transaction against hypothetical state
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct TransactionSimulationResult {
+    /// The simulated transaction, including its write set and events
+    pub transaction: UserTransaction,
+    pub gas_profile: GasProfile,
+}
+
+/// Gas used by a simulated transaction, broken down by what it was charged for
+///
+/// Execution and IO costs are in internal gas units, which are converted to gas units by
+/// dividing them by `gas_scaling_factor`. Storage fees are in octas.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct GasProfile {
+    pub gas_scaling_factor: U64,
+    /// Total execution and IO cost
+    pub execution_and_io_cost: U64,
+    /// Cost charged for every transaction, depending on its size
+    pub intrinsic_cost: U64,
+    /// IO cost of writing the transaction itself
+    pub transaction_write_cost: U64,
+    /// Execution cost of instructions and native functions
+    pub operations: Vec<GasProfileEntry>,
+    /// IO cost of reading resources
+    pub storage_reads: Vec<GasProfileEntry>,
+    /// IO cost of writing state
+    pub storage_writes: Vec<GasProfileEntry>,
+    /// IO cost of emitting events
+    pub event_writes: Vec<GasProfileEntry>,
+    /// Total storage fee
+    pub storage_fee: U64,
+    /// Total storage fee refunded for freed slots
+    pub storage_fee_refund: U64,
+    /// Storage fee charged and refunded per state slot, ordered from most to least expensive
+    pub state_storage_fees: Vec<StateSlotStorageFee>,
+}
+
+/// Aggregated cost of one kind of gas charge, ordered from most to least expensive
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct GasProfileEntry {
+    pub name: String,
+    /// Number of times it was charged
+    pub count: U64,
+    pub cost: U64,
+}
+
+/// Storage fee charged for, and refunded from, a single state slot written by the transaction
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct StateSlotStorageFee {
+    /// Hash of the state key, matching the `state_key_hash` of the corresponding change in
+    /// the write set of the transaction
+    pub state_key_hash: String,
+    pub storage_fee: U64,
+    pub storage_fee_refund: U64,
+}
@@ -5,7 +5,7 @@
 use crate::{
     Address, AptosError, EntryFunctionId, EventGuid, HashValue, HexEncodedBytes,
     MoveModuleBytecode, MoveModuleId, MoveResource, MoveScriptBytecode, MoveStructTag, MoveType,
-    MoveValue, VerifyInput, VerifyInputWithRecursion, U64,
+    MoveValue, StateSlotStorageFee, VerifyInput, VerifyInputWithRecursion, U64,
 };
 use anyhow::{bail, Context as AnyhowContext};
 use aptos_crypto::{
@@ -27,7 +27,7 @@ use aptos_types::{
             SingleKeyAuthenticator, TransactionAuthenticator, MAX_NUM_OF_SIGS,
         },
         webauthn::{PartialAuthenticatorAssertionResponse, MAX_WEBAUTHN_SIGNATURE_BYTES},
-        Script, SignedTransaction, TransactionOutput, TransactionWithProof,
+        ReplayProtector, Script, SignedTransaction, TransactionOutput, TransactionWithProof,
     },
 };
 use once_cell::sync::Lazy;
@@ -287,6 +287,7 @@ impl
             request: (txn, payload).into(),
             events,
             timestamp: timestamp.into(),
+            state_storage_fees: None,
         }))
     }
 }
@@ -343,6 +344,10 @@ impl From<(&SignedTransaction, TransactionPayload)> for UserTransactionRequest {
             expiration_timestamp_secs: txn.expiration_timestamp_secs().into(),
             signature: Some(txn.authenticator().into()),
             payload,
+            replay_protection_nonce: match txn.replay_protector() {
+                ReplayProtector::Nonce(nonce) => Some(nonce.into()),
+                ReplayProtector::SequenceNumber(_) => None,
+            },
         }
     }
 }
@@ -413,6 +418,11 @@ pub struct UserTransaction {
     /// Events generated by the transaction
     pub events: Vec<Event>,
     pub timestamp: U64,
+    /// Storage fee charged and refunded per state slot, ordered from most to least expensive.
+    /// Only present in simulations which request it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(skip_serializing_if_is_none)]
+    pub state_storage_fees: Option<Vec<StateSlotStorageFee>>,
 }
 
 /// A state checkpoint transaction
@@ -468,6 +478,10 @@ pub struct UserTransactionRequestInner {
     pub gas_unit_price: U64,
     pub expiration_timestamp_secs: U64,
     pub payload: TransactionPayload,
+    /// Set for orderless transactions, which are protected against replay by this nonce
+    /// instead of by `sequence_number`, which must then be 18446744073709551615
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replay_protection_nonce: Option<U64>,
 }
 
 impl VerifyInput for UserTransactionRequestInner {
@@ -496,6 +510,10 @@ pub struct UserTransactionRequest {
     pub payload: TransactionPayload,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub signature: Option<TransactionSignature>,
+    /// Set for orderless transactions, which are protected against replay by this nonce
+    /// instead of by `sequence_number`, which must then be 18446744073709551615
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replay_protection_nonce: Option<U64>,
 }
 
 /// Request to create signing messages
@@ -1171,6 +1189,33 @@ impl VerifyInput for Secp256k1EcdsaSignature {
     }
 }
 
+/// A single Secp256r1Ecdsa signature
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
+pub struct Secp256r1EcdsaSignature {
+    pub public_key: HexEncodedBytes,
+    pub signature: HexEncodedBytes,
+}
+
+impl VerifyInput for Secp256r1EcdsaSignature {
+    fn verify(&self) -> anyhow::Result<()> {
+        let public_key_len = self.public_key.inner().len();
+        let signature_len = self.signature.inner().len();
+        if public_key_len != secp256r1_ecdsa::PUBLIC_KEY_LENGTH {
+            bail!(
+                "Secp256r1Ecdsa signature's public key is an invalid number of bytes, should be {} bytes but found {}",
+                secp256r1_ecdsa::PUBLIC_KEY_LENGTH, public_key_len
+            )
+        } else if signature_len != secp256r1_ecdsa::SIGNATURE_LENGTH {
+            bail!(
+                "Secp256r1Ecdsa signature length is an invalid number of bytes, should be {} bytes but found {}",
+                secp256r1_ecdsa::SIGNATURE_LENGTH, signature_len
+            )
+        } else {
+            Ok(())
+        }
+    }
+}
+
 /// A single WebAuthn signature
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Object)]
 pub struct WebAuthnSignature {
@@ -1233,6 +1278,7 @@ impl VerifyInput for KeylessSignature {
 pub enum Signature {
     Ed25519(HexEncodedBytes),
     Secp256k1Ecdsa(HexEncodedBytes),
+    Secp256r1Ecdsa(HexEncodedBytes),
     WebAuthn(HexEncodedBytes),
     Keyless(HexEncodedBytes),
 }
@@ -1244,6 +1290,7 @@ impl TryFrom<Signature> for AnySignature {
         Ok(match signature {
             Signature::Ed25519(s) => AnySignature::ed25519(s.inner().try_into()?),
             Signature::Secp256k1Ecdsa(s) => AnySignature::secp256k1_ecdsa(s.inner().try_into()?),
+            Signature::Secp256r1Ecdsa(s) => AnySignature::secp256r1_ecdsa(s.inner().try_into()?),
             Signature::WebAuthn(s) => AnySignature::webauthn(s.inner().try_into()?),
             Signature::Keyless(s) => AnySignature::keyless(s.inner().try_into()?),
         })
@@ -1259,6 +1306,9 @@ impl From<AnySignature> for Signature {
             AnySignature::Secp256k1Ecdsa { signature } => {
                 Signature::Secp256k1Ecdsa(signature.to_bytes().to_vec().into())
             },
+            AnySignature::Secp256r1Ecdsa { signature } => {
+                Signature::Secp256r1Ecdsa(signature.to_bytes().to_vec().into())
+            },
             AnySignature::WebAuthn { signature } => {
                 Signature::WebAuthn(signature.to_bytes().to_vec().into())
             },
@@ -1331,6 +1381,13 @@ impl VerifyInput for SingleKeySignature {
                 }
                 .verify()
             },
+            (PublicKey::Secp256r1Ecdsa(p), Signature::Secp256r1Ecdsa(s)) => {
+                Secp256r1EcdsaSignature {
+                    public_key: p.clone(),
+                    signature: s.clone(),
+                }
+                .verify()
+            },
             (PublicKey::Secp256r1Ecdsa(p), Signature::WebAuthn(s)) => WebAuthnSignature {
                 public_key: p.clone(),
                 signature: s.clone(),
@@ -1403,6 +1460,13 @@ impl TryFrom<SingleKeySignature> for AccountAuthenticator {
                     .context("Failed to parse given signature bytes as Secp256k1EcdsaSignature")?;
                 AnySignature::secp256k1_ecdsa(signature)
             },
+            Signature::Secp256r1Ecdsa(s) => {
+                let signature = s
+                    .inner()
+                    .try_into()
+                    .context("Failed to parse given signature bytes as Secp256r1EcdsaSignature")?;
+                AnySignature::secp256r1_ecdsa(signature)
+            },
             Signature::WebAuthn(s) => {
                 let signature = s
                     .inner()
@@ -1506,6 +1570,12 @@ impl TryFrom<MultiKeySignature> for AccountAuthenticator {
                         )?;
                         AnySignature::secp256k1_ecdsa(signature)
                     },
+                    Signature::Secp256r1Ecdsa(s) => {
+                        let signature = s.inner().try_into().context(
+                            "Failed to parse given signature as Secp256r1EcdsaSignature",
+                        )?;
+                        AnySignature::secp256r1_ecdsa(signature)
+                    },
                     Signature::WebAuthn(s) => {
                         let paar = s.inner().try_into().context(
                         "Failed to parse given signature as PartialAuthenticatorAssertionResponse",
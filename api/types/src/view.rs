@@ -1,7 +1,7 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{EntryFunctionId, MoveType};
+use crate::{AptosError, EntryFunctionId, MoveType, MoveValue};
 use aptos_types::serde_helper::vec_bytes;
 use move_core_types::{
     identifier::Identifier,
@@ -28,3 +28,19 @@ pub struct ViewFunction {
     #[serde(with = "vec_bytes")]
     pub args: Vec<Vec<u8>>,
 }
+
+/// Result of one view function of a batch view request
+///
+/// Either `values` or `error` is set, depending on whether the function succeeded.
+#[derive(Clone, Debug, Serialize, Deserialize, Object)]
+pub struct ViewFunctionResult {
+    /// Return values of the function
+    pub values: Option<Vec<MoveValue>>,
+    /// Why the function failed
+    pub error: Option<AptosError>,
+}
+
+/// Result of one view function of a batch view request, as returned when BCS is requested
+///
+/// On success, holds each of the BCS encoded return values of the function.
+pub type ViewFunctionBcsResult = Result<Vec<Vec<u8>>, AptosError>;
@@ -13,8 +13,9 @@ use aptos_types::{
     on_chain_config::{Features, OnChainConfig, TimedFeaturesBuilder},
     state_store::TStateView,
     transaction::{
-        signature_verified_transaction::SignatureVerifiedTransaction, SignedTransaction,
-        Transaction, TransactionInfo, TransactionOutput, TransactionPayload, Version,
+        signature_verified_transaction::SignatureVerifiedTransaction, OrderlessPayload,
+        SignedTransaction, Transaction, TransactionExecutable, TransactionInfo, TransactionOutput,
+        TransactionPayload, Version,
     },
     vm_status::VMStatus,
 };
@@ -121,8 +122,16 @@ impl AptosDebugger {
                         balance,
                     )));
                 let gas_profiler = match txn.payload() {
-                    TransactionPayload::Script(_) => GasProfiler::new_script(gas_meter),
-                    TransactionPayload::EntryFunction(entry_func) => GasProfiler::new_function(
+                    TransactionPayload::Script(_)
+                    | TransactionPayload::Orderless(OrderlessPayload {
+                        executable: TransactionExecutable::Script(_),
+                        ..
+                    }) => GasProfiler::new_script(gas_meter),
+                    TransactionPayload::EntryFunction(entry_func)
+                    | TransactionPayload::Orderless(OrderlessPayload {
+                        executable: TransactionExecutable::EntryFunction(entry_func),
+                        ..
+                    }) => GasProfiler::new_function(
                         gas_meter,
                         entry_func.module().clone(),
                         entry_func.function().to_owned(),
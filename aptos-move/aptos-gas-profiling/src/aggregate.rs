@@ -53,7 +53,7 @@ fn insert_or_add<K, U>(
     }
 }
 
-fn into_sorted_vec<I, K, N>(collection: I) -> Vec<(K, usize, N)>
+pub(crate) fn into_sorted_vec<I, K, N>(collection: I) -> Vec<(K, usize, N)>
 where
     N: Ord,
     I: IntoIterator<Item = (K, (usize, N))>,
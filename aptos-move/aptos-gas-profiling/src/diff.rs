@@ -0,0 +1,413 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    log::{CallFrame, ExecutionGasEvent, TransactionGasLog},
+    render::Render,
+    report::render_table,
+};
+use aptos_gas_algebra::GasScalingFactor;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::{
+    collections::{btree_map, BTreeMap},
+    fmt::{self, Write},
+};
+
+/// The costs of one item (a function, an instruction, a storage slot, etc.) in the two gas logs
+/// being compared, along with the number of times it was charged in each of them.
+///
+/// Execution & IO costs are in internal gas units, storage fees are in octas.
+#[derive(Debug, Clone)]
+pub struct GasDiffEntry {
+    pub name: String,
+    pub base_count: usize,
+    pub base: u64,
+    pub new_count: usize,
+    pub new: u64,
+}
+
+/// A differential report between two gas logs, e.g. of the same transaction running against an
+/// old and a new version of a package.
+///
+/// All the entries are sorted by how much their cost changed, from high to low. Items that only
+/// appear in one of the logs are included, with a cost of zero in the other.
+#[derive(Debug, Clone)]
+pub struct GasProfileDiff {
+    /// The gas scaling factor of the new log, to convert internal gas units to gas units.
+    pub gas_scaling_factor: GasScalingFactor,
+
+    pub execution_and_io: GasDiffEntry,
+    pub storage_fee: GasDiffEntry,
+    pub storage_refund: GasDiffEntry,
+
+    /// Execution costs of function frames, including the costs of the functions they call.
+    pub frames: Vec<GasDiffEntry>,
+    /// Execution costs of instructions and native functions.
+    pub ops: Vec<GasDiffEntry>,
+    /// IO costs of reading resources, per resource type.
+    pub storage_reads: Vec<GasDiffEntry>,
+    /// IO costs of writing state, per storage slot.
+    pub storage_writes: Vec<GasDiffEntry>,
+    /// Storage fees charged for state writes, per storage slot.
+    pub storage_fees: Vec<GasDiffEntry>,
+    /// Storage fees refunded for state deletions, per storage slot.
+    pub storage_refunds: Vec<GasDiffEntry>,
+}
+
+/// The costs of a gas log that are compared in a [`GasProfileDiff`], without the call graph.
+///
+/// They can be saved as JSON, e.g. as a baseline to compare later runs of the same transactions
+/// or tests against. Execution & IO costs are in internal gas units, storage fees are in octas.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GasCosts {
+    pub gas_scaling_factor: u64,
+    pub execution_and_io: u64,
+    pub storage_fee: u64,
+    pub storage_refund: u64,
+
+    frames: Costs,
+    ops: Costs,
+    storage_reads: Costs,
+    storage_writes: Costs,
+    storage_fees: Costs,
+    storage_refunds: Costs,
+}
+
+impl GasDiffEntry {
+    fn new(name: impl Into<String>, base: u64, new: u64) -> Self {
+        Self {
+            name: name.into(),
+            base_count: 1,
+            base,
+            new_count: 1,
+            new,
+        }
+    }
+
+    /// Change in cost, positive if the cost went up.
+    pub fn delta(&self) -> i128 {
+        self.new as i128 - self.base as i128
+    }
+
+    /// Change in cost relative to the base cost, or `None` if there was no base cost.
+    pub fn delta_percentage(&self) -> Option<f64> {
+        if self.base == 0 {
+            None
+        } else {
+            Some(self.delta() as f64 / self.base as f64 * 100.0)
+        }
+    }
+
+    /// Whether the cost went up by more than the given percentage. A cost going up from zero
+    /// always counts as a regression.
+    pub fn is_regression(&self, max_increase_percentage: f64) -> bool {
+        match self.delta_percentage() {
+            Some(percentage) => percentage > max_increase_percentage,
+            None => self.new > 0,
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        json!({
+            "name": self.name,
+            "base_count": self.base_count,
+            "base": self.base,
+            "new_count": self.new_count,
+            "new": self.new,
+            "delta": self.delta() as i64,
+            "delta_percentage": self.delta_percentage(),
+        })
+    }
+}
+
+/// Costs of a set of items, keyed by name, along with the number of times they were charged.
+pub(crate) type Costs = BTreeMap<String, (usize, u64)>;
+
+pub(crate) fn insert_or_add(costs: &mut Costs, key: String, count: usize, amount: u64) {
+    match costs.entry(key) {
+        btree_map::Entry::Occupied(entry) => {
+            let r = entry.into_mut();
+            r.0 += count;
+            r.1 += amount;
+        },
+        btree_map::Entry::Vacant(entry) => {
+            entry.insert((count, amount));
+        },
+    }
+}
+
+pub(crate) fn from_aggregated<N>(events: Vec<(String, usize, N)>) -> Costs
+where
+    u64: From<N>,
+{
+    let mut costs = Costs::new();
+    for (name, count, cost) in events {
+        insert_or_add(&mut costs, name, count, u64::from(cost));
+    }
+    costs
+}
+
+/// Pairs up the items of both logs and sorts them by how much their cost changed.
+fn diff_costs(base: &Costs, new: &Costs) -> Vec<GasDiffEntry> {
+    let mut entries: BTreeMap<String, GasDiffEntry> = base
+        .iter()
+        .map(|(name, &(count, cost))| {
+            let entry = GasDiffEntry {
+                name: name.clone(),
+                base_count: count,
+                base: cost,
+                new_count: 0,
+                new: 0,
+            };
+            (name, entry)
+        })
+        .collect();
+    for (name, &(count, cost)) in new {
+        let entry = entries.entry(name.clone()).or_insert_with(|| GasDiffEntry {
+            name: name.clone(),
+            base_count: 0,
+            base: 0,
+            new_count: 0,
+            new: 0,
+        });
+        entry.new_count = count;
+        entry.new = cost;
+    }
+
+    let mut entries = entries.into_values().collect::<Vec<_>>();
+    // Sort in descending order of the absolute change.
+    entries.sort_by(|lhs, rhs| rhs.delta().abs().cmp(&lhs.delta().abs()));
+    entries
+}
+
+impl CallFrame {
+    /// Adds the cost of this frame and of all the frames it calls to `costs`, returning the
+    /// cost of this frame. The cost of recursive calls is only counted once, in the outermost
+    /// frame of the function.
+    fn aggregate_frame_costs(&self, stack: &mut Vec<String>, costs: &mut Costs) -> u64 {
+        use ExecutionGasEvent::*;
+
+        let name = format!("{}", self.name);
+        let is_recursive = stack.contains(&name);
+        stack.push(name);
+
+        let mut total = 0;
+        for event in &self.events {
+            match event {
+                Loc(..) => (),
+                Call(frame) => total += frame.aggregate_frame_costs(stack, costs),
+                Bytecode { cost, .. }
+                | CallNative { cost, .. }
+                | LoadResource { cost, .. }
+                | CreateTy { cost } => total += u64::from(*cost),
+            }
+        }
+
+        let name = stack.pop().expect("frame must be on the stack");
+        insert_or_add(costs, name, 1, if is_recursive { 0 } else { total });
+        total
+    }
+}
+
+impl TransactionGasLog {
+    pub(crate) fn frame_costs(&self) -> Costs {
+        let mut costs = Costs::new();
+        self.exec_io
+            .call_graph
+            .aggregate_frame_costs(&mut vec![], &mut costs);
+        costs
+    }
+
+    fn storage_write_costs(&self) -> Costs {
+        let mut costs = Costs::new();
+        for write in &self.exec_io.write_set_transient {
+            insert_or_add(
+                &mut costs,
+                format!("{}", Render(&write.key)),
+                1,
+                u64::from(write.cost),
+            );
+        }
+        costs
+    }
+
+    fn storage_fee_costs(&self) -> (Costs, Costs) {
+        let mut fees = Costs::new();
+        let mut refunds = Costs::new();
+        for write in &self.storage.write_set_storage {
+            let name = format!("{}", Render(&write.key));
+            if !write.cost.is_zero() {
+                insert_or_add(&mut fees, name.clone(), 1, u64::from(write.cost));
+            }
+            if !write.refund.is_zero() {
+                insert_or_add(&mut refunds, name, 1, u64::from(write.refund));
+            }
+        }
+        for event in &self.storage.events {
+            insert_or_add(
+                &mut fees,
+                format!("events<{}>", Render(&event.ty)),
+                1,
+                u64::from(event.cost),
+            );
+        }
+        if !self.storage.txn_storage.is_zero() {
+            insert_or_add(
+                &mut fees,
+                "transaction".to_string(),
+                1,
+                u64::from(self.storage.txn_storage),
+            );
+        }
+        (fees, refunds)
+    }
+
+    /// Extracts the costs compared in a diff from this gas log.
+    pub fn costs(&self) -> GasCosts {
+        let aggregated = self.exec_io.aggregate_gas_events();
+        let (storage_fees, storage_refunds) = self.storage_fee_costs();
+
+        GasCosts {
+            gas_scaling_factor: self.exec_io.gas_scaling_factor.into(),
+            execution_and_io: self.exec_io.total.into(),
+            storage_fee: self.storage.total.into(),
+            storage_refund: self.storage.total_refund.into(),
+
+            frames: self.frame_costs(),
+            ops: from_aggregated(aggregated.ops),
+            storage_reads: from_aggregated(aggregated.storage_reads),
+            storage_writes: self.storage_write_costs(),
+            storage_fees,
+            storage_refunds,
+        }
+    }
+
+    /// Compares this gas log against a newer one, e.g. of the same transaction after the
+    /// contracts it calls have been changed.
+    pub fn diff(&self, new: &TransactionGasLog) -> GasProfileDiff {
+        self.costs().diff(&new.costs())
+    }
+}
+
+impl GasCosts {
+    /// Compares these costs against newer ones, e.g. of the same test after the package it
+    /// belongs to has been changed.
+    pub fn diff(&self, new: &GasCosts) -> GasProfileDiff {
+        GasProfileDiff {
+            gas_scaling_factor: new.gas_scaling_factor.into(),
+
+            execution_and_io: GasDiffEntry::new(
+                "execution_and_io",
+                self.execution_and_io,
+                new.execution_and_io,
+            ),
+            storage_fee: GasDiffEntry::new("storage_fee", self.storage_fee, new.storage_fee),
+            storage_refund: GasDiffEntry::new(
+                "storage_refund",
+                self.storage_refund,
+                new.storage_refund,
+            ),
+
+            frames: diff_costs(&self.frames, &new.frames),
+            ops: diff_costs(&self.ops, &new.ops),
+            storage_reads: diff_costs(&self.storage_reads, &new.storage_reads),
+            storage_writes: diff_costs(&self.storage_writes, &new.storage_writes),
+            storage_fees: diff_costs(&self.storage_fees, &new.storage_fees),
+            storage_refunds: diff_costs(&self.storage_refunds, &new.storage_refunds),
+        }
+    }
+}
+
+impl GasProfileDiff {
+    /// Returns the totals (execution & IO costs and storage fee) that went up by more than the
+    /// given percentage, so that CI can fail on gas regressions.
+    pub fn regressions(&self, max_increase_percentage: f64) -> Vec<&GasDiffEntry> {
+        [&self.execution_and_io, &self.storage_fee]
+            .into_iter()
+            .filter(|entry| entry.is_regression(max_increase_percentage))
+            .collect()
+    }
+
+    /// Renders the diff as JSON, with all the costs in internal gas units or octas.
+    pub fn to_json(&self) -> Value {
+        let entries =
+            |entries: &[GasDiffEntry]| Value::Array(entries.iter().map(|e| e.to_json()).collect());
+
+        json!({
+            "gas_scaling_factor": u64::from(self.gas_scaling_factor),
+            "execution_and_io": self.execution_and_io.to_json(),
+            "storage_fee": self.storage_fee.to_json(),
+            "storage_refund": self.storage_refund.to_json(),
+            "frames": entries(&self.frames),
+            "ops": entries(&self.ops),
+            "storage_reads": entries(&self.storage_reads),
+            "storage_writes": entries(&self.storage_writes),
+            "storage_fees": entries(&self.storage_fees),
+            "storage_refunds": entries(&self.storage_refunds),
+        })
+    }
+
+    /// Renders the diff as human readable tables, with the costs in gas units or APT. Entries
+    /// whose cost did not change are omitted.
+    pub fn to_text_report(&self) -> Result<String, fmt::Error> {
+        let scaling_factor = u64::from(self.gas_scaling_factor) as f64;
+        let fmt_gas: &dyn Fn(f64) -> String = &|cost| {
+            let scaled = format!("{:.8}", cost / scaling_factor);
+            crate::misc::strip_trailing_zeros_and_decimal_point(&scaled).to_string()
+        };
+        let fmt_apt: &dyn Fn(f64) -> String = &|fee| {
+            let scaled = format!("{:.8}", fee / 1_0000_0000f64);
+            crate::misc::strip_trailing_zeros_and_decimal_point(&scaled).to_string()
+        };
+
+        let mut output = String::new();
+        let mut render_section =
+            |title: &str, entries: &[&GasDiffEntry], fmt: &dyn Fn(f64) -> String| {
+                let mut table = vec![["name", "base", "new", "delta", "delta %"].map(String::from)];
+                for entry in entries.iter().filter(|entry| entry.delta() != 0) {
+                    let delta = fmt(entry.delta().unsigned_abs() as f64);
+                    table.push([
+                        entry.name.clone(),
+                        fmt(entry.base as f64),
+                        fmt(entry.new as f64),
+                        if entry.delta() > 0 {
+                            format!("+{}", delta)
+                        } else {
+                            format!("-{}", delta)
+                        },
+                        match entry.delta_percentage() {
+                            Some(percentage) => format!("{:+.2}%", percentage),
+                            None => "/".to_string(),
+                        },
+                    ]);
+                }
+                writeln!(output, "{}", title)?;
+                if table.len() > 1 {
+                    render_table(&mut output, &table, 4)?;
+                } else {
+                    writeln!(output, "(no change)")?;
+                }
+                writeln!(output)
+            };
+
+        render_section("Totals (gas units)", &[&self.execution_and_io], fmt_gas)?;
+        render_section(
+            "Totals (APT)",
+            &[&self.storage_fee, &self.storage_refund],
+            fmt_apt,
+        )?;
+        for (title, entries, fmt) in [
+            ("Function frames (gas units)", &self.frames, fmt_gas),
+            ("Instructions & natives (gas units)", &self.ops, fmt_gas),
+            ("Storage reads (gas units)", &self.storage_reads, fmt_gas),
+            ("Storage writes (gas units)", &self.storage_writes, fmt_gas),
+            ("Storage fees (APT)", &self.storage_fees, fmt_apt),
+            ("Storage refunds (APT)", &self.storage_refunds, fmt_apt),
+        ] {
+            render_section(title, &entries.iter().collect::<Vec<_>>(), fmt)?;
+        }
+
+        Ok(output)
+    }
+}
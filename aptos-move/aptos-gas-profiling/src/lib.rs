@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod aggregate;
+mod diff;
 mod erased;
 mod flamegraph;
 mod log;
@@ -9,6 +10,9 @@ mod misc;
 mod profiler;
 mod render;
 mod report;
+mod summary;
 
+pub use diff::{GasCosts, GasDiffEntry, GasProfileDiff};
 pub use log::{FrameName, TransactionGasLog};
 pub use profiler::GasProfiler;
+pub use summary::GasProfileSummary;
@@ -32,7 +32,11 @@ fn indent(output: &mut impl Write, count: usize) -> fmt::Result {
     write!(output, "{}", " ".repeat(count))
 }
 
-fn render_table<R, S>(output: &mut impl Write, table: &[R], spacing: usize) -> fmt::Result
+pub(crate) fn render_table<R, S>(
+    output: &mut impl Write,
+    table: &[R],
+    spacing: usize,
+) -> fmt::Result
 where
     R: AsRef<[S]>,
     S: AsRef<str>,
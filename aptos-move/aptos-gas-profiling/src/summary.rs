@@ -0,0 +1,132 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    aggregate::into_sorted_vec,
+    diff::{from_aggregated, insert_or_add, Costs},
+    log::TransactionGasLog,
+    report::render_table,
+};
+use aptos_gas_algebra::GasScalingFactor;
+use serde_json::{json, Value};
+use std::fmt::{self, Write};
+
+/// Execution costs aggregated over a set of gas logs, e.g. of all the unit tests of a package,
+/// to find the functions that are the most expensive across all of them.
+///
+/// All the costs are in internal gas units, and the entries are sorted from high to low.
+#[derive(Debug, Clone)]
+pub struct GasProfileSummary {
+    /// The gas scaling factor of the logs, to convert internal gas units to gas units.
+    pub gas_scaling_factor: GasScalingFactor,
+    pub num_logs: usize,
+    /// Total execution & IO costs of all the logs.
+    pub execution_and_io: u64,
+
+    /// Execution costs of function frames, including the costs of the functions they call,
+    /// along with the number of calls.
+    pub frames: Vec<(String, usize, u64)>,
+    /// Execution costs of instructions and native functions, along with the number of hits.
+    pub ops: Vec<(String, usize, u64)>,
+}
+
+impl GasProfileSummary {
+    pub fn new<'a>(logs: impl IntoIterator<Item = &'a TransactionGasLog>) -> Self {
+        let mut gas_scaling_factor = 1.into();
+        let mut num_logs = 0;
+        let mut execution_and_io = 0;
+        let mut frames = Costs::new();
+        let mut ops = Costs::new();
+
+        for log in logs {
+            gas_scaling_factor = log.exec_io.gas_scaling_factor;
+            num_logs += 1;
+            execution_and_io += u64::from(log.exec_io.total);
+
+            for (name, (count, cost)) in log.frame_costs() {
+                insert_or_add(&mut frames, name, count, cost);
+            }
+            for (name, (count, cost)) in from_aggregated(log.exec_io.aggregate_gas_events().ops) {
+                insert_or_add(&mut ops, name, count, cost);
+            }
+        }
+
+        Self {
+            gas_scaling_factor,
+            num_logs,
+            execution_and_io,
+            frames: into_sorted_vec(frames),
+            ops: into_sorted_vec(ops),
+        }
+    }
+
+    /// Renders the summary as JSON, with all the costs in internal gas units.
+    pub fn to_json(&self) -> Value {
+        let entries = |entries: &[(String, usize, u64)]| {
+            Value::Array(
+                entries
+                    .iter()
+                    .map(
+                        |(name, count, cost)| json!({ "name": name, "count": count, "cost": cost }),
+                    )
+                    .collect(),
+            )
+        };
+
+        json!({
+            "gas_scaling_factor": u64::from(self.gas_scaling_factor),
+            "num_logs": self.num_logs,
+            "execution_and_io": self.execution_and_io,
+            "frames": entries(&self.frames),
+            "ops": entries(&self.ops),
+        })
+    }
+
+    /// Renders the summary as human readable tables, with the costs in gas units, listing at
+    /// most `max_entries` of the most expensive items per table.
+    pub fn to_text_report(&self, max_entries: usize) -> Result<String, fmt::Error> {
+        let scaling_factor = u64::from(self.gas_scaling_factor) as f64;
+        let total = self.execution_and_io as f64;
+        let fmt_gas = |cost: f64| {
+            let scaled = format!("{:.8}", cost / scaling_factor);
+            crate::misc::strip_trailing_zeros_and_decimal_point(&scaled).to_string()
+        };
+
+        let mut output = String::new();
+        writeln!(
+            output,
+            "Total execution & IO: {} gas units ({} runs)",
+            fmt_gas(total),
+            self.num_logs
+        )?;
+        writeln!(output)?;
+
+        for (title, entries) in [
+            ("Function frames (gas units)", &self.frames),
+            ("Instructions & natives (gas units)", &self.ops),
+        ] {
+            let mut table = vec![["name", "count", "cost", "percentage"].map(String::from)];
+            for (name, count, cost) in entries.iter().take(max_entries) {
+                table.push([
+                    name.clone(),
+                    format!("{}", count),
+                    fmt_gas(*cost as f64),
+                    if total == 0.0 {
+                        "/".to_string()
+                    } else {
+                        format!("{:.2}%", *cost as f64 / total * 100.0)
+                    },
+                ]);
+            }
+            writeln!(output, "{}", title)?;
+            if table.len() > 1 {
+                render_table(&mut output, &table, 4)?;
+            } else {
+                writeln!(output, "(empty)")?;
+            }
+            writeln!(output)?;
+        }
+
+        Ok(output)
+    }
+}
@@ -8,6 +8,9 @@
 ///   - Changing how gas is calculated in any way
 ///
 /// Change log:
+/// - V17
+///   - Added secp256r1 ECDSA verification and Poseidon-BN254 hashing natives
+///   - Added Groth16 proof verification natives for BN254 and BLS12-381
 /// - V16
 ///   - IO Gas for the transaction itself and events in the transaction output
 /// - V15
@@ -55,4 +58,4 @@
 ///       global operations.
 /// - V1
 ///   - TBA
-pub const LATEST_GAS_FEATURE_VERSION: u64 = 16;
+pub const LATEST_GAS_FEATURE_VERSION: u64 = 17;
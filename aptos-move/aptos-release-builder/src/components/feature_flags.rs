@@ -109,6 +109,10 @@ pub enum FeatureFlag {
     DelegationPoolAllowlisting,
     ModuleEventMigration,
     RejectUnstableBytecode,
+    Secp256r1EcdsaSignature,
+    Secp256r1Natives,
+    PoseidonBn254Natives,
+    OrderlessTransactions,
 }
 
 fn generate_features_blob(writer: &CodeWriter, data: &[u64]) {
@@ -274,6 +278,10 @@ impl From<FeatureFlag> for AptosFeatureFlag {
             },
             FeatureFlag::ModuleEventMigration => AptosFeatureFlag::MODULE_EVENT_MIGRATION,
             FeatureFlag::RejectUnstableBytecode => AptosFeatureFlag::REJECT_UNSTABLE_BYTECODE,
+            FeatureFlag::Secp256r1EcdsaSignature => AptosFeatureFlag::SECP256R1_ECDSA_SIGNATURE,
+            FeatureFlag::Secp256r1Natives => AptosFeatureFlag::SECP256R1_NATIVES,
+            FeatureFlag::PoseidonBn254Natives => AptosFeatureFlag::POSEIDON_BN254_NATIVES,
+            FeatureFlag::OrderlessTransactions => AptosFeatureFlag::ORDERLESS_TRANSACTIONS,
         }
     }
 }
@@ -368,6 +376,10 @@ impl From<AptosFeatureFlag> for FeatureFlag {
             },
             AptosFeatureFlag::MODULE_EVENT_MIGRATION => FeatureFlag::ModuleEventMigration,
             AptosFeatureFlag::REJECT_UNSTABLE_BYTECODE => FeatureFlag::RejectUnstableBytecode,
+            AptosFeatureFlag::SECP256R1_ECDSA_SIGNATURE => FeatureFlag::Secp256r1EcdsaSignature,
+            AptosFeatureFlag::SECP256R1_NATIVES => FeatureFlag::Secp256r1Natives,
+            AptosFeatureFlag::POSEIDON_BN254_NATIVES => FeatureFlag::PoseidonBn254Natives,
+            AptosFeatureFlag::ORDERLESS_TRANSACTIONS => FeatureFlag::OrderlessTransactions,
         }
     }
 }
@@ -16,8 +16,8 @@ use aptos_types::{
     account_address::AccountAddress,
     state_store::{state_key::StateKey, state_value::StateValue},
     transaction::{
-        EntryFunction, ExecutionStatus::MiscellaneousError, Transaction, TransactionInfo,
-        TransactionPayload, Version,
+        EntryFunction, ExecutionStatus::MiscellaneousError, OrderlessPayload, Transaction,
+        TransactionExecutable, TransactionInfo, TransactionPayload, Version,
     },
 };
 use async_recursion::async_recursion;
@@ -288,7 +288,11 @@ impl AptosValidatorInterface for RestDebuggerInterface {
                         multi_sig.transaction_payload.clone().unwrap();
                     Some(e.clone())
                 },
-                TransactionPayload::EntryFunction(e) => Some(e.clone()),
+                TransactionPayload::EntryFunction(e)
+                | TransactionPayload::Orderless(OrderlessPayload {
+                    executable: TransactionExecutable::EntryFunction(e),
+                    ..
+                }) => Some(e.clone()),
                 _ => None,
             }
         };
@@ -31,7 +31,7 @@ use aptos_framework::{
     natives::{code::PublishRequest, randomness::RandomnessContext},
     RuntimeModuleMetadataV1,
 };
-use aptos_gas_algebra::{Gas, GasQuantity, NumBytes, Octa};
+use aptos_gas_algebra::{DynamicExpression, Gas, GasQuantity, NumBytes, Octa};
 use aptos_gas_meter::{AptosGasMeter, GasAlgebra, StandardGasAlgebra, StandardGasMeter};
 use aptos_gas_schedule::{AptosGasParameters, TransactionGasParameters, VMGasParameters};
 use aptos_logger::{enabled, prelude::*, Level};
@@ -61,9 +61,10 @@ use aptos_types::{
     transaction::{
         authenticator::AnySignature, signature_verified_transaction::SignatureVerifiedTransaction,
         BlockOutput, EntryFunction, ExecutionError, ExecutionStatus, ModuleBundle, Multisig,
-        MultisigTransactionPayload, Script, SignatureCheckedTransaction, SignedTransaction,
-        Transaction, TransactionAuxiliaryData, TransactionOutput, TransactionPayload,
-        TransactionStatus, VMValidatorResult, ViewFunctionOutput, WriteSetPayload,
+        MultisigTransactionPayload, OrderlessPayload, Script, SignatureCheckedTransaction,
+        SignedTransaction, Transaction, TransactionAuxiliaryData, TransactionExecutable,
+        TransactionOutput, TransactionPayload, TransactionStatus, VMValidatorResult,
+        ViewFunctionOutput, WriteSetPayload, ORDERLESS_TRANSACTION_SEQUENCE_NUMBER,
     },
     vm_status::{AbortLocation, StatusCode, VMStatus},
 };
@@ -208,6 +209,18 @@ impl AptosVM {
     pub fn new(
         resolver: &impl AptosMoveResolver,
         override_is_delayed_field_optimization_capable: Option<bool>,
+    ) -> Self {
+        Self::new_impl(
+            resolver,
+            override_is_delayed_field_optimization_capable,
+            /*use_warm_vm_cache=*/ true,
+        )
+    }
+
+    fn new_impl(
+        resolver: &impl AptosMoveResolver,
+        override_is_delayed_field_optimization_capable: Option<bool>,
+        use_warm_vm_cache: bool,
     ) -> Self {
         let _timer = TIMER.timer_with(&["AptosVM::new"]);
 
@@ -247,15 +260,17 @@ impl AptosVM {
             .unwrap_or_else(OnChainRandomnessConfig::default_if_missing);
         let randomness_enabled =
             consensus_config.is_vtxn_enabled() && randomness_config.randomness_enabled();
-        let move_vm = MoveVmExt::new(
+        let move_vm = MoveVmExt::new_impl::<fn(DynamicExpression)>(
             native_gas_params,
             misc_gas_params,
             gas_feature_version,
             chain_id.id(),
             features,
             timed_features.clone(),
+            None,
             resolver,
             aggregator_v2_type_tagging,
+            use_warm_vm_cache,
         )
         .expect("should be able to create Move VM; check if there are duplicated natives");
 
@@ -819,7 +834,11 @@ impl AptosVM {
         gas_meter.charge_intrinsic_gas_for_transaction(txn_data.transaction_size())?;
 
         match payload {
-            TransactionPayload::Script(script) => {
+            TransactionPayload::Script(script)
+            | TransactionPayload::Orderless(OrderlessPayload {
+                executable: TransactionExecutable::Script(script),
+                ..
+            }) => {
                 session.execute(|session| {
                     self.validate_and_execute_script(
                         session,
@@ -830,7 +849,11 @@ impl AptosVM {
                     )
                 })?;
             },
-            TransactionPayload::EntryFunction(entry_fn) => {
+            TransactionPayload::EntryFunction(entry_fn)
+            | TransactionPayload::Orderless(OrderlessPayload {
+                executable: TransactionExecutable::EntryFunction(entry_fn),
+                ..
+            }) => {
                 session.execute(|session| {
                     self.validate_and_execute_entry_function(
                         session,
@@ -843,8 +866,8 @@ impl AptosVM {
                 })?;
             },
 
-            // Not reachable as this function should only be invoked for entry or script
-            // transaction payload.
+            // Not reachable as this function should only be invoked for entry, script or
+            // orderless transaction payload.
             _ => unreachable!("Only scripts or entry functions are executed"),
         };
 
@@ -1560,6 +1583,41 @@ impl AptosVM {
             ));
         }
 
+        // Raw secp256r1 signatures are checked here rather than only in the mempool, so that
+        // they cannot be executed before the feature is enabled.
+        if !self
+            .features()
+            .is_enabled(FeatureFlag::SECP256R1_ECDSA_SIGNATURE)
+        {
+            let sk_authenticators = transaction
+                .authenticator_ref()
+                .to_single_key_authenticators()
+                .map_err(|_| VMStatus::error(StatusCode::INVALID_SIGNATURE, None))?;
+            if sk_authenticators.iter().any(|authenticator| {
+                matches!(
+                    authenticator.signature(),
+                    AnySignature::Secp256r1Ecdsa { .. }
+                )
+            }) {
+                return Err(VMStatus::error(StatusCode::FEATURE_UNDER_GATING, None));
+            }
+        }
+
+        // Orderless transactions call prologues and an epilogue that only exist in frameworks
+        // shipping the feature, so they must not run before it is enabled. Their sequence number
+        // is not checked by the prologue, so the fixed value is enforced here instead.
+        if transaction.replay_protector().is_nonce() {
+            if !self.features().is_orderless_transactions_enabled() {
+                return Err(VMStatus::error(StatusCode::FEATURE_UNDER_GATING, None));
+            }
+            if transaction.sequence_number() != ORDERLESS_TRANSACTION_SEQUENCE_NUMBER {
+                return Err(VMStatus::error(
+                    StatusCode::INVALID_ORDERLESS_SEQUENCE_NUMBER,
+                    None,
+                ));
+            }
+        }
+
         let authenticators = aptos_types::keyless::get_authenticators(transaction)
             .map_err(|_| VMStatus::error(StatusCode::INVALID_SIGNATURE, None))?;
 
@@ -1675,18 +1733,18 @@ impl AptosVM {
         let mut new_published_modules_loaded = false;
         let result = match txn.payload() {
             payload @ TransactionPayload::Script(_)
-            | payload @ TransactionPayload::EntryFunction(_) => self
-                .execute_script_or_entry_function(
-                    resolver,
-                    user_session,
-                    gas_meter,
-                    traversal_context,
-                    &txn_data,
-                    payload,
-                    log_context,
-                    &mut new_published_modules_loaded,
-                    change_set_configs,
-                ),
+            | payload @ TransactionPayload::EntryFunction(_)
+            | payload @ TransactionPayload::Orderless(_) => self.execute_script_or_entry_function(
+                resolver,
+                user_session,
+                gas_meter,
+                traversal_context,
+                &txn_data,
+                payload,
+                log_context,
+                &mut new_published_modules_loaded,
+                change_set_configs,
+            ),
             TransactionPayload::Multisig(payload) => self.execute_or_simulate_multisig_transaction(
                 resolver,
                 user_session,
@@ -2060,32 +2118,63 @@ impl AptosVM {
         arguments: Vec<Vec<u8>>,
         max_gas_amount: u64,
     ) -> ViewFunctionOutput {
+        Self::execute_view_functions(
+            state_view,
+            [(module_id, func_name, type_args, arguments)],
+            max_gas_amount,
+        )
+        .pop()
+        .expect("One output is returned per view function")
+    }
+
+    /// Executes a batch of view functions against the same state, with a single VM so that
+    /// modules are only loaded once. Every function gets its own session and gas budget, and
+    /// an output is returned for each of them, in order.
+    pub fn execute_view_functions<I>(
+        state_view: &impl StateView,
+        view_functions: I,
+        max_gas_amount: u64,
+    ) -> Vec<ViewFunctionOutput>
+    where
+        I: IntoIterator<Item = (ModuleId, Identifier, Vec<TypeTag>, Vec<Vec<u8>>)>,
+    {
         let resolver = state_view.as_move_resolver();
         let vm = AptosVM::new(
             &resolver,
             /*override_is_delayed_field_optimization_capable=*/ Some(false),
         );
         let log_context = AdapterLogSchema::new(state_view.id(), 0);
-        let mut gas_meter = match vm.make_standard_gas_meter(max_gas_amount.into(), &log_context) {
-            Ok(gas_meter) => gas_meter,
-            Err(e) => return ViewFunctionOutput::new(Err(anyhow::Error::msg(format!("{}", e))), 0),
-        };
-
-        let mut session = vm.new_session(&resolver, SessionId::Void);
-        let execution_result = Self::execute_view_function_in_vm(
-            &mut session,
-            &vm,
-            module_id,
-            func_name,
-            type_args,
-            arguments,
-            &mut gas_meter,
-        );
-        let gas_used = Self::gas_used(max_gas_amount.into(), &gas_meter);
-        match execution_result {
-            Ok(result) => ViewFunctionOutput::new(Ok(result), gas_used),
-            Err(e) => ViewFunctionOutput::new(Err(e), gas_used),
-        }
+        view_functions
+            .into_iter()
+            .map(|(module_id, func_name, type_args, arguments)| {
+                let mut gas_meter =
+                    match vm.make_standard_gas_meter(max_gas_amount.into(), &log_context) {
+                        Ok(gas_meter) => gas_meter,
+                        Err(e) => {
+                            return ViewFunctionOutput::new(
+                                Err(anyhow::Error::msg(format!("{}", e))),
+                                0,
+                            )
+                        },
+                    };
+
+                let mut session = vm.new_session(&resolver, SessionId::Void);
+                let execution_result = Self::execute_view_function_in_vm(
+                    &mut session,
+                    &vm,
+                    module_id,
+                    func_name,
+                    type_args,
+                    arguments,
+                    &mut gas_meter,
+                );
+                let gas_used = Self::gas_used(max_gas_amount.into(), &gas_meter);
+                match execution_result {
+                    Ok(result) => ViewFunctionOutput::new(Ok(result), gas_used),
+                    Err(e) => ViewFunctionOutput::new(Err(e), gas_used),
+                }
+            })
+            .collect()
     }
 
     fn gas_used(max_gas_amount: Gas, gas_meter: &impl AptosGasMeter) -> u64 {
@@ -2148,7 +2237,9 @@ impl AptosVM {
         )?;
 
         match payload {
-            TransactionPayload::Script(_) | TransactionPayload::EntryFunction(_) => {
+            TransactionPayload::Script(_)
+            | TransactionPayload::EntryFunction(_)
+            | TransactionPayload::Orderless(_) => {
                 transaction_validation::run_script_prologue(session, txn_data, log_context)
             },
             TransactionPayload::Multisig(multisig_payload) => {
@@ -2321,7 +2412,11 @@ impl AptosVM {
         payload: &TransactionPayload,
     ) -> Option<u64> {
         match payload {
-            TransactionPayload::EntryFunction(entry_func) => {
+            TransactionPayload::EntryFunction(entry_func)
+            | TransactionPayload::Orderless(OrderlessPayload {
+                executable: TransactionExecutable::EntryFunction(entry_func),
+                ..
+            }) => {
                 if self.randomness_enabled
                     && has_randomness_attribute(resolver, session, entry_func).unwrap_or(false)
                 {
@@ -2343,7 +2438,8 @@ impl AptosVM {
             },
             TransactionPayload::Script(_)
             | TransactionPayload::ModuleBundle(_)
-            | TransactionPayload::Multisig(_) => None,
+            | TransactionPayload::Multisig(_)
+            | TransactionPayload::Orderless(_) => None,
         }
     }
 }
@@ -2445,14 +2541,51 @@ impl VMValidator for AptosVM {
         state_view: &impl StateView,
     ) -> VMValidatorResult {
         let _timer = TXN_VALIDATION_SECONDS.start_timer();
-        let log_context = AdapterLogSchema::new(state_view.id(), 0);
+        if let Err(status_code) = self.check_gated_authenticator(&transaction) {
+            return VMValidatorResult::error(status_code);
+        }
+        let txn = match transaction.check_signature() {
+            Ok(t) => t,
+            _ => {
+                return VMValidatorResult::error(StatusCode::INVALID_SIGNATURE);
+            },
+        };
+        self.validate_signature_checked_transaction(&txn, state_view)
+    }
+}
 
+impl AptosVM {
+    /// Determine if a transaction is valid, like `VMValidator::validate_transaction`, for a
+    /// transaction whose signature was already checked (for example, in a batch with other
+    /// transactions by `check_signatures`). The transaction is an error if its signature is
+    /// invalid.
+    pub fn validate_transaction_with_checked_signature(
+        &self,
+        transaction: Result<SignatureCheckedTransaction, SignedTransaction>,
+        state_view: &impl StateView,
+    ) -> VMValidatorResult {
+        let _timer = TXN_VALIDATION_SECONDS.start_timer();
+        let signed_transaction = match &transaction {
+            Ok(txn) => &**txn,
+            Err(txn) => txn,
+        };
+        if let Err(status_code) = self.check_gated_authenticator(signed_transaction) {
+            return VMValidatorResult::error(status_code);
+        }
+        match transaction {
+            Ok(txn) => self.validate_signature_checked_transaction(&txn, state_view),
+            Err(_) => VMValidatorResult::error(StatusCode::INVALID_SIGNATURE),
+        }
+    }
+
+    /// Rejects transactions with authenticators whose feature is not enabled.
+    fn check_gated_authenticator(&self, transaction: &SignedTransaction) -> Result<(), StatusCode> {
         if !self
             .features()
             .is_enabled(FeatureFlag::SINGLE_SENDER_AUTHENTICATOR)
         {
             if let aptos_types::transaction::authenticator::TransactionAuthenticator::SingleSender{ .. } = transaction.authenticator_ref() {
-                return VMValidatorResult::error(StatusCode::FEATURE_UNDER_GATING);
+                return Err(StatusCode::FEATURE_UNDER_GATING);
             }
         }
 
@@ -2463,21 +2596,23 @@ impl VMValidator for AptosVM {
             {
                 for authenticator in sk_authenticators {
                     if let AnySignature::WebAuthn { .. } = authenticator.signature() {
-                        return VMValidatorResult::error(StatusCode::FEATURE_UNDER_GATING);
+                        return Err(StatusCode::FEATURE_UNDER_GATING);
                     }
                 }
             } else {
-                return VMValidatorResult::error(StatusCode::INVALID_SIGNATURE);
+                return Err(StatusCode::INVALID_SIGNATURE);
             }
         }
+        Ok(())
+    }
 
-        let txn = match transaction.check_signature() {
-            Ok(t) => t,
-            _ => {
-                return VMValidatorResult::error(StatusCode::INVALID_SIGNATURE);
-            },
-        };
-        let mut txn_data = TransactionMetadata::new(&txn);
+    fn validate_signature_checked_transaction(
+        &self,
+        txn: &SignatureCheckedTransaction,
+        state_view: &impl StateView,
+    ) -> VMValidatorResult {
+        let log_context = AdapterLogSchema::new(state_view.id(), 0);
+        let mut txn_data = TransactionMetadata::new(txn);
 
         let resolver = self.as_move_resolver(&state_view);
         let mut session = self.new_session(&resolver, SessionId::prologue_meta(&txn_data));
@@ -2499,7 +2634,7 @@ impl VMValidator for AptosVM {
         let (counter_label, result) = match self.validate_signed_transaction(
             &mut session,
             &resolver,
-            &txn,
+            txn,
             &txn_data,
             &log_context,
         ) {
@@ -2557,6 +2692,61 @@ impl AptosSimulationVM {
             .expect("Materializing aggregator V1 deltas should never fail");
         (vm_status, txn_output)
     }
+
+    /// Like [`Self::create_vm_and_simulate_signed_transaction`], but meters gas with a custom gas
+    /// meter, which is returned alongside the output.
+    ///
+    /// If the state view contains code that was never published (e.g. overridden modules), or
+    /// differs from the chain in what the warm VM cache is keyed by, `use_warm_vm_cache` must be
+    /// unset: the transaction then runs on a cold Move VM of its own, which is much slower to
+    /// create, rather than on one shared with regular execution.
+    /// *Precondition:* the transaction must **not** have a valid signature.
+    pub fn create_vm_and_simulate_signed_transaction_with_gas_meter<G, F>(
+        transaction: &SignedTransaction,
+        state_view: &impl StateView,
+        use_warm_vm_cache: bool,
+        make_gas_meter: F,
+    ) -> Result<(VMStatus, TransactionOutput, G), VMStatus>
+    where
+        G: AptosGasMeter,
+        F: FnOnce(u64, VMGasParameters, StorageGasParameters, Gas) -> Result<G, VMStatus>,
+    {
+        assert_err!(
+            transaction.verify_signature(),
+            "Simulated transaction should not have a valid signature"
+        );
+
+        let resolver = state_view.as_move_resolver();
+        let mut vm = AptosVM::new_impl(
+            &resolver,
+            /*override_is_delayed_field_optimization_capable=*/ Some(false),
+            use_warm_vm_cache,
+        );
+        vm.is_simulation = true;
+        let log_context = AdapterLogSchema::new(state_view.id(), 0);
+
+        let mut gas_meter = make_gas_meter(
+            vm.gas_feature_version,
+            get_or_vm_startup_failure(&vm.gas_params, &log_context)?
+                .vm
+                .clone(),
+            get_or_vm_startup_failure(&vm.storage_gas_params, &log_context)?.clone(),
+            transaction.max_gas_amount().into(),
+        )?;
+        let traversal_storage = TraversalStorage::new();
+        let mut traversal_context = TraversalContext::new(&traversal_storage);
+        let (vm_status, vm_output) = vm.execute_user_transaction_impl(
+            &resolver,
+            transaction,
+            &log_context,
+            &mut gas_meter,
+            &mut traversal_context,
+        );
+        let txn_output = vm_output
+            .try_materialize_into_transaction_output(&resolver)
+            .expect("Materializing aggregator V1 deltas should never fail");
+        Ok((vm_status, txn_output, gas_meter))
+    }
 }
 
 fn create_account_if_does_not_exist(
@@ -6,7 +6,7 @@ use aptos_crypto::{hash::CryptoHash, HashValue};
 use aptos_crypto_derive::{BCSCryptoHash, CryptoHasher};
 use aptos_types::{
     block_metadata::BlockMetadata, block_metadata_ext::BlockMetadataExt,
-    validator_txn::ValidatorTransaction,
+    transaction::ReplayProtector, validator_txn::ValidatorTransaction,
 };
 use move_core_types::account_address::AccountAddress;
 use serde::{Deserialize, Serialize};
@@ -50,14 +50,48 @@ pub enum SessionId {
     ValidatorTxn {
         script_hash: Vec<u8>,
     },
+    // Orderless transactions all carry the same placeholder sequence number, so their sessions
+    // are identified by the nonce and expiration time instead.
+    OrderlessTxn {
+        sender: AccountAddress,
+        nonce: u64,
+        expiration_time: u64,
+        script_hash: Vec<u8>,
+    },
+    OrderlessPrologue {
+        sender: AccountAddress,
+        nonce: u64,
+        expiration_time: u64,
+        script_hash: Vec<u8>,
+    },
+    OrderlessEpilogue {
+        sender: AccountAddress,
+        nonce: u64,
+        expiration_time: u64,
+        script_hash: Vec<u8>,
+    },
+    OrderlessRunOnAbort {
+        sender: AccountAddress,
+        nonce: u64,
+        expiration_time: u64,
+        script_hash: Vec<u8>,
+    },
 }
 
 impl SessionId {
     pub fn txn_meta(txn_metadata: &TransactionMetadata) -> Self {
-        Self::Txn {
-            sender: txn_metadata.sender,
-            sequence_number: txn_metadata.sequence_number,
-            script_hash: txn_metadata.script_hash.clone(),
+        match txn_metadata.replay_protector() {
+            ReplayProtector::SequenceNumber(sequence_number) => Self::Txn {
+                sender: txn_metadata.sender,
+                sequence_number,
+                script_hash: txn_metadata.script_hash.clone(),
+            },
+            ReplayProtector::Nonce(nonce) => Self::OrderlessTxn {
+                sender: txn_metadata.sender,
+                nonce,
+                expiration_time: txn_metadata.expiration_timestamp_secs,
+                script_hash: txn_metadata.script_hash.clone(),
+            },
         }
     }
 
@@ -78,26 +112,50 @@ impl SessionId {
     }
 
     pub fn prologue_meta(txn_metadata: &TransactionMetadata) -> Self {
-        Self::Prologue {
-            sender: txn_metadata.sender,
-            sequence_number: txn_metadata.sequence_number,
-            script_hash: txn_metadata.script_hash.clone(),
+        match txn_metadata.replay_protector() {
+            ReplayProtector::SequenceNumber(sequence_number) => Self::Prologue {
+                sender: txn_metadata.sender,
+                sequence_number,
+                script_hash: txn_metadata.script_hash.clone(),
+            },
+            ReplayProtector::Nonce(nonce) => Self::OrderlessPrologue {
+                sender: txn_metadata.sender,
+                nonce,
+                expiration_time: txn_metadata.expiration_timestamp_secs,
+                script_hash: txn_metadata.script_hash.clone(),
+            },
         }
     }
 
     pub fn run_on_abort(txn_metadata: &TransactionMetadata) -> Self {
-        Self::RunOnAbort {
-            sender: txn_metadata.sender,
-            sequence_number: txn_metadata.sequence_number,
-            script_hash: txn_metadata.script_hash.clone(),
+        match txn_metadata.replay_protector() {
+            ReplayProtector::SequenceNumber(sequence_number) => Self::RunOnAbort {
+                sender: txn_metadata.sender,
+                sequence_number,
+                script_hash: txn_metadata.script_hash.clone(),
+            },
+            ReplayProtector::Nonce(nonce) => Self::OrderlessRunOnAbort {
+                sender: txn_metadata.sender,
+                nonce,
+                expiration_time: txn_metadata.expiration_timestamp_secs,
+                script_hash: txn_metadata.script_hash.clone(),
+            },
         }
     }
 
     pub fn epilogue_meta(txn_metadata: &TransactionMetadata) -> Self {
-        Self::Epilogue {
-            sender: txn_metadata.sender,
-            sequence_number: txn_metadata.sequence_number,
-            script_hash: txn_metadata.script_hash.clone(),
+        match txn_metadata.replay_protector() {
+            ReplayProtector::SequenceNumber(sequence_number) => Self::Epilogue {
+                sender: txn_metadata.sender,
+                sequence_number,
+                script_hash: txn_metadata.script_hash.clone(),
+            },
+            ReplayProtector::Nonce(nonce) => Self::OrderlessEpilogue {
+                sender: txn_metadata.sender,
+                nonce,
+                expiration_time: txn_metadata.expiration_timestamp_secs,
+                script_hash: txn_metadata.script_hash.clone(),
+            },
         }
     }
 
@@ -137,7 +195,11 @@ impl SessionId {
                 sequence_number: _,
                 script_hash,
             }
-            | Self::ValidatorTxn { script_hash } => script_hash,
+            | Self::ValidatorTxn { script_hash }
+            | Self::OrderlessTxn { script_hash, .. }
+            | Self::OrderlessPrologue { script_hash, .. }
+            | Self::OrderlessEpilogue { script_hash, .. }
+            | Self::OrderlessRunOnAbort { script_hash, .. } => script_hash,
             Self::BlockMeta { id: _ }
             | Self::Genesis { id: _ }
             | Self::Void
@@ -1,7 +1,10 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::move_vm_ext::{warm_vm_cache::WarmVmCache, AptosMoveResolver, SessionExt, SessionId};
+use crate::{
+    move_vm_ext::{warm_vm_cache::WarmVmCache, AptosMoveResolver, SessionExt, SessionId},
+    natives::aptos_natives_with_builder,
+};
 use aptos_framework::natives::{
     aggregator_natives::NativeAggregatorContext,
     code::NativeCodeContext,
@@ -64,7 +67,10 @@ pub fn get_max_identifier_size(features: &Features) -> u64 {
 }
 
 impl MoveVmExt {
-    fn new_impl<F>(
+    /// Creates the VM. Unless `use_warm_vm_cache` is set, a fresh Move VM is created instead of
+    /// sharing one from the warm VM cache, so that code loaded into it (e.g. modules that only
+    /// exist in a simulation) can never be observed by other executions.
+    pub(crate) fn new_impl<F>(
         native_gas_params: NativeGasParameters,
         misc_gas_params: MiscGasParameters,
         gas_feature_version: u64,
@@ -74,6 +80,7 @@ impl MoveVmExt {
         gas_hook: Option<F>,
         resolver: &impl AptosMoveResolver,
         aggregator_v2_type_tagging: bool,
+        use_warm_vm_cache: bool,
     ) -> VMResult<Self>
     where
         F: Fn(DynamicExpression) + Send + Sync + 'static,
@@ -114,26 +121,29 @@ impl MoveVmExt {
             builder.set_gas_hook(hook);
         }
 
+        let vm_config = VMConfig {
+            verifier: verifier_config,
+            deserializer_config: DeserializerConfig::new(
+                max_binary_format_version,
+                max_identifier_size,
+            ),
+            paranoid_type_checks: crate::AptosVM::get_paranoid_checks(),
+            enable_invariant_violation_check_in_swap_loc,
+            type_size_limit,
+            max_value_nest_depth: Some(128),
+            type_max_cost,
+            type_base_cost,
+            type_byte_cost,
+            aggregator_v2_type_tagging,
+        };
+        let inner = if use_warm_vm_cache {
+            WarmVmCache::get_warm_vm(builder, vm_config, resolver)?
+        } else {
+            MoveVM::new_with_config(aptos_natives_with_builder(&mut builder), vm_config)?
+        };
+
         Ok(Self {
-            inner: WarmVmCache::get_warm_vm(
-                builder,
-                VMConfig {
-                    verifier: verifier_config,
-                    deserializer_config: DeserializerConfig::new(
-                        max_binary_format_version,
-                        max_identifier_size,
-                    ),
-                    paranoid_type_checks: crate::AptosVM::get_paranoid_checks(),
-                    enable_invariant_violation_check_in_swap_loc,
-                    type_size_limit,
-                    max_value_nest_depth: Some(128),
-                    type_max_cost,
-                    type_base_cost,
-                    type_byte_cost,
-                    aggregator_v2_type_tagging,
-                },
-                resolver,
-            )?,
+            inner,
             chain_id,
             features,
         })
@@ -159,6 +169,7 @@ impl MoveVmExt {
             None,
             resolver,
             aggregator_v2_type_tagging,
+            /*use_warm_vm_cache=*/ true,
         )
     }
 
@@ -186,6 +197,7 @@ impl MoveVmExt {
             gas_hook,
             resolver,
             aggregator_v2_type_tagging,
+            /*use_warm_vm_cache=*/ true,
         )
     }
 
@@ -7,7 +7,10 @@ use aptos_gas_algebra::{FeePerGasUnit, Gas, NumBytes};
 use aptos_types::{
     account_address::AccountAddress,
     chain_id::ChainId,
-    transaction::{SignedTransaction, TransactionPayload},
+    transaction::{
+        OrderlessPayload, ReplayProtector, SignedTransaction, TransactionExecutable,
+        TransactionPayload,
+    },
 };
 
 pub struct TransactionMetadata {
@@ -16,6 +19,7 @@ pub struct TransactionMetadata {
     pub secondary_signers: Vec<AccountAddress>,
     pub secondary_authentication_keys: Vec<Vec<u8>>,
     pub sequence_number: u64,
+    pub replay_protector: ReplayProtector,
     pub fee_payer: Option<AccountAddress>,
     pub fee_payer_authentication_key: Option<Vec<u8>>,
     pub max_gas_amount: Gas,
@@ -41,6 +45,7 @@ impl TransactionMetadata {
                 .map(|account_auth| account_auth.authentication_key().to_vec())
                 .collect(),
             sequence_number: txn.sequence_number(),
+            replay_protector: txn.replay_protector(),
             fee_payer: txn.authenticator_ref().fee_payer_address(),
             fee_payer_authentication_key: txn
                 .authenticator()
@@ -52,16 +57,25 @@ impl TransactionMetadata {
             expiration_timestamp_secs: txn.expiration_timestamp_secs(),
             chain_id: txn.chain_id(),
             script_hash: match txn.payload() {
-                TransactionPayload::Script(s) => HashValue::sha3_256_of(s.code()).to_vec(),
+                TransactionPayload::Script(s)
+                | TransactionPayload::Orderless(OrderlessPayload {
+                    executable: TransactionExecutable::Script(s),
+                    ..
+                }) => HashValue::sha3_256_of(s.code()).to_vec(),
                 TransactionPayload::EntryFunction(_) => vec![],
                 TransactionPayload::Multisig(_) => vec![],
+                TransactionPayload::Orderless(_) => vec![],
 
                 // Deprecated. Return an empty vec because we cannot do anything
                 // else here, only `unreachable!` otherwise.
                 TransactionPayload::ModuleBundle(_) => vec![],
             },
             script_size: match txn.payload() {
-                TransactionPayload::Script(s) => (s.code().len() as u64).into(),
+                TransactionPayload::Script(s)
+                | TransactionPayload::Orderless(OrderlessPayload {
+                    executable: TransactionExecutable::Script(s),
+                    ..
+                }) => (s.code().len() as u64).into(),
                 _ => NumBytes::zero(),
             },
             required_deposit: None,
@@ -106,6 +120,10 @@ impl TransactionMetadata {
         self.sequence_number
     }
 
+    pub fn replay_protector(&self) -> ReplayProtector {
+        self.replay_protector
+    }
+
     pub fn transaction_size(&self) -> NumBytes {
         self.transaction_size
     }
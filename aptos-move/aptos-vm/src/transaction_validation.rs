@@ -13,8 +13,11 @@ use crate::{
 };
 use aptos_gas_algebra::Gas;
 use aptos_types::{
-    account_config::constants::CORE_CODE_ADDRESS, fee_statement::FeeStatement,
-    move_utils::as_move_value::AsMoveValue, on_chain_config::Features, transaction::Multisig,
+    account_config::constants::CORE_CODE_ADDRESS,
+    fee_statement::FeeStatement,
+    move_utils::as_move_value::AsMoveValue,
+    on_chain_config::Features,
+    transaction::{Multisig, ReplayProtector},
 };
 use aptos_vm_logging::log_schema::AdapterLogSchema;
 use fail::fail_point;
@@ -51,6 +54,14 @@ pub static APTOS_TRANSACTION_VALIDATION: Lazy<TransactionValidation> =
             "epilogue_gas_payer_return_deposit",
         )
         .unwrap(),
+        orderless_script_prologue_name: Identifier::new("orderless_script_prologue").unwrap(),
+        orderless_multi_agent_prologue_name: Identifier::new(
+            "orderless_multi_agent_script_prologue",
+        )
+        .unwrap(),
+        orderless_fee_payer_prologue_name: Identifier::new("orderless_fee_payer_script_prologue")
+            .unwrap(),
+        orderless_epilogue_name: Identifier::new("orderless_epilogue").unwrap(),
     });
 
 /// On-chain functions used to validate transactions
@@ -67,6 +78,10 @@ pub struct TransactionValidation {
     pub user_epilogue_return_deposit_name: Identifier,
     pub user_epilogue_gas_payer_name: Identifier,
     pub user_epilogue_gas_payer_return_deposit_name: Identifier,
+    pub orderless_script_prologue_name: Identifier,
+    pub orderless_multi_agent_prologue_name: Identifier,
+    pub orderless_fee_payer_prologue_name: Identifier,
+    pub orderless_epilogue_name: Identifier,
 }
 
 impl TransactionValidation {
@@ -89,6 +104,10 @@ pub(crate) fn run_script_prologue(
     txn_data: &TransactionMetadata,
     log_context: &AdapterLogSchema,
 ) -> Result<(), VMStatus> {
+    if let ReplayProtector::Nonce(nonce) = txn_data.replay_protector() {
+        return run_orderless_script_prologue(session, txn_data, nonce, log_context);
+    }
+
     let txn_sequence_number = txn_data.sequence_number();
     let txn_authentication_key = txn_data.authentication_key().to_vec();
     let txn_gas_price = txn_data.gas_unit_price();
@@ -177,6 +196,93 @@ pub(crate) fn run_script_prologue(
         .or_else(|err| convert_prologue_error(err, log_context))
 }
 
+/// Run the prologue of an orderless transaction, which checks and records its nonce instead of the
+/// sender's sequence number. The deposit is always passed, so there are no separate deposit
+/// variants of the orderless prologues.
+fn run_orderless_script_prologue(
+    session: &mut SessionExt,
+    txn_data: &TransactionMetadata,
+    nonce: u64,
+    log_context: &AdapterLogSchema,
+) -> Result<(), VMStatus> {
+    let txn_authentication_key = txn_data.authentication_key().to_vec();
+    let txn_gas_price = txn_data.gas_unit_price();
+    let txn_max_gas_units = txn_data.max_gas_amount();
+    let txn_expiration_timestamp_secs = txn_data.expiration_timestamp_secs();
+    let chain_id = txn_data.chain_id();
+    let secondary_auth_keys: Vec<MoveValue> = txn_data
+        .secondary_authentication_keys
+        .iter()
+        .map(|auth_key| MoveValue::vector_u8(auth_key.to_vec()))
+        .collect();
+    let (prologue_function_name, args) = if let (Some(fee_payer), Some(fee_payer_auth_key)) = (
+        txn_data.fee_payer(),
+        txn_data.fee_payer_authentication_key.as_ref(),
+    ) {
+        let args = vec![
+            MoveValue::Signer(txn_data.sender),
+            MoveValue::U64(nonce),
+            MoveValue::vector_u8(txn_authentication_key),
+            MoveValue::vector_address(txn_data.secondary_signers()),
+            MoveValue::Vector(secondary_auth_keys),
+            MoveValue::Address(fee_payer),
+            MoveValue::vector_u8(fee_payer_auth_key.to_vec()),
+            MoveValue::U64(txn_gas_price.into()),
+            MoveValue::U64(txn_max_gas_units.into()),
+            MoveValue::U64(txn_expiration_timestamp_secs),
+            MoveValue::U8(chain_id.id()),
+            txn_data.required_deposit.as_move_value(),
+        ];
+        (
+            &APTOS_TRANSACTION_VALIDATION.orderless_fee_payer_prologue_name,
+            args,
+        )
+    } else if txn_data.is_multi_agent() {
+        let args = vec![
+            MoveValue::Signer(txn_data.sender),
+            MoveValue::U64(nonce),
+            MoveValue::vector_u8(txn_authentication_key),
+            MoveValue::vector_address(txn_data.secondary_signers()),
+            MoveValue::Vector(secondary_auth_keys),
+            MoveValue::U64(txn_gas_price.into()),
+            MoveValue::U64(txn_max_gas_units.into()),
+            MoveValue::U64(txn_expiration_timestamp_secs),
+            MoveValue::U8(chain_id.id()),
+        ];
+        (
+            &APTOS_TRANSACTION_VALIDATION.orderless_multi_agent_prologue_name,
+            args,
+        )
+    } else {
+        let args = vec![
+            MoveValue::Signer(txn_data.sender),
+            MoveValue::U64(nonce),
+            MoveValue::vector_u8(txn_authentication_key),
+            MoveValue::U64(txn_gas_price.into()),
+            MoveValue::U64(txn_max_gas_units.into()),
+            MoveValue::U64(txn_expiration_timestamp_secs),
+            MoveValue::U8(chain_id.id()),
+            MoveValue::vector_u8(txn_data.script_hash.clone()),
+            txn_data.required_deposit.as_move_value(),
+        ];
+        (
+            &APTOS_TRANSACTION_VALIDATION.orderless_script_prologue_name,
+            args,
+        )
+    };
+    session
+        .execute_function_bypass_visibility(
+            &APTOS_TRANSACTION_VALIDATION.module_id(),
+            prologue_function_name,
+            vec![],
+            serialize_values(&args),
+            &mut UnmeteredGasMeter,
+        )
+        .map(|_return_vals| ())
+        .map_err(expect_no_verification_errors)
+        .or_else(|err| convert_prologue_error(err, log_context))
+}
+
 /// Run the prologue for a multisig transaction. This needs to verify that:
 /// 1. The the multisig tx exists
 /// 2. It has received enough approvals to meet the signature threshold of the multisig account
@@ -223,9 +329,27 @@ fn run_epilogue(
     let txn_gas_price = txn_data.gas_unit_price();
     let txn_max_gas_units = txn_data.max_gas_amount();
 
-    // We can unconditionally do this as this condition can only be true if the prologue
-    // accepted it, in which case the gas payer feature is enabled.
-    if let Some(fee_payer) = txn_data.fee_payer() {
+    if txn_data.replay_protector().is_nonce() {
+        // Orderless transactions have a single epilogue, which leaves the sequence number alone.
+        let args = vec![
+            MoveValue::Signer(txn_data.sender),
+            MoveValue::Address(txn_data.fee_payer().unwrap_or(txn_data.sender)),
+            MoveValue::U64(fee_statement.storage_fee_refund()),
+            MoveValue::U64(txn_gas_price.into()),
+            MoveValue::U64(txn_max_gas_units.into()),
+            MoveValue::U64(gas_remaining.into()),
+            txn_data.required_deposit.as_move_value(),
+        ];
+        session.execute_function_bypass_visibility(
+            &APTOS_TRANSACTION_VALIDATION.module_id(),
+            &APTOS_TRANSACTION_VALIDATION.orderless_epilogue_name,
+            vec![],
+            serialize_values(&args),
+            &mut UnmeteredGasMeter,
+        )
+    } else if let Some(fee_payer) = txn_data.fee_payer() {
+        // We can unconditionally do this as this condition can only be true if the prologue
+        // accepted it, in which case the gas payer feature is enabled.
         let (func_name, args) = {
             let mut args = vec![
                 MoveValue::Signer(txn_data.sender),
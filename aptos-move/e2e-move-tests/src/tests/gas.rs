@@ -13,7 +13,7 @@ use crate::{
 };
 use aptos_cached_packages::{aptos_stdlib, aptos_token_sdk_builder};
 use aptos_crypto::{bls12381, PrivateKey, Uniform};
-use aptos_gas_profiling::TransactionGasLog;
+use aptos_gas_profiling::{GasProfileSummary, TransactionGasLog};
 use aptos_types::{
     account_address::{default_stake_pool_address, AccountAddress},
     transaction::{EntryFunction, TransactionPayload},
@@ -398,6 +398,105 @@ fn test_gas() {
     );
 }
 
+#[test]
+fn test_gas_profile_diff() {
+    let mut harness = MoveHarness::new();
+    let sender = &harness.new_account_at(AccountAddress::from_hex_literal("0x121").unwrap());
+    let receiver = &harness.new_account_at(AccountAddress::from_hex_literal("0x122").unwrap());
+
+    // Transferring to an account that doesn't exist yet creates it, which costs more.
+    let (base, _) = harness.evaluate_gas_with_profiler(
+        sender,
+        aptos_stdlib::aptos_account_transfer(*receiver.address(), 1000),
+    );
+    let (new, _) = harness.evaluate_gas_with_profiler(
+        sender,
+        aptos_stdlib::aptos_account_transfer(
+            AccountAddress::from_hex_literal("0x123").unwrap(),
+            1000,
+        ),
+    );
+
+    let same = base.diff(&base);
+    assert!(same.regressions(0.0).is_empty());
+    assert!(same.frames.iter().all(|entry| entry.delta() == 0));
+    assert!(same.ops.iter().all(|entry| entry.delta() == 0));
+
+    let diff = base.diff(&new);
+    assert!(diff.execution_and_io.delta() > 0);
+    assert!(diff.storage_fee.delta() > 0);
+    assert_eq!(diff.regressions(0.0).len(), 2);
+    // The slots of the new account only show up in the new log.
+    assert!(diff
+        .storage_fees
+        .iter()
+        .any(|entry| entry.base_count == 0 && entry.new_count > 0));
+    assert!(diff
+        .frames
+        .iter()
+        .any(|entry| entry.name.ends_with("::account::create_account")));
+
+    let json = diff.to_json();
+    assert_eq!(
+        json["storage_fee"]["delta"].as_i64(),
+        Some(diff.storage_fee.delta() as i64)
+    );
+    let report = diff.to_text_report().unwrap();
+    assert!(report.contains("::account::create_account"));
+}
+
+#[test]
+fn test_gas_profile_summary() {
+    let mut harness = MoveHarness::new();
+    let sender = &harness.new_account_at(AccountAddress::from_hex_literal("0x121").unwrap());
+    let receiver = &harness.new_account_at(AccountAddress::from_hex_literal("0x122").unwrap());
+
+    let logs = (0..2)
+        .map(|_| {
+            harness
+                .evaluate_gas_with_profiler(
+                    sender,
+                    aptos_stdlib::aptos_account_transfer(*receiver.address(), 1000),
+                )
+                .0
+        })
+        .collect::<Vec<_>>();
+
+    let summary = GasProfileSummary::new(&logs);
+    assert_eq!(summary.num_logs, 2);
+    assert_eq!(
+        summary.execution_and_io,
+        logs.iter().map(|log| u64::from(log.exec_io.total)).sum()
+    );
+    // Every call of the transfer is counted.
+    let (_, count, cost) = summary
+        .frames
+        .iter()
+        .find(|(name, _, _)| name.ends_with("::aptos_account::transfer"))
+        .unwrap();
+    assert_eq!(*count, 2);
+    assert!(*cost > 0 && *cost <= summary.execution_and_io);
+    // Entries are sorted from the most to the least expensive.
+    assert!(summary.frames.windows(2).all(|w| w[0].2 >= w[1].2));
+    assert!(summary.ops.windows(2).all(|w| w[0].2 >= w[1].2));
+
+    let json = summary.to_json();
+    assert_eq!(json["num_logs"].as_u64(), Some(2));
+    assert_eq!(
+        json["frames"].as_array().unwrap().len(),
+        summary.frames.len()
+    );
+
+    let report = summary.to_text_report(1).unwrap();
+    assert!(report.contains("(2 runs)"));
+    assert!(report.contains(&summary.frames[0].0));
+    assert!(!report.contains(&summary.frames[1].0));
+
+    let empty = GasProfileSummary::new(&[]);
+    assert_eq!(empty.num_logs, 0);
+    assert!(empty.to_text_report(10).unwrap().contains("(empty)"));
+}
+
 fn dollar_cost(gas_units: u64, price: u64) -> f64 {
     ((gas_units * 100/* gas unit price */) as f64) / 100_000_000_f64 * (price as f64)
 }
@@ -0,0 +1,113 @@
+/// Verifies BN254 Groth16 proofs, either with the dedicated `groth16_verify_proof_prepared()` native
+/// or with the equivalent sequence of generic algebra operations, so the two can be compared.
+module 0xcafe::groth16_verifier {
+    use aptos_std::bn254_algebra::{Fr, FormatFrLsb, FormatG1Compr, FormatG2Compr, G1, G2, Gt};
+    use aptos_std::crypto_algebra::{
+        deserialize, eq, from_u64, groth16_verify_proof_prepared, multi_pairing, multi_scalar_mul, neg, pairing,
+        Element,
+    };
+    use std::option;
+    use std::vector;
+
+    /// The proof did not verify.
+    const E_INVALID_PROOF: u64 = 1;
+
+    struct PreparedVerificationKey has drop {
+        alpha_g1_beta_g2: Element<Gt>,
+        gamma_g2_neg: Element<G2>,
+        delta_g2_neg: Element<G2>,
+        gamma_abc_g1: vector<Element<G1>>,
+    }
+
+    struct Proof has drop {
+        a: Element<G1>,
+        b: Element<G2>,
+        c: Element<G1>,
+    }
+
+    entry fun verify_with_native(
+        vk_alpha_g1: vector<u8>,
+        vk_beta_g2: vector<u8>,
+        vk_gamma_g2: vector<u8>,
+        vk_delta_g2: vector<u8>,
+        vk_gamma_abc_g1: vector<vector<u8>>,
+        public_inputs: vector<vector<u8>>,
+        proof_a: vector<u8>,
+        proof_b: vector<u8>,
+        proof_c: vector<u8>,
+    ) {
+        let pvk = prepare_verification_key(vk_alpha_g1, vk_beta_g2, vk_gamma_g2, vk_delta_g2, vk_gamma_abc_g1);
+        let public_inputs = deserialize_public_inputs(public_inputs);
+        let proof = deserialize_proof(proof_a, proof_b, proof_c);
+        assert!(groth16_verify_proof_prepared<G1, G2, Gt, Fr>(
+            &pvk.alpha_g1_beta_g2,
+            &pvk.gamma_g2_neg,
+            &pvk.delta_g2_neg,
+            &pvk.gamma_abc_g1,
+            &public_inputs,
+            &proof.a,
+            &proof.b,
+            &proof.c,
+        ), E_INVALID_PROOF);
+    }
+
+    entry fun verify_with_generic_operations(
+        vk_alpha_g1: vector<u8>,
+        vk_beta_g2: vector<u8>,
+        vk_gamma_g2: vector<u8>,
+        vk_delta_g2: vector<u8>,
+        vk_gamma_abc_g1: vector<vector<u8>>,
+        public_inputs: vector<vector<u8>>,
+        proof_a: vector<u8>,
+        proof_b: vector<u8>,
+        proof_c: vector<u8>,
+    ) {
+        let pvk = prepare_verification_key(vk_alpha_g1, vk_beta_g2, vk_gamma_g2, vk_delta_g2, vk_gamma_abc_g1);
+        let public_inputs = deserialize_public_inputs(public_inputs);
+        let proof = deserialize_proof(proof_a, proof_b, proof_c);
+        let scalars = vector[from_u64<Fr>(1)];
+        vector::append(&mut scalars, public_inputs);
+        let g1_elements = vector[proof.a, multi_scalar_mul(&pvk.gamma_abc_g1, &scalars), proof.c];
+        let g2_elements = vector[proof.b, pvk.gamma_g2_neg, pvk.delta_g2_neg];
+        assert!(
+            eq(&pvk.alpha_g1_beta_g2, &multi_pairing<G1, G2, Gt>(&g1_elements, &g2_elements)),
+            E_INVALID_PROOF
+        );
+    }
+
+    fun prepare_verification_key(
+        alpha_g1: vector<u8>,
+        beta_g2: vector<u8>,
+        gamma_g2: vector<u8>,
+        delta_g2: vector<u8>,
+        gamma_abc_g1: vector<vector<u8>>,
+    ): PreparedVerificationKey {
+        let alpha_g1 = option::extract(&mut deserialize<G1, FormatG1Compr>(&alpha_g1));
+        let beta_g2 = option::extract(&mut deserialize<G2, FormatG2Compr>(&beta_g2));
+        let gamma_g2 = option::extract(&mut deserialize<G2, FormatG2Compr>(&gamma_g2));
+        let delta_g2 = option::extract(&mut deserialize<G2, FormatG2Compr>(&delta_g2));
+        let gamma_abc_g1 = vector::map_ref(&gamma_abc_g1, |bytes| {
+            option::extract(&mut deserialize<G1, FormatG1Compr>(bytes))
+        });
+        PreparedVerificationKey {
+            alpha_g1_beta_g2: pairing<G1, G2, Gt>(&alpha_g1, &beta_g2),
+            gamma_g2_neg: neg(&gamma_g2),
+            delta_g2_neg: neg(&delta_g2),
+            gamma_abc_g1,
+        }
+    }
+
+    fun deserialize_public_inputs(public_inputs: vector<vector<u8>>): vector<Element<Fr>> {
+        vector::map_ref(&public_inputs, |bytes| {
+            option::extract(&mut deserialize<Fr, FormatFrLsb>(bytes))
+        })
+    }
+
+    fun deserialize_proof(a: vector<u8>, b: vector<u8>, c: vector<u8>): Proof {
+        Proof {
+            a: option::extract(&mut deserialize<G1, FormatG1Compr>(&a)),
+            b: option::extract(&mut deserialize<G2, FormatG2Compr>(&b)),
+            c: option::extract(&mut deserialize<G1, FormatG1Compr>(&c)),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    assert_abort, assert_success,
+    tests::{common, gas::print_gas_cost},
+    MoveHarness,
+};
+use aptos_language_e2e_tests::account::Account;
+use aptos_types::{
+    account_address::AccountAddress,
+    keyless::{
+        test_utils::get_sample_groth16_zkp_and_statement, Groth16VerificationKey,
+        DEVNET_VERIFICATION_KEY,
+    },
+    on_chain_config::FeatureFlag,
+};
+use move_core_types::value::{serialize_values, MoveValue};
+
+// Error codes from the groth16_verifier test module.
+const E_INVALID_PROOF: u64 = 1;
+
+/// Serializes the arguments of the `0xcafe::groth16_verifier::verify_with_*` entry functions for
+/// the keyless devnet verification key and the sample keyless proof, with the public inputs hash
+/// optionally tampered with.
+fn keyless_verification_args(tamper_with_public_inputs: bool) -> Vec<Vec<u8>> {
+    let vk = Groth16VerificationKey::from(DEVNET_VERIFICATION_KEY.clone());
+    let statement = get_sample_groth16_zkp_and_statement();
+    let mut public_inputs_hash = statement.public_inputs_hash;
+    if tamper_with_public_inputs {
+        public_inputs_hash[0] ^= 1;
+    }
+
+    let bytes = MoveValue::vector_u8;
+    serialize_values(&[
+        bytes(vk.alpha_g1),
+        bytes(vk.beta_g2),
+        bytes(vk.gamma_g2),
+        bytes(vk.delta_g2),
+        MoveValue::Vector(vk.gamma_abc_g1.into_iter().map(bytes).collect()),
+        MoveValue::Vector(vec![bytes(public_inputs_hash.to_vec())]),
+        bytes(bcs::to_bytes(statement.proof.get_a()).unwrap()),
+        bytes(bcs::to_bytes(statement.proof.get_b()).unwrap()),
+        bytes(bcs::to_bytes(statement.proof.get_c()).unwrap()),
+    ])
+}
+
+fn setup() -> (MoveHarness, Account) {
+    let mut h = MoveHarness::new_with_features(
+        vec![
+            FeatureFlag::CRYPTOGRAPHY_ALGEBRA_NATIVES,
+            FeatureFlag::BN254_STRUCTURES,
+        ],
+        vec![],
+    );
+    let acc = h.new_account_at(AccountAddress::from_hex_literal("0xcafe").unwrap());
+    assert_success!(h.publish_package(&acc, &common::test_dir_path("groth16.data")));
+    (h, acc)
+}
+
+/// Run with `cargo test test_groth16_keyless_proof_gas -- --nocapture` to see output.
+#[test]
+fn test_groth16_keyless_proof_gas() {
+    let (mut h, acc) = setup();
+
+    let native_gas = h.evaluate_entry_function_gas(
+        &acc,
+        str::parse("0xcafe::groth16_verifier::verify_with_native").unwrap(),
+        vec![],
+        keyless_verification_args(false),
+    );
+    let generic_gas = h.evaluate_entry_function_gas(
+        &acc,
+        str::parse("0xcafe::groth16_verifier::verify_with_generic_operations").unwrap(),
+        vec![],
+        keyless_verification_args(false),
+    );
+    print_gas_cost("groth16_verify_native", native_gas);
+    print_gas_cost("groth16_verify_generic", generic_gas);
+    assert!(native_gas < generic_gas);
+}
+
+#[test]
+fn test_groth16_keyless_proof_rejects_wrong_public_inputs() {
+    let (mut h, acc) = setup();
+
+    for fun in [
+        "0xcafe::groth16_verifier::verify_with_native",
+        "0xcafe::groth16_verifier::verify_with_generic_operations",
+    ] {
+        let status = h.run_entry_function(
+            &acc,
+            str::parse(fun).unwrap(),
+            vec![],
+            keyless_verification_args(true),
+        );
+        assert_abort!(status, E_INVALID_PROOF);
+    }
+}
@@ -19,6 +19,7 @@ mod fungible_asset;
 mod gas;
 mod generate_upgrade_script;
 mod governance_updates;
+mod groth16;
 mod infinite_loop;
 mod init_module;
 mod keyless_feature_gating;
@@ -34,11 +35,13 @@ mod nft_dao;
 mod object_code_deployment;
 mod offer_rotation_capability;
 mod offer_signer_capability;
+mod orderless;
 mod per_category_gas_limits;
 mod randomness_test_and_abort;
 mod resource_groups;
 mod rotate_auth_key;
 mod scripts;
+mod secp256r1_ecdsa;
 mod simple_defi;
 mod smart_data_structures;
 mod stake;
@@ -0,0 +1,175 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{assert_abort, assert_success, MoveHarness};
+use aptos_cached_packages::aptos_stdlib;
+use aptos_language_e2e_tests::account::Account;
+use aptos_types::{
+    access_path::AccessPath,
+    account_address::AccountAddress,
+    chain_id::ChainId,
+    on_chain_config::FeatureFlag,
+    state_store::state_key::StateKey,
+    transaction::{
+        OrderlessPayload, RawTransaction, SignedTransaction, TransactionExecutable,
+        TransactionPayload, TransactionStatus,
+    },
+};
+use move_core_types::{
+    language_storage::StructTag, parser::parse_struct_tag, vm_status::StatusCode,
+};
+
+fn orderless_transfer(
+    h: &mut MoveHarness,
+    sender: &Account,
+    receiver: &Account,
+    nonce: u64,
+    expires_in_secs: u64,
+) -> SignedTransaction {
+    orderless_transfer_amount(h, sender, receiver, nonce, expires_in_secs, 1)
+}
+
+fn orderless_transfer_amount(
+    h: &mut MoveHarness,
+    sender: &Account,
+    receiver: &Account,
+    nonce: u64,
+    expires_in_secs: u64,
+    amount: u64,
+) -> SignedTransaction {
+    let entry_function =
+        aptos_stdlib::aptos_coin_transfer(*receiver.address(), amount).into_entry_function();
+    RawTransaction::new_orderless(
+        *sender.address(),
+        nonce,
+        TransactionExecutable::EntryFunction(entry_function),
+        1_000_000,
+        h.default_gas_unit_price,
+        h.executor.get_block_time_seconds() + expires_in_secs,
+        ChainId::test(),
+    )
+    .sign(&sender.privkey, sender.pubkey.as_ed25519().unwrap())
+    .unwrap()
+    .into_inner()
+}
+
+#[test]
+fn test_orderless_transaction_cannot_be_replayed() {
+    let mut h = MoveHarness::new_with_features(vec![FeatureFlag::ORDERLESS_TRANSACTIONS], vec![]);
+    let alice = h.new_account_with_balance_and_sequence_number(1_000_000_000, 10);
+    let bob = h.new_account_with_balance_and_sequence_number(0, 0);
+
+    let txn = orderless_transfer(&mut h, &alice, &bob, 42, 30);
+    assert_success!(h.run(txn.clone()));
+    assert_eq!(h.read_aptos_balance(bob.address()), 1);
+    // Orderless transactions leave the sequence number alone.
+    assert_eq!(h.sequence_number(alice.address()), 10);
+
+    assert_eq!(
+        h.run(txn),
+        TransactionStatus::Discard(StatusCode::NONCE_ALREADY_USED)
+    );
+
+    // Any other nonce is still accepted, regardless of the order it is used in.
+    assert_success!(h.run(orderless_transfer(&mut h, &alice, &bob, 7, 30)));
+    assert_eq!(h.read_aptos_balance(bob.address()), 2);
+}
+
+fn nonce_history_struct_tag() -> StructTag {
+    parse_struct_tag("0x1::nonce_validation::NonceHistory").unwrap()
+}
+
+#[test]
+fn test_aborted_orderless_transaction_consumes_nonce() {
+    let mut h = MoveHarness::new_with_features(vec![FeatureFlag::ORDERLESS_TRANSACTIONS], vec![]);
+    let alice = h.new_account_with_balance_and_sequence_number(1_000_000_000, 0);
+    let bob = h.new_account_with_balance_and_sequence_number(0, 0);
+
+    // Transferring more than the balance aborts after the prologue has recorded the nonce.
+    let txn = orderless_transfer_amount(&mut h, &alice, &bob, 42, 30, 2_000_000_000);
+    assert_abort!(h.run(txn.clone()), _);
+    assert_eq!(h.read_aptos_balance(bob.address()), 0);
+
+    assert_eq!(
+        h.run(txn),
+        TransactionStatus::Discard(StatusCode::NONCE_ALREADY_USED)
+    );
+}
+
+#[test]
+fn test_nonce_history_is_created_by_first_orderless_transaction() {
+    let mut h = MoveHarness::new_with_features(vec![FeatureFlag::ORDERLESS_TRANSACTIONS], vec![]);
+    let alice = h.new_account_with_balance_and_sequence_number(1_000_000_000, 0);
+    let bob = h.new_account_with_balance_and_sequence_number(0, 0);
+
+    // Networks whose genesis predates orderless transactions have no nonce history.
+    let path =
+        AccessPath::resource_access_path(AccountAddress::ONE, nonce_history_struct_tag()).unwrap();
+    h.executor
+        .data_store_mut()
+        .remove(&StateKey::access_path(path))
+        .unwrap();
+    assert!(!h.exists_resource(&AccountAddress::ONE, nonce_history_struct_tag()));
+
+    let txn = orderless_transfer(&mut h, &alice, &bob, 42, 30);
+    assert_success!(h.run(txn.clone()));
+    assert!(h.exists_resource(&AccountAddress::ONE, nonce_history_struct_tag()));
+    assert_eq!(
+        h.run(txn),
+        TransactionStatus::Discard(StatusCode::NONCE_ALREADY_USED)
+    );
+}
+
+#[test]
+fn test_orderless_transaction_requires_fixed_sequence_number() {
+    let mut h = MoveHarness::new_with_features(vec![FeatureFlag::ORDERLESS_TRANSACTIONS], vec![]);
+    let alice = h.new_account_with_balance_and_sequence_number(1_000_000_000, 0);
+    let bob = h.new_account_with_balance_and_sequence_number(0, 0);
+
+    let entry_function = aptos_stdlib::aptos_coin_transfer(*bob.address(), 1).into_entry_function();
+    let txn = RawTransaction::new(
+        *alice.address(),
+        0,
+        TransactionPayload::Orderless(OrderlessPayload {
+            nonce: 1,
+            executable: TransactionExecutable::EntryFunction(entry_function),
+        }),
+        1_000_000,
+        h.default_gas_unit_price,
+        h.executor.get_block_time_seconds() + 30,
+        ChainId::test(),
+    )
+    .sign(&alice.privkey, alice.pubkey.as_ed25519().unwrap())
+    .unwrap()
+    .into_inner();
+    assert_eq!(
+        h.run(txn),
+        TransactionStatus::Discard(StatusCode::INVALID_ORDERLESS_SEQUENCE_NUMBER)
+    );
+}
+
+#[test]
+fn test_orderless_transaction_expiration_is_bounded() {
+    let mut h = MoveHarness::new_with_features(vec![FeatureFlag::ORDERLESS_TRANSACTIONS], vec![]);
+    let alice = h.new_account_with_balance_and_sequence_number(1_000_000_000, 0);
+    let bob = h.new_account_with_balance_and_sequence_number(0, 0);
+
+    let txn = orderless_transfer(&mut h, &alice, &bob, 1, 3600);
+    assert_eq!(
+        h.run(txn),
+        TransactionStatus::Discard(StatusCode::TRANSACTION_EXPIRATION_TOO_FAR_IN_FUTURE)
+    );
+}
+
+#[test]
+fn test_orderless_transaction_feature_gated() {
+    let mut h = MoveHarness::new_with_features(vec![], vec![FeatureFlag::ORDERLESS_TRANSACTIONS]);
+    let alice = h.new_account_with_balance_and_sequence_number(1_000_000_000, 0);
+    let bob = h.new_account_with_balance_and_sequence_number(0, 0);
+
+    let txn = orderless_transfer(&mut h, &alice, &bob, 1, 30);
+    assert_eq!(
+        h.run(txn),
+        TransactionStatus::Discard(StatusCode::FEATURE_UNDER_GATING)
+    );
+}
@@ -0,0 +1,68 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{assert_success, MoveHarness};
+use aptos_cached_packages::aptos_stdlib;
+use aptos_crypto::{secp256r1_ecdsa, PrivateKey, Uniform};
+use aptos_language_e2e_tests::account::{Account, AccountPublicKey, TransactionBuilder};
+use aptos_types::{
+    on_chain_config::FeatureFlag,
+    transaction::{
+        authenticator::{AnyPublicKey, AuthenticationKey},
+        SignedTransaction, TransactionStatus,
+    },
+};
+use move_core_types::{account_address::AccountAddress, vm_status::StatusCode};
+
+/// Creates and funds an account for a new secp256r1 key, and signs a transfer of `amount` coins
+/// to `recipient` with the key.
+fn get_secp256r1_ecdsa_txn(
+    h: &mut MoveHarness,
+    recipient: AccountAddress,
+    amount: u64,
+) -> SignedTransaction {
+    let private_key = secp256r1_ecdsa::PrivateKey::generate_for_testing();
+    let public_key = private_key.public_key();
+    let addr = AuthenticationKey::any_key(AnyPublicKey::secp256r1_ecdsa(public_key.clone()))
+        .account_address();
+    let account = h.store_and_fund_account(
+        &Account::new_from_addr(addr, AccountPublicKey::Secp256r1Ecdsa(public_key.clone())),
+        100000000,
+        0,
+    );
+
+    TransactionBuilder::new(account.clone())
+        .payload(aptos_stdlib::aptos_coin_transfer(recipient, amount))
+        .sequence_number(h.sequence_number(account.address()))
+        .max_gas_amount(1_000_000)
+        .gas_unit_price(1)
+        .raw()
+        .sign_secp256r1_ecdsa(&private_key, public_key)
+        .unwrap()
+        .into_inner()
+}
+
+#[test]
+fn test_secp256r1_ecdsa_transfer() {
+    let mut h =
+        MoveHarness::new_with_features(vec![FeatureFlag::SECP256R1_ECDSA_SIGNATURE], vec![]);
+    let recipient = h.new_account_at(AccountAddress::from_hex_literal("0xb0b").unwrap());
+    let balance = h.read_aptos_balance(recipient.address());
+
+    let txn = get_secp256r1_ecdsa_txn(&mut h, *recipient.address(), 5);
+    assert_success!(h.run(txn));
+    assert_eq!(h.read_aptos_balance(recipient.address()), balance + 5);
+}
+
+#[test]
+fn test_secp256r1_ecdsa_feature_gating() {
+    let mut h =
+        MoveHarness::new_with_features(vec![], vec![FeatureFlag::SECP256R1_ECDSA_SIGNATURE]);
+    let recipient = h.new_account_at(AccountAddress::from_hex_literal("0xb0b").unwrap());
+
+    let txn = get_secp256r1_ecdsa_txn(&mut h, *recipient.address(), 5);
+    assert_eq!(
+        h.run(txn),
+        TransactionStatus::Discard(StatusCode::FEATURE_UNDER_GATING)
+    );
+}
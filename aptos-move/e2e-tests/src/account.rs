@@ -5,7 +5,7 @@
 //! Test infrastructure for modeling Aptos accounts.
 
 use crate::gas_costs;
-use aptos_crypto::ed25519::*;
+use aptos_crypto::{ed25519::*, secp256r1_ecdsa};
 use aptos_keygen::KeyGen;
 use aptos_types::{
     access_path::AccessPath,
@@ -31,6 +31,7 @@ pub const DEFAULT_EXPIRATION_TIME: u64 = 4_000_000;
 pub enum AccountPublicKey {
     Ed25519(Ed25519PublicKey),
     Keyless(KeylessPublicKey),
+    Secp256r1Ecdsa(secp256r1_ecdsa::PublicKey),
 }
 
 impl AccountPublicKey {
@@ -38,13 +39,14 @@ impl AccountPublicKey {
         match self {
             AccountPublicKey::Ed25519(pk) => pk.to_bytes().to_vec(),
             AccountPublicKey::Keyless(pk) => pk.to_bytes(),
+            AccountPublicKey::Secp256r1Ecdsa(pk) => pk.to_bytes().to_vec(),
         }
     }
 
     pub fn as_ed25519(&self) -> Option<Ed25519PublicKey> {
         match self {
             AccountPublicKey::Ed25519(pk) => Some(pk.clone()),
-            AccountPublicKey::Keyless(_) => None,
+            AccountPublicKey::Keyless(_) | AccountPublicKey::Secp256r1Ecdsa(_) => None,
         }
     }
 }
@@ -59,7 +61,7 @@ impl AccountPublicKey {
 pub struct Account {
     addr: AccountAddress,
     /// The current private key for this account.
-    /// TODO: When `pubkey` is of type `AccountPublicKey::Keyless`, this will be undefined.
+    /// TODO: When `pubkey` is not of type `AccountPublicKey::Ed25519`, this will be undefined.
     pub privkey: Ed25519PrivateKey,
     /// The current public key for this account.
     pub pubkey: AccountPublicKey,
@@ -183,6 +185,9 @@ impl Account {
             AccountPublicKey::Keyless(pk) => {
                 AuthenticationKey::any_key(AnyPublicKey::keyless(pk.clone()))
             },
+            AccountPublicKey::Secp256r1Ecdsa(pk) => {
+                AuthenticationKey::any_key(AnyPublicKey::secp256r1_ecdsa(pk.clone()))
+            },
         }
         .to_vec()
     }
@@ -47,9 +47,9 @@ use aptos_types::{
         signature_verified_transaction::{
             into_signature_verified_block, SignatureVerifiedTransaction,
         },
-        BlockOutput, EntryFunction, ExecutionStatus, SignedTransaction, Transaction,
-        TransactionOutput, TransactionPayload, TransactionStatus, VMValidatorResult,
-        ViewFunctionOutput,
+        BlockOutput, EntryFunction, ExecutionStatus, OrderlessPayload, SignedTransaction,
+        Transaction, TransactionExecutable, TransactionOutput, TransactionPayload,
+        TransactionStatus, VMValidatorResult, ViewFunctionOutput,
     },
     vm_status::VMStatus,
     write_set::WriteSet,
@@ -655,8 +655,16 @@ impl FakeExecutor {
                         balance,
                     )));
                 let gas_profiler = match txn.payload() {
-                    TransactionPayload::Script(_) => GasProfiler::new_script(gas_meter),
-                    TransactionPayload::EntryFunction(entry_func) => GasProfiler::new_function(
+                    TransactionPayload::Script(_)
+                    | TransactionPayload::Orderless(OrderlessPayload {
+                        executable: TransactionExecutable::Script(_),
+                        ..
+                    }) => GasProfiler::new_script(gas_meter),
+                    TransactionPayload::EntryFunction(entry_func)
+                    | TransactionPayload::Orderless(OrderlessPayload {
+                        executable: TransactionExecutable::EntryFunction(entry_func),
+                        ..
+                    }) => GasProfiler::new_function(
                         gas_meter,
                         entry_func.module().clone(),
                         entry_func.function().to_owned(),
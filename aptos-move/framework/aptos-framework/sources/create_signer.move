@@ -13,6 +13,7 @@ module aptos_framework::create_signer {
     friend aptos_framework::aptos_account;
     friend aptos_framework::genesis;
     friend aptos_framework::multisig_account;
+    friend aptos_framework::nonce_validation;
     friend aptos_framework::object;
 
     public(friend) native fun create_signer(addr: address): signer;
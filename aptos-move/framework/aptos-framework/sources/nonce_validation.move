@@ -0,0 +1,165 @@
+/// Replay protection for orderless transactions.
+///
+/// An orderless transaction carries a sender-chosen nonce instead of relying on the sender's sequence number. This
+/// module records every `(sender, nonce)` pair until the transaction carrying it expires, so the same signed
+/// transaction can never be executed twice. Once a transaction has expired it is rejected by the prologue anyway,
+/// so its entry is no longer needed and is dropped the next time its bucket is touched. Together with the short
+/// maximum expiration enforced in `transaction_validation`, this keeps the history bounded by the recent orderless
+/// transaction throughput rather than growing forever.
+module aptos_framework::nonce_validation {
+    use std::vector;
+    use aptos_std::aptos_hash;
+    use aptos_std::table::{Self, Table};
+    use aptos_framework::create_signer;
+    use aptos_framework::system_addresses;
+    use aptos_framework::timestamp;
+
+    friend aptos_framework::transaction_validation;
+
+    /// Number of buckets the `(sender, nonce)` pairs are spread over.
+    const NUM_BUCKETS: u64 = 50000;
+
+    /// The nonces of all orderless transactions that have been executed and have not yet expired.
+    struct NonceHistory has key {
+        buckets: Table<u64, Bucket>,
+    }
+
+    struct Bucket has store {
+        entries: vector<NonceEntry>,
+    }
+
+    struct NonceEntry has copy, drop, store {
+        sender: address,
+        nonce: u64,
+        txn_expiration_time: u64,
+    }
+
+    /// Hashed to pick the bucket of a `(sender, nonce)` pair.
+    struct NonceKey has copy, drop {
+        sender: address,
+        nonce: u64,
+    }
+
+    /// Called in genesis.move. Networks created before orderless transactions existed get the history created by
+    /// their first orderless transaction instead, see `check_and_insert_nonce()`.
+    public fun initialize(aptos_framework: &signer) {
+        system_addresses::assert_aptos_framework(aptos_framework);
+        if (!exists<NonceHistory>(@aptos_framework)) {
+            move_to(aptos_framework, NonceHistory { buckets: table::new() });
+        }
+    }
+
+    /// Records `nonce` for `sender` until `txn_expiration_time` and returns true, or returns false if the pair is
+    /// still recorded from an earlier transaction. Expired entries of the bucket are dropped along the way.
+    public(friend) fun check_and_insert_nonce(
+        sender: address,
+        nonce: u64,
+        txn_expiration_time: u64,
+    ): bool acquires NonceHistory {
+        if (!exists<NonceHistory>(@aptos_framework)) {
+            initialize(&create_signer::create_signer(@aptos_framework));
+        };
+        let now = timestamp::now_seconds();
+        let bucket_index = aptos_hash::sip_hash_from_value(&NonceKey { sender, nonce }) % NUM_BUCKETS;
+        let buckets = &mut borrow_global_mut<NonceHistory>(@aptos_framework).buckets;
+        if (!table::contains(buckets, bucket_index)) {
+            table::add(buckets, bucket_index, Bucket { entries: vector::empty() });
+        };
+        let entries = &mut table::borrow_mut(buckets, bucket_index).entries;
+
+        let i = 0;
+        let len = vector::length(entries);
+        while (i < len) {
+            let entry = vector::borrow(entries, i);
+            if (entry.txn_expiration_time <= now) {
+                vector::swap_remove(entries, i);
+                len = len - 1;
+            } else if (entry.sender == sender && entry.nonce == nonce) {
+                return false
+            } else {
+                i = i + 1;
+            }
+        };
+        vector::push_back(entries, NonceEntry { sender, nonce, txn_expiration_time });
+        true
+    }
+
+    #[view]
+    /// Returns whether `nonce` is currently recorded for `sender`, i.e. whether an unexpired orderless transaction
+    /// with this nonce has already been executed.
+    public fun is_nonce_used(sender: address, nonce: u64): bool acquires NonceHistory {
+        if (!exists<NonceHistory>(@aptos_framework)) {
+            return false
+        };
+        let now = timestamp::now_seconds();
+        let bucket_index = aptos_hash::sip_hash_from_value(&NonceKey { sender, nonce }) % NUM_BUCKETS;
+        let buckets = &borrow_global<NonceHistory>(@aptos_framework).buckets;
+        if (!table::contains(buckets, bucket_index)) {
+            return false
+        };
+        let entries = &table::borrow(buckets, bucket_index).entries;
+        let i = 0;
+        let len = vector::length(entries);
+        while (i < len) {
+            let entry = vector::borrow(entries, i);
+            if (entry.sender == sender && entry.nonce == nonce && entry.txn_expiration_time > now) {
+                return true
+            };
+            i = i + 1;
+        };
+        false
+    }
+
+    #[test_only]
+    fun bucket_size(sender: address, nonce: u64): u64 acquires NonceHistory {
+        let bucket_index = aptos_hash::sip_hash_from_value(&NonceKey { sender, nonce }) % NUM_BUCKETS;
+        let buckets = &borrow_global<NonceHistory>(@aptos_framework).buckets;
+        vector::length(&table::borrow(buckets, bucket_index).entries)
+    }
+
+    #[test(aptos_framework = @aptos_framework)]
+    fun test_nonce_cannot_be_reused_before_expiration(aptos_framework: signer) acquires NonceHistory {
+        timestamp::set_time_has_started_for_testing(&aptos_framework);
+        initialize(&aptos_framework);
+        timestamp::update_global_time_for_test_secs(100);
+
+        assert!(check_and_insert_nonce(@0xcafe, 7, 130), 0);
+        assert!(is_nonce_used(@0xcafe, 7), 1);
+        assert!(!check_and_insert_nonce(@0xcafe, 7, 130), 2);
+        assert!(!check_and_insert_nonce(@0xcafe, 7, 150), 3);
+
+        // The nonce is tracked per sender.
+        assert!(!is_nonce_used(@0xbeef, 7), 4);
+        assert!(check_and_insert_nonce(@0xbeef, 7, 130), 5);
+        assert!(check_and_insert_nonce(@0xcafe, 8, 130), 6);
+    }
+
+    #[test(aptos_framework = @aptos_framework)]
+    fun test_expired_nonces_are_pruned(aptos_framework: signer) acquires NonceHistory {
+        timestamp::set_time_has_started_for_testing(&aptos_framework);
+        initialize(&aptos_framework);
+        timestamp::update_global_time_for_test_secs(100);
+
+        assert!(check_and_insert_nonce(@0xcafe, 7, 130), 0);
+        assert!(bucket_size(@0xcafe, 7) == 1, 1);
+
+        timestamp::update_global_time_for_test_secs(130);
+        assert!(!is_nonce_used(@0xcafe, 7), 2);
+        // Re-inserting drops the expired entry before recording the new one.
+        assert!(check_and_insert_nonce(@0xcafe, 7, 160), 3);
+        assert!(bucket_size(@0xcafe, 7) == 1, 4);
+    }
+
+    #[test(aptos_framework = @aptos_framework)]
+    fun test_nonce_history_is_created_on_first_use(aptos_framework: signer) acquires NonceHistory {
+        timestamp::set_time_has_started_for_testing(&aptos_framework);
+        timestamp::update_global_time_for_test_secs(100);
+        assert!(!exists<NonceHistory>(@aptos_framework), 0);
+        assert!(!is_nonce_used(@0xcafe, 7), 1);
+
+        assert!(check_and_insert_nonce(@0xcafe, 7, 130), 2);
+        assert!(exists<NonceHistory>(@aptos_framework), 3);
+        assert!(is_nonce_used(@0xcafe, 7), 4);
+        assert!(!check_and_insert_nonce(@0xcafe, 7, 130), 5);
+    }
+}
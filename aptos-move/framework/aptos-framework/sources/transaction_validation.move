@@ -11,6 +11,7 @@ module aptos_framework::transaction_validation {
     use aptos_framework::aptos_coin::AptosCoin;
     use aptos_framework::chain_id;
     use aptos_framework::coin;
+    use aptos_framework::nonce_validation;
     use aptos_framework::system_addresses;
     use aptos_framework::timestamp;
     use aptos_framework::transaction_fee;
@@ -49,6 +50,12 @@ module aptos_framework::transaction_validation {
     const PROLOGUE_ESECONDARY_KEYS_ADDRESSES_COUNT_MISMATCH: u64 = 1009;
     const PROLOGUE_EFEE_PAYER_NOT_ENABLED: u64 = 1010;
     const PROLOGUE_EINSUFFICIENT_BALANCE_FOR_REQUIRED_DEPOSIT: u64 = 1011;
+    const PROLOGUE_ENONCE_ALREADY_USED: u64 = 1012;
+    const PROLOGUE_ETRANSACTION_EXPIRATION_TOO_FAR_IN_FUTURE: u64 = 1013;
+
+    /// How far in the future an orderless transaction may expire. This bounds how long its nonce has to be kept in
+    /// `nonce_validation`.
+    const MAX_ORDERLESS_EXPIRATION_TIME_SECS: u64 = 60;
 
     /// Only called during genesis to initialize system resources for this module.
     public(friend) fun initialize(
@@ -159,6 +166,48 @@ module aptos_framework::transaction_validation {
         assert!(balance >= max_transaction_fee, error::invalid_argument(PROLOGUE_ECANT_PAY_GAS_DEPOSIT));
     }
 
+    /// Counterpart of `prologue_common()` for orderless transactions: instead of checking the sender's sequence
+    /// number, records the nonce in `nonce_validation` and rejects the transaction if the nonce was already used.
+    /// The expiration time must be close enough that the nonce history stays small.
+    fun orderless_prologue_common(
+        sender: signer,
+        gas_payer: address,
+        txn_nonce: u64,
+        txn_authentication_key: vector<u8>,
+        txn_gas_price: u64,
+        txn_max_gas_units: u64,
+        txn_expiration_time: u64,
+        chain_id: u8,
+    ) {
+        let now = timestamp::now_seconds();
+        assert!(now < txn_expiration_time, error::invalid_argument(PROLOGUE_ETRANSACTION_EXPIRED));
+        assert!(
+            txn_expiration_time <= now + MAX_ORDERLESS_EXPIRATION_TIME_SECS,
+            error::invalid_argument(PROLOGUE_ETRANSACTION_EXPIRATION_TOO_FAR_IN_FUTURE),
+        );
+        assert!(chain_id::get() == chain_id, error::invalid_argument(PROLOGUE_EBAD_CHAIN_ID));
+
+        let transaction_sender = signer::address_of(&sender);
+        assert!(account::exists_at(transaction_sender), error::invalid_argument(PROLOGUE_EACCOUNT_DOES_NOT_EXIST));
+        assert!(
+            txn_authentication_key == account::get_authentication_key(transaction_sender),
+            error::invalid_argument(PROLOGUE_EINVALID_ACCOUNT_AUTH_KEY),
+        );
+
+        let max_transaction_fee = txn_gas_price * txn_max_gas_units;
+        assert!(
+            coin::is_account_registered<AptosCoin>(gas_payer),
+            error::invalid_argument(PROLOGUE_ECANT_PAY_GAS_DEPOSIT),
+        );
+        let balance = coin::balance<AptosCoin>(gas_payer);
+        assert!(balance >= max_transaction_fee, error::invalid_argument(PROLOGUE_ECANT_PAY_GAS_DEPOSIT));
+
+        assert!(
+            nonce_validation::check_and_insert_nonce(transaction_sender, txn_nonce, txn_expiration_time),
+            error::invalid_argument(PROLOGUE_ENONCE_ALREADY_USED),
+        );
+    }
+
     fun script_prologue(
         sender: signer,
         txn_sequence_number: u64,
@@ -314,6 +363,92 @@ module aptos_framework::transaction_validation {
         collect_deposit(fee_payer_address, required_deposit);
     }
 
+    /// Prologue of single-signer orderless transactions, then collect an optional deposit depending on the txn.
+    fun orderless_script_prologue(
+        sender: signer,
+        txn_nonce: u64,
+        txn_public_key: vector<u8>,
+        txn_gas_price: u64,
+        txn_max_gas_units: u64,
+        txn_expiration_time: u64,
+        chain_id: u8,
+        _script_hash: vector<u8>,
+        required_deposit: Option<u64>,
+    ) {
+        let gas_payer = signer::address_of(&sender);
+        orderless_prologue_common(
+            sender,
+            gas_payer,
+            txn_nonce,
+            txn_public_key,
+            txn_gas_price,
+            txn_max_gas_units,
+            txn_expiration_time,
+            chain_id,
+        );
+        collect_deposit(gas_payer, required_deposit);
+    }
+
+    /// Prologue of multi-agent orderless transactions.
+    fun orderless_multi_agent_script_prologue(
+        sender: signer,
+        txn_nonce: u64,
+        txn_sender_public_key: vector<u8>,
+        secondary_signer_addresses: vector<address>,
+        secondary_signer_public_key_hashes: vector<vector<u8>>,
+        txn_gas_price: u64,
+        txn_max_gas_units: u64,
+        txn_expiration_time: u64,
+        chain_id: u8,
+    ) {
+        let sender_addr = signer::address_of(&sender);
+        orderless_prologue_common(
+            sender,
+            sender_addr,
+            txn_nonce,
+            txn_sender_public_key,
+            txn_gas_price,
+            txn_max_gas_units,
+            txn_expiration_time,
+            chain_id,
+        );
+        multi_agent_common_prologue(secondary_signer_addresses, secondary_signer_public_key_hashes);
+    }
+
+    /// Prologue of fee-payer orderless transactions, then collect an optional deposit depending on the txn.
+    fun orderless_fee_payer_script_prologue(
+        sender: signer,
+        txn_nonce: u64,
+        txn_sender_public_key: vector<u8>,
+        secondary_signer_addresses: vector<address>,
+        secondary_signer_public_key_hashes: vector<vector<u8>>,
+        fee_payer_address: address,
+        fee_payer_public_key_hash: vector<u8>,
+        txn_gas_price: u64,
+        txn_max_gas_units: u64,
+        txn_expiration_time: u64,
+        chain_id: u8,
+        required_deposit: Option<u64>,
+    ) {
+        assert!(features::fee_payer_enabled(), error::invalid_state(PROLOGUE_EFEE_PAYER_NOT_ENABLED));
+        orderless_prologue_common(
+            sender,
+            fee_payer_address,
+            txn_nonce,
+            txn_sender_public_key,
+            txn_gas_price,
+            txn_max_gas_units,
+            txn_expiration_time,
+            chain_id,
+        );
+        multi_agent_common_prologue(secondary_signer_addresses, secondary_signer_public_key_hashes);
+        assert!(
+            fee_payer_public_key_hash == account::get_authentication_key(fee_payer_address),
+            error::invalid_argument(PROLOGUE_EINVALID_ACCOUNT_AUTH_KEY),
+        );
+        collect_deposit(fee_payer_address, required_deposit);
+    }
+
     /// Epilogue function is run after a transaction is successfully executed.
     /// Called by the Adapter
     fun epilogue(
@@ -358,6 +493,21 @@ module aptos_framework::transaction_validation {
         txn_gas_price: u64,
         txn_max_gas_units: u64,
         gas_units_remaining: u64
+    ) {
+        charge_transaction_fee(gas_payer, storage_fee_refunded, txn_gas_price, txn_max_gas_units, gas_units_remaining);
+
+        // Increment sequence number
+        let addr = signer::address_of(&account);
+        account::increment_sequence_number(addr);
+    }
+
+    /// Charges the gas payer for the gas used and settles the storage fee refund.
+    fun charge_transaction_fee(
+        gas_payer: address,
+        storage_fee_refunded: u64,
+        txn_gas_price: u64,
+        txn_max_gas_units: u64,
+        gas_units_remaining: u64
     ) {
         assert!(txn_max_gas_units >= gas_units_remaining, error::invalid_argument(EOUT_OF_GAS));
         let gas_used = txn_max_gas_units - gas_units_remaining;
@@ -396,10 +546,6 @@ module aptos_framework::transaction_validation {
             let mint_amount = storage_fee_refunded - amount_to_burn;
             transaction_fee::mint_and_refund(gas_payer, mint_amount)
         };
-
-        // Increment sequence number
-        let addr = signer::address_of(&account);
-        account::increment_sequence_number(addr);
     }
 
     /// Return the deposit held in prologue to the gas payer, then `epilogue_gas_payer()`.
@@ -424,4 +570,19 @@ module aptos_framework::transaction_validation {
             gas_units_remaining,
         );
     }
+
+    /// Epilogue of orderless transactions: return the deposit held in prologue to the gas payer, then charge the
+    /// transaction fee. Unlike `epilogue_gas_payer()`, the sender's sequence number is left untouched.
+    fun orderless_epilogue(
+        _account: signer,
+        gas_payer: address,
+        storage_fee_refunded: u64,
+        txn_gas_price: u64,
+        txn_max_gas_units: u64,
+        gas_units_remaining: u64,
+        required_deposit: Option<u64>,
+    ) {
+        return_deposit(gas_payer, required_deposit);
+        charge_transaction_fee(gas_payer, storage_fee_refunded, txn_gas_price, txn_max_gas_units, gas_units_remaining);
+    }
 }
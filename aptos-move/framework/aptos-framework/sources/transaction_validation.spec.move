@@ -358,4 +358,28 @@ spec aptos_framework::transaction_validation {
     spec epilogue_return_deposit {
         pragma verify = false;
     }
+
+    spec orderless_prologue_common {
+        pragma verify = false;
+    }
+
+    spec orderless_script_prologue {
+        pragma verify = false;
+    }
+
+    spec orderless_multi_agent_script_prologue {
+        pragma verify = false;
+    }
+
+    spec orderless_fee_payer_script_prologue {
+        pragma verify = false;
+    }
+
+    spec charge_transaction_fee {
+        pragma verify = false;
+    }
+
+    spec orderless_epilogue {
+        pragma verify = false;
+    }
 }
@@ -627,7 +627,7 @@ module aptos_std::bls12381_algebra {
     }
 
     #[test_only]
-    use aptos_std::crypto_algebra::{zero, one, from_u64, eq, deserialize, serialize, neg, add, sub, mul, div, inv, rand_insecure, sqr, order, scalar_mul, multi_scalar_mul, double, hash_to, upcast, enable_cryptography_algebra_natives, pairing, multi_pairing, downcast, Element};
+    use aptos_std::crypto_algebra::{zero, one, from_u64, eq, deserialize, serialize, neg, add, sub, mul, div, inv, rand_insecure, sqr, order, scalar_mul, multi_scalar_mul, double, hash_to, upcast, enable_cryptography_algebra_natives, pairing, multi_pairing, downcast, groth16_verify_proof_prepared, Element};
 
     #[test_only]
     const FR_VAL_0_SERIALIZED_LSB: vector<u8> = x"0000000000000000000000000000000000000000000000000000000000000000";
@@ -770,6 +770,95 @@ module aptos_std::bls12381_algebra {
         multi_scalar_mul(&elements, &scalars);
     }
 
+    #[test_only]
+    /// A BLS12-381 Groth16 MiMC proof and its prepared verification key, sampled from
+    /// https://github.com/arkworks-rs/groth16/blob/b6f9166bcf15ff4bfe101bb34e1bdc0d92302e37/tests/mimc.rs#L147.
+    const GROTH16_MIMC_PVK_ALPHA_G1_BETA_G2: vector<u8> = x"15cee98b42f8d158f421bce13983e23597123817a3b19b006294b9145f3f382686706ad9161d6234661fb1a32da19d0e2a9e672901fe4abe9efd4da96bcdb8324459b93aa48a8abb92ddd28ef053f118e190eddd6c6212bc09428ea05e709104290e37f320a3aac1dcf96f66efd9f5826b69cd075b72801ef54ccb740a0947bb3f73174e5d2fdc04292f58841ad9cc0d0c25021dfd8d592943b5e61c97f1ba68dcabd7de970ecc347c04bbaf9a062d9d49476f0b5bc77b2b9c7222781c53b713c0aae7a4cc57ff8cfb433d27fb1328d0c5453dbb97f3a70e9ce3b1da52cee2047cad225410b6dacb28e7b6876795d005cf0aefb7f25350d0197a5c2aa7369a5e06a210580bba1cc1941e1871a465cf68c84f32a29e6e898e4961a2b1fd5f8f03f03b1e1a0e191becdc8f01fb15adeb7cb6cc39e686edfcf7d65e952cf5e19a477fb5f6d2dab61a4d6c07777c1842150646c8b6fcb5989d9e524a97e7bf8b7be6b12983205970f16aeaccbdbe6cd565fa570dc45b0ad8f51c46e1f05e9f3f230dcf7567db5fc9a59a55c39139c7b357103c26bca9b70032cccff2345b76f596901ea81dc28f1d490a129501cf02204e00e8b59770188d69379144629239933523a8ec71ce6f91fbd01b2b9c411f89948183fea3949d89919e239a4aadb2347803e97ae8f7f20ade26da001f803cd61eb9bf8a67356f7cf6ec1744720b078eb992529f5c219bf16d5ef2e233a04572730e7c9572eadd9aa63c69c9f7dcf3423b1dc4c9b2032c8a7bbe91505283163a85413ecf0a0095fe1899b29f60011226f009";
+    #[test_only]
+    const GROTH16_MIMC_PVK_GAMMA_G2_NEG: vector<u8> = x"b6750d8445596af8d679487c7267ae9734aeac584ace191d225680a18ecff8ebae6dd6a5fd68e4414b1611164904ee120363c2b49f33a873d6cfc26249b66327a0de03e673b8139f79809e8b641586cde9943fa072ee5ed701c81b3fd426c220";
+    #[test_only]
+    const GROTH16_MIMC_PVK_DELTA_G2_NEG: vector<u8> = x"ad3ac832f2508af6f01872ada87ea66d2fb5b099d34c5bac81e7482c956276dfc234c8d2af5fd2394b5440d0708a2c9f124a53c0755e9595cf9f8adade5deefcb8a574a67debd3b74d08c49c23ddc14cd6d48b65dce500c8a5d330e760fe85bb";
+    #[test_only]
+    const GROTH16_MIMC_PVK_UVW_GAMMA_G1_0: vector<u8> = x"b0df760d0f2d67fdff69d0ed3a0653dd8808df3c407ea4d0e27f8612c3fbb748cb4372d33cac512ee5ef4ee1683c3fe5";
+    #[test_only]
+    const GROTH16_MIMC_PVK_UVW_GAMMA_G1_1: vector<u8> = x"96ec80d6b1050bbfc209f727678acce8788c05475771daffdd444ad8786c7a40195d859850fe2e72be3054e9fb8ce805";
+    #[test_only]
+    const GROTH16_MIMC_PUBLIC_INPUT: vector<u8> = x"0ee291cfc951388c3c7f7c85ff2dfd42bbc66a6b4acaef9a5a51ce955125a74f";
+    #[test_only]
+    const GROTH16_MIMC_PROOF_A: vector<u8> = x"8a7c7364403d91bfa5c723ce93b920c8d2e559ea5e7e34eb68cea437aa4f26bf56ba22d9400988a86f2943c79401e959";
+    #[test_only]
+    const GROTH16_MIMC_PROOF_B: vector<u8> = x"9352f8a2f9ff60d390e363d063354e9728adf39c91294499575855e803dd80eeaa1488cd24d1b80eb1b2625011e22a5d139e24f2c7ac3508874ec4bdb9c71ddf109e7853d641d23ed27bef265248d78eabe9137c03b088d8adbdf39e10f87eab";
+    #[test_only]
+    const GROTH16_MIMC_PROOF_C: vector<u8> = x"896f68b438e076d3017e64aa47621fcd69b45f49a7038e2b1b9ed4f2de9b8eb8e0a76785a39a08f024435811a73a6818";
+
+    #[test(fx = @std)]
+    fun test_groth16_verify_proof_prepared(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        let pvk_alpha_g1_beta_g2 = std::option::extract(&mut deserialize<Gt, FormatGt>(&GROTH16_MIMC_PVK_ALPHA_G1_BETA_G2));
+        let pvk_gamma_g2_neg = std::option::extract(&mut deserialize<G2, FormatG2Compr>(&GROTH16_MIMC_PVK_GAMMA_G2_NEG));
+        let pvk_delta_g2_neg = std::option::extract(&mut deserialize<G2, FormatG2Compr>(&GROTH16_MIMC_PVK_DELTA_G2_NEG));
+        let pvk_uvw_gamma_g1 = vector[
+            std::option::extract(&mut deserialize<G1, FormatG1Compr>(&GROTH16_MIMC_PVK_UVW_GAMMA_G1_0)),
+            std::option::extract(&mut deserialize<G1, FormatG1Compr>(&GROTH16_MIMC_PVK_UVW_GAMMA_G1_1)),
+        ];
+        let public_input = std::option::extract(&mut deserialize<Fr, FormatFrLsb>(&GROTH16_MIMC_PUBLIC_INPUT));
+        let proof_a = std::option::extract(&mut deserialize<G1, FormatG1Compr>(&GROTH16_MIMC_PROOF_A));
+        let proof_b = std::option::extract(&mut deserialize<G2, FormatG2Compr>(&GROTH16_MIMC_PROOF_B));
+        let proof_c = std::option::extract(&mut deserialize<G1, FormatG1Compr>(&GROTH16_MIMC_PROOF_C));
+
+        assert!(groth16_verify_proof_prepared<G1, G2, Gt, Fr>(
+            &pvk_alpha_g1_beta_g2,
+            &pvk_gamma_g2_neg,
+            &pvk_delta_g2_neg,
+            &pvk_uvw_gamma_g1,
+            &vector[public_input],
+            &proof_a,
+            &proof_b,
+            &proof_c,
+        ), 1);
+
+        // A different public input should be rejected.
+        assert!(!groth16_verify_proof_prepared<G1, G2, Gt, Fr>(
+            &pvk_alpha_g1_beta_g2,
+            &pvk_gamma_g2_neg,
+            &pvk_delta_g2_neg,
+            &pvk_uvw_gamma_g1,
+            &vector[add(&public_input, &one<Fr>())],
+            &proof_a,
+            &proof_b,
+            &proof_c,
+        ), 1);
+
+        // So should a tampered proof.
+        assert!(!groth16_verify_proof_prepared<G1, G2, Gt, Fr>(
+            &pvk_alpha_g1_beta_g2,
+            &pvk_gamma_g2_neg,
+            &pvk_delta_g2_neg,
+            &pvk_uvw_gamma_g1,
+            &vector[public_input],
+            &proof_c,
+            &proof_b,
+            &proof_a,
+        ), 1);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010002, location = aptos_std::crypto_algebra)]
+    fun test_groth16_verify_proof_prepared_should_abort_when_sizes_mismatch(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        groth16_verify_proof_prepared<G1, G2, Gt, Fr>(
+            &rand_insecure<Gt>(),
+            &rand_insecure<G2>(),
+            &rand_insecure<G2>(),
+            &vector[rand_insecure<G1>()],
+            &vector[rand_insecure<Fr>()],
+            &rand_insecure<G1>(),
+            &rand_insecure<G2>(),
+            &rand_insecure<G1>(),
+        );
+    }
+
     #[test_only]
     /// The maximum number of `G1` elements that can be created in a transaction,
     /// calculated by the current memory limit (1MB) and the in-mem G1 representation size (144 bytes per element).
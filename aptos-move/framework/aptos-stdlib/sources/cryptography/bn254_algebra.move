@@ -606,7 +606,7 @@ module std::bn254_algebra {
     }
 
     #[test_only]
-    use aptos_std::crypto_algebra::{zero, one, from_u64, eq, deserialize, serialize, neg, add, sub, mul, div, inv, rand_insecure, sqr, order, scalar_mul, multi_scalar_mul, double, upcast, enable_cryptography_algebra_natives, pairing, multi_pairing, downcast, Element};
+    use aptos_std::crypto_algebra::{zero, one, from_u64, eq, deserialize, serialize, neg, add, sub, mul, div, inv, rand_insecure, sqr, order, scalar_mul, multi_scalar_mul, double, upcast, enable_cryptography_algebra_natives, pairing, multi_pairing, downcast, groth16_verify_proof_prepared, Element};
 
     #[test_only]
     const FR_VAL_0_SERIALIZED_LSB: vector<u8> = x"0000000000000000000000000000000000000000000000000000000000000000";
@@ -822,6 +822,74 @@ module std::bn254_algebra {
         multi_scalar_mul(&elements, &scalars);
     }
 
+    #[test(fx = @std)]
+    fun test_groth16_verify_proof_prepared(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+
+        // Build a verification key and a proof with known discrete logs,
+        // such that `e(A,B) == e(alpha,beta) + e(u0+x*u1,gamma) + e(C,delta)` holds.
+        let g1 = one<G1>();
+        let g2 = one<G2>();
+        let alpha = rand_insecure<Fr>();
+        let beta = rand_insecure<Fr>();
+        let gamma = rand_insecure<Fr>();
+        let delta = rand_insecure<Fr>();
+        let u0 = rand_insecure<Fr>();
+        let u1 = rand_insecure<Fr>();
+        let x = rand_insecure<Fr>();
+        let a = rand_insecure<Fr>();
+        let b = rand_insecure<Fr>();
+        let s = add(&u0, &mul(&x, &u1));
+        let c = std::option::extract(&mut div(&sub(&sub(&mul(&a, &b), &mul(&alpha, &beta)), &mul(&s, &gamma)), &delta));
+
+        let pvk_alpha_g1_beta_g2 = pairing<G1, G2, Gt>(&scalar_mul(&g1, &alpha), &scalar_mul(&g2, &beta));
+        let pvk_gamma_g2_neg = neg(&scalar_mul(&g2, &gamma));
+        let pvk_delta_g2_neg = neg(&scalar_mul(&g2, &delta));
+        let pvk_uvw_gamma_g1 = vector[scalar_mul(&g1, &u0), scalar_mul(&g1, &u1)];
+        let proof_a = scalar_mul(&g1, &a);
+        let proof_b = scalar_mul(&g2, &b);
+        let proof_c = scalar_mul(&g1, &c);
+
+        assert!(groth16_verify_proof_prepared<G1, G2, Gt, Fr>(
+            &pvk_alpha_g1_beta_g2,
+            &pvk_gamma_g2_neg,
+            &pvk_delta_g2_neg,
+            &pvk_uvw_gamma_g1,
+            &vector[x],
+            &proof_a,
+            &proof_b,
+            &proof_c,
+        ), 1);
+
+        // A different public input should be rejected.
+        assert!(!groth16_verify_proof_prepared<G1, G2, Gt, Fr>(
+            &pvk_alpha_g1_beta_g2,
+            &pvk_gamma_g2_neg,
+            &pvk_delta_g2_neg,
+            &pvk_uvw_gamma_g1,
+            &vector[add(&x, &one<Fr>())],
+            &proof_a,
+            &proof_b,
+            &proof_c,
+        ), 1);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010002, location = aptos_std::crypto_algebra)]
+    fun test_groth16_verify_proof_prepared_should_abort_when_sizes_mismatch(fx: signer) {
+        enable_cryptography_algebra_natives(&fx);
+        groth16_verify_proof_prepared<G1, G2, Gt, Fr>(
+            &rand_insecure<Gt>(),
+            &rand_insecure<G2>(),
+            &rand_insecure<G2>(),
+            &vector[rand_insecure<G1>()],
+            &vector[rand_insecure<Fr>()],
+            &rand_insecure<G1>(),
+            &rand_insecure<G2>(),
+            &rand_insecure<G1>(),
+        );
+    }
+
     #[test_only]
     /// The maximum number of `G1` elements that can be created in a transaction,
     /// calculated by the current memory limit (1MB) and the in-mem G1 representation size (96 bytes per element).
@@ -29,6 +29,7 @@
 /// - `from_u64()` for quick conversion from u64 to field element.
 ///
 /// For 3 groups that admit a bilinear map, `pairing()` and `multi_pairing()` may be implemented.
+/// For such a pairing and the scalar field `S` of `G1`, `groth16_verify_proof_prepared()` may also be implemented.
 ///
 /// For a subset/superset relationship between 2 structures, `upcast()` and `downcast()` may be implemented.
 /// E.g., in BLS12-381 pairing, since `Gt` is a subset of `Fq12`,
@@ -205,6 +206,39 @@ module aptos_std::crypto_algebra {
         }
     }
 
+    /// Verify a Groth16 proof (https://eprint.iacr.org/2016/260.pdf, Section 3.2) against a prepared verification key,
+    /// with the multi-scalar multiplication and the multi-pairing done in a single native call.
+    /// Below are the full input (in the original paper notations).
+    /// - Prepared verification key: $\left([\alpha]_1 \cdot [\beta]_2, -[\gamma]_2, -[\delta]_2, \left\\{ \left[ \frac{\beta \cdot u_i(x) + \alpha \cdot v_i(x) + w_i(x)}{\gamma} \right]_1 \right\\}\_{i=0}^l \right)$.
+    /// - Public inputs: $\\{a_i\\}_{i=1}^l$.
+    /// - Proof: $\left( \left[ A \right]_1, \left[ B \right]_2, \left[ C \right]_1 \right)$.
+    ///
+    /// Abort with code `std::error::invalid_argument(E_NON_EQUAL_LENGTHS)` if the size of `pvk_uvw_gamma_g1` is not the size of `public_inputs` plus 1.
+    ///
+    /// NOTE: currently supported `(G1,G2,Gt,S)` combinations are the ones of BLS12-381 and BN254.
+    public fun groth16_verify_proof_prepared<G1,G2,Gt,S>(
+        pvk_alpha_g1_beta_g2: &Element<Gt>,
+        pvk_gamma_g2_neg: &Element<G2>,
+        pvk_delta_g2_neg: &Element<G2>,
+        pvk_uvw_gamma_g1: &vector<Element<G1>>,
+        public_inputs: &vector<Element<S>>,
+        proof_a: &Element<G1>,
+        proof_b: &Element<G2>,
+        proof_c: &Element<G1>,
+    ): bool {
+        abort_unless_cryptography_algebra_natives_enabled();
+        groth16_verify_proof_prepared_internal<G1,G2,Gt,S>(
+            pvk_alpha_g1_beta_g2.handle,
+            pvk_gamma_g2_neg.handle,
+            pvk_delta_g2_neg.handle,
+            handles_from_elements(pvk_uvw_gamma_g1),
+            handles_from_elements(public_inputs),
+            proof_a.handle,
+            proof_b.handle,
+            proof_c.handle,
+        )
+    }
+
     /// Try deserializing a byte array to an element of an algebraic structure `S` using a given serialization format `F`.
     /// Return none if the deserialization failed.
     public fun deserialize<S, F>(bytes: &vector<u8>): Option<Element<S>> {
@@ -314,6 +348,16 @@ module aptos_std::crypto_algebra {
     native fun double_internal<G>(element_handle: u64): u64;
     native fun downcast_internal<L,S>(handle: u64): (bool, u64);
     native fun from_u64_internal<S>(value: u64): u64;
+    native fun groth16_verify_proof_prepared_internal<G1,G2,Gt,S>(
+        pvk_alpha_g1_beta_g2_handle: u64,
+        pvk_gamma_g2_neg_handle: u64,
+        pvk_delta_g2_neg_handle: u64,
+        pvk_uvw_gamma_g1_handles: vector<u64>,
+        public_input_handles: vector<u64>,
+        proof_a_handle: u64,
+        proof_b_handle: u64,
+        proof_c_handle: u64,
+    ): bool;
     native fun eq_internal<S>(handle_1: u64, handle_2: u64): bool;
     native fun hash_to_internal<S, H>(dst: &vector<u8>, bytes: &vector<u8>): u64;
     native fun inv_internal<F>(handle: u64): (bool, u64);
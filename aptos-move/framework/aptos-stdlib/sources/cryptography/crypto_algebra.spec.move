@@ -29,6 +29,19 @@ spec aptos_std::crypto_algebra {
         pragma opaque;
     }
 
+    spec groth16_verify_proof_prepared_internal<G1,G2,Gt,S>(
+        pvk_alpha_g1_beta_g2_handle: u64,
+        pvk_gamma_g2_neg_handle: u64,
+        pvk_delta_g2_neg_handle: u64,
+        pvk_uvw_gamma_g1_handles: vector<u64>,
+        public_input_handles: vector<u64>,
+        proof_a_handle: u64,
+        proof_b_handle: u64,
+        proof_c_handle: u64,
+    ): bool {
+        pragma opaque;
+    }
+
     spec eq_internal<S>(handle_1: u64, handle_2: u64): bool {
         pragma opaque;
     }
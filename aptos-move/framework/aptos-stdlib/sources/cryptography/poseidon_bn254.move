@@ -0,0 +1,85 @@
+/// This module implements the Poseidon hash function over the scalar field of the BN254 curve, with the same
+/// parameters as `circomlib`, which makes it suitable for computing commitments that are later opened inside
+/// Groth16 circuits.
+///
+/// Every input and the output are elements of the BN254 scalar field $\mathbb{F}_r$, encoded as 32 bytes in
+/// little-endian order. Inputs must be canonical (i.e., strictly smaller than $r$).
+module aptos_std::poseidon_bn254 {
+    use std::error;
+    use std::features;
+    use std::vector;
+
+    /// One of the inputs is not a canonical 32-byte encoding of a BN254 scalar.
+    const E_DESERIALIZE: u64 = 1;
+
+    /// The number of inputs is not between 1 and `MAX_NUM_INPUTS`.
+    const E_WRONG_NUM_INPUTS: u64 = 2;
+
+    /// The native functions have not been rolled out yet.
+    const E_NATIVE_FUN_NOT_AVAILABLE: u64 = 3;
+
+    /// The maximum number of scalars that can be hashed at once.
+    const MAX_NUM_INPUTS: u64 = 16;
+
+    /// Hashes a vector of 1 to `MAX_NUM_INPUTS` scalars into a single scalar.
+    public fun hash(inputs: vector<vector<u8>>): vector<u8> {
+        assert!(features::poseidon_bn254_natives_enabled(), error::invalid_state(E_NATIVE_FUN_NOT_AVAILABLE));
+        let num_inputs = vector::length(&inputs);
+        assert!(num_inputs > 0 && num_inputs <= MAX_NUM_INPUTS, error::invalid_argument(E_WRONG_NUM_INPUTS));
+        hash_internal(inputs)
+    }
+
+    //
+    // Native functions
+    //
+
+    /// Aborts with `E_DESERIALIZE` if any of the inputs is not a canonical scalar.
+    native fun hash_internal(inputs: vector<vector<u8>>): vector<u8>;
+
+    //
+    // Tests
+    //
+
+    #[test(fx = @std)]
+    fun test_hash(fx: signer) {
+        features::change_feature_flags_for_testing(&fx, vector[ features::get_poseidon_bn254_natives_feature() ], vector[]);
+
+        // Matches `poseidon([1, 2])` from circomlibjs, i.e., 0x115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a
+        let h = hash(vector[
+            x"0100000000000000000000000000000000000000000000000000000000000000",
+            x"0200000000000000000000000000000000000000000000000000000000000000",
+        ]);
+        assert!(h == x"9a1817447a60199e51453274f217362acfe962966b4cf63d4190d6e7f5c05c11", 1);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010001, location = Self)]
+    fun test_non_canonical_input(fx: signer) {
+        features::change_feature_flags_for_testing(&fx, vector[ features::get_poseidon_bn254_natives_feature() ], vector[]);
+
+        // The scalar field order r, which is not a canonical encoding
+        hash(vector[ x"010000f093f5e1439170b97948e833285d588181b64550b829a031e1724e6430" ]);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010001, location = Self)]
+    fun test_wrong_input_size(fx: signer) {
+        features::change_feature_flags_for_testing(&fx, vector[ features::get_poseidon_bn254_natives_feature() ], vector[]);
+
+        hash(vector[ x"01" ]);
+    }
+
+    #[test(fx = @std)]
+    #[expected_failure(abort_code = 0x010002, location = Self)]
+    fun test_no_inputs(fx: signer) {
+        features::change_feature_flags_for_testing(&fx, vector[ features::get_poseidon_bn254_natives_feature() ], vector[]);
+
+        hash(vector[]);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x030003, location = Self)]
+    fun test_hash_feature_disabled() {
+        hash(vector[ x"0100000000000000000000000000000000000000000000000000000000000000" ]);
+    }
+}
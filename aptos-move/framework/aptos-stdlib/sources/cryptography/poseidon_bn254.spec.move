@@ -0,0 +1,21 @@
+spec aptos_std::poseidon_bn254 {
+    spec module {
+        /// `spec_hash_internal` models the native Poseidon hash; it is not assumed to be injective.
+        fun spec_hash_internal(inputs: vector<vector<u8>>): vector<u8>;
+    }
+
+    spec hash(inputs: vector<vector<u8>>): vector<u8> {
+        pragma aborts_if_is_partial;
+        aborts_if !features::spec_is_enabled(features::POSEIDON_BN254_NATIVES);
+        aborts_if len(inputs) == 0 || len(inputs) > MAX_NUM_INPUTS;
+        ensures result == spec_hash_internal(inputs);
+    }
+
+    /// The native function aborts with `E_DESERIALIZE` on a non-canonical input, which the prover does not
+    /// model, hence `aborts_if_is_partial`.
+    spec hash_internal(inputs: vector<vector<u8>>): vector<u8> {
+        pragma opaque;
+        pragma aborts_if_is_partial;
+        ensures [abstract] result == spec_hash_internal(inputs);
+    }
+}
@@ -0,0 +1,129 @@
+/// This module implements ECDSA signature verification over the NIST P-256 elliptic curve (a.k.a., secp256r1 or
+/// prime256v1), which is the curve used by WebAuthn passkeys and most secure enclaves.
+///
+/// Messages are hashed with SHA2-256 before being verified, as per the ES256 algorithm. To prevent signature
+/// malleability, only signatures $(r, s)$ with $s \le n/2$ are accepted, where $n$ is the order of the curve.
+module aptos_std::secp256r1 {
+    use std::error;
+    use std::features;
+
+    /// An error occurred while deserializing, for example due to wrong input size.
+    const E_DESERIALIZE: u64 = 1;
+
+    /// The native functions have not been rolled out yet.
+    const E_NATIVE_FUN_NOT_AVAILABLE: u64 = 2;
+
+    /// The size of a secp256r1-based ECDSA raw public key, in bytes.
+    const RAW_PUBLIC_KEY_NUM_BYTES: u64 = 64;
+
+    /// The size of a secp256r1-based ECDSA signature, in bytes.
+    const SIGNATURE_NUM_BYTES: u64 = 64;
+
+    /// A 64-byte ECDSA public key, consisting of the big-endian encodings of the $x$ and $y$ coordinates of the point
+    /// (i.e., the uncompressed SEC1 encoding, without the leading `0x04` byte).
+    struct ECDSARawPublicKey has copy, drop, store {
+        bytes: vector<u8>
+    }
+
+    /// A 64-byte ECDSA signature, consisting of the big-endian encodings of $r$ and $s$.
+    struct ECDSASignature has copy, drop, store {
+        bytes: vector<u8>
+    }
+
+    /// Constructs an ECDSASignature struct from the given 64 bytes.
+    public fun ecdsa_signature_from_bytes(bytes: vector<u8>): ECDSASignature {
+        assert!(std::vector::length(&bytes) == SIGNATURE_NUM_BYTES, error::invalid_argument(E_DESERIALIZE));
+        ECDSASignature { bytes }
+    }
+
+    /// Constructs an ECDSARawPublicKey struct, given a 64-byte raw representation.
+    public fun ecdsa_raw_public_key_from_64_bytes(bytes: vector<u8>): ECDSARawPublicKey {
+        assert!(std::vector::length(&bytes) == RAW_PUBLIC_KEY_NUM_BYTES, error::invalid_argument(E_DESERIALIZE));
+        ECDSARawPublicKey { bytes }
+    }
+
+    /// Serializes an ECDSARawPublicKey struct to 64-bytes.
+    public fun ecdsa_raw_public_key_to_bytes(pk: &ECDSARawPublicKey): vector<u8> {
+        pk.bytes
+    }
+
+    /// Serializes an ECDSASignature struct to 64-bytes.
+    public fun ecdsa_signature_to_bytes(sig: &ECDSASignature): vector<u8> {
+        sig.bytes
+    }
+
+    /// Returns `true` if `signature` verifies on the SHA2-256 hash of `message` under `public_key`.
+    ///
+    /// Returns `false` if the public key is not a point on the curve or if the signature is malleable (i.e., its $s$
+    /// is larger than half the order of the curve).
+    public fun ecdsa_verify(
+        message: vector<u8>,
+        public_key: &ECDSARawPublicKey,
+        signature: &ECDSASignature,
+    ): bool {
+        assert!(features::secp256r1_natives_enabled(), error::invalid_state(E_NATIVE_FUN_NOT_AVAILABLE));
+        ecdsa_verify_internal(message, public_key.bytes, signature.bytes)
+    }
+
+    //
+    // Native functions
+    //
+
+    /// Returns `true` if `signature` verifies on `message` under the 64-byte raw `public_key` and `false` otherwise.
+    native fun ecdsa_verify_internal(
+        message: vector<u8>,
+        public_key: vector<u8>,
+        signature: vector<u8>
+    ): bool;
+
+    //
+    // Tests
+    //
+
+    #[test_only]
+    /// The public key for sk = x"0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"
+    const TEST_PK: vector<u8> = x"d8cd12ea5c67f2f8a00c1124893edcfa6754c4d6cede6be13bdf2295c810a97fa5a89d2d2a360c0ca9a4d6c7c9ed4b28d3e199d6627f2e696d689c310a5b0f48";
+
+    #[test_only]
+    /// A signature on b"test aptos secp256r1" under `TEST_PK`
+    const TEST_SIG: vector<u8> = x"ec3100bb741104b24e8f5e7e6f002b18e6176f64119891cf05c13d46ace6424900c81326a8dc707d360745a6dd5413739e35276929a79c2f1a0f518a06d8ce81";
+
+    #[test_only]
+    /// The malleated (high-s) version of `TEST_SIG`
+    const TEST_SIG_HIGH_S: vector<u8> = x"ec3100bb741104b24e8f5e7e6f002b18e6176f64119891cf05c13d46ace64249ff37ecd857238f83c9f8ba5922abec8c1eb1d3447d700255d9aa7938f58a56d0";
+
+    #[test(fx = @std)]
+    fun test_ecdsa_verify(fx: signer) {
+        features::change_feature_flags_for_testing(&fx, vector[ features::get_secp256r1_natives_feature() ], vector[]);
+
+        let pk = ecdsa_raw_public_key_from_64_bytes(TEST_PK);
+        let sig = ecdsa_signature_from_bytes(TEST_SIG);
+        assert!(ecdsa_verify(b"test aptos secp256r1", &pk, &sig), 1);
+
+        // Wrong message
+        assert!(!ecdsa_verify(b"test aptos secp256k1", &pk, &sig), 2);
+
+        // Malleated signature
+        let sig = ecdsa_signature_from_bytes(TEST_SIG_HIGH_S);
+        assert!(!ecdsa_verify(b"test aptos secp256r1", &pk, &sig), 3);
+
+        // Public key not on the curve
+        let pk = ECDSARawPublicKey { bytes: x"d8cd12ea5c67f2f8a00c1124893edcfa6754c4d6cede6be13bdf2295c810a97fa5a89d2d2a360c0ca9a4d6c7c9ed4b28d3e199d6627f2e696d689c310a5b0f49" };
+        let sig = ecdsa_signature_from_bytes(TEST_SIG);
+        assert!(!ecdsa_verify(b"test aptos secp256r1", &pk, &sig), 4);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x010001, location = Self)]
+    fun test_wrong_signature_size() {
+        ecdsa_signature_from_bytes(x"00");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 0x030002, location = Self)]
+    fun test_ecdsa_verify_feature_disabled() {
+        let pk = ecdsa_raw_public_key_from_64_bytes(TEST_PK);
+        let sig = ecdsa_signature_from_bytes(TEST_SIG);
+        ecdsa_verify(b"test aptos secp256r1", &pk, &sig);
+    }
+}
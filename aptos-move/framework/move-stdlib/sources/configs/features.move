@@ -476,6 +476,52 @@ module std::features {
         is_enabled(MODULE_EVENT_MIGRATION)
     }
 
+    /// Whether transactions can be signed with raw secp256r1 (P-256) ECDSA signatures, via the
+    /// `Secp256r1Ecdsa` variant of `AnySignature`.
+    ///
+    /// Lifetime: transient
+    const SECP256R1_ECDSA_SIGNATURE: u64 = 59;
+
+    public fun get_secp256r1_ecdsa_signature_feature(): u64 { SECP256R1_ECDSA_SIGNATURE }
+
+    public fun secp256r1_ecdsa_signature_enabled(): bool acquires Features {
+        is_enabled(SECP256R1_ECDSA_SIGNATURE)
+    }
+
+    /// Whether the secp256r1 (P-256) ECDSA verification natives in `aptos_std::secp256r1` are enabled.
+    ///
+    /// Lifetime: transient
+    const SECP256R1_NATIVES: u64 = 60;
+
+    public fun get_secp256r1_natives_feature(): u64 { SECP256R1_NATIVES }
+
+    public fun secp256r1_natives_enabled(): bool acquires Features {
+        is_enabled(SECP256R1_NATIVES)
+    }
+
+    /// Whether the Poseidon-BN254 hashing natives in `aptos_std::poseidon_bn254` are enabled.
+    ///
+    /// Lifetime: transient
+    const POSEIDON_BN254_NATIVES: u64 = 61;
+
+    public fun get_poseidon_bn254_natives_feature(): u64 { POSEIDON_BN254_NATIVES }
+
+    public fun poseidon_bn254_natives_enabled(): bool acquires Features {
+        is_enabled(POSEIDON_BN254_NATIVES)
+    }
+
+    /// Whether orderless transactions, which are protected against replay by a nonce instead of the
+    /// sender's sequence number, are accepted.
+    ///
+    /// Lifetime: transient
+    const ORDERLESS_TRANSACTIONS: u64 = 62;
+
+    public fun get_orderless_transactions_feature(): u64 { ORDERLESS_TRANSACTIONS }
+
+    public fun orderless_transactions_enabled(): bool acquires Features {
+        is_enabled(ORDERLESS_TRANSACTIONS)
+    }
+
     // ============================================================================================
     // Feature Flag Implementation
 
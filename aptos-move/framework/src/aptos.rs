@@ -118,12 +118,16 @@ impl ReleaseTarget {
                     references_file: Some("doc_template/references.md".to_string()),
                 }),
                 skip_fetch_latest_git_deps: true,
+                locked: false,
+                vendored: false,
+                update_lock_file: false,
                 bytecode_version: None,
                 compiler_version: None,
                 language_version: None,
                 skip_attribute_checks: false,
                 check_test_code: false,
                 known_attributes: extended_checks::get_all_attribute_names().clone(),
+                experiments: vec![],
             },
             packages: packages.iter().map(|(path, _)| path.to_owned()).collect(),
             rust_bindings: packages
@@ -77,6 +77,18 @@ pub struct BuildOptions {
     pub docgen_options: Option<DocgenOptions>,
     #[clap(long)]
     pub skip_fetch_latest_git_deps: bool,
+    /// Require the lock file `Move.lock` to be up to date with the resolved dependencies
+    #[clap(long)]
+    #[serde(default)]
+    pub locked: bool,
+    /// Resolve git and on-chain dependencies from the `vendor` directory of the package
+    #[clap(long)]
+    #[serde(default)]
+    pub vendored: bool,
+    /// Write the resolved dependencies to the lock file `Move.lock`
+    #[clap(skip)]
+    #[serde(default)]
+    pub update_lock_file: bool,
     #[clap(long)]
     pub bytecode_version: Option<u32>,
     #[clap(long, value_parser = clap::value_parser!(CompilerVersion))]
@@ -89,6 +101,9 @@ pub struct BuildOptions {
     pub check_test_code: bool,
     #[clap(skip)]
     pub known_attributes: BTreeSet<String>,
+    /// Experiments for compiler v2, as in `name[=on/off]`
+    #[clap(skip)]
+    pub experiments: Vec<String>,
 }
 
 // Because named_addresses has no parser, we can't use clap's default impl. This must be aligned
@@ -108,12 +123,16 @@ impl Default for BuildOptions {
             // This is false by default, because it could accidentally pull new dependencies
             // while in a test (and cause some havoc)
             skip_fetch_latest_git_deps: false,
+            locked: false,
+            vendored: false,
+            update_lock_file: false,
             bytecode_version: None,
             compiler_version: None,
             language_version: None,
             skip_attribute_checks: false,
             check_test_code: false,
             known_attributes: extended_checks::get_all_attribute_names().clone(),
+            experiments: vec![],
         }
     }
 }
@@ -150,12 +169,16 @@ pub fn build_model(
         force_recompilation: false,
         fetch_deps_only: false,
         skip_fetch_latest_git_deps: true,
+        locked: false,
+        vendored: false,
+        update_lock_file: false,
         compiler_config: CompilerConfig {
             bytecode_version,
             compiler_version,
             language_version,
             skip_attribute_checks,
             known_attributes,
+            experiments: vec![],
         },
     };
     let compiler_version = compiler_version.unwrap_or_default();
@@ -193,12 +216,16 @@ impl BuiltPackage {
             force_recompilation: false,
             fetch_deps_only: false,
             skip_fetch_latest_git_deps: options.skip_fetch_latest_git_deps,
+            locked: options.locked,
+            vendored: options.vendored,
+            update_lock_file: options.update_lock_file,
             compiler_config: CompilerConfig {
                 bytecode_version,
                 compiler_version,
                 language_version,
                 skip_attribute_checks,
                 known_attributes: options.known_attributes.clone(),
+                experiments: options.experiments.clone(),
             },
         };
 
@@ -0,0 +1,171 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    abort_unless_feature_flag_enabled,
+    natives::cryptography::algebra::{
+        abort_invariant_violated, feature_flag_from_structure, AlgebraContext, Structure,
+        MOVE_ABORT_CODE_INPUT_VECTOR_SIZES_NOT_MATCHING, MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+    },
+    safe_borrow_element, structure_from_ty_arg,
+};
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_pop_arg, SafeNativeContext, SafeNativeError, SafeNativeResult,
+};
+use ark_ec::{pairing::Pairing, CurveGroup, VariableBaseMSM};
+use ark_ff::One;
+use move_core_types::gas_algebra::NumArgs;
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use smallvec::{smallvec, SmallVec};
+use std::collections::VecDeque;
+
+/// Verifies a Groth16 proof against a prepared verification key, doing the MSM over the public
+/// inputs, the 3-pair multi-pairing and the final `Gt` comparison without returning to Move.
+///
+/// Charged upfront and linearly in the number of public inputs, with dedicated gas parameters:
+/// pricing it as the sum of the underlying algebra operations overcharges it by 15-35%.
+macro_rules! groth16_verify_proof_prepared_internal {
+    (
+        $context:expr,
+        $args:ident,
+        $pairing:ty,
+        $g1_projective:ty,
+        $g2_projective:ty,
+        $gt:ty,
+        $scalar:ty,
+        $verify_base_gas:expr,
+        $verify_per_public_input_gas:expr
+    ) => {{
+        let proof_c_handle = safely_pop_arg!($args, u64) as usize;
+        let proof_b_handle = safely_pop_arg!($args, u64) as usize;
+        let proof_a_handle = safely_pop_arg!($args, u64) as usize;
+        let public_input_handles = safely_pop_arg!($args, Vec<u64>);
+        let uvw_gamma_g1_handles = safely_pop_arg!($args, Vec<u64>);
+        let delta_g2_neg_handle = safely_pop_arg!($args, u64) as usize;
+        let gamma_g2_neg_handle = safely_pop_arg!($args, u64) as usize;
+        let alpha_g1_beta_g2_handle = safely_pop_arg!($args, u64) as usize;
+
+        // The first base is multiplied by the implicit public input 1.
+        let num_bases = uvw_gamma_g1_handles.len();
+        if num_bases != public_input_handles.len() + 1 {
+            return Err(SafeNativeError::Abort {
+                abort_code: MOVE_ABORT_CODE_INPUT_VECTOR_SIZES_NOT_MATCHING,
+            });
+        }
+
+        $context.charge(
+            $verify_base_gas
+                + $verify_per_public_input_gas * NumArgs::from(public_input_handles.len() as u64),
+        )?;
+
+        let mut bases = Vec::with_capacity(num_bases);
+        for handle in uvw_gamma_g1_handles {
+            safe_borrow_element!($context, handle as usize, $g1_projective, ptr, element);
+            bases.push(element.into_affine());
+        }
+        let mut scalars = Vec::with_capacity(num_bases);
+        scalars.push(<$scalar>::one());
+        for handle in public_input_handles {
+            safe_borrow_element!($context, handle as usize, $scalar, ptr, scalar);
+            scalars.push(*scalar);
+        }
+        let public_inputs_acc: $g1_projective =
+            VariableBaseMSM::msm(bases.as_slice(), scalars.as_slice()).unwrap();
+
+        safe_borrow_element!($context, proof_a_handle, $g1_projective, a_ptr, proof_a);
+        safe_borrow_element!($context, proof_c_handle, $g1_projective, c_ptr, proof_c);
+        let g1_elements_affine = [
+            proof_a.into_affine(),
+            public_inputs_acc.into_affine(),
+            proof_c.into_affine(),
+        ];
+
+        safe_borrow_element!($context, proof_b_handle, $g2_projective, b_ptr, proof_b);
+        safe_borrow_element!(
+            $context,
+            gamma_g2_neg_handle,
+            $g2_projective,
+            gamma_ptr,
+            gamma_g2_neg
+        );
+        safe_borrow_element!(
+            $context,
+            delta_g2_neg_handle,
+            $g2_projective,
+            delta_ptr,
+            delta_g2_neg
+        );
+        let g2_elements_affine = [
+            proof_b.into_affine(),
+            gamma_g2_neg.into_affine(),
+            delta_g2_neg.into_affine(),
+        ];
+
+        let pairing_result = <$pairing>::multi_pairing(g1_elements_affine, g2_elements_affine).0;
+
+        safe_borrow_element!(
+            $context,
+            alpha_g1_beta_g2_handle,
+            $gt,
+            alpha_beta_ptr,
+            alpha_g1_beta_g2
+        );
+        Ok(smallvec![Value::bool(*alpha_g1_beta_g2 == pairing_result)])
+    }};
+}
+
+pub fn groth16_verify_proof_prepared_internal(
+    context: &mut SafeNativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    assert_eq!(4, ty_args.len());
+    let g1_opt = structure_from_ty_arg!(context, &ty_args[0]);
+    let g2_opt = structure_from_ty_arg!(context, &ty_args[1]);
+    let gt_opt = structure_from_ty_arg!(context, &ty_args[2]);
+    let scalar_opt = structure_from_ty_arg!(context, &ty_args[3]);
+    match (g1_opt, g2_opt, gt_opt, scalar_opt) {
+        (
+            Some(Structure::BLS12381G1),
+            Some(Structure::BLS12381G2),
+            Some(Structure::BLS12381Gt),
+            Some(Structure::BLS12381Fr),
+        ) => {
+            abort_unless_feature_flag_enabled!(context, feature_flag_from_structure(g1_opt));
+            groth16_verify_proof_prepared_internal!(
+                context,
+                args,
+                ark_bls12_381::Bls12_381,
+                ark_bls12_381::G1Projective,
+                ark_bls12_381::G2Projective,
+                ark_bls12_381::Fq12,
+                ark_bls12_381::Fr,
+                ALGEBRA_ARK_BLS12_381_GROTH16_VERIFY_BASE,
+                ALGEBRA_ARK_BLS12_381_GROTH16_VERIFY_PER_PUBLIC_INPUT
+            )
+        },
+        (
+            Some(Structure::BN254G1),
+            Some(Structure::BN254G2),
+            Some(Structure::BN254Gt),
+            Some(Structure::BN254Fr),
+        ) => {
+            abort_unless_feature_flag_enabled!(context, feature_flag_from_structure(g1_opt));
+            groth16_verify_proof_prepared_internal!(
+                context,
+                args,
+                ark_bn254::Bn254,
+                ark_bn254::G1Projective,
+                ark_bn254::G2Projective,
+                ark_bn254::Fq12,
+                ark_bn254::Fr,
+                ALGEBRA_ARK_BN254_GROTH16_VERIFY_BASE,
+                ALGEBRA_ARK_BN254_GROTH16_VERIFY_PER_PUBLIC_INPUT
+            )
+        },
+        _ => Err(SafeNativeError::Abort {
+            abort_code: MOVE_ABORT_CODE_NOT_IMPLEMENTED,
+        }),
+    }
+}
@@ -11,6 +11,7 @@ use crate::natives::cryptography::algebra::{
     casting::{downcast_internal, upcast_internal},
     constants::{one_internal, order_internal, zero_internal},
     eq::eq_internal,
+    groth16::groth16_verify_proof_prepared_internal,
     hash_to_structure::hash_to_internal,
     new::from_u64_internal,
     pairing::{multi_pairing_internal, pairing_internal},
@@ -36,6 +37,7 @@ pub mod arithmetics;
 pub mod casting;
 pub mod constants;
 pub mod eq;
+pub mod groth16;
 pub mod hash_to_structure;
 pub mod new;
 pub mod pairing;
@@ -338,6 +340,10 @@ pub fn make_all(
         ("sub_internal", sub_internal),
         ("zero_internal", zero_internal),
         ("from_u64_internal", from_u64_internal),
+        (
+            "groth16_verify_proof_prepared_internal",
+            groth16_verify_proof_prepared_internal,
+        ),
         ("double_internal", double_internal),
         ("multi_scalar_mul_internal", multi_scalar_mul_internal),
         ("order_internal", order_internal),
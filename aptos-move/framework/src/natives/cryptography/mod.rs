@@ -7,7 +7,9 @@ pub mod bulletproofs;
 pub mod ed25519;
 mod helpers;
 pub mod multi_ed25519;
+pub mod poseidon_bn254;
 pub mod ristretto255;
 pub mod ristretto255_point;
 pub mod ristretto255_scalar;
 pub mod secp256k1;
+pub mod secp256r1;
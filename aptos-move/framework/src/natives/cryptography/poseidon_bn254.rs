@@ -0,0 +1,95 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_crypto::poseidon_bn254;
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_pop_arg, RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeError,
+    SafeNativeResult,
+};
+use ark_serialize::CanonicalDeserialize;
+use move_core_types::gas_algebra::NumArgs;
+use move_vm_runtime::native_functions::NativeFunction;
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use smallvec::{smallvec, SmallVec};
+use std::collections::VecDeque;
+
+/// Abort codes (0x01 == INVALID_ARGUMENT)
+/// NOTE: These must match the codes in the Move implementation
+pub mod abort_codes {
+    pub const NFE_DESERIALIZE: u64 = 0x01_0001;
+    pub const NFE_WRONG_NUM_INPUTS: u64 = 0x01_0002;
+}
+
+/// The size of a serialized BN254 scalar, in bytes.
+const SCALAR_NUM_BYTES: usize = 32;
+
+/***************************************************************************************************
+ * native fun hash_internal
+ *
+ *   gas cost: base_cost + per_input_cost * |inputs|
+ *
+ **************************************************************************************************/
+fn native_hash(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let inputs = safely_pop_arg!(arguments, Vec<Value>);
+
+    context.charge(POSEIDON_BN254_BASE)?;
+
+    if inputs.is_empty() || inputs.len() > poseidon_bn254::MAX_NUM_INPUT_SCALARS {
+        return Err(SafeNativeError::Abort {
+            abort_code: abort_codes::NFE_WRONG_NUM_INPUTS,
+        });
+    }
+
+    // NOTE(Gas): The cost of a Poseidon permutation grows (roughly) linearly with its width, which
+    // is one more than the number of inputs; deserialization is included in the per-input cost.
+    context.charge(POSEIDON_BN254_PER_INPUT * NumArgs::new(inputs.len() as u64))?;
+
+    let mut scalars = Vec::with_capacity(inputs.len());
+    for input in inputs {
+        let bytes = input.value_as::<Vec<u8>>()?;
+        if bytes.len() != SCALAR_NUM_BYTES {
+            return Err(SafeNativeError::Abort {
+                abort_code: abort_codes::NFE_DESERIALIZE,
+            });
+        }
+
+        // Rejects non-canonical encodings (i.e., integers not smaller than the scalar field order).
+        match ark_bn254::Fr::deserialize_uncompressed(bytes.as_slice()) {
+            Ok(scalar) => scalars.push(scalar),
+            Err(_) => {
+                return Err(SafeNativeError::Abort {
+                    abort_code: abort_codes::NFE_DESERIALIZE,
+                });
+            },
+        }
+    }
+
+    // The number of inputs was checked above, so hashing cannot fail.
+    let hash = poseidon_bn254::hash_scalars(scalars).map_err(|_| SafeNativeError::Abort {
+        abort_code: abort_codes::NFE_WRONG_NUM_INPUTS,
+    })?;
+
+    Ok(smallvec![Value::vector_u8(
+        poseidon_bn254::fr_to_bytes_le(&hash).to_vec()
+    )])
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(
+    builder: &SafeNativeBuilder,
+) -> impl Iterator<Item = (String, NativeFunction)> + '_ {
+    let natives = [("hash_internal", native_hash as RawSafeNative)];
+
+    builder.make_named_natives(natives)
+}
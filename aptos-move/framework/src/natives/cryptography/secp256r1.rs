@@ -0,0 +1,84 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use aptos_crypto::{secp256r1_ecdsa, traits::Signature};
+use aptos_gas_schedule::gas_params::natives::aptos_framework::*;
+use aptos_native_interface::{
+    safely_pop_arg, RawSafeNative, SafeNativeBuilder, SafeNativeContext, SafeNativeResult,
+};
+use move_core_types::gas_algebra::{NumArgs, NumBytes};
+use move_vm_runtime::native_functions::NativeFunction;
+use move_vm_types::{loaded_data::runtime_types::Type, values::Value};
+use smallvec::{smallvec, SmallVec};
+use std::{collections::VecDeque, convert::TryFrom};
+
+/// The SEC1 tag for an uncompressed point, which the Move module omits from its 64-byte raw public keys.
+const SEC1_UNCOMPRESSED_TAG: u8 = 0x04;
+
+/***************************************************************************************************
+ * native fun ecdsa_verify_internal
+ *
+ *   gas cost: base_cost + per_pubkey_deserialize_cost
+ *                       +? ( ecdsa_verify_cost + per_msg_byte_hashing_cost * |msg| )
+ *
+ **************************************************************************************************/
+fn native_ecdsa_verify(
+    context: &mut SafeNativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> SafeNativeResult<SmallVec<[Value; 1]>> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let signature = safely_pop_arg!(arguments, Vec<u8>);
+    let raw_pubkey = safely_pop_arg!(arguments, Vec<u8>);
+    let msg = safely_pop_arg!(arguments, Vec<u8>);
+
+    context.charge(SECP256R1_BASE)?;
+
+    context.charge(SECP256R1_PER_PUBKEY_DESERIALIZE * NumArgs::one())?;
+
+    let mut pubkey = Vec::with_capacity(secp256r1_ecdsa::PUBLIC_KEY_LENGTH);
+    pubkey.push(SEC1_UNCOMPRESSED_TAG);
+    pubkey.extend_from_slice(&raw_pubkey);
+
+    // NOTE(Gas): O(1) cost, since this also checks that the point is on the curve.
+    let pk = match secp256r1_ecdsa::PublicKey::try_from(pubkey.as_slice()) {
+        Ok(pk) => pk,
+        Err(_) => {
+            return Ok(smallvec![Value::bool(false)]);
+        },
+    };
+
+    // NOTE(Gas): O(1) cost. Rejects malleable signatures (i.e., those whose s is in the upper
+    // half of the group order).
+    let sig = match secp256r1_ecdsa::Signature::try_from(signature.as_slice()) {
+        Ok(sig) => sig,
+        Err(_) => {
+            return Ok(smallvec![Value::bool(false)]);
+        },
+    };
+
+    // NOTE(Gas): hashing the message with SHA2-256 and a size-2 multi-scalar multiplication
+    let hash_then_verify_cost = SECP256R1_ECDSA_VERIFY * NumArgs::one()
+        + SECP256R1_PER_MSG_BYTE_HASHING * NumBytes::new(msg.len() as u64);
+    context.charge(hash_then_verify_cost)?;
+
+    let verify_result = sig.verify_arbitrary_msg(msg.as_slice(), &pk).is_ok();
+    Ok(smallvec![Value::bool(verify_result)])
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(
+    builder: &SafeNativeBuilder,
+) -> impl Iterator<Item = (String, NativeFunction)> + '_ {
+    let natives = [(
+        "ecdsa_verify_internal",
+        native_ecdsa_verify as RawSafeNative,
+    )];
+
+    builder.make_named_natives(natives)
+}
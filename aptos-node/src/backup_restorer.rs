@@ -0,0 +1,115 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{anyhow, Result};
+use aptos_backup_cli::{
+    coordinators::bootstrap::BootstrapCoordinator,
+    metadata::cache::MetadataCacheOpt,
+    storage::{
+        command_adapter::{config::CommandAdapterConfig, CommandAdapter},
+        local_fs::LocalFs,
+        BackupStorage,
+    },
+    utils::{GlobalRestoreOptions, RestoreRunMode},
+};
+use aptos_config::config::{BootstrapBackupStorageConfig, NodeConfig};
+use aptos_db::{
+    backup::restore_handler::RestoreHandler, get_restore_handler::GetRestoreHandler, AptosDB,
+};
+use aptos_state_sync_driver::{backup_restorer::BackupRestorerInterface, error::Error};
+use aptos_storage_interface::DbReader;
+use aptos_types::{transaction::Version, waypoint::Waypoint};
+use async_trait::async_trait;
+use std::{collections::HashMap, path::PathBuf, sync::Arc};
+
+// The sub-directory (of the storage dir) used to cache the backup metadata
+const METADATA_CACHE_DIR: &str = "backup_metadata_cache";
+
+/// Creates a backup restorer for state sync, iff the node is configured
+/// to bootstrap from a backup store.
+pub fn create_backup_restorer(
+    node_config: &NodeConfig,
+    aptos_db: &Arc<AptosDB>,
+) -> Result<Option<Arc<dyn BackupRestorerInterface>>> {
+    if !node_config
+        .state_sync
+        .state_sync_driver
+        .bootstrapping_mode
+        .is_restore_from_backup()
+    {
+        return Ok(None);
+    }
+
+    let backup_storage_config = node_config
+        .storage
+        .bootstrap_backup_storage
+        .clone()
+        .ok_or_else(|| anyhow!("No bootstrap backup storage is configured!"))?;
+
+    // The backup must extend the genesis already in storage, and must
+    // agree with the waypoint the node is configured to trust.
+    let genesis_ledger_info = aptos_db.get_epoch_ending_ledger_info(0)?;
+    let genesis_waypoint = Waypoint::new_epoch_boundary(genesis_ledger_info.ledger_info())?;
+    let waypoint = node_config.base.waypoint.waypoint();
+    let trusted_waypoints = HashMap::from([
+        (genesis_waypoint.version(), genesis_waypoint),
+        (waypoint.version(), waypoint),
+    ]);
+
+    Ok(Some(Arc::new(BackupStoreRestorer {
+        backup_storage_config,
+        metadata_cache_dir: node_config.storage.dir().join(METADATA_CACHE_DIR),
+        restore_handler: aptos_db.get_restore_handler(),
+        trusted_waypoints,
+    })))
+}
+
+/// Restores the latest state snapshot from a backup store (written by the
+/// backup coordinator) directly into the node's DB.
+struct BackupStoreRestorer {
+    backup_storage_config: BootstrapBackupStorageConfig,
+    metadata_cache_dir: PathBuf,
+    restore_handler: RestoreHandler,
+    trusted_waypoints: HashMap<Version, Waypoint>,
+}
+
+impl BackupStoreRestorer {
+    async fn init_storage(&self) -> Result<Arc<dyn BackupStorage>> {
+        Ok(match &self.backup_storage_config {
+            BootstrapBackupStorageConfig::LocalFs { dir } => Arc::new(LocalFs::new(dir.clone())),
+            BootstrapBackupStorageConfig::CommandAdapter { config } => Arc::new(
+                CommandAdapter::new(CommandAdapterConfig::load_from_file(config).await?),
+            ),
+        })
+    }
+
+    async fn restore(&self) -> Result<Version> {
+        let storage = self.init_storage().await?;
+        let global_opt = GlobalRestoreOptions {
+            target_version: Version::MAX,
+            trusted_waypoints: Arc::new(self.trusted_waypoints.clone()),
+            run_mode: Arc::new(RestoreRunMode::Restore {
+                restore_handler: self.restore_handler.clone(),
+            }),
+            concurrent_downloads: num_cpus::get(),
+            replay_concurrency_level: num_cpus::get(),
+        };
+
+        BootstrapCoordinator::new(
+            MetadataCacheOpt::new(Some(&self.metadata_cache_dir)),
+            global_opt,
+            storage,
+        )
+        .run()
+        .await
+    }
+}
+
+#[async_trait]
+impl BackupRestorerInterface for BackupStoreRestorer {
+    async fn restore_latest_state_snapshot(&self) -> Result<Version, Error> {
+        self.restore()
+            .await
+            .map_err(|error| Error::BackupRestoreError(format!("{:?}", error)))
+    }
+}
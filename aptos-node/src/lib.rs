@@ -4,6 +4,8 @@
 
 #![forbid(unsafe_code)]
 
+#[cfg(not(feature = "consensus-only-perf-test"))]
+mod backup_restorer;
 mod indexer;
 mod logger;
 mod network;
@@ -23,6 +25,7 @@ use aptos_build_info::build_information;
 use aptos_config::config::{
     merge_node_config, InitialSafetyRulesConfig, NodeConfig, PersistableConfig,
 };
+use aptos_consensus::consensus_observer::publisher::ConsensusPublisher;
 use aptos_dkg_runtime::start_dkg_runtime;
 use aptos_framework::ReleaseBundle;
 use aptos_jwk_consensus::start_jwk_consensus_runtime;
@@ -199,6 +202,7 @@ pub struct AptosHandle {
     _admin_service: AdminService,
     _api_runtime: Option<Runtime>,
     _backup_runtime: Option<Runtime>,
+    _consensus_observer_runtime: Option<Runtime>,
     _consensus_runtime: Option<Runtime>,
     _dkg_runtime: Option<Runtime>,
     _indexer_grpc_runtime: Option<Runtime>,
@@ -610,7 +614,7 @@ pub fn setup_environment_and_start_node(
     let admin_service = services::start_admin_service(&node_config);
 
     // Set up the storage database and any RocksDB checkpoints
-    let (db_rw, backup_service, genesis_waypoint) =
+    let (db_rw, backup_service, genesis_waypoint, backup_restorer) =
         storage::initialize_database_and_checkpoints(&mut node_config)?;
 
     admin_service.set_aptos_db(db_rw.clone().into());
@@ -646,6 +650,7 @@ pub fn setup_environment_and_start_node(
     let (
         network_runtimes,
         consensus_network_interfaces,
+        consensus_observer_network_interfaces,
         dkg_network_interfaces,
         jwk_consensus_network_interfaces,
         mempool_network_interfaces,
@@ -665,6 +670,10 @@ pub fn setup_environment_and_start_node(
         db_rw.reader.clone(),
     );
 
+    // Create the partial state storage (if the node only syncs the tracked state)
+    let partial_state = state_sync::create_partial_state_storage(&node_config)?;
+    let api_db_rw = state_sync::create_api_db_rw(&db_rw, partial_state.as_ref());
+
     // Start state sync and get the notification endpoints for mempool and consensus
     let (aptos_data_client, state_sync_runtimes, mempool_listener, consensus_notifier) =
         state_sync::start_state_sync_and_get_notification_handles(
@@ -673,6 +682,8 @@ pub fn setup_environment_and_start_node(
             genesis_waypoint,
             event_subscription_service,
             db_rw.clone(),
+            backup_restorer,
+            partial_state,
         )?;
 
     // Start the node inspection service
@@ -689,7 +700,7 @@ pub fn setup_environment_and_start_node(
         indexer_table_info_runtime,
         indexer_runtime,
         indexer_grpc_runtime,
-    ) = services::bootstrap_api_and_indexer(&node_config, db_rw.clone(), chain_id)?;
+    ) = services::bootstrap_api_and_indexer(&node_config, api_db_rw, chain_id)?;
 
     // Create mempool and get the consensus to mempool sender
     let (mempool_runtime, consensus_to_mempool_sender) =
@@ -774,31 +785,65 @@ pub fn setup_environment_and_start_node(
         _ => None,
     };
 
-    // Create the consensus runtime (this blocks on state sync first)
-    let consensus_runtime = consensus_network_interfaces.map(|consensus_network_interfaces| {
-        // Wait until state sync has been initialized
-        debug!("Waiting until state sync is initialized!");
-        state_sync_runtimes.block_until_initialized();
-        debug!("State sync initialization complete.");
+    // Create the consensus publisher (if enabled)
+    let consensus_publisher = consensus_observer_network_interfaces
+        .as_ref()
+        .filter(|_| node_config.consensus_observer.publisher_enabled)
+        .map(|interfaces| Arc::new(ConsensusPublisher::new(interfaces.network_client.clone())));
 
-        // Initialize and start consensus
-        let (runtime, consensus_db, quorum_store_db) = services::start_consensus_runtime(
-            &mut node_config,
-            db_rw,
-            consensus_reconfig_subscription,
-            consensus_network_interfaces,
-            consensus_notifier,
-            consensus_to_mempool_sender,
-            vtxn_pool,
-        );
-        admin_service.set_consensus_dbs(consensus_db, quorum_store_db);
-        runtime
-    });
+    // Create the consensus (or consensus observer) runtime (this blocks on state sync first)
+    let (consensus_runtime, consensus_observer_runtime) = match (
+        consensus_network_interfaces,
+        consensus_observer_network_interfaces,
+    ) {
+        (Some(consensus_network_interfaces), _) => {
+            // Wait until state sync has been initialized
+            debug!("Waiting until state sync is initialized!");
+            state_sync_runtimes.block_until_initialized();
+            debug!("State sync initialization complete.");
+
+            // Initialize and start consensus
+            let (runtime, consensus_db, quorum_store_db) = services::start_consensus_runtime(
+                &mut node_config,
+                db_rw,
+                consensus_reconfig_subscription,
+                consensus_network_interfaces,
+                consensus_notifier,
+                consensus_to_mempool_sender,
+                vtxn_pool,
+                consensus_publisher,
+            );
+            admin_service.set_consensus_dbs(consensus_db, quorum_store_db);
+            (Some(runtime), None)
+        },
+        (None, Some(consensus_observer_network_interfaces))
+            if node_config.consensus_observer.observer_enabled =>
+        {
+            // Wait until state sync has been initialized
+            debug!("Waiting until state sync is initialized!");
+            state_sync_runtimes.block_until_initialized();
+            debug!("State sync initialization complete.");
+
+            // Initialize and start the consensus observer
+            let runtime = services::start_consensus_observer_runtime(
+                &node_config,
+                db_rw,
+                consensus_reconfig_subscription,
+                consensus_observer_network_interfaces,
+                consensus_notifier,
+                consensus_to_mempool_sender,
+                consensus_publisher,
+            );
+            (None, Some(runtime))
+        },
+        _ => (None, None),
+    };
 
     Ok(AptosHandle {
         _admin_service: admin_service,
         _api_runtime: api_runtime,
         _backup_runtime: backup_service,
+        _consensus_observer_runtime: consensus_observer_runtime,
         _consensus_runtime: consensus_runtime,
         _dkg_runtime: dkg_runtime,
         _indexer_grpc_runtime: indexer_grpc_runtime,
@@ -6,7 +6,11 @@ use aptos_admin_service::AdminService;
 use aptos_build_info::build_information;
 use aptos_config::config::NodeConfig;
 use aptos_consensus::{
-    network_interface::ConsensusMsg, persistent_liveness_storage::StorageWriteProxy,
+    consensus_observer::{
+        network_message::ConsensusObserverMessage, publisher::ConsensusPublisher,
+    },
+    network_interface::ConsensusMsg,
+    persistent_liveness_storage::StorageWriteProxy,
     quorum_store::quorum_store_db::QuorumStoreDB,
 };
 use aptos_consensus_notifications::ConsensusNotifier;
@@ -115,6 +119,7 @@ pub fn start_consensus_runtime(
     consensus_notifier: ConsensusNotifier,
     consensus_to_mempool_sender: Sender<QuorumStoreRequest>,
     vtxn_pool: VTxnPoolState,
+    consensus_publisher: Option<Arc<ConsensusPublisher>>,
 ) -> (Runtime, Arc<StorageWriteProxy>, Arc<QuorumStoreDB>) {
     let instant = Instant::now();
     let consensus = aptos_consensus::consensus_provider::start_consensus(
@@ -127,11 +132,41 @@ pub fn start_consensus_runtime(
         consensus_reconfig_subscription
             .expect("Consensus requires a reconfiguration subscription!"),
         vtxn_pool,
+        consensus_publisher,
     );
     debug!("Consensus started in {} ms", instant.elapsed().as_millis());
     consensus
 }
 
+/// Starts the consensus observer and returns the runtime
+pub fn start_consensus_observer_runtime(
+    node_config: &NodeConfig,
+    db_rw: DbReaderWriter,
+    consensus_reconfig_subscription: Option<ReconfigNotificationListener<DbBackedOnChainConfig>>,
+    consensus_observer_network_interfaces: ApplicationNetworkInterfaces<ConsensusObserverMessage>,
+    consensus_notifier: ConsensusNotifier,
+    consensus_to_mempool_sender: Sender<QuorumStoreRequest>,
+    consensus_publisher: Option<Arc<ConsensusPublisher>>,
+) -> Runtime {
+    let instant = Instant::now();
+    let consensus_observer = aptos_consensus::consensus_provider::start_consensus_observer(
+        node_config,
+        consensus_observer_network_interfaces.network_client,
+        consensus_observer_network_interfaces.network_service_events,
+        Arc::new(consensus_notifier),
+        consensus_to_mempool_sender,
+        db_rw,
+        consensus_reconfig_subscription
+            .expect("The consensus observer requires a reconfiguration subscription!"),
+        consensus_publisher,
+    );
+    debug!(
+        "Consensus observer started in {} ms",
+        instant.elapsed().as_millis()
+    );
+    consensus_observer
+}
+
 /// Create the mempool runtime and start mempool
 pub fn start_mempool_runtime_and_get_consensus_sender(
     node_config: &mut NodeConfig,
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::network::ApplicationNetworkInterfaces;
-use aptos_config::config::{NodeConfig, StateSyncConfig};
+use aptos_config::config::{NodeConfig, StateSyncConfig, TrackedStateFilter};
 use aptos_consensus_notifications::ConsensusNotifier;
 use aptos_data_client::{client::AptosDataClient, poller};
 use aptos_data_streaming_service::{
@@ -21,8 +21,11 @@ use aptos_network::application::{
     storage::PeersAndMetadata,
 };
 use aptos_state_sync_driver::{
+    backup_restorer::BackupRestorerInterface,
     driver_factory::{DriverFactory, StateSyncRuntimes},
     metadata_storage::PersistentMetadataStorage,
+    partial_state_reader::PartialStateReader,
+    partial_state_storage::PartialStateStorage,
 };
 use aptos_storage_interface::{DbReader, DbReaderWriter};
 use aptos_storage_service_client::StorageServiceClient;
@@ -64,8 +67,11 @@ pub fn create_event_subscription_service(
         .subscribe_to_reconfigurations()
         .expect("Mempool must subscribe to reconfigurations");
 
-    // Create a reconfiguration subscription for consensus (if this is a validator)
-    let consensus_reconfig_subscription = if node_config.base.role.is_validator() {
+    // Create a reconfiguration subscription for consensus (if this is a validator
+    // or the consensus observer is enabled)
+    let consensus_reconfig_subscription = if node_config.base.role.is_validator()
+        || node_config.consensus_observer.observer_enabled
+    {
         Some(
             event_subscription_service
                 .subscribe_to_reconfigurations()
@@ -108,6 +114,38 @@ pub fn create_event_subscription_service(
     )
 }
 
+/// Creates the partial state storage (and tracked state filter), iff the
+/// node is configured to run as a partial state ("light") fullnode.
+pub fn create_partial_state_storage(
+    node_config: &NodeConfig,
+) -> anyhow::Result<Option<(PartialStateStorage, TrackedStateFilter)>> {
+    let tracked_state_filter = match node_config.partial_state.tracked_state_filter()? {
+        Some(tracked_state_filter) => tracked_state_filter,
+        None => return Ok(None),
+    };
+    let partial_state_storage = PartialStateStorage::new(&node_config.storage.dir());
+    Ok(Some((partial_state_storage, tracked_state_filter)))
+}
+
+/// Returns the DB reader and writer to be used by the API. For partial
+/// state fullnodes, all reads are served from the partial state storage.
+pub fn create_api_db_rw(
+    db_rw: &DbReaderWriter,
+    partial_state: Option<&(PartialStateStorage, TrackedStateFilter)>,
+) -> DbReaderWriter {
+    match partial_state {
+        Some((partial_state_storage, tracked_state_filter)) => DbReaderWriter {
+            reader: Arc::new(PartialStateReader::new(
+                partial_state_storage.clone(),
+                db_rw.reader.clone(),
+                tracked_state_filter.clone(),
+            )),
+            writer: db_rw.writer.clone(),
+        },
+        None => db_rw.clone(),
+    }
+}
+
 /// Sets up all state sync runtimes and return the notification endpoints
 pub fn start_state_sync_and_get_notification_handles(
     node_config: &NodeConfig,
@@ -115,6 +153,8 @@ pub fn start_state_sync_and_get_notification_handles(
     waypoint: Waypoint,
     event_subscription_service: EventSubscriptionService,
     db_rw: DbReaderWriter,
+    backup_restorer: Option<Arc<dyn BackupRestorerInterface>>,
+    partial_state: Option<(PartialStateStorage, TrackedStateFilter)>,
 ) -> anyhow::Result<(
     AptosDataClient,
     StateSyncRuntimes,
@@ -164,22 +204,36 @@ pub fn start_state_sync_and_get_notification_handles(
         storage_service_listener,
     )?;
 
-    // Create the state sync driver factory
-    let state_sync = DriverFactory::create_and_spawn_driver(
-        true,
-        node_config,
-        waypoint,
-        db_rw,
-        chunk_executor,
-        mempool_notifier,
-        storage_service_notifier,
-        metadata_storage,
-        consensus_listener,
-        event_subscription_service,
-        aptos_data_client.clone(),
-        streaming_service_client,
-        TimeService::real(),
-    );
+    // Create the state sync driver factory (or the partial state syncer)
+    let state_sync = match partial_state {
+        Some((partial_state_storage, tracked_state_filter)) => {
+            DriverFactory::create_and_spawn_partial_state_syncer(
+                true,
+                node_config,
+                waypoint,
+                db_rw.reader,
+                partial_state_storage,
+                tracked_state_filter,
+                aptos_data_client.clone(),
+            )
+        },
+        None => DriverFactory::create_and_spawn_driver(
+            true,
+            node_config,
+            waypoint,
+            db_rw,
+            chunk_executor,
+            mempool_notifier,
+            storage_service_notifier,
+            metadata_storage,
+            backup_restorer,
+            consensus_listener,
+            event_subscription_service,
+            aptos_data_client.clone(),
+            streaming_service_client,
+            TimeService::real(),
+        ),
+    };
 
     // Create a new state sync runtime handle
     let state_sync_runtimes = StateSyncRuntimes::new(
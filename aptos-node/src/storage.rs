@@ -1,12 +1,15 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(not(feature = "consensus-only-perf-test"))]
+use crate::backup_restorer::create_backup_restorer;
 use anyhow::{anyhow, Result};
 use aptos_backup_service::start_backup_service;
 use aptos_config::{config::NodeConfig, utils::get_genesis_txn};
 use aptos_db::{fast_sync_storage_wrapper::FastSyncStorageWrapper, AptosDB};
 use aptos_executor::db_bootstrapper::maybe_bootstrap;
 use aptos_logger::{debug, info};
+use aptos_state_sync_driver::backup_restorer::BackupRestorerInterface;
 use aptos_storage_interface::{DbReader, DbReaderWriter};
 use aptos_types::{ledger_info::LedgerInfoWithSignatures, waypoint::Waypoint};
 use aptos_vm::AptosVM;
@@ -38,8 +41,13 @@ pub(crate) fn maybe_apply_genesis(
 #[cfg(not(feature = "consensus-only-perf-test"))]
 pub(crate) fn bootstrap_db(
     node_config: &NodeConfig,
-) -> Result<(Arc<dyn DbReader>, DbReaderWriter, Option<Runtime>)> {
-    let (aptos_db_reader, db_rw, backup_service) =
+) -> Result<(
+    Arc<dyn DbReader>,
+    DbReaderWriter,
+    Option<Runtime>,
+    Option<Arc<dyn BackupRestorerInterface>>,
+)> {
+    let (aptos_db_reader, db_rw, backup_service, backup_restorer) =
         match FastSyncStorageWrapper::initialize_dbs(node_config)? {
             Either::Left(db) => {
                 let (db_arc, db_rw) = DbReaderWriter::wrap(db);
@@ -48,7 +56,13 @@ pub(crate) fn bootstrap_db(
                     db_arc.clone(),
                 );
                 maybe_apply_genesis(&db_rw, node_config)?;
-                (db_arc as Arc<dyn DbReader>, db_rw, Some(db_backup_service))
+                let backup_restorer = create_backup_restorer(node_config, &db_arc)?;
+                (
+                    db_arc as Arc<dyn DbReader>,
+                    db_rw,
+                    Some(db_backup_service),
+                    backup_restorer,
+                )
             },
             Either::Right(fast_sync_db_wrapper) => {
                 let temp_db = fast_sync_db_wrapper.get_temporary_db_with_genesis();
@@ -74,11 +88,16 @@ pub(crate) fn bootstrap_db(
                 let db_backup_service =
                     start_backup_service(node_config.storage.backup_service_address, fast_sync_db);
 
-                (db_arc as Arc<dyn DbReader>, db_rw, Some(db_backup_service))
+                (
+                    db_arc as Arc<dyn DbReader>,
+                    db_rw,
+                    Some(db_backup_service),
+                    None,
+                )
             },
         };
 
-    Ok((aptos_db_reader, db_rw, backup_service))
+    Ok((aptos_db_reader, db_rw, backup_service, backup_restorer))
 }
 
 /// In consensus-only mode, return a in-memory based [FakeAptosDB] and
@@ -86,7 +105,12 @@ pub(crate) fn bootstrap_db(
 #[cfg(feature = "consensus-only-perf-test")]
 pub(crate) fn bootstrap_db(
     node_config: &NodeConfig,
-) -> Result<(Arc<dyn DbReader>, DbReaderWriter, Option<Runtime>)> {
+) -> Result<(
+    Arc<dyn DbReader>,
+    DbReaderWriter,
+    Option<Runtime>,
+    Option<Arc<dyn BackupRestorerInterface>>,
+)> {
     use aptos_db::db::fake_aptosdb::FakeAptosDB;
 
     let aptos_db = AptosDB::open(
@@ -101,7 +125,7 @@ pub(crate) fn bootstrap_db(
     .map_err(|err| anyhow!("DB failed to open {}", err))?;
     let (aptos_db, db_rw) = DbReaderWriter::wrap(FakeAptosDB::new(aptos_db));
     maybe_apply_genesis(&db_rw, node_config)?;
-    Ok((aptos_db, db_rw, None))
+    Ok((aptos_db, db_rw, None, None))
 }
 
 /// Creates a RocksDb checkpoint for the consensus_db, state_sync_db,
@@ -143,10 +167,15 @@ fn create_rocksdb_checkpoint_and_change_working_dir(
 
 /// Creates any rocksdb checkpoints, opens the storage database,
 /// starts the backup service, handles genesis initialization and returns
-/// the various handles.
+/// the various handles (including any restorer for bootstrapping from a backup).
 pub fn initialize_database_and_checkpoints(
     node_config: &mut NodeConfig,
-) -> Result<(DbReaderWriter, Option<Runtime>, Waypoint)> {
+) -> Result<(
+    DbReaderWriter,
+    Option<Runtime>,
+    Waypoint,
+    Option<Arc<dyn BackupRestorerInterface>>,
+)> {
     // If required, create RocksDB checkpoints and change the working directory.
     // This is test-only.
     if let Some(working_dir) = node_config.base.working_dir.clone() {
@@ -155,7 +184,7 @@ pub fn initialize_database_and_checkpoints(
 
     // Open the database
     let instant = Instant::now();
-    let (_aptos_db, db_rw, backup_service) = bootstrap_db(node_config)?;
+    let (_aptos_db, db_rw, backup_service, backup_restorer) = bootstrap_db(node_config)?;
 
     // Log the duration to open storage
     debug!(
@@ -167,5 +196,6 @@ pub fn initialize_database_and_checkpoints(
         db_rw,
         backup_service,
         node_config.base.waypoint.genesis_waypoint(),
+        backup_restorer,
     ))
 }
@@ -61,6 +61,9 @@ pub struct ConsensusConfig {
     pub quorum_store: QuorumStoreConfig,
     pub vote_back_pressure_limit: u64,
     pub pipeline_backpressure: Vec<PipelineBackpressureValues>,
+    // Sizes proposals so that they are expected to execute within a target time, based on the
+    // recently executed blocks. Disabled if None.
+    pub execution_backpressure: Option<ExecutionBackpressureConfig>,
     // Used to decide if backoff is needed.
     // must match one of the CHAIN_HEALTH_WINDOW_SIZES values.
     pub window_for_chain_health: usize,
@@ -134,6 +137,37 @@ pub struct PipelineBackpressureValues {
     pub max_txns_from_block_to_execute: Option<usize>,
 }
 
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ExecutionBackpressureConfig {
+    /// Number of most recent executed blocks to learn the execution time per transaction from
+    pub num_blocks_to_look_at: usize,
+    /// Minimal number of executed blocks needed before limiting the block size
+    pub min_blocks_to_activate: usize,
+    /// Percentile (between 0 and 1) of the per-block estimates to use, lower is more conservative
+    pub percentile: f64,
+    /// Execution time a proposed block should fit in
+    pub target_block_time_ms: u64,
+    /// Blocks that executed faster than this (and were not cut by the block limit) are too small
+    /// to learn from, as fixed per-block overheads dominate
+    pub min_block_time_ms_to_activate: u64,
+    /// Never limit the block size below this number of transactions
+    pub min_calibrated_txns_per_block: u64,
+}
+
+impl Default for ExecutionBackpressureConfig {
+    fn default() -> Self {
+        Self {
+            num_blocks_to_look_at: 12,
+            min_blocks_to_activate: 4,
+            percentile: 0.5,
+            target_block_time_ms: 250,
+            min_block_time_ms_to_activate: 100,
+            min_calibrated_txns_per_block: 8,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
 pub struct ChainHealthBackoffValues {
     pub backoff_if_below_participating_voting_power_percentage: usize,
@@ -255,6 +289,8 @@ impl Default for ConsensusConfig {
                     max_txns_from_block_to_execute: Some(5),
                 },
             ],
+            // Disabled until it has been evaluated on forge, as it changes the block sizes
+            execution_backpressure: None,
             window_for_chain_health: 100,
             chain_health_backoff: vec![
                 ChainHealthBackoffValues {
@@ -470,6 +506,18 @@ impl ConfigSanitizer for ConsensusConfig {
         // Quorum store batches must be <= consensus blocks
         Self::sanitize_batch_block_limits(&sanitizer_name, &node_config.consensus)?;
 
+        // Execution backpressure must be able to activate, with a valid percentile
+        if let Some(config) = &node_config.consensus.execution_backpressure {
+            if !(0.0..=1.0).contains(&config.percentile)
+                || config.min_blocks_to_activate > config.num_blocks_to_look_at
+            {
+                return Err(Error::ConfigSanitizerFailed(
+                    sanitizer_name,
+                    format!("Invalid execution backpressure config: {:?}", config),
+                ));
+            }
+        }
+
         Ok(())
     }
 }
@@ -685,6 +733,26 @@ mod test {
         assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
     }
 
+    #[test]
+    fn test_invalid_execution_backpressure() {
+        // Create a node config with an execution backpressure percentile above 1
+        let node_config = NodeConfig {
+            consensus: ConsensusConfig {
+                execution_backpressure: Some(ExecutionBackpressureConfig {
+                    percentile: 1.5,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Sanitize the config and verify that it fails
+        let error =
+            ConsensusConfig::sanitize(&node_config, NodeType::ValidatorFullnode, None).unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
+
     #[test]
     fn test_invalid_chain_health_backoff_txn_limits() {
         // Create a node config with invalid chain health backoff txn limits
@@ -0,0 +1,84 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::{
+    config_sanitizer::ConfigSanitizer, node_config_loader::NodeType, Error, NodeConfig,
+};
+use aptos_types::chain_id::ChainId;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ConsensusObserverConfig {
+    /// Whether the node executes the ordered blocks published by its
+    /// upstream peers (instead of relying only on state sync)
+    pub observer_enabled: bool,
+    /// Whether the node publishes ordered blocks and commit decisions
+    /// to the downstream peers that connect to it
+    pub publisher_enabled: bool,
+
+    /// Maximum number of pending network messages
+    pub max_network_channel_size: u64,
+    /// Maximum number of ordered blocks the observer buffers ahead of its root
+    pub max_pending_blocks: u64,
+    /// Duration (in ms) without observer commits after which state sync takes over
+    pub observer_fallback_duration_ms: u64,
+}
+
+impl Default for ConsensusObserverConfig {
+    fn default() -> Self {
+        Self {
+            observer_enabled: false,
+            publisher_enabled: false,
+            max_network_channel_size: 1000,
+            max_pending_blocks: 100,
+            observer_fallback_duration_ms: 10_000, // 10 seconds
+        }
+    }
+}
+
+impl ConfigSanitizer for ConsensusObserverConfig {
+    fn sanitize(
+        node_config: &NodeConfig,
+        node_type: NodeType,
+        _chain_id: Option<ChainId>,
+    ) -> Result<(), Error> {
+        let sanitizer_name = Self::get_sanitizer_name();
+
+        // Verify that validators do not observe consensus (they participate in it)
+        if node_type.is_validator() && node_config.consensus_observer.observer_enabled {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                "The consensus observer should not be enabled on validators!".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_observer_on_validator() {
+        // Create a node config with the observer enabled
+        let node_config = NodeConfig {
+            consensus_observer: ConsensusObserverConfig {
+                observer_enabled: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Verify that the config fails sanitization for validators
+        let error =
+            ConsensusObserverConfig::sanitize(&node_config, NodeType::Validator, None).unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+
+        // Verify that the config passes sanitization for fullnodes
+        ConsensusObserverConfig::sanitize(&node_config, NodeType::ValidatorFullnode, None).unwrap();
+        ConsensusObserverConfig::sanitize(&node_config, NodeType::PublicFullnode, None).unwrap();
+    }
+}
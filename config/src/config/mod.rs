@@ -9,6 +9,7 @@ mod base_config;
 mod config_optimizer;
 mod config_sanitizer;
 mod consensus_config;
+mod consensus_observer_config;
 mod dag_consensus_config;
 mod dkg_config;
 mod error;
@@ -28,6 +29,7 @@ mod node_config;
 mod node_config_loader;
 mod node_startup_config;
 mod override_node_config;
+mod partial_state_config;
 mod peer_monitoring_config;
 mod persistable_config;
 mod quorum_store_config;
@@ -43,6 +45,7 @@ pub use admin_service_config::*;
 pub use api_config::*;
 pub use base_config::*;
 pub use consensus_config::*;
+pub use consensus_observer_config::*;
 pub use dag_consensus_config::*;
 pub use error::*;
 pub use execution_config::*;
@@ -59,6 +62,7 @@ pub use network_config::*;
 pub use node_config::*;
 pub use node_config_loader::sanitize_node_config;
 pub use override_node_config::*;
+pub use partial_state_config::*;
 pub use peer_monitoring_config::*;
 pub use persistable_config::*;
 pub use quorum_store_config::*;
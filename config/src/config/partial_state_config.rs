@@ -0,0 +1,316 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::config::{
+    config_sanitizer::ConfigSanitizer, node_config_loader::NodeType, Error, NodeConfig,
+};
+use aptos_types::{
+    access_path::AccessPath,
+    account_address::AccountAddress,
+    chain_id::ChainId,
+    state_store::state_key::{StateKey, StateKeyInner},
+};
+use move_core_types::language_storage::StructTag;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, str::FromStr};
+
+/// The config for a partial state ("light") fullnode. Such a node follows
+/// the ledger (i.e., ledger infos and transaction outputs) using state sync,
+/// but only persists the state it tracks: the resources of the tracked types
+/// under each of the tracked addresses. Every tracked value is verified
+/// against the state root using a sparse merkle proof fetched from peers.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PartialStateConfig {
+    /// Whether the node only syncs (and serves) the tracked state
+    pub enabled: bool,
+    /// The addresses whose resources are tracked
+    pub tracked_addresses: Vec<AccountAddress>,
+    /// The resource types tracked under each address (e.g.,
+    /// "0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>"). Resources stored
+    /// in a resource group are tracked using the group type (e.g.,
+    /// "0x1::object::ObjectGroup").
+    pub tracked_resource_types: Vec<String>,
+
+    /// Maximum number of state value proofs to request concurrently
+    pub max_concurrent_state_requests: u64,
+    /// Interval (in ms) at which to check for (and sync to) new ledger infos
+    pub sync_interval_ms: u64,
+}
+
+impl Default for PartialStateConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            tracked_addresses: vec![],
+            tracked_resource_types: vec![],
+            max_concurrent_state_requests: 16,
+            sync_interval_ms: 1000,
+        }
+    }
+}
+
+impl PartialStateConfig {
+    /// Returns the filter for the state tracked by the node. If the node
+    /// does not run in partial state mode, None is returned.
+    pub fn tracked_state_filter(&self) -> Result<Option<TrackedStateFilter>, Error> {
+        if !self.enabled {
+            return Ok(None);
+        }
+
+        let resource_types = self
+            .tracked_resource_types
+            .iter()
+            .map(|resource_type| {
+                StructTag::from_str(resource_type).map_err(|error| {
+                    Error::Unexpected(format!(
+                        "Failed to parse the tracked resource type: {}. Error: {:?}",
+                        resource_type, error
+                    ))
+                })
+            })
+            .collect::<Result<HashSet<_>, _>>()?;
+
+        Ok(Some(TrackedStateFilter::new(
+            self.tracked_addresses.iter().copied().collect(),
+            resource_types,
+        )))
+    }
+}
+
+impl ConfigSanitizer for PartialStateConfig {
+    fn sanitize(
+        node_config: &NodeConfig,
+        node_type: NodeType,
+        _chain_id: Option<ChainId>,
+    ) -> Result<(), Error> {
+        let sanitizer_name = Self::get_sanitizer_name();
+        let partial_state_config = &node_config.partial_state;
+
+        // If partial state is disabled, there's nothing to verify
+        if !partial_state_config.enabled {
+            return Ok(());
+        }
+
+        // Verify that the node is a public fullnode (validators and VFNs need all state)
+        if node_type.is_validator() || node_type.is_validator_fullnode() {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                "Partial state can only be enabled on public fullnodes!".to_string(),
+            ));
+        }
+
+        // Verify that the node doesn't execute blocks (this requires all state)
+        if node_config.consensus_observer.observer_enabled {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                "Partial state cannot be enabled alongside the consensus observer!".to_string(),
+            ));
+        }
+
+        // Verify that the tracked state is specified
+        if partial_state_config.tracked_addresses.is_empty()
+            || partial_state_config.tracked_resource_types.is_empty()
+        {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                "Partial state requires tracked addresses and resource types!".to_string(),
+            ));
+        }
+        if let Err(error) = partial_state_config.tracked_state_filter() {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                error.to_string(),
+            ));
+        }
+
+        // Verify that state requests can be made
+        if partial_state_config.max_concurrent_state_requests == 0 {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                "The maximum number of concurrent state requests must be non-zero!".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// A filter that identifies the state keys tracked by a partial state node
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TrackedStateFilter {
+    addresses: HashSet<AccountAddress>,
+    resource_types: HashSet<StructTag>,
+}
+
+impl TrackedStateFilter {
+    pub fn new(addresses: HashSet<AccountAddress>, resource_types: HashSet<StructTag>) -> Self {
+        Self {
+            addresses,
+            resource_types,
+        }
+    }
+
+    /// Returns true iff the given state key is tracked, i.e., it holds a
+    /// resource (or resource group) of a tracked type under a tracked address.
+    pub fn is_tracked(&self, state_key: &StateKey) -> bool {
+        match state_key.inner() {
+            StateKeyInner::AccessPath(access_path) => {
+                self.addresses.contains(&access_path.address)
+                    && access_path.get_struct_tag().map_or(false, |struct_tag| {
+                        self.resource_types.contains(&struct_tag)
+                    })
+            },
+            _ => false,
+        }
+    }
+
+    /// Returns all state keys that may be tracked. As the storage layout
+    /// of each type is unknown, both the resource and resource group keys
+    /// are returned for every tracked type.
+    pub fn tracked_state_keys(&self) -> Vec<StateKey> {
+        let mut state_keys = vec![];
+        for address in &self.addresses {
+            for resource_type in &self.resource_types {
+                state_keys.push(StateKey::resource(address, resource_type));
+                state_keys.push(StateKey::access_path(
+                    AccessPath::resource_group_access_path(*address, resource_type.clone()),
+                ));
+            }
+        }
+        state_keys
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COIN_STORE_TYPE: &str = "0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>";
+    const OBJECT_GROUP_TYPE: &str = "0x1::object::ObjectGroup";
+
+    #[test]
+    fn test_sanitize_disabled() {
+        // Create a node config with partial state disabled (but no tracked state)
+        let node_config = NodeConfig::default();
+
+        // Verify that the config passes sanitization for all node types
+        for node_type in [
+            NodeType::Validator,
+            NodeType::ValidatorFullnode,
+            NodeType::PublicFullnode,
+        ] {
+            PartialStateConfig::sanitize(&node_config, node_type, None).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_sanitize_node_types() {
+        // Create a node config with partial state enabled
+        let node_config = create_node_config(vec![COIN_STORE_TYPE.into()]);
+
+        // Verify that the config fails sanitization for validators and VFNs
+        for node_type in [NodeType::Validator, NodeType::ValidatorFullnode] {
+            let error = PartialStateConfig::sanitize(&node_config, node_type, None).unwrap_err();
+            assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+        }
+
+        // Verify that the config passes sanitization for public fullnodes
+        PartialStateConfig::sanitize(&node_config, NodeType::PublicFullnode, None).unwrap();
+    }
+
+    #[test]
+    fn test_sanitize_consensus_observer() {
+        // Create a node config with partial state and the consensus observer enabled
+        let mut node_config = create_node_config(vec![COIN_STORE_TYPE.into()]);
+        node_config.consensus_observer.observer_enabled = true;
+
+        // Verify that the config fails sanitization
+        let error =
+            PartialStateConfig::sanitize(&node_config, NodeType::PublicFullnode, None).unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
+
+    #[test]
+    fn test_sanitize_tracked_state() {
+        // Verify that the config fails sanitization without tracked resource types
+        let node_config = create_node_config(vec![]);
+        let error =
+            PartialStateConfig::sanitize(&node_config, NodeType::PublicFullnode, None).unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+
+        // Verify that the config fails sanitization with an invalid resource type
+        let node_config = create_node_config(vec!["0x1::coin".into()]);
+        let error =
+            PartialStateConfig::sanitize(&node_config, NodeType::PublicFullnode, None).unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+
+        // Verify that the config fails sanitization without tracked addresses
+        let mut node_config = create_node_config(vec![COIN_STORE_TYPE.into()]);
+        node_config.partial_state.tracked_addresses = vec![];
+        let error =
+            PartialStateConfig::sanitize(&node_config, NodeType::PublicFullnode, None).unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
+
+    #[test]
+    fn test_tracked_state_filter() {
+        // Create a tracked state filter
+        let node_config =
+            create_node_config(vec![COIN_STORE_TYPE.into(), OBJECT_GROUP_TYPE.into()]);
+        let filter = node_config
+            .partial_state
+            .tracked_state_filter()
+            .unwrap()
+            .unwrap();
+
+        // Verify that the tracked resources and resource groups are tracked
+        let tracked_address = AccountAddress::ONE;
+        let coin_store_type = StructTag::from_str(COIN_STORE_TYPE).unwrap();
+        let object_group_type = StructTag::from_str(OBJECT_GROUP_TYPE).unwrap();
+        assert!(filter.is_tracked(&StateKey::resource(&tracked_address, &coin_store_type)));
+        assert!(filter.is_tracked(&StateKey::access_path(
+            AccessPath::resource_group_access_path(tracked_address, object_group_type)
+        )));
+
+        // Verify that other resources, addresses and tables are not tracked
+        let other_type = StructTag::from_str("0x1::account::Account").unwrap();
+        assert!(!filter.is_tracked(&StateKey::resource(&tracked_address, &other_type)));
+        assert!(!filter.is_tracked(&StateKey::resource(&AccountAddress::TWO, &coin_store_type)));
+        assert!(!filter.is_tracked(&StateKey::raw(vec![1, 2, 3])));
+
+        // Verify that all possible tracked keys are enumerated (and tracked)
+        let tracked_state_keys = filter.tracked_state_keys();
+        assert_eq!(tracked_state_keys.len(), 4);
+        for state_key in tracked_state_keys {
+            assert!(filter.is_tracked(&state_key));
+        }
+    }
+
+    #[test]
+    fn test_tracked_state_filter_disabled() {
+        // Verify that no filter is returned when partial state is disabled
+        let partial_state_config = PartialStateConfig {
+            tracked_addresses: vec![AccountAddress::ONE],
+            tracked_resource_types: vec![COIN_STORE_TYPE.into()],
+            ..Default::default()
+        };
+        assert!(partial_state_config
+            .tracked_state_filter()
+            .unwrap()
+            .is_none());
+    }
+
+    /// Creates a node config with partial state enabled for the given resource types
+    fn create_node_config(tracked_resource_types: Vec<String>) -> NodeConfig {
+        NodeConfig {
+            partial_state: PartialStateConfig {
+                enabled: true,
+                tracked_addresses: vec![AccountAddress::ONE],
+                tracked_resource_types,
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+}
@@ -12,7 +12,7 @@ use crate::{
     keys::ConfigKey,
 };
 use anyhow::bail;
-use aptos_crypto::{bls12381, Uniform};
+use aptos_crypto::{bls12381, x25519, Uniform};
 use aptos_types::{chain_id::ChainId, network_address::NetworkAddress, waypoint::Waypoint, PeerId};
 use rand::rngs::StdRng;
 use serde::{Deserialize, Serialize};
@@ -113,6 +113,20 @@ impl ConfigSanitizer for SafetyRulesConfig {
             }
         }
 
+        // Verify that the validator pins the key of the remote safety rules service
+        if let SafetyRulesService::Process(RemoteService {
+            noise: Some(noise_config),
+            ..
+        }) = &safety_rules_config.service
+        {
+            if noise_config.server_public_key.is_none() {
+                return Err(Error::ConfigSanitizerFailed(
+                    sanitizer_name,
+                    "The server public key must be set when using noise for the remote safety rules service!".to_string(),
+                ));
+            }
+        }
+
         Ok(())
     }
 }
@@ -188,6 +202,9 @@ impl SafetyRulesService {
 #[serde(deny_unknown_fields)]
 pub struct RemoteService {
     pub server_address: NetworkAddress,
+    /// If set, the connection to the service is authenticated and encrypted using Noise IK
+    #[serde(default)]
+    pub noise: Option<RemoteServiceNoiseConfig>,
 }
 
 impl RemoteService {
@@ -200,6 +217,21 @@ impl RemoteService {
     }
 }
 
+/// The pinned keys used to authenticate the connection between the safety rules
+/// client (i.e., consensus) and the remote safety rules service.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RemoteServiceNoiseConfig {
+    /// The static x25519 key of this endpoint
+    pub private_key: ConfigKey<x25519::PrivateKey>,
+    /// The public key of the safety rules service (required by the client)
+    #[serde(default)]
+    pub server_public_key: Option<x25519::PublicKey>,
+    /// The public keys of the clients allowed to connect (only used by the service)
+    #[serde(default)]
+    pub trusted_client_public_keys: Vec<x25519::PublicKey>,
+}
+
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct SafetyRulesTestConfig {
     pub author: PeerId,
@@ -302,6 +334,33 @@ mod tests {
         assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
     }
 
+    #[test]
+    fn test_sanitize_missing_noise_server_key() {
+        // Create a node config with a noise config that doesn't pin the server key
+        let node_config = NodeConfig {
+            consensus: ConsensusConfig {
+                safety_rules: SafetyRulesConfig {
+                    service: SafetyRulesService::Process(RemoteService {
+                        server_address: "/ip4/127.0.0.1/tcp/5555".parse().unwrap(),
+                        noise: Some(RemoteServiceNoiseConfig {
+                            private_key: ConfigKey::default(),
+                            server_public_key: None,
+                            trusted_client_public_keys: vec![],
+                        }),
+                    }),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Verify that the config sanitizer fails
+        let error =
+            SafetyRulesConfig::sanitize(&node_config, NodeType::Validator, None).unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
+
     #[test]
     fn test_sanitize_test_config_on_mainnet() {
         // Create a node config with a test config
@@ -44,6 +44,10 @@ pub enum BootstrappingMode {
     ExecuteTransactionsFromGenesis,
     /// Executes transactions or applies outputs from genesis (whichever is faster)
     ExecuteOrApplyFromGenesis,
+    /// Restores the latest state snapshot from a backup store (see
+    /// `StorageConfig::bootstrap_backup_storage`) and then executes transactions
+    /// or applies outputs (whichever is faster) to catch up with the network.
+    RestoreFromBackup,
 }
 
 impl BootstrappingMode {
@@ -57,6 +61,7 @@ impl BootstrappingMode {
                 "execute_transactions_from_genesis"
             },
             BootstrappingMode::ExecuteOrApplyFromGenesis => "execute_or_apply_from_genesis",
+            BootstrappingMode::RestoreFromBackup => "restore_from_backup",
         }
     }
 
@@ -64,6 +69,11 @@ impl BootstrappingMode {
     pub fn is_fast_sync(&self) -> bool {
         *self == BootstrappingMode::DownloadLatestStates
     }
+
+    /// Returns true iff the bootstrapping mode restores from a backup store
+    pub fn is_restore_from_backup(&self) -> bool {
+        *self == BootstrappingMode::RestoreFromBackup
+    }
 }
 
 /// The continuous syncing mode determines how the node will stay up-to-date
@@ -483,6 +493,20 @@ impl ConfigSanitizer for StateSyncDriverConfig {
             ));
         }
 
+        // Verify that a backup store is configured for nodes
+        // that are restoring from a backup.
+        if state_sync_driver_config
+            .bootstrapping_mode
+            .is_restore_from_backup()
+            && node_config.storage.bootstrap_backup_storage.is_none()
+        {
+            return Err(Error::ConfigSanitizerFailed(
+                sanitizer_name,
+                "A bootstrap backup storage must be configured for nodes that are restoring from a backup!"
+                    .to_string(),
+            ));
+        }
+
         Ok(())
     }
 }
@@ -788,6 +812,31 @@ mod tests {
         assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
     }
 
+    #[test]
+    fn test_sanitize_restore_from_backup_without_storage() {
+        // Create a node config that restores from a backup,
+        // but without a backup storage.
+        let node_config = NodeConfig {
+            state_sync: StateSyncConfig {
+                state_sync_driver: StateSyncDriverConfig {
+                    bootstrapping_mode: BootstrappingMode::RestoreFromBackup,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        // Verify that sanitization fails
+        let error = StateSyncConfig::sanitize(
+            &node_config,
+            NodeType::PublicFullnode,
+            Some(ChainId::testnet()),
+        )
+        .unwrap_err();
+        assert!(matches!(error, Error::ConfigSanitizerFailed(_, _)));
+    }
+
     /// Creates and returns a node config with the syncing modes set to execution
     fn create_execution_mode_config() -> NodeConfig {
         NodeConfig {
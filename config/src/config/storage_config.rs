@@ -175,6 +175,19 @@ pub struct StorageConfig {
     /// If not specificed, will use `dir` as default.
     /// Only allowed when sharding is enabled.
     pub db_path_overrides: Option<DbPathConfig>,
+    /// The backup storage to restore the latest state snapshot from, when state sync
+    /// bootstraps the node from a backup (i.e., `BootstrappingMode::RestoreFromBackup`).
+    pub bootstrap_backup_storage: Option<BootstrapBackupStorageConfig>,
+}
+
+/// The backup storage from which a node can be bootstrapped
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum BootstrapBackupStorageConfig {
+    /// Backups held in a local directory
+    LocalFs { dir: PathBuf },
+    /// Backups accessed through the commands in a command adapter config file
+    CommandAdapter { config: PathBuf },
 }
 
 pub const NO_OP_STORAGE_PRUNER_CONFIG: PrunerConfig = PrunerConfig {
@@ -322,6 +335,7 @@ impl Default for StorageConfig {
             rocksdb_configs: RocksdbConfigs::default(),
             enable_indexer: false,
             db_path_overrides: None,
+            bootstrap_backup_storage: None,
             buffered_state_target_items: BUFFERED_STATE_TARGET_ITEMS,
             max_num_nodes_per_lru_cache_shard: DEFAULT_MAX_NUM_NODES_PER_LRU_CACHE_SHARD,
         }
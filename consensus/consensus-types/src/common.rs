@@ -12,8 +12,11 @@ use aptos_executor_types::ExecutorResult;
 use aptos_infallible::Mutex;
 use aptos_logger::prelude::*;
 use aptos_types::{
-    account_address::AccountAddress, transaction::SignedTransaction,
-    validator_verifier::ValidatorVerifier, vm_status::DiscardedVMStatus, PeerId,
+    account_address::AccountAddress,
+    transaction::{ReplayProtector, SignedTransaction},
+    validator_verifier::ValidatorVerifier,
+    vm_status::DiscardedVMStatus,
+    PeerId,
 };
 use once_cell::sync::OnceCell;
 use rayon::prelude::*;
@@ -31,21 +34,21 @@ pub type Author = AccountAddress;
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, Hash, Ord, PartialOrd)]
 pub struct TransactionSummary {
     pub sender: AccountAddress,
-    pub sequence_number: u64,
+    pub replay_protector: ReplayProtector,
 }
 
 impl TransactionSummary {
-    pub fn new(sender: AccountAddress, sequence_number: u64) -> Self {
+    pub fn new(sender: AccountAddress, replay_protector: ReplayProtector) -> Self {
         Self {
             sender,
-            sequence_number,
+            replay_protector,
         }
     }
 }
 
 impl fmt::Display for TransactionSummary {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}:{}", self.sender, self.sequence_number,)
+        write!(f, "{}:{}", self.sender, self.replay_protector,)
     }
 }
 
@@ -81,7 +84,7 @@ impl TransactionInProgress {
 #[derive(Clone)]
 pub struct RejectedTransactionSummary {
     pub sender: AccountAddress,
-    pub sequence_number: u64,
+    pub replay_protector: ReplayProtector,
     pub hash: HashValue,
     pub reason: DiscardedVMStatus,
 }
@@ -529,7 +532,7 @@ impl From<&Vec<&Payload>> for PayloadFilter {
                     for txn in txns {
                         exclude_txns.push(TransactionSummary {
                             sender: txn.sender(),
-                            sequence_number: txn.sequence_number(),
+                            replay_protector: txn.replay_protector(),
                         });
                     }
                 }
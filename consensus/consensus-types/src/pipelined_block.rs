@@ -11,12 +11,16 @@ use crate::{
 use aptos_crypto::hash::HashValue;
 use aptos_executor_types::StateComputeResult;
 use aptos_types::{
-    block_info::BlockInfo, contract_event::ContractEvent, randomness::Randomness,
-    transaction::SignedTransaction, validator_txn::ValidatorTransaction,
+    block_info::BlockInfo,
+    contract_event::ContractEvent,
+    randomness::Randomness,
+    transaction::{SignedTransaction, TransactionStatus},
+    validator_txn::ValidatorTransaction,
 };
 use once_cell::sync::OnceCell;
 use std::{
     fmt::{Debug, Display, Formatter},
+    sync::Arc,
     time::{Duration, Instant},
 };
 
@@ -35,6 +39,21 @@ pub struct PipelinedBlock {
     state_compute_result: StateComputeResult,
     randomness: OnceCell<Randomness>,
     pipeline_insertion_time: OnceCell<Instant>,
+    // Shared between the clones of the block, so that the summary recorded by the execution
+    // pipeline is visible from the block tree.
+    execution_summary: Arc<OnceCell<ExecutionSummary>>,
+}
+
+/// How long it took to execute a block, and how many of its transactions were executed.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ExecutionSummary {
+    /// Number of user transactions given to the executor
+    pub payload_len: u64,
+    /// Number of user transactions that were executed (kept or discarded)
+    pub to_commit: u64,
+    /// Number of user transactions that were not executed because the block limit was reached
+    pub to_retry: u64,
+    pub execution_time: Duration,
 }
 
 impl PipelinedBlock {
@@ -42,7 +61,23 @@ impl PipelinedBlock {
         mut self,
         input_transactions: Vec<SignedTransaction>,
         result: StateComputeResult,
+        execution_time: Duration,
     ) -> Self {
+        let to_retry = result
+            .compute_status_for_input_txns()
+            .iter()
+            .filter(|status| matches!(status, TransactionStatus::Retry))
+            .count() as u64;
+        let payload_len = input_transactions.len() as u64;
+        // The block might be executed more than once (e.g. after a reset), the first
+        // execution is as good a sample as any.
+        let _ = self.execution_summary.set(ExecutionSummary {
+            payload_len,
+            to_commit: payload_len.saturating_sub(to_retry),
+            to_retry,
+            execution_time,
+        });
+
         self.state_compute_result = result;
         self.input_transactions = input_transactions;
         self
@@ -81,6 +116,7 @@ impl PipelinedBlock {
             state_compute_result,
             randomness: OnceCell::new(),
             pipeline_insertion_time: OnceCell::new(),
+            execution_summary: Arc::new(OnceCell::new()),
         }
     }
 
@@ -91,6 +127,7 @@ impl PipelinedBlock {
             state_compute_result: StateComputeResult::new_dummy(),
             randomness: OnceCell::new(),
             pipeline_insertion_time: OnceCell::new(),
+            execution_summary: Arc::new(OnceCell::new()),
         }
     }
 
@@ -184,4 +221,8 @@ impl PipelinedBlock {
     pub fn elapsed_in_pipeline(&self) -> Option<Duration> {
         self.pipeline_insertion_time.get().map(|t| t.elapsed())
     }
+
+    pub fn get_execution_summary(&self) -> Option<ExecutionSummary> {
+        self.execution_summary.get().cloned()
+    }
 }
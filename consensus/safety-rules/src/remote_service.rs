@@ -7,17 +7,36 @@ use crate::{
     serializer::{SafetyRulesInput, SerializerClient, SerializerService, TSerializerClient},
     Error, SafetyRules, TSafetyRules,
 };
+use aptos_config::config::RemoteServiceNoiseConfig;
 use aptos_logger::warn;
-use aptos_secure_net::{NetworkClient, NetworkServer};
+use aptos_secure_net::{NetworkClient, NetworkServer, NoiseClientConfig, NoiseServerConfig};
 use std::net::SocketAddr;
 
+const SERVICE_NAME: &str = "safety-rules";
+
 pub trait RemoteService {
     fn client(&self) -> SerializerClient {
-        let network_client = NetworkClient::new(
-            "safety-rules".to_string(),
-            self.server_address(),
-            self.network_timeout_ms(),
-        );
+        let network_client = match self.noise_config() {
+            Some(noise_config) => {
+                let server_public_key = noise_config
+                    .server_public_key
+                    .expect("The server public key is required to connect using noise");
+                NetworkClient::new_with_noise(
+                    SERVICE_NAME.to_string(),
+                    self.server_address(),
+                    self.network_timeout_ms(),
+                    NoiseClientConfig::new(
+                        noise_config.private_key.private_key(),
+                        server_public_key,
+                    ),
+                )
+            },
+            None => NetworkClient::new(
+                SERVICE_NAME.to_string(),
+                self.server_address(),
+                self.network_timeout_ms(),
+            ),
+        };
         let service = Box::new(RemoteClient::new(network_client));
         SerializerClient::new_client(service)
     }
@@ -26,17 +45,40 @@ pub trait RemoteService {
 
     /// Network Timeout in milliseconds.
     fn network_timeout_ms(&self) -> u64;
+
+    /// The noise config used to authenticate and encrypt the connection (if any)
+    fn noise_config(&self) -> Option<&RemoteServiceNoiseConfig> {
+        None
+    }
 }
 
-pub fn execute(storage: PersistentSafetyStorage, listen_addr: SocketAddr, network_timeout_ms: u64) {
+pub fn execute(
+    storage: PersistentSafetyStorage,
+    listen_addr: SocketAddr,
+    network_timeout_ms: u64,
+    noise_config: Option<RemoteServiceNoiseConfig>,
+) {
     let mut safety_rules = SafetyRules::new(storage);
     if let Err(e) = safety_rules.consensus_state() {
         warn!("Unable to print consensus state: {}", e);
     }
 
     let mut serializer_service = SerializerService::new(safety_rules);
-    let mut network_server =
-        NetworkServer::new("safety-rules".to_string(), listen_addr, network_timeout_ms);
+    let mut network_server = match noise_config {
+        Some(noise_config) => NetworkServer::new_with_noise(
+            SERVICE_NAME.to_string(),
+            listen_addr,
+            network_timeout_ms,
+            NoiseServerConfig::new(
+                noise_config.private_key.private_key(),
+                noise_config
+                    .trusted_client_public_keys
+                    .into_iter()
+                    .collect(),
+            ),
+        ),
+        None => NetworkServer::new(SERVICE_NAME.to_string(), listen_addr, network_timeout_ms),
+    };
 
     loop {
         if let Err(e) = process_one_message(&mut network_server, &mut serializer_service) {
@@ -12,7 +12,9 @@ use crate::{
     SafetyRules, TSafetyRules,
 };
 use anyhow::anyhow;
-use aptos_config::config::{InitialSafetyRulesConfig, SafetyRulesConfig, SafetyRulesService};
+use aptos_config::config::{
+    InitialSafetyRulesConfig, RemoteServiceNoiseConfig, SafetyRulesConfig, SafetyRulesService,
+};
 use aptos_crypto::bls12381::PrivateKey;
 use aptos_global_constants::CONSENSUS_KEY;
 use aptos_infallible::RwLock;
@@ -101,7 +103,11 @@ pub struct SafetyRulesManager {
 impl SafetyRulesManager {
     pub fn new(config: &SafetyRulesConfig) -> Self {
         if let SafetyRulesService::Process(conf) = &config.service {
-            return Self::new_process(conf.server_address(), config.network_timeout_ms);
+            return Self::new_process(
+                conf.server_address(),
+                config.network_timeout_ms,
+                conf.noise.clone(),
+            );
         }
 
         let storage = storage(config);
@@ -120,8 +126,12 @@ impl SafetyRulesManager {
         }
     }
 
-    pub fn new_process(server_addr: SocketAddr, timeout_ms: u64) -> Self {
-        let process_service = ProcessService::new(server_addr, timeout_ms);
+    pub fn new_process(
+        server_addr: SocketAddr,
+        timeout_ms: u64,
+        noise_config: Option<RemoteServiceNoiseConfig>,
+    ) -> Self {
+        let process_service = ProcessService::new(server_addr, timeout_ms, noise_config);
         Self {
             internal_safety_rules: SafetyRulesWrapper::Process(process_service),
         }
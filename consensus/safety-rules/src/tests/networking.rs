@@ -2,8 +2,19 @@
 // Parts of the project are originally copyright © Meta Platforms, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{test_utils, SafetyRulesManager};
+use crate::{
+    process::ProcessService,
+    remote_service::{self, RemoteService},
+    test_utils, SafetyRulesManager, TSafetyRules,
+};
+use aptos_config::{config::RemoteServiceNoiseConfig, keys::ConfigKey, utils};
+use aptos_crypto::{x25519, Uniform};
 use aptos_types::validator_signer::ValidatorSigner;
+use rand::{rngs::StdRng, SeedableRng};
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    thread,
+};
 
 #[test]
 fn test_reconnect() {
@@ -18,3 +29,47 @@ fn test_reconnect() {
     let state1 = safety_rules_manager.client().consensus_state().unwrap();
     assert_eq!(state0, state1);
 }
+
+#[test]
+fn test_noise() {
+    let signer = ValidatorSigner::from_int(0);
+    let storage = test_utils::test_storage(&signer);
+    // test value for network timeout, in milliseconds.
+    let network_timeout = 5_000;
+
+    // Generate the pinned keys of the service and the client
+    let mut rng = StdRng::from_seed([0u8; 32]);
+    let server_private_key = x25519::PrivateKey::generate(&mut rng);
+    let server_public_key = server_private_key.public_key();
+    let client_private_key = x25519::PrivateKey::generate(&mut rng);
+    let client_public_key = client_private_key.public_key();
+
+    // Start the service
+    let listen_port = utils::get_available_port();
+    let listen_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), listen_port);
+    let server_noise_config = RemoteServiceNoiseConfig {
+        private_key: ConfigKey::new(server_private_key),
+        server_public_key: Some(server_public_key),
+        trusted_client_public_keys: vec![client_public_key],
+    };
+    thread::spawn(move || {
+        remote_service::execute(
+            storage,
+            listen_addr,
+            network_timeout,
+            Some(server_noise_config),
+        )
+    });
+
+    // Verify that the client can communicate with the service over noise
+    let client_noise_config = RemoteServiceNoiseConfig {
+        private_key: ConfigKey::new(client_private_key),
+        server_public_key: Some(server_public_key),
+        trusted_client_public_keys: vec![],
+    };
+    let process_service =
+        ProcessService::new(listen_addr, network_timeout, Some(client_noise_config));
+    let state0 = process_service.client().consensus_state().unwrap();
+    let state1 = process_service.client().consensus_state().unwrap();
+    assert_eq!(state0, state1);
+}
@@ -18,8 +18,12 @@ use crate::{
 };
 use anyhow::{bail, ensure, format_err, Context};
 use aptos_consensus_types::{
-    block::Block, common::Round, pipelined_block::PipelinedBlock, quorum_cert::QuorumCert,
-    sync_info::SyncInfo, timeout_2chain::TwoChainTimeoutCertificate,
+    block::Block,
+    common::Round,
+    pipelined_block::{ExecutionSummary, PipelinedBlock},
+    quorum_cert::QuorumCert,
+    sync_info::SyncInfo,
+    timeout_2chain::TwoChainTimeoutCertificate,
 };
 use aptos_crypto::{hash::ACCUMULATOR_PLACEHOLDER_HASH, HashValue};
 use aptos_executor_types::StateComputeResult;
@@ -587,6 +591,23 @@ impl BlockReader for BlockStore {
     fn pipeline_pending_latency(&self, proposal_timestamp: Duration) -> Duration {
         self.pipeline_pending_latency(proposal_timestamp)
     }
+
+    fn get_recent_block_execution_times(&self, num_blocks: usize) -> Vec<ExecutionSummary> {
+        let mut summaries = vec![];
+        let mut cur_block = Some(self.ordered_root());
+        // Walks back from the ordered root, the commit root and a few pruned blocks are
+        // still in memory.
+        while let Some(block) = cur_block {
+            if summaries.len() >= num_blocks {
+                break;
+            }
+            if let Some(summary) = block.get_execution_summary() {
+                summaries.push(summary);
+            }
+            cur_block = self.get_block(block.parent_id());
+        }
+        summaries
+    }
 }
 
 #[cfg(any(test, feature = "fuzzing"))]
@@ -0,0 +1,8 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+pub mod network_message;
+pub mod observer;
+pub mod publisher;
+#[cfg(test)]
+mod test_utils;
@@ -0,0 +1,259 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::{bail, ensure};
+use aptos_consensus_types::{block::Block, pipelined_block::PipelinedBlock};
+use aptos_crypto::HashValue;
+use aptos_network::ProtocolId;
+use aptos_types::{
+    epoch_state::EpochState, ledger_info::LedgerInfoWithSignatures, randomness::Randomness,
+    transaction::SignedTransaction,
+};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Supported protocols in preferred order (from highest priority to lowest).
+pub const DIRECT_SEND: &[ProtocolId] = &[ProtocolId::ConsensusObserver];
+
+/// Network type for the consensus observer
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum ConsensusObserverMessage {
+    /// A chain of ordered blocks (along with the transactions to execute for each
+    /// block), and the proof that they were ordered by consensus.
+    OrderedBlock(Box<OrderedBlock>),
+    /// The proof that the state computed by executing the ordered blocks (up to and
+    /// including the certified block) was committed by consensus.
+    CommitDecision(Box<LedgerInfoWithSignatures>),
+}
+
+impl ConsensusObserverMessage {
+    /// Creates an ordered block message from the given executed blocks
+    pub fn new_ordered_block_message(
+        executed_blocks: &[PipelinedBlock],
+        ordered_proof: LedgerInfoWithSignatures,
+    ) -> Self {
+        let blocks = executed_blocks
+            .iter()
+            .map(|executed_block| ObservedBlock {
+                block: executed_block.block().clone(),
+                transactions: executed_block.input_transactions().clone(),
+                randomness: executed_block.randomness().cloned(),
+            })
+            .collect();
+        Self::OrderedBlock(Box::new(OrderedBlock {
+            blocks,
+            ordered_proof,
+        }))
+    }
+
+    /// Creates a commit decision message from the given commit proof
+    pub fn new_commit_decision_message(commit_proof: LedgerInfoWithSignatures) -> Self {
+        Self::CommitDecision(Box::new(commit_proof))
+    }
+
+    /// Returns the epoch of the message
+    pub fn epoch(&self) -> u64 {
+        match self {
+            ConsensusObserverMessage::OrderedBlock(ordered_block) => {
+                ordered_block.ordered_proof.ledger_info().epoch()
+            },
+            ConsensusObserverMessage::CommitDecision(commit_proof) => {
+                commit_proof.ledger_info().epoch()
+            },
+        }
+    }
+
+    /// Returns a summary label for the message
+    pub fn get_label(&self) -> &'static str {
+        match self {
+            ConsensusObserverMessage::OrderedBlock(_) => "ordered_block",
+            ConsensusObserverMessage::CommitDecision(_) => "commit_decision",
+        }
+    }
+}
+
+impl Display for ConsensusObserverMessage {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            ConsensusObserverMessage::OrderedBlock(ordered_block) => {
+                write!(
+                    f,
+                    "OrderedBlock({})",
+                    ordered_block.ordered_proof.commit_info()
+                )
+            },
+            ConsensusObserverMessage::CommitDecision(commit_proof) => {
+                write!(f, "CommitDecision({})", commit_proof.commit_info())
+            },
+        }
+    }
+}
+
+/// A block ordered by consensus, along with the transactions that were executed for it
+/// (i.e., after the payload was resolved, filtered, deduplicated and shuffled), and the
+/// randomness generated for it (if any).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ObservedBlock {
+    pub block: Block,
+    pub transactions: Vec<SignedTransaction>,
+    pub randomness: Option<Randomness>,
+}
+
+/// A chain of ordered blocks and the ordered proof certifying the last one
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct OrderedBlock {
+    pub blocks: Vec<ObservedBlock>,
+    pub ordered_proof: LedgerInfoWithSignatures,
+}
+
+impl OrderedBlock {
+    /// Returns the id of the first block in the chain
+    pub fn first_block_parent_id(&self) -> HashValue {
+        self.blocks
+            .first()
+            .map(|observed_block| observed_block.block.parent_id())
+            .unwrap_or_default()
+    }
+
+    /// Returns the id of the last block in the chain
+    pub fn last_block_id(&self) -> HashValue {
+        self.ordered_proof.commit_info().id()
+    }
+
+    /// Verifies that the blocks form a chain and that the ordered proof certifies
+    /// the last block of the chain, with signatures from the given epoch.
+    ///
+    /// Note: the transactions and randomness are not covered by the ordered proof.
+    /// They are only trusted once the execution results match a commit decision.
+    pub fn verify(&self, epoch_state: &EpochState) -> anyhow::Result<()> {
+        let Some(last_block) = self.blocks.last() else {
+            bail!("The ordered block contains no blocks!");
+        };
+        ensure!(
+            self.ordered_proof.ledger_info().epoch() == epoch_state.epoch,
+            "The ordered proof is for epoch {}, but the current epoch is {}!",
+            self.ordered_proof.ledger_info().epoch(),
+            epoch_state.epoch
+        );
+        ensure!(
+            last_block.block.id() == self.last_block_id(),
+            "The last block id {} does not match the ordered proof {}!",
+            last_block.block.id(),
+            self.ordered_proof.commit_info()
+        );
+        for window in self.blocks.windows(2) {
+            ensure!(
+                window[1].block.parent_id() == window[0].block.id(),
+                "The blocks do not form a chain: {} is not the parent of {}!",
+                window[0].block.id(),
+                window[1].block.id()
+            );
+        }
+        for observed_block in &self.blocks {
+            observed_block.block.verify_well_formed()?;
+        }
+
+        self.ordered_proof
+            .verify_signatures(&epoch_state.verifier)
+            .map_err(|error| anyhow::anyhow!("Invalid ordered proof: {:?}", error))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::consensus_observer::test_utils::{create_blocks, create_ordered_block};
+    use aptos_consensus_types::block::block_test_utils::certificate_for_genesis;
+    use aptos_types::{
+        aggregate_signature::PartialSignatures,
+        validator_verifier::{generate_validator_verifier, random_validator_verifier},
+    };
+    use claims::{assert_err, assert_ok};
+
+    #[test]
+    fn test_verify_ordered_block() {
+        // Create an ordered block for a chain of blocks in epoch 1
+        let (signers, verifier) = random_validator_verifier(4, None, false);
+        let epoch_state = EpochState::new(1, verifier);
+        let blocks = create_blocks(&signers, certificate_for_genesis(), 1..=3);
+        let ordered_block = create_ordered_block(&signers, &blocks);
+
+        // Verify the ordered block
+        assert_ok!(ordered_block.verify(&epoch_state));
+        assert_eq!(ordered_block.first_block_parent_id(), blocks[0].parent_id());
+        assert_eq!(ordered_block.last_block_id(), blocks[2].id());
+
+        // Verify that the ordered block fails verification in another epoch
+        let next_epoch_state = EpochState::new(2, epoch_state.verifier.clone());
+        assert_err!(ordered_block.verify(&next_epoch_state));
+
+        // Verify that the ordered block fails verification without blocks
+        let mut empty_ordered_block = ordered_block.clone();
+        empty_ordered_block.blocks.clear();
+        assert_err!(empty_ordered_block.verify(&epoch_state));
+    }
+
+    #[test]
+    fn test_verify_ordered_block_chain() {
+        // Create two chains of blocks that fork at the genesis block
+        let (signers, verifier) = random_validator_verifier(4, None, false);
+        let epoch_state = EpochState::new(1, verifier);
+        let blocks = create_blocks(&signers, certificate_for_genesis(), 1..=3);
+        let forked_blocks = create_blocks(&signers, certificate_for_genesis(), 1..=3);
+
+        // Verify that the ordered proof must certify the last block
+        let mut ordered_block = create_ordered_block(&signers, &blocks);
+        ordered_block.blocks.pop();
+        assert_err!(ordered_block.verify(&epoch_state));
+
+        // Verify that the blocks must form a chain
+        let mut ordered_block = create_ordered_block(&signers, &blocks);
+        ordered_block.blocks[1] = ObservedBlock {
+            block: forked_blocks[1].clone(),
+            transactions: vec![],
+            randomness: None,
+        };
+        assert_err!(ordered_block.verify(&epoch_state));
+
+        // Verify that the blocks must be well formed (i.e., the round must increase)
+        let ill_formed_blocks = create_blocks(&signers, certificate_for_genesis(), 0..=0);
+        let ordered_block = create_ordered_block(&signers, &ill_formed_blocks);
+        assert_err!(ordered_block.verify(&epoch_state));
+    }
+
+    #[test]
+    fn test_verify_ordered_block_signatures() {
+        // Create an ordered block signed by validators of another validator set
+        let (signers, verifier) = random_validator_verifier(4, None, false);
+        let (other_signers, _) = random_validator_verifier(4, None, false);
+        let epoch_state = EpochState::new(1, verifier);
+        let blocks = create_blocks(&signers, certificate_for_genesis(), 1..=2);
+        let ordered_block = create_ordered_block(&other_signers, &blocks);
+
+        // Verify that the ordered proof fails verification
+        assert_err!(ordered_block.verify(&epoch_state));
+
+        // Verify that the ordered proof fails verification without a quorum
+        let mut ordered_block = create_ordered_block(&signers, &blocks);
+        let ledger_info = ordered_block.ordered_proof.ledger_info().clone();
+        let partial_signatures = PartialSignatures::new(
+            signers[..2]
+                .iter()
+                .map(|signer| (signer.author(), signer.sign(&ledger_info).unwrap()))
+                .collect(),
+        );
+        ordered_block.ordered_proof = LedgerInfoWithSignatures::new(
+            ledger_info,
+            epoch_state
+                .verifier
+                .aggregate_signatures(&partial_signatures)
+                .unwrap(),
+        );
+        assert_err!(ordered_block.verify(&epoch_state));
+
+        // Verify that the ordered proof passes verification with the signers of the epoch
+        let epoch_state = EpochState::new(1, generate_validator_verifier(&other_signers));
+        let ordered_block = create_ordered_block(&other_signers, &blocks);
+        assert_ok!(ordered_block.verify(&epoch_state));
+    }
+}
@@ -0,0 +1,1069 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    consensus_observer::{
+        network_message::{ConsensusObserverMessage, OrderedBlock},
+        publisher::{is_inbound_connection, ConsensusPublisher},
+    },
+    counters,
+    payload_manager::PayloadManager,
+    state_computer::PipelineExecutionResult,
+    state_replication::StateComputer,
+    transaction_deduper::NoOpDeduper,
+    transaction_shuffler::NoOpShuffler,
+};
+use aptos_config::{config::ConsensusObserverConfig, network_id::PeerNetworkId};
+use aptos_consensus_types::{block::Block, common::Round, pipelined_block::PipelinedBlock};
+use aptos_crypto::HashValue;
+use aptos_event_notifications::{DbBackedOnChainConfig, ReconfigNotificationListener};
+use aptos_executor_types::ExecutorResult;
+use aptos_infallible::Mutex;
+use aptos_logger::prelude::*;
+use aptos_network::{
+    application::{interface::NetworkServiceEvents, storage::PeersAndMetadata},
+    protocols::network::Event,
+};
+use aptos_storage_interface::DbReader;
+use aptos_types::{
+    epoch_state::EpochState,
+    ledger_info::{LedgerInfo, LedgerInfoWithSignatures},
+    on_chain_config::{
+        OnChainConfigPayload, OnChainConsensusConfig, OnChainExecutionConfig,
+        OnChainRandomnessConfig, RandomnessConfigMoveStruct, ValidatorSet,
+    },
+    transaction::SignedTransaction,
+};
+use futures::{
+    future::BoxFuture,
+    stream::{select_all, FuturesOrdered},
+    FutureExt, StreamExt,
+};
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+/// Pending ordered blocks are keyed by the epoch and round of their last block
+type OrderedBlockKey = (u64, Round);
+
+/// The result of executing a chain of ordered blocks
+type ExecutionFuture = BoxFuture<'static, (OrderedBlockKey, ExecutorResult<Vec<PipelinedBlock>>)>;
+
+/// An ordered block received from an upstream peer, waiting to be committed
+struct PendingOrderedBlock {
+    ordered_block: OrderedBlock,
+    executed_blocks: Option<Vec<PipelinedBlock>>,
+    commit_proof: Option<LedgerInfoWithSignatures>,
+}
+
+/// The consensus observer runs on fullnodes. It executes the ordered blocks published by
+/// its upstream peers (i.e., validators or VFNs) with the regular execution pipeline, and
+/// commits them once the execution results match a verified commit decision. Whenever the
+/// observer misses blocks (or the results do not match), it falls back to state sync.
+pub struct ConsensusObserver {
+    config: ConsensusObserverConfig,
+    db_reader: Arc<dyn DbReader>,
+    execution_proxy: Arc<dyn StateComputer>,
+    peers_and_metadata: Arc<PeersAndMetadata>,
+    reconfig_events: ReconfigNotificationListener<DbBackedOnChainConfig>,
+    // Republishes the verified messages to downstream peers (e.g., when running on VFNs)
+    consensus_publisher: Option<Arc<ConsensusPublisher>>,
+
+    // The epoch state used to verify incoming messages
+    epoch_state: Option<Arc<EpochState>>,
+    // The epoch the execution proxy is currently configured for
+    execution_epoch: Option<u64>,
+    // The latest ledger info committed to storage
+    root: LedgerInfoWithSignatures,
+    // The id of the last block scheduled for execution
+    execution_tip: HashValue,
+    pending_blocks: BTreeMap<OrderedBlockKey, PendingOrderedBlock>,
+    // The transactions of the pending blocks (read by the payload manager during execution)
+    block_transactions: Arc<Mutex<HashMap<HashValue, Vec<SignedTransaction>>>>,
+    executing_blocks: FuturesOrdered<ExecutionFuture>,
+}
+
+impl ConsensusObserver {
+    pub fn new(
+        config: ConsensusObserverConfig,
+        db_reader: Arc<dyn DbReader>,
+        execution_proxy: Arc<dyn StateComputer>,
+        peers_and_metadata: Arc<PeersAndMetadata>,
+        reconfig_events: ReconfigNotificationListener<DbBackedOnChainConfig>,
+        consensus_publisher: Option<Arc<ConsensusPublisher>>,
+    ) -> Self {
+        let root = db_reader
+            .get_latest_ledger_info()
+            .expect("Failed to read the latest ledger info from storage!");
+        let execution_tip = root_block_id(root.ledger_info());
+        Self {
+            config,
+            db_reader,
+            execution_proxy,
+            peers_and_metadata,
+            reconfig_events,
+            consensus_publisher,
+            epoch_state: None,
+            execution_epoch: None,
+            root,
+            execution_tip,
+            pending_blocks: BTreeMap::new(),
+            block_transactions: Arc::new(Mutex::new(HashMap::new())),
+            executing_blocks: FuturesOrdered::new(),
+        }
+    }
+
+    /// Starts the consensus observer loop
+    pub async fn start(
+        mut self,
+        network_service_events: NetworkServiceEvents<ConsensusObserverMessage>,
+    ) {
+        // Transform events to also include the network id
+        let network_events: Vec<_> = network_service_events
+            .into_network_and_events()
+            .into_iter()
+            .map(|(network_id, events)| events.map(move |event| (network_id, event)))
+            .collect();
+        let mut network_events = select_all(network_events).fuse();
+
+        // The initial reconfig notification is only sent once the node has bootstrapped
+        let reconfig_notification = self
+            .reconfig_events
+            .next()
+            .await
+            .expect("Reconfig sender dropped, unable to start the consensus observer!");
+        self.root = self.get_latest_ledger_info();
+        self.execution_tip = root_block_id(self.root.ledger_info());
+        self.process_reconfig_notification(reconfig_notification.on_chain_configs)
+            .await;
+
+        info!(
+            "Consensus observer started at root {}",
+            self.root.commit_info()
+        );
+        loop {
+            tokio::select! {
+                Some((network_id, event)) = network_events.next() => {
+                    if let Event::Message(peer_id, message) = event {
+                        let peer_network_id = PeerNetworkId::new(network_id, peer_id);
+                        self.process_network_message(peer_network_id, message).await;
+                    }
+                },
+                Some((key, result)) = self.executing_blocks.next(), if !self.executing_blocks.is_empty() => {
+                    self.process_execution_result(key, result).await;
+                },
+                Some(reconfig_notification) = self.reconfig_events.next() => {
+                    self.process_reconfig_notification(reconfig_notification.on_chain_configs).await;
+                },
+                else => break,
+            }
+        }
+        error!("The consensus observer loop stopped unexpectedly!");
+    }
+
+    /// Processes a message sent by a peer
+    async fn process_network_message(
+        &mut self,
+        peer_network_id: PeerNetworkId,
+        message: ConsensusObserverMessage,
+    ) {
+        let message_label = message.get_label();
+        let outcome = if !self.is_upstream_peer(&peer_network_id) {
+            "unexpected_peer"
+        } else {
+            match message {
+                ConsensusObserverMessage::OrderedBlock(ordered_block) => {
+                    self.process_ordered_block(*ordered_block).await
+                },
+                ConsensusObserverMessage::CommitDecision(commit_proof) => {
+                    self.process_commit_decision(*commit_proof).await
+                },
+            }
+        };
+        counters::CONSENSUS_OBSERVER_RECEIVED_MESSAGES
+            .with_label_values(&[message_label, outcome])
+            .inc();
+    }
+
+    /// Returns true iff this node connected to the given peer (i.e., the peer is upstream)
+    fn is_upstream_peer(&self, peer_network_id: &PeerNetworkId) -> bool {
+        self.peers_and_metadata
+            .get_metadata_for_peer(*peer_network_id)
+            .map(|peer_metadata| !is_inbound_connection(&peer_metadata.get_connection_metadata()))
+            .unwrap_or(false)
+    }
+
+    /// Verifies the ordered block and schedules it for execution (if it extends the
+    /// chain of executing blocks). Returns the outcome label for the metrics.
+    async fn process_ordered_block(&mut self, ordered_block: OrderedBlock) -> &'static str {
+        let Some(epoch_state) = self.epoch_state.clone() else {
+            return "no_epoch_state";
+        };
+        let key = ordered_block_key(&ordered_block.ordered_proof);
+        if key <= ordered_block_key(&self.root) || self.pending_blocks.contains_key(&key) {
+            return "duplicate";
+        }
+        if let Err(error) = ordered_block.verify(&epoch_state) {
+            debug!("Dropping an invalid ordered block: {:?}", error);
+            return "invalid";
+        }
+        if self.pending_blocks.len() as u64 >= self.config.max_pending_blocks {
+            return "too_many_pending_blocks";
+        }
+
+        if let Some(consensus_publisher) = &self.consensus_publisher {
+            consensus_publisher.publish_message(ConsensusObserverMessage::OrderedBlock(Box::new(
+                ordered_block.clone(),
+            )));
+        }
+
+        {
+            let mut block_transactions = self.block_transactions.lock();
+            for observed_block in &ordered_block.blocks {
+                block_transactions.insert(
+                    observed_block.block.id(),
+                    observed_block.transactions.clone(),
+                );
+            }
+        }
+        self.pending_blocks.insert(key, PendingOrderedBlock {
+            ordered_block,
+            executed_blocks: None,
+            commit_proof: None,
+        });
+        self.execute_pending_blocks().await;
+
+        "accepted"
+    }
+
+    /// Verifies the commit decision and commits the matching blocks (or falls back to
+    /// state sync if the blocks are missing). Returns the outcome label for the metrics.
+    async fn process_commit_decision(
+        &mut self,
+        commit_proof: LedgerInfoWithSignatures,
+    ) -> &'static str {
+        let Some(epoch_state) = self.epoch_state.clone() else {
+            return "no_epoch_state";
+        };
+        let key = ordered_block_key(&commit_proof);
+        if key <= ordered_block_key(&self.root) {
+            return "duplicate";
+        }
+        if commit_proof.ledger_info().epoch() != epoch_state.epoch {
+            return "unexpected_epoch";
+        }
+        if let Err(error) = commit_proof.verify_signatures(&epoch_state.verifier) {
+            debug!("Dropping an invalid commit decision: {:?}", error);
+            return "invalid";
+        }
+
+        if let Some(consensus_publisher) = &self.consensus_publisher {
+            consensus_publisher.publish_message(
+                ConsensusObserverMessage::new_commit_decision_message(commit_proof.clone()),
+            );
+        }
+
+        match self.pending_blocks.get_mut(&key) {
+            Some(pending_block) => {
+                pending_block.commit_proof = Some(commit_proof);
+                self.try_commit_pending_blocks().await;
+            },
+            None => {
+                info!(
+                    "Missing the blocks for commit decision {}. Falling back to state sync!",
+                    commit_proof.commit_info()
+                );
+                self.sync_to_commit(commit_proof).await;
+            },
+        }
+
+        "accepted"
+    }
+
+    /// Processes the results of executing a chain of ordered blocks
+    async fn process_execution_result(
+        &mut self,
+        key: OrderedBlockKey,
+        result: ExecutorResult<Vec<PipelinedBlock>>,
+    ) {
+        match result {
+            Ok(executed_blocks) => {
+                if let Some(pending_block) = self.pending_blocks.get_mut(&key) {
+                    pending_block.executed_blocks = Some(executed_blocks);
+                }
+                self.try_commit_pending_blocks().await;
+            },
+            Err(error) => {
+                // Wait for a commit decision to sync to
+                warn!(
+                    "Failed to execute the ordered block at {:?}: {:?}. Dropping the pending blocks!",
+                    key, error
+                );
+                self.drain_executing_blocks().await;
+                self.remove_pending_blocks(|_| true);
+                self.execution_tip = root_block_id(self.root.ledger_info());
+            },
+        }
+    }
+
+    /// Processes a reconfig notification: sets up the execution proxy for the new epoch
+    async fn process_reconfig_notification(
+        &mut self,
+        payload: OnChainConfigPayload<DbBackedOnChainConfig>,
+    ) {
+        let validator_set: ValidatorSet = payload
+            .get()
+            .expect("failed to get ValidatorSet from payload");
+        let epoch_state = Arc::new(EpochState {
+            epoch: payload.epoch(),
+            verifier: (&validator_set).into(),
+        });
+
+        let consensus_config: OnChainConsensusConfig = payload.get().unwrap_or_default();
+        let execution_config = payload
+            .get::<OnChainExecutionConfig>()
+            .unwrap_or_else(|_| OnChainExecutionConfig::default_if_missing());
+        let randomness_config = payload
+            .get::<RandomnessConfigMoveStruct>()
+            .and_then(OnChainRandomnessConfig::try_from)
+            .unwrap_or_else(|_| OnChainRandomnessConfig::default_if_missing());
+
+        // The transactions were already filtered, deduplicated and shuffled by the publisher
+        self.execution_proxy.new_epoch(
+            &epoch_state,
+            Arc::new(PayloadManager::ConsensusObserver(
+                self.block_transactions.clone(),
+            )),
+            Arc::new(NoOpShuffler {}),
+            execution_config.block_executor_onchain_config(),
+            Arc::new(NoOpDeduper {}),
+            consensus_config.is_vtxn_enabled() && randomness_config.randomness_enabled(),
+        );
+        info!("Consensus observer started epoch {}", epoch_state.epoch);
+
+        self.epoch_state = Some(epoch_state.clone());
+        self.execution_epoch = Some(epoch_state.epoch);
+        self.remove_pending_blocks(|(epoch, _)| *epoch < epoch_state.epoch);
+        self.execute_pending_blocks().await;
+    }
+
+    /// Schedules the execution of the pending blocks that extend the execution tip
+    async fn execute_pending_blocks(&mut self) {
+        loop {
+            let Some((key, pending_block)) =
+                self.pending_blocks.iter().find(|(_, pending_block)| {
+                    pending_block.ordered_block.first_block_parent_id() == self.execution_tip
+                })
+            else {
+                return;
+            };
+            if Some(key.0) != self.execution_epoch {
+                return; // Wait for the reconfig notification of the new epoch
+            }
+
+            // Schedule the blocks in order, and wait for the results in the returned future
+            let key = *key;
+            let ordered_block = pending_block.ordered_block.clone();
+            let mut parent_block_id = self.execution_tip;
+            let mut blocks_and_futures = vec![];
+            for observed_block in ordered_block.blocks {
+                let future = self
+                    .execution_proxy
+                    .schedule_compute(
+                        &observed_block.block,
+                        parent_block_id,
+                        observed_block.randomness.clone(),
+                    )
+                    .await;
+                parent_block_id = observed_block.block.id();
+                blocks_and_futures.push((observed_block, future));
+            }
+            self.executing_blocks.push_back(
+                async move {
+                    let mut executed_blocks = vec![];
+                    for (observed_block, future) in blocks_and_futures {
+                        let PipelineExecutionResult {
+                            input_txns, result, ..
+                        } = match future.await {
+                            Ok(result) => result,
+                            Err(error) => return (key, Err(error)),
+                        };
+                        let executed_block =
+                            PipelinedBlock::new(observed_block.block, input_txns, result);
+                        if let Some(randomness) = observed_block.randomness {
+                            executed_block.set_randomness(randomness);
+                        }
+                        executed_blocks.push(executed_block);
+                    }
+                    (key, Ok(executed_blocks))
+                }
+                .boxed(),
+            );
+            self.execution_tip = parent_block_id;
+        }
+    }
+
+    /// Commits the pending blocks that are certified by a commit decision and whose
+    /// execution results match it.
+    async fn try_commit_pending_blocks(&mut self) {
+        loop {
+            let Some((commit_key, commit_proof)) =
+                self.pending_blocks.iter().find_map(|(key, pending_block)| {
+                    pending_block
+                        .commit_proof
+                        .clone()
+                        .map(|commit_proof| (*key, commit_proof))
+                })
+            else {
+                return;
+            };
+
+            // Gather the executed blocks (up to and including the certified block)
+            let mut blocks_to_commit = vec![];
+            for (_, pending_block) in self.pending_blocks.range(..=commit_key) {
+                match &pending_block.executed_blocks {
+                    Some(executed_blocks) => blocks_to_commit.extend(
+                        executed_blocks
+                            .iter()
+                            .map(|executed_block| Arc::new(executed_block.clone())),
+                    ),
+                    None => return, // Wait for the execution to complete
+                }
+            }
+
+            // Verify the execution results against the commit decision
+            let executed_block_info = blocks_to_commit
+                .last()
+                .expect("The committed blocks should not be empty!")
+                .block_info();
+            let commit_info = commit_proof.commit_info();
+            if executed_block_info.id() != commit_info.id()
+                || executed_block_info.executed_state_id() != commit_info.executed_state_id()
+                || executed_block_info.version() != commit_info.version()
+            {
+                error!(
+                    "The execution result {} does not match the commit decision {}. Falling back to state sync!",
+                    executed_block_info, commit_info
+                );
+                return self.sync_to_commit(commit_proof).await;
+            }
+
+            // Verify that storage has not been updated by state sync in the meantime
+            let latest_ledger_info = self.get_latest_ledger_info();
+            if latest_ledger_info.ledger_info().version() != self.root.ledger_info().version() {
+                info!(
+                    "Storage moved from {} to {}. Resetting the consensus observer!",
+                    self.root.commit_info(),
+                    latest_ledger_info.commit_info()
+                );
+                return self.sync_to_commit(commit_proof).await;
+            }
+
+            if let Err(error) = self
+                .execution_proxy
+                .commit(&blocks_to_commit, commit_proof.clone(), Box::new(|_, _| {}))
+                .await
+            {
+                error!("Failed to commit the blocks: {:?}", error);
+                return self.sync_to_commit(commit_proof).await;
+            }
+            self.update_root(commit_proof);
+        }
+    }
+
+    /// Falls back to state sync (up to the given commit decision), and resets the execution
+    /// tip to the new root. Pending blocks beyond the target are kept.
+    async fn sync_to_commit(&mut self, commit_proof: LedgerInfoWithSignatures) {
+        counters::CONSENSUS_OBSERVER_STATE_SYNC_FALLBACKS.inc();
+        self.drain_executing_blocks().await;
+
+        // State sync might have already moved beyond the target
+        let latest_ledger_info = self.get_latest_ledger_info();
+        let sync_target =
+            if latest_ledger_info.ledger_info().version() >= commit_proof.ledger_info().version() {
+                latest_ledger_info
+            } else {
+                commit_proof
+            };
+        if let Err(error) = self.execution_proxy.sync_to(sync_target.clone()).await {
+            warn!(
+                "Failed to sync to {}: {:?}",
+                sync_target.commit_info(),
+                error
+            );
+        }
+
+        // Drop the executed (but not committed) results, and start executing again from the root
+        for pending_block in self.pending_blocks.values_mut() {
+            pending_block.executed_blocks = None;
+        }
+        self.update_root(self.get_latest_ledger_info());
+        self.execution_tip = root_block_id(self.root.ledger_info());
+        self.execute_pending_blocks().await;
+    }
+
+    /// Updates the root to the given committed ledger info, and removes the pending
+    /// blocks that it covers.
+    fn update_root(&mut self, root: LedgerInfoWithSignatures) {
+        let root_key = ordered_block_key(&root);
+        self.remove_pending_blocks(|key| *key <= root_key);
+        if let Some(next_epoch_state) = root.ledger_info().next_epoch_state() {
+            // Messages of the next epoch can be verified right away (but the blocks are only
+            // executed once the reconfig notification is processed).
+            self.epoch_state = Some(Arc::new(next_epoch_state.clone()));
+            self.execution_tip = root_block_id(root.ledger_info());
+        }
+
+        counters::CONSENSUS_OBSERVER_COMMITTED_ROUND.set(root.commit_info().round() as i64);
+        self.root = root;
+    }
+
+    /// Removes the pending blocks (and their transactions) whose keys match the predicate
+    fn remove_pending_blocks(&mut self, predicate: impl Fn(&OrderedBlockKey) -> bool) {
+        let keys_to_remove: Vec<_> = self
+            .pending_blocks
+            .keys()
+            .filter(|key| predicate(key))
+            .cloned()
+            .collect();
+        let mut block_transactions = self.block_transactions.lock();
+        for key in keys_to_remove {
+            if let Some(pending_block) = self.pending_blocks.remove(&key) {
+                for observed_block in pending_block.ordered_block.blocks {
+                    block_transactions.remove(&observed_block.block.id());
+                }
+            }
+        }
+    }
+
+    /// Waits for all in-flight executions to complete (ignoring the results)
+    async fn drain_executing_blocks(&mut self) {
+        while self.executing_blocks.next().await.is_some() {}
+    }
+
+    /// Returns the latest ledger info committed to storage
+    fn get_latest_ledger_info(&self) -> LedgerInfoWithSignatures {
+        self.db_reader
+            .get_latest_ledger_info()
+            .expect("Failed to read the latest ledger info from storage!")
+    }
+}
+
+/// Returns the key of the ordered block certified by the given ledger info
+fn ordered_block_key(ledger_info: &LedgerInfoWithSignatures) -> OrderedBlockKey {
+    (
+        ledger_info.ledger_info().epoch(),
+        ledger_info.ledger_info().round(),
+    )
+}
+
+/// Returns the id of the block that the next blocks extend, given the committed ledger
+/// info (i.e., the genesis block of the next epoch if the ledger info ends the epoch).
+fn root_block_id(ledger_info: &LedgerInfo) -> HashValue {
+    if ledger_info.ends_epoch() {
+        Block::make_genesis_block_from_ledger_info(ledger_info).id()
+    } else {
+        ledger_info.commit_info().id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        consensus_observer::test_utils::{
+            compute_result, create_blocks, create_commit_decision, create_ordered_block,
+            executed_block_info,
+        },
+        error::StateSyncError,
+        state_computer::StateComputeResultFut,
+        state_replication::StateComputerCommitCallBackType,
+        transaction_deduper::TransactionDeduper,
+        transaction_shuffler::TransactionShuffler,
+    };
+    use aptos_channels::{aptos_channel, message_queues::QueueStyle};
+    use aptos_config::{config::PeerRole, network_id::NetworkId};
+    use aptos_consensus_types::{
+        block::block_test_utils::certificate_for_genesis, quorum_cert::QuorumCert,
+    };
+    use aptos_netcore::transport::ConnectionOrigin;
+    use aptos_network::transport::ConnectionMetadata;
+    use aptos_types::{
+        aggregate_signature::AggregateSignature,
+        block_executor::config::BlockExecutorConfigFromOnchain,
+        block_info::BlockInfo,
+        randomness::Randomness,
+        validator_signer::ValidatorSigner,
+        validator_verifier::{generate_validator_verifier, random_validator_verifier},
+        PeerId,
+    };
+    use std::time::Duration;
+
+    /// A state computer that executes blocks instantly (see `compute_result`), and
+    /// applies the commits and state sync targets to the shared storage
+    struct TestStateComputer {
+        storage: Arc<Mutex<LedgerInfoWithSignatures>>,
+        committed_blocks: Mutex<Vec<HashValue>>,
+        sync_targets: Mutex<Vec<LedgerInfoWithSignatures>>,
+    }
+
+    #[async_trait::async_trait]
+    impl StateComputer for TestStateComputer {
+        async fn schedule_compute(
+            &self,
+            block: &Block,
+            _parent_block_id: HashValue,
+            _randomness: Option<Randomness>,
+        ) -> StateComputeResultFut {
+            let result =
+                PipelineExecutionResult::new(vec![], compute_result(block), Duration::ZERO);
+            Box::pin(async move { Ok(result) })
+        }
+
+        async fn commit(
+            &self,
+            blocks: &[Arc<PipelinedBlock>],
+            finality_proof: LedgerInfoWithSignatures,
+            _callback: StateComputerCommitCallBackType,
+        ) -> ExecutorResult<()> {
+            self.committed_blocks
+                .lock()
+                .extend(blocks.iter().map(|block| block.id()));
+            *self.storage.lock() = finality_proof;
+            Ok(())
+        }
+
+        async fn sync_to(&self, target: LedgerInfoWithSignatures) -> Result<(), StateSyncError> {
+            self.sync_targets.lock().push(target.clone());
+            *self.storage.lock() = target;
+            Ok(())
+        }
+
+        fn new_epoch(
+            &self,
+            _: &EpochState,
+            _: Arc<PayloadManager>,
+            _: Arc<dyn TransactionShuffler>,
+            _: BlockExecutorConfigFromOnchain,
+            _: Arc<dyn TransactionDeduper>,
+            _: bool,
+        ) {
+        }
+
+        fn end_epoch(&self) {}
+    }
+
+    /// A database reader that only serves the latest ledger info of the shared storage
+    struct TestDbReader {
+        storage: Arc<Mutex<LedgerInfoWithSignatures>>,
+    }
+
+    impl DbReader for TestDbReader {
+        fn get_latest_ledger_info_option(
+            &self,
+        ) -> aptos_storage_interface::Result<Option<LedgerInfoWithSignatures>> {
+            Ok(Some(self.storage.lock().clone()))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_process_ordered_block() {
+        // Create an observer and an ordered block
+        let (signers, _) = random_validator_verifier(4, None, false);
+        let (mut observer, _) = create_observer(&signers);
+        let blocks = create_blocks(&signers, certificate_for_genesis(), 1..=3);
+        let ordered_block = create_ordered_block(&signers, &blocks[..2]);
+
+        // Verify that the ordered block is dropped without an epoch state
+        let epoch_state = observer.epoch_state.take();
+        assert_eq!(
+            observer.process_ordered_block(ordered_block.clone()).await,
+            "no_epoch_state"
+        );
+        observer.epoch_state = epoch_state;
+
+        // Verify that the ordered block is dropped if it is sent by a downstream peer
+        let downstream_peer = create_peer(&observer, ConnectionOrigin::Inbound);
+        let message = ConsensusObserverMessage::OrderedBlock(Box::new(ordered_block.clone()));
+        observer
+            .process_network_message(downstream_peer, message.clone())
+            .await;
+        let unknown_peer = PeerNetworkId::new(NetworkId::Public, PeerId::random());
+        observer
+            .process_network_message(unknown_peer, message.clone())
+            .await;
+        assert!(observer.pending_blocks.is_empty());
+
+        // Verify that the ordered block is executed if it is sent by an upstream peer
+        let upstream_peer = create_peer(&observer, ConnectionOrigin::Outbound);
+        observer
+            .process_network_message(upstream_peer, message)
+            .await;
+        assert_eq!(observer.pending_blocks.len(), 1);
+        assert_eq!(observer.executing_blocks.len(), 1);
+        assert_eq!(observer.execution_tip, blocks[1].id());
+
+        // Verify that duplicate and invalid ordered blocks are dropped
+        assert_eq!(
+            observer.process_ordered_block(ordered_block).await,
+            "duplicate"
+        );
+        let (other_signers, _) = random_validator_verifier(4, None, false);
+        let invalid_ordered_block = create_ordered_block(&other_signers, &blocks[2..]);
+        assert_eq!(
+            observer.process_ordered_block(invalid_ordered_block).await,
+            "invalid"
+        );
+
+        // Verify that the ordered block is dropped if there are too many pending blocks
+        observer.config.max_pending_blocks = 1;
+        let ordered_block = create_ordered_block(&signers, &blocks[2..]);
+        assert_eq!(
+            observer.process_ordered_block(ordered_block).await,
+            "too_many_pending_blocks"
+        );
+        assert_eq!(observer.pending_blocks.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_execute_out_of_order_blocks() {
+        // Create an observer and two consecutive ordered blocks
+        let (signers, _) = random_validator_verifier(4, None, false);
+        let (mut observer, _) = create_observer(&signers);
+        let blocks = create_blocks(&signers, certificate_for_genesis(), 1..=4);
+        let first_ordered_block = create_ordered_block(&signers, &blocks[..2]);
+        let second_ordered_block = create_ordered_block(&signers, &blocks[2..]);
+
+        // Verify that the second ordered block waits for its parent
+        assert_eq!(
+            observer.process_ordered_block(second_ordered_block).await,
+            "accepted"
+        );
+        assert_eq!(observer.pending_blocks.len(), 1);
+        assert!(observer.executing_blocks.is_empty());
+
+        // Verify that both ordered blocks are executed once the first one is received
+        assert_eq!(
+            observer.process_ordered_block(first_ordered_block).await,
+            "accepted"
+        );
+        assert_eq!(observer.pending_blocks.len(), 2);
+        assert_eq!(observer.executing_blocks.len(), 2);
+        assert_eq!(observer.execution_tip, blocks[3].id());
+        assert_eq!(observer.block_transactions.lock().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_commit_pending_blocks_in_order() {
+        // Create an observer and process two consecutive ordered blocks
+        let (signers, _) = random_validator_verifier(4, None, false);
+        let (mut observer, execution_proxy) = create_observer(&signers);
+        let blocks = create_blocks(&signers, certificate_for_genesis(), 1..=4);
+        observer
+            .process_ordered_block(create_ordered_block(&signers, &blocks[..2]))
+            .await;
+        observer
+            .process_ordered_block(create_ordered_block(&signers, &blocks[2..]))
+            .await;
+
+        // Process the commit decision for the second ordered block before any execution
+        // results, and verify that nothing is committed.
+        let commit_decision = create_commit_decision(&signers, executed_block_info(&blocks[3]));
+        assert_eq!(
+            observer
+                .process_commit_decision(commit_decision.clone())
+                .await,
+            "accepted"
+        );
+        assert!(execution_proxy.committed_blocks.lock().is_empty());
+
+        // Verify that nothing is committed until all the blocks have been executed
+        process_next_execution_result(&mut observer).await;
+        assert!(execution_proxy.committed_blocks.lock().is_empty());
+        process_next_execution_result(&mut observer).await;
+
+        // Verify that all the blocks were committed in order
+        let block_ids: Vec<_> = blocks.iter().map(|block| block.id()).collect();
+        assert_eq!(*execution_proxy.committed_blocks.lock(), block_ids);
+        assert_eq!(observer.root, commit_decision);
+        assert!(observer.pending_blocks.is_empty());
+        assert!(observer.block_transactions.lock().is_empty());
+        assert!(execution_proxy.sync_targets.lock().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_commit_matching() {
+        // Create an observer, and process and execute two consecutive ordered blocks
+        let (signers, _) = random_validator_verifier(4, None, false);
+        let (mut observer, execution_proxy) = create_observer(&signers);
+        let blocks = create_blocks(&signers, certificate_for_genesis(), 1..=4);
+        observer
+            .process_ordered_block(create_ordered_block(&signers, &blocks[..2]))
+            .await;
+        observer
+            .process_ordered_block(create_ordered_block(&signers, &blocks[2..]))
+            .await;
+        process_next_execution_result(&mut observer).await;
+        process_next_execution_result(&mut observer).await;
+
+        // Verify that a commit decision only commits the blocks it certifies
+        let commit_decision = create_commit_decision(&signers, executed_block_info(&blocks[1]));
+        observer
+            .process_commit_decision(commit_decision.clone())
+            .await;
+        assert_eq!(*execution_proxy.committed_blocks.lock(), vec![
+            blocks[0].id(),
+            blocks[1].id()
+        ]);
+        assert_eq!(observer.root, commit_decision);
+        assert_eq!(observer.pending_blocks.len(), 1);
+
+        // Verify that stale commit decisions are dropped
+        assert_eq!(
+            observer.process_commit_decision(commit_decision).await,
+            "duplicate"
+        );
+
+        // Verify that the observer falls back to state sync if the execution results
+        // do not match the commit decision.
+        let block_info = executed_block_info(&blocks[3]);
+        let mismatched_block_info = BlockInfo::new(
+            block_info.epoch(),
+            block_info.round(),
+            block_info.id(),
+            HashValue::random(),
+            block_info.version(),
+            block_info.timestamp_usecs(),
+            None,
+        );
+        let commit_decision = create_commit_decision(&signers, mismatched_block_info);
+        observer
+            .process_commit_decision(commit_decision.clone())
+            .await;
+        assert_eq!(execution_proxy.committed_blocks.lock().len(), 2);
+        assert_eq!(*execution_proxy.sync_targets.lock(), vec![
+            commit_decision.clone()
+        ]);
+        assert_eq!(observer.root, commit_decision);
+        assert!(observer.pending_blocks.is_empty());
+        assert_eq!(observer.execution_tip, blocks[3].id());
+    }
+
+    #[tokio::test]
+    async fn test_state_sync_fallback_on_missing_blocks() {
+        // Create an observer
+        let (signers, _) = random_validator_verifier(4, None, false);
+        let (mut observer, execution_proxy) = create_observer(&signers);
+        let blocks = create_blocks(&signers, certificate_for_genesis(), 1..=4);
+
+        // Verify that invalid commit decisions are dropped
+        let (other_signers, _) = random_validator_verifier(4, None, false);
+        let invalid_commit_decision =
+            create_commit_decision(&other_signers, executed_block_info(&blocks[1]));
+        assert_eq!(
+            observer
+                .process_commit_decision(invalid_commit_decision)
+                .await,
+            "invalid"
+        );
+        let block_info = executed_block_info(&blocks[1]);
+        let next_epoch_block_info = BlockInfo::new(
+            block_info.epoch() + 1,
+            block_info.round(),
+            block_info.id(),
+            block_info.executed_state_id(),
+            block_info.version(),
+            block_info.timestamp_usecs(),
+            None,
+        );
+        let next_epoch_commit_decision = create_commit_decision(&signers, next_epoch_block_info);
+        assert_eq!(
+            observer
+                .process_commit_decision(next_epoch_commit_decision)
+                .await,
+            "unexpected_epoch"
+        );
+        assert!(execution_proxy.sync_targets.lock().is_empty());
+
+        // Verify that the observer falls back to state sync if the certified blocks are missing
+        let commit_decision = create_commit_decision(&signers, block_info);
+        assert_eq!(
+            observer
+                .process_commit_decision(commit_decision.clone())
+                .await,
+            "accepted"
+        );
+        assert_eq!(*execution_proxy.sync_targets.lock(), vec![
+            commit_decision.clone()
+        ]);
+        assert_eq!(observer.root, commit_decision);
+        assert_eq!(observer.execution_tip, blocks[1].id());
+
+        // Verify that the observer executes the blocks that extend the synced root
+        observer
+            .process_ordered_block(create_ordered_block(&signers, &blocks[2..]))
+            .await;
+        assert_eq!(observer.executing_blocks.len(), 1);
+        process_next_execution_result(&mut observer).await;
+        let commit_decision = create_commit_decision(&signers, executed_block_info(&blocks[3]));
+        observer
+            .process_commit_decision(commit_decision.clone())
+            .await;
+        assert_eq!(*execution_proxy.committed_blocks.lock(), vec![
+            blocks[2].id(),
+            blocks[3].id()
+        ]);
+        assert_eq!(observer.root, commit_decision);
+    }
+
+    #[tokio::test]
+    async fn test_state_sync_fallback_on_storage_update() {
+        // Create an observer, and process and execute an ordered block
+        let (signers, _) = random_validator_verifier(4, None, false);
+        let (mut observer, execution_proxy) = create_observer(&signers);
+        let blocks = create_blocks(&signers, certificate_for_genesis(), 1..=4);
+        observer
+            .process_ordered_block(create_ordered_block(&signers, &blocks[..2]))
+            .await;
+        process_next_execution_result(&mut observer).await;
+
+        // Move storage beyond the ordered block (e.g., state sync took over)
+        let synced_ledger_info = create_commit_decision(&signers, executed_block_info(&blocks[3]));
+        *execution_proxy.storage.lock() = synced_ledger_info.clone();
+
+        // Verify that the observer syncs to storage instead of committing the blocks
+        let commit_decision = create_commit_decision(&signers, executed_block_info(&blocks[1]));
+        observer.process_commit_decision(commit_decision).await;
+        assert!(execution_proxy.committed_blocks.lock().is_empty());
+        assert_eq!(*execution_proxy.sync_targets.lock(), vec![
+            synced_ledger_info.clone()
+        ]);
+        assert_eq!(observer.root, synced_ledger_info);
+        assert!(observer.pending_blocks.is_empty());
+        assert_eq!(observer.execution_tip, blocks[3].id());
+    }
+
+    #[tokio::test]
+    async fn test_epoch_change() {
+        // Create an observer, and process and execute the last ordered block of the epoch
+        let (signers, _) = random_validator_verifier(4, None, false);
+        let (mut observer, execution_proxy) = create_observer(&signers);
+        let blocks = create_blocks(&signers, certificate_for_genesis(), 1..=2);
+        observer
+            .process_ordered_block(create_ordered_block(&signers, &blocks))
+            .await;
+        process_next_execution_result(&mut observer).await;
+
+        // Commit the blocks with a commit decision that ends the epoch
+        let (next_signers, next_verifier) = random_validator_verifier(4, None, false);
+        let block_info = executed_block_info(&blocks[1]);
+        let epoch_ending_block_info = BlockInfo::new(
+            block_info.epoch(),
+            block_info.round(),
+            block_info.id(),
+            block_info.executed_state_id(),
+            block_info.version(),
+            block_info.timestamp_usecs(),
+            Some(EpochState::new(2, next_verifier)),
+        );
+        let commit_decision = create_commit_decision(&signers, epoch_ending_block_info);
+        observer
+            .process_commit_decision(commit_decision.clone())
+            .await;
+        assert_eq!(execution_proxy.committed_blocks.lock().len(), 2);
+
+        // Verify that the root moved to the next epoch
+        let genesis_block =
+            Block::make_genesis_block_from_ledger_info(commit_decision.ledger_info());
+        assert_eq!(observer.root, commit_decision);
+        assert_eq!(observer.epoch_state.as_ref().unwrap().epoch, 2);
+        assert_eq!(observer.execution_tip, genesis_block.id());
+
+        // Verify that the ordered blocks of the next epoch are verified, but only executed
+        // once the execution proxy is configured for the next epoch.
+        let genesis_qc = QuorumCert::certificate_for_genesis_from_ledger_info(
+            commit_decision.ledger_info(),
+            genesis_block.id(),
+        );
+        let next_epoch_blocks = create_blocks(&next_signers, genesis_qc, 1..=2);
+        assert_eq!(
+            observer
+                .process_ordered_block(create_ordered_block(&signers, &next_epoch_blocks))
+                .await,
+            "invalid"
+        );
+        assert_eq!(
+            observer
+                .process_ordered_block(create_ordered_block(&next_signers, &next_epoch_blocks))
+                .await,
+            "accepted"
+        );
+        assert!(observer.executing_blocks.is_empty());
+        observer.execution_epoch = Some(2);
+        observer.execute_pending_blocks().await;
+        assert_eq!(observer.executing_blocks.len(), 1);
+
+        // Verify that the blocks of the next epoch are committed
+        process_next_execution_result(&mut observer).await;
+        let commit_decision =
+            create_commit_decision(&next_signers, executed_block_info(&next_epoch_blocks[1]));
+        observer
+            .process_commit_decision(commit_decision.clone())
+            .await;
+        assert_eq!(execution_proxy.committed_blocks.lock().len(), 4);
+        assert_eq!(observer.root, commit_decision);
+    }
+
+    /// Creates a consensus observer for epoch 1 (rooted at genesis), along with its
+    /// execution proxy
+    fn create_observer(signers: &[ValidatorSigner]) -> (ConsensusObserver, Arc<TestStateComputer>) {
+        let storage = Arc::new(Mutex::new(LedgerInfoWithSignatures::new(
+            LedgerInfo::mock_genesis(None),
+            AggregateSignature::empty(),
+        )));
+        let execution_proxy = Arc::new(TestStateComputer {
+            storage: storage.clone(),
+            committed_blocks: Mutex::new(vec![]),
+            sync_targets: Mutex::new(vec![]),
+        });
+        let (_, reconfig_events) = aptos_channel::new(QueueStyle::LIFO, 1, None);
+        let mut observer = ConsensusObserver::new(
+            ConsensusObserverConfig::default(),
+            Arc::new(TestDbReader { storage }),
+            execution_proxy.clone(),
+            PeersAndMetadata::new(&[NetworkId::Public]),
+            ReconfigNotificationListener {
+                notification_receiver: reconfig_events,
+            },
+            None,
+        );
+        observer.epoch_state = Some(Arc::new(EpochState::new(
+            1,
+            generate_validator_verifier(signers),
+        )));
+        observer.execution_epoch = Some(1);
+        (observer, execution_proxy)
+    }
+
+    /// Creates a peer connected to the observer (with the given connection origin)
+    fn create_peer(observer: &ConsensusObserver, origin: ConnectionOrigin) -> PeerNetworkId {
+        let peer_network_id = PeerNetworkId::new(NetworkId::Public, PeerId::random());
+        let connection_metadata = ConnectionMetadata::mock_with_role_and_origin(
+            peer_network_id.peer_id(),
+            PeerRole::Unknown,
+            origin,
+        );
+        observer
+            .peers_and_metadata
+            .insert_connection_metadata(peer_network_id, connection_metadata)
+            .unwrap();
+        peer_network_id
+    }
+
+    /// Waits for the next execution result and processes it
+    async fn process_next_execution_result(observer: &mut ConsensusObserver) {
+        let (key, result) = observer.executing_blocks.next().await.unwrap();
+        observer.process_execution_result(key, result).await;
+    }
+}
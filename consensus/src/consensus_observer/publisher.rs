@@ -0,0 +1,81 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    consensus_observer::network_message::{ConsensusObserverMessage, DIRECT_SEND},
+    counters,
+};
+use aptos_config::network_id::PeerNetworkId;
+use aptos_logger::prelude::*;
+use aptos_netcore::transport::ConnectionOrigin;
+use aptos_network::{
+    application::interface::{NetworkClient, NetworkClientInterface},
+    transport::ConnectionMetadata,
+};
+use std::time::Duration;
+
+// Useful constants for the publisher
+const PUBLISHER_ERROR_LOG_FREQ_SECS: u64 = 5;
+
+/// Publishes the ordered blocks and commit decisions of this node to the
+/// downstream peers (i.e., the peers that connected to this node) that
+/// run the consensus observer.
+#[derive(Clone)]
+pub struct ConsensusPublisher {
+    network_client: NetworkClient<ConsensusObserverMessage>,
+}
+
+impl ConsensusPublisher {
+    pub fn new(network_client: NetworkClient<ConsensusObserverMessage>) -> Self {
+        Self { network_client }
+    }
+
+    /// Sends the given message to all downstream peers. Publishing is best
+    /// effort: observers that miss messages fall back to state sync.
+    pub fn publish_message(&self, message: ConsensusObserverMessage) {
+        let downstream_peers = self.get_downstream_peers();
+        if downstream_peers.is_empty() {
+            return;
+        }
+
+        counters::CONSENSUS_OBSERVER_PUBLISHED_MESSAGES
+            .with_label_values(&[message.get_label()])
+            .inc_by(downstream_peers.len() as u64);
+        if let Err(error) = self
+            .network_client
+            .send_to_peers(message, &downstream_peers)
+        {
+            sample!(
+                SampleRate::Duration(Duration::from_secs(PUBLISHER_ERROR_LOG_FREQ_SECS)),
+                warn!(
+                    "Failed to publish the consensus observer message: {:?}",
+                    error
+                )
+            );
+        }
+    }
+
+    /// Returns the connected peers that dialed this node and support the observer protocol
+    fn get_downstream_peers(&self) -> Vec<PeerNetworkId> {
+        let peers_and_metadata = self.network_client.get_peers_and_metadata();
+        match peers_and_metadata.get_connected_peers_and_metadata() {
+            Ok(connected_peers) => connected_peers
+                .into_iter()
+                .filter(|(_, peer_metadata)| {
+                    peer_metadata.supports_any_protocol(DIRECT_SEND)
+                        && is_inbound_connection(&peer_metadata.get_connection_metadata())
+                })
+                .map(|(peer_network_id, _)| peer_network_id)
+                .collect(),
+            Err(error) => {
+                warn!("Failed to get the connected peers: {:?}", error);
+                vec![]
+            },
+        }
+    }
+}
+
+/// Returns true iff the connection was initiated by the remote peer
+pub(crate) fn is_inbound_connection(connection_metadata: &ConnectionMetadata) -> bool {
+    connection_metadata.origin == ConnectionOrigin::Inbound
+}
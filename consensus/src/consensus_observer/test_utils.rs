@@ -0,0 +1,100 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::consensus_observer::network_message::{ObservedBlock, OrderedBlock};
+use aptos_consensus_types::{
+    block::{block_test_utils::gen_test_certificate, Block},
+    common::{Payload, Round},
+    quorum_cert::QuorumCert,
+};
+use aptos_crypto::HashValue;
+use aptos_executor_types::StateComputeResult;
+use aptos_types::{
+    block_info::BlockInfo,
+    ledger_info::{generate_ledger_info_with_sig, LedgerInfo, LedgerInfoWithSignatures},
+    validator_signer::ValidatorSigner,
+};
+use std::ops::RangeInclusive;
+
+/// Creates a chain of blocks (one per round) extending the block certified by the given QC.
+/// Each block is proposed by the first signer, one microsecond after its parent.
+pub(super) fn create_blocks(
+    signers: &[ValidatorSigner],
+    parent_qc: QuorumCert,
+    rounds: RangeInclusive<Round>,
+) -> Vec<Block> {
+    let mut quorum_cert = parent_qc;
+    let mut blocks = vec![];
+    for round in rounds {
+        let block = Block::new_proposal(
+            Payload::empty(false, true),
+            round,
+            quorum_cert.certified_block().timestamp_usecs() + 1,
+            quorum_cert.clone(),
+            &signers[0],
+            vec![],
+        )
+        .unwrap();
+        quorum_cert = gen_test_certificate(
+            signers,
+            executed_block_info(&block),
+            quorum_cert.certified_block().clone(),
+            None,
+        );
+        blocks.push(block);
+    }
+    blocks
+}
+
+/// Creates an ordered block for the given chain, with an ordered proof signed by the signers
+pub(super) fn create_ordered_block(signers: &[ValidatorSigner], blocks: &[Block]) -> OrderedBlock {
+    let last_block = blocks.last().expect("The blocks should not be empty!");
+    let ordered_proof = generate_ledger_info_with_sig(
+        signers,
+        LedgerInfo::new(
+            last_block.gen_block_info(HashValue::zero(), 0, None),
+            HashValue::zero(),
+        ),
+    );
+    OrderedBlock {
+        blocks: blocks
+            .iter()
+            .map(|block| ObservedBlock {
+                block: block.clone(),
+                transactions: vec![],
+                randomness: None,
+            })
+            .collect(),
+        ordered_proof,
+    }
+}
+
+/// Creates a commit decision for the given block info, signed by the signers
+pub(super) fn create_commit_decision(
+    signers: &[ValidatorSigner],
+    block_info: BlockInfo,
+) -> LedgerInfoWithSignatures {
+    generate_ledger_info_with_sig(signers, LedgerInfo::new(block_info, HashValue::zero()))
+}
+
+/// Returns the result of executing the given block in tests: the executed state id is
+/// the block id, and the version is the block round.
+pub(super) fn compute_result(block: &Block) -> StateComputeResult {
+    StateComputeResult::new(
+        block.id(),
+        vec![],
+        block.round() + 1,
+        vec![],
+        0,
+        None,
+        vec![],
+        vec![],
+        vec![],
+    )
+}
+
+/// Returns the block info of the given block, once executed in tests
+pub(super) fn executed_block_info(block: &Block) -> BlockInfo {
+    let compute_result = compute_result(block);
+    block.gen_block_info(compute_result.root_hash(), compute_result.version(), None)
+}
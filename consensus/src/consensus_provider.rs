@@ -3,6 +3,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    consensus_observer::{
+        network_message::ConsensusObserverMessage, observer::ConsensusObserver,
+        publisher::ConsensusPublisher,
+    },
     counters,
     epoch_manager::EpochManager,
     network::NetworkTask,
@@ -17,13 +21,15 @@ use crate::{
     util::time_service::ClockTimeService,
 };
 use aptos_bounded_executor::BoundedExecutor;
-use aptos_config::config::NodeConfig;
+use aptos_config::config::{transaction_filter_type::Filter, NodeConfig};
 use aptos_consensus_notifications::ConsensusNotificationSender;
 use aptos_event_notifications::{DbBackedOnChainConfig, ReconfigNotificationListener};
 use aptos_executor::block_executor::BlockExecutor;
 use aptos_logger::prelude::*;
 use aptos_mempool::QuorumStoreRequest;
-use aptos_network::application::interface::{NetworkClient, NetworkServiceEvents};
+use aptos_network::application::interface::{
+    NetworkClient, NetworkClientInterface, NetworkServiceEvents,
+};
 use aptos_storage_interface::DbReaderWriter;
 use aptos_validator_transaction_pool::VTxnPoolState;
 use aptos_vm::AptosVM;
@@ -41,6 +47,7 @@ pub fn start_consensus(
     aptos_db: DbReaderWriter,
     reconfig_events: ReconfigNotificationListener<DbBackedOnChainConfig>,
     vtxn_pool: VTxnPoolState,
+    consensus_publisher: Option<Arc<ConsensusPublisher>>,
 ) -> (Runtime, Arc<StorageWriteProxy>, Arc<QuorumStoreDB>) {
     let runtime = aptos_runtimes::spawn_named_runtime("consensus".into(), None);
     let storage = Arc::new(StorageWriteProxy::new(node_config, aptos_db.reader.clone()));
@@ -77,6 +84,7 @@ pub fn start_consensus(
         consensus_network_client.clone(),
         bounded_executor.clone(),
         rand_storage.clone(),
+        consensus_publisher,
     ));
 
     let epoch_mgr = EpochManager::new(
@@ -104,3 +112,44 @@ pub fn start_consensus(
     debug!("Consensus started.");
     (runtime, storage, quorum_store_db)
 }
+
+/// Helper function to start the consensus observer (on fullnodes) and return the runtime
+pub fn start_consensus_observer(
+    node_config: &NodeConfig,
+    network_client: NetworkClient<ConsensusObserverMessage>,
+    network_service_events: NetworkServiceEvents<ConsensusObserverMessage>,
+    state_sync_notifier: Arc<dyn ConsensusNotificationSender>,
+    consensus_to_mempool_sender: mpsc::Sender<QuorumStoreRequest>,
+    aptos_db: DbReaderWriter,
+    reconfig_events: ReconfigNotificationListener<DbBackedOnChainConfig>,
+    consensus_publisher: Option<Arc<ConsensusPublisher>>,
+) -> Runtime {
+    let runtime = aptos_runtimes::spawn_named_runtime("consensus-observer".into(), None);
+
+    let txn_notifier = Arc::new(MempoolNotifier::new(
+        consensus_to_mempool_sender,
+        node_config.consensus.mempool_executed_txn_timeout_ms,
+    ));
+
+    // The published transactions were already filtered by the upstream peers
+    let execution_proxy = ExecutionProxy::new(
+        Arc::new(BlockExecutor::<AptosVM>::new(aptos_db.clone())),
+        txn_notifier,
+        state_sync_notifier,
+        runtime.handle(),
+        TransactionFilter::new(Filter::empty()),
+    );
+
+    let consensus_observer = ConsensusObserver::new(
+        node_config.consensus_observer,
+        aptos_db.reader,
+        Arc::new(execution_proxy),
+        network_client.get_peers_and_metadata(),
+        reconfig_events,
+        consensus_publisher,
+    );
+    runtime.spawn(consensus_observer.start(network_service_events));
+
+    debug!("Consensus observer started.");
+    runtime
+}
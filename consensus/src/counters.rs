@@ -264,6 +264,14 @@ pub static PIPELINE_BACKPRESSURE_ON_PROPOSAL_TRIGGERED: Lazy<Histogram> = Lazy::
     )
 });
 
+/// Counts when execution backpressure reduces the proposal size
+pub static EXECUTION_BACKPRESSURE_ON_PROPOSAL_TRIGGERED: Lazy<Histogram> = Lazy::new(|| {
+    register_avg_counter(
+        "aptos_execution_backpressure_on_proposal_triggered",
+        "Counts when execution backpressure reduces the proposal size",
+    )
+});
+
 /// number of rounds pending when creating proposal
 pub static CONSENSUS_PROPOSAL_PENDING_ROUNDS: Lazy<Histogram> = Lazy::new(|| {
     register_avg_counter(
@@ -307,6 +315,15 @@ pub static PROPOSER_PENDING_BLOCKS_FILL_FRACTION: Lazy<Gauge> = Lazy::new(|| {
     .unwrap()
 });
 
+/// Number of txns the proposer expects to execute within the target block time
+pub static PROPOSER_ESTIMATED_CALIBRATED_BLOCK_TXNS: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "aptos_proposer_estimated_calibrated_block_txns",
+        "Number of txns the proposer expects to execute within the target block time",
+    )
+    .unwrap()
+});
+
 /// Next set of counters are computed at leader election time, with some delay.
 
 /// Current voting power fraction that participated in consensus
@@ -967,3 +984,46 @@ pub static RAND_QUEUE_SIZE: Lazy<IntGauge> = Lazy::new(|| {
     )
     .unwrap()
 });
+
+pub static PENDING_CONSENSUS_OBSERVER_NETWORK_EVENTS: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "aptos_consensus_observer_pending_network_events",
+        "Counters(queued,dequeued,dropped) related to pending network notifications to the consensus observer",
+        &["state"]
+    )
+    .unwrap()
+});
+
+pub static CONSENSUS_OBSERVER_PUBLISHED_MESSAGES: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "aptos_consensus_observer_published_messages",
+        "Count of the messages published to consensus observers (per peer)",
+        &["message_type"]
+    )
+    .unwrap()
+});
+
+pub static CONSENSUS_OBSERVER_RECEIVED_MESSAGES: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "aptos_consensus_observer_received_messages",
+        "Count of the messages received by the consensus observer, by type and outcome",
+        &["message_type", "outcome"]
+    )
+    .unwrap()
+});
+
+pub static CONSENSUS_OBSERVER_COMMITTED_ROUND: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "aptos_consensus_observer_committed_round",
+        "The round of the last block committed by the consensus observer"
+    )
+    .unwrap()
+});
+
+pub static CONSENSUS_OBSERVER_STATE_SYNC_FALLBACKS: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "aptos_consensus_observer_state_sync_fallbacks",
+        "Count of the times the consensus observer fell back to state sync"
+    )
+    .unwrap()
+});
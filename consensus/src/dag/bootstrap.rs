@@ -26,7 +26,9 @@ use crate::{
         round_state::{AdaptiveResponsive, RoundState},
     },
     liveness::{
-        leader_reputation::{ProposerAndVoterHeuristic, ReputationHeuristic},
+        leader_reputation::{
+            LatencyAwareHeuristic, ProposerAndVoterHeuristic, ReputationHeuristic,
+        },
         proposal_generator::{ChainHealthBackoffConfig, PipelineBackpressureConfig},
     },
     monitor,
@@ -48,10 +50,9 @@ use aptos_reliable_broadcast::{RBNetworkSender, ReliableBroadcast};
 use aptos_types::{
     epoch_state::EpochState,
     on_chain_config::{
-        AnchorElectionMode, DagConsensusConfigV1,
-        LeaderReputationType::{ProposerAndVoter, ProposerAndVoterV2},
-        OnChainJWKConsensusConfig, OnChainRandomnessConfig, ProposerAndVoterConfig,
-        ValidatorTxnConfig,
+        AnchorElectionMode, DagConsensusConfigV1, LatencyAwareConfig,
+        LeaderReputationType::{self, LatencyAware, ProposerAndVoter, ProposerAndVoterV2},
+        OnChainJWKConsensusConfig, OnChainRandomnessConfig, ValidatorTxnConfig,
     },
     validator_signer::ValidatorSigner,
 };
@@ -392,8 +393,9 @@ impl DagBootstrapper {
 
     fn build_leader_reputation_components(
         &self,
-        config: &ProposerAndVoterConfig,
+        reputation_type: &LeaderReputationType,
     ) -> Arc<LeaderReputationAdapter> {
+        let config = reputation_type.proposer_and_voter_config();
         let num_validators = self.epoch_state.verifier.len();
         let epoch_to_validators_vec = self.storage.get_epoch_to_proposers();
         let epoch_to_validator_map = epoch_to_validators_vec
@@ -417,16 +419,32 @@ impl DagBootstrapper {
                 ),
             epoch_to_validator_map,
         ));
-        let heuristic: Box<dyn ReputationHeuristic> = Box::new(ProposerAndVoterHeuristic::new(
+        let voter_window_size = num_validators * config.voter_window_num_validators_multiplier;
+        let proposer_window_size =
+            num_validators * config.proposer_window_num_validators_multiplier;
+        let proposer_and_voter_heuristic = ProposerAndVoterHeuristic::new(
             self.self_peer,
             config.active_weight,
             config.inactive_weight,
             config.failed_weight,
             config.failure_threshold_percent,
-            num_validators * config.voter_window_num_validators_multiplier,
-            num_validators * config.proposer_window_num_validators_multiplier,
+            voter_window_size,
+            proposer_window_size,
             false,
-        ));
+        );
+        let heuristic: Box<dyn ReputationHeuristic> = match reputation_type {
+            // The commit events carry no timestamps, so only the vote participation
+            // of the latency-aware heuristic applies to the DAG.
+            LatencyAware(latency_aware_config) => Box::new(LatencyAwareHeuristic::new(
+                proposer_and_voter_heuristic,
+                latency_aware_config.slow_weight,
+                latency_aware_config.proposal_latency_threshold_percent,
+                latency_aware_config.vote_participation_threshold_percent,
+                voter_window_size,
+                proposer_window_size,
+            )),
+            ProposerAndVoter(_) | ProposerAndVoterV2(_) => Box::new(proposer_and_voter_heuristic),
+        };
 
         let voting_power: Vec<u64> = self
             .epoch_state
@@ -461,7 +479,11 @@ impl DagBootstrapper {
             },
             AnchorElectionMode::LeaderReputation(reputation_type) => {
                 let (commit_events, leader_reputation) = match reputation_type {
-                    ProposerAndVoterV2(config) => {
+                    ProposerAndVoterV2(config)
+                    | LatencyAware(LatencyAwareConfig {
+                        proposer_and_voter_config: config,
+                        ..
+                    }) => {
                         let commit_events = self
                             .storage
                             .get_latest_k_committed_events(
@@ -474,7 +496,7 @@ impl DagBootstrapper {
                             .expect("Failed to read commit events from storage");
                         (
                             commit_events,
-                            self.build_leader_reputation_components(config),
+                            self.build_leader_reputation_components(reputation_type),
                         )
                     },
                     ProposerAndVoter(_) => unreachable!("unsupported mode"),
@@ -620,6 +642,7 @@ impl DagBootstrapper {
                     .health_config
                     .pipeline_backpressure_config
                     .clone(),
+                None,
             ),
             ordered_notifier.clone(),
         );
@@ -721,8 +744,9 @@ impl DagBootstrapper {
     }
 }
 
-pub(super) fn bootstrap_dag_for_test(
+pub(crate) fn bootstrap_dag_for_test(
     self_peer: Author,
+    config: DagConsensusConfig,
     signer: ValidatorSigner,
     epoch_state: Arc<EpochState>,
     storage: Arc<dyn DAGStorage>,
@@ -742,7 +766,7 @@ pub(super) fn bootstrap_dag_for_test(
     let (ordered_nodes_tx, ordered_nodes_rx) = futures_channel::mpsc::unbounded();
     let bootstraper = DagBootstrapper::new(
         self_peer,
-        DagConsensusConfig::default(),
+        config,
         DagConsensusConfigV1::default(),
         signer.into(),
         epoch_state.clone(),
@@ -12,7 +12,10 @@ use crate::{
     test_utils::{consensus_runtime, MockPayloadManager, MockStorage},
 };
 use aptos_channels::{aptos_channel, message_queues::QueueStyle};
-use aptos_config::network_id::{NetworkId, PeerNetworkId};
+use aptos_config::{
+    config::DagConsensusConfig,
+    network_id::{NetworkId, PeerNetworkId},
+};
 use aptos_consensus_types::common::Author;
 use aptos_logger::debug;
 use aptos_network::{
@@ -85,6 +88,7 @@ impl DagBootstrapUnit {
         let (nh_abort_handle, df_abort_handle, dag_rpc_tx, ordered_nodes_rx) =
             bootstrap_dag_for_test(
                 self_peer,
+                DagConsensusConfig::default(),
                 signer,
                 epoch_state,
                 Arc::new(dag_storage),
@@ -13,7 +13,7 @@ use crate::{
     liveness::{
         cached_proposer_election::CachedProposerElection,
         leader_reputation::{
-            extract_epoch_to_proposers, AptosDBBackend, LeaderReputation,
+            extract_epoch_to_proposers, AptosDBBackend, LatencyAwareHeuristic, LeaderReputation,
             ProposerAndVoterHeuristic, ReputationHeuristic,
         },
         proposal_generator::{
@@ -289,37 +289,41 @@ impl<P: OnChainConfigProvider> EpochManager<P> {
                 Arc::new(RotatingProposer::new(vec![proposer], *contiguous_rounds))
             },
             ProposerElectionType::LeaderReputation(leader_reputation_type) => {
-                let (
-                    heuristic,
-                    window_size,
-                    weight_by_voting_power,
-                    use_history_from_previous_epoch_max_count,
-                ) = match &leader_reputation_type {
-                    LeaderReputationType::ProposerAndVoter(proposer_and_voter_config)
-                    | LeaderReputationType::ProposerAndVoterV2(proposer_and_voter_config) => {
-                        let proposer_window_size = proposers.len()
-                            * proposer_and_voter_config.proposer_window_num_validators_multiplier;
-                        let voter_window_size = proposers.len()
-                            * proposer_and_voter_config.voter_window_num_validators_multiplier;
-                        let heuristic: Box<dyn ReputationHeuristic> =
-                            Box::new(ProposerAndVoterHeuristic::new(
-                                self.author,
-                                proposer_and_voter_config.active_weight,
-                                proposer_and_voter_config.inactive_weight,
-                                proposer_and_voter_config.failed_weight,
-                                proposer_and_voter_config.failure_threshold_percent,
-                                voter_window_size,
-                                proposer_window_size,
-                                leader_reputation_type.use_reputation_window_from_stale_end(),
-                            ));
-                        (
-                            heuristic,
-                            std::cmp::max(proposer_window_size, voter_window_size),
-                            proposer_and_voter_config.weight_by_voting_power,
-                            proposer_and_voter_config.use_history_from_previous_epoch_max_count,
-                        )
+                let proposer_and_voter_config = leader_reputation_type.proposer_and_voter_config();
+                let proposer_window_size = proposers.len()
+                    * proposer_and_voter_config.proposer_window_num_validators_multiplier;
+                let voter_window_size = proposers.len()
+                    * proposer_and_voter_config.voter_window_num_validators_multiplier;
+                let proposer_and_voter_heuristic = ProposerAndVoterHeuristic::new(
+                    self.author,
+                    proposer_and_voter_config.active_weight,
+                    proposer_and_voter_config.inactive_weight,
+                    proposer_and_voter_config.failed_weight,
+                    proposer_and_voter_config.failure_threshold_percent,
+                    voter_window_size,
+                    proposer_window_size,
+                    leader_reputation_type.use_reputation_window_from_stale_end(),
+                );
+                let heuristic: Box<dyn ReputationHeuristic> = match &leader_reputation_type {
+                    LeaderReputationType::ProposerAndVoter(_)
+                    | LeaderReputationType::ProposerAndVoterV2(_) => {
+                        Box::new(proposer_and_voter_heuristic)
+                    },
+                    LeaderReputationType::LatencyAware(latency_aware_config) => {
+                        Box::new(LatencyAwareHeuristic::new(
+                            proposer_and_voter_heuristic,
+                            latency_aware_config.slow_weight,
+                            latency_aware_config.proposal_latency_threshold_percent,
+                            latency_aware_config.vote_participation_threshold_percent,
+                            voter_window_size,
+                            proposer_window_size,
+                        ))
                     },
                 };
+                let window_size = std::cmp::max(proposer_window_size, voter_window_size);
+                let weight_by_voting_power = proposer_and_voter_config.weight_by_voting_power;
+                let use_history_from_previous_epoch_max_count =
+                    proposer_and_voter_config.use_history_from_previous_epoch_max_count;
 
                 let seek_len = onchain_config.leader_reputation_exclude_round() as usize
                     + onchain_config.max_failed_authors_to_store()
@@ -686,6 +690,7 @@ impl<P: OnChainConfigProvider> EpochManager<P> {
                 self.config.safety_rules.backend.clone(),
                 self.quorum_store_storage.clone(),
                 !consensus_config.is_dag_enabled(),
+                consensus_config.is_batch_erasure_coding_enabled(),
             ))
         } else {
             info!("Building DirectMempool");
@@ -779,8 +784,10 @@ impl<P: OnChainConfigProvider> EpochManager<P> {
             self.create_proposer_election(&epoch_state, &onchain_consensus_config);
         let chain_health_backoff_config =
             ChainHealthBackoffConfig::new(self.config.chain_health_backoff.clone());
-        let pipeline_backpressure_config =
-            PipelineBackpressureConfig::new(self.config.pipeline_backpressure.clone());
+        let pipeline_backpressure_config = PipelineBackpressureConfig::new(
+            self.config.pipeline_backpressure.clone(),
+            self.config.execution_backpressure.clone(),
+        );
 
         let safety_rules_container = Arc::new(Mutex::new(safety_rules));
 
@@ -1416,7 +1423,8 @@ impl<P: OnChainConfigProvider> EpochManager<P> {
             | ConsensusMsg::BatchMsg(_)
             | ConsensusMsg::BatchRequestMsg(_)
             | ConsensusMsg::SignedBatchInfo(_)
-            | ConsensusMsg::ProofOfStoreMsg(_) => {
+            | ConsensusMsg::ProofOfStoreMsg(_)
+            | ConsensusMsg::BatchChunkMsg(_) => {
                 let event: UnverifiedEvent = msg.into();
                 if event.epoch()? == self.epoch() {
                     return Ok(Some(event));
@@ -1474,7 +1482,8 @@ impl<P: OnChainConfigProvider> EpochManager<P> {
         match event {
             UnverifiedEvent::BatchMsg(_)
             | UnverifiedEvent::SignedBatchInfo(_)
-            | UnverifiedEvent::ProofOfStoreMsg(_) => {
+            | UnverifiedEvent::ProofOfStoreMsg(_)
+            | UnverifiedEvent::BatchChunkMsg(_) => {
                 if self.quorum_store_enabled {
                     Ok(true) // This states that we shouldn't filter out the event
                 } else if self.recovery_mode {
@@ -1521,7 +1530,8 @@ impl<P: OnChainConfigProvider> EpochManager<P> {
         if let Err(e) = match event {
             quorum_store_event @ (VerifiedEvent::SignedBatchInfo(_)
             | VerifiedEvent::ProofOfStoreMsg(_)
-            | VerifiedEvent::BatchMsg(_)) => {
+            | VerifiedEvent::BatchMsg(_)
+            | VerifiedEvent::BatchChunkMsg(_)) => {
                 Self::forward_event_to(quorum_store_msg_tx, peer_id, quorum_store_event)
                     .context("quorum store sender")
             },
@@ -20,25 +20,55 @@ use aptos_types::{
     block_executor::{config::BlockExecutorConfigFromOnchain, partitioner::ExecutableBlock},
     block_metadata_ext::BlockMetadataExt,
     transaction::{
-        signature_verified_transaction::SignatureVerifiedTransaction, SignedTransaction,
+        signature_verified_transaction::{
+            into_signature_verified_block, SignatureVerifiedTransaction,
+            MIN_ED25519_SIGNATURE_BATCH_SIZE,
+        },
+        SignedTransaction,
     },
 };
 use fail::fail_point;
 use once_cell::sync::Lazy;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
-use std::sync::Arc;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use tokio::sync::{mpsc, oneshot};
 
+/// More than 8 threads doesn't seem to help much
+const NUM_SIG_VERIFY_THREADS: usize = 8;
+
 pub static SIG_VERIFY_POOL: Lazy<Arc<rayon::ThreadPool>> = Lazy::new(|| {
     Arc::new(
         rayon::ThreadPoolBuilder::new()
-            .num_threads(8) // More than 8 threads doesn't seem to help much
+            .num_threads(NUM_SIG_VERIFY_THREADS)
             .thread_name(|index| format!("signature-checker-{}", index))
             .build()
             .unwrap(),
     )
 });
 
+/// The number of signature verification threads which can run at the same time.
+static SIG_VERIFY_PARALLELISM: Lazy<usize> = Lazy::new(|| {
+    std::thread::available_parallelism()
+        .map_or(1, |parallelism| parallelism.get())
+        .min(NUM_SIG_VERIFY_THREADS)
+});
+
+/// Returns the size of the chunks in which the signatures of a block are verified, one chunk per
+/// thread. Batch verification of the Ed25519 signatures of a chunk is at most 25% faster than
+/// verifying them one by one, and only once a chunk has `MIN_ED25519_SIGNATURE_BATCH_SIZE` of
+/// them. So chunks are only made that large if every thread still gets one: in a smaller block,
+/// splitting the signatures over all threads takes much less time than batching them on fewer.
+fn sig_verify_chunk_size(num_txns: usize, parallelism: usize) -> usize {
+    if num_txns >= parallelism * MIN_ED25519_SIGNATURE_BATCH_SIZE {
+        num_txns.div_ceil(parallelism)
+    } else {
+        optimal_min_len(num_txns, 32).max(num_txns.div_ceil(NUM_SIG_VERIFY_THREADS))
+    }
+}
+
 pub struct ExecutionPipeline {
     prepare_block_tx: mpsc::UnboundedSender<PrepareBlockCommand>,
 }
@@ -127,11 +157,12 @@ impl ExecutionPipeline {
                 Block::combine_to_input_transactions(validator_txns, input_txns.clone(), metadata);
             let sig_verified_txns: Vec<SignatureVerifiedTransaction> =
                 SIG_VERIFY_POOL.install(|| {
-                    let num_txns = txns_to_execute.len();
+                    let chunk_size =
+                        sig_verify_chunk_size(txns_to_execute.len(), *SIG_VERIFY_PARALLELISM);
                     txns_to_execute
                         .into_par_iter()
-                        .with_min_len(optimal_min_len(num_txns, 32))
-                        .map(|t| t.into())
+                        .chunks(chunk_size)
+                        .flat_map_iter(into_signature_verified_block)
                         .collect::<Vec<_>>()
                 });
             execute_block_tx
@@ -177,6 +208,7 @@ impl ExecutionPipeline {
             let block_id = block.block_id;
             debug!("execute_stage received block {}.", block_id);
             let executor = executor.clone();
+            let execution_start = Instant::now();
             let state_checkpoint_output = monitor!(
                 "execute_block",
                 tokio::task::spawn_blocking(move || {
@@ -194,10 +226,12 @@ impl ExecutionPipeline {
                 .await
             )
             .expect("Failed to spawn_blocking.");
+            let execution_time = execution_start.elapsed();
 
             ledger_apply_tx
                 .send(LedgerApplyCommand {
                     input_txns,
+                    execution_time,
                     block_id,
                     parent_block_id,
                     state_checkpoint_output,
@@ -214,6 +248,7 @@ impl ExecutionPipeline {
     ) {
         while let Some(LedgerApplyCommand {
             input_txns,
+            execution_time,
             block_id,
             parent_block_id,
             state_checkpoint_output,
@@ -233,7 +268,8 @@ impl ExecutionPipeline {
                 .expect("Failed to spawn_blocking().")
             }
             .await;
-            let pipe_line_res = res.map(|output| PipelineExecutionResult::new(input_txns, output));
+            let pipe_line_res =
+                res.map(|output| PipelineExecutionResult::new(input_txns, output, execution_time));
             result_tx.send(pipe_line_res).unwrap_or_else(|err| {
                 error!(
                     block_id = block_id,
@@ -265,6 +301,7 @@ struct ExecuteBlockCommand {
 
 struct LedgerApplyCommand {
     input_txns: Vec<SignedTransaction>,
+    execution_time: Duration,
     block_id: HashValue,
     parent_block_id: HashValue,
     state_checkpoint_output: ExecutorResult<StateCheckpointOutput>,
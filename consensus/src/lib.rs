@@ -18,6 +18,7 @@ extern crate scopeguard;
 extern crate core;
 
 mod block_storage;
+pub mod consensus_observer;
 mod consensusdb;
 mod dag;
 mod epoch_manager;
@@ -36,6 +37,8 @@ pub mod quorum_store;
 mod rand;
 mod recovery_manager;
 mod round_manager;
+#[cfg(test)]
+mod simulator;
 mod state_computer;
 #[cfg(test)]
 mod state_computer_tests;
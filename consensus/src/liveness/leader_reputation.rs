@@ -24,7 +24,7 @@ use aptos_types::{
     account_config::NewBlockEvent, epoch_change::EpochChangeProof, epoch_state::EpochState,
 };
 use std::{
-    cmp::max,
+    cmp::{max, min},
     collections::{HashMap, HashSet},
     convert::TryFrom,
     sync::Arc,
@@ -547,6 +547,152 @@ impl ReputationHeuristic for ProposerAndVoterHeuristic {
     }
 }
 
+/// Heuristic that extends ProposerAndVoterHeuristic, to additionally reduce the leader slots
+/// of validators that are live, but slow.
+///
+/// Both signals are derived from the committed history, so that all validators compute
+/// the same weights (local measurements could differ across validators, and so couldn't be used
+/// for leader election):
+///  * proposal latency: for each committed block directly following its parent (i.e. without
+///    failed rounds in between), the time between the parent and the block is attributed to the
+///    proposer of the block. A proposer is slow if its median latency within the proposer window
+///    is strictly above latency_threshold_percent of the median latency across all proposers.
+///  * vote participation: as only the first quorum of votes is aggregated into the quorum cert,
+///    the votes of slow voters are rarely included in the committed history. A voter is slow if
+///    its votes are included in strictly less than participation_threshold_percent of the blocks
+///    within the voter window.
+///
+/// Slow validators get at most slow_weight (i.e. failed and inactive validators keep their
+/// weights). The window is always taken from the recent end of the history.
+pub struct LatencyAwareHeuristic {
+    proposer_and_voter_heuristic: ProposerAndVoterHeuristic,
+    slow_weight: u64,
+    latency_threshold_percent: u32,
+    participation_threshold_percent: u32,
+    aggregation: NewBlockEventAggregation,
+}
+
+impl LatencyAwareHeuristic {
+    pub fn new(
+        proposer_and_voter_heuristic: ProposerAndVoterHeuristic,
+        slow_weight: u64,
+        latency_threshold_percent: u32,
+        participation_threshold_percent: u32,
+        voter_window_size: usize,
+        proposer_window_size: usize,
+    ) -> Self {
+        Self {
+            proposer_and_voter_heuristic,
+            slow_weight,
+            latency_threshold_percent,
+            participation_threshold_percent,
+            aggregation: NewBlockEventAggregation::new(
+                voter_window_size,
+                proposer_window_size,
+                false,
+            ),
+        }
+    }
+
+    fn median(mut values: Vec<u64>) -> Option<u64> {
+        values.sort_unstable();
+        values.get(values.len() / 2).copied()
+    }
+
+    /// Returns the proposal latencies (in microseconds) within the proposer window, per proposer.
+    /// The history is ordered from the most recent block.
+    fn proposal_latencies(
+        &self,
+        epoch_to_candidates: &HashMap<u64, Vec<Author>>,
+        history: &[NewBlockEvent],
+    ) -> HashMap<Author, Vec<u64>> {
+        let window_size = min(self.aggregation.proposer_window_size, history.len());
+        history[..window_size]
+            .iter()
+            .zip(history.iter().skip(1))
+            .filter(|(meta, parent)| {
+                epoch_to_candidates.contains_key(&meta.epoch())
+                    && meta.epoch() == parent.epoch()
+                    && meta.round() == parent.round() + 1
+            })
+            .fold(HashMap::new(), |mut map, (meta, parent)| {
+                map.entry(meta.proposer())
+                    .or_default()
+                    .push(meta.proposed_time().saturating_sub(parent.proposed_time()));
+                map
+            })
+    }
+
+    /// Returns the number of blocks within the voter window, per epoch.
+    fn count_blocks_per_epoch(
+        &self,
+        epoch_to_candidates: &HashMap<u64, Vec<Author>>,
+        history: &[NewBlockEvent],
+    ) -> HashMap<u64, u32> {
+        NewBlockEventAggregation::history_iter(
+            history,
+            epoch_to_candidates,
+            self.aggregation.voter_window_size,
+            false,
+        )
+        .fold(HashMap::new(), |mut map, meta| {
+            *map.entry(meta.epoch()).or_insert(0) += 1;
+            map
+        })
+    }
+}
+
+impl ReputationHeuristic for LatencyAwareHeuristic {
+    fn get_weights(
+        &self,
+        epoch: u64,
+        epoch_to_candidates: &HashMap<u64, Vec<Author>>,
+        history: &[NewBlockEvent],
+    ) -> Vec<u64> {
+        let weights =
+            self.proposer_and_voter_heuristic
+                .get_weights(epoch, epoch_to_candidates, history);
+
+        let latencies = self.proposal_latencies(epoch_to_candidates, history);
+        let median_latency = Self::median(latencies.values().flatten().copied().collect());
+        let votes = self.aggregation.count_votes(epoch_to_candidates, history);
+        let blocks_per_epoch = self.count_blocks_per_epoch(epoch_to_candidates, history);
+
+        epoch_to_candidates[&epoch]
+            .iter()
+            .zip(weights)
+            .map(|(author, weight)| {
+                let slow_proposer = match (
+                    latencies.get(author).cloned().and_then(Self::median),
+                    median_latency,
+                ) {
+                    (Some(latency), Some(median_latency)) => {
+                        latency as u128 * 100
+                            > median_latency as u128 * self.latency_threshold_percent as u128
+                    },
+                    _ => false,
+                };
+
+                // Only count the blocks of the epochs in which the author was a candidate
+                let num_blocks: u32 = blocks_per_epoch
+                    .iter()
+                    .filter(|(epoch, _)| epoch_to_candidates[epoch].contains(author))
+                    .map(|(_, count)| count)
+                    .sum();
+                let cur_votes = *votes.get(author).unwrap_or(&0);
+                let slow_voter =
+                    cur_votes * 100 < num_blocks * self.participation_threshold_percent;
+
+                if slow_proposer || slow_voter {
+                    min(weight, self.slow_weight)
+                } else {
+                    weight
+                }
+            })
+            .collect()
+    }
+}
+
 /// Committed history based proposer election implementation that could help bias towards
 /// successful leaders to help improve performance.
 pub struct LeaderReputation {
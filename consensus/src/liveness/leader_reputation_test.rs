@@ -3,7 +3,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use super::leader_reputation::{
-    extract_epoch_to_proposers_impl, AptosDBBackend, ProposerAndVoterHeuristic,
+    extract_epoch_to_proposers_impl, AptosDBBackend, LatencyAwareHeuristic,
+    ProposerAndVoterHeuristic,
 };
 use crate::liveness::{
     leader_reputation::{
@@ -275,6 +276,102 @@ fn test_proposer_and_voter_heuristic() {
     );
 }
 
+fn new_block_event(
+    epoch: u64,
+    round: Round,
+    proposer: Author,
+    votes: Vec<bool>,
+    timestamp: u64,
+) -> NewBlockEvent {
+    NewBlockEvent::new(
+        AccountAddress::random(),
+        epoch,
+        round,
+        round,
+        BitVec::from(votes).into(),
+        proposer,
+        vec![],
+        timestamp,
+    )
+}
+
+#[test]
+fn test_latency_aware_heuristic() {
+    let validators: Vec<Author> = (0..4).map(|_| Author::random()).sorted().collect();
+    let epoch_to_validators = HashMap::from([(0u64, validators.clone())]);
+
+    // validators[2] votes are never included, and validators[3] takes 3x longer to propose
+    let mut history = vec![];
+    let mut timestamp = 1_000_000;
+    for round in 1..=8u64 {
+        let proposer_index = (round % 4) as usize;
+        timestamp += if proposer_index == 3 {
+            300_000
+        } else {
+            100_000
+        };
+        history.push(new_block_event(
+            0,
+            round,
+            validators[proposer_index],
+            vec![true, true, false, true],
+            timestamp,
+        ));
+    }
+    // The time of the skipped round is not attributed to the proposer
+    timestamp += 1_000_000;
+    history.push(new_block_event(
+        0,
+        10,
+        validators[0],
+        vec![true, true, false, true],
+        timestamp,
+    ));
+    history.reverse();
+
+    let new_proposer_and_voter_heuristic =
+        || ProposerAndVoterHeuristic::new(validators[0], 1000, 10, 1, 10, 9, 9, false);
+    assert_eq!(
+        new_proposer_and_voter_heuristic().get_weights(0, &epoch_to_validators, &history),
+        vec![1000, 1000, 1000, 1000]
+    );
+
+    let heuristic =
+        LatencyAwareHeuristic::new(new_proposer_and_voter_heuristic(), 100, 150, 50, 9, 9);
+    assert_eq!(
+        heuristic.get_weights(0, &epoch_to_validators, &history),
+        vec![1000, 1000, 100, 100]
+    );
+
+    // The slow weight is not applied if it is higher than the original weight
+    let heuristic =
+        LatencyAwareHeuristic::new(new_proposer_and_voter_heuristic(), 10_000, 150, 50, 9, 9);
+    assert_eq!(
+        heuristic.get_weights(0, &epoch_to_validators, &history),
+        vec![1000, 1000, 1000, 1000]
+    );
+
+    // Without timestamps (e.g., the commit events of the DAG), only slow voters are penalized
+    let history: Vec<_> = history
+        .iter()
+        .map(|event| {
+            new_block_event(
+                event.epoch(),
+                event.round(),
+                event.proposer(),
+                vec![true, true, false, true],
+                0,
+            )
+        })
+        .collect();
+    let heuristic =
+        LatencyAwareHeuristic::new(new_proposer_and_voter_heuristic(), 100, 150, 50, 9, 9);
+    assert_eq!(
+        heuristic.get_weights(0, &epoch_to_validators, &history),
+        vec![1000, 1000, 100, 1000]
+    );
+}
+
 /// #### LeaderReputation test ####
 
 #[test]
@@ -387,6 +484,138 @@ fn test_api(use_root_hash: bool) {
     }
 }
 
+/// #### LatencyAwareHeuristic simulation ####
+
+/// Committed history of a simulated chain, ordered from the most recent block
+struct MockHistory {
+    events: Mutex<Vec<NewBlockEvent>>,
+}
+
+impl MetadataBackend for MockHistory {
+    fn get_block_metadata(
+        &self,
+        _target_epoch: u64,
+        _target_round: Round,
+    ) -> (Vec<NewBlockEvent>, HashValue) {
+        (self.events.lock().clone(), HashValue::zero())
+    }
+}
+
+/// Simulates a chain in which every elected proposer succeeds, after the given proposal latency,
+/// and the votes of slow voters are never included in the quorum certs. Returns the number of
+/// rounds each validator was elected for.
+fn simulate_leader_election(
+    validators: &[Author],
+    proposal_latencies: &[u64],
+    slow_voters: &[bool],
+    heuristic: Box<dyn ReputationHeuristic>,
+    num_rounds: u64,
+) -> Vec<u32> {
+    let history = Arc::new(MockHistory {
+        events: Mutex::new(vec![]),
+    });
+    let leader_reputation = LeaderReputation::new(
+        1,
+        HashMap::from([(1, validators.to_vec())]),
+        vec![1; validators.len()],
+        history.clone(),
+        heuristic,
+        0,
+        true,
+        30,
+    );
+
+    let votes: Vec<bool> = slow_voters.iter().map(|slow| !slow).collect();
+    let mut elected = vec![0; validators.len()];
+    let mut timestamp = 1_000_000;
+    for round in 1..=num_rounds {
+        let proposer = leader_reputation.get_valid_proposer(round);
+        let proposer_index = validators.iter().position(|v| *v == proposer).unwrap();
+        elected[proposer_index] += 1;
+        timestamp += proposal_latencies[proposer_index];
+        history.events.lock().insert(
+            0,
+            new_block_event(1, round, proposer, votes.clone(), timestamp),
+        );
+    }
+    elected
+}
+
+#[test]
+fn test_latency_aware_leader_election_simulation() {
+    let num_validators = 10;
+    let num_rounds = 1000;
+    let window_size = num_validators * 10;
+    let validators: Vec<Author> = (0..num_validators)
+        .map(|_| Author::random())
+        .sorted()
+        .collect();
+    // validators[0] is live, but slow to propose, and validators[1] is live, but slow to vote
+    let mut proposal_latencies = vec![100_000; num_validators];
+    proposal_latencies[0] = 400_000;
+    let mut slow_voters = vec![false; num_validators];
+    slow_voters[1] = true;
+
+    let new_proposer_and_voter_heuristic = || {
+        ProposerAndVoterHeuristic::new(
+            validators[2],
+            1000,
+            10,
+            1,
+            10,
+            window_size,
+            window_size,
+            false,
+        )
+    };
+    let baseline = simulate_leader_election(
+        &validators,
+        &proposal_latencies,
+        &slow_voters,
+        Box::new(new_proposer_and_voter_heuristic()),
+        num_rounds,
+    );
+    let latency_aware = simulate_leader_election(
+        &validators,
+        &proposal_latencies,
+        &slow_voters,
+        Box::new(LatencyAwareHeuristic::new(
+            new_proposer_and_voter_heuristic(),
+            100,
+            150,
+            50,
+            window_size,
+            window_size,
+        )),
+        num_rounds,
+    );
+
+    // Slow validators get significantly fewer leader slots, but are not excluded
+    for slow_index in [0, 1] {
+        assert!(
+            latency_aware[slow_index] * 2 < baseline[slow_index],
+            "validator {}: latency aware {:?}, baseline {:?}",
+            slow_index,
+            latency_aware,
+            baseline
+        );
+    }
+    assert!(latency_aware[0] > 0, "latency aware {:?}", latency_aware);
+    // The other validators get more leader slots
+    for (index, (latency_aware_count, baseline_count)) in
+        latency_aware.iter().zip(&baseline).enumerate().skip(2)
+    {
+        assert!(
+            latency_aware_count * 2 >= *baseline_count,
+            "validator {}: latency aware {:?}, baseline {:?}",
+            index,
+            latency_aware,
+            baseline
+        );
+    }
+    assert_eq!(latency_aware.iter().sum::<u32>(), num_rounds as u32);
+}
+
 struct MockDbReader {
     events: Mutex<Vec<EventWithVersion>>,
     random_address: Author,
@@ -8,23 +8,27 @@ use super::{
 use crate::{
     block_storage::BlockReader,
     counters::{
-        CHAIN_HEALTH_BACKOFF_TRIGGERED, PIPELINE_BACKPRESSURE_ON_PROPOSAL_TRIGGERED,
-        PROPOSER_DELAY_PROPOSAL, PROPOSER_PENDING_BLOCKS_COUNT,
+        CHAIN_HEALTH_BACKOFF_TRIGGERED, EXECUTION_BACKPRESSURE_ON_PROPOSAL_TRIGGERED,
+        PIPELINE_BACKPRESSURE_ON_PROPOSAL_TRIGGERED, PROPOSER_DELAY_PROPOSAL,
+        PROPOSER_ESTIMATED_CALIBRATED_BLOCK_TXNS, PROPOSER_PENDING_BLOCKS_COUNT,
         PROPOSER_PENDING_BLOCKS_FILL_FRACTION,
     },
     payload_client::PayloadClient,
     util::time_service::TimeService,
 };
 use anyhow::{bail, ensure, format_err, Context};
-use aptos_config::config::{ChainHealthBackoffValues, PipelineBackpressureValues};
+use aptos_config::config::{
+    ChainHealthBackoffValues, ExecutionBackpressureConfig, PipelineBackpressureValues,
+};
 use aptos_consensus_types::{
     block::Block,
     block_data::BlockData,
     common::{Author, Payload, PayloadFilter, Round},
+    pipelined_block::ExecutionSummary,
     quorum_cert::QuorumCert,
 };
 use aptos_crypto::{hash::CryptoHash, HashValue};
-use aptos_logger::{error, sample, sample::SampleRate, warn};
+use aptos_logger::{debug, error, sample, sample::SampleRate, warn};
 use aptos_types::{on_chain_config::ValidatorTxnConfig, validator_txn::ValidatorTransaction};
 use aptos_validator_transaction_pool as vtxn_pool;
 use futures::future::BoxFuture;
@@ -95,26 +99,100 @@ impl ChainHealthBackoffConfig {
 #[derive(Clone)]
 pub struct PipelineBackpressureConfig {
     backoffs: BTreeMap<Round, PipelineBackpressureValues>,
+    execution: Option<ExecutionBackpressureConfig>,
 }
 
 impl PipelineBackpressureConfig {
-    pub fn new(backoffs: Vec<PipelineBackpressureValues>) -> Self {
+    pub fn new(
+        backoffs: Vec<PipelineBackpressureValues>,
+        execution: Option<ExecutionBackpressureConfig>,
+    ) -> Self {
         let original_len = backoffs.len();
         let backoffs = backoffs
             .into_iter()
             .map(|v| (v.back_pressure_pipeline_latency_limit_ms, v))
             .collect::<BTreeMap<_, _>>();
         assert_eq!(original_len, backoffs.len());
-        Self { backoffs }
+        Self {
+            backoffs,
+            execution,
+        }
     }
 
     #[allow(dead_code)]
     pub fn new_no_backoff() -> Self {
         Self {
             backoffs: BTreeMap::new(),
+            execution: None,
         }
     }
 
+    /// Number of recent blocks the execution backpressure looks at, None if it is disabled.
+    pub fn num_blocks_to_look_at(&self) -> Option<usize> {
+        self.execution
+            .as_ref()
+            .map(|config| config.num_blocks_to_look_at)
+    }
+
+    /// Estimates how many transactions a block can have, to execute within the target block
+    /// time, from the recently executed blocks:
+    /// - the execution time per transaction of every block is assumed constant, so the executed
+    ///   transactions are scaled to the target block time;
+    /// - a block cut by the block gas limit can't be larger than the transactions it executed,
+    ///   as the rest of them would only be retried.
+    ///
+    /// Returns None if there aren't enough (large enough) blocks to learn from.
+    pub fn get_execution_block_size_backoff(
+        &self,
+        block_execution_times: &[ExecutionSummary],
+    ) -> Option<u64> {
+        let config = self.execution.as_ref()?;
+
+        let mut sizes: Vec<u64> = block_execution_times
+            .iter()
+            .filter_map(|summary| {
+                let execution_time_ms = summary.execution_time.as_millis() as u64;
+                let cut_by_block_limit = summary.to_retry > 0;
+                if !cut_by_block_limit
+                    && (summary.to_commit == 0
+                        || execution_time_ms < config.min_block_time_ms_to_activate)
+                {
+                    return None;
+                }
+
+                let size_for_target_time = if execution_time_ms == 0 {
+                    u64::MAX
+                } else {
+                    (summary.to_commit as u128 * config.target_block_time_ms as u128
+                        / execution_time_ms as u128)
+                        .try_into()
+                        .unwrap_or(u64::MAX)
+                };
+                Some(
+                    if cut_by_block_limit {
+                        size_for_target_time.min(summary.to_commit)
+                    } else {
+                        size_for_target_time
+                    },
+                )
+            })
+            .collect();
+        if sizes.is_empty() || sizes.len() < config.min_blocks_to_activate {
+            return None;
+        }
+
+        sizes.sort_unstable();
+        let idx = ((sizes.len() as f64 * config.percentile) as usize).min(sizes.len() - 1);
+        let size = sizes[idx].max(config.min_calibrated_txns_per_block);
+        debug!(
+            "Estimated execution backpressure block size {} from {} recent blocks: {:?}",
+            size,
+            sizes.len(),
+            sizes
+        );
+        Some(size)
+    }
+
     pub fn get_backoff(
         &self,
         pipeline_pending_latency: Duration,
@@ -446,18 +524,49 @@ impl ProposalGenerator {
             PIPELINE_BACKPRESSURE_ON_PROPOSAL_TRIGGERED.observe(0.0);
         };
 
+        let execution_backpressure = self
+            .pipeline_backpressure_config
+            .num_blocks_to_look_at()
+            .and_then(|num_blocks| {
+                self.pipeline_backpressure_config
+                    .get_execution_block_size_backoff(
+                        &self
+                            .block_store
+                            .get_recent_block_execution_times(num_blocks),
+                    )
+            });
+        if let Some(block_size) = execution_backpressure {
+            PROPOSER_ESTIMATED_CALIBRATED_BLOCK_TXNS.set(block_size as i64);
+            EXECUTION_BACKPRESSURE_ON_PROPOSAL_TRIGGERED.observe(
+                if block_size < self.max_block_txns {
+                    1.0
+                } else {
+                    0.0
+                },
+            );
+            values_max_block_txns.push(block_size);
+        } else {
+            EXECUTION_BACKPRESSURE_ON_PROPOSAL_TRIGGERED.observe(0.0);
+        }
+
         let max_block_txns = values_max_block_txns.into_iter().min().unwrap();
         let max_block_bytes = values_max_block_bytes.into_iter().min().unwrap();
         let proposal_delay = values_proposal_delay.into_iter().max().unwrap();
         let max_txns_from_block_to_execute =
             values_max_txns_from_block_to_execute.into_iter().min();
-        if pipeline_backpressure.is_some() || chain_health_backoff.is_some() {
+        let execution_backpressure_triggered =
+            execution_backpressure.map_or(false, |block_size| block_size < self.max_block_txns);
+        if pipeline_backpressure.is_some()
+            || chain_health_backoff.is_some()
+            || execution_backpressure_triggered
+        {
             warn!(
-                "Generating proposal: reducing limits to {} txns (filtered to {:?}) and {} bytes, due to pipeline_backpressure: {}, chain health backoff: {}. Delaying sending proposal by {}ms. Round: {}",
+                "Generating proposal: reducing limits to {} txns (filtered to {:?}) and {} bytes, due to pipeline_backpressure: {}, execution backpressure: {}, chain health backoff: {}. Delaying sending proposal by {}ms. Round: {}",
                 max_block_txns,
                 max_txns_from_block_to_execute,
                 max_block_bytes,
                 pipeline_backpressure.is_some(),
+                execution_backpressure_triggered,
                 chain_health_backoff.is_some(),
                 proposal_delay.as_millis(),
                 round,
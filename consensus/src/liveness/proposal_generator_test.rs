@@ -14,9 +14,11 @@ use crate::{
     test_utils::{build_empty_tree, MockPayloadManager, TreeInserter},
     util::mock_time_service::SimulatedTimeService,
 };
+use aptos_config::config::ExecutionBackpressureConfig;
 use aptos_consensus_types::{
     block::{block_test_utils::certificate_for_genesis, Block},
     common::Author,
+    pipelined_block::ExecutionSummary,
 };
 use aptos_types::{on_chain_config::ValidatorTxnConfig, validator_signer::ValidatorSigner};
 use futures::{future::BoxFuture, FutureExt};
@@ -229,3 +231,74 @@ async fn test_correct_failed_authors() {
     assert_eq!(result.failed_authors().unwrap()[3], (4, peer1));
     assert_eq!(result.failed_authors().unwrap()[4], (5, peer2));
 }
+
+fn execution_summary(to_commit: u64, to_retry: u64, execution_time_ms: u64) -> ExecutionSummary {
+    ExecutionSummary {
+        payload_len: to_commit + to_retry,
+        to_commit,
+        to_retry,
+        execution_time: Duration::from_millis(execution_time_ms),
+    }
+}
+
+#[test]
+fn test_execution_block_size_backoff() {
+    let config = PipelineBackpressureConfig::new(
+        vec![],
+        Some(ExecutionBackpressureConfig {
+            num_blocks_to_look_at: 10,
+            min_blocks_to_activate: 3,
+            percentile: 0.5,
+            target_block_time_ms: 200,
+            min_block_time_ms_to_activate: 50,
+            min_calibrated_txns_per_block: 10,
+        }),
+    );
+    assert_eq!(config.num_blocks_to_look_at(), Some(10));
+
+    // Not enough blocks, the fast ones are too small to learn from
+    assert_eq!(
+        config.get_execution_block_size_backoff(&[
+            execution_summary(1000, 0, 400),
+            execution_summary(1000, 0, 400),
+            execution_summary(10, 0, 10),
+        ]),
+        None
+    );
+
+    // 1000 txns in 400ms => 500 txns in 200ms, the median of [250, 500, 1000]
+    assert_eq!(
+        config.get_execution_block_size_backoff(&[
+            execution_summary(1000, 0, 800),
+            execution_summary(1000, 0, 400),
+            execution_summary(1000, 0, 200),
+        ]),
+        Some(500)
+    );
+
+    // Blocks cut by the block limit can't grow, even if they executed fast
+    assert_eq!(
+        config.get_execution_block_size_backoff(&[
+            execution_summary(300, 700, 20),
+            execution_summary(300, 700, 20),
+            execution_summary(1000, 0, 100),
+        ]),
+        Some(300)
+    );
+
+    // Never below the minimum
+    assert_eq!(
+        config.get_execution_block_size_backoff(&[
+            execution_summary(1, 999, 20),
+            execution_summary(1, 999, 20),
+            execution_summary(1, 999, 20),
+        ]),
+        Some(10)
+    );
+
+    assert_eq!(
+        PipelineBackpressureConfig::new_no_backoff()
+            .get_execution_block_size_backoff(&[execution_summary(1000, 0, 400); 5]),
+        None
+    );
+}
@@ -13,7 +13,13 @@ use crate::{
     monitor,
     network_interface::{ConsensusMsg, ConsensusNetworkClient, RPC},
     pipeline::commit_reliable_broadcast::CommitMessage,
-    quorum_store::types::{Batch, BatchMsg, BatchRequest, BatchResponse},
+    quorum_store::{
+        counters as quorum_store_counters,
+        types::{
+            Batch, BatchChunk, BatchChunkMsg, BatchChunkRequest, BatchMsg, BatchRequest,
+            BatchResponse,
+        },
+    },
     rand::rand_gen::{
         network_messages::{RandGenMessage, RandMessage},
         types::{AugmentedData, FastShare, Share},
@@ -101,6 +107,8 @@ pub struct IncomingBlockRetrievalRequest {
 #[derive(Debug)]
 pub struct IncomingBatchRetrievalRequest {
     pub req: BatchRequest,
+    /// If set, only the erasure coded chunk with this index is requested
+    pub chunk_index: Option<u64>,
     pub protocol: ProtocolId,
     pub response_sender: oneshot::Sender<Result<Bytes, RpcError>>,
 }
@@ -177,8 +185,19 @@ pub trait QuorumStoreSender: Send + Clone {
         timeout: Duration,
     ) -> anyhow::Result<BatchResponse>;
 
+    async fn request_batch_chunk(
+        &self,
+        request: BatchChunkRequest,
+        recipient: Author,
+        timeout: Duration,
+    ) -> anyhow::Result<BatchResponse>;
+
     async fn send_batch(&self, batch: Batch, recipients: Vec<Author>);
 
+    async fn send_batch_msg(&self, batches: Vec<Batch>, recipients: Vec<Author>);
+
+    async fn send_batch_chunk_msg(&self, chunks: Vec<BatchChunk>, recipients: Vec<Author>);
+
     async fn send_signed_batch_info_msg(
         &self,
         signed_batch_infos: Vec<SignedBatchInfo>,
@@ -504,12 +523,55 @@ impl QuorumStoreSender for NetworkSender {
         }
     }
 
+    async fn request_batch_chunk(
+        &self,
+        request: BatchChunkRequest,
+        recipient: Author,
+        timeout: Duration,
+    ) -> anyhow::Result<BatchResponse> {
+        let (request_digest, request_index) = (request.digest(), request.index());
+        let msg = ConsensusMsg::BatchChunkRequestMsg(Box::new(request));
+        let response = self
+            .consensus_network_client
+            .send_rpc(recipient, msg, timeout)
+            .await?;
+        match response {
+            ConsensusMsg::BatchResponseV2(response) => {
+                match response.as_ref() {
+                    BatchResponse::Chunk(chunk) => {
+                        ensure!(
+                            *chunk.digest() == request_digest && chunk.index() == request_index,
+                            "Response chunk doesn't match the request"
+                        );
+                    },
+                    BatchResponse::Batch(batch) => batch.verify_with_digest(request_digest)?,
+                    BatchResponse::NotFound(_) => {},
+                }
+                // Note the chunk is verified later against the other chunks of the batch
+                Ok(*response)
+            },
+            _ => Err(anyhow!("Invalid batch chunk response")),
+        }
+    }
+
     async fn send_batch(&self, batch: Batch, recipients: Vec<Author>) {
         fail_point!("consensus::send::batch", |_| ());
         let msg = ConsensusMsg::BatchResponse(Box::new(batch));
         self.send(msg, recipients).await
     }
 
+    async fn send_batch_msg(&self, batches: Vec<Batch>, recipients: Vec<Author>) {
+        fail_point!("consensus::send::batch", |_| ());
+        let msg = ConsensusMsg::BatchMsg(Box::new(BatchMsg::new(batches)));
+        self.send(msg, recipients).await
+    }
+
+    async fn send_batch_chunk_msg(&self, chunks: Vec<BatchChunk>, recipients: Vec<Author>) {
+        fail_point!("consensus::send::batch_chunk", |_| ());
+        let msg = ConsensusMsg::BatchChunkMsg(Box::new(BatchChunkMsg::new(chunks)));
+        self.send(msg, recipients).await
+    }
+
     async fn send_signed_batch_info_msg(
         &self,
         signed_batch_infos: Vec<SignedBatchInfo>,
@@ -523,6 +585,12 @@ impl QuorumStoreSender for NetworkSender {
 
     async fn broadcast_batch_msg(&mut self, batches: Vec<Batch>) {
         fail_point!("consensus::send::broadcast_batch", |_| ());
+        let num_bytes: u64 = batches.iter().map(|batch| batch.num_bytes()).sum();
+        quorum_store_counters::inc_batch_dissemination_sent_bytes(
+            quorum_store_counters::FULL_DISSEMINATION_LABEL,
+            quorum_store_counters::DISPERSAL_LABEL,
+            num_bytes * self.validators.len().saturating_sub(1) as u64,
+        );
         let msg = ConsensusMsg::BatchMsg(Box::new(BatchMsg::new(batches)));
         self.broadcast(msg).await
     }
@@ -696,6 +764,7 @@ impl NetworkTask {
                     match msg {
                         quorum_store_msg @ (ConsensusMsg::SignedBatchInfo(_)
                         | ConsensusMsg::BatchMsg(_)
+                        | ConsensusMsg::BatchChunkMsg(_)
                         | ConsensusMsg::ProofOfStoreMsg(_)) => {
                             Self::push_msg(
                                 peer_id,
@@ -803,6 +872,22 @@ impl NetworkTask {
                             );
                             IncomingRpcRequest::BatchRetrieval(IncomingBatchRetrievalRequest {
                                 req: *request,
+                                chunk_index: None,
+                                protocol,
+                                response_sender: callback,
+                            })
+                        },
+                        ConsensusMsg::BatchChunkRequestMsg(request) => {
+                            debug!(
+                                remote_peer = peer_id,
+                                event = LogEvent::ReceiveBatchRetrieval,
+                                "{}",
+                                request
+                            );
+                            let (req, chunk_index) = request.into_parts();
+                            IncomingRpcRequest::BatchRetrieval(IncomingBatchRetrievalRequest {
+                                req,
+                                chunk_index: Some(chunk_index),
                                 protocol,
                                 response_sender: callback,
                             })
@@ -7,7 +7,9 @@
 use crate::{
     dag::DAGNetworkMessage,
     pipeline,
-    quorum_store::types::{Batch, BatchMsg, BatchRequest, BatchResponse},
+    quorum_store::types::{
+        Batch, BatchChunkMsg, BatchChunkRequest, BatchMsg, BatchRequest, BatchResponse,
+    },
     rand::rand_gen::network_messages::RandGenMessage,
 };
 use aptos_config::network_id::{NetworkId, PeerNetworkId};
@@ -75,6 +77,10 @@ pub enum ConsensusMsg {
     RandGenMessage(RandGenMessage),
     /// Quorum Store: Response to the batch request.
     BatchResponseV2(Box<BatchResponse>),
+    /// Quorum Store: Send erasure coded chunks of batches (if enabled on chain).
+    BatchChunkMsg(Box<BatchChunkMsg>),
+    /// Quorum Store: Request a chunk of the payload of a completed batch.
+    BatchChunkRequestMsg(Box<BatchChunkRequest>),
 }
 
 /// Network type for consensus
@@ -101,6 +107,8 @@ impl ConsensusMsg {
             ConsensusMsg::CommitMessage(_) => "CommitMessage",
             ConsensusMsg::RandGenMessage(_) => "RandGenMessage",
             ConsensusMsg::BatchResponseV2(_) => "BatchResponseV2",
+            ConsensusMsg::BatchChunkMsg(_) => "BatchChunkMsg",
+            ConsensusMsg::BatchChunkRequestMsg(_) => "BatchChunkRequestMsg",
         }
     }
 }
@@ -12,10 +12,11 @@ use aptos_consensus_types::{
 };
 use aptos_crypto::HashValue;
 use aptos_executor_types::{ExecutorError::DataNotFound, *};
+use aptos_infallible::Mutex;
 use aptos_logger::prelude::*;
 use aptos_types::transaction::SignedTransaction;
 use futures::channel::mpsc::Sender;
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 use tokio::sync::oneshot;
 
 pub trait TPayloadManager: Send + Sync {
@@ -27,6 +28,9 @@ pub trait TPayloadManager: Send + Sync {
 pub enum PayloadManager {
     DirectMempool,
     InQuorumStore(Arc<dyn BatchReader>, Sender<CoordinatorCommand>),
+    /// The consensus observer receives the transactions of each block (keyed by block id)
+    /// from its upstream peers, so there is no payload to resolve.
+    ConsensusObserver(Arc<Mutex<HashMap<HashValue, Vec<SignedTransaction>>>>),
 }
 
 impl TPayloadManager for PayloadManager {
@@ -64,7 +68,7 @@ impl PayloadManager {
     ///Pass commit information to BatchReader and QuorumStore wrapper for their internal cleanups.
     pub fn notify_commit(&self, block_timestamp: u64, payloads: Vec<Payload>) {
         match self {
-            PayloadManager::DirectMempool => {},
+            PayloadManager::DirectMempool | PayloadManager::ConsensusObserver(_) => {},
             PayloadManager::InQuorumStore(batch_reader, coordinator_tx) => {
                 batch_reader.update_certified_timestamp(block_timestamp);
 
@@ -131,7 +135,7 @@ impl PayloadManager {
             };
 
         match self {
-            PayloadManager::DirectMempool => {},
+            PayloadManager::DirectMempool | PayloadManager::ConsensusObserver(_) => {},
             PayloadManager::InQuorumStore(batch_reader, _) => match payload {
                 Payload::InQuorumStore(proof_with_status) => {
                     request_txns_and_update_status(proof_with_status, batch_reader.clone());
@@ -158,6 +162,14 @@ impl PayloadManager {
         &self,
         block: &Block,
     ) -> ExecutorResult<(Vec<SignedTransaction>, Option<usize>)> {
+        if let PayloadManager::ConsensusObserver(block_transactions) = self {
+            return block_transactions
+                .lock()
+                .get(&block.id())
+                .map(|transactions| (transactions.clone(), None))
+                .ok_or(DataNotFound(block.id()));
+        }
+
         let payload = match block.payload() {
             Some(p) => p,
             None => return Ok((Vec::new(), None)),
@@ -4,6 +4,9 @@
 
 use crate::{
     block_storage::tracing::{observe_block, BlockStage},
+    consensus_observer::{
+        network_message::ConsensusObserverMessage, publisher::ConsensusPublisher,
+    },
     counters, monitor,
     network::{IncomingCommitRequest, NetworkSender},
     network_interface::ConsensusMsg,
@@ -130,6 +133,9 @@ pub struct BufferManager {
     previous_commit_time: Instant,
     reset_flag: Arc<AtomicBool>,
     bounded_executor: BoundedExecutor,
+
+    // publishes the ordered blocks and commit decisions to consensus observers (if enabled)
+    consensus_publisher: Option<Arc<ConsensusPublisher>>,
 }
 
 impl BufferManager {
@@ -154,6 +160,7 @@ impl BufferManager {
         ongoing_tasks: Arc<AtomicU64>,
         reset_flag: Arc<AtomicBool>,
         executor: BoundedExecutor,
+        consensus_publisher: Option<Arc<ConsensusPublisher>>,
     ) -> Self {
         let buffer = Buffer::<BufferItem>::new();
 
@@ -199,6 +206,8 @@ impl BufferManager {
             previous_commit_time: Instant::now(),
             reset_flag,
             bounded_executor: executor,
+
+            consensus_publisher,
         }
     }
 
@@ -359,6 +368,11 @@ impl BufferManager {
                         .replace(self.do_reliable_broadcast(commit_decision));
                 }
                 let commit_proof = aggregated_item.commit_proof.clone();
+                if let Some(consensus_publisher) = &self.consensus_publisher {
+                    consensus_publisher.publish_message(
+                        ConsensusObserverMessage::new_commit_decision_message(commit_proof.clone()),
+                    );
+                }
                 if commit_proof.ledger_info().ends_epoch() {
                     // the epoch ends, reset to avoid executing more blocks, execute after
                     // this persisting request will result in BlockNotFound
@@ -466,6 +480,18 @@ impl BufferManager {
             return;
         }
 
+        // Publish the executed blocks (with their input transactions) to any consensus observers
+        if let Some(consensus_publisher) = &self.consensus_publisher {
+            if let BufferItem::Ordered(ordered_item) = current_item {
+                consensus_publisher.publish_message(
+                    ConsensusObserverMessage::new_ordered_block_message(
+                        &executed_blocks,
+                        ordered_item.ordered_proof.clone(),
+                    ),
+                );
+            }
+        }
+
         // Handle reconfiguration timestamp reconciliation.
         // end epoch timestamp is set to the first block that causes the reconfiguration.
         // once it's set, any subsequent block commit info will be set to this timestamp.
@@ -6,23 +6,31 @@ use crate::{
     quorum_store::{
         batch_store::{BatchStore, BatchWriter},
         counters,
+        erasure_coding::{BatchChunkCollector, ErasureCodingContext},
         proof_manager::ProofManagerCommand,
-        types::{Batch, PersistedValue},
+        types::{Batch, BatchChunk, PersistedValue},
     },
 };
 use anyhow::ensure;
+use aptos_consensus_types::proof_of_store::BatchInfo;
+use aptos_crypto::HashValue;
 use aptos_logger::prelude::*;
 use aptos_types::PeerId;
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 use tokio::sync::{
     mpsc::{Receiver, Sender},
     oneshot,
 };
 
+/// The maximum number of batches of an author whose echoed chunks are collected before
+/// the chunk dispersed by the author itself is received
+const MAX_NUM_PENDING_CHUNKED_BATCHES_PER_AUTHOR: usize = 100;
+
 #[derive(Debug)]
 pub enum BatchCoordinatorCommand {
     Shutdown(oneshot::Sender<()>),
     NewBatches(PeerId, Vec<Batch>),
+    NewBatchChunks(PeerId, Vec<BatchChunk>),
 }
 
 /// The `BatchCoordinator` is responsible for coordinating the receipt and persistence of batches.
@@ -35,6 +43,11 @@ pub struct BatchCoordinator {
     max_batch_bytes: u64,
     max_total_txns: u64,
     max_total_bytes: u64,
+    erasure_coding: Option<ErasureCodingContext>,
+    // The batches whose root is pinned by the chunk received from their author
+    batch_chunks: HashMap<HashValue, BatchChunkCollector>,
+    // The batches with only echoed chunks so far, by author
+    pending_batch_chunks: HashMap<PeerId, HashMap<HashValue, BatchChunkCollector>>,
 }
 
 impl BatchCoordinator {
@@ -47,6 +60,7 @@ impl BatchCoordinator {
         max_batch_bytes: u64,
         max_total_txns: u64,
         max_total_bytes: u64,
+        erasure_coding: Option<ErasureCodingContext>,
     ) -> Self {
         Self {
             my_peer_id,
@@ -57,10 +71,17 @@ impl BatchCoordinator {
             max_batch_bytes,
             max_total_txns,
             max_total_bytes,
+            erasure_coding,
+            batch_chunks: HashMap::new(),
+            pending_batch_chunks: HashMap::new(),
         }
     }
 
-    fn persist_and_send_digests(&self, persist_requests: Vec<PersistedValue>) {
+    fn persist_and_send_digests(
+        &self,
+        persist_requests: Vec<PersistedValue>,
+        send_signatures: bool,
+    ) {
         if persist_requests.is_empty() {
             return;
         }
@@ -75,7 +96,7 @@ impl BatchCoordinator {
                 .map(|persisted_value| persisted_value.batch_info().clone())
                 .collect();
             let signed_batch_infos = batch_store.persist(persist_requests);
-            if !signed_batch_infos.is_empty() {
+            if send_signatures && !signed_batch_infos.is_empty() {
                 network_sender
                     .send_signed_batch_info_msg(signed_batch_infos, vec![peer_id])
                     .await;
@@ -86,10 +107,35 @@ impl BatchCoordinator {
         });
     }
 
-    fn ensure_max_limits(&self, batches: &[Batch]) -> anyhow::Result<()> {
+    /// Stores our own chunks of the batches of the author, and sends the author our
+    /// signatures, which count towards the proofs of store of the batches
+    fn persist_chunks_and_send_signatures(&self, author: PeerId, chunks: Vec<BatchChunk>) {
+        if chunks.is_empty() {
+            return;
+        }
+
+        let batch_store = self.batch_store.clone();
+        let network_sender = self.network_sender.clone();
+        tokio::spawn(async move {
+            let signed_batch_infos: Vec<_> = chunks
+                .into_iter()
+                .filter_map(|chunk| batch_store.persist_chunk(chunk))
+                .collect();
+            if !signed_batch_infos.is_empty() {
+                network_sender
+                    .send_signed_batch_info_msg(signed_batch_infos, vec![author])
+                    .await;
+            }
+        });
+    }
+
+    fn ensure_max_limits<'a>(
+        &self,
+        batches: impl Iterator<Item = &'a BatchInfo>,
+    ) -> anyhow::Result<()> {
         let mut total_txns = 0;
         let mut total_bytes = 0;
-        for batch in batches.iter() {
+        for batch in batches {
             ensure!(
                 batch.num_txns() <= self.max_batch_txns,
                 "Exceeds batch txn limit {} > {}",
@@ -123,7 +169,7 @@ impl BatchCoordinator {
     }
 
     async fn handle_batches_msg(&mut self, author: PeerId, batches: Vec<Batch>) {
-        if let Err(e) = self.ensure_max_limits(&batches) {
+        if let Err(e) = self.ensure_max_limits(batches.iter().map(|batch| batch.batch_info())) {
             error!("Batch from {}: {}", author, e);
             counters::RECEIVED_BATCH_MAX_LIMIT_FAILED.inc();
             return;
@@ -137,7 +183,120 @@ impl BatchCoordinator {
         if author != self.my_peer_id {
             counters::RECEIVED_REMOTE_BATCH_COUNT.inc_by(persist_requests.len() as u64);
         }
-        self.persist_and_send_digests(persist_requests);
+        self.persist_and_send_digests(persist_requests, true);
+    }
+
+    /// Collects the chunks of the batches of the author (sent either by the author, or
+    /// echoed by the validators they are assigned to). When the author sends us our own
+    /// chunk, we store it and sign the batch right away: under erasure coding, a proof of
+    /// store means that its signers can serve their chunks, enough of which are held by
+    /// the honest signers to reconstruct the batch (see `ReedSolomon::for_validators`).
+    /// Our chunk is also echoed to the other validators, so that everyone reconstructs
+    /// the batch ahead of execution (then it's persisted, but not signed again).
+    ///
+    /// Only the chunks with the same root as the chunk dispersed to us by the author are
+    /// collected. Until it's received, at most one chunk per index is kept, for a bounded
+    /// number of batches of the author, and a batch reconstructed from these echoes is
+    /// signed as if it was received in full.
+    async fn handle_batch_chunks_msg(&mut self, author: PeerId, chunks: Vec<BatchChunk>) {
+        let Some(erasure_coding) = self.erasure_coding.clone() else {
+            warn!(
+                "QS: received batch chunks from {}, but erasure coding is disabled",
+                author
+            );
+            return;
+        };
+        if let Err(e) = self.ensure_max_limits(chunks.iter().map(|chunk| chunk.batch_info())) {
+            error!("Batch chunks from {}: {}", author, e);
+            counters::RECEIVED_BATCH_MAX_LIMIT_FAILED.inc();
+            return;
+        }
+
+        let last_certified_time = self.batch_store.last_certified_time();
+        self.batch_chunks
+            .retain(|_, collector| collector.expiration() > last_certified_time);
+        self.pending_batch_chunks.retain(|_, collectors| {
+            collectors.retain(|_, collector| collector.expiration() > last_certified_time);
+            !collectors.is_empty()
+        });
+
+        let mut echoed_chunks = vec![];
+        let mut persist_requests = vec![];
+        let mut unsigned_persist_requests = vec![];
+        for chunk in chunks {
+            if chunk.expiration() <= last_certified_time {
+                continue;
+            }
+            let digest = *chunk.digest();
+            let expiration = chunk.expiration();
+            let new_collector = || BatchChunkCollector::new(*erasure_coding.codec(), expiration);
+            if chunk.index() == erasure_coding.my_index() as u64 {
+                // Only the author sends us our own chunk (see BatchChunkMsg::verify), and
+                // only the first one counts
+                if self.batch_chunks.contains_key(&digest) {
+                    continue;
+                }
+                let mut collector = self
+                    .pending_batch_chunks
+                    .get_mut(&author)
+                    .and_then(|collectors| collectors.remove(&digest))
+                    .unwrap_or_else(new_collector);
+                collector.pin_root(chunk.root());
+                // Unless the batch was already reconstructed (and signed) from echoes
+                if !collector.is_reconstructed() {
+                    echoed_chunks.push(chunk.clone());
+                    if let Some(batch) = collector.add(chunk) {
+                        unsigned_persist_requests.push(batch.into());
+                    }
+                }
+                self.batch_chunks.insert(digest, collector);
+            } else if let Some(collector) = self.batch_chunks.get_mut(&digest) {
+                if let Some(batch) = collector.add(chunk) {
+                    unsigned_persist_requests.push(batch.into());
+                }
+            } else {
+                let collectors = self.pending_batch_chunks.entry(author).or_default();
+                if !collectors.contains_key(&digest)
+                    && collectors.len() >= MAX_NUM_PENDING_CHUNKED_BATCHES_PER_AUTHOR
+                {
+                    debug!(
+                        "QS: too many pending chunked batches of {}, dropping chunk of {}",
+                        author, digest
+                    );
+                    continue;
+                }
+                if let Some(batch) = collectors
+                    .entry(digest)
+                    .or_insert_with(new_collector)
+                    .add(chunk)
+                {
+                    persist_requests.push(batch.into());
+                }
+            }
+        }
+
+        if !echoed_chunks.is_empty() {
+            self.persist_chunks_and_send_signatures(author, echoed_chunks.clone());
+            let recipients = erasure_coding.other_validators(author);
+            let num_bytes: usize = echoed_chunks
+                .iter()
+                .map(|chunk| chunk.num_chunk_bytes())
+                .sum();
+            counters::inc_batch_dissemination_sent_bytes(
+                counters::ERASURE_CODED_DISSEMINATION_LABEL,
+                counters::ECHO_LABEL,
+                (num_bytes * recipients.len()) as u64,
+            );
+            self.network_sender
+                .send_batch_chunk_msg(echoed_chunks, recipients)
+                .await;
+        }
+
+        let num_batches = (persist_requests.len() + unsigned_persist_requests.len()) as u64;
+        counters::RECEIVED_BATCH_COUNT.inc_by(num_batches);
+        counters::RECEIVED_REMOTE_BATCH_COUNT.inc_by(num_batches);
+        self.persist_and_send_digests(persist_requests, true);
+        self.persist_and_send_digests(unsigned_persist_requests, false);
     }
 
     pub(crate) async fn start(mut self, mut command_rx: Receiver<BatchCoordinatorCommand>) {
@@ -152,6 +311,9 @@ impl BatchCoordinator {
                 BatchCoordinatorCommand::NewBatches(author, batches) => {
                     self.handle_batches_msg(author, batches).await;
                 },
+                BatchCoordinatorCommand::NewBatchChunks(author, chunks) => {
+                    self.handle_batch_chunks_msg(author, chunks).await;
+                },
             }
         }
     }
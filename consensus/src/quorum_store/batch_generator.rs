@@ -6,8 +6,9 @@ use crate::{
     quorum_store::{
         batch_store::BatchWriter,
         counters,
+        erasure_coding::ErasureCodingContext,
         quorum_store_db::QuorumStoreStorage,
-        types::Batch,
+        types::{Batch, BatchChunk},
         utils::{MempoolProxy, TimeExpirations},
     },
 };
@@ -119,7 +120,7 @@ impl BatchGenerator {
             .iter()
             .map(|txn| {
                 (
-                    TransactionSummary::new(txn.sender(), txn.sequence_number()),
+                    TransactionSummary::new(txn.sender(), txn.replay_protector()),
                     TransactionInProgress::new(txn.gas_unit_price()),
                 )
             })
@@ -334,12 +335,55 @@ impl BatchGenerator {
         batches
     }
 
+    /// Sends the full batches to self, and only the chunk of every other validator to it
+    /// (instead of broadcasting the full batches).
+    async fn disperse_batches(
+        &self,
+        network_sender: &mut NetworkSender,
+        erasure_coding: &ErasureCodingContext,
+        batches: Vec<Batch>,
+    ) {
+        let codec = erasure_coding.codec();
+        let mut chunks_by_index = vec![vec![]; codec.num_chunks()];
+        for batch in batches.iter() {
+            counters::BATCH_DISSEMINATION_FULL_EQUIVALENT_BYTES
+                .inc_by(batch.num_bytes() * (codec.num_chunks() as u64 - 1));
+            match BatchChunk::encode(batch, codec) {
+                Ok(chunks) => {
+                    for chunk in chunks {
+                        chunks_by_index[chunk.index() as usize].push(chunk);
+                    }
+                },
+                Err(e) => error!("QS: failed to encode batch {}: {}", batch.digest(), e),
+            }
+        }
+
+        network_sender
+            .send_batch_msg(batches, vec![self.my_peer_id])
+            .await;
+        for (index, chunks) in chunks_by_index.into_iter().enumerate() {
+            if index == erasure_coding.my_index() || chunks.is_empty() {
+                continue;
+            }
+            let num_bytes: usize = chunks.iter().map(|chunk| chunk.num_chunk_bytes()).sum();
+            counters::inc_batch_dissemination_sent_bytes(
+                counters::ERASURE_CODED_DISSEMINATION_LABEL,
+                counters::DISPERSAL_LABEL,
+                num_bytes as u64,
+            );
+            network_sender
+                .send_batch_chunk_msg(chunks, vec![erasure_coding.validator(index)])
+                .await;
+        }
+    }
+
     pub async fn start(
         mut self,
         mut network_sender: NetworkSender,
         mut cmd_rx: tokio::sync::mpsc::Receiver<BatchGeneratorCommand>,
         mut back_pressure_rx: tokio::sync::mpsc::Receiver<BackPressure>,
         mut interval: Interval,
+        erasure_coding: Option<ErasureCodingContext>,
     ) {
         let start = Instant::now();
 
@@ -421,7 +465,11 @@ impl BatchGenerator {
                             self.batch_writer.persist(persist_requests);
                             counters::BATCH_CREATION_PERSIST_LATENCY.observe_duration(persist_start.elapsed());
 
-                            network_sender.broadcast_batch_msg(batches).await;
+                            if let Some(erasure_coding) = &erasure_coding {
+                                self.disperse_batches(&mut network_sender, erasure_coding, batches).await;
+                            } else {
+                                network_sender.broadcast_batch_msg(batches).await;
+                            }
                         } else if tick_start.elapsed() > interval.period().checked_div(2).unwrap_or(Duration::ZERO) {
                             // If the pull takes too long, it's also accounted as a non-empty pull to avoid pulling too often.
                             last_non_empty_pull = tick_start;
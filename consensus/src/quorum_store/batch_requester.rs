@@ -6,7 +6,8 @@ use crate::{
     network::QuorumStoreSender,
     quorum_store::{
         counters,
-        types::{BatchRequest, BatchResponse},
+        erasure_coding::{BatchChunkCollector, ReedSolomon},
+        types::{BatchChunkRequest, BatchRequest, BatchResponse},
     },
 };
 use aptos_consensus_types::proof_of_store::{BatchInfo, ProofOfStore};
@@ -103,6 +104,7 @@ pub(crate) struct BatchRequester<T> {
     rpc_timeout_ms: usize,
     network_sender: T,
     validator_verifier: Arc<ValidatorVerifier>,
+    erasure_codec: Option<ReedSolomon>,
 }
 
 impl<T: QuorumStoreSender + Sync + 'static> BatchRequester<T> {
@@ -115,6 +117,7 @@ impl<T: QuorumStoreSender + Sync + 'static> BatchRequester<T> {
         rpc_timeout_ms: usize,
         network_sender: T,
         validator_verifier: ValidatorVerifier,
+        erasure_codec: Option<ReedSolomon>,
     ) -> Self {
         Self {
             epoch,
@@ -125,6 +128,7 @@ impl<T: QuorumStoreSender + Sync + 'static> BatchRequester<T> {
             rpc_timeout_ms,
             network_sender,
             validator_verifier: Arc::new(validator_verifier),
+            erasure_codec,
         }
     }
 
@@ -144,6 +148,16 @@ impl<T: QuorumStoreSender + Sync + 'static> BatchRequester<T> {
         let epoch = self.epoch;
         let retry_interval = Duration::from_millis(self.retry_interval_ms as u64);
         let rpc_timeout = Duration::from_millis(self.rpc_timeout_ms as u64);
+        // With erasure coding, every signer is requested its own chunk (which it signed
+        // for), and enough signers are requested to reconstruct the batch (with the same
+        // redundancy as full requests)
+        let erasure_codec = self.erasure_codec;
+        let request_num_peers = match &erasure_codec {
+            Some(codec) => codec.num_data_chunks() + request_num_peers.saturating_sub(1),
+            None => request_num_peers,
+        };
+        let mut chunk_collector =
+            erasure_codec.map(|codec| BatchChunkCollector::new(codec, expiration));
 
         monitor!("batch_request", {
             let mut interval = time::interval(retry_interval);
@@ -155,7 +169,15 @@ impl<T: QuorumStoreSender + Sync + 'static> BatchRequester<T> {
                         // send batch request to a set of peers of size request_num_peers
                         if let Some(request_peers) = request_state.next_request_peers(request_num_peers) {
                             for peer in request_peers {
-                                futures.push(network_sender.request_batch(request.clone(), peer, rpc_timeout));
+                                if erasure_codec.is_some() {
+                                    let Some(index) = validator_verifier.address_to_validator_index().get(&peer) else {
+                                        continue;
+                                    };
+                                    let chunk_request = BatchChunkRequest::new(my_peer_id, epoch, digest, *index as u64);
+                                    futures.push(network_sender.request_batch_chunk(chunk_request, peer, rpc_timeout));
+                                } else {
+                                    futures.push(network_sender.request_batch(request.clone(), peer, rpc_timeout));
+                                }
                             }
                         } else if futures.is_empty() {
                             // end the loop when the futures are drained
@@ -172,6 +194,24 @@ impl<T: QuorumStoreSender + Sync + 'static> BatchRequester<T> {
                                 request_state.serve_request(digest, Some(payload.clone()));
                                 return Some((batch_info, payload));
                             }
+                            Ok(BatchResponse::Chunk(chunk)) => {
+                                counters::RECEIVED_BATCH_RESPONSE_COUNT.inc();
+                                let (Some(codec), Some(collector)) = (&erasure_codec, &mut chunk_collector) else {
+                                    debug!("QS: unexpected batch chunk response, digest:{}", digest);
+                                    continue;
+                                };
+                                if let Err(e) = chunk.verify(codec) {
+                                    counters::RECEIVED_BATCH_RESPONSE_ERROR_COUNT.inc();
+                                    debug!("QS: invalid batch chunk, digest:{}, error:{:?}", digest, e);
+                                    continue;
+                                }
+                                if let Some(batch) = collector.add(chunk) {
+                                    let batch_info = batch.batch_info().clone();
+                                    let payload = batch.into_transactions();
+                                    request_state.serve_request(digest, Some(payload.clone()));
+                                    return Some((batch_info, payload));
+                                }
+                            }
                             // Short-circuit if the chain has moved beyond expiration
                             Ok(BatchResponse::NotFound(ledger_info)) => {
                                 counters::RECEIVED_BATCH_NOT_FOUND_COUNT.inc();
@@ -7,11 +7,11 @@ use crate::{
         batch_requester::BatchRequester,
         counters,
         quorum_store_db::QuorumStoreStorage,
-        types::{PersistedValue, StorageMode},
+        types::{BatchChunk, PersistedChunk, PersistedValue, StorageMode},
         utils::TimeExpirations,
     },
 };
-use anyhow::bail;
+use anyhow::{bail, ensure};
 use aptos_consensus_types::proof_of_store::{ProofOfStore, SignedBatchInfo};
 use aptos_crypto::HashValue;
 use aptos_executor_types::{ExecutorError, ExecutorResult};
@@ -104,13 +104,16 @@ impl QuotaManager {
 }
 
 /// Provides in memory representation of stored batches (strong cache), and allows
-/// efficient concurrent readers.
+/// efficient concurrent readers. With erasure coding, it also stores our own chunks of
+/// the batches of other validators.
 pub struct BatchStore {
     epoch: OnceCell<u64>,
     last_certified_time: AtomicU64,
     db_cache: DashMap<HashValue, PersistedValue>,
+    chunk_cache: DashMap<HashValue, PersistedChunk>,
     peer_quota: DashMap<PeerId, QuotaManager>,
     expirations: Mutex<TimeExpirations<HashValue>>,
+    chunk_expirations: Mutex<TimeExpirations<HashValue>>,
     db: Arc<dyn QuorumStoreStorage>,
     memory_quota: usize,
     db_quota: usize,
@@ -133,8 +136,10 @@ impl BatchStore {
             epoch: OnceCell::with_value(epoch),
             last_certified_time: AtomicU64::new(last_certified_time),
             db_cache: DashMap::new(),
+            chunk_cache: DashMap::new(),
             peer_quota: DashMap::new(),
             expirations: Mutex::new(TimeExpirations::new()),
+            chunk_expirations: Mutex::new(TimeExpirations::new()),
             db,
             memory_quota,
             db_quota,
@@ -174,6 +179,21 @@ impl BatchStore {
         );
         db_clone.delete_batches(expired_keys).unwrap();
 
+        let mut expired_chunk_keys = Vec::new();
+        for (digest, chunk) in db_clone
+            .get_all_batch_chunks()
+            .expect("failed to read chunks from db")
+        {
+            if last_certified_time >= chunk.expiration() {
+                expired_chunk_keys.push(digest);
+            } else {
+                batch_store
+                    .save_chunk(&chunk)
+                    .expect("Storage limit exceeded upon BatchReader construction");
+            }
+        }
+        db_clone.delete_batch_chunks(expired_chunk_keys).unwrap();
+
         batch_store
     }
 
@@ -189,6 +209,14 @@ impl BatchStore {
         quota_manager.free_quota(value.num_bytes() as usize, value.payload_storage_mode());
     }
 
+    fn free_chunk_quota(&self, value: PersistedChunk) {
+        let mut quota_manager = self
+            .peer_quota
+            .get_mut(&value.author())
+            .expect("No QuotaManager for batch author");
+        quota_manager.free_quota(value.num_chunk_bytes(), value.chunk_storage_mode());
+    }
+
     // Inserts a PersistedValue into the in-memory db_cache. If an entry with a higher
     // value is already in the db_cache, Ok(false) is returned. If there was no entry
     // Ok(true) is returned after the successful insertion. Finally, the method returns
@@ -273,6 +301,86 @@ impl BatchStore {
         );
     }
 
+    // Inserts our own chunk of a batch into the chunk_cache (within the quota of the batch
+    // author, like batches), and returns whether it needs to be written to the db. Only
+    // the first chunk of a batch is kept. Same as for the db_cache, the chunk_cache entry
+    // lock is held while accessing peer_quota.
+    fn save_chunk(&self, chunk: &BatchChunk) -> anyhow::Result<bool> {
+        let last_certified_time = self.last_certified_time();
+        ensure!(
+            chunk.expiration() > last_certified_time,
+            "Incorrect chunk expiration {} in epoch {}, last committed timestamp {}",
+            chunk.expiration(),
+            self.epoch(),
+            last_certified_time,
+        );
+
+        let digest = *chunk.digest();
+        {
+            let Vacant(slot) = self.chunk_cache.entry(digest) else {
+                return Ok(false);
+            };
+            let storage_mode = self
+                .peer_quota
+                .entry(chunk.author())
+                .or_insert(QuotaManager::new(
+                    self.db_quota,
+                    self.memory_quota,
+                    self.batch_quota,
+                ))
+                .update_quota(chunk.num_chunk_bytes())?;
+            slot.insert(PersistedChunk::new(chunk.clone(), storage_mode));
+        }
+
+        self.chunk_expirations
+            .lock()
+            .unwrap()
+            .add_item(digest, chunk.expiration());
+        Ok(true)
+    }
+
+    /// Stores our own chunk of a batch, and signs the batch info: under erasure coding,
+    /// a signature attests that the signer can serve its chunk (see `BatchCoordinator`).
+    pub(crate) fn persist_chunk(&self, chunk: BatchChunk) -> Option<SignedBatchInfo> {
+        match self.save_chunk(&chunk) {
+            Ok(needs_db) => {
+                let batch_info = chunk.batch_info().clone();
+                trace!(
+                    "QS: sign digest {} of chunk {}",
+                    chunk.digest(),
+                    chunk.index()
+                );
+                if needs_db {
+                    self.db
+                        .save_batch_chunk(chunk)
+                        .expect("Could not write to DB");
+                }
+                SignedBatchInfo::new(batch_info, &self.validator_signer).ok()
+            },
+            Err(e) => {
+                debug!("QS: failed to store chunk to cache {:?}", e);
+                None
+            },
+        }
+    }
+
+    fn clear_expired_chunks(&self, certified_time: u64) -> Vec<HashValue> {
+        let expired_digests = self
+            .chunk_expirations
+            .lock()
+            .unwrap()
+            .expire(certified_time);
+        let mut ret = Vec::new();
+        for h in expired_digests {
+            // Chunks are never replaced, so their expiration is never extended
+            if let Some((_, value)) = self.chunk_cache.remove(&h) {
+                self.free_chunk_quota(value);
+                ret.push(h);
+            }
+        }
+        ret
+    }
+
     // pub(crate) for testing
     pub(crate) fn clear_expired_payload(&self, certified_time: u64) -> Vec<HashValue> {
         let expired_digests = self.expirations.lock().unwrap().expire(certified_time);
@@ -338,9 +446,13 @@ impl BatchStore {
         if let Err(e) = self.db.delete_batches(expired_keys) {
             debug!("Error deleting batches: {:?}", e)
         }
+        let expired_chunk_keys = self.clear_expired_chunks(certified_time);
+        if let Err(e) = self.db.delete_batch_chunks(expired_chunk_keys) {
+            debug!("Error deleting batch chunks: {:?}", e)
+        }
     }
 
-    fn last_certified_time(&self) -> u64 {
+    pub(crate) fn last_certified_time(&self) -> u64 {
         self.last_certified_time.load(Ordering::Relaxed)
     }
 
@@ -371,6 +483,30 @@ impl BatchStore {
             Err(ExecutorError::CouldNotGetData)
         }
     }
+
+    pub(crate) fn get_batch_chunk_from_local(
+        &self,
+        digest: &HashValue,
+    ) -> ExecutorResult<BatchChunk> {
+        if let Some(value) = self.chunk_cache.get(digest) {
+            match value.chunk() {
+                // Available in memory.
+                Some(chunk) => Ok(chunk.clone()),
+                None => {
+                    counters::GET_BATCH_FROM_DB_COUNT.inc();
+                    match self.db.get_batch_chunk(digest) {
+                        Ok(Some(chunk)) => Ok(chunk),
+                        Ok(None) | Err(_) => {
+                            warn!("Could not get batch chunk from db");
+                            Err(ExecutorError::CouldNotGetData)
+                        },
+                    }
+                },
+            }
+        } else {
+            Err(ExecutorError::CouldNotGetData)
+        }
+    }
 }
 
 impl BatchWriter for BatchStore {
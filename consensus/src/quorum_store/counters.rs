@@ -461,6 +461,15 @@ pub static RECEIVED_BATCH_MSG_COUNT: Lazy<IntCounter> = Lazy::new(|| {
     .unwrap()
 });
 
+/// Count of the received batch chunk msg since last restart.
+pub static RECEIVED_BATCH_CHUNK_MSG_COUNT: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "quorum_store_received_batch_chunk_msg_count",
+        "Count of the received batch chunk msg since last restart."
+    )
+    .unwrap()
+});
+
 /// Count of the received batch since last restart.
 pub static RECEIVED_BATCH_COUNT: Lazy<IntCounter> = Lazy::new(|| {
     register_int_counter!(
@@ -606,6 +615,50 @@ pub static RECEIVED_BATCH_RESPONSE_ERROR_COUNT: Lazy<IntCounter> = Lazy::new(||
     .unwrap()
 });
 
+pub const FULL_DISSEMINATION_LABEL: &str = "full";
+pub const ERASURE_CODED_DISSEMINATION_LABEL: &str = "erasure_coded";
+
+pub const DISPERSAL_LABEL: &str = "dispersal";
+pub const ECHO_LABEL: &str = "echo";
+pub const RETRIEVAL_LABEL: &str = "retrieval";
+
+/// Bytes of batch payloads sent to other validators, by dissemination mode (full or
+/// erasure coded) and by type (dispersal by the author, echo of chunks, or retrieval).
+pub static BATCH_DISSEMINATION_SENT_BYTES: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "quorum_store_batch_dissemination_sent_bytes",
+        "Bytes of batch payloads sent to other validators, by mode and type.",
+        &["mode", "type"]
+    )
+    .unwrap()
+});
+
+/// Bytes the author would have sent to disperse its batches with the full broadcast, to
+/// compare against the erasure coded dispersal.
+pub static BATCH_DISSEMINATION_FULL_EQUIVALENT_BYTES: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "quorum_store_batch_dissemination_full_equivalent_bytes",
+        "Bytes the author would have sent to disperse its batches with the full broadcast."
+    )
+    .unwrap()
+});
+
+pub fn inc_batch_dissemination_sent_bytes(mode: &str, kind: &str, num_bytes: u64) {
+    BATCH_DISSEMINATION_SENT_BYTES
+        .with_label_values(&[mode, kind])
+        .inc_by(num_bytes);
+}
+
+/// Count of the batches reconstructed from erasure coded chunks, by result.
+pub static BATCH_RECONSTRUCTION_COUNT: Lazy<IntCounterVec> = Lazy::new(|| {
+    register_int_counter_vec!(
+        "quorum_store_batch_reconstruction_count",
+        "Count of the batches reconstructed from erasure coded chunks, by result.",
+        &["result"]
+    )
+    .unwrap()
+});
+
 pub static QS_BACKPRESSURE_TXN_COUNT: Lazy<Histogram> = Lazy::new(|| {
     register_avg_counter(
         "quorum_store_backpressure_txn_count",
@@ -0,0 +1,553 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! A Reed-Solomon erasure code over GF(256), used to disperse quorum store batches.
+//!
+//! The (length prefixed) data is split into `num_data_chunks` shards. For every byte
+//! position, the bytes of the shards are the coefficients of a polynomial, and chunk `i`
+//! holds the evaluations of these polynomials at point `i + 1`. Any `num_data_chunks`
+//! distinct chunks are enough to recover the data (by inverting the Vandermonde matrix
+//! of their evaluation points).
+
+use crate::quorum_store::{
+    counters,
+    types::{Batch, BatchChunk},
+};
+use anyhow::{bail, ensure};
+use aptos_crypto::HashValue;
+use aptos_logger::prelude::*;
+use aptos_types::{validator_verifier::ValidatorVerifier, PeerId};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// The maximum number of chunks (the non-zero elements of GF(256))
+pub const MAX_NUM_CHUNKS: usize = 255;
+
+const LENGTH_PREFIX_BYTES: usize = 8;
+
+// The primitive polynomial x^8 + x^4 + x^3 + x^2 + 1
+const PRIMITIVE_POLYNOMIAL: u16 = 0x11D;
+
+struct GaloisTables {
+    exp: [u8; 512],
+    log: [u8; 256],
+}
+
+const fn build_tables() -> GaloisTables {
+    let mut exp = [0u8; 512];
+    let mut log = [0u8; 256];
+    let mut x: u16 = 1;
+    let mut i = 0;
+    while i < 255 {
+        exp[i] = x as u8;
+        log[x as usize] = i as u8;
+        x <<= 1;
+        if x & 0x100 != 0 {
+            x ^= PRIMITIVE_POLYNOMIAL;
+        }
+        i += 1;
+    }
+    // Duplicate the table to avoid reducing the exponents modulo 255 on multiplication
+    while i < 512 {
+        exp[i] = exp[i - 255];
+        i += 1;
+    }
+    GaloisTables { exp, log }
+}
+
+static TABLES: GaloisTables = build_tables();
+
+fn gf_mul(a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    TABLES.exp[TABLES.log[a as usize] as usize + TABLES.log[b as usize] as usize]
+}
+
+fn gf_inv(a: u8) -> u8 {
+    assert_ne!(a, 0, "Zero has no inverse in GF(256)");
+    TABLES.exp[255 - TABLES.log[a as usize] as usize]
+}
+
+/// The evaluation point of the chunk with the given index
+fn evaluation_point(index: usize) -> u8 {
+    (index + 1) as u8
+}
+
+/// Inverts the given square matrix (with Gauss-Jordan elimination)
+fn invert(mut matrix: Vec<Vec<u8>>) -> anyhow::Result<Vec<Vec<u8>>> {
+    let size = matrix.len();
+    let mut inverse: Vec<Vec<u8>> = (0..size)
+        .map(|row| (0..size).map(|col| (row == col) as u8).collect())
+        .collect();
+    for col in 0..size {
+        let Some(pivot) = (col..size).find(|row| matrix[*row][col] != 0) else {
+            bail!("Singular matrix");
+        };
+        matrix.swap(col, pivot);
+        inverse.swap(col, pivot);
+
+        let pivot_inv = gf_inv(matrix[col][col]);
+        for value in matrix[col].iter_mut().chain(inverse[col].iter_mut()) {
+            *value = gf_mul(*value, pivot_inv);
+        }
+        for row in 0..size {
+            let factor = matrix[row][col];
+            if row == col || factor == 0 {
+                continue;
+            }
+            for k in 0..size {
+                let (scaled_matrix, scaled_inverse) = (
+                    gf_mul(factor, matrix[col][k]),
+                    gf_mul(factor, inverse[col][k]),
+                );
+                matrix[row][k] ^= scaled_matrix;
+                inverse[row][k] ^= scaled_inverse;
+            }
+        }
+    }
+    Ok(inverse)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReedSolomon {
+    num_data_chunks: usize,
+    num_chunks: usize,
+}
+
+impl ReedSolomon {
+    pub fn new(num_data_chunks: usize, num_chunks: usize) -> anyhow::Result<Self> {
+        ensure!(
+            num_data_chunks > 0 && num_data_chunks <= num_chunks,
+            "Invalid number of data chunks {} for {} chunks",
+            num_data_chunks,
+            num_chunks
+        );
+        ensure!(
+            num_chunks <= MAX_NUM_CHUNKS,
+            "Too many chunks: {} > {}",
+            num_chunks,
+            MAX_NUM_CHUNKS
+        );
+        Ok(Self {
+            num_data_chunks,
+            num_chunks,
+        })
+    }
+
+    /// One chunk per validator (in the order of the validator verifier), see
+    /// `for_voting_powers`.
+    pub fn for_validators(verifier: &ValidatorVerifier) -> anyhow::Result<Self> {
+        let voting_powers: Vec<u64> = verifier
+            .get_ordered_account_addresses_iter()
+            .map(|author| verifier.get_voting_power(&author).unwrap_or(0))
+            .collect();
+        Self::for_voting_powers(&voting_powers, verifier.quorum_voting_power())
+    }
+
+    /// One chunk per validator. A quorum can include up to (total - quorum) faulty voting
+    /// power, so the honest validators that certify a batch have at least
+    /// (2 * quorum - total) voting power. The number of data chunks is the smallest number
+    /// of validators that can have that much voting power (f + 1 with equal voting power),
+    /// so that the honest signers of any quorum always hold enough chunks to recover the data.
+    pub fn for_voting_powers(
+        voting_powers: &[u64],
+        quorum_voting_power: u128,
+    ) -> anyhow::Result<Self> {
+        let total_voting_power: u128 = voting_powers.iter().map(|power| *power as u128).sum();
+        let max_faulty_voting_power = total_voting_power.saturating_sub(quorum_voting_power);
+        let min_honest_voting_power = quorum_voting_power.saturating_sub(max_faulty_voting_power);
+
+        let mut sorted_voting_powers = voting_powers.to_vec();
+        sorted_voting_powers.sort_unstable_by(|a, b| b.cmp(a));
+        let mut num_data_chunks = 0;
+        let mut voting_power = 0u128;
+        for power in sorted_voting_powers {
+            if voting_power >= min_honest_voting_power {
+                break;
+            }
+            voting_power += power as u128;
+            num_data_chunks += 1;
+        }
+        Self::new(num_data_chunks.max(1), voting_powers.len())
+    }
+
+    pub fn num_data_chunks(&self) -> usize {
+        self.num_data_chunks
+    }
+
+    pub fn num_chunks(&self) -> usize {
+        self.num_chunks
+    }
+
+    /// The size of every chunk of data with the given length
+    pub fn chunk_len(&self, data_len: usize) -> usize {
+        (data_len + LENGTH_PREFIX_BYTES).div_ceil(self.num_data_chunks)
+    }
+
+    /// Encodes the data into `num_chunks` chunks of equal size
+    pub fn encode(&self, data: &[u8]) -> Vec<Vec<u8>> {
+        let chunk_len = self.chunk_len(data.len());
+        let mut padded = Vec::with_capacity(chunk_len * self.num_data_chunks);
+        padded.extend_from_slice(&(data.len() as u64).to_le_bytes());
+        padded.extend_from_slice(data);
+        padded.resize(chunk_len * self.num_data_chunks, 0);
+        let shards: Vec<&[u8]> = padded.chunks(chunk_len).collect();
+
+        (0..self.num_chunks)
+            .map(|index| {
+                let point = evaluation_point(index);
+                // Horner's rule, from the highest degree coefficient (i.e., the last shard)
+                let mut chunk = vec![0u8; chunk_len];
+                for shard in shards.iter().rev() {
+                    for (value, coefficient) in chunk.iter_mut().zip(shard.iter()) {
+                        *value = gf_mul(*value, point) ^ coefficient;
+                    }
+                }
+                chunk
+            })
+            .collect()
+    }
+
+    /// Recovers the data from (at least) `num_data_chunks` distinct (index, chunk) pairs
+    pub fn decode(&self, chunks: &[(usize, &[u8])]) -> anyhow::Result<Vec<u8>> {
+        let mut indices = HashSet::new();
+        let chunks: Vec<_> = chunks
+            .iter()
+            .filter(|(index, _)| indices.insert(*index))
+            .take(self.num_data_chunks)
+            .collect();
+        ensure!(
+            chunks.len() == self.num_data_chunks,
+            "Not enough chunks to decode: {} < {}",
+            chunks.len(),
+            self.num_data_chunks
+        );
+        let chunk_len = chunks[0].1.len();
+        for (index, chunk) in &chunks {
+            ensure!(
+                *index < self.num_chunks,
+                "Invalid chunk index {} for {} chunks",
+                index,
+                self.num_chunks
+            );
+            ensure!(chunk.len() == chunk_len, "Chunks have different sizes");
+        }
+
+        // Row i of the Vandermonde matrix holds the powers of the evaluation point of chunk i
+        let vandermonde = chunks
+            .iter()
+            .map(|(index, _)| {
+                let point = evaluation_point(*index);
+                let mut power = 1u8;
+                (0..self.num_data_chunks)
+                    .map(|_| {
+                        let value = power;
+                        power = gf_mul(power, point);
+                        value
+                    })
+                    .collect()
+            })
+            .collect();
+        let inverse = invert(vandermonde)?;
+
+        let mut padded = vec![0u8; chunk_len * self.num_data_chunks];
+        for (shard, coefficients) in padded.chunks_mut(chunk_len).zip(inverse.iter()) {
+            for ((_, chunk), coefficient) in chunks.iter().zip(coefficients.iter()) {
+                for (value, byte) in shard.iter_mut().zip(chunk.iter()) {
+                    *value ^= gf_mul(*coefficient, *byte);
+                }
+            }
+        }
+
+        ensure!(padded.len() >= LENGTH_PREFIX_BYTES, "Chunks are too short");
+        let mut length_prefix = [0u8; LENGTH_PREFIX_BYTES];
+        length_prefix.copy_from_slice(&padded[..LENGTH_PREFIX_BYTES]);
+        let data_len = u64::from_le_bytes(length_prefix) as usize;
+        ensure!(
+            data_len <= padded.len() - LENGTH_PREFIX_BYTES,
+            "Invalid data length {}",
+            data_len
+        );
+        padded.truncate(LENGTH_PREFIX_BYTES + data_len);
+        Ok(padded.split_off(LENGTH_PREFIX_BYTES))
+    }
+}
+
+/// The erasure code of the epoch, and the assignment of its chunks to the validators
+/// (the chunk with index i belongs to the validator with index i).
+#[derive(Clone, Debug)]
+pub(crate) struct ErasureCodingContext {
+    codec: ReedSolomon,
+    validators: Vec<PeerId>,
+    my_index: usize,
+}
+
+impl ErasureCodingContext {
+    pub(crate) fn new(my_peer_id: PeerId, verifier: &ValidatorVerifier) -> anyhow::Result<Self> {
+        let validators = verifier.get_ordered_account_addresses();
+        let Some(my_index) = validators.iter().position(|peer| *peer == my_peer_id) else {
+            bail!("{} is not a validator", my_peer_id);
+        };
+        Ok(Self {
+            codec: ReedSolomon::for_validators(verifier)?,
+            validators,
+            my_index,
+        })
+    }
+
+    pub(crate) fn codec(&self) -> &ReedSolomon {
+        &self.codec
+    }
+
+    pub(crate) fn my_index(&self) -> usize {
+        self.my_index
+    }
+
+    pub(crate) fn validator(&self, index: usize) -> PeerId {
+        self.validators[index]
+    }
+
+    /// All the validators, except self and the given peer
+    pub(crate) fn other_validators(&self, excluded: PeerId) -> Vec<PeerId> {
+        let me = self.validators[self.my_index];
+        self.validators
+            .iter()
+            .filter(|peer| **peer != me && **peer != excluded)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Collects the chunks of a batch until it can be reconstructed. Chunks are grouped by
+/// their root, since only chunks encoded together can be decoded together. At most one
+/// chunk is kept per index, and once the root of the batch author's dispersal is pinned,
+/// chunks with any other root are dropped, so the chunks of a batch never take more
+/// memory than its encoding (whatever roots the senders make up).
+pub(crate) struct BatchChunkCollector {
+    codec: ReedSolomon,
+    pinned_root: Option<HashValue>,
+    chunks_by_root: HashMap<HashValue, BTreeMap<u64, BatchChunk>>,
+    failed_roots: HashSet<HashValue>,
+    reconstructed: bool,
+    expiration: u64,
+}
+
+impl BatchChunkCollector {
+    pub(crate) fn new(codec: ReedSolomon, expiration: u64) -> Self {
+        Self {
+            codec,
+            pinned_root: None,
+            chunks_by_root: HashMap::new(),
+            failed_roots: HashSet::new(),
+            reconstructed: false,
+            expiration,
+        }
+    }
+
+    pub(crate) fn expiration(&self) -> u64 {
+        self.expiration
+    }
+
+    pub(crate) fn is_reconstructed(&self) -> bool {
+        self.reconstructed
+    }
+
+    /// Only accepts the chunks with the given root from now on (e.g., the root of the
+    /// chunk received from the batch author), and drops the others
+    pub(crate) fn pin_root(&mut self, root: HashValue) {
+        if self.pinned_root.is_none() {
+            self.pinned_root = Some(root);
+            self.chunks_by_root
+                .retain(|chunks_root, _| *chunks_root == root);
+        }
+    }
+
+    /// Adds a verified chunk, and returns the batch once it's reconstructed (only once).
+    /// A chunk is ignored if a chunk with the same index is already held. The chunks of
+    /// a root that fails to decode (i.e., that was not encoded from the batch with the
+    /// expected digest) are dropped, and ignored from then on.
+    pub(crate) fn add(&mut self, chunk: BatchChunk) -> Option<Batch> {
+        let root = chunk.root();
+        if self.reconstructed
+            || self.failed_roots.contains(&root)
+            || self
+                .pinned_root
+                .is_some_and(|pinned_root| pinned_root != root)
+            || self
+                .chunks_by_root
+                .values()
+                .any(|chunks| chunks.contains_key(&chunk.index()))
+        {
+            return None;
+        }
+        let chunks = self.chunks_by_root.entry(root).or_default();
+        chunks.insert(chunk.index(), chunk);
+        if chunks.len() < self.codec.num_data_chunks() {
+            return None;
+        }
+
+        let chunks: Vec<_> = self
+            .chunks_by_root
+            .remove(&root)
+            .expect("chunks must exist")
+            .into_values()
+            .collect();
+        match BatchChunk::decode(&chunks, &self.codec) {
+            Ok(batch) => {
+                counters::BATCH_RECONSTRUCTION_COUNT
+                    .with_label_values(&[counters::REQUEST_SUCCESS_LABEL])
+                    .inc();
+                self.reconstructed = true;
+                self.chunks_by_root.clear();
+                Some(batch)
+            },
+            Err(e) => {
+                counters::BATCH_RECONSTRUCTION_COUNT
+                    .with_label_values(&[counters::REQUEST_FAIL_LABEL])
+                    .inc();
+                self.failed_roots.insert(root);
+                warn!(
+                    "QS: failed to reconstruct batch {} from chunks with root {}: {}",
+                    chunks[0].digest(),
+                    root,
+                    e
+                );
+                None
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_consensus_types::proof_of_store::BatchId;
+
+    fn test_data(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i * 31 + 7) as u8).collect()
+    }
+
+    #[test]
+    fn test_gf_inverse() {
+        for a in 1..=255u8 {
+            assert_eq!(gf_mul(a, gf_inv(a)), 1);
+        }
+    }
+
+    #[test]
+    fn test_num_data_chunks() {
+        // Equal voting power: f + 1
+        for (num_validators, quorum_voting_power, num_data_chunks) in
+            [(1, 1, 1), (4, 3, 2), (7, 5, 3), (100, 67, 34)]
+        {
+            let codec =
+                ReedSolomon::for_voting_powers(&vec![1; num_validators], quorum_voting_power)
+                    .unwrap();
+            assert_eq!(codec.num_data_chunks(), num_data_chunks);
+            assert_eq!(codec.num_chunks(), num_validators);
+        }
+
+        // Total 100, quorum 67: the honest signers have at least 34 voting power, which
+        // can be held by the single largest validator
+        let codec = ReedSolomon::for_voting_powers(&[10, 40, 10, 10, 10, 10, 10], 67).unwrap();
+        assert_eq!(codec.num_data_chunks(), 1);
+        // ... or by the two largest ones
+        let codec = ReedSolomon::for_voting_powers(&[20, 20, 15, 15, 15, 15], 67).unwrap();
+        assert_eq!(codec.num_data_chunks(), 2);
+        // Validators without voting power still get a chunk
+        let codec = ReedSolomon::for_voting_powers(&[1, 1, 1, 1, 0, 0], 3).unwrap();
+        assert_eq!(codec.num_data_chunks(), 2);
+        assert_eq!(codec.num_chunks(), 6);
+    }
+
+    #[test]
+    fn test_decode_from_any_data_chunks() {
+        let codec = ReedSolomon::for_voting_powers(&[1; 7], 5).unwrap();
+        assert_eq!(codec.num_data_chunks(), 3);
+        let data = test_data(1000);
+        let chunks = codec.encode(&data);
+        assert_eq!(chunks.len(), 7);
+        assert!(chunks
+            .iter()
+            .all(|chunk| chunk.len() == codec.chunk_len(data.len())));
+
+        for indices in [[0, 1, 2], [4, 5, 6], [6, 0, 3], [1, 3, 5]] {
+            let selected: Vec<_> = indices
+                .iter()
+                .map(|index| (*index, chunks[*index].as_slice()))
+                .collect();
+            assert_eq!(codec.decode(&selected).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_decode_edge_cases() {
+        let codec = ReedSolomon::for_voting_powers(&[1; 100], 67).unwrap();
+        for len in [0, 1, 33, 34, 4096] {
+            let data = test_data(len);
+            let chunks = codec.encode(&data);
+            let selected: Vec<_> = chunks
+                .iter()
+                .enumerate()
+                .rev()
+                .map(|(index, chunk)| (index, chunk.as_slice()))
+                .collect();
+            assert_eq!(codec.decode(&selected).unwrap(), data);
+        }
+
+        let single = ReedSolomon::for_voting_powers(&[1], 1).unwrap();
+        let chunks = single.encode(&test_data(10));
+        assert_eq!(single.decode(&[(0, &chunks[0])]).unwrap(), test_data(10));
+    }
+
+    #[test]
+    fn test_not_enough_chunks() {
+        let codec = ReedSolomon::for_voting_powers(&[1; 4], 3).unwrap();
+        let chunks = codec.encode(&test_data(100));
+        // Duplicated chunks are only counted once
+        assert!(codec
+            .decode(&[(0, &chunks[0]), (0, &chunks[0]), (0, &chunks[0])])
+            .is_err());
+        assert!(codec.decode(&[(1, &chunks[1]), (5, &chunks[1])]).is_err());
+        assert!(ReedSolomon::new(3, 256).is_err());
+        assert!(ReedSolomon::new(0, 4).is_err());
+    }
+
+    #[test]
+    fn test_batch_chunk_collector() {
+        let batch = Batch::new(BatchId::new_for_test(1), vec![], 1, 1, PeerId::random(), 0);
+        let codec = ReedSolomon::for_voting_powers(&[1; 4], 3).unwrap();
+        let chunks = BatchChunk::encode(&batch, &codec).unwrap();
+        assert!(chunks.iter().all(|chunk| chunk.verify(&codec).is_ok()));
+
+        let mut collector = BatchChunkCollector::new(codec, batch.expiration());
+        assert!(collector.add(chunks[3].clone()).is_none());
+        // Duplicated chunks don't count towards reconstruction
+        assert!(collector.add(chunks[3].clone()).is_none());
+        let reconstructed = collector.add(chunks[0].clone()).unwrap();
+        assert_eq!(reconstructed.batch_info(), batch.batch_info());
+        assert!(collector.is_reconstructed());
+        // The batch is only returned once
+        assert!(collector.add(chunks[1].clone()).is_none());
+    }
+
+    #[test]
+    fn test_batch_chunk_collector_pinned_root() {
+        let batch = Batch::new(BatchId::new_for_test(1), vec![], 1, 1, PeerId::random(), 0);
+        let other_batch = Batch::new(BatchId::new_for_test(2), vec![], 1, 1, PeerId::random(), 0);
+        let codec = ReedSolomon::for_voting_powers(&[1; 4], 3).unwrap();
+        let chunks = BatchChunk::encode(&batch, &codec).unwrap();
+        let other_chunks = BatchChunk::encode(&other_batch, &codec).unwrap();
+
+        let mut collector = BatchChunkCollector::new(codec, batch.expiration());
+        assert!(collector.add(other_chunks[0].clone()).is_none());
+        // Only one chunk is kept per index, whatever its root
+        assert!(collector.add(chunks[0].clone()).is_none());
+        // Pinning the root drops the chunks with other roots, and ignores them from then on
+        collector.pin_root(chunks[1].root());
+        assert!(collector.add(other_chunks[2].clone()).is_none());
+        assert!(collector.add(chunks[1].clone()).is_none());
+        let reconstructed = collector.add(chunks[0].clone()).unwrap();
+        assert_eq!(reconstructed.batch_info(), batch.batch_info());
+    }
+}
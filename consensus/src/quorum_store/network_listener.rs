@@ -75,6 +75,20 @@ impl NetworkListener {
                             .await
                             .expect("Could not send remote batch");
                     },
+                    VerifiedEvent::BatchChunkMsg(batch_chunk_msg) => {
+                        // Route by the batch author (not the sender), so that all the
+                        // chunks of a batch are collected by the same coordinator
+                        let author = batch_chunk_msg.author();
+                        let chunks = batch_chunk_msg.take();
+                        counters::RECEIVED_BATCH_CHUNK_MSG_COUNT.inc();
+
+                        let idx =
+                            author.to_vec()[0] as usize % self.remote_batch_coordinator_tx.len();
+                        self.remote_batch_coordinator_tx[idx]
+                            .send(BatchCoordinatorCommand::NewBatchChunks(author, chunks))
+                            .await
+                            .expect("Could not send remote batch chunks");
+                    },
                     VerifiedEvent::ProofOfStoreMsg(proofs) => {
                         let cmd = ProofManagerCommand::ReceiveProofs(*proofs);
                         self.proof_manager_tx
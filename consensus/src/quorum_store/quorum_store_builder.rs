@@ -14,11 +14,12 @@ use crate::{
         batch_store::{BatchReader, BatchReaderImpl, BatchStore},
         counters,
         direct_mempool_quorum_store::DirectMempoolQuorumStore,
+        erasure_coding::ErasureCodingContext,
         network_listener::NetworkListener,
         proof_coordinator::{ProofCoordinator, ProofCoordinatorCommand},
         proof_manager::{ProofManager, ProofManagerCommand},
         quorum_store_coordinator::{CoordinatorCommand, QuorumStoreCoordinator},
-        types::{Batch, BatchResponse},
+        types::{Batch, BatchChunk, BatchResponse},
     },
     round_manager::VerifiedEvent,
 };
@@ -144,6 +145,7 @@ pub struct InnerBuilder {
     batch_store: Option<Arc<BatchStore>>,
     batch_reader: Option<Arc<dyn BatchReader>>,
     broadcast_proofs: bool,
+    erasure_coding: Option<ErasureCodingContext>,
 }
 
 impl InnerBuilder {
@@ -162,6 +164,7 @@ impl InnerBuilder {
         backend: SecureBackend,
         quorum_store_storage: Arc<dyn QuorumStoreStorage>,
         broadcast_proofs: bool,
+        batch_erasure_coding: bool,
     ) -> Self {
         let (coordinator_tx, coordinator_rx) = futures_channel::mpsc::channel(config.channel_size);
         let (batch_generator_cmd_tx, batch_generator_cmd_rx) =
@@ -186,6 +189,21 @@ impl InnerBuilder {
             remote_batch_coordinator_cmd_rx.push(batch_coordinator_cmd_rx);
         }
 
+        let erasure_coding = if batch_erasure_coding {
+            match ErasureCodingContext::new(author, &verifier) {
+                Ok(erasure_coding) => Some(erasure_coding),
+                Err(e) => {
+                    warn!(
+                        epoch = epoch,
+                        "QS: batch erasure coding disabled for the epoch: {}", e
+                    );
+                    None
+                },
+            }
+        } else {
+            None
+        };
+
         Self {
             epoch,
             author,
@@ -217,6 +235,7 @@ impl InnerBuilder {
             batch_store: None,
             batch_reader: None,
             broadcast_proofs,
+            erasure_coding,
         }
     }
 
@@ -247,6 +266,9 @@ impl InnerBuilder {
             self.config.batch_request_rpc_timeout_ms,
             self.network_sender.clone(),
             self.verifier.clone(),
+            self.erasure_coding
+                .as_ref()
+                .map(|erasure_coding| *erasure_coding.codec()),
         );
         let batch_store = Arc::new(BatchStore::new(
             self.epoch,
@@ -306,7 +328,8 @@ impl InnerBuilder {
                 self.network_sender.clone(),
                 batch_generator_cmd_rx,
                 back_pressure_rx,
-                interval
+                interval,
+                self.erasure_coding.clone(),
             )
         );
 
@@ -322,6 +345,7 @@ impl InnerBuilder {
                 self.config.receiver_max_batch_bytes as u64,
                 self.config.receiver_max_total_txns as u64,
                 self.config.receiver_max_total_bytes as u64,
+                self.erasure_coding.clone(),
             );
             #[allow(unused_variables)]
             let name = format!("batch_coordinator-{}", i);
@@ -387,6 +411,7 @@ impl InnerBuilder {
                 Some(&counters::BATCH_RETRIEVAL_TASK_MSGS),
             );
         let aptos_db_clone = self.aptos_db.clone();
+        let erasure_coding = self.erasure_coding.clone();
         spawn_named!("batch_serve", async move {
             info!(epoch = epoch, "Batch retrieval task starts");
             while let Some(rpc_request) = batch_retrieval_rx.next().await {
@@ -395,7 +420,52 @@ impl InnerBuilder {
                     batch_store.get_batch_from_local(&rpc_request.req.digest())
                 {
                     let batch: Batch = value.try_into().unwrap();
-                    BatchResponse::Batch(batch)
+                    match (rpc_request.chunk_index, &erasure_coding) {
+                        // Re-encode the batch, and only respond with the requested chunk
+                        (Some(index), Some(erasure_coding)) => {
+                            match BatchChunk::encode(&batch, erasure_coding.codec())
+                                .map(|chunks| chunks.into_iter().nth(index as usize))
+                            {
+                                Ok(Some(chunk)) => {
+                                    counters::inc_batch_dissemination_sent_bytes(
+                                        counters::ERASURE_CODED_DISSEMINATION_LABEL,
+                                        counters::RETRIEVAL_LABEL,
+                                        chunk.num_chunk_bytes() as u64,
+                                    );
+                                    BatchResponse::Chunk(chunk)
+                                },
+                                Ok(None) => {
+                                    warn!(epoch = epoch, "QS: invalid batch chunk index {}", index);
+                                    continue;
+                                },
+                                Err(e) => {
+                                    error!(epoch = epoch, error = ?e, kind = error_kind(&e));
+                                    continue;
+                                },
+                            }
+                        },
+                        _ => {
+                            counters::inc_batch_dissemination_sent_bytes(
+                                counters::FULL_DISSEMINATION_LABEL,
+                                counters::RETRIEVAL_LABEL,
+                                batch.num_bytes(),
+                            );
+                            BatchResponse::Batch(batch)
+                        },
+                    }
+                } else if let Some(chunk) = rpc_request.chunk_index.and_then(|index| {
+                    // We may only store our own chunk of the batch
+                    batch_store
+                        .get_batch_chunk_from_local(&rpc_request.req.digest())
+                        .ok()
+                        .filter(|chunk| chunk.index() == index)
+                }) {
+                    counters::inc_batch_dissemination_sent_bytes(
+                        counters::ERASURE_CODED_DISSEMINATION_LABEL,
+                        counters::RETRIEVAL_LABEL,
+                        chunk.num_chunk_bytes() as u64,
+                    );
+                    BatchResponse::Chunk(chunk)
                 } else {
                     match aptos_db_clone.get_latest_ledger_info() {
                         Ok(ledger_info) => BatchResponse::NotFound(ledger_info),
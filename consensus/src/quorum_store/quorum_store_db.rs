@@ -4,8 +4,11 @@
 use crate::{
     error::DbError,
     quorum_store::{
-        schema::{BatchIdSchema, BatchSchema, BATCH_CF_NAME, BATCH_ID_CF_NAME},
-        types::PersistedValue,
+        schema::{
+            BatchChunkSchema, BatchIdSchema, BatchSchema, BATCH_CF_NAME, BATCH_CHUNK_CF_NAME,
+            BATCH_ID_CF_NAME,
+        },
+        types::{BatchChunk, PersistedValue},
     },
 };
 use anyhow::Result;
@@ -24,6 +27,14 @@ pub trait QuorumStoreStorage: Sync + Send {
 
     fn get_batch(&self, digest: &HashValue) -> Result<Option<PersistedValue>, DbError>;
 
+    fn delete_batch_chunks(&self, digests: Vec<HashValue>) -> Result<(), DbError>;
+
+    fn get_all_batch_chunks(&self) -> Result<HashMap<HashValue, BatchChunk>>;
+
+    fn save_batch_chunk(&self, chunk: BatchChunk) -> Result<(), DbError>;
+
+    fn get_batch_chunk(&self, digest: &HashValue) -> Result<Option<BatchChunk>, DbError>;
+
     fn delete_batch_id(&self, epoch: u64) -> Result<(), DbError>;
 
     fn clean_and_get_batch_id(&self, current_epoch: u64) -> Result<Option<BatchId>, DbError>;
@@ -40,7 +51,7 @@ pub struct QuorumStoreDB {
 
 impl QuorumStoreDB {
     pub(crate) fn new<P: AsRef<Path> + Clone>(db_root_path: P) -> Self {
-        let column_families = vec![BATCH_CF_NAME, BATCH_ID_CF_NAME];
+        let column_families = vec![BATCH_CF_NAME, BATCH_ID_CF_NAME, BATCH_CHUNK_CF_NAME];
 
         // TODO: this fails twins tests because it assumes a unique path per process
         let path = db_root_path.as_ref().join(QUORUM_STORE_DB_NAME);
@@ -92,6 +103,37 @@ impl QuorumStoreStorage for QuorumStoreDB {
         Ok(self.db.get::<BatchSchema>(digest)?)
     }
 
+    fn delete_batch_chunks(&self, digests: Vec<HashValue>) -> Result<(), DbError> {
+        let batch = SchemaBatch::new();
+        for digest in digests.iter() {
+            trace!("QS: db delete chunk of digest {}", digest);
+            batch.delete::<BatchChunkSchema>(digest)?;
+        }
+        self.db.write_schemas(batch)?;
+        Ok(())
+    }
+
+    fn get_all_batch_chunks(&self) -> Result<HashMap<HashValue, BatchChunk>> {
+        let mut iter = self.db.iter::<BatchChunkSchema>(ReadOptions::default())?;
+        iter.seek_to_first();
+        iter.map(|res| res.map_err(Into::into))
+            .collect::<Result<HashMap<HashValue, BatchChunk>>>()
+    }
+
+    fn save_batch_chunk(&self, chunk: BatchChunk) -> Result<(), DbError> {
+        trace!(
+            "QS: db persists chunk {} of digest {} expiration {:?}",
+            chunk.index(),
+            chunk.digest(),
+            chunk.expiration()
+        );
+        Ok(self.db.put::<BatchChunkSchema>(chunk.digest(), &chunk)?)
+    }
+
+    fn get_batch_chunk(&self, digest: &HashValue) -> Result<Option<BatchChunk>, DbError> {
+        Ok(self.db.get::<BatchChunkSchema>(digest)?)
+    }
+
     fn delete_batch_id(&self, epoch: u64) -> Result<(), DbError> {
         let batch = SchemaBatch::new();
         batch.delete::<BatchIdSchema>(&epoch)?;
@@ -149,6 +191,22 @@ impl QuorumStoreStorage for MockQuorumStoreDB {
         Ok(None)
     }
 
+    fn delete_batch_chunks(&self, _: Vec<HashValue>) -> Result<(), DbError> {
+        Ok(())
+    }
+
+    fn get_all_batch_chunks(&self) -> Result<HashMap<HashValue, BatchChunk>> {
+        Ok(HashMap::new())
+    }
+
+    fn save_batch_chunk(&self, _: BatchChunk) -> Result<(), DbError> {
+        Ok(())
+    }
+
+    fn get_batch_chunk(&self, _: &HashValue) -> Result<Option<BatchChunk>, DbError> {
+        Ok(None)
+    }
+
     fn delete_batch_id(&self, _: u64) -> Result<(), DbError> {
         Ok(())
     }
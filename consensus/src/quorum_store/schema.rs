@@ -1,7 +1,7 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::quorum_store::types::PersistedValue;
+use crate::quorum_store::types::{BatchChunk, PersistedValue};
 use anyhow::Result;
 use aptos_consensus_types::proof_of_store::BatchId;
 use aptos_crypto::HashValue;
@@ -12,6 +12,7 @@ use aptos_schemadb::{
 
 pub(crate) const BATCH_CF_NAME: ColumnFamilyName = "batch";
 pub(crate) const BATCH_ID_CF_NAME: ColumnFamilyName = "batch_ID";
+pub(crate) const BATCH_CHUNK_CF_NAME: ColumnFamilyName = "batch_chunk";
 
 #[derive(Debug)]
 pub(crate) struct BatchSchema;
@@ -72,3 +73,33 @@ impl ValueCodec<BatchIdSchema> for BatchId {
         Ok(bcs::from_bytes(data)?)
     }
 }
+
+#[derive(Debug)]
+pub(crate) struct BatchChunkSchema;
+
+impl Schema for BatchChunkSchema {
+    type Key = HashValue;
+    type Value = BatchChunk;
+
+    const COLUMN_FAMILY_NAME: aptos_schemadb::ColumnFamilyName = BATCH_CHUNK_CF_NAME;
+}
+
+impl KeyCodec<BatchChunkSchema> for HashValue {
+    fn encode_key(&self) -> Result<Vec<u8>> {
+        Ok(self.to_vec())
+    }
+
+    fn decode_key(data: &[u8]) -> Result<Self> {
+        Ok(HashValue::from_slice(data)?)
+    }
+}
+
+impl ValueCodec<BatchChunkSchema> for BatchChunk {
+    fn encode_value(&self) -> Result<Vec<u8>> {
+        Ok(bcs::to_bytes(&self)?)
+    }
+
+    fn decode_value(data: &[u8]) -> Result<Self> {
+        Ok(bcs::from_bytes(data)?)
+    }
+}
@@ -5,7 +5,8 @@ use crate::{
     network::QuorumStoreSender,
     quorum_store::{
         batch_requester::BatchRequester,
-        types::{Batch, BatchRequest, BatchResponse},
+        erasure_coding::ReedSolomon,
+        types::{Batch, BatchChunk, BatchChunkRequest, BatchRequest, BatchResponse},
     },
 };
 use aptos_consensus_types::{
@@ -26,11 +27,22 @@ use std::time::{Duration, Instant};
 #[derive(Clone)]
 struct MockBatchRequester {
     return_value: BatchResponse,
+    chunks: Vec<BatchChunk>,
 }
 
 impl MockBatchRequester {
     fn new(return_value: BatchResponse) -> Self {
-        Self { return_value }
+        Self {
+            return_value,
+            chunks: vec![],
+        }
+    }
+
+    fn new_with_chunks(chunks: Vec<BatchChunk>) -> Self {
+        Self {
+            return_value: BatchResponse::Chunk(chunks[0].clone()),
+            chunks,
+        }
     }
 }
 
@@ -49,10 +61,29 @@ impl QuorumStoreSender for MockBatchRequester {
         Ok(self.return_value.clone())
     }
 
+    async fn request_batch_chunk(
+        &self,
+        request: BatchChunkRequest,
+        _recipient: Author,
+        _timeout: Duration,
+    ) -> anyhow::Result<BatchResponse> {
+        Ok(BatchResponse::Chunk(
+            self.chunks[request.index() as usize].clone(),
+        ))
+    }
+
     async fn send_batch(&self, _batch: Batch, _recipients: Vec<Author>) {
         unimplemented!()
     }
 
+    async fn send_batch_msg(&self, _batches: Vec<Batch>, _recipients: Vec<Author>) {
+        unimplemented!()
+    }
+
+    async fn send_batch_chunk_msg(&self, _chunks: Vec<BatchChunk>, _recipients: Vec<Author>) {
+        unimplemented!()
+    }
+
     async fn send_signed_batch_info_msg(
         &self,
         _signed_batch_infos: Vec<SignedBatchInfo>,
@@ -97,6 +128,7 @@ async fn test_batch_request_exists() {
         1_000,
         MockBatchRequester::new(batch_response),
         ValidatorVerifier::new_single(validator_signer.author(), validator_signer.public_key()),
+        None,
     );
 
     let result = batch_requester
@@ -115,6 +147,54 @@ async fn test_batch_request_exists() {
     assert!(rx.try_recv().is_ok());
 }
 
+#[tokio::test]
+async fn test_batch_request_erasure_coded() {
+    let validator_signers: Vec<_> = (0..4).map(|i| ValidatorSigner::random([i; 32])).collect();
+    let validator_verifier = ValidatorVerifier::new(
+        validator_signers
+            .iter()
+            .map(|signer| ValidatorConsensusInfo::new(signer.author(), signer.public_key(), 1))
+            .collect(),
+    );
+    let batch = Batch::new(
+        BatchId::new_for_test(1),
+        vec![],
+        1,
+        1,
+        validator_signers[0].author(),
+        0,
+    );
+    let codec = ReedSolomon::for_validators(&validator_verifier).unwrap();
+    let chunks = BatchChunk::encode(&batch, &codec).unwrap();
+
+    let (tx, mut rx) = tokio::sync::oneshot::channel();
+    let batch_requester = BatchRequester::new(
+        1,
+        AccountAddress::random(),
+        1,
+        2,
+        1_000,
+        1_000,
+        MockBatchRequester::new_with_chunks(chunks),
+        validator_verifier,
+        Some(codec),
+    );
+
+    let result = batch_requester
+        .request_batch(
+            ProofOfStore::new(
+                batch.batch_info().clone(),
+                AggregateSignature::new(vec![u8::MAX].into(), None),
+            ),
+            tx,
+        )
+        .await;
+    let (batch_info, payload) = result.expect("batch must be reconstructed");
+    assert_eq!(batch_info, *batch.batch_info());
+    assert_eq!(payload, batch.into_transactions());
+    assert!(rx.try_recv().is_ok());
+}
+
 fn create_ledger_info_with_timestamp(
     timestamp: u64,
 ) -> (LedgerInfoWithSignatures, ValidatorVerifier) {
@@ -191,6 +271,7 @@ async fn test_batch_request_not_exists_not_expired() {
         1_000,
         MockBatchRequester::new(batch_response),
         validator_verifier,
+        None,
     );
 
     let request_start = Instant::now();
@@ -238,6 +319,7 @@ async fn test_batch_request_not_exists_expired() {
         1_000,
         MockBatchRequester::new(batch_response),
         validator_verifier,
+        None,
     );
 
     let request_start = Instant::now();
@@ -3,8 +3,9 @@
 
 use crate::quorum_store::{
     batch_store::{BatchStore, BatchWriter, QuotaManager},
+    erasure_coding::ReedSolomon,
     quorum_store_db::QuorumStoreDB,
-    types::{PersistedValue, StorageMode},
+    types::{Batch, BatchChunk, PersistedValue, StorageMode},
 };
 use aptos_consensus_types::proof_of_store::{BatchId, BatchInfo};
 use aptos_crypto::HashValue;
@@ -281,3 +282,46 @@ fn test_get_local_batch() {
     assert_err!(store.get_batch_from_local(&digest_2));
     assert_err!(store.get_batch_from_local(&digest_3));
 }
+
+#[test]
+fn test_persist_chunk() {
+    let store = batch_store_for_test(30);
+    let codec = ReedSolomon::for_voting_powers(&[1; 4], 3).unwrap();
+    let batch = Batch::new(
+        BatchId::new_for_test(1),
+        vec![],
+        10,
+        50,
+        *TEST_REQUEST_ACCOUNT,
+        0,
+    );
+    let chunks = BatchChunk::encode(&batch, &codec).unwrap();
+
+    // Storing a chunk signs the batch
+    let signed_info = store.persist_chunk(chunks[1].clone()).unwrap();
+    assert_eq!(signed_info.batch_info(), batch.batch_info());
+    assert_eq!(
+        store
+            .get_batch_chunk_from_local(batch.digest())
+            .unwrap()
+            .index(),
+        1
+    );
+    // Only the first chunk of a batch is kept
+    assert!(store.persist_chunk(chunks[2].clone()).is_some());
+    assert_eq!(
+        store
+            .get_batch_chunk_from_local(batch.digest())
+            .unwrap()
+            .index(),
+        1
+    );
+    // A chunk doesn't make the batch available locally
+    assert_err!(store.get_batch_from_local(batch.digest()));
+
+    store.update_certified_timestamp(50);
+    // Expired chunk
+    assert_err!(store.get_batch_chunk_from_local(batch.digest()));
+    // Expired chunks are neither stored nor signed
+    assert!(store.persist_chunk(chunks[1].clone()).is_none());
+}
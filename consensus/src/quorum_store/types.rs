@@ -1,13 +1,17 @@
 // Copyright © Aptos Foundation
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::quorum_store::erasure_coding::ReedSolomon;
 use anyhow::ensure;
 use aptos_consensus_types::{
     common::BatchPayload,
     proof_of_store::{BatchId, BatchInfo},
 };
 use aptos_crypto::{hash::CryptoHash, HashValue};
-use aptos_types::{ledger_info::LedgerInfoWithSignatures, transaction::SignedTransaction, PeerId};
+use aptos_types::{
+    ledger_info::LedgerInfoWithSignatures, transaction::SignedTransaction,
+    validator_verifier::ValidatorVerifier, PeerId,
+};
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Display, Formatter},
@@ -245,10 +249,280 @@ impl From<Batch> for PersistedValue {
     }
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub struct BatchChunkRequest {
+    request: BatchRequest,
+    index: u64,
+}
+
+impl Display for BatchChunkRequest {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "BatchChunkRequest: {}, index: {}",
+            self.request, self.index
+        )
+    }
+}
+
+impl BatchChunkRequest {
+    pub fn new(source: PeerId, epoch: u64, digest: HashValue, index: u64) -> Self {
+        Self {
+            request: BatchRequest::new(source, epoch, digest),
+            index,
+        }
+    }
+
+    pub fn epoch(&self) -> u64 {
+        self.request.epoch()
+    }
+
+    pub fn verify(&self, peer_id: PeerId) -> anyhow::Result<()> {
+        self.request.verify(peer_id)
+    }
+
+    pub fn digest(&self) -> HashValue {
+        self.request.digest()
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    pub fn into_parts(self) -> (BatchRequest, u64) {
+        (self.request, self.index)
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum BatchResponse {
     Batch(Batch),
     NotFound(LedgerInfoWithSignatures),
+    Chunk(BatchChunk),
+}
+
+/// A Reed-Solomon encoded chunk of a batch (see `erasure_coding`). The digests of all
+/// the chunks are attached, so that every chunk can be checked against the others.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BatchChunk {
+    batch_info: BatchInfo,
+    chunk_digests: Vec<HashValue>,
+    index: u64,
+    #[serde(with = "serde_bytes")]
+    chunk: Vec<u8>,
+}
+
+impl BatchChunk {
+    /// Encodes the payload of the batch into one chunk per validator
+    pub fn encode(batch: &Batch, codec: &ReedSolomon) -> anyhow::Result<Vec<BatchChunk>> {
+        let bytes = bcs::to_bytes(&batch.payload)?;
+        let chunks = codec.encode(&bytes);
+        let chunk_digests: Vec<_> = chunks
+            .iter()
+            .map(|chunk| HashValue::sha3_256_of(chunk.as_slice()))
+            .collect();
+        Ok(chunks
+            .into_iter()
+            .enumerate()
+            .map(|(index, chunk)| BatchChunk {
+                batch_info: batch.batch_info.clone(),
+                chunk_digests: chunk_digests.clone(),
+                index: index as u64,
+                chunk,
+            })
+            .collect())
+    }
+
+    /// Reconstructs (and verifies) the batch from chunks with the same root
+    pub fn decode(chunks: &[BatchChunk], codec: &ReedSolomon) -> anyhow::Result<Batch> {
+        ensure!(!chunks.is_empty(), "No chunks to decode");
+        let root = chunks[0].root();
+        ensure!(
+            chunks.iter().all(|chunk| chunk.root() == root),
+            "Chunks have different roots"
+        );
+        let indexed_chunks: Vec<_> = chunks
+            .iter()
+            .map(|chunk| (chunk.index as usize, chunk.chunk.as_slice()))
+            .collect();
+        let bytes = codec.decode(&indexed_chunks)?;
+        let batch = Batch {
+            batch_info: chunks[0].batch_info.clone(),
+            payload: bcs::from_bytes(&bytes)?,
+        };
+        batch.verify()?;
+        Ok(batch)
+    }
+
+    pub fn verify(&self, codec: &ReedSolomon) -> anyhow::Result<()> {
+        ensure!(
+            self.chunk_digests.len() == codec.num_chunks(),
+            "Invalid number of chunk digests: {} != {}",
+            self.chunk_digests.len(),
+            codec.num_chunks()
+        );
+        ensure!(
+            self.index < self.chunk_digests.len() as u64,
+            "Invalid chunk index {}",
+            self.index
+        );
+        ensure!(
+            self.chunk.len() == codec.chunk_len(self.num_bytes() as usize),
+            "Chunk size doesn't match batch info"
+        );
+        ensure!(
+            HashValue::sha3_256_of(&self.chunk) == self.chunk_digests[self.index as usize],
+            "Chunk hash doesn't match its digest"
+        );
+        Ok(())
+    }
+
+    /// Commits to the batch info and to the digests of all the chunks
+    pub fn root(&self) -> HashValue {
+        let mut bytes = self.batch_info.hash().to_vec();
+        for chunk_digest in &self.chunk_digests {
+            bytes.extend(chunk_digest.iter());
+        }
+        HashValue::sha3_256_of(&bytes)
+    }
+
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    pub fn batch_info(&self) -> &BatchInfo {
+        &self.batch_info
+    }
+
+    pub fn num_chunk_bytes(&self) -> usize {
+        self.chunk.len()
+    }
+}
+
+impl Deref for BatchChunk {
+    type Target = BatchInfo;
+
+    fn deref(&self) -> &Self::Target {
+        &self.batch_info
+    }
+}
+
+/// Our own chunk of a batch, as stored by the batch store. Like the payload of a batch,
+/// the chunk is only kept in memory within the memory quota of the batch author.
+#[derive(Clone, Debug)]
+pub(crate) struct PersistedChunk {
+    info: BatchInfo,
+    num_chunk_bytes: usize,
+    maybe_chunk: Option<BatchChunk>,
+}
+
+impl PersistedChunk {
+    pub(crate) fn new(chunk: BatchChunk, storage_mode: StorageMode) -> Self {
+        Self {
+            info: chunk.batch_info().clone(),
+            num_chunk_bytes: chunk.num_chunk_bytes(),
+            maybe_chunk: match storage_mode {
+                StorageMode::MemoryAndPersisted => Some(chunk),
+                StorageMode::PersistedOnly => None,
+            },
+        }
+    }
+
+    pub(crate) fn chunk_storage_mode(&self) -> StorageMode {
+        match self.maybe_chunk {
+            Some(_) => StorageMode::MemoryAndPersisted,
+            None => StorageMode::PersistedOnly,
+        }
+    }
+
+    pub(crate) fn num_chunk_bytes(&self) -> usize {
+        self.num_chunk_bytes
+    }
+
+    pub(crate) fn chunk(&self) -> &Option<BatchChunk> {
+        &self.maybe_chunk
+    }
+}
+
+impl Deref for PersistedChunk {
+    type Target = BatchInfo;
+
+    fn deref(&self) -> &Self::Target {
+        &self.info
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BatchChunkMsg {
+    chunks: Vec<BatchChunk>,
+}
+
+impl BatchChunkMsg {
+    pub fn new(chunks: Vec<BatchChunk>) -> Self {
+        Self { chunks }
+    }
+
+    /// The chunks must belong to batches of the same author, and must be sent either by
+    /// the batch author, or by the validator the chunk is assigned to (i.e., echoed).
+    pub fn verify(
+        &self,
+        peer_id: PeerId,
+        max_num_batches: usize,
+        max_batch_expiry_gap_usecs: u64,
+        validator: &ValidatorVerifier,
+    ) -> anyhow::Result<()> {
+        ensure!(!self.chunks.is_empty(), "Empty message");
+        ensure!(
+            self.chunks.len() <= max_num_batches,
+            "Too many chunks: {} > {}",
+            self.chunks.len(),
+            max_num_batches
+        );
+        let codec = ReedSolomon::for_validators(validator)?;
+        let author = self.author();
+        let sender_index = validator.address_to_validator_index().get(&peer_id);
+        let max_expiration = aptos_infallible::duration_since_epoch().as_micros() as u64
+            + max_batch_expiry_gap_usecs;
+        for chunk in self.chunks.iter() {
+            ensure!(chunk.author() == author, "Chunk authors don't match");
+            ensure!(
+                peer_id == author
+                    || sender_index.is_some_and(|index| *index as u64 == chunk.index()),
+                "Chunk is sent neither by its author nor by its assigned validator"
+            );
+            ensure!(
+                chunk.expiration() <= max_expiration,
+                "Batch expiration too far in future: {} > {}",
+                chunk.expiration(),
+                max_expiration
+            );
+            chunk.verify(&codec)?
+        }
+        Ok(())
+    }
+
+    pub fn epoch(&self) -> anyhow::Result<u64> {
+        ensure!(!self.chunks.is_empty(), "Empty message");
+        let epoch = self.chunks[0].epoch();
+        for chunk in self.chunks.iter() {
+            ensure!(
+                chunk.epoch() == epoch,
+                "Epoch mismatch: {} != {}",
+                chunk.epoch(),
+                epoch
+            );
+        }
+        Ok(epoch)
+    }
+
+    /// The author of the batches (not necessarily the sender)
+    pub fn author(&self) -> PeerId {
+        self.chunks[0].author()
+    }
+
+    pub fn take(self) -> Vec<BatchChunk> {
+        self.chunks
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -22,7 +22,7 @@ use crate::{
     network_interface::ConsensusMsg,
     pending_votes::VoteReceptionResult,
     persistent_liveness_storage::PersistentLivenessStorage,
-    quorum_store::types::BatchMsg,
+    quorum_store::types::{BatchChunkMsg, BatchMsg},
     rand::rand_gen::types::{FastShare, RandConfig, Share, TShare},
     util::is_vtxn_expected,
 };
@@ -75,6 +75,7 @@ pub enum UnverifiedEvent {
     BatchMsg(Box<BatchMsg>),
     SignedBatchInfo(Box<SignedBatchInfoMsg>),
     ProofOfStoreMsg(Box<ProofOfStoreMsg>),
+    BatchChunkMsg(Box<BatchChunkMsg>),
 }
 
 pub const BACK_PRESSURE_POLLING_INTERVAL_MS: u64 = 10;
@@ -145,6 +146,20 @@ impl UnverifiedEvent {
                 }
                 VerifiedEvent::ProofOfStoreMsg(p)
             },
+            UnverifiedEvent::BatchChunkMsg(c) => {
+                if !self_message {
+                    c.verify(
+                        peer_id,
+                        max_num_batches,
+                        max_batch_expiry_gap_usecs,
+                        validator,
+                    )?;
+                    counters::VERIFY_MSG
+                        .with_label_values(&["batch_chunk"])
+                        .observe(start_time.elapsed().as_secs_f64());
+                }
+                VerifiedEvent::BatchChunkMsg(c)
+            },
         })
     }
 
@@ -156,6 +171,7 @@ impl UnverifiedEvent {
             UnverifiedEvent::BatchMsg(b) => b.epoch(),
             UnverifiedEvent::SignedBatchInfo(sd) => sd.epoch(),
             UnverifiedEvent::ProofOfStoreMsg(p) => p.epoch(),
+            UnverifiedEvent::BatchChunkMsg(c) => c.epoch(),
         }
     }
 }
@@ -169,6 +185,7 @@ impl From<ConsensusMsg> for UnverifiedEvent {
             ConsensusMsg::BatchMsg(m) => UnverifiedEvent::BatchMsg(m),
             ConsensusMsg::SignedBatchInfo(m) => UnverifiedEvent::SignedBatchInfo(m),
             ConsensusMsg::ProofOfStoreMsg(m) => UnverifiedEvent::ProofOfStoreMsg(m),
+            ConsensusMsg::BatchChunkMsg(m) => UnverifiedEvent::BatchChunkMsg(m),
             _ => unreachable!("Unexpected conversion"),
         }
     }
@@ -184,6 +201,7 @@ pub enum VerifiedEvent {
     BatchMsg(Box<BatchMsg>),
     SignedBatchInfo(Box<SignedBatchInfoMsg>),
     ProofOfStoreMsg(Box<ProofOfStoreMsg>),
+    BatchChunkMsg(Box<BatchChunkMsg>),
     // local messages
     LocalTimeout(Round),
     // Shutdown the NetworkListener
@@ -0,0 +1,151 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    dag::{CertifiedNode, CertifiedNodeMessage, DAGMessage, DAGRpcResult, Extensions, Node},
+    network_interface::ConsensusMsg,
+};
+use aptos_consensus_types::{
+    block::Block, common::Payload, proposal_msg::ProposalMsg, quorum_cert::QuorumCert,
+};
+use aptos_types::{
+    aggregate_signature::AggregateSignature, ledger_info::LedgerInfoWithSignatures,
+    validator_signer::ValidatorSigner,
+};
+
+/// The Byzantine behaviours that can be injected into simulated nodes. A Byzantine
+/// node runs the regular consensus logic, but its outbound messages are corrupted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ByzantineBehavior {
+    /// Sends a conflicting proposal (or DAG node) for the same round to every other validator
+    EquivocatingProposals,
+    /// Never sends votes (including timeout votes and DAG node votes) to other validators
+    VoteWithholding,
+    /// Replaces the quorum certificates in its proposals (and the certificates of its
+    /// DAG nodes) with unsigned certificates
+    InvalidQuorumCerts,
+}
+
+impl ByzantineBehavior {
+    /// Returns the message to send to the given recipient in place of the given
+    /// message (or None, if the message should not be sent at all).
+    pub fn corrupt_message(
+        &self,
+        signer: &ValidatorSigner,
+        message: ConsensusMsg,
+        recipient_index: usize,
+    ) -> Option<ConsensusMsg> {
+        match (self, message) {
+            (Self::EquivocatingProposals, ConsensusMsg::ProposalMsg(proposal_msg))
+                if recipient_index % 2 == 1 =>
+            {
+                // Shifting the timestamp is enough to create a conflicting block
+                let proposal = proposal_msg.proposal();
+                let conflicting_proposal = new_proposal(
+                    signer,
+                    proposal,
+                    proposal.timestamp_usecs() + 1,
+                    proposal.quorum_cert().clone(),
+                )?;
+                Some(ConsensusMsg::ProposalMsg(Box::new(ProposalMsg::new(
+                    conflicting_proposal,
+                    proposal_msg.sync_info().clone(),
+                ))))
+            },
+            (Self::VoteWithholding, ConsensusMsg::VoteMsg(_)) => None,
+            (Self::InvalidQuorumCerts, ConsensusMsg::ProposalMsg(proposal_msg)) => {
+                let proposal = proposal_msg.proposal();
+                let quorum_cert = proposal.quorum_cert();
+                let unsigned_quorum_cert = QuorumCert::new(
+                    quorum_cert.vote_data().clone(),
+                    LedgerInfoWithSignatures::new(
+                        quorum_cert.ledger_info().ledger_info().clone(),
+                        AggregateSignature::empty(),
+                    ),
+                );
+                let invalid_proposal = new_proposal(
+                    signer,
+                    proposal,
+                    proposal.timestamp_usecs(),
+                    unsigned_quorum_cert,
+                )?;
+                Some(ConsensusMsg::ProposalMsg(Box::new(ProposalMsg::new(
+                    invalid_proposal,
+                    proposal_msg.sync_info().clone(),
+                ))))
+            },
+            (_, message) => Some(message),
+        }
+    }
+
+    /// Returns the DAG message to send to the given recipient in place of the given
+    /// message (or None, if the message should not be sent at all).
+    pub fn corrupt_dag_message(
+        &self,
+        message: DAGMessage,
+        recipient_index: usize,
+    ) -> Option<DAGMessage> {
+        match (self, message) {
+            (Self::EquivocatingProposals, DAGMessage::NodeMsg(node))
+                if recipient_index % 2 == 1 =>
+            {
+                // Shifting the timestamp is enough to create a conflicting node
+                Some(DAGMessage::NodeMsg(Node::new(
+                    node.epoch(),
+                    node.round(),
+                    *node.author(),
+                    node.timestamp() + 1,
+                    node.validator_txns().clone(),
+                    node.payload().clone(),
+                    node.parents().to_vec(),
+                    Extensions::empty(),
+                )))
+            },
+            (Self::InvalidQuorumCerts, DAGMessage::CertifiedNodeMsg(certified_node_msg)) => {
+                let unsigned_certified_node = CertifiedNode::new(
+                    Node::clone(&certified_node_msg),
+                    AggregateSignature::empty(),
+                );
+                Some(DAGMessage::CertifiedNodeMsg(CertifiedNodeMessage::new(
+                    unsigned_certified_node,
+                    certified_node_msg.ledger_info().clone(),
+                )))
+            },
+            (_, message) => Some(message),
+        }
+    }
+
+    /// Returns the response to send in place of the given response to a DAG RPC
+    /// (or None, if the response should not be sent at all).
+    pub fn corrupt_dag_response(&self, response: DAGRpcResult) -> Option<DAGRpcResult> {
+        match (self, &response.0) {
+            (Self::VoteWithholding, Ok(DAGMessage::VoteMsg(_))) => None,
+            _ => Some(response),
+        }
+    }
+}
+
+/// Signs a copy of the given proposal with the given timestamp and quorum certificate
+fn new_proposal(
+    signer: &ValidatorSigner,
+    proposal: &Block,
+    timestamp_usecs: u64,
+    quorum_cert: QuorumCert,
+) -> Option<Block> {
+    Block::new_proposal(
+        proposal
+            .payload()
+            .cloned()
+            .unwrap_or_else(|| Payload::empty(false, true)),
+        proposal.round(),
+        timestamp_usecs,
+        quorum_cert,
+        signer,
+        proposal
+            .block_data()
+            .failed_authors()
+            .cloned()
+            .unwrap_or_default(),
+    )
+    .ok()
+}
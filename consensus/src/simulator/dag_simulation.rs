@@ -0,0 +1,381 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    dag::{DAGMessage, DAGRpcResult},
+    network::{IncomingDAGRequest, RpcResponder, TConsensusMsg},
+    network_interface::ConsensusMsg,
+    pipeline::buffer_manager::OrderedBlocks,
+    simulator::{
+        byzantine::ByzantineBehavior,
+        invariants::{InvariantChecker, InvariantViolation},
+        scheduler::{ScheduledMessage, SimulationScheduler, Tick},
+        simulated_node::{new_peers_and_metadata, SimulatedDagNode},
+        simulation::{SimulationConfig, SimulationError, SimulationReport},
+    },
+};
+use aptos_consensus_types::common::Author;
+use aptos_logger::prelude::*;
+use aptos_network::{
+    peer_manager::PeerManagerRequest,
+    protocols::{
+        network::{Event, SerializedRequest},
+        rpc::error::RpcError,
+    },
+    ProtocolId,
+};
+use aptos_time_service::{MockTimeService, TimeService};
+use aptos_types::validator_verifier::random_validator_verifier;
+use bytes::Bytes;
+use futures::{channel::oneshot, FutureExt, StreamExt};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
+
+/// The number of times the simulation yields to the DAG tasks after every step,
+/// so that the nodes can process the delivered messages (and expired timers).
+const NUM_SETTLE_YIELDS: usize = 100;
+
+/// The maximum number of ticks to advance the virtual time by when no message is due.
+/// The DAG timers (e.g., the reliable broadcast retries) are woken up at this granularity.
+const MAX_IDLE_TICKS: Tick = 10;
+
+type RpcResponseSender = oneshot::Sender<Result<Bytes, RpcError>>;
+
+/// A DAG RPC (or its response) in flight between two simulated nodes
+#[derive(Debug)]
+enum DagRpc {
+    Request {
+        message: ConsensusMsg,
+        protocol_id: ProtocolId,
+        response_sender: RpcResponseSender,
+    },
+    Response {
+        response: Result<Bytes, RpcError>,
+        response_sender: RpcResponseSender,
+    },
+}
+
+/// A DAG RPC that was delivered, and is waiting for the response of its recipient
+struct PendingRpc {
+    requester: usize,
+    responder: usize,
+    protocol_id: ProtocolId,
+    response_receiver: oneshot::Receiver<Result<Bytes, RpcError>>,
+    response_sender: RpcResponseSender,
+}
+
+/// A simulation of a set of validators running DAG consensus. The RPCs between the
+/// nodes (and their responses) are delivered by a seeded scheduler, and the DAG
+/// timers run on a mock clock that follows the virtual time.
+///
+/// Unlike the round manager, the DAG runs in its own tasks, so the simulation lets
+/// the tasks settle after every step. The simulation must run on a current-thread
+/// runtime, so that the tasks are always polled in the same order for a given seed.
+pub struct DagSimulation {
+    seed: u64,
+    config: SimulationConfig,
+    scheduler: SimulationScheduler<DagRpc>,
+    time_service: MockTimeService,
+    nodes: Vec<SimulatedDagNode>,
+    author_indices: HashMap<Author, usize>,
+    pending_rpcs: Vec<PendingRpc>,
+    invariant_checker: InvariantChecker,
+    steps: u64,
+}
+
+impl DagSimulation {
+    pub fn new(seed: u64, config: SimulationConfig) -> Self {
+        let (signers, validator_verifier) =
+            random_validator_verifier(config.num_nodes, None, false);
+        let author_indices: HashMap<_, _> = signers
+            .iter()
+            .enumerate()
+            .map(|(index, signer)| (signer.author(), index))
+            .collect();
+
+        let peers_and_metadata = new_peers_and_metadata(&signers);
+        let time_service = MockTimeService::new();
+        let nodes = signers
+            .iter()
+            .map(|signer| {
+                SimulatedDagNode::new(
+                    signer.clone(),
+                    &signers,
+                    validator_verifier.clone(),
+                    peers_and_metadata.clone(),
+                    TimeService::from_mock(time_service.clone()),
+                )
+            })
+            .collect();
+
+        let honest_nodes: HashSet<_> = (0..config.num_nodes)
+            .filter(|index| !config.byzantine_nodes.contains_key(index))
+            .collect();
+        Self {
+            seed,
+            scheduler: SimulationScheduler::new(seed, config.network_faults.clone()),
+            time_service,
+            nodes,
+            author_indices,
+            pending_rpcs: vec![],
+            invariant_checker: InvariantChecker::new(honest_nodes, config.liveness_window),
+            steps: 0,
+            config,
+        }
+    }
+
+    /// Runs the simulation until the maximum tick is reached (or an invariant is violated)
+    pub async fn run(mut self) -> Result<SimulationReport, SimulationError> {
+        // Schedule the messages sent during initialization (e.g., the first nodes)
+        self.complete_step().await?;
+
+        while self.scheduler.now() < self.config.max_ticks {
+            if let Some(message) = self.scheduler.pop_due_message() {
+                self.deliver_rpc(message);
+            } else {
+                // Nothing is due, so advance the time to the next delivery (or by the
+                // maximum idle time, to fire the DAG timers in the meantime)
+                let now = self.scheduler.now();
+                let next_tick = self
+                    .scheduler
+                    .next_delivery_tick()
+                    .unwrap_or(Tick::MAX)
+                    .min(now + MAX_IDLE_TICKS)
+                    .min(self.config.max_ticks);
+                self.scheduler.advance_to(next_tick);
+                self.time_service
+                    .advance(Duration::from_millis(next_tick - now));
+            }
+            self.complete_step().await?;
+        }
+
+        let highest_ordered_round = self.invariant_checker.highest_ordered_round();
+        if highest_ordered_round < self.config.min_ordered_rounds {
+            return Err(self.error(InvariantViolation::InsufficientProgress {
+                highest_ordered_round,
+                expected_round: self.config.min_ordered_rounds,
+            }));
+        }
+        Ok(SimulationReport {
+            steps: self.steps,
+            highest_ordered_round,
+        })
+    }
+
+    /// Hands the given request to the DAG handler of the destination node, or the
+    /// given response to the requester.
+    fn deliver_rpc(&mut self, message: ScheduledMessage<DagRpc>) {
+        let ScheduledMessage { src, dst, message } = message;
+        match message {
+            DagRpc::Request {
+                message: ConsensusMsg::DAGMessage(request),
+                protocol_id,
+                response_sender,
+            } => {
+                // The response is intercepted, so that it can be scheduled as well
+                let (intercepted_response_sender, response_receiver) = oneshot::channel();
+                let src_author = self.nodes[src].signer.author();
+                let incoming_request = IncomingDAGRequest {
+                    req: request,
+                    sender: src_author,
+                    responder: RpcResponder {
+                        protocol: protocol_id,
+                        response_sender: intercepted_response_sender,
+                    },
+                };
+                if let Err(error) = self.nodes[dst]
+                    .dag_rpc_tx
+                    .push(src_author, incoming_request)
+                {
+                    debug!(
+                        "[Simulator] Node {} failed to receive a DAG request from node {}: {:?}",
+                        dst, src, error
+                    );
+                    return;
+                }
+                self.pending_rpcs.push(PendingRpc {
+                    requester: src,
+                    responder: dst,
+                    protocol_id,
+                    response_receiver,
+                    response_sender,
+                });
+            },
+            DagRpc::Request { message, .. } => {
+                debug!(
+                    "[Simulator] Ignoring unsupported request: {}",
+                    message.name()
+                );
+            },
+            DagRpc::Response {
+                response,
+                response_sender,
+            } => {
+                // The requester may have timed out already
+                let _ = response_sender.send(response);
+            },
+        }
+    }
+
+    /// Lets the DAG tasks settle, schedules the RPCs (and responses) sent by the nodes,
+    /// records the blocks they ordered and checks all invariants.
+    async fn complete_step(&mut self) -> Result<(), SimulationError> {
+        self.steps += 1;
+        for _ in 0..NUM_SETTLE_YIELDS {
+            tokio::task::yield_now().await;
+        }
+
+        for node_index in 0..self.nodes.len() {
+            self.schedule_outbound_rpcs(node_index);
+        }
+        self.schedule_rpc_responses();
+
+        // Record and commit the ordered blocks
+        for node_index in 0..self.nodes.len() {
+            while let Ok(Some(ordered_blocks)) = self.nodes[node_index].ordered_blocks.try_next() {
+                if let Err(violation) = self.invariant_checker.record_ordered_blocks(
+                    node_index,
+                    &ordered_blocks.ordered_blocks,
+                    self.scheduler.now(),
+                ) {
+                    return Err(self.error(violation));
+                }
+                // Committing the blocks lets the DAG prune its store
+                let OrderedBlocks {
+                    ordered_blocks,
+                    ordered_proof,
+                    callback,
+                } = ordered_blocks;
+                callback(
+                    &ordered_blocks.into_iter().map(Arc::new).collect::<Vec<_>>(),
+                    ordered_proof,
+                );
+            }
+        }
+
+        self.invariant_checker
+            .check_liveness(
+                self.scheduler.now(),
+                self.scheduler.global_stabilization_tick(),
+            )
+            .map_err(|violation| self.error(violation))
+    }
+
+    /// Drains the RPCs sent by the given node (to itself and to others), and schedules
+    /// them for delivery (after corrupting them, if the node is Byzantine).
+    fn schedule_outbound_rpcs(&mut self, node_index: usize) {
+        let node = &mut self.nodes[node_index];
+        let mut rpcs = vec![];
+        while let Some(Some(event)) = node.self_messages.next().now_or_never() {
+            if let Event::RpcRequest(_, message, protocol_id, response_sender) = event {
+                rpcs.push((node_index, message, protocol_id, response_sender));
+            }
+        }
+        while let Some(Some(request)) = node.outbound_requests.next().now_or_never() {
+            // The DAG only communicates over RPCs
+            let PeerManagerRequest::SendRpc(peer_id, rpc_request) = request else {
+                continue;
+            };
+            let (Some(&dst), Ok(message)) = (
+                self.author_indices.get(&peer_id),
+                rpc_request.to_message::<ConsensusMsg>(),
+            ) else {
+                continue;
+            };
+            rpcs.push((dst, message, rpc_request.protocol_id, rpc_request.res_tx));
+        }
+
+        for (dst, message, protocol_id, response_sender) in rpcs {
+            let message = match self.config.byzantine_nodes.get(&node_index) {
+                Some(behavior) if dst != node_index => {
+                    match DAGMessage::from_network_message(message)
+                        .ok()
+                        .and_then(|message| behavior.corrupt_dag_message(message, dst))
+                    {
+                        Some(message) => message.into_network_message(),
+                        None => continue,
+                    }
+                },
+                _ => message,
+            };
+            self.scheduler.send(node_index, dst, DagRpc::Request {
+                message,
+                protocol_id,
+                response_sender,
+            });
+        }
+    }
+
+    /// Schedules the responses to the delivered RPCs that have been processed (after
+    /// corrupting them, if the responder is Byzantine).
+    fn schedule_rpc_responses(&mut self) {
+        let mut pending_rpcs = vec![];
+        for mut pending_rpc in std::mem::take(&mut self.pending_rpcs) {
+            let response = match pending_rpc.response_receiver.try_recv() {
+                Ok(Some(response)) => response,
+                Ok(None) => {
+                    pending_rpcs.push(pending_rpc);
+                    continue;
+                },
+                // The responder dropped the request, so the RPC fails
+                Err(_) => continue,
+            };
+            let response = match self.config.byzantine_nodes.get(&pending_rpc.responder) {
+                Some(behavior) if pending_rpc.requester != pending_rpc.responder => {
+                    match corrupt_rpc_response(behavior, pending_rpc.protocol_id, response) {
+                        Some(response) => response,
+                        None => continue,
+                    }
+                },
+                _ => response,
+            };
+            self.scheduler.send(
+                pending_rpc.responder,
+                pending_rpc.requester,
+                DagRpc::Response {
+                    response,
+                    response_sender: pending_rpc.response_sender,
+                },
+            );
+        }
+        self.pending_rpcs = pending_rpcs;
+    }
+
+    fn error(&self, violation: InvariantViolation) -> SimulationError {
+        SimulationError {
+            seed: self.seed,
+            tick: self.scheduler.now(),
+            step: self.steps,
+            violation,
+        }
+    }
+}
+
+/// Returns the serialized response to send in place of the given response (or None,
+/// if the response should not be sent at all). Responses that can't be deserialized
+/// are sent as is.
+fn corrupt_rpc_response(
+    behavior: &ByzantineBehavior,
+    protocol_id: ProtocolId,
+    response: Result<Bytes, RpcError>,
+) -> Option<Result<Bytes, RpcError>> {
+    let Ok(bytes) = &response else {
+        return Some(response);
+    };
+    let Ok(result) = protocol_id
+        .from_bytes::<ConsensusMsg>(bytes)
+        .and_then(DAGRpcResult::from_network_message)
+    else {
+        return Some(response);
+    };
+    let result = behavior.corrupt_dag_response(result)?;
+    Some(
+        protocol_id
+            .to_bytes(&result.into_network_message())
+            .map(Bytes::from)
+            .map_err(RpcError::Error),
+    )
+}
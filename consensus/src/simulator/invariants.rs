@@ -0,0 +1,165 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::simulator::scheduler::Tick;
+use aptos_consensus_types::{common::Round, pipelined_block::PipelinedBlock};
+use aptos_crypto::HashValue;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::{Display, Formatter},
+};
+
+/// A violation of the safety or liveness properties of consensus
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InvariantViolation {
+    /// Two honest nodes ordered different blocks for the same round
+    ConflictingOrderedBlocks {
+        round: Round,
+        first: (usize, HashValue),
+        second: (usize, HashValue),
+    },
+    /// An honest node ordered a block at a round that is not higher than its last ordered block
+    NonIncreasingOrderedRound {
+        node: usize,
+        last_ordered_round: Round,
+        round: Round,
+    },
+    /// The honest nodes did not order a new block for too long after the network stabilized
+    NoProgress { last_progress_tick: Tick, now: Tick },
+    /// The honest nodes did not order enough blocks by the end of the simulation
+    InsufficientProgress {
+        highest_ordered_round: Round,
+        expected_round: Round,
+    },
+}
+
+impl Display for InvariantViolation {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            InvariantViolation::ConflictingOrderedBlocks {
+                round,
+                first,
+                second,
+            } => write!(
+                f,
+                "Conflicting blocks ordered at round {}: {} by node {} and {} by node {}",
+                round, first.1, first.0, second.1, second.0
+            ),
+            InvariantViolation::NonIncreasingOrderedRound {
+                node,
+                last_ordered_round,
+                round,
+            } => write!(
+                f,
+                "Node {} ordered round {} after round {}",
+                node, round, last_ordered_round
+            ),
+            InvariantViolation::NoProgress {
+                last_progress_tick,
+                now,
+            } => write!(
+                f,
+                "No block was ordered between ticks {} and {}",
+                last_progress_tick, now
+            ),
+            InvariantViolation::InsufficientProgress {
+                highest_ordered_round,
+                expected_round,
+            } => write!(
+                f,
+                "The highest ordered round is {}, but expected at least {}",
+                highest_ordered_round, expected_round
+            ),
+        }
+    }
+}
+
+/// Checks the safety and liveness invariants using the blocks ordered by the honest nodes
+pub struct InvariantChecker {
+    honest_nodes: HashSet<usize>,
+    liveness_window: Tick,
+
+    // The block ordered at each round (and the first node that ordered it)
+    ordered_blocks: BTreeMap<Round, (usize, HashValue)>,
+    // The last round ordered by each node
+    last_ordered_rounds: HashMap<usize, Round>,
+    highest_ordered_round: Round,
+    last_progress_tick: Tick,
+}
+
+impl InvariantChecker {
+    pub fn new(honest_nodes: HashSet<usize>, liveness_window: Tick) -> Self {
+        Self {
+            honest_nodes,
+            liveness_window,
+            ordered_blocks: BTreeMap::new(),
+            last_ordered_rounds: HashMap::new(),
+            highest_ordered_round: 0,
+            last_progress_tick: 0,
+        }
+    }
+
+    pub fn highest_ordered_round(&self) -> Round {
+        self.highest_ordered_round
+    }
+
+    /// Records the blocks ordered by the given node, and verifies that they do not
+    /// conflict with the blocks ordered by the other honest nodes.
+    pub fn record_ordered_blocks(
+        &mut self,
+        node: usize,
+        blocks: &[PipelinedBlock],
+        now: Tick,
+    ) -> Result<(), InvariantViolation> {
+        if !self.honest_nodes.contains(&node) {
+            return Ok(());
+        }
+
+        for block in blocks {
+            let round = block.round();
+            let last_ordered_round = self.last_ordered_rounds.entry(node).or_default();
+            if round <= *last_ordered_round {
+                return Err(InvariantViolation::NonIncreasingOrderedRound {
+                    node,
+                    last_ordered_round: *last_ordered_round,
+                    round,
+                });
+            }
+            *last_ordered_round = round;
+
+            let (first_node, first_block_id) = *self
+                .ordered_blocks
+                .entry(round)
+                .or_insert((node, block.id()));
+            if first_block_id != block.id() {
+                return Err(InvariantViolation::ConflictingOrderedBlocks {
+                    round,
+                    first: (first_node, first_block_id),
+                    second: (node, block.id()),
+                });
+            }
+
+            if round > self.highest_ordered_round {
+                self.highest_ordered_round = round;
+                self.last_progress_tick = now;
+            }
+        }
+        Ok(())
+    }
+
+    /// Verifies that the honest nodes made progress recently (if the network is stable)
+    pub fn check_liveness(
+        &self,
+        now: Tick,
+        global_stabilization_tick: Tick,
+    ) -> Result<(), InvariantViolation> {
+        let last_progress_tick = self.last_progress_tick.max(global_stabilization_tick);
+        if now > last_progress_tick + self.liveness_window {
+            return Err(InvariantViolation::NoProgress {
+                last_progress_tick,
+                now,
+            });
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! A deterministic simulator for consensus. A set of validators (running the
+//! round manager or DAG consensus) is driven by a seeded scheduler that controls
+//! the delivery of every message and the virtual time. The scheduler can inject
+//! network faults (delays, reordering, drops and partitions) and Byzantine
+//! behaviours, and the safety and liveness invariants are checked after every
+//! step. A failing run can be replayed by setting `CONSENSUS_SIMULATOR_SEED`.
+//!
+//! Messages are taken from the network senders and handed to the nodes directly,
+//! instead of going through the memsocket transport. The transport runs its own
+//! tasks for every connection, so the delivery order would depend on the tokio
+//! scheduler rather than on the seed. The DAG also runs its own tasks, so the DAG
+//! simulation runs on a current-thread runtime and lets the tasks settle after
+//! every step.
+
+mod byzantine;
+mod dag_simulation;
+mod invariants;
+mod scheduler;
+mod simulated_node;
+mod simulation;
+mod simulation_tests;
@@ -0,0 +1,185 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::network_interface::ConsensusMsg;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::{BTreeMap, HashSet};
+
+/// The virtual time of the simulation. Each tick corresponds to one millisecond.
+pub type Tick = u64;
+
+/// A network partition that is active in the range `[start, end)`. Nodes in
+/// different groups (or in no group at all) cannot communicate while it is active.
+#[derive(Clone, Debug)]
+pub struct Partition {
+    pub start: Tick,
+    pub end: Tick,
+    pub groups: Vec<HashSet<usize>>,
+}
+
+impl Partition {
+    pub fn new(start: Tick, end: Tick, groups: Vec<Vec<usize>>) -> Self {
+        Self {
+            start,
+            end,
+            groups: groups
+                .into_iter()
+                .map(|group| group.into_iter().collect())
+                .collect(),
+        }
+    }
+
+    fn is_active(&self, now: Tick) -> bool {
+        self.start <= now && now < self.end
+    }
+
+    fn separates(&self, first_node: usize, second_node: usize) -> bool {
+        !self
+            .groups
+            .iter()
+            .any(|group| group.contains(&first_node) && group.contains(&second_node))
+    }
+}
+
+/// The faults injected by the simulated network
+#[derive(Clone, Debug)]
+pub struct NetworkFaults {
+    /// The minimum and maximum delay (in ticks) of each message. Messages are
+    /// delivered in the order of their (random) delivery ticks, so a non-zero
+    /// range also reorders messages.
+    pub min_delay: Tick,
+    pub max_delay: Tick,
+    /// The probability of dropping each message, until `drop_until`
+    pub drop_probability: f64,
+    pub drop_until: Tick,
+    /// The network partitions
+    pub partitions: Vec<Partition>,
+}
+
+impl NetworkFaults {
+    /// Returns the tick after which no messages are dropped (i.e., the global
+    /// stabilization time), and only the liveness of consensus can be checked.
+    pub fn global_stabilization_tick(&self) -> Tick {
+        self.partitions
+            .iter()
+            .map(|partition| partition.end)
+            .chain(std::iter::once(self.drop_until))
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl Default for NetworkFaults {
+    fn default() -> Self {
+        Self {
+            min_delay: 10,
+            max_delay: 10,
+            drop_probability: 0.0,
+            drop_until: 0,
+            partitions: vec![],
+        }
+    }
+}
+
+/// A message in flight between two simulated nodes
+#[derive(Debug)]
+pub struct ScheduledMessage<M = ConsensusMsg> {
+    pub src: usize,
+    pub dst: usize,
+    pub message: M,
+}
+
+/// Schedules the delivery of messages between simulated nodes. All decisions
+/// (i.e., delays and drops) are taken from a single seeded RNG, so that the same
+/// seed always leads to the same schedule.
+pub struct SimulationScheduler<M = ConsensusMsg> {
+    rng: StdRng,
+    faults: NetworkFaults,
+    now: Tick,
+    next_sequence_number: u64,
+    pending_messages: BTreeMap<(Tick, u64), ScheduledMessage<M>>,
+}
+
+impl<M> SimulationScheduler<M> {
+    pub fn new(seed: u64, faults: NetworkFaults) -> Self {
+        assert!(faults.min_delay <= faults.max_delay);
+        Self {
+            rng: StdRng::seed_from_u64(seed),
+            faults,
+            now: 0,
+            next_sequence_number: 0,
+            pending_messages: BTreeMap::new(),
+        }
+    }
+
+    pub fn now(&self) -> Tick {
+        self.now
+    }
+
+    pub fn global_stabilization_tick(&self) -> Tick {
+        self.faults.global_stabilization_tick()
+    }
+
+    /// Returns true iff the given nodes cannot communicate at the current tick
+    pub fn is_partitioned(&self, first_node: usize, second_node: usize) -> bool {
+        first_node != second_node
+            && self.faults.partitions.iter().any(|partition| {
+                partition.is_active(self.now) && partition.separates(first_node, second_node)
+            })
+    }
+
+    /// Schedules the given message for delivery. Messages to self are delivered
+    /// without delay and are never dropped.
+    pub fn send(&mut self, src: usize, dst: usize, message: M) {
+        let delay = if src == dst {
+            0
+        } else {
+            if self.is_partitioned(src, dst) {
+                return;
+            }
+            if self.now < self.faults.drop_until && self.rng.gen_bool(self.faults.drop_probability)
+            {
+                return;
+            }
+            self.rng
+                .gen_range(self.faults.min_delay, self.faults.max_delay + 1)
+        };
+
+        let sequence_number = self.next_sequence_number;
+        self.next_sequence_number += 1;
+        self.pending_messages
+            .insert((self.now + delay, sequence_number), ScheduledMessage {
+                src,
+                dst,
+                message,
+            });
+    }
+
+    /// Removes and returns the next message due for delivery (if any). Messages
+    /// between nodes that are partitioned at delivery time are dropped.
+    pub fn pop_due_message(&mut self) -> Option<ScheduledMessage<M>> {
+        while let Some(entry) = self.pending_messages.first_entry() {
+            if entry.key().0 > self.now {
+                return None;
+            }
+            let message = entry.remove();
+            if !self.is_partitioned(message.src, message.dst) {
+                return Some(message);
+            }
+        }
+        None
+    }
+
+    /// Returns the tick at which the next pending message is due
+    pub fn next_delivery_tick(&self) -> Option<Tick> {
+        self.pending_messages
+            .keys()
+            .next()
+            .map(|(delivery_tick, _)| *delivery_tick)
+    }
+
+    /// Advances the virtual time to the given tick
+    pub fn advance_to(&mut self, tick: Tick) {
+        self.now = self.now.max(tick);
+    }
+}
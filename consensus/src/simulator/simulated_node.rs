@@ -0,0 +1,401 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    block_storage::BlockStore,
+    dag::{bootstrap_dag_for_test, MockDAGStorage},
+    error::QuorumStoreError,
+    liveness::{
+        proposal_generator::{
+            ChainHealthBackoffConfig, PipelineBackpressureConfig, ProposalGenerator,
+        },
+        rotating_proposer_election::RotatingProposer,
+        round_state::{ExponentialTimeInterval, RoundState},
+    },
+    metrics_safety_rules::MetricsSafetyRules,
+    network::{IncomingDAGRequest, NetworkSender},
+    network_interface::{ConsensusMsg, ConsensusNetworkClient, DIRECT_SEND, RPC},
+    payload_client::PayloadClient,
+    payload_manager::PayloadManager,
+    pipeline::{buffer_manager::OrderedBlocks, execution_client::DummyExecutionClient},
+    round_manager::RoundManager,
+    test_utils::{mock_execution_client::MockExecutionClient, MockStorage},
+    util::time_service::{ScheduledTask, TimeService},
+};
+use aptos_channels::{aptos_channel, message_queues::QueueStyle};
+use aptos_config::{
+    config::{ConsensusConfig, DagConsensusConfig, DagRoundStateConfig, QcAggregatorType},
+    network_id::{NetworkId, PeerNetworkId},
+};
+use aptos_consensus_types::common::{Author, Payload, PayloadFilter};
+use aptos_infallible::Mutex;
+use aptos_network::{
+    application::{interface::NetworkClient, storage::PeersAndMetadata},
+    peer_manager::{ConnectionRequestSender, PeerManagerRequest, PeerManagerRequestSender},
+    protocols::{
+        network::{self, Event, NewNetworkSender},
+        wire::handshake::v1::ProtocolIdSet,
+    },
+    transport::ConnectionMetadata,
+    ProtocolId,
+};
+use aptos_safety_rules::{PersistentSafetyStorage, SafetyRulesManager};
+use aptos_secure_storage::Storage;
+use aptos_types::{
+    epoch_state::EpochState,
+    ledger_info::{generate_ledger_info_with_sig, LedgerInfo},
+    on_chain_config::{
+        ConsensusConfigV1, OnChainConsensusConfig, OnChainJWKConsensusConfig,
+        OnChainRandomnessConfig,
+    },
+    transaction::SignedTransaction,
+    validator_signer::ValidatorSigner,
+    validator_txn::ValidatorTransaction,
+    validator_verifier::ValidatorVerifier,
+    waypoint::Waypoint,
+    PeerId,
+};
+use aptos_validator_transaction_pool::TransactionFilter;
+use async_trait::async_trait;
+use futures::{
+    channel::mpsc,
+    future::{AbortHandle, BoxFuture},
+};
+use futures_channel::mpsc::unbounded;
+use maplit::hashmap;
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::task;
+
+// The virtual clock starts at a fixed (non-zero) timestamp to keep block timestamps deterministic
+const SIMULATION_START_TIMESTAMP_USECS: u64 = 1_000_000;
+
+/// A time service driven by the simulation: timers never fire (the simulation
+/// injects the round timeouts itself), and sleeping simply advances the clock.
+pub struct SimulatedTimeService {
+    now_usecs: AtomicU64,
+}
+
+impl SimulatedTimeService {
+    pub fn new() -> Self {
+        Self {
+            now_usecs: AtomicU64::new(SIMULATION_START_TIMESTAMP_USECS),
+        }
+    }
+
+    /// Advances the clock to the given duration since the start of the simulation
+    pub fn advance_to(&self, elapsed: Duration) {
+        self.now_usecs.fetch_max(
+            SIMULATION_START_TIMESTAMP_USECS + elapsed.as_micros() as u64,
+            Ordering::SeqCst,
+        );
+    }
+}
+
+#[async_trait]
+impl TimeService for SimulatedTimeService {
+    fn run_after(&self, _timeout: Duration, _task: Box<dyn ScheduledTask>) -> AbortHandle {
+        let (abort_handle, _) = AbortHandle::new_pair();
+        abort_handle
+    }
+
+    fn get_current_timestamp(&self) -> Duration {
+        Duration::from_micros(self.now_usecs.load(Ordering::SeqCst))
+    }
+
+    async fn sleep(&self, t: Duration) {
+        self.now_usecs
+            .fetch_add(t.as_micros() as u64, Ordering::SeqCst);
+    }
+}
+
+/// A payload client that always returns empty payloads (to keep blocks deterministic)
+struct EmptyPayloadClient {}
+
+#[async_trait]
+impl PayloadClient for EmptyPayloadClient {
+    async fn pull_payload(
+        &self,
+        _max_poll_time: Duration,
+        _max_items: u64,
+        _max_bytes: u64,
+        _max_inline_items: u64,
+        _max_inline_bytes: u64,
+        _validator_txn_filter: TransactionFilter,
+        _user_txn_filter: PayloadFilter,
+        _wait_callback: BoxFuture<'static, ()>,
+        _pending_ordering: bool,
+        _pending_uncommitted_blocks: usize,
+        _recent_max_fill_fraction: f32,
+    ) -> anyhow::Result<(Vec<ValidatorTransaction>, Payload), QuorumStoreError> {
+        Ok((vec![], Payload::empty(false, true)))
+    }
+}
+
+/// A validator running in the simulation. Instead of being connected to a network,
+/// the outbound requests (and self messages) of the node are drained by the simulation.
+pub struct SimulatedNode {
+    pub signer: ValidatorSigner,
+    pub round_manager: RoundManager,
+    pub block_store: Arc<BlockStore>,
+    pub execution_client: Arc<MockExecutionClient>,
+    pub outbound_requests: aptos_channel::Receiver<(PeerId, ProtocolId), PeerManagerRequest>,
+    pub self_messages: aptos_channels::UnboundedReceiver<Event<ConsensusMsg>>,
+    pub ordered_blocks: mpsc::UnboundedReceiver<OrderedBlocks>,
+    _safety_rules_manager: SafetyRulesManager,
+    _state_sync_receiver: mpsc::UnboundedReceiver<Vec<SignedTransaction>>,
+}
+
+impl SimulatedNode {
+    pub async fn new(
+        signer: ValidatorSigner,
+        validators: ValidatorVerifier,
+        proposers: Vec<Author>,
+        peers_and_metadata: Arc<PeersAndMetadata>,
+        time_service: Arc<SimulatedTimeService>,
+    ) -> Self {
+        let author = signer.author();
+        let onchain_consensus_config = OnChainConsensusConfig::V1(ConsensusConfigV1::default());
+        let (initial_data, storage) = MockStorage::start_for_testing((&validators).into());
+        let waypoint =
+            Waypoint::new_epoch_boundary(&LedgerInfo::mock_genesis(Some((&validators).into())))
+                .unwrap();
+
+        // Create the safety rules
+        let safety_storage = PersistentSafetyStorage::initialize(
+            Storage::from(aptos_secure_storage::InMemoryStorage::new()),
+            author,
+            signer.private_key().clone(),
+            waypoint,
+            true,
+        );
+        let safety_rules_manager = SafetyRulesManager::new_local(safety_storage);
+        let mut safety_rules =
+            MetricsSafetyRules::new(safety_rules_manager.client(), storage.clone());
+        safety_rules.perform_initialize().unwrap();
+
+        let (network, outbound_requests, self_messages) =
+            new_network_sender(author, validators.clone(), peers_and_metadata);
+        let network = Arc::new(network);
+
+        // Create the block store (ordered blocks are committed by the simulation)
+        let (ordered_blocks_tx, ordered_blocks) = mpsc::unbounded::<OrderedBlocks>();
+        let (state_sync_client, _state_sync_receiver) = mpsc::unbounded();
+        let execution_client = Arc::new(MockExecutionClient::new(
+            state_sync_client,
+            ordered_blocks_tx,
+            storage.clone(),
+        ));
+        let last_vote_sent = initial_data.last_vote();
+        let block_store = Arc::new(BlockStore::new(
+            storage.clone(),
+            initial_data,
+            execution_client.clone(),
+            10, // max pruned blocks in mem
+            time_service.clone(),
+            10,
+            Arc::from(PayloadManager::DirectMempool),
+        ));
+
+        let proposal_generator = ProposalGenerator::new(
+            author,
+            block_store.clone(),
+            Arc::new(EmptyPayloadClient {}),
+            time_service.clone(),
+            Duration::ZERO,
+            10,
+            1000,
+            5,
+            500,
+            10,
+            PipelineBackpressureConfig::new_no_backoff(),
+            ChainHealthBackoffConfig::new_no_backoff(),
+            false,
+            onchain_consensus_config.effective_validator_txn_config(),
+            true,
+        );
+
+        // Round timeouts are injected by the simulation, so the timeout channel is unused
+        let (round_timeout_sender, _) = aptos_channels::new_test(1_024);
+        let (delayed_qc_tx, _) = unbounded();
+        let round_state = RoundState::new(
+            Box::new(ExponentialTimeInterval::fixed(Duration::from_secs(3_600))),
+            time_service,
+            round_timeout_sender,
+            delayed_qc_tx,
+            QcAggregatorType::NoDelay,
+        );
+
+        let (round_manager_tx, _) = aptos_channel::new(QueueStyle::LIFO, 1, None);
+        let epoch_state = Arc::new(EpochState {
+            epoch: 1,
+            verifier: validators,
+        });
+        let mut round_manager = RoundManager::new(
+            epoch_state,
+            block_store.clone(),
+            round_state,
+            Arc::new(RotatingProposer::new(proposers, 1)),
+            proposal_generator,
+            Arc::new(Mutex::new(safety_rules)),
+            network,
+            storage,
+            onchain_consensus_config,
+            round_manager_tx,
+            ConsensusConfig::default(),
+            OnChainRandomnessConfig::default_if_missing(),
+            OnChainJWKConsensusConfig::default_if_missing(),
+            None,
+        );
+        round_manager.init(last_vote_sent).await;
+
+        Self {
+            signer,
+            round_manager,
+            block_store,
+            execution_client,
+            outbound_requests,
+            self_messages,
+            ordered_blocks,
+            _safety_rules_manager: safety_rules_manager,
+            _state_sync_receiver,
+        }
+    }
+}
+
+/// A validator running DAG consensus in the simulation. The DAG runs in its own tasks,
+/// so the simulation hands the incoming RPCs to its DAG handler, and drains the
+/// outbound requests (and self messages) of the node once its tasks have settled.
+pub struct SimulatedDagNode {
+    pub signer: ValidatorSigner,
+    pub dag_rpc_tx: aptos_channel::Sender<Author, IncomingDAGRequest>,
+    pub outbound_requests: aptos_channel::Receiver<(PeerId, ProtocolId), PeerManagerRequest>,
+    pub self_messages: aptos_channels::UnboundedReceiver<Event<ConsensusMsg>>,
+    pub ordered_blocks: mpsc::UnboundedReceiver<OrderedBlocks>,
+    // The DAG tasks are aborted when the node is dropped
+    tasks: Vec<task::AbortHandle>,
+}
+
+impl SimulatedDagNode {
+    pub fn new(
+        signer: ValidatorSigner,
+        all_signers: &[ValidatorSigner],
+        validators: ValidatorVerifier,
+        peers_and_metadata: Arc<PeersAndMetadata>,
+        time_service: aptos_time_service::TimeService,
+    ) -> Self {
+        let author = signer.author();
+        let epoch_state = Arc::new(EpochState {
+            epoch: 1,
+            verifier: validators.clone(),
+        });
+        let (_, storage) = MockStorage::start_for_testing((&validators).into());
+        let ledger_info = generate_ledger_info_with_sig(all_signers, storage.get_ledger_info());
+        let dag_storage = Arc::new(MockDAGStorage::new_with_ledger_info(
+            ledger_info,
+            epoch_state.clone(),
+        ));
+
+        let (network, outbound_requests, self_messages) =
+            new_network_sender(author, validators, peers_and_metadata);
+        let network = Arc::new(network);
+
+        // Enter the next round as soon as the strong links form a quorum. Otherwise, the
+        // round state waits (in real time) for the links of the remaining validators.
+        let config = DagConsensusConfig {
+            round_state_config: DagRoundStateConfig {
+                adaptive_responsive_minimum_wait_time_ms: 0,
+            },
+            ..DagConsensusConfig::default()
+        };
+        let (handler_task, fetcher_task, dag_rpc_tx, ordered_blocks) = bootstrap_dag_for_test(
+            author,
+            config,
+            signer.clone(),
+            epoch_state,
+            dag_storage,
+            network.clone(),
+            network.clone(),
+            network,
+            time_service,
+            Arc::new(PayloadManager::DirectMempool),
+            Arc::new(EmptyPayloadClient {}),
+            Arc::new(DummyExecutionClient),
+        );
+
+        Self {
+            signer,
+            dag_rpc_tx,
+            outbound_requests,
+            self_messages,
+            ordered_blocks,
+            tasks: vec![handler_task.abort_handle(), fetcher_task.abort_handle()],
+        }
+    }
+}
+
+impl Drop for SimulatedDagNode {
+    fn drop(&mut self) {
+        for task in &self.tasks {
+            task.abort();
+        }
+    }
+}
+
+/// Returns the peers and metadata of the given validators. All validators are marked
+/// as connected, so that messages are sent to every peer.
+pub fn new_peers_and_metadata(signers: &[ValidatorSigner]) -> Arc<PeersAndMetadata> {
+    let peers_and_metadata = PeersAndMetadata::new(&[NetworkId::Validator]);
+    for signer in signers {
+        let mut connection_metadata = ConnectionMetadata::mock(signer.author());
+        connection_metadata.application_protocols = ProtocolIdSet::from_iter([
+            ProtocolId::ConsensusDirectSendBcs,
+            ProtocolId::ConsensusRpcBcs,
+        ]);
+        peers_and_metadata
+            .insert_connection_metadata(
+                PeerNetworkId::new(NetworkId::Validator, signer.author()),
+                connection_metadata,
+            )
+            .unwrap();
+    }
+    peers_and_metadata
+}
+
+/// Creates a network sender for the given validator. Instead of being sent to the
+/// network, the outbound requests (and self messages) are returned to the simulation.
+fn new_network_sender(
+    author: Author,
+    validators: ValidatorVerifier,
+    peers_and_metadata: Arc<PeersAndMetadata>,
+) -> (
+    NetworkSender,
+    aptos_channel::Receiver<(PeerId, ProtocolId), PeerManagerRequest>,
+    aptos_channels::UnboundedReceiver<Event<ConsensusMsg>>,
+) {
+    let (network_reqs_tx, outbound_requests) = aptos_channel::new(QueueStyle::FIFO, 1_024, None);
+    let (connection_reqs_tx, _) = aptos_channel::new(QueueStyle::FIFO, 8, None);
+    let network_sender = network::NetworkSender::new(
+        PeerManagerRequestSender::new(network_reqs_tx),
+        ConnectionRequestSender::new(connection_reqs_tx),
+    );
+    let network_client = NetworkClient::new(
+        DIRECT_SEND.into(),
+        RPC.into(),
+        hashmap! {NetworkId::Validator => network_sender},
+        peers_and_metadata,
+    );
+    let (self_sender, self_messages) = aptos_channels::new_unbounded_test();
+    let network = NetworkSender::new(
+        author,
+        ConsensusNetworkClient::new(network_client),
+        self_sender,
+        validators,
+    );
+    (network, outbound_requests, self_messages)
+}
@@ -0,0 +1,499 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    block_storage::BlockStore,
+    network::IncomingBlockRetrievalRequest,
+    network_interface::ConsensusMsg,
+    round_manager::{UnverifiedEvent, VerifiedEvent},
+    simulator::{
+        byzantine::ByzantineBehavior,
+        invariants::{InvariantChecker, InvariantViolation},
+        scheduler::{NetworkFaults, ScheduledMessage, SimulationScheduler, Tick},
+        simulated_node::{new_peers_and_metadata, SimulatedNode, SimulatedTimeService},
+    },
+};
+use aptos_channels::aptos_channel;
+use aptos_consensus_types::{
+    common::{Author, Round},
+    proof_of_store::ProofCache,
+};
+use aptos_logger::prelude::*;
+use aptos_network::{
+    peer_manager::PeerManagerRequest,
+    protocols::{
+        network::{Event, SerializedRequest},
+        rpc::OutboundRpcRequest,
+    },
+    ProtocolId,
+};
+use aptos_types::{
+    validator_verifier::{random_validator_verifier, ValidatorVerifier},
+    PeerId,
+};
+use futures::{FutureExt, StreamExt};
+use mini_moka::sync::Cache;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt::{Display, Formatter},
+    future::Future,
+    sync::Arc,
+    time::Duration,
+};
+
+/// The environment variable used to replay a single simulation seed
+pub const SIMULATOR_SEED_ENV_VAR: &str = "CONSENSUS_SIMULATOR_SEED";
+
+/// Returns the seeds to simulate: the seed given by the environment (if any),
+/// otherwise the seeds `[0, num_seeds)`.
+pub fn seeds_to_run(num_seeds: u64) -> Vec<u64> {
+    match std::env::var(SIMULATOR_SEED_ENV_VAR) {
+        Ok(seed) => vec![seed
+            .parse()
+            .unwrap_or_else(|_| panic!("Invalid {}: {}", SIMULATOR_SEED_ENV_VAR, seed))],
+        Err(_) => (0..num_seeds).collect(),
+    }
+}
+
+/// The configuration of a single simulation run
+#[derive(Clone, Debug)]
+pub struct SimulationConfig {
+    pub num_nodes: usize,
+    /// The Byzantine nodes (by index) and their behaviours. All other nodes are honest.
+    pub byzantine_nodes: BTreeMap<usize, ByzantineBehavior>,
+    pub network_faults: NetworkFaults,
+    /// The number of ticks to run the simulation for
+    pub max_ticks: Tick,
+    /// The number of ticks after which a node times out of its current round
+    pub round_timeout: Tick,
+    /// The maximum number of ticks (after the global stabilization time) between
+    /// two consecutive rounds ordered by the honest nodes.
+    pub liveness_window: Tick,
+    /// The minimum round that must be ordered by the end of the simulation
+    pub min_ordered_rounds: Round,
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            num_nodes: 4,
+            byzantine_nodes: BTreeMap::new(),
+            network_faults: NetworkFaults::default(),
+            max_ticks: 2_000,
+            round_timeout: 500,
+            liveness_window: 1_500,
+            min_ordered_rounds: 10,
+        }
+    }
+}
+
+/// A failed simulation run. The seed can be used to replay the exact same run.
+#[derive(Debug)]
+pub struct SimulationError {
+    pub seed: u64,
+    pub tick: Tick,
+    pub step: u64,
+    pub violation: InvariantViolation,
+}
+
+impl Display for SimulationError {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "Invariant violated at tick {} (step {}): {}. Replay with {}={}",
+            self.tick, self.step, self.violation, SIMULATOR_SEED_ENV_VAR, self.seed
+        )
+    }
+}
+
+impl std::error::Error for SimulationError {}
+
+/// The summary of a successful simulation run
+#[derive(Debug)]
+pub struct SimulationReport {
+    pub steps: u64,
+    pub highest_ordered_round: Round,
+}
+
+/// A deterministic simulation of a set of validators running the round manager.
+/// Messages are intercepted at the network layer and delivered (one at a time)
+/// by a seeded scheduler, and all time (including round timeouts) is virtual.
+pub struct Simulation {
+    seed: u64,
+    config: SimulationConfig,
+    scheduler: SimulationScheduler,
+    time_service: Arc<SimulatedTimeService>,
+    nodes: Vec<SimulatedNode>,
+    // The block stores are kept separately to serve block retrievals while a node is busy
+    block_stores: Vec<Arc<BlockStore>>,
+    author_indices: HashMap<Author, usize>,
+    validator_verifier: ValidatorVerifier,
+    proof_cache: ProofCache,
+    // The current round of each node, and the tick at which it times out
+    round_timers: Vec<(Round, Tick)>,
+    invariant_checker: InvariantChecker,
+    steps: u64,
+}
+
+impl Simulation {
+    pub async fn new(seed: u64, config: SimulationConfig) -> Self {
+        let (signers, validator_verifier) =
+            random_validator_verifier(config.num_nodes, None, false);
+        let author_indices: HashMap<_, _> = signers
+            .iter()
+            .enumerate()
+            .map(|(index, signer)| (signer.author(), index))
+            .collect();
+
+        let peers_and_metadata = new_peers_and_metadata(&signers);
+        let time_service = Arc::new(SimulatedTimeService::new());
+        let proposers: Vec<_> = signers.iter().map(|signer| signer.author()).collect();
+        let mut nodes = vec![];
+        for signer in signers {
+            nodes.push(
+                SimulatedNode::new(
+                    signer,
+                    validator_verifier.clone(),
+                    proposers.clone(),
+                    peers_and_metadata.clone(),
+                    time_service.clone(),
+                )
+                .await,
+            );
+        }
+
+        let honest_nodes: HashSet<_> = (0..config.num_nodes)
+            .filter(|index| !config.byzantine_nodes.contains_key(index))
+            .collect();
+        let mut simulation = Self {
+            seed,
+            scheduler: SimulationScheduler::new(seed, config.network_faults.clone()),
+            time_service,
+            block_stores: nodes.iter().map(|node| node.block_store.clone()).collect(),
+            round_timers: nodes
+                .iter()
+                .map(|node| {
+                    (
+                        node.round_manager.round_state().current_round(),
+                        config.round_timeout,
+                    )
+                })
+                .collect(),
+            nodes,
+            author_indices,
+            validator_verifier,
+            proof_cache: Cache::builder()
+                .max_capacity(1_000)
+                .initial_capacity(1_000)
+                .time_to_live(Duration::from_secs(20))
+                .build(),
+            invariant_checker: InvariantChecker::new(honest_nodes, config.liveness_window),
+            steps: 0,
+            config,
+        };
+
+        // Schedule the messages sent during initialization (e.g., the first proposal)
+        for index in 0..simulation.nodes.len() {
+            simulation.schedule_outbound_messages(index, vec![]);
+        }
+        simulation
+    }
+
+    /// Runs the simulation until the maximum tick is reached (or an invariant is violated)
+    pub async fn run(mut self) -> Result<SimulationReport, SimulationError> {
+        while self.scheduler.now() < self.config.max_ticks {
+            if let Some(message) = self.scheduler.pop_due_message() {
+                let node_index = message.dst;
+                let buffered_requests = self.deliver_message(message).await;
+                self.complete_step(node_index, buffered_requests).await?;
+                continue;
+            }
+
+            // Nothing is due, so advance the time to the next delivery or round timeout
+            let next_timeout = self
+                .round_timers
+                .iter()
+                .map(|(_, timeout_tick)| *timeout_tick)
+                .min()
+                .unwrap_or(Tick::MAX);
+            let next_tick = self
+                .scheduler
+                .next_delivery_tick()
+                .unwrap_or(Tick::MAX)
+                .min(next_timeout)
+                .min(self.config.max_ticks);
+            self.scheduler.advance_to(next_tick);
+            self.time_service
+                .advance_to(Duration::from_millis(self.scheduler.now()));
+
+            for node_index in 0..self.nodes.len() {
+                let (round, timeout_tick) = self.round_timers[node_index];
+                if timeout_tick > self.scheduler.now() {
+                    continue;
+                }
+                // The round manager returns an error even if the timeout was processed
+                if let Err(error) = self.nodes[node_index]
+                    .round_manager
+                    .process_local_timeout(round)
+                    .await
+                {
+                    debug!("[Simulator] Node {} timed out: {:?}", node_index, error);
+                }
+                self.round_timers[node_index] =
+                    (round, self.scheduler.now() + self.config.round_timeout);
+                self.complete_step(node_index, vec![]).await?;
+            }
+        }
+
+        let highest_ordered_round = self.invariant_checker.highest_ordered_round();
+        if highest_ordered_round < self.config.min_ordered_rounds {
+            return Err(self.error(InvariantViolation::InsufficientProgress {
+                highest_ordered_round,
+                expected_round: self.config.min_ordered_rounds,
+            }));
+        }
+        Ok(SimulationReport {
+            steps: self.steps,
+            highest_ordered_round,
+        })
+    }
+
+    /// Verifies and processes the given message on the destination node. Returns the
+    /// network requests sent by the node that couldn't be handled during processing.
+    async fn deliver_message(&mut self, message: ScheduledMessage) -> Vec<PeerManagerRequest> {
+        let ScheduledMessage { src, dst, message } = message;
+        let unverified_event = match message {
+            ConsensusMsg::ProposalMsg(_) | ConsensusMsg::VoteMsg(_) | ConsensusMsg::SyncInfo(_) => {
+                UnverifiedEvent::from(message)
+            },
+            message => {
+                debug!(
+                    "[Simulator] Ignoring unsupported message: {}",
+                    message.name()
+                );
+                return vec![];
+            },
+        };
+
+        let src_author = self.nodes[src].signer.author();
+        let verified_event = match unverified_event.verify(
+            src_author,
+            &self.validator_verifier,
+            &self.proof_cache,
+            false,
+            src == dst,
+            0,
+            0,
+        ) {
+            Ok(verified_event) => verified_event,
+            Err(error) => {
+                debug!(
+                    "[Simulator] Node {} rejected a message from node {}: {:?}",
+                    dst, src, error
+                );
+                return vec![];
+            },
+        };
+
+        let SimulatedNode {
+            round_manager,
+            outbound_requests,
+            ..
+        } = &mut self.nodes[dst];
+        let mut rpc_handler = BlockRetrievalHandler {
+            node_index: dst,
+            scheduler: &self.scheduler,
+            block_stores: &self.block_stores,
+            author_indices: &self.author_indices,
+            outbound_requests,
+            buffered_requests: vec![],
+        };
+        let result = match verified_event {
+            VerifiedEvent::ProposalMsg(proposal_msg) => {
+                rpc_handler
+                    .run(round_manager.process_proposal_msg(*proposal_msg))
+                    .await
+            },
+            VerifiedEvent::VoteMsg(vote_msg) => {
+                rpc_handler
+                    .run(round_manager.process_vote_msg(*vote_msg))
+                    .await
+            },
+            VerifiedEvent::UnverifiedSyncInfo(sync_info) => {
+                rpc_handler
+                    .run(round_manager.process_sync_info_msg(*sync_info, src_author))
+                    .await
+            },
+            _ => unreachable!("Unexpected verified event!"),
+        };
+        if let Err(error) = result {
+            debug!(
+                "[Simulator] Node {} failed to process a message from node {}: {:?}",
+                dst, src, error
+            );
+        }
+        rpc_handler.buffered_requests
+    }
+
+    /// Schedules the messages sent by the given node, records the blocks it ordered,
+    /// updates its round timer and checks all invariants.
+    async fn complete_step(
+        &mut self,
+        node_index: usize,
+        buffered_requests: Vec<PeerManagerRequest>,
+    ) -> Result<(), SimulationError> {
+        self.steps += 1;
+        self.schedule_outbound_messages(node_index, buffered_requests);
+
+        // Record and commit the ordered blocks
+        while let Ok(Some(ordered_blocks)) = self.nodes[node_index].ordered_blocks.try_next() {
+            if let Err(violation) = self.invariant_checker.record_ordered_blocks(
+                node_index,
+                &ordered_blocks.ordered_blocks,
+                self.scheduler.now(),
+            ) {
+                return Err(self.error(violation));
+            }
+            if let Err(error) = self.nodes[node_index]
+                .execution_client
+                .commit_to_storage(ordered_blocks)
+                .await
+            {
+                debug!(
+                    "[Simulator] Node {} failed to commit blocks: {:?}",
+                    node_index, error
+                );
+            }
+        }
+
+        // Restart the round timer if the node entered a new round
+        let current_round = self.nodes[node_index]
+            .round_manager
+            .round_state()
+            .current_round();
+        if current_round != self.round_timers[node_index].0 {
+            self.round_timers[node_index] = (
+                current_round,
+                self.scheduler.now() + self.config.round_timeout,
+            );
+        }
+
+        self.invariant_checker
+            .check_liveness(
+                self.scheduler.now(),
+                self.scheduler.global_stabilization_tick(),
+            )
+            .map_err(|violation| self.error(violation))
+    }
+
+    /// Drains the messages sent by the given node (to itself and to others), and
+    /// schedules them for delivery (after corrupting them, if the node is Byzantine).
+    fn schedule_outbound_messages(
+        &mut self,
+        node_index: usize,
+        mut buffered_requests: Vec<PeerManagerRequest>,
+    ) {
+        let node = &mut self.nodes[node_index];
+        while let Some(Some(event)) = node.self_messages.next().now_or_never() {
+            if let Event::Message(_, message) = event {
+                self.scheduler.send(node_index, node_index, message);
+            }
+        }
+        while let Some(Some(request)) = node.outbound_requests.next().now_or_never() {
+            buffered_requests.push(request);
+        }
+
+        for request in buffered_requests {
+            let (peer_id, message) = match request {
+                PeerManagerRequest::SendDirectSend(peer_id, message) => (peer_id, message),
+                // Block retrievals are only served while the requester is processing a message
+                PeerManagerRequest::SendRpc(..) => continue,
+            };
+            let (Some(&dst), Ok(message)) = (
+                self.author_indices.get(&peer_id),
+                message.to_message::<ConsensusMsg>(),
+            ) else {
+                continue;
+            };
+            let message = match self.config.byzantine_nodes.get(&node_index) {
+                Some(behavior) => match behavior.corrupt_message(&node.signer, message, dst) {
+                    Some(message) => message,
+                    None => continue,
+                },
+                None => message,
+            };
+            self.scheduler.send(node_index, dst, message);
+        }
+    }
+
+    fn error(&self, violation: InvariantViolation) -> SimulationError {
+        SimulationError {
+            seed: self.seed,
+            tick: self.scheduler.now(),
+            step: self.steps,
+            violation,
+        }
+    }
+}
+
+/// Serves the block retrievals of a node while it processes a message. Block
+/// retrievals are answered immediately by the target's block store (unless the
+/// nodes are partitioned), and all other requests are buffered for scheduling.
+struct BlockRetrievalHandler<'a> {
+    node_index: usize,
+    scheduler: &'a SimulationScheduler,
+    block_stores: &'a [Arc<BlockStore>],
+    author_indices: &'a HashMap<Author, usize>,
+    outbound_requests: &'a mut aptos_channel::Receiver<(PeerId, ProtocolId), PeerManagerRequest>,
+    buffered_requests: Vec<PeerManagerRequest>,
+}
+
+impl BlockRetrievalHandler<'_> {
+    async fn run(
+        &mut self,
+        process: impl Future<Output = anyhow::Result<()>>,
+    ) -> anyhow::Result<()> {
+        futures::pin_mut!(process);
+        loop {
+            let request = tokio::select! {
+                biased;
+                result = &mut process => return result,
+                Some(request) = self.outbound_requests.next() => request,
+            };
+            match request {
+                PeerManagerRequest::SendRpc(peer_id, rpc_request) => {
+                    self.serve_block_retrieval(peer_id, rpc_request).await
+                },
+                request => self.buffered_requests.push(request),
+            }
+        }
+    }
+
+    async fn serve_block_retrieval(&self, peer_id: PeerId, rpc_request: OutboundRpcRequest) {
+        // Dropping the response channel fails the request immediately
+        let Some(&target_index) = self.author_indices.get(&peer_id) else {
+            return;
+        };
+        if self.scheduler.is_partitioned(self.node_index, target_index) {
+            return;
+        }
+        let request = match rpc_request.to_message::<ConsensusMsg>() {
+            Ok(ConsensusMsg::BlockRetrievalRequest(request)) => request,
+            _ => return,
+        };
+
+        if let Err(error) = self.block_stores[target_index]
+            .process_block_retrieval(IncomingBlockRetrievalRequest {
+                req: *request,
+                protocol: rpc_request.protocol_id,
+                response_sender: rpc_request.res_tx,
+            })
+            .await
+        {
+            debug!(
+                "[Simulator] Node {} failed to serve a block retrieval: {:?}",
+                target_index, error
+            );
+        }
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    simulator::{
+        byzantine::ByzantineBehavior,
+        dag_simulation::DagSimulation,
+        scheduler::{NetworkFaults, Partition},
+        simulation::{seeds_to_run, Simulation, SimulationConfig},
+    },
+    test_utils::{consensus_runtime, timed_block_on},
+};
+use aptos_logger::info;
+use maplit::btreemap;
+
+const NUM_SEEDS: u64 = 3;
+
+/// Runs the simulation with the given config for every seed, and panics with
+/// the replayable seed if any invariant is violated.
+fn run_simulations(config: SimulationConfig) {
+    let runtime = consensus_runtime();
+    for seed in seeds_to_run(NUM_SEEDS) {
+        let simulation_config = config.clone();
+        let report = timed_block_on(&runtime, async move {
+            Simulation::new(seed, simulation_config).await.run().await
+        })
+        .unwrap_or_else(|error| panic!("{}", error));
+        info!(
+            "[Simulator] Seed {} ordered {} rounds in {} steps",
+            seed, report.highest_ordered_round, report.steps
+        );
+    }
+}
+
+/// Runs the DAG simulation with the given config for every seed, and panics with
+/// the replayable seed if any invariant is violated. The DAG runs in its own tasks,
+/// so the simulation runs on a current-thread runtime to keep them deterministic.
+fn run_dag_simulations(config: SimulationConfig) {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    for seed in seeds_to_run(NUM_SEEDS) {
+        let simulation_config = config.clone();
+        let report = timed_block_on(&runtime, async move {
+            DagSimulation::new(seed, simulation_config).run().await
+        })
+        .unwrap_or_else(|error| panic!("{}", error));
+        info!(
+            "[Simulator] Seed {} ordered {} DAG rounds in {} steps",
+            seed, report.highest_ordered_round, report.steps
+        );
+    }
+}
+
+#[test]
+/// All nodes are honest and the network is synchronous
+fn honest_nodes_test() {
+    run_simulations(SimulationConfig::default());
+}
+
+#[test]
+/// Messages are delayed (and reordered) randomly, and some are dropped before the
+/// global stabilization time.
+fn random_delays_and_drops_test() {
+    run_simulations(SimulationConfig {
+        network_faults: NetworkFaults {
+            min_delay: 1,
+            max_delay: 50,
+            drop_probability: 0.1,
+            drop_until: 1_000,
+            partitions: vec![],
+        },
+        max_ticks: 3_000,
+        ..SimulationConfig::default()
+    });
+}
+
+#[test]
+/// The network is split into two halves (without a quorum) and then heals
+fn healed_partition_test() {
+    run_simulations(SimulationConfig {
+        network_faults: NetworkFaults {
+            partitions: vec![Partition::new(0, 1_000, vec![vec![0, 1], vec![2, 3]])],
+            ..NetworkFaults::default()
+        },
+        max_ticks: 3_000,
+        ..SimulationConfig::default()
+    });
+}
+
+#[test]
+/// A Byzantine proposer sends conflicting proposals to different validators
+fn equivocating_proposer_test() {
+    run_simulations(SimulationConfig {
+        byzantine_nodes: btreemap! {3 => ByzantineBehavior::EquivocatingProposals},
+        ..SimulationConfig::default()
+    });
+}
+
+#[test]
+/// A Byzantine validator never sends its votes
+fn vote_withholding_test() {
+    run_simulations(SimulationConfig {
+        byzantine_nodes: btreemap! {3 => ByzantineBehavior::VoteWithholding},
+        ..SimulationConfig::default()
+    });
+}
+
+#[test]
+/// A Byzantine proposer sends proposals with unsigned quorum certificates
+fn invalid_quorum_certs_test() {
+    run_simulations(SimulationConfig {
+        byzantine_nodes: btreemap! {3 => ByzantineBehavior::InvalidQuorumCerts},
+        ..SimulationConfig::default()
+    });
+}
+
+#[test]
+/// All DAG nodes are honest and the network is synchronous
+fn dag_honest_nodes_test() {
+    run_dag_simulations(SimulationConfig::default());
+}
+
+#[test]
+/// The DAG RPCs (and their responses) are delayed and reordered randomly, and some
+/// are dropped before the global stabilization time.
+fn dag_random_delays_and_drops_test() {
+    run_dag_simulations(SimulationConfig {
+        network_faults: NetworkFaults {
+            min_delay: 1,
+            max_delay: 50,
+            drop_probability: 0.1,
+            drop_until: 1_000,
+            partitions: vec![],
+        },
+        max_ticks: 3_000,
+        ..SimulationConfig::default()
+    });
+}
+
+#[test]
+/// The DAG nodes are split into two halves (without a quorum) and then heal
+fn dag_healed_partition_test() {
+    run_dag_simulations(SimulationConfig {
+        network_faults: NetworkFaults {
+            partitions: vec![Partition::new(0, 1_000, vec![vec![0, 1], vec![2, 3]])],
+            ..NetworkFaults::default()
+        },
+        max_ticks: 3_000,
+        ..SimulationConfig::default()
+    });
+}
+
+#[test]
+/// A Byzantine DAG node sends conflicting nodes for the same round to different validators
+fn dag_equivocating_nodes_test() {
+    run_dag_simulations(SimulationConfig {
+        byzantine_nodes: btreemap! {3 => ByzantineBehavior::EquivocatingProposals},
+        ..SimulationConfig::default()
+    });
+}
+
+#[test]
+/// A Byzantine DAG node never votes for the nodes of other validators
+fn dag_vote_withholding_test() {
+    run_dag_simulations(SimulationConfig {
+        byzantine_nodes: btreemap! {3 => ByzantineBehavior::VoteWithholding},
+        ..SimulationConfig::default()
+    });
+}
+
+#[test]
+/// A Byzantine DAG node broadcasts its certified nodes with unsigned certificates
+fn dag_invalid_certificates_test() {
+    run_dag_simulations(SimulationConfig {
+        byzantine_nodes: btreemap! {3 => ByzantineBehavior::InvalidQuorumCerts},
+        ..SimulationConfig::default()
+    });
+}
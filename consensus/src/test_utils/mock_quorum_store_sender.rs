@@ -4,7 +4,9 @@
 use crate::{
     network::QuorumStoreSender,
     network_interface::ConsensusMsg,
-    quorum_store::types::{Batch, BatchRequest, BatchResponse},
+    quorum_store::types::{
+        Batch, BatchChunk, BatchChunkMsg, BatchChunkRequest, BatchMsg, BatchRequest, BatchResponse,
+    },
 };
 use aptos_consensus_types::{
     common::Author,
@@ -42,6 +44,15 @@ impl QuorumStoreSender for MockQuorumStoreSender {
         unimplemented!();
     }
 
+    async fn request_batch_chunk(
+        &self,
+        _request: BatchChunkRequest,
+        _recipient: Author,
+        _timeout: Duration,
+    ) -> anyhow::Result<BatchResponse> {
+        unimplemented!();
+    }
+
     async fn send_batch(&self, batch: Batch, recipients: Vec<Author>) {
         self.tx
             .send((ConsensusMsg::BatchResponse(Box::new(batch)), recipients))
@@ -49,6 +60,26 @@ impl QuorumStoreSender for MockQuorumStoreSender {
             .expect("could not send");
     }
 
+    async fn send_batch_msg(&self, batches: Vec<Batch>, recipients: Vec<Author>) {
+        self.tx
+            .send((
+                ConsensusMsg::BatchMsg(Box::new(BatchMsg::new(batches))),
+                recipients,
+            ))
+            .await
+            .expect("could not send");
+    }
+
+    async fn send_batch_chunk_msg(&self, chunks: Vec<BatchChunk>, recipients: Vec<Author>) {
+        self.tx
+            .send((
+                ConsensusMsg::BatchChunkMsg(Box::new(BatchChunkMsg::new(chunks))),
+                recipients,
+            ))
+            .await
+            .expect("could not send");
+    }
+
     async fn send_signed_batch_info_msg(
         &self,
         signed_batch_infos: Vec<SignedBatchInfo>,
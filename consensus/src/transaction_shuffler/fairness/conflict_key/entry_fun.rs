@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::transaction_shuffler::fairness::conflict_key::ConflictKey;
-use aptos_types::transaction::{SignedTransaction, TransactionPayload};
+use aptos_types::transaction::{
+    OrderlessPayload, SignedTransaction, TransactionExecutable, TransactionPayload,
+};
 use move_core_types::{identifier::Identifier, language_storage::ModuleId};
 
 #[derive(Eq, Hash, PartialEq)]
@@ -17,7 +19,11 @@ pub enum EntryFunKey {
 impl ConflictKey<SignedTransaction> for EntryFunKey {
     fn extract_from(txn: &SignedTransaction) -> Self {
         match txn.payload() {
-            TransactionPayload::EntryFunction(entry_fun) => {
+            TransactionPayload::EntryFunction(entry_fun)
+            | TransactionPayload::Orderless(OrderlessPayload {
+                executable: TransactionExecutable::EntryFunction(entry_fun),
+                ..
+            }) => {
                 let module_id = entry_fun.module();
                 if module_id.address().is_special() {
                     // Exempt framework modules
@@ -32,7 +38,8 @@ impl ConflictKey<SignedTransaction> for EntryFunKey {
             },
             TransactionPayload::Multisig(_)
             | TransactionPayload::Script(_)
-            | TransactionPayload::ModuleBundle(_) => Self::Exempt,
+            | TransactionPayload::ModuleBundle(_)
+            | TransactionPayload::Orderless(_) => Self::Exempt,
         }
     }
 
@@ -2,7 +2,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::transaction_shuffler::fairness::conflict_key::ConflictKey;
-use aptos_types::transaction::{SignedTransaction, TransactionPayload};
+use aptos_types::transaction::{
+    OrderlessPayload, SignedTransaction, TransactionExecutable, TransactionPayload,
+};
 use move_core_types::language_storage::ModuleId;
 
 #[derive(Eq, Hash, PartialEq)]
@@ -15,7 +17,11 @@ pub enum EntryFunModuleKey {
 impl ConflictKey<SignedTransaction> for EntryFunModuleKey {
     fn extract_from(txn: &SignedTransaction) -> Self {
         match txn.payload() {
-            TransactionPayload::EntryFunction(entry_fun) => {
+            TransactionPayload::EntryFunction(entry_fun)
+            | TransactionPayload::Orderless(OrderlessPayload {
+                executable: TransactionExecutable::EntryFunction(entry_fun),
+                ..
+            }) => {
                 let module_id = entry_fun.module();
 
                 if module_id.address().is_special() {
@@ -26,7 +32,8 @@ impl ConflictKey<SignedTransaction> for EntryFunModuleKey {
             },
             TransactionPayload::Multisig(..)
             | TransactionPayload::Script(_)
-            | TransactionPayload::ModuleBundle(_) => Self::AnyScriptOrMultiSig,
+            | TransactionPayload::ModuleBundle(_)
+            | TransactionPayload::Orderless(_) => Self::AnyScriptOrMultiSig,
         }
     }
 
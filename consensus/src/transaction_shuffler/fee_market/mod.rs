@@ -0,0 +1,230 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::transaction_shuffler::{
+    fairness::conflict_key::{
+        entry_fun::EntryFunKey, entry_fun_module::EntryFunModuleKey, txn_sender::TxnSenderKey,
+        ConflictKeyId, ConflictKeyRegistry, MapByKeyId,
+    },
+    TransactionShuffler,
+};
+use aptos_types::transaction::SignedTransaction;
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, VecDeque},
+};
+
+#[cfg(test)]
+mod tests;
+
+type TxnIdx = usize;
+
+/// The number of ready transactions (in priority order) that are examined to find one that
+/// doesn't conflict with the recently selected transactions, which bounds how far a cheaper
+/// transaction can jump ahead of a more expensive one.
+const MAX_CONFLICT_SCAN: usize = 32;
+
+/// A shuffler that orders a block like a fee market, while still spreading conflicts:
+/// 1. Transactions are selected by gas unit price (highest first, ties broken by the original
+///    order), among the next transaction of every sender, so that the relative order of the
+///    transactions of a sender is always preserved.
+/// 2. Among the `MAX_CONFLICT_SCAN` highest priority candidates, the first one whose sender and
+///    module don't appear in the last `sender_conflict_window_size` and
+///    `module_conflict_window_size` selected transactions is preferred.
+/// 3. At most `max_txns_per_hot_resource` transactions calling the same (non-framework) entry
+///    function, the proxy for touching the same hot resource, are selected. The transactions
+///    over the cap, and the remaining transactions of their senders, are moved to the end of the
+///    block in their original order (where they are likely to be cut by the block gas limit).
+///    Zero means no cap.
+///
+/// The output is a permutation of the input, and only depends on the input.
+#[derive(Debug)]
+pub struct FeeMarketShuffler {
+    pub sender_conflict_window_size: usize,
+    pub module_conflict_window_size: usize,
+    pub max_txns_per_hot_resource: usize,
+}
+
+impl FeeMarketShuffler {
+    fn conflict_key_registries(txns: &[SignedTransaction]) -> [ConflictKeyRegistry; 3] {
+        [
+            ConflictKeyRegistry::build::<TxnSenderKey, SignedTransaction>(txns),
+            ConflictKeyRegistry::build::<EntryFunModuleKey, SignedTransaction>(txns),
+            ConflictKeyRegistry::build::<EntryFunKey, SignedTransaction>(txns),
+        ]
+    }
+}
+
+impl TransactionShuffler for FeeMarketShuffler {
+    fn shuffle(&self, txns: Vec<SignedTransaction>) -> Vec<SignedTransaction> {
+        let registries = Self::conflict_key_registries(&txns);
+        let gas_unit_prices: Vec<_> = txns.iter().map(|txn| txn.gas_unit_price()).collect();
+        let (selected, deferred) =
+            FeeMarketShufflerImpl::new(self, &registries, &gas_unit_prices).shuffle();
+
+        let mut txns: Vec<_> = txns.into_iter().map(Some).collect();
+        selected
+            .into_iter()
+            .chain(deferred)
+            .map(|idx| txns[idx].take().expect("Each transaction is selected once"))
+            .collect()
+    }
+}
+
+/// Keeps track of the conflict keys of the last `window_size` selected transactions
+struct ConflictWindow<'a> {
+    registry: &'a ConflictKeyRegistry,
+    window_size: usize,
+    recent_keys: VecDeque<ConflictKeyId>,
+    counts_by_key: MapByKeyId<usize>,
+}
+
+impl<'a> ConflictWindow<'a> {
+    fn new(registry: &'a ConflictKeyRegistry, window_size: usize) -> Self {
+        Self {
+            registry,
+            window_size,
+            recent_keys: VecDeque::with_capacity(window_size + 1),
+            counts_by_key: registry.new_map_by_id(),
+        }
+    }
+
+    fn is_conflict(&self, txn_idx: TxnIdx) -> bool {
+        let key_id = self.registry.key_id_for_txn(txn_idx);
+        !self.registry.is_conflict_exempt(key_id) && *self.counts_by_key.get(key_id) > 0
+    }
+
+    fn add(&mut self, txn_idx: TxnIdx) {
+        if self.window_size == 0 {
+            return;
+        }
+        let key_id = self.registry.key_id_for_txn(txn_idx);
+        *self.counts_by_key.get_mut(key_id) += 1;
+        self.recent_keys.push_back(key_id);
+        if self.recent_keys.len() > self.window_size {
+            let expired = self.recent_keys.pop_front().expect("Window is not empty");
+            *self.counts_by_key.get_mut(expired) -= 1;
+        }
+    }
+}
+
+struct FeeMarketShufflerImpl<'a> {
+    sender_registry: &'a ConflictKeyRegistry,
+    resource_registry: &'a ConflictKeyRegistry,
+    gas_unit_prices: &'a [u64],
+    max_txns_per_hot_resource: usize,
+    conflict_windows: [ConflictWindow<'a>; 2],
+    // The (ordered) transactions of every sender that are not selected (nor deferred) yet
+    pending_by_sender: MapByKeyId<VecDeque<TxnIdx>>,
+    // The next transaction of every sender, by priority
+    ready: BTreeSet<(Reverse<u64>, TxnIdx)>,
+    num_selected_by_resource: MapByKeyId<usize>,
+}
+
+impl<'a> FeeMarketShufflerImpl<'a> {
+    /// The registries are by sender, module and entry function (the hot resource proxy)
+    fn new(
+        config: &FeeMarketShuffler,
+        registries: &'a [ConflictKeyRegistry; 3],
+        gas_unit_prices: &'a [u64],
+    ) -> Self {
+        let [sender_registry, module_registry, resource_registry] = registries;
+        assert!(registries
+            .iter()
+            .all(|registry| registry.num_txns() == gas_unit_prices.len()));
+
+        let mut pending_by_sender: MapByKeyId<VecDeque<TxnIdx>> = sender_registry.new_map_by_id();
+        for txn_idx in 0..gas_unit_prices.len() {
+            pending_by_sender
+                .get_mut(sender_registry.key_id_for_txn(txn_idx))
+                .push_back(txn_idx);
+        }
+
+        let mut shuffler = Self {
+            sender_registry,
+            resource_registry,
+            gas_unit_prices,
+            max_txns_per_hot_resource: config.max_txns_per_hot_resource,
+            conflict_windows: [
+                ConflictWindow::new(sender_registry, config.sender_conflict_window_size),
+                ConflictWindow::new(module_registry, config.module_conflict_window_size),
+            ],
+            pending_by_sender,
+            ready: BTreeSet::new(),
+            num_selected_by_resource: resource_registry.new_map_by_id(),
+        };
+        for txn_idx in 0..gas_unit_prices.len() {
+            let sender = sender_registry.key_id_for_txn(txn_idx);
+            if shuffler.pending_by_sender.get(sender).front() == Some(&txn_idx) {
+                shuffler.make_ready(txn_idx);
+            }
+        }
+        shuffler
+    }
+
+    /// Returns the selected transactions, and the transactions deferred to the end of the
+    /// block because of the hot resource cap (in their original order).
+    fn shuffle(mut self) -> (Vec<TxnIdx>, Vec<TxnIdx>) {
+        let mut selected = Vec::with_capacity(self.gas_unit_prices.len());
+        let mut deferred = vec![];
+
+        while let Some(candidate) = self.next_candidate() {
+            self.ready.remove(&candidate);
+            let txn_idx = candidate.1;
+            let exceeds_cap = self.exceeds_hot_resource_cap(txn_idx);
+            let sender = self.sender_registry.key_id_for_txn(txn_idx);
+            let pending = self.pending_by_sender.get_mut(sender);
+            assert_eq!(pending.pop_front(), Some(txn_idx));
+
+            if exceeds_cap {
+                // Defer the remaining transactions of the sender as well, to preserve their order
+                deferred.push(txn_idx);
+                deferred.extend(pending.drain(..));
+                continue;
+            }
+            if let Some(next_idx) = pending.front().copied() {
+                self.make_ready(next_idx);
+            }
+
+            selected.push(txn_idx);
+            self.conflict_windows
+                .iter_mut()
+                .for_each(|window| window.add(txn_idx));
+            *self
+                .num_selected_by_resource
+                .get_mut(self.resource_registry.key_id_for_txn(txn_idx)) += 1;
+        }
+
+        deferred.sort_unstable();
+        (selected, deferred)
+    }
+
+    fn make_ready(&mut self, txn_idx: TxnIdx) {
+        self.ready
+            .insert((Reverse(self.gas_unit_prices[txn_idx]), txn_idx));
+    }
+
+    /// The highest priority candidate without conflicts (among the first `MAX_CONFLICT_SCAN`),
+    /// or the highest priority one if all of them conflict.
+    fn next_candidate(&self) -> Option<(Reverse<u64>, TxnIdx)> {
+        self.ready
+            .iter()
+            .take(MAX_CONFLICT_SCAN)
+            .find(|(_, txn_idx)| !self.is_conflict(*txn_idx))
+            .or_else(|| self.ready.first())
+            .copied()
+    }
+
+    fn is_conflict(&self, txn_idx: TxnIdx) -> bool {
+        self.conflict_windows
+            .iter()
+            .any(|window| window.is_conflict(txn_idx))
+    }
+
+    fn exceeds_hot_resource_cap(&self, txn_idx: TxnIdx) -> bool {
+        let key_id = self.resource_registry.key_id_for_txn(txn_idx);
+        self.max_txns_per_hot_resource > 0
+            && !self.resource_registry.is_conflict_exempt(key_id)
+            && *self.num_selected_by_resource.get(key_id) >= self.max_txns_per_hot_resource
+    }
+}
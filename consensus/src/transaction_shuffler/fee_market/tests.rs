@@ -0,0 +1,184 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::transaction_shuffler::{
+    fairness::conflict_key::{
+        test_utils::{FakeEntryFunKey, FakeEntryFunModuleKey, FakeSenderKey, FakeTxn},
+        ConflictKeyRegistry, MapByKeyId,
+    },
+    fee_market::{FeeMarketShuffler, FeeMarketShufflerImpl, TxnIdx},
+};
+use proptest::{collection::vec, prelude::*};
+use std::cmp::Reverse;
+
+fn registries(txns: &[FakeTxn]) -> [ConflictKeyRegistry; 3] {
+    [
+        ConflictKeyRegistry::build::<FakeSenderKey, FakeTxn>(txns),
+        ConflictKeyRegistry::build::<FakeEntryFunModuleKey, FakeTxn>(txns),
+        ConflictKeyRegistry::build::<FakeEntryFunKey, FakeTxn>(txns),
+    ]
+}
+
+fn arb_shuffler() -> impl Strategy<Value = FeeMarketShuffler> {
+    (0..10usize, 0..10usize, 0..10usize).prop_map(
+        |(sender_conflict_window_size, module_conflict_window_size, max_txns_per_hot_resource)| {
+            FeeMarketShuffler {
+                sender_conflict_window_size,
+                module_conflict_window_size,
+                max_txns_per_hot_resource,
+            }
+        },
+    )
+}
+
+fn shuffle(
+    shuffler: &FeeMarketShuffler,
+    registries: &[ConflictKeyRegistry; 3],
+    gas_unit_prices: &[u64],
+) -> (Vec<TxnIdx>, Vec<TxnIdx>) {
+    FeeMarketShufflerImpl::new(shuffler, registries, gas_unit_prices).shuffle()
+}
+
+#[test]
+fn test_no_conflicts_no_cap_orders_by_gas() {
+    let num_txns = 6;
+    let registries = [
+        ConflictKeyRegistry::non_conflict(num_txns),
+        ConflictKeyRegistry::all_exempt(num_txns),
+        ConflictKeyRegistry::all_exempt(num_txns),
+    ];
+    let shuffler = FeeMarketShuffler {
+        sender_conflict_window_size: 3,
+        module_conflict_window_size: 3,
+        max_txns_per_hot_resource: 0,
+    };
+
+    let (selected, deferred) = shuffle(&shuffler, &registries, &[1, 5, 3, 5, 0, 3]);
+    assert_eq!(selected, vec![1, 3, 2, 5, 0, 4]);
+    assert!(deferred.is_empty());
+}
+
+#[test]
+fn test_sender_order_preserved() {
+    // Txns 0..3 are from one sender, 3..5 from another.
+    let registries = [
+        ConflictKeyRegistry::nums_per_key([3, 2]),
+        ConflictKeyRegistry::all_exempt(5),
+        ConflictKeyRegistry::all_exempt(5),
+    ];
+    let shuffler = FeeMarketShuffler {
+        sender_conflict_window_size: 0,
+        module_conflict_window_size: 0,
+        max_txns_per_hot_resource: 0,
+    };
+
+    // The expensive txn 2 can't jump ahead of txns 0 and 1 of the same sender.
+    let (selected, _) = shuffle(&shuffler, &registries, &[1, 1, 100, 2, 2]);
+    assert_eq!(selected, vec![3, 4, 0, 1, 2]);
+}
+
+#[test]
+fn test_sender_conflicts_spread() {
+    let registries = [
+        ConflictKeyRegistry::nums_per_key([3, 3]),
+        ConflictKeyRegistry::all_exempt(6),
+        ConflictKeyRegistry::all_exempt(6),
+    ];
+    let shuffler = FeeMarketShuffler {
+        sender_conflict_window_size: 1,
+        module_conflict_window_size: 0,
+        max_txns_per_hot_resource: 0,
+    };
+
+    let (selected, _) = shuffle(&shuffler, &registries, &[10, 10, 10, 1, 1, 1]);
+    assert_eq!(selected, vec![0, 3, 1, 4, 2, 5]);
+}
+
+#[test]
+fn test_hot_resource_cap() {
+    // Every txn has its own sender, and the first four call the same entry function.
+    let registries = [
+        ConflictKeyRegistry::non_conflict(6),
+        ConflictKeyRegistry::all_exempt(6),
+        ConflictKeyRegistry::nums_per_key([4, 1, 1]),
+    ];
+    let shuffler = FeeMarketShuffler {
+        sender_conflict_window_size: 0,
+        module_conflict_window_size: 0,
+        max_txns_per_hot_resource: 2,
+    };
+
+    let (selected, deferred) = shuffle(&shuffler, &registries, &[4, 3, 2, 1, 0, 0]);
+    assert_eq!(selected, vec![0, 1, 4, 5]);
+    assert_eq!(deferred, vec![2, 3]);
+}
+
+proptest! {
+    #[test]
+    fn test_fee_market_shuffler(
+        txns_and_gas in vec((any::<FakeTxn>(), 0..5u64), 0..500),
+        shuffler in arb_shuffler(),
+    ) {
+        let (txns, gas_unit_prices): (Vec<_>, Vec<_>) = txns_and_gas.into_iter().unzip();
+        let registries = registries(&txns);
+        let (selected, deferred) = shuffle(&shuffler, &registries, &gas_unit_prices);
+
+        // Deterministic.
+        prop_assert_eq!(
+            shuffle(&shuffler, &registries, &gas_unit_prices),
+            (selected.clone(), deferred.clone())
+        );
+
+        // A permutation.
+        let order: Vec<_> = selected.iter().chain(deferred.iter()).copied().collect();
+        let mut sorted = order.clone();
+        sorted.sort_unstable();
+        prop_assert_eq!(sorted, (0..txns.len()).collect::<Vec<_>>());
+
+        // The relative order of the txns of every sender is preserved.
+        let sender_registry = &registries[0];
+        let mut last_by_sender: MapByKeyId<Option<TxnIdx>> = sender_registry.new_map_by_id();
+        for &txn_idx in &order {
+            let last = last_by_sender.get_mut(sender_registry.key_id_for_txn(txn_idx));
+            prop_assert!(last.map_or(true, |last| last < txn_idx));
+            *last = Some(txn_idx);
+        }
+
+        // The hot resource cap is respected by the selected txns.
+        if shuffler.max_txns_per_hot_resource > 0 {
+            let resource_registry = &registries[2];
+            let mut counts: MapByKeyId<usize> = resource_registry.new_map_by_id();
+            for &txn_idx in &selected {
+                let key_id = resource_registry.key_id_for_txn(txn_idx);
+                if !resource_registry.is_conflict_exempt(key_id) {
+                    let count = counts.get_mut(key_id);
+                    *count += 1;
+                    prop_assert!(*count <= shuffler.max_txns_per_hot_resource);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fee_market_shuffler_without_conflicts(
+        gas_unit_prices in vec(0..5u64, 0..500),
+    ) {
+        let num_txns = gas_unit_prices.len();
+        let registries = [
+            ConflictKeyRegistry::non_conflict(num_txns),
+            ConflictKeyRegistry::all_exempt(num_txns),
+            ConflictKeyRegistry::all_exempt(num_txns),
+        ];
+        let shuffler = FeeMarketShuffler {
+            sender_conflict_window_size: 0,
+            module_conflict_window_size: 0,
+            max_txns_per_hot_resource: 0,
+        };
+        let (selected, deferred) = shuffle(&shuffler, &registries, &gas_unit_prices);
+
+        let mut expected: Vec<_> = (0..num_txns).collect();
+        expected.sort_by_key(|&idx| (Reverse(gas_unit_prices[idx]), idx));
+        prop_assert_eq!(selected, expected);
+        prop_assert!(deferred.is_empty());
+    }
+}
@@ -13,6 +13,7 @@ use sender_aware::SenderAwareShuffler;
 use std::sync::Arc;
 
 mod fairness;
+mod fee_market;
 mod sender_aware;
 
 /// Interface to shuffle transactions
@@ -65,5 +66,22 @@ pub fn create_transaction_shuffler(
                 entry_fun_conflict_window_size: entry_fun_conflict_window_size as usize,
             })
         },
+        TransactionShufflerType::FeeMarket {
+            sender_conflict_window_size,
+            module_conflict_window_size,
+            max_txns_per_hot_resource,
+        } => {
+            info!(
+                "Using fee market transaction shuffling with conflict window sizes: sender {}, module {}, max txns per hot resource {}",
+                sender_conflict_window_size,
+                module_conflict_window_size,
+                max_txns_per_hot_resource
+            );
+            Arc::new(fee_market::FeeMarketShuffler {
+                sender_conflict_window_size: sender_conflict_window_size as usize,
+                module_conflict_window_size: module_conflict_window_size as usize,
+                max_txns_per_hot_resource: max_txns_per_hot_resource as usize,
+            })
+        },
     }
 }
@@ -0,0 +1,560 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Inspection and repair of the consensus db (and the quorum store db) of a stopped node.
+//! The repair commands are checked against the safety rules persistent storage of the node,
+//! so that they never remove data that safety rules has not persisted itself.
+
+use crate::{
+    consensusdb::{create_checkpoint, ConsensusDB},
+    quorum_store::quorum_store_db::{QuorumStoreDB, QuorumStoreStorage},
+};
+use anyhow::{bail, ensure, Context, Result};
+use aptos_config::config::NodeConfig;
+use aptos_consensus_types::{
+    block::Block, common::Author, quorum_cert::QuorumCert, safety_data::SafetyData,
+    timeout_2chain::TwoChainTimeoutCertificate, vote::Vote,
+};
+use aptos_crypto::HashValue;
+use aptos_safety_rules::PersistentSafetyStorage;
+use aptos_secure_storage::Storage;
+use clap::Parser;
+use serde::Serialize;
+use std::{
+    collections::{HashMap, HashSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Parser)]
+pub enum Cmd {
+    ListBlocks(ListBlocks),
+
+    DumpBlock(DumpBlock),
+
+    ListQuorumCerts(ListQuorumCerts),
+
+    LastVote(LastVote),
+
+    HighestTimeoutCert(HighestTimeoutCert),
+
+    ListBatches(ListBatches),
+
+    SafetyData(ShowSafetyData),
+
+    PruneBlocks(PruneBlocks),
+
+    ResetLivenessState(ResetLivenessState),
+}
+
+impl Cmd {
+    pub fn run(self) -> Result<()> {
+        match self {
+            Cmd::ListBlocks(cmd) => cmd.run(),
+            Cmd::DumpBlock(cmd) => cmd.run(),
+            Cmd::ListQuorumCerts(cmd) => cmd.run(),
+            Cmd::LastVote(cmd) => cmd.run(),
+            Cmd::HighestTimeoutCert(cmd) => cmd.run(),
+            Cmd::ListBatches(cmd) => cmd.run(),
+            Cmd::SafetyData(cmd) => cmd.run(),
+            Cmd::PruneBlocks(cmd) => cmd.run(),
+            Cmd::ResetLivenessState(cmd) => cmd.run(),
+        }
+    }
+}
+
+#[derive(Parser)]
+#[clap(about = "List the blocks in the consensus db (ordered by epoch and round).")]
+pub struct ListBlocks {
+    #[clap(long, value_parser)]
+    db_dir: PathBuf,
+}
+
+impl ListBlocks {
+    pub fn run(self) -> Result<()> {
+        let (_, _, mut blocks, _) = ConsensusDB::new(&self.db_dir).get_data()?;
+        blocks.sort_by_key(|block| (block.epoch(), block.round()));
+        print_json(&blocks.iter().map(BlockSummary::from).collect::<Vec<_>>())
+    }
+}
+
+#[derive(Parser)]
+#[clap(about = "Dump a block (and the quorum cert certifying it, if any) from the consensus db.")]
+pub struct DumpBlock {
+    #[clap(long, value_parser)]
+    db_dir: PathBuf,
+
+    #[clap(long)]
+    block_id: HashValue,
+}
+
+#[derive(Serialize)]
+struct BlockWithQuorumCert {
+    block: Block,
+    quorum_cert: Option<QuorumCert>,
+}
+
+impl DumpBlock {
+    pub fn run(self) -> Result<()> {
+        let (_, _, blocks, qcs) = ConsensusDB::new(&self.db_dir).get_data()?;
+        let block = blocks
+            .into_iter()
+            .find(|block| block.id() == self.block_id)
+            .with_context(|| format!("Block {} is not found.", self.block_id))?;
+        let quorum_cert = qcs
+            .into_iter()
+            .find(|qc| qc.certified_block().id() == self.block_id);
+        print_json(&BlockWithQuorumCert { block, quorum_cert })
+    }
+}
+
+#[derive(Parser)]
+#[clap(about = "List the quorum certs in the consensus db (ordered by epoch and round).")]
+pub struct ListQuorumCerts {
+    #[clap(long, value_parser)]
+    db_dir: PathBuf,
+}
+
+impl ListQuorumCerts {
+    pub fn run(self) -> Result<()> {
+        let (_, _, _, mut qcs) = ConsensusDB::new(&self.db_dir).get_data()?;
+        qcs.sort_by_key(|qc| (qc.certified_block().epoch(), qc.certified_block().round()));
+        print_json(&qcs.iter().map(QuorumCertSummary::from).collect::<Vec<_>>())
+    }
+}
+
+#[derive(Parser)]
+#[clap(about = "Print the last vote in the consensus db.")]
+pub struct LastVote {
+    #[clap(long, value_parser)]
+    db_dir: PathBuf,
+}
+
+impl LastVote {
+    pub fn run(self) -> Result<()> {
+        print_json(&read_last_vote(&ConsensusDB::new(&self.db_dir))?)
+    }
+}
+
+#[derive(Parser)]
+#[clap(about = "Print the highest timeout certificate in the consensus db.")]
+pub struct HighestTimeoutCert {
+    #[clap(long, value_parser)]
+    db_dir: PathBuf,
+}
+
+impl HighestTimeoutCert {
+    pub fn run(self) -> Result<()> {
+        print_json(&read_highest_timeout_cert(&ConsensusDB::new(&self.db_dir))?)
+    }
+}
+
+#[derive(Parser)]
+#[clap(about = "List the batches in the quorum store db (ordered by epoch and expiration).")]
+pub struct ListBatches {
+    #[clap(long, value_parser)]
+    db_dir: PathBuf,
+}
+
+impl ListBatches {
+    pub fn run(self) -> Result<()> {
+        let mut batches: Vec<_> = QuorumStoreDB::new(&self.db_dir)
+            .get_all_batches()?
+            .into_values()
+            .map(|batch| BatchSummary {
+                digest: *batch.digest(),
+                author: batch.author(),
+                epoch: batch.epoch(),
+                batch_id: batch.batch_id().id,
+                expiration: batch.expiration(),
+                num_txns: batch.num_txns(),
+                num_bytes: batch.num_bytes(),
+                has_payload: batch.payload().is_some(),
+            })
+            .collect();
+        batches.sort_by_key(|batch| (batch.epoch, batch.expiration, batch.digest));
+        print_json(&batches)
+    }
+}
+
+#[derive(Parser)]
+#[clap(about = "Print the safety data in the safety rules persistent storage of the node.")]
+pub struct ShowSafetyData {
+    #[clap(long, value_parser)]
+    node_config_path: PathBuf,
+}
+
+impl ShowSafetyData {
+    pub fn run(self) -> Result<()> {
+        print_json(&read_safety_data(&self.node_config_path)?)
+    }
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Delete the blocks (and quorum certs) that can no longer be committed: \
+    the blocks of the epochs before the safety rules epoch, and the blocks of the \
+    current epoch that are not ancestors of the highest committed block."
+)]
+#[clap(group(clap::ArgGroup::new("backup")
+        .required(true)
+        .args(&["backup_checkpoint_dir", "opt_out_backup_checkpoint", "dry_run"]),
+))]
+pub struct PruneBlocks {
+    #[clap(long, value_parser)]
+    db_dir: PathBuf,
+
+    #[clap(long, value_parser)]
+    node_config_path: PathBuf,
+
+    #[clap(long, value_parser, group = "backup")]
+    backup_checkpoint_dir: Option<PathBuf>,
+
+    #[clap(long, group = "backup")]
+    opt_out_backup_checkpoint: bool,
+
+    /// Only print the blocks that would be deleted
+    #[clap(long, group = "backup")]
+    dry_run: bool,
+}
+
+impl PruneBlocks {
+    pub fn run(self) -> Result<()> {
+        let safety_data = read_safety_data(&self.node_config_path)?;
+        let consensus_db = ConsensusDB::new(&self.db_dir);
+        let (_, _, blocks, qcs) = consensus_db.get_data()?;
+
+        let stale_block_ids = find_stale_blocks(safety_data.epoch, &blocks, &qcs)?;
+        let stale_blocks: Vec<_> = blocks
+            .iter()
+            .filter(|block| stale_block_ids.contains(&block.id()))
+            .map(BlockSummary::from)
+            .collect();
+        print_json(&stale_blocks)?;
+        if self.dry_run {
+            println!("Dry run, {} blocks are not deleted.", stale_blocks.len());
+            return Ok(());
+        }
+
+        drop(consensus_db);
+        create_backup(&self.db_dir, self.backup_checkpoint_dir)?;
+        ConsensusDB::new(&self.db_dir).delete_blocks_and_quorum_certificates(stale_block_ids)?;
+        println!("Deleted {} blocks.", stale_blocks.len());
+        Ok(())
+    }
+}
+
+#[derive(Parser)]
+#[clap(
+    about = "Delete the last vote and the highest timeout certificate from the consensus db, \
+    if the last vote is already persisted by safety rules."
+)]
+#[clap(group(clap::ArgGroup::new("backup")
+        .required(true)
+        .args(&["backup_checkpoint_dir", "opt_out_backup_checkpoint"]),
+))]
+pub struct ResetLivenessState {
+    #[clap(long, value_parser)]
+    db_dir: PathBuf,
+
+    #[clap(long, value_parser)]
+    node_config_path: PathBuf,
+
+    #[clap(long, value_parser, group = "backup")]
+    backup_checkpoint_dir: Option<PathBuf>,
+
+    #[clap(long, group = "backup")]
+    opt_out_backup_checkpoint: bool,
+}
+
+impl ResetLivenessState {
+    pub fn run(self) -> Result<()> {
+        let safety_data = read_safety_data(&self.node_config_path)?;
+        let last_vote = read_last_vote(&ConsensusDB::new(&self.db_dir))?;
+        if let Some(last_vote) = &last_vote {
+            ensure_vote_persisted_by_safety_rules(&safety_data, last_vote)?;
+        }
+
+        create_backup(&self.db_dir, self.backup_checkpoint_dir)?;
+        let consensus_db = ConsensusDB::new(&self.db_dir);
+        consensus_db.delete_last_vote_msg()?;
+        consensus_db.delete_highest_2chain_timeout_certificate()?;
+        println!("Deleted the last vote and the highest timeout certificate.");
+        Ok(())
+    }
+}
+
+#[derive(Serialize)]
+struct BlockSummary {
+    id: HashValue,
+    parent_id: HashValue,
+    epoch: u64,
+    round: u64,
+    author: Option<Author>,
+    timestamp_usecs: u64,
+    num_validator_txns: usize,
+}
+
+impl From<&Block> for BlockSummary {
+    fn from(block: &Block) -> Self {
+        Self {
+            id: block.id(),
+            parent_id: block.parent_id(),
+            epoch: block.epoch(),
+            round: block.round(),
+            author: block.author(),
+            timestamp_usecs: block.timestamp_usecs(),
+            num_validator_txns: block.validator_txns().map_or(0, Vec::len),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct QuorumCertSummary {
+    certified_block_id: HashValue,
+    epoch: u64,
+    round: u64,
+    commit_block_id: Option<HashValue>,
+    commit_round: Option<u64>,
+}
+
+impl From<&QuorumCert> for QuorumCertSummary {
+    fn from(qc: &QuorumCert) -> Self {
+        let commit_info = Some(qc.commit_info()).filter(|commit_info| !commit_info.is_empty());
+        Self {
+            certified_block_id: qc.certified_block().id(),
+            epoch: qc.certified_block().epoch(),
+            round: qc.certified_block().round(),
+            commit_block_id: commit_info.map(|commit_info| commit_info.id()),
+            commit_round: commit_info.map(|commit_info| commit_info.round()),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct BatchSummary {
+    digest: HashValue,
+    author: Author,
+    epoch: u64,
+    batch_id: u64,
+    expiration: u64,
+    num_txns: u64,
+    num_bytes: u64,
+    has_payload: bool,
+}
+
+fn print_json<T: Serialize>(value: &T) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+fn read_last_vote(consensus_db: &ConsensusDB) -> Result<Option<Vote>> {
+    let (last_vote, _, _, _) = consensus_db.get_data()?;
+    last_vote
+        .map(|bytes| bcs::from_bytes(&bytes).context("Unable to deserialize the last vote."))
+        .transpose()
+}
+
+fn read_highest_timeout_cert(
+    consensus_db: &ConsensusDB,
+) -> Result<Option<TwoChainTimeoutCertificate>> {
+    let (_, highest_timeout_cert, _, _) = consensus_db.get_data()?;
+    highest_timeout_cert
+        .map(|bytes| {
+            bcs::from_bytes(&bytes).context("Unable to deserialize the highest timeout cert.")
+        })
+        .transpose()
+}
+
+fn read_safety_data(node_config_path: &Path) -> Result<SafetyData> {
+    let node_config = NodeConfig::load_from_path(node_config_path)
+        .with_context(|| format!("Failed to load the node config {:?}.", node_config_path))?;
+    let storage: Storage = (&node_config.consensus.safety_rules.backend).into();
+    let mut safety_storage = PersistentSafetyStorage::new(storage, false);
+    safety_storage
+        .safety_data()
+        .context("Failed to read the safety data from the safety rules storage.")
+}
+
+fn create_backup(db_dir: &Path, backup_checkpoint_dir: Option<PathBuf>) -> Result<()> {
+    match backup_checkpoint_dir {
+        Some(backup_checkpoint_dir) => {
+            ensure!(
+                !backup_checkpoint_dir.exists(),
+                "Backup dir already exists."
+            );
+            println!("Creating backup at: {:?}", &backup_checkpoint_dir);
+            fs::create_dir_all(&backup_checkpoint_dir)?;
+            create_checkpoint(db_dir, backup_checkpoint_dir.as_path())?;
+            println!("Done!");
+        },
+        None => println!("Opted out backup creation!."),
+    }
+    Ok(())
+}
+
+/// Ensures that safety rules has persisted (at least) the given vote, i.e., that removing it
+/// from the consensus db cannot allow the node to vote again in the same round.
+fn ensure_vote_persisted_by_safety_rules(safety_data: &SafetyData, vote: &Vote) -> Result<()> {
+    let vote_round = vote.vote_data().proposed().round();
+    ensure!(
+        safety_data.epoch > vote.epoch()
+            || (safety_data.epoch == vote.epoch() && safety_data.last_voted_round >= vote_round),
+        "The last vote (epoch {}, round {}) is not persisted by safety rules (epoch {}, last voted round {}).",
+        vote.epoch(),
+        vote_round,
+        safety_data.epoch,
+        safety_data.last_voted_round,
+    );
+    Ok(())
+}
+
+/// Returns the ids of the blocks that can no longer be committed: the blocks of the epochs before
+/// the safety rules epoch, and the blocks of the safety rules epoch at or below the highest
+/// committed round that are not ancestors of the highest committed block. If the highest
+/// committed block is not in the db, no block of the safety rules epoch is returned.
+fn find_stale_blocks(
+    safety_epoch: u64,
+    blocks: &[Block],
+    qcs: &[QuorumCert],
+) -> Result<Vec<HashValue>> {
+    if let Some(block) = blocks.iter().find(|block| block.epoch() > safety_epoch) {
+        bail!(
+            "Block {} is in epoch {}, but the safety rules storage is in epoch {}.",
+            block.id(),
+            block.epoch(),
+            safety_epoch
+        );
+    }
+
+    let mut stale_block_ids: Vec<_> = blocks
+        .iter()
+        .filter(|block| block.epoch() < safety_epoch)
+        .map(Block::id)
+        .collect();
+
+    let highest_commit_info = qcs
+        .iter()
+        .map(QuorumCert::commit_info)
+        .filter(|commit_info| !commit_info.is_empty() && commit_info.epoch() == safety_epoch)
+        .max_by_key(|commit_info| commit_info.round());
+    let blocks_by_id: HashMap<_, _> = blocks.iter().map(|block| (block.id(), block)).collect();
+    if let Some(committed_block) =
+        highest_commit_info.and_then(|commit_info| blocks_by_id.get(&commit_info.id()))
+    {
+        let mut committed_block_ids = HashSet::new();
+        let mut current = Some(*committed_block);
+        while let Some(block) = current {
+            committed_block_ids.insert(block.id());
+            current = blocks_by_id.get(&block.parent_id()).copied();
+        }
+        stale_block_ids.extend(
+            blocks
+                .iter()
+                .filter(|block| {
+                    block.epoch() == safety_epoch
+                        && block.round() <= committed_block.round()
+                        && !committed_block_ids.contains(&block.id())
+                })
+                .map(Block::id),
+        );
+    }
+    Ok(stale_block_ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aptos_consensus_types::vote_data::VoteData;
+    use aptos_types::{
+        aggregate_signature::AggregateSignature,
+        block_info::BlockInfo,
+        ledger_info::{LedgerInfo, LedgerInfoWithSignatures},
+        validator_signer::ValidatorSigner,
+    };
+
+    fn block_info(block: &Block) -> BlockInfo {
+        block.gen_block_info(HashValue::zero(), 0, None)
+    }
+
+    fn certify(certified: BlockInfo, parent: BlockInfo, committed: BlockInfo) -> QuorumCert {
+        QuorumCert::new(
+            VoteData::new(certified, parent),
+            LedgerInfoWithSignatures::new(
+                LedgerInfo::new(committed, HashValue::zero()),
+                AggregateSignature::empty(),
+            ),
+        )
+    }
+
+    #[test]
+    fn verify_tool() {
+        use clap::CommandFactory;
+        Cmd::command().debug_assert()
+    }
+
+    #[test]
+    fn test_find_stale_blocks() {
+        // genesis <- a <- b <- c, with the fork a <- d, and b committed by the QC of c
+        let genesis = Block::make_genesis_block();
+        let empty = BlockInfo::empty;
+        let a = Block::new_nil(
+            1,
+            certify(block_info(&genesis), block_info(&genesis), empty()),
+            vec![],
+        );
+        let b = Block::new_nil(
+            2,
+            certify(block_info(&a), block_info(&genesis), empty()),
+            vec![],
+        );
+        let d = Block::new_nil(
+            2,
+            certify(block_info(&a), block_info(&genesis), empty()),
+            vec![(1, Author::ONE)],
+        );
+        let c = Block::new_nil(3, certify(block_info(&b), block_info(&a), empty()), vec![]);
+        let qcs = vec![certify(block_info(&c), block_info(&b), block_info(&b))];
+        let mut blocks = vec![genesis.clone(), a, b, c, d.clone()];
+        let epoch = genesis.epoch();
+
+        assert_eq!(find_stale_blocks(epoch, &blocks, &qcs).unwrap(), vec![
+            d.id()
+        ]);
+        // Without the committed block, only the blocks of the previous epochs are stale
+        assert!(find_stale_blocks(epoch, &blocks, &[]).unwrap().is_empty());
+        assert_eq!(
+            find_stale_blocks(epoch + 1, &blocks, &qcs).unwrap().len(),
+            blocks.len()
+        );
+
+        // The safety rules storage is behind the consensus db
+        let next_epoch_genesis = BlockInfo::random_with_epoch(epoch + 1, 0);
+        blocks.push(Block::new_nil(
+            1,
+            certify(next_epoch_genesis.clone(), next_epoch_genesis, empty()),
+            vec![],
+        ));
+        assert!(find_stale_blocks(epoch, &blocks, &qcs).is_err());
+    }
+
+    #[test]
+    fn test_ensure_vote_persisted_by_safety_rules() {
+        let signer = ValidatorSigner::random(None);
+        let proposed = BlockInfo::random_with_epoch(2, 10);
+        let vote = Vote::new(
+            VoteData::new(proposed, BlockInfo::random_with_epoch(2, 9)),
+            signer.author(),
+            LedgerInfo::new(BlockInfo::empty(), HashValue::zero()),
+            &signer,
+        )
+        .unwrap();
+        let safety_data =
+            |epoch, last_voted_round| SafetyData::new(epoch, last_voted_round, 0, 0, None);
+
+        assert!(ensure_vote_persisted_by_safety_rules(&safety_data(2, 10), &vote).is_ok());
+        assert!(ensure_vote_persisted_by_safety_rules(&safety_data(3, 0), &vote).is_ok());
+        assert!(ensure_vote_persisted_by_safety_rules(&safety_data(2, 9), &vote).is_err());
+        assert!(ensure_vote_persisted_by_safety_rules(&safety_data(1, 20), &vote).is_err());
+    }
+}
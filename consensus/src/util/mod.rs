@@ -7,6 +7,7 @@ use aptos_types::{
     validator_txn::ValidatorTransaction,
 };
 
+pub mod db_debugger;
 pub mod db_tool;
 #[cfg(any(test, feature = "fuzzing"))]
 pub mod mock_time_service;
@@ -7,11 +7,11 @@ extern crate criterion;
 
 use aptos_crypto::{
     ed25519::{Ed25519PrivateKey, Ed25519PublicKey, Ed25519Signature},
-    traits::{Signature, SigningKey, Uniform},
+    traits::{signing_message, Signature, SigningKey, Uniform},
     PrivateKey,
 };
 use aptos_crypto_derive::{BCSCryptoHash, CryptoHasher};
-use criterion::{measurement::Measurement, BenchmarkGroup, Criterion, Throughput};
+use criterion::{measurement::Measurement, BenchmarkGroup, BenchmarkId, Criterion, Throughput};
 use curve25519_dalek::{constants::ED25519_BASEPOINT_POINT, scalar::Scalar};
 use rand::{distributions, prelude::ThreadRng, thread_rng, Rng};
 use serde::{Deserialize, Serialize};
@@ -40,6 +40,16 @@ fn benchmark_groups(c: &mut Criterion) {
     small_subgroup_check(&mut group);
 
     group.finish();
+
+    let mut group = c.benchmark_group("ed25519_batch");
+
+    group.sample_size(10);
+
+    for num_signatures in [16, 128, 256, 512, 1024, 2048, 4096] {
+        batch_sig_verify_arbitrary_msgs(&mut group, num_signatures);
+    }
+
+    group.finish();
 }
 
 fn sig_verify_struct<M: Measurement>(g: &mut BenchmarkGroup<M>) {
@@ -81,6 +91,45 @@ fn sig_verify_zero_bytes<M: Measurement>(g: &mut BenchmarkGroup<M>) {
     });
 }
 
+/// Benchmarks the time to verify signatures on different messages under different public keys,
+/// one by one and as a batch.
+fn batch_sig_verify_arbitrary_msgs<M: Measurement>(
+    g: &mut BenchmarkGroup<M>,
+    num_signatures: usize,
+) {
+    let mut csprng: ThreadRng = thread_rng();
+
+    let mut messages = vec![];
+    let mut keys_and_signatures = vec![];
+    for _ in 0..num_signatures {
+        let msg = random_message(&mut csprng);
+        let priv_key = Ed25519PrivateKey::generate(&mut csprng);
+        keys_and_signatures.push((priv_key.public_key(), priv_key.sign(&msg).unwrap()));
+        messages.push(signing_message(&msg).unwrap());
+    }
+    let batch: Vec<(&[u8], &Ed25519PublicKey, &Ed25519Signature)> = messages
+        .iter()
+        .zip(keys_and_signatures.iter())
+        .map(|(message, (pub_key, sig))| (message.as_slice(), pub_key, sig))
+        .collect();
+
+    g.throughput(Throughput::Elements(num_signatures as u64));
+    g.bench_function(
+        BenchmarkId::new("sig_verify_arbitrary_msgs_individually", num_signatures),
+        |b| {
+            b.iter(|| {
+                for (message, pub_key, sig) in &batch {
+                    sig.verify_arbitrary_msg(message, pub_key).unwrap();
+                }
+            })
+        },
+    );
+    g.bench_function(
+        BenchmarkId::new("batch_sig_verify_arbitrary_msgs", num_signatures),
+        |b| b.iter(|| Ed25519Signature::batch_verify_arbitrary_msgs(&batch).unwrap()),
+    );
+}
+
 /// Benchmarks the time to check if an EdwardsPoint is in a small subgroup.
 fn small_subgroup_check<M: Measurement>(g: &mut BenchmarkGroup<M>) {
     let point = ED25519_BASEPOINT_POINT;
@@ -17,6 +17,11 @@ cargo bench -- "ed25519/sig_verify_zero_bytes"
 
 cargo bench -- "secp256k1/ecdsa_recover"
 
+cargo bench -- "secp256r1/pk_deserialize"
+cargo bench -- "secp256r1/ecdsa_verify"
+
+cargo bench -- "zk/poseidon-bn254-ark"
+
 cargo bench -- "ristretto255/basepoint_mul"
 cargo bench -- "ristretto255/basepoint_double_mul"
 cargo bench -- "ristretto255/point_add"
@@ -76,6 +76,9 @@ ed25519:sig_verify_zero_bytes
 
 secp256k1:ecdsa_recover
 
+secp256r1:pk_deserialize
+secp256r1:ecdsa_verify
+
 ristretto255:basepoint_mul
 ristretto255:basepoint_double_mul
 ristretto255:point_add
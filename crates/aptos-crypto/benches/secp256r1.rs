@@ -0,0 +1,74 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+#[macro_use]
+extern crate criterion;
+
+use aptos_crypto::{
+    secp256r1_ecdsa::{PrivateKey, PublicKey},
+    traits::{Signature, SigningKey, Uniform},
+};
+use aptos_crypto_derive::{BCSCryptoHash, CryptoHasher};
+use criterion::{measurement::Measurement, BenchmarkGroup, Criterion, Throughput};
+use rand::{distributions, prelude::ThreadRng, thread_rng, Rng};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, CryptoHasher, BCSCryptoHash, Serialize, Deserialize)]
+pub struct TestAptosCrypto(pub String);
+
+fn random_message(rng: &mut ThreadRng) -> TestAptosCrypto {
+    TestAptosCrypto(
+        rng.sample_iter(&distributions::Alphanumeric)
+            .take(32)
+            .map(char::from)
+            .collect::<String>(),
+    )
+}
+
+fn benchmark_groups(c: &mut Criterion) {
+    let mut group = c.benchmark_group("secp256r1");
+
+    group.sample_size(1000);
+
+    ecdsa_verify(&mut group);
+    pk_deserialize(&mut group);
+
+    group.finish();
+}
+
+/// Benchmarks the time to verify a signature on a short message, including hashing it with
+/// SHA2-256. (Used for gas estimation.)
+fn ecdsa_verify<M: Measurement>(g: &mut BenchmarkGroup<M>) {
+    let mut csprng: ThreadRng = thread_rng();
+
+    let sk = PrivateKey::generate(&mut csprng);
+    let pk = PublicKey::from(&sk);
+
+    g.throughput(Throughput::Elements(1));
+    g.bench_function("ecdsa_verify", move |b| {
+        b.iter_with_setup(
+            || {
+                let msg = random_message(&mut csprng);
+                let sig = sk.sign(&msg).unwrap();
+                (sig, msg)
+            },
+            |(sig, msg)| sig.verify(&msg, &pk).unwrap(),
+        )
+    });
+}
+
+/// Benchmarks the time to deserialize and validate an uncompressed public key.
+fn pk_deserialize<M: Measurement>(g: &mut BenchmarkGroup<M>) {
+    let mut csprng: ThreadRng = thread_rng();
+
+    g.throughput(Throughput::Elements(1));
+    g.bench_function("pk_deserialize", move |b| {
+        b.iter_with_setup(
+            || PublicKey::from(&PrivateKey::generate(&mut csprng)).to_bytes(),
+            |pk_bytes| PublicKey::try_from(&pk_bytes[..]).unwrap(),
+        )
+    });
+}
+
+criterion_group!(secp256r1_benches, benchmark_groups);
+criterion_main!(secp256r1_benches);
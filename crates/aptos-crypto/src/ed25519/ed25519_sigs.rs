@@ -11,7 +11,15 @@ use crate::{
 use anyhow::{anyhow, Result};
 use aptos_crypto_derive::{DeserializeKey, SerializeKey};
 use core::convert::TryFrom;
+use curve25519_dalek::{
+    constants::ED25519_BASEPOINT_POINT,
+    edwards::{CompressedEdwardsY, EdwardsPoint},
+    scalar::Scalar,
+    traits::{Identity, IsIdentity, VartimeMultiscalarMul},
+};
+use rand::Rng;
 use serde::Serialize;
+use sha2::{Digest, Sha512};
 use std::{cmp::Ordering, fmt};
 
 /// An Ed25519 signature
@@ -130,6 +138,89 @@ impl Signature for Ed25519Signature {
             .and(Ok(()))
     }
 
+    /// Verifies a batch of signatures on different messages under different public keys, with
+    /// the same outcome as verifying each of them with `verify_arbitrary_msg`.
+    ///
+    /// The batch is checked with a random linear combination of the verification equations
+    /// R_i + k_i A_i - s_i B = 0, as in "High-speed high-security signatures" by Bernstein et al.
+    /// This combination only implies the strict (cofactorless) equation of each signature if
+    /// none of the points R_i and A_i has a small-order component: two such components can cancel
+    /// out in the combination, or be cancelled by the random coefficients. Hence all points are
+    /// first checked to be torsion-free, using random subset sums which are multiplied by the
+    /// group order. Batches in which a point is not torsion-free, which are never produced by
+    /// honest signers, are verified one signature at a time.
+    ///
+    /// Both randomized checks err with probability at most 2^-128. The torsion check has a fixed
+    /// cost of 128 scalar multiplications, so batching only pays off for large batches.
+    fn batch_verify_arbitrary_msgs(
+        messages_keys_and_signatures: &[(&[u8], &Ed25519PublicKey, &Self)],
+    ) -> Result<()> {
+        let num_signatures = messages_keys_and_signatures.len();
+        let mut points = Vec::with_capacity(2 * num_signatures);
+        let mut hrams = Vec::with_capacity(num_signatures);
+        let mut ss = Vec::with_capacity(num_signatures);
+        for (message, public_key, signature) in messages_keys_and_signatures {
+            // The same checks as in ed25519::PublicKey::verify_strict
+            let bytes = signature.to_bytes();
+            Ed25519Signature::check_s_malleability(&bytes)?;
+            let mut r_bytes = [0u8; 32];
+            r_bytes.copy_from_slice(&bytes[..32]);
+            let mut s_bytes = [0u8; 32];
+            s_bytes.copy_from_slice(&bytes[32..]);
+            let s = Scalar::from_canonical_bytes(s_bytes)
+                .ok_or_else(|| anyhow!("{}", CryptoMaterialError::CanonicalRepresentationError))?;
+            let public_key_bytes = public_key.to_bytes();
+            let (r, a) = match (
+                CompressedEdwardsY(r_bytes).decompress(),
+                CompressedEdwardsY(public_key_bytes).decompress(),
+            ) {
+                (Some(r), Some(a)) => (r, a),
+                _ => return Err(anyhow!("{}", CryptoMaterialError::PointNotOnCurveError)),
+            };
+            if r.is_small_order() || a.is_small_order() {
+                return Err(anyhow!("{}", CryptoMaterialError::SmallSubgroupError));
+            }
+            let hram = Scalar::from_hash(
+                Sha512::new()
+                    .chain(r_bytes)
+                    .chain(public_key_bytes)
+                    .chain(message),
+            );
+            points.push(r);
+            points.push(a);
+            hrams.push(hram);
+            ss.push(s);
+        }
+
+        let mut rng = rand::thread_rng();
+        if !all_torsion_free(&points, &mut rng) {
+            for (message, public_key, signature) in messages_keys_and_signatures {
+                signature.verify_arbitrary_msg(message, public_key)?
+            }
+            return Ok(());
+        }
+
+        // sum_i z_i R_i + sum_i (z_i k_i) A_i - (sum_i z_i s_i) B = 0
+        let zs: Vec<Scalar> = (0..num_signatures)
+            .map(|_| Scalar::from(rng.gen::<u128>()))
+            .collect();
+        let b_coefficient = -zs
+            .iter()
+            .zip(ss.iter())
+            .fold(Scalar::zero(), |acc, (z, s)| acc + z * s);
+        let scalars = std::iter::once(b_coefficient).chain(
+            zs.iter()
+                .zip(hrams.iter())
+                .flat_map(|(z, hram)| [*z, z * hram]),
+        );
+        let points = std::iter::once(ED25519_BASEPOINT_POINT).chain(points);
+        if EdwardsPoint::vartime_multiscalar_mul(scalars, points).is_identity() {
+            Ok(())
+        } else {
+            Err(anyhow!("Batch verification of Ed25519 signatures failed"))
+        }
+    }
+
     fn to_bytes(&self) -> Vec<u8> {
         self.0.to_bytes().to_vec()
     }
@@ -186,3 +277,33 @@ impl fmt::Debug for Ed25519Signature {
         write!(f, "Ed25519Signature({})", self)
     }
 }
+
+/// The number of random subset sums checked by `all_torsion_free`.
+const TORSION_CHECK_REPETITIONS: usize = 128;
+
+/// Checks that none of the points has a small-order component, except with probability at most
+/// 2^-128. If a point has a small-order component, so has at least one of any two subset sums
+/// which differ only in this point, hence a random subset sum is torsion-free with probability at
+/// most 1/2. To make a random subset sum cost a single addition per four points, the sums of all
+/// subsets of every four consecutive points are precomputed.
+fn all_torsion_free<R: Rng>(points: &[EdwardsPoint], rng: &mut R) -> bool {
+    let tables: Vec<Vec<EdwardsPoint>> = points
+        .chunks(4)
+        .map(|group| {
+            let mut table = vec![EdwardsPoint::identity(); 1 << group.len()];
+            for subset in 1..table.len() {
+                let lowest = subset.trailing_zeros() as usize;
+                table[subset] = table[subset & (subset - 1)] + group[lowest];
+            }
+            table
+        })
+        .collect();
+    (0..TORSION_CHECK_REPETITIONS).all(|_| {
+        tables
+            .iter()
+            .fold(EdwardsPoint::identity(), |sum, table| {
+                sum + table[rng.gen::<usize>() % table.len()]
+            })
+            .is_torsion_free()
+    })
+}
@@ -273,6 +273,19 @@ pub trait Signature:
         }
         Ok(())
     }
+
+    /// Verifies a batch of signatures, each on its own message under its own public key, and
+    /// succeeds only if all of them are valid. The implementer can override this implementation,
+    /// which by default iterates over each signature, with a multi-message batch verification
+    /// which accepts exactly the same signatures as `verify_arbitrary_msg`.
+    fn batch_verify_arbitrary_msgs(
+        messages_keys_and_signatures: &[(&[u8], &Self::VerifyingKeyMaterial, &Self)],
+    ) -> Result<()> {
+        for (message, key, signature) in messages_keys_and_signatures {
+            signature.verify_arbitrary_msg(message, key)?
+        }
+        Ok(())
+    }
 }
 
 /// A type family for schemes which know how to generate key material from
@@ -325,6 +325,26 @@ proptest! {
         prop_assert!(Ed25519Signature::batch_verify(&message, signatures).is_err());
     }
 
+    #[test]
+    fn test_batch_verify_arbitrary_msgs(
+        messages in vec(any::<Vec<u8>>(), 10),
+        keypairs in proptest::array::uniform10(uniform_keypair_strategy::<Ed25519PrivateKey, Ed25519PublicKey>())
+    ) {
+        let signatures: Vec<Ed25519Signature> = keypairs.iter().zip(messages.iter()).map(|(keypair, message)| {
+            SigningKey::sign_arbitrary_message(&keypair.private_key, message)
+        }).collect();
+        let mut batch: Vec<(&[u8], &Ed25519PublicKey, &Ed25519Signature)> = messages
+            .iter()
+            .zip(keypairs.iter())
+            .zip(signatures.iter())
+            .map(|((message, keypair), signature)| (message.as_slice(), &keypair.public_key, signature))
+            .collect();
+        prop_assert!(Ed25519Signature::batch_verify_arbitrary_msgs(&batch).is_ok());
+        // A signature by another key on another message is rejected
+        batch[0].2 = &signatures[1];
+        prop_assert!(Ed25519Signature::batch_verify_arbitrary_msgs(&batch).is_err());
+    }
+
     #[test]
     fn test_keys_custom_serialisation(
         keypair in uniform_keypair_strategy::<Ed25519PrivateKey, Ed25519PublicKey>()
@@ -499,6 +519,101 @@ proptest! {
     }
 }
 
+/// Signs the message under the public key [a]B + `key_torsion` with the nonce commitment
+/// [r]B + `nonce_torsion`, so that the signature satisfies sB - kA = R up to the small-order
+/// component `nonce_torsion + k * key_torsion`.
+fn sign_with_torsion(
+    a: Scalar,
+    key_torsion: EdwardsPoint,
+    r: Scalar,
+    nonce_torsion: EdwardsPoint,
+    message: &[u8],
+) -> (Ed25519PublicKey, Ed25519Signature, Scalar) {
+    let public_key = ED25519_BASEPOINT_POINT.mul(a).add(key_torsion).compress();
+    let nonce = ED25519_BASEPOINT_POINT.mul(r).add(nonce_torsion).compress();
+    let k = Scalar::from_hash(
+        Sha512::new()
+            .chain(nonce.as_bytes())
+            .chain(public_key.as_bytes())
+            .chain(message),
+    );
+    let s = r + k * a;
+    let signature = Ed25519Signature::try_from(&[nonce.to_bytes(), s.to_bytes()].concat()[..]);
+    (
+        Ed25519PublicKey::try_from(&public_key.to_bytes()[..]).unwrap(),
+        signature.unwrap(),
+        k,
+    )
+}
+
+#[test]
+fn batch_verify_arbitrary_msgs_torsion() {
+    let order_8 = CompressedEdwardsY(EIGHT_TORSION[1]).decompress().unwrap();
+    let order_4 = CompressedEdwardsY(EIGHT_TORSION[2]).decompress().unwrap();
+    let identity = EdwardsPoint::default();
+    let message = b"batch";
+
+    // A public key with a small-order component, with a signature which is valid under strict
+    // verification because k kills the small-order component
+    let valid = (1u64..)
+        .map(|r| {
+            sign_with_torsion(
+                Scalar::from(7u64),
+                order_8,
+                Scalar::from(r),
+                identity,
+                message,
+            )
+        })
+        .find(|(_, _, k)| k.to_bytes()[0] % 8 == 0)
+        .unwrap();
+    assert!(valid.1.verify_arbitrary_msg(message, &valid.0).is_ok());
+    let honest = sign_with_torsion(
+        Scalar::from(11u64),
+        identity,
+        Scalar::from(13u64),
+        identity,
+        message,
+    );
+    assert!(Ed25519Signature::batch_verify_arbitrary_msgs(&[
+        (message, &valid.0, &valid.1),
+        (message, &honest.0, &honest.1),
+    ])
+    .is_ok());
+
+    // Two invalid signatures whose small-order components cancel out
+    let invalid_1 = sign_with_torsion(
+        Scalar::from(3u64),
+        identity,
+        Scalar::from(5u64),
+        order_4,
+        message,
+    );
+    let invalid_2 = sign_with_torsion(
+        Scalar::from(17u64),
+        identity,
+        Scalar::from(19u64),
+        order_4.neg(),
+        message,
+    );
+    assert!(invalid_1
+        .1
+        .verify_arbitrary_msg(message, &invalid_1.0)
+        .is_err());
+    assert!(invalid_2
+        .1
+        .verify_arbitrary_msg(message, &invalid_2.0)
+        .is_err());
+    for _ in 0..16 {
+        assert!(Ed25519Signature::batch_verify_arbitrary_msgs(&[
+            (message, &invalid_1.0, &invalid_1.1),
+            (message, &invalid_2.0, &invalid_2.1),
+            (message, &honest.0, &honest.1),
+        ])
+        .is_err());
+    }
+}
+
 // The 8-torsion subgroup E[8].
 //
 // In the case of Curve25519, it is cyclic; the i-th element of
@@ -28,7 +28,7 @@ use aptos_api_types::{
     mime_types::{BCS, BCS_SIGNED_TRANSACTION, BCS_VIEW_FUNCTION, JSON},
     AptosError, BcsBlock, Block, GasEstimation, HexEncodedBytes, IndexResponse, MoveModuleId,
     TransactionData, TransactionOnChainData, TransactionsBatchSubmissionResult, UserTransaction,
-    VersionedEvent, ViewFunction, ViewRequest,
+    VersionedEvent, ViewFunction, ViewFunctionBcsResult, ViewFunctionResult, ViewRequest,
 };
 use aptos_crypto::HashValue;
 use aptos_logger::{debug, info, sample, sample::SampleRate};
@@ -331,6 +331,56 @@ impl Client {
         Ok(response.and_then(|bytes| bcs::from_bytes(&bytes))?)
     }
 
+    /// Executes several view functions against the same ledger version. A result is returned
+    /// for each of them, in order, so one failing function doesn't fail the others.
+    pub async fn view_batch(
+        &self,
+        requests: &[ViewRequest],
+        version: Option<u64>,
+    ) -> AptosResult<Response<Vec<ViewFunctionResult>>> {
+        let request = serde_json::to_string(requests)?;
+        let mut url = self.build_path("view/batch")?;
+        if let Some(version) = version {
+            url.set_query(Some(format!("ledger_version={}", version).as_str()));
+        }
+
+        let response = self
+            .inner
+            .post(url)
+            .header(CONTENT_TYPE, JSON)
+            .body(request)
+            .send()
+            .await?;
+
+        self.json(response).await
+    }
+
+    /// Same as [`Self::view_batch`], but each successful result holds the BCS encoded return
+    /// values of the function.
+    pub async fn view_batch_bcs(
+        &self,
+        requests: &[ViewFunction],
+        version: Option<u64>,
+    ) -> AptosResult<Response<Vec<ViewFunctionBcsResult>>> {
+        let txn_payload = bcs::to_bytes(requests)?;
+        let mut url = self.build_path("view/batch")?;
+        if let Some(version) = version {
+            url.set_query(Some(format!("ledger_version={}", version).as_str()));
+        }
+
+        let response = self
+            .inner
+            .post(url)
+            .header(CONTENT_TYPE, BCS_VIEW_FUNCTION)
+            .header(ACCEPT, BCS)
+            .body(txn_payload)
+            .send()
+            .await?;
+
+        let response = self.check_and_parse_bcs_response(response).await?;
+        Ok(response.and_then(|bytes| bcs::from_bytes(&bytes))?)
+    }
+
     pub async fn simulate(
         &self,
         txn: &SignedTransaction,
@@ -280,6 +280,9 @@ impl From<RestError> for ApiError {
                 AptosErrorCode::StateValueNotFound => {
                     ApiError::StateValueNotFound(Some(err.error.message))
                 },
+                AptosErrorCode::StateKeyNotTracked => {
+                    ApiError::InvalidInput(Some(err.error.message))
+                },
                 AptosErrorCode::VersionPruned => ApiError::VersionPruned(Some(err.error.message)),
                 AptosErrorCode::BlockPruned => ApiError::BlockPruned(Some(err.error.message)),
                 AptosErrorCode::InvalidInput => ApiError::InvalidInput(Some(err.error.message)),
@@ -114,6 +114,10 @@ pub enum CliError {
     SimulationError(String),
     #[error("Coverage failed with status: {0}")]
     CoverageError(String),
+    #[error("Move formatting failed: {0}")]
+    MoveFormatError(String),
+    #[error("Gas regressions found in tests: {0}")]
+    GasRegressionError(String),
 }
 
 impl CliError {
@@ -134,6 +138,8 @@ impl CliError {
             CliError::UnexpectedError(_) => "UnexpectedError",
             CliError::SimulationError(_) => "SimulationError",
             CliError::CoverageError(_) => "CoverageError",
+            CliError::MoveFormatError(_) => "MoveFormatError",
+            CliError::GasRegressionError(_) => "GasRegressionError",
         }
     }
 }
@@ -1055,6 +1061,21 @@ pub struct MovePackageDir {
     #[clap(long)]
     pub(crate) skip_fetch_latest_git_deps: bool,
 
+    /// Require the lock file `Move.lock` to be up to date
+    ///
+    /// The lock file records the revision and source digest of the git and on-chain
+    /// dependencies of the package. With this flag, a build fails instead of updating the
+    /// lock file if the resolved dependencies differ from it.
+    #[clap(long)]
+    pub locked: bool,
+
+    /// Resolve git and on-chain dependencies from the `vendor` directory of the package
+    ///
+    /// The directory is populated by `aptos move vendor`. Vendored dependencies are never
+    /// downloaded, which allows building without a network connection.
+    #[clap(long)]
+    pub vendored: bool,
+
     /// Specify the version of the bytecode the compiler is going to emit.
     #[clap(long)]
     pub bytecode_version: Option<u32>,
@@ -1090,6 +1111,8 @@ impl MovePackageDir {
             output_dir: None,
             named_addresses: Default::default(),
             skip_fetch_latest_git_deps: true,
+            locked: false,
+            vendored: false,
             bytecode_version: None,
             compiler_version: None,
             language_version: None,
@@ -994,6 +994,8 @@ impl CliCommand<()> for GenerateUpgradeProposal {
         let options = included_artifacts.build_options(
             move_options.dev,
             move_options.skip_fetch_latest_git_deps,
+            move_options.locked,
+            move_options.vendored,
             move_options.named_addresses(),
             move_options.bytecode_version,
             move_options.compiler_version,
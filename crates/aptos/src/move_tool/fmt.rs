@@ -0,0 +1,79 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::types::{CliCommand, CliError, CliTypedResult, MovePackageDir};
+use async_trait::async_trait;
+use clap::Parser;
+use move_command_line_common::files::find_move_filenames;
+use move_fmt::{format_source, FormatConfig};
+use move_package::source_package::layout::SourcePackageLayout;
+
+/// Formats the Move source files of a package
+///
+/// All `.move` files in the `sources`, `specifications`, `tests`, `scripts` and `examples`
+/// directories of the package are formatted in place. Formatting can be configured in the
+/// `[fmt]` section of `Move.toml`, with the options `indent_size` (default 4) and
+/// `max_blank_lines` (default 1).
+#[derive(Parser)]
+pub struct FmtPackage {
+    /// Only check whether the files are formatted, without changing them
+    ///
+    /// Fails if any file is not formatted, which is useful in CI.
+    #[clap(long)]
+    pub check: bool,
+
+    #[clap(flatten)]
+    pub(crate) move_options: MovePackageDir,
+}
+
+#[async_trait]
+impl CliCommand<Vec<String>> for FmtPackage {
+    fn command_name(&self) -> &'static str {
+        "FmtPackage"
+    }
+
+    /// Returns the files which were changed, or in check mode, which are not formatted.
+    async fn execute(self) -> CliTypedResult<Vec<String>> {
+        let package_path =
+            SourcePackageLayout::try_find_root(&self.move_options.get_package_path()?)
+                .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+        let config =
+            FormatConfig::from_manifest(&package_path.join(SourcePackageLayout::Manifest.path()))
+                .map_err(|err| CliError::MoveFormatError(format!("{:#}", err)))?;
+        let source_dirs = [
+            SourcePackageLayout::Sources,
+            SourcePackageLayout::Specifications,
+            SourcePackageLayout::Tests,
+            SourcePackageLayout::Scripts,
+            SourcePackageLayout::Examples,
+        ]
+        .iter()
+        .map(|layout| package_path.join(layout.path()))
+        .filter(|path| path.exists())
+        .collect::<Vec<_>>();
+        let files = find_move_filenames(&source_dirs, false)
+            .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+
+        let mut unformatted = vec![];
+        for file in files {
+            let source = std::fs::read_to_string(&file)
+                .map_err(|err| CliError::UnableToReadFile(file.clone(), err.to_string()))?;
+            let formatted = format_source(&file, &source, &config)
+                .map_err(|err| CliError::MoveFormatError(format!("{:#}", err)))?;
+            if formatted != source {
+                if !self.check {
+                    std::fs::write(&file, formatted)
+                        .map_err(|err| CliError::IO(file.clone(), err))?;
+                }
+                unformatted.push(file);
+            }
+        }
+        if self.check && !unformatted.is_empty() {
+            return Err(CliError::MoveFormatError(format!(
+                "the following files are not formatted, run `aptos move fmt` to format them: {}",
+                unformatted.join(", ")
+            )));
+        }
+        Ok(unformatted)
+    }
+}
@@ -0,0 +1,50 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::types::{CliCommand, CliError, CliTypedResult, MovePackageDir};
+use aptos_framework::{BuildOptions, BuiltPackage};
+use async_trait::async_trait;
+use clap::Parser;
+use move_compiler_v2::Experiment;
+use move_model::metadata::CompilerVersion;
+
+/// Runs lints on a package
+///
+/// The package is compiled with compiler v2 and the lints are reported as warnings, or as
+/// errors for lints set to `deny`. Lint levels can be configured in the `[lints]` section of
+/// `Move.toml`, as in `needless_borrow = "allow"`, and individual lints can be suppressed for
+/// a module or function with the attribute `#[lint::skip(<lint name>, ..)]`.
+#[derive(Parser)]
+pub struct LintPackage {
+    #[clap(flatten)]
+    pub(crate) move_options: MovePackageDir,
+}
+
+#[async_trait]
+impl CliCommand<&'static str> for LintPackage {
+    fn command_name(&self) -> &'static str {
+        "LintPackage"
+    }
+
+    async fn execute(self) -> CliTypedResult<&'static str> {
+        let move_options = &self.move_options;
+        let build_options = BuildOptions {
+            dev: move_options.dev,
+            install_dir: move_options.output_dir.clone(),
+            named_addresses: move_options.named_addresses(),
+            skip_fetch_latest_git_deps: move_options.skip_fetch_latest_git_deps,
+            locked: move_options.locked,
+            vendored: move_options.vendored,
+            bytecode_version: move_options.bytecode_version,
+            compiler_version: Some(CompilerVersion::V2_0),
+            language_version: move_options.language_version,
+            skip_attribute_checks: move_options.skip_attribute_checks,
+            check_test_code: move_options.check_test_code,
+            experiments: vec![Experiment::LINT_CHECKS.to_string()],
+            ..BuildOptions::default()
+        };
+        BuiltPackage::build(move_options.get_package_path()?, build_options)
+            .map_err(|e| CliError::MoveCompilationError(format!("{:#}", e)))?;
+        Ok("succeeded")
+    }
+}
@@ -22,6 +22,7 @@ use crate::{
         bytecode::{Decompile, Disassemble},
         coverage::SummaryCoverage,
         manifest::{Dependency, ManifestNamedAddress, MovePackageManifest, PackageInfo},
+        unit_test_gas_profiling::UnitTestGasProfiler,
     },
     CliCommand, CliResult,
 };
@@ -30,7 +31,7 @@ use aptos_framework::{
     docgen::DocgenOptions, extended_checks, natives::code::UpgradePolicy, prover::ProverOptions,
     BuildOptions, BuiltPackage,
 };
-use aptos_gas_schedule::{MiscGasParameters, NativeGasParameters};
+use aptos_gas_schedule::{InitialGasSchedule, MiscGasParameters, NativeGasParameters};
 use aptos_move_debugger::aptos_debugger::AptosDebugger;
 use aptos_rest_client::{
     aptos_api_types::{EntryFunctionId, HexEncodedBytes, IdentifierWrapper, MoveModuleId},
@@ -68,10 +69,14 @@ use url::Url;
 mod aptos_debug_natives;
 mod bytecode;
 pub mod coverage;
+pub mod fmt;
+pub mod lint;
 mod manifest;
+pub mod mutate;
 pub mod package_hooks;
 mod show;
 pub mod stored_package;
+mod unit_test_gas_profiling;
 
 /// Tool for Move related operations
 ///
@@ -93,8 +98,11 @@ pub enum MoveTool {
     Decompile(Decompile),
     Document(DocumentPackage),
     Download(DownloadPackage),
+    Fmt(fmt::FmtPackage),
     Init(InitPackage),
+    Lint(lint::LintPackage),
     List(ListPackage),
+    Mutate(mutate::MutatePackage),
     Prove(ProvePackage),
     Publish(PublishPackage),
     Run(RunFunction),
@@ -102,6 +110,7 @@ pub enum MoveTool {
     #[clap(subcommand, hide = true)]
     Show(show::ShowTool),
     Test(TestPackage),
+    Vendor(VendorPackage),
     VerifyPackage(VerifyPackage),
     View(ViewFunction),
     Replay(Replay),
@@ -126,14 +135,18 @@ impl MoveTool {
             MoveTool::Decompile(tool) => tool.execute_serialized().await,
             MoveTool::Document(tool) => tool.execute_serialized().await,
             MoveTool::Download(tool) => tool.execute_serialized().await,
+            MoveTool::Fmt(tool) => tool.execute_serialized().await,
             MoveTool::Init(tool) => tool.execute_serialized_success().await,
+            MoveTool::Lint(tool) => tool.execute_serialized().await,
             MoveTool::List(tool) => tool.execute_serialized().await,
+            MoveTool::Mutate(tool) => tool.execute_serialized().await,
             MoveTool::Prove(tool) => tool.execute_serialized().await,
             MoveTool::Publish(tool) => tool.execute_serialized().await,
             MoveTool::Run(tool) => tool.execute_serialized().await,
             MoveTool::RunScript(tool) => tool.execute_serialized().await,
             MoveTool::Show(tool) => tool.execute_serialized().await,
             MoveTool::Test(tool) => tool.execute_serialized().await,
+            MoveTool::Vendor(tool) => tool.execute_serialized().await,
             MoveTool::VerifyPackage(tool) => tool.execute_serialized().await,
             MoveTool::View(tool) => tool.execute_serialized().await,
             MoveTool::Replay(tool) => tool.execute_serialized().await,
@@ -324,12 +337,15 @@ impl CliCommand<Vec<String>> for CompilePackage {
     async fn execute(self) -> CliTypedResult<Vec<String>> {
         let build_options = BuildOptions {
             install_dir: self.move_options.output_dir.clone(),
+            update_lock_file: true,
             ..self
                 .included_artifacts_args
                 .included_artifacts
                 .build_options(
                     self.move_options.dev,
                     self.move_options.skip_fetch_latest_git_deps,
+                    self.move_options.locked,
+                    self.move_options.vendored,
                     self.move_options.named_addresses(),
                     self.move_options.bytecode_version,
                     self.move_options.compiler_version,
@@ -393,6 +409,8 @@ impl CompileScript {
             ..IncludedArtifacts::None.build_options(
                 self.move_options.dev,
                 self.move_options.skip_fetch_latest_git_deps,
+                self.move_options.locked,
+                self.move_options.vendored,
                 self.move_options.named_addresses(),
                 self.move_options.bytecode_version,
                 self.move_options.compiler_version,
@@ -462,6 +480,29 @@ pub struct TestPackage {
     /// Dump storage state on failure.
     #[clap(long = "dump")]
     pub dump_state: bool,
+
+    /// Run the tests under the gas profiler, charging gas according to the latest gas schedule
+    ///
+    /// A gas report with flamegraphs is generated for every test in `gas-profiling/unit-tests`
+    /// in the package directory, along with a summary of the most expensive functions across all
+    /// the tests. The tests are given an unbounded gas budget.
+    #[clap(long)]
+    pub profile_gas: bool,
+
+    /// Compare the gas costs of the tests against a baseline saved by an earlier run
+    ///
+    /// A gas diff report is generated for every test found in the baseline.
+    #[clap(long, requires = "profile_gas", value_parser)]
+    pub gas_baseline: Option<PathBuf>,
+
+    /// Save the gas costs of the tests to a file, to be used as the baseline of later runs
+    #[clap(long, requires = "profile_gas", value_parser)]
+    pub save_gas_baseline: Option<PathBuf>,
+
+    /// Fail if the gas cost of any test went up by more than this percentage
+    /// compared to the baseline
+    #[clap(long, requires = "gas_baseline")]
+    pub max_gas_increase_percentage: Option<f64>,
 }
 
 #[async_trait]
@@ -479,6 +520,9 @@ impl CliCommand<&'static str> for TestPackage {
             full_model_generation: self.move_options.check_test_code,
             install_dir: self.move_options.output_dir.clone(),
             skip_fetch_latest_git_deps: self.move_options.skip_fetch_latest_git_deps,
+            locked: self.move_options.locked,
+            vendored: self.move_options.vendored,
+            update_lock_file: true,
             compiler_config: CompilerConfig {
                 known_attributes: known_attributes.clone(),
                 skip_attribute_checks: self.move_options.skip_attribute_checks,
@@ -489,6 +533,13 @@ impl CliCommand<&'static str> for TestPackage {
         };
 
         let path = self.move_options.get_package_path()?;
+        let (native_gas_params, misc_gas_params) = if self.profile_gas {
+            (NativeGasParameters::initial(), MiscGasParameters::initial())
+        } else {
+            // TODO(Gas): we may want to switch to non-zero costs in the future
+            (NativeGasParameters::zeros(), MiscGasParameters::zeros())
+        };
+        let gas_profiler = self.profile_gas.then(UnitTestGasProfiler::default);
         let result = move_cli::base::test::run_move_unit_tests(
             path.as_path(),
             config.clone(),
@@ -497,19 +548,27 @@ impl CliCommand<&'static str> for TestPackage {
                 report_stacktrace_on_abort: true,
                 report_storage_on_error: self.dump_state,
                 ignore_compile_warnings: self.ignore_compile_warnings,
+                execution_hook: gas_profiler
+                    .as_ref()
+                    .map(UnitTestGasProfiler::execution_hook),
                 ..UnitTestingConfig::default_with_bound(None)
             },
-            // TODO(Gas): we may want to switch to non-zero costs in the future
-            aptos_debug_natives::aptos_debug_natives(
-                NativeGasParameters::zeros(),
-                MiscGasParameters::zeros(),
-            ),
+            aptos_debug_natives::aptos_debug_natives(native_gas_params, misc_gas_params),
             aptos_test_feature_flags_genesis(),
             None,
             self.compute_coverage,
             &mut std::io::stdout(),
-        )
-        .map_err(|err| CliError::UnexpectedError(format!("Failed to run tests: {:#}", err)))?;
+        );
+        if let Some(gas_profiler) = gas_profiler {
+            gas_profiler.finish(
+                &path,
+                self.gas_baseline.as_deref(),
+                self.save_gas_baseline.as_deref(),
+                self.max_gas_increase_percentage,
+            )?;
+        }
+        let result = result
+            .map_err(|err| CliError::UnexpectedError(format!("Failed to run tests: {:#}", err)))?;
 
         // Print coverage summary if --coverage is set
         if self.compute_coverage {
@@ -613,12 +672,15 @@ impl CliCommand<&'static str> for DocumentPackage {
             named_addresses: move_options.named_addresses(),
             docgen_options: Some(docgen_options),
             skip_fetch_latest_git_deps: move_options.skip_fetch_latest_git_deps,
+            locked: move_options.locked,
+            vendored: move_options.vendored,
             bytecode_version: move_options.bytecode_version,
             compiler_version: move_options.compiler_version,
             language_version: move_options.language_version,
             skip_attribute_checks: move_options.skip_attribute_checks,
             check_test_code: move_options.check_test_code,
             known_attributes: extended_checks::get_all_attribute_names().clone(),
+            experiments: vec![],
         };
         BuiltPackage::build(move_options.get_package_path()?, build_options)?;
         Ok("succeeded")
@@ -681,6 +743,8 @@ impl TryInto<PackagePublicationData> for &PublishPackage {
             .build_options(
                 self.move_options.dev,
                 self.move_options.skip_fetch_latest_git_deps,
+                self.move_options.locked,
+                self.move_options.vendored,
                 self.move_options.named_addresses(),
                 self.move_options.bytecode_version,
                 self.move_options.compiler_version,
@@ -752,6 +816,8 @@ impl IncludedArtifacts {
         self,
         dev: bool,
         skip_fetch_latest_git_deps: bool,
+        locked: bool,
+        vendored: bool,
         named_addresses: BTreeMap<String, AccountAddress>,
         bytecode_version: Option<u32>,
         compiler_version: Option<CompilerVersion>,
@@ -770,6 +836,8 @@ impl IncludedArtifacts {
                 with_error_map: true,
                 named_addresses,
                 skip_fetch_latest_git_deps,
+                locked,
+                vendored,
                 bytecode_version,
                 compiler_version,
                 language_version,
@@ -786,6 +854,8 @@ impl IncludedArtifacts {
                 with_error_map: true,
                 named_addresses,
                 skip_fetch_latest_git_deps,
+                locked,
+                vendored,
                 bytecode_version,
                 compiler_version,
                 language_version,
@@ -802,6 +872,8 @@ impl IncludedArtifacts {
                 with_error_map: true,
                 named_addresses,
                 skip_fetch_latest_git_deps,
+                locked,
+                vendored,
                 bytecode_version,
                 compiler_version,
                 language_version,
@@ -922,6 +994,8 @@ impl CliCommand<TransactionSummary> for CreateObjectAndPublishPackage {
             .build_options(
                 self.move_options.dev,
                 self.move_options.skip_fetch_latest_git_deps,
+                self.move_options.locked,
+                self.move_options.vendored,
                 self.move_options.named_addresses(),
                 self.move_options.bytecode_version,
                 self.move_options.compiler_version,
@@ -999,6 +1073,8 @@ impl CliCommand<TransactionSummary> for UpgradeObjectPackage {
             .build_options(
                 self.move_options.dev,
                 self.move_options.skip_fetch_latest_git_deps,
+                self.move_options.locked,
+                self.move_options.vendored,
                 self.move_options.named_addresses(),
                 self.move_options.bytecode_version,
                 self.move_options.compiler_version,
@@ -1124,6 +1200,8 @@ impl CliCommand<TransactionSummary> for CreateResourceAccountAndPublishPackage {
         let options = included_artifacts_args.included_artifacts.build_options(
             move_options.dev,
             move_options.skip_fetch_latest_git_deps,
+            move_options.locked,
+            move_options.vendored,
             move_options.named_addresses(),
             move_options.bytecode_version,
             move_options.compiler_version,
@@ -1276,6 +1354,8 @@ impl CliCommand<&'static str> for VerifyPackage {
             ..self.included_artifacts.build_options(
                 self.move_options.dev,
                 self.move_options.skip_fetch_latest_git_deps,
+                self.move_options.locked,
+                self.move_options.vendored,
                 self.move_options.named_addresses(),
                 self.move_options.bytecode_version,
                 self.move_options.compiler_version,
@@ -1422,6 +1502,42 @@ impl CliCommand<&'static str> for CleanPackage {
     }
 }
 
+/// Copies the git and on-chain dependencies of a package into its `vendor` directory
+///
+/// Building with `--vendored` resolves these dependencies from the `vendor` directory instead of
+/// downloading them. The lock file `Move.lock` is updated with the revisions and source digests
+/// of the vendored packages, against which the vendored sources are verified on every build.
+/// Dev dependencies are vendored as well, so that tests can be run offline.
+#[derive(Parser)]
+pub struct VendorPackage {
+    #[clap(flatten)]
+    pub(crate) move_options: MovePackageDir,
+}
+
+#[async_trait]
+impl CliCommand<Vec<String>> for VendorPackage {
+    fn command_name(&self) -> &'static str {
+        "VendorPackage"
+    }
+
+    async fn execute(self) -> CliTypedResult<Vec<String>> {
+        let config = BuildConfig {
+            dev_mode: true,
+            additional_named_addresses: self.move_options.named_addresses(),
+            skip_fetch_latest_git_deps: self.move_options.skip_fetch_latest_git_deps,
+            locked: self.move_options.locked,
+            ..Default::default()
+        };
+        let vendored = config
+            .vendor_dependencies(
+                &self.move_options.get_package_path()?,
+                &mut std::io::stderr(),
+            )
+            .map_err(|err| CliError::UnexpectedError(format!("{:#}", err)))?;
+        Ok(vendored.into_iter().map(|name| name.to_string()).collect())
+    }
+}
+
 /// Run a Move function
 #[derive(Parser)]
 pub struct RunFunction {
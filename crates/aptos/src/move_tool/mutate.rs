@@ -0,0 +1,382 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::types::{CliCommand, CliError, CliTypedResult, MovePackageDir};
+use aptos_framework::extended_checks;
+use async_trait::async_trait;
+use clap::Parser;
+use futures::StreamExt;
+use move_compiler_v2::mutation_generator::{generate_mutations, Mutation};
+use move_model::metadata::CompilerVersion;
+use move_package::{
+    source_package::layout::SourcePackageLayout, BuildConfig, CompilerConfig, ModelConfig,
+};
+use serde::Serialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Stdio,
+};
+use tempfile::TempDir;
+use tokio::process::Command;
+
+/// Runs mutation testing for a Move package
+///
+/// Source-level mutations (flipped comparisons, swapped arithmetic operators, changed
+/// literals and dropped `assert!`s) are applied to the non-test code of the package, one at
+/// a time, and the unit tests are run against each mutant. A mutant which is not detected
+/// by any failing test is reported as surviving, which indicates behavior the tests do not
+/// check.
+#[derive(Parser)]
+pub struct MutatePackage {
+    /// A filter string to determine which unit tests to run
+    #[clap(long, short)]
+    pub filter: Option<String>,
+
+    /// Only mutate source files whose path contains this string
+    #[clap(long)]
+    pub mutate_filter: Option<String>,
+
+    /// The number of mutants to test in parallel
+    ///
+    /// Defaults to the number of available CPUs.
+    #[clap(long)]
+    pub jobs: Option<usize>,
+
+    /// The maximum number of instructions that can be executed by a test
+    ///
+    /// Mutations may turn terminating loops into infinite ones, so this bound should not
+    /// be set much higher than needed by the unmutated tests.
+    #[clap(long, default_value_t = 100000)]
+    pub instructions: u64,
+
+    #[clap(flatten)]
+    pub(crate) move_options: MovePackageDir,
+}
+
+/// Summary of a mutation testing run
+#[derive(Debug, Serialize)]
+pub struct MutationTestSummary {
+    /// Total number of mutants tested
+    pub total: usize,
+    /// Number of mutants detected by a failing test
+    pub killed: usize,
+    /// Number of mutants which did not compile, and hence were not tested
+    pub failed_to_compile: usize,
+    /// Mutants not detected by any test
+    pub survived: Vec<SurvivingMutant>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct SurvivingMutant {
+    pub location: String,
+    pub function: String,
+    pub mutation: String,
+    pub original: String,
+    pub replacement: String,
+}
+
+impl From<&Mutation> for SurvivingMutant {
+    fn from(mutation: &Mutation) -> Self {
+        Self {
+            location: format!(
+                "{}:{}:{}",
+                mutation.file_name, mutation.line, mutation.column
+            ),
+            function: mutation.function.clone(),
+            mutation: mutation.kind.to_string(),
+            original: mutation.original.clone(),
+            replacement: mutation.replacement.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl CliCommand<MutationTestSummary> for MutatePackage {
+    fn command_name(&self) -> &'static str {
+        "MutatePackage"
+    }
+
+    async fn execute(self) -> CliTypedResult<MutationTestSummary> {
+        let package_dir =
+            SourcePackageLayout::try_find_root(&self.move_options.get_package_path()?)
+                .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+        let package_dir = package_dir
+            .canonicalize()
+            .map_err(|err| CliError::IO(package_dir.display().to_string(), err))?;
+
+        if !self.run_tests(&package_dir).await? {
+            return Err(CliError::UnexpectedError(
+                "Unit tests must pass before running mutation testing".to_string(),
+            ));
+        }
+
+        let mutations = self.mutations(&package_dir)?;
+        let jobs = self.jobs.unwrap_or_else(|| {
+            std::thread::available_parallelism()
+                .map(|n| n.get())
+                .unwrap_or(1)
+        });
+        println!(
+            "Testing {} mutants with {} parallel jobs",
+            mutations.len(),
+            jobs
+        );
+
+        let this = &self;
+        let package_dir = &package_dir;
+        let results = futures::stream::iter(mutations.iter())
+            .map(|mutation| async move {
+                let outcome = this.test_mutant(package_dir, mutation).await?;
+                if outcome == MutantOutcome::Survived {
+                    println!("SURVIVED {}", mutation);
+                }
+                Ok::<_, CliError>((mutation, outcome))
+            })
+            .buffer_unordered(jobs.max(1))
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut killed = 0;
+        let mut failed_to_compile = 0;
+        let mut survived = vec![];
+        for result in results {
+            match result? {
+                (_, MutantOutcome::Killed) => killed += 1,
+                (_, MutantOutcome::FailedToCompile) => failed_to_compile += 1,
+                (mutation, MutantOutcome::Survived) => survived.push(mutation),
+            }
+        }
+        // Report in source order, independent of the order the jobs finished in.
+        survived.sort();
+        Ok(MutationTestSummary {
+            total: mutations.len(),
+            killed,
+            failed_to_compile,
+            survived: survived.into_iter().map(SurvivingMutant::from).collect(),
+        })
+    }
+}
+
+impl MutatePackage {
+    /// Builds the model of the package with compiler v2 and generates mutations for it.
+    fn mutations(&self, package_dir: &Path) -> CliTypedResult<Vec<Mutation>> {
+        let build_config = BuildConfig {
+            dev_mode: true,
+            additional_named_addresses: self.move_options.named_addresses(),
+            skip_fetch_latest_git_deps: self.move_options.skip_fetch_latest_git_deps,
+            locked: self.move_options.locked,
+            vendored: self.move_options.vendored,
+            compiler_config: CompilerConfig {
+                known_attributes: extended_checks::get_all_attribute_names().clone(),
+                skip_attribute_checks: self.move_options.skip_attribute_checks,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let env = build_config
+            .move_model_for_package(package_dir, ModelConfig {
+                all_files_as_targets: false,
+                target_filter: None,
+                compiler_version: CompilerVersion::V2_0,
+                language_version: self.move_options.language_version.unwrap_or_default(),
+            })
+            .map_err(|err| CliError::MoveCompilationError(format!("{:#}", err)))?;
+        if env.has_errors() {
+            return Err(CliError::MoveCompilationError(
+                "Failed to build the package model".to_string(),
+            ));
+        }
+        Ok(generate_mutations(&env)
+            .into_iter()
+            .filter(|mutation| {
+                self.mutate_filter
+                    .as_ref()
+                    .map_or(true, |filter| mutation.file_name.contains(filter.as_str()))
+            })
+            .collect())
+    }
+
+    /// Copies the package into a temporary directory, applies the mutation and runs the unit
+    /// tests against the mutant.
+    async fn test_mutant(
+        &self,
+        package_dir: &Path,
+        mutation: &Mutation,
+    ) -> CliTypedResult<MutantOutcome> {
+        let source_path = PathBuf::from(&mutation.file_name)
+            .canonicalize()
+            .map_err(|err| CliError::IO(mutation.file_name.clone(), err))?;
+        let relative_path = source_path.strip_prefix(package_dir).map_err(|_| {
+            CliError::UnexpectedError(format!(
+                "Source file {} is not in package {}",
+                mutation.file_name,
+                package_dir.display()
+            ))
+        })?;
+        let temp_dir =
+            TempDir::new().map_err(|err| CliError::IO("temporary directory".to_string(), err))?;
+        copy_package(package_dir, temp_dir.path())?;
+
+        let source = fs::read_to_string(&source_path).map_err(|err| {
+            CliError::UnableToReadFile(mutation.file_name.clone(), err.to_string())
+        })?;
+        let mutant_path = temp_dir.path().join(relative_path);
+        fs::write(&mutant_path, mutation.apply(&source))
+            .map_err(|err| CliError::IO(mutant_path.display().to_string(), err))?;
+
+        if self.run_tests(temp_dir.path()).await? {
+            Ok(MutantOutcome::Survived)
+        } else if self.compiles(temp_dir.path()).await? {
+            Ok(MutantOutcome::Killed)
+        } else {
+            Ok(MutantOutcome::FailedToCompile)
+        }
+    }
+
+    /// Runs the unit tests of the package in a separate process, so that compilation errors
+    /// and aborts in a mutant cannot affect the other ones. Returns true if all tests passed.
+    /// Note that a package which fails to compile fails the tests as well.
+    async fn run_tests(&self, package_dir: &Path) -> CliTypedResult<bool> {
+        let mut command = self.move_command("test", package_dir)?;
+        command
+            .arg("--ignore-compile-warnings")
+            .arg("--instructions")
+            .arg(self.instructions.to_string());
+        if let Some(filter) = &self.filter {
+            command.arg("--filter").arg(filter);
+        }
+        run_command(command).await
+    }
+
+    /// Compiles the package (in dev mode, as the tests do) in a separate process. Returns true
+    /// if the package compiled.
+    async fn compiles(&self, package_dir: &Path) -> CliTypedResult<bool> {
+        let mut command = self.move_command("compile", package_dir)?;
+        command.arg("--dev");
+        run_command(command).await
+    }
+
+    /// Creates a `move` subcommand of this CLI for the package, passing on the package options.
+    fn move_command(&self, subcommand: &str, package_dir: &Path) -> CliTypedResult<Command> {
+        let exe = std::env::current_exe()
+            .map_err(|err| CliError::UnexpectedError(format!("Cannot find executable: {}", err)))?;
+        let mut command = Command::new(exe);
+        command
+            .args(["move", subcommand])
+            .arg("--package-dir")
+            .arg(package_dir);
+        let named_addresses = self
+            .move_options
+            .named_addresses()
+            .into_iter()
+            .map(|(name, address)| format!("{}={}", name, address.to_hex_literal()))
+            .collect::<Vec<_>>();
+        if !named_addresses.is_empty() {
+            command
+                .arg("--named-addresses")
+                .arg(named_addresses.join(","));
+        }
+        if self.move_options.skip_fetch_latest_git_deps {
+            command.arg("--skip-fetch-latest-git-deps");
+        }
+        if self.move_options.locked {
+            command.arg("--locked");
+        }
+        if self.move_options.vendored {
+            command.arg("--vendored");
+        }
+        if self.move_options.skip_attribute_checks {
+            command.arg("--skip-attribute-checks");
+        }
+        if let Some(compiler_version) = self.move_options.compiler_version {
+            command
+                .arg("--compiler-version")
+                .arg(compiler_version.to_string());
+        }
+        if let Some(language_version) = self.move_options.language_version {
+            command
+                .arg("--language-version")
+                .arg(language_version.to_string());
+        }
+        Ok(command)
+    }
+}
+
+/// The outcome of testing a single mutant
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum MutantOutcome {
+    /// A test failed on the mutant
+    Killed,
+    /// All tests passed on the mutant
+    Survived,
+    /// The mutant did not compile, so it could not be tested
+    FailedToCompile,
+}
+
+/// Runs the command with its output discarded. Returns true if it succeeded.
+async fn run_command(mut command: Command) -> CliTypedResult<bool> {
+    let status = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .await
+        .map_err(|err| CliError::UnexpectedError(format!("Failed to run command: {}", err)))?;
+    Ok(status.success())
+}
+
+/// Recursively copies the package, skipping build artifacts. Local dependencies in the
+/// manifest are made absolute, so they still resolve from the new location.
+fn copy_package(from: &Path, to: &Path) -> CliTypedResult<()> {
+    copy_dir(from, to)?;
+    let manifest_path = from.join(SourcePackageLayout::Manifest.path());
+    let manifest = fs::read_to_string(&manifest_path).map_err(|err| {
+        CliError::UnableToReadFile(manifest_path.display().to_string(), err.to_string())
+    })?;
+    let mut manifest = manifest
+        .parse::<toml::Value>()
+        .map_err(|err| CliError::UnableToParse("Move.toml", err.to_string()))?;
+    for section in ["dependencies", "dev-dependencies"] {
+        let Some(deps) = manifest
+            .get_mut(section)
+            .and_then(|deps| deps.as_table_mut())
+        else {
+            continue;
+        };
+        for dep in deps.values_mut() {
+            if let Some(local) = dep.get_mut("local") {
+                if let Some(path) = local.as_str() {
+                    *local = toml::Value::String(from.join(path).display().to_string());
+                }
+            }
+        }
+    }
+    let manifest = toml::to_string(&manifest)
+        .map_err(|err| CliError::UnexpectedError(format!("Failed to write Move.toml: {}", err)))?;
+    let manifest_path = to.join(SourcePackageLayout::Manifest.path());
+    fs::write(&manifest_path, manifest)
+        .map_err(|err| CliError::IO(manifest_path.display().to_string(), err))
+}
+
+fn copy_dir(from: &Path, to: &Path) -> CliTypedResult<()> {
+    fs::create_dir_all(to).map_err(|err| CliError::IO(to.display().to_string(), err))?;
+    let entries =
+        fs::read_dir(from).map_err(|err| CliError::IO(from.display().to_string(), err))?;
+    for entry in entries {
+        let entry = entry.map_err(|err| CliError::IO(from.display().to_string(), err))?;
+        let path = entry.path();
+        let target = to.join(entry.file_name());
+        if path.is_dir() {
+            if entry.file_name() == "build" || entry.file_name().to_string_lossy().starts_with('.')
+            {
+                continue;
+            }
+            copy_dir(&path, &target)?;
+        } else {
+            fs::copy(&path, &target)
+                .map_err(|err| CliError::IO(path.display().to_string(), err))?;
+        }
+    }
+    Ok(())
+}
@@ -48,7 +48,8 @@ async fn maybe_download_package(info: &CustomDepInfo) -> anyhow::Result<()> {
         )
         .await?;
         let package = registry.get_package(info.package_name).await?;
-        package.save_package_to_disk(info.download_to.as_path())
+        package.save_package_to_disk(info.download_to.as_path())?;
+        package.verify_package_on_disk(info.download_to.as_path())
     } else {
         Ok(())
     }
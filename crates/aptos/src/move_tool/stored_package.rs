@@ -179,6 +179,28 @@ impl<'a> CachedPackageMetadata<'a> {
         Ok(())
     }
 
+    /// Verifies that a package saved with `save_package_to_disk` matches the source digest
+    /// published in the registry.
+    pub fn verify_package_on_disk(&self, path: &Path) -> anyhow::Result<()> {
+        if self.metadata.modules.iter().all(|m| m.source.is_empty()) {
+            bail!(
+                "Package `{}` was published without sources and cannot be used as a dependency",
+                self.metadata.name
+            )
+        }
+        let digest = move_package::resolution::lock_file::published_digest(path)?;
+        if digest != self.metadata.source_digest {
+            bail!(
+                "Source digest mismatch in package `{}`: the registry publishes '{}' but the \
+                 downloaded sources have '{}'",
+                self.metadata.name,
+                self.metadata.source_digest,
+                digest
+            )
+        }
+        Ok(())
+    }
+
     pub fn save_bytecode_to_disk(
         &self,
         path: &Path,
@@ -0,0 +1,360 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::common::{
+    types::{CliError, CliTypedResult},
+    utils::{create_dir_if_not_exist, read_from_file, write_to_file},
+};
+use aptos_gas_meter::{StandardGasAlgebra, StandardGasMeter};
+use aptos_gas_profiling::{GasCosts, GasProfileSummary, GasProfiler, TransactionGasLog};
+use aptos_gas_schedule::{AptosGasParameters, InitialGasSchedule, LATEST_GAS_FEATURE_VERSION};
+use aptos_vm_types::storage::StorageGasParameters;
+use move_core_types::{identifier::IdentStr, language_storage::ModuleId};
+use move_unit_test::test_runner::TestExecutionHook;
+use move_vm_runtime::session::Session;
+use std::{
+    collections::BTreeMap,
+    path::Path,
+    sync::{Arc, Mutex},
+};
+
+/// Maximum number of entries listed per table in the summary of the test suite.
+const MAX_SUMMARY_ENTRIES: usize = 50;
+
+/// Collects the gas logs of the unit tests of a package, by running them under the gas profiler
+/// with the latest gas schedule.
+#[derive(Default)]
+pub struct UnitTestGasProfiler {
+    logs: Arc<Mutex<Vec<(ModuleId, String, TransactionGasLog)>>>,
+}
+
+impl UnitTestGasProfiler {
+    /// Returns a hook which runs the unit tests under the gas profiler, to be set in the
+    /// `UnitTestingConfig`.
+    ///
+    /// The tests are given an unbounded gas budget: they are not subject to the limits of a
+    /// transaction, nor to the instruction bound of the test runner.
+    pub fn execution_hook(&self) -> TestExecutionHook {
+        let logs = self.logs.clone();
+        let mut vm_gas_params = AptosGasParameters::initial().vm;
+        vm_gas_params.txn.max_execution_gas = u64::MAX.into();
+        vm_gas_params.txn.max_io_gas = u64::MAX.into();
+
+        TestExecutionHook::new(
+            move |session: &mut Session,
+                  module_id: &ModuleId,
+                  function_name: &IdentStr,
+                  args: Vec<Vec<u8>>| {
+                let gas_meter = StandardGasMeter::new(StandardGasAlgebra::new(
+                    LATEST_GAS_FEATURE_VERSION,
+                    vm_gas_params.clone(),
+                    StorageGasParameters::latest(),
+                    u64::MAX,
+                ));
+                let mut gas_profiler = GasProfiler::new_function(
+                    gas_meter,
+                    module_id.clone(),
+                    function_name.to_owned(),
+                    vec![],
+                );
+
+                let result = session.execute_function_bypass_visibility(
+                    module_id,
+                    function_name,
+                    vec![], // no ty args, at least for now
+                    args,
+                    &mut gas_profiler,
+                );
+
+                let log = gas_profiler.finish();
+                let gas_used = u64::from(log.exec_io.total)
+                    .div_ceil(u64::from(log.exec_io.gas_scaling_factor));
+                logs.lock()
+                    .unwrap()
+                    .push((module_id.clone(), function_name.to_string(), log));
+
+                (result, gas_used)
+            },
+        )
+    }
+
+    /// Writes a gas report with flamegraphs for every test, along with a summary of the most
+    /// expensive functions across all of them, to `gas-profiling/unit-tests` in the package
+    /// directory.
+    ///
+    /// If a `baseline` saved by an earlier run is given, a gas diff report is written for every
+    /// test found in it, and an error is returned if the cost of any test went up by more than
+    /// `max_gas_increase_percentage`. The costs of the tests are saved to `save_baseline`.
+    pub fn finish(
+        self,
+        package_dir: &Path,
+        baseline: Option<&Path>,
+        save_baseline: Option<&Path>,
+        max_gas_increase_percentage: Option<f64>,
+    ) -> CliTypedResult<()> {
+        let mut logs = std::mem::take(&mut *self.logs.lock().unwrap());
+        logs.sort_by(|(module_id1, name1, _), (module_id2, name2, _)| {
+            (module_id1, name1).cmp(&(module_id2, name2))
+        });
+
+        let dir = package_dir.join("gas-profiling").join("unit-tests");
+        create_dir_if_not_exist(&dir)?;
+        for (module_id, name, log) in &logs {
+            let human_readable_name = human_readable_name(module_id, name);
+            log.generate_html_report(
+                dir.join(&human_readable_name),
+                format!("Gas Report - {}", human_readable_name),
+            )?;
+        }
+
+        let summary = GasProfileSummary::new(logs.iter().map(|(_, _, log)| log))
+            .to_text_report(MAX_SUMMARY_ENTRIES)
+            .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+        let summary_path = dir.join("summary.txt");
+        write_to_file(&summary_path, "gas summary", summary.as_bytes())?;
+
+        println!(
+            "Gas reports of {} tests saved to {}.",
+            logs.len(),
+            dir.display()
+        );
+        println!("Gas summary saved to {}.", summary_path.display());
+
+        let costs: BTreeMap<String, GasCosts> = logs
+            .iter()
+            .map(|(module_id, name, log)| (test_name(module_id, name), log.costs()))
+            .collect();
+
+        let mut regressions = vec![];
+        if let Some(baseline_path) = baseline {
+            let baseline: BTreeMap<String, GasCosts> =
+                serde_json::from_slice(&read_from_file(baseline_path)?)
+                    .map_err(|err| CliError::UnableToParse("gas baseline", err.to_string()))?;
+
+            let mut num_diffs = 0;
+            for (module_id, name, _) in &logs {
+                let test = test_name(module_id, name);
+                let Some(base) = baseline.get(&test) else {
+                    continue;
+                };
+                let diff = base.diff(&costs[&test]);
+                let report = diff
+                    .to_text_report()
+                    .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+                write_to_file(
+                    &dir.join(human_readable_name(module_id, name))
+                        .join("diff.txt"),
+                    "gas diff",
+                    report.as_bytes(),
+                )?;
+                num_diffs += 1;
+
+                if let Some(max_gas_increase_percentage) = max_gas_increase_percentage {
+                    regressions.extend(
+                        diff.regressions(max_gas_increase_percentage)
+                            .into_iter()
+                            .map(|entry| match entry.delta_percentage() {
+                                Some(percentage) => {
+                                    format!("{} ({} {:+.2}%)", test, entry.name, percentage)
+                                },
+                                None => format!("{} ({} up from zero)", test, entry.name),
+                            }),
+                    );
+                }
+            }
+
+            println!(
+                "Gas diffs of {} tests against the baseline saved to {}.",
+                num_diffs,
+                dir.display()
+            );
+            if num_diffs < logs.len() {
+                println!("{} tests are not in the baseline.", logs.len() - num_diffs);
+            }
+        }
+
+        if let Some(save_baseline) = save_baseline {
+            let bytes = serde_json::to_vec_pretty(&costs)
+                .map_err(|err| CliError::UnexpectedError(err.to_string()))?;
+            write_to_file(save_baseline, "gas baseline", &bytes)?;
+            println!("Gas baseline saved to {}.", save_baseline.display());
+        }
+
+        if regressions.is_empty() {
+            Ok(())
+        } else {
+            Err(CliError::GasRegressionError(regressions.join(", ")))
+        }
+    }
+}
+
+/// Identifies a test in a gas baseline.
+fn test_name(module_id: &ModuleId, name: &str) -> String {
+    format!("{}::{}", module_id.short_str_lossless(), name)
+}
+
+/// Name of the directory holding the gas reports of a test.
+fn human_readable_name(module_id: &ModuleId, name: &str) -> String {
+    let addr_short = module_id.address().short_str_lossless();
+    let addr_truncated = if addr_short.len() > 4 {
+        &addr_short[..4]
+    } else {
+        addr_short.as_str()
+    };
+    format!("0x{}-{}-{}", addr_truncated, module_id.name(), name)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        common::types::{CliCommand, CliError, MovePackageDir},
+        move_tool::TestPackage,
+    };
+    use std::fs;
+    use tempfile::TempDir;
+
+    const MOVE_TOML: &str = r#"
+[package]
+name = "GasProfiling"
+version = "0.0.0"
+
+[dependencies]
+MoveStdlib = { local = "STDLIB_DIR" }
+"#;
+
+    const PROFILED_MODULE: &str = r#"
+module 0xcafe::profiled {
+    #[test_only]
+    fun sum(n: u64): u64 {
+        let i = 0;
+        let s = 0;
+        while (i < n) {
+            s = s + i;
+            i = i + 1;
+        };
+        s
+    }
+
+    #[test]
+    fun test_long_loop() {
+        // Executes more instructions than the test runner allows without profiling.
+        assert!(sum(LOOP_COUNT) == EXPECTED_SUM, 0);
+    }
+
+    #[test]
+    fun test_vector() {
+        let v = std::vector::singleton(1);
+        std::vector::push_back(&mut v, 2);
+        assert!(std::vector::length(&v) == 2, 0);
+    }
+}
+"#;
+
+    fn write_package(package_dir: &TempDir, loop_count: u64) {
+        let stdlib_dir = aptos_framework::path_in_crate("move-stdlib");
+        fs::write(
+            package_dir.path().join("Move.toml"),
+            MOVE_TOML.replace("STDLIB_DIR", &stdlib_dir.display().to_string()),
+        )
+        .unwrap();
+        fs::create_dir_all(package_dir.path().join("sources")).unwrap();
+        fs::write(
+            package_dir.path().join("sources").join("profiled.move"),
+            PROFILED_MODULE
+                .replace("LOOP_COUNT", &loop_count.to_string())
+                .replace(
+                    "EXPECTED_SUM",
+                    &(loop_count * (loop_count - 1) / 2).to_string(),
+                ),
+        )
+        .unwrap();
+    }
+
+    fn test_package(package_dir: &TempDir, profile_gas: bool) -> TestPackage {
+        TestPackage {
+            filter: None,
+            ignore_compile_warnings: false,
+            move_options: MovePackageDir::new(package_dir.path().to_path_buf()),
+            instruction_execution_bound: 100_000,
+            compute_coverage: false,
+            dump_state: false,
+            profile_gas,
+            gas_baseline: None,
+            save_gas_baseline: None,
+            max_gas_increase_percentage: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_profile_unit_tests() {
+        let package_dir = TempDir::new().unwrap();
+        write_package(&package_dir, 100_000);
+
+        // The loop runs out of instructions under the test runner.
+        assert!(test_package(&package_dir, false).execute().await.is_err());
+        assert_eq!(
+            test_package(&package_dir, true).execute().await.unwrap(),
+            "Success"
+        );
+
+        // The reports are saved in the package directory.
+        let dir = package_dir.path().join("gas-profiling").join("unit-tests");
+        for test in ["test_long_loop", "test_vector"] {
+            assert!(dir
+                .join(format!("0xcafe-profiled-{}", test))
+                .join("index.html")
+                .exists());
+        }
+        let summary = fs::read_to_string(dir.join("summary.txt")).unwrap();
+        assert!(summary.contains("(2 runs)"));
+        assert!(summary.contains("0xcafe::profiled::sum"));
+        assert!(summary.contains("0x1::vector::singleton"));
+    }
+
+    #[tokio::test]
+    async fn test_profile_unit_tests_against_baseline() {
+        let package_dir = TempDir::new().unwrap();
+        let baseline_path = package_dir.path().join("baseline.json");
+        write_package(&package_dir, 100_000);
+
+        let mut save_baseline = test_package(&package_dir, true);
+        save_baseline.save_gas_baseline = Some(baseline_path.clone());
+        save_baseline.execute().await.unwrap();
+        let baseline: serde_json::Value =
+            serde_json::from_slice(&fs::read(&baseline_path).unwrap()).unwrap();
+        for test in ["test_long_loop", "test_vector"] {
+            let costs = &baseline[format!("0xcafe::profiled::{}", test)];
+            assert!(costs["execution_and_io"].as_u64().unwrap() > 0);
+        }
+
+        // Make one of the tests about 10% more expensive.
+        write_package(&package_dir, 110_000);
+        let compare = |max_gas_increase_percentage| {
+            let mut cmd = test_package(&package_dir, true);
+            cmd.gas_baseline = Some(baseline_path.clone());
+            cmd.max_gas_increase_percentage = max_gas_increase_percentage;
+            cmd.execute()
+        };
+
+        assert_eq!(compare(None).await.unwrap(), "Success");
+        assert_eq!(compare(Some(20.0)).await.unwrap(), "Success");
+        match compare(Some(5.0)).await {
+            Err(CliError::GasRegressionError(regressions)) => {
+                assert!(regressions.contains("0xcafe::profiled::test_long_loop"));
+                assert!(!regressions.contains("test_vector"));
+            },
+            result => panic!("Expected a gas regression, got {:?}", result),
+        }
+
+        let diff = fs::read_to_string(
+            package_dir
+                .path()
+                .join("gas-profiling")
+                .join("unit-tests")
+                .join("0xcafe-profiled-test_long_loop")
+                .join("diff.txt"),
+        )
+        .unwrap();
+        assert!(diff.contains("0xcafe::profiled::sum"));
+    }
+}
@@ -861,6 +861,10 @@ impl CliTestFramework {
             ignore_compile_warnings: false,
             compute_coverage: false,
             dump_state: false,
+            profile_gas: false,
+            gas_baseline: None,
+            save_gas_baseline: None,
+            max_gas_increase_percentage: None,
         }
         .execute()
         .await
@@ -1056,6 +1060,8 @@ impl CliTestFramework {
             output_dir: None,
             named_addresses: Self::named_addresses(account_strs),
             skip_fetch_latest_git_deps: true,
+            locked: false,
+            vendored: false,
             bytecode_version: None,
             compiler_version: None,
             language_version: None,
@@ -15,7 +15,10 @@ use aptos_logger::warn;
 use aptos_protos::{
     transaction::{
         v1 as transaction,
-        v1::{any_signature, Ed25519, Keyless, Secp256k1Ecdsa, TransactionSizeInfo, WebAuthn},
+        v1::{
+            any_signature, Ed25519, Keyless, Secp256k1Ecdsa, Secp256r1Ecdsa, TransactionSizeInfo,
+            WebAuthn,
+        },
     },
     util::timestamp,
 };
@@ -595,6 +598,15 @@ fn convert_signature(signature: &Signature) -> transaction::AnySignature {
                 },
             )),
         },
+        Signature::Secp256r1Ecdsa(s) => transaction::AnySignature {
+            r#type: transaction::any_signature::Type::Secp256r1Ecdsa as i32,
+            signature: s.0.clone(),
+            signature_variant: Some(any_signature::SignatureVariant::Secp256r1Ecdsa(
+                Secp256r1Ecdsa {
+                    signature: s.0.clone(),
+                },
+            )),
+        },
         Signature::WebAuthn(s) => transaction::AnySignature {
             r#type: transaction::any_signature::Type::Webauthn as i32,
             signature: s.0.clone(),
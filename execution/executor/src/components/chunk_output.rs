@@ -388,6 +388,11 @@ pub fn update_counters_for_processed_chunk<T, O>(
                         .with_label_values(&[process_type, "multisig", state])
                         .inc();
                 },
+                aptos_types::transaction::TransactionPayload::Orderless(_) => {
+                    metrics::APTOS_PROCESSED_USER_TRANSACTIONS_PAYLOAD_TYPE
+                        .with_label_values(&[process_type, "orderless", state])
+                        .inc();
+                },
 
                 // Deprecated.
                 aptos_types::transaction::TransactionPayload::ModuleBundle(_) => {
@@ -416,6 +416,9 @@ fn decode_transaction(txn: &SignedTransaction) -> MockVMTransaction {
         TransactionPayload::Multisig(_) => {
             unimplemented!("MockVM does not support multisig transaction payload.")
         },
+        TransactionPayload::Orderless(_) => {
+            unimplemented!("MockVM does not support orderless transaction payload.")
+        },
         // Deprecated.
         TransactionPayload::ModuleBundle(_) => {
             unreachable!("Module bundle payload has been removed")
@@ -11,7 +11,7 @@ use crate::{
 };
 use aptos_consensus_types::common::TransactionSummary;
 use aptos_logger::prelude::*;
-use aptos_types::account_address::AccountAddress;
+use aptos_types::{account_address::AccountAddress, transaction::ReplayProtector};
 use rand::seq::SliceRandom;
 use std::{
     cmp::Ordering,
@@ -23,6 +23,9 @@ use std::{
 
 pub type AccountTransactions = BTreeMap<u64, MempoolTransaction>;
 
+/// Orderless transactions of an account, keyed by nonce.
+pub type OrderlessAccountTransactions = BTreeMap<u64, MempoolTransaction>;
+
 /// PriorityIndex represents the main Priority Queue in Mempool.
 /// It's used to form the transaction block for Consensus.
 /// Transactions are ordered by gas price. Second level ordering is done by expiration time.
@@ -101,8 +104,8 @@ impl Ord for OrderedQueueKey {
             ordering => return ordering,
         }
         self.sequence_number
-            .transaction_sequence_number
-            .cmp(&other.sequence_number.transaction_sequence_number)
+            .transaction_replay_protector
+            .cmp(&other.sequence_number.transaction_replay_protector)
             .reverse()
     }
 }
@@ -141,7 +144,7 @@ impl TTLIndex {
         let ttl_key = TTLOrderingKey {
             expiration_time: now,
             address: AccountAddress::ZERO,
-            sequence_number: 0,
+            replay_protector: ReplayProtector::SequenceNumber(0),
         };
 
         let mut active = self.data.split_off(&ttl_key);
@@ -155,7 +158,7 @@ impl TTLIndex {
         TTLOrderingKey {
             expiration_time: (self.get_expiration_time)(txn),
             address: txn.get_sender(),
-            sequence_number: txn.sequence_info.transaction_sequence_number,
+            replay_protector: txn.sequence_info.transaction_replay_protector,
         }
     }
 
@@ -173,7 +176,7 @@ impl TTLIndex {
 pub struct TTLOrderingKey {
     pub expiration_time: Duration,
     pub address: AccountAddress,
-    pub sequence_number: u64,
+    pub replay_protector: ReplayProtector,
 }
 
 /// Be very careful with this, to not break the partial ordering.
@@ -182,9 +185,8 @@ pub struct TTLOrderingKey {
 impl Ord for TTLOrderingKey {
     fn cmp(&self, other: &TTLOrderingKey) -> Ordering {
         match self.expiration_time.cmp(&other.expiration_time) {
-            Ordering::Equal => {
-                (&self.address, self.sequence_number).cmp(&(&other.address, other.sequence_number))
-            },
+            Ordering::Equal => (&self.address, self.replay_protector)
+                .cmp(&(&other.address, other.replay_protector)),
             ordering => ordering,
         }
     }
@@ -194,12 +196,12 @@ impl Ord for TTLOrderingKey {
 /// We only add a transaction to the index if it has a chance to be included in the next consensus
 /// block (which means its status is != NotReady or its sequential to another "ready" transaction).
 ///
-/// It's represented as Map <timeline_id, (Address, sequence_number)>, where timeline_id is auto
-/// increment unique id of "ready" transaction in local Mempool. (Address, sequence_number) is a
+/// It's represented as Map <timeline_id, (Address, replay_protector)>, where timeline_id is auto
+/// increment unique id of "ready" transaction in local Mempool. (Address, replay_protector) is a
 /// logical reference to transaction content in main storage.
 pub struct TimelineIndex {
     timeline_id: u64,
-    timeline: BTreeMap<u64, (AccountAddress, u64)>,
+    timeline: BTreeMap<u64, (AccountAddress, ReplayProtector)>,
 }
 
 impl TimelineIndex {
@@ -216,13 +218,13 @@ impl TimelineIndex {
         &self,
         timeline_id: u64,
         count: usize,
-    ) -> Vec<(AccountAddress, u64)> {
+    ) -> Vec<(AccountAddress, ReplayProtector)> {
         let mut batch = vec![];
-        for (_id, &(address, sequence_number)) in self
+        for (_id, &(address, replay_protector)) in self
             .timeline
             .range((Bound::Excluded(timeline_id), Bound::Unbounded))
         {
-            batch.push((address, sequence_number));
+            batch.push((address, replay_protector));
             if batch.len() == count {
                 break;
             }
@@ -231,7 +233,11 @@ impl TimelineIndex {
     }
 
     /// Read transactions from the timeline from `start_id` (exclusive) to `end_id` (inclusive).
-    pub(crate) fn timeline_range(&self, start_id: u64, end_id: u64) -> Vec<(AccountAddress, u64)> {
+    pub(crate) fn timeline_range(
+        &self,
+        start_id: u64,
+        end_id: u64,
+    ) -> Vec<(AccountAddress, ReplayProtector)> {
         self.timeline
             .range((Bound::Excluded(start_id), Bound::Included(end_id)))
             .map(|(_idx, txn)| txn)
@@ -244,7 +250,7 @@ impl TimelineIndex {
             self.timeline_id,
             (
                 txn.get_sender(),
-                txn.sequence_info.transaction_sequence_number,
+                txn.sequence_info.transaction_replay_protector,
             ),
         );
         txn.timeline_state = TimelineState::Ready(self.timeline_id);
@@ -301,7 +307,7 @@ impl MultiBucketTimelineIndex {
         &self,
         timeline_id: &MultiBucketTimelineIndexIds,
         count: usize,
-    ) -> Vec<Vec<(AccountAddress, u64)>> {
+    ) -> Vec<Vec<(AccountAddress, ReplayProtector)>> {
         assert!(timeline_id.id_per_bucket.len() == self.bucket_mins.len());
 
         let mut added = 0;
@@ -330,7 +336,7 @@ impl MultiBucketTimelineIndex {
     pub(crate) fn timeline_range(
         &self,
         start_end_pairs: &Vec<(u64, u64)>,
-    ) -> Vec<(AccountAddress, u64)> {
+    ) -> Vec<(AccountAddress, ReplayProtector)> {
         assert_eq!(start_end_pairs.len(), self.timelines.len());
 
         let mut all_txns = vec![];
@@ -387,6 +393,7 @@ impl MultiBucketTimelineIndex {
 /// ParkingLotIndex keeps track of "not_ready" transactions, e.g., transactions that
 /// can't be included in the next block because their sequence number is too high.
 /// We keep a separate index to be able to efficiently evict them when Mempool is full.
+/// Orderless transactions are always ready, so they never enter the parking lot.
 pub struct ParkingLotIndex {
     // DS invariants:
     // 1. for each entry (account, txns) in `data`, `txns` is never empty
@@ -475,7 +482,7 @@ impl ParkingLotIndex {
         self.data.choose(&mut rng).and_then(|(sender, txns)| {
             txns.iter().next_back().map(|seq_num| TxnPointer {
                 sender: *sender,
-                sequence_number: *seq_num,
+                replay_protector: ReplayProtector::SequenceNumber(*seq_num),
             })
         })
     }
@@ -486,14 +493,14 @@ impl ParkingLotIndex {
 }
 
 /// Logical pointer to `MempoolTransaction`.
-/// Includes Account's address and transaction sequence number.
+/// Includes Account's address and transaction sequence number or nonce.
 pub type TxnPointer = TransactionSummary;
 
 impl From<&MempoolTransaction> for TxnPointer {
     fn from(txn: &MempoolTransaction) -> Self {
         Self {
             sender: txn.get_sender(),
-            sequence_number: txn.sequence_info.transaction_sequence_number,
+            replay_protector: txn.sequence_info.transaction_replay_protector,
         }
     }
 }
@@ -502,7 +509,7 @@ impl From<&OrderedQueueKey> for TxnPointer {
     fn from(key: &OrderedQueueKey) -> Self {
         Self {
             sender: key.address,
-            sequence_number: key.sequence_number.transaction_sequence_number,
+            replay_protector: key.sequence_number.transaction_replay_protector,
         }
     }
 }
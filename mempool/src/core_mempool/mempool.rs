@@ -21,7 +21,7 @@ use aptos_logger::prelude::*;
 use aptos_types::{
     account_address::AccountAddress,
     mempool_status::{MempoolStatus, MempoolStatusCode},
-    transaction::SignedTransaction,
+    transaction::{ReplayProtector, SignedTransaction},
     vm_status::DiscardedVMStatus,
 };
 use std::{
@@ -48,23 +48,30 @@ impl Mempool {
     }
 
     /// This function will be called once the transaction has been stored.
-    pub(crate) fn commit_transaction(&mut self, sender: &AccountAddress, sequence_number: u64) {
+    pub(crate) fn commit_transaction(
+        &mut self,
+        sender: &AccountAddress,
+        replay_protector: ReplayProtector,
+    ) {
         self.transactions
-            .commit_transaction(sender, sequence_number);
+            .commit_transaction(sender, replay_protector);
     }
 
     pub(crate) fn log_commit_transaction(
         &self,
         sender: &AccountAddress,
-        sequence_number: u64,
+        replay_protector: ReplayProtector,
         block_timestamp: Duration,
     ) {
         trace!(
-            LogSchema::new(LogEntry::RemoveTxn).txns(TxnsLog::new_txn(*sender, sequence_number)),
+            LogSchema::new(LogEntry::RemoveTxn).txns(TxnsLog::new_txn(*sender, replay_protector)),
             is_rejected = false
         );
-        self.log_commit_latency(*sender, sequence_number, block_timestamp);
-        if let Some(ranking_score) = self.transactions.get_ranking_score(sender, sequence_number) {
+        self.log_commit_latency(*sender, replay_protector, block_timestamp);
+        if let Some(ranking_score) = self
+            .transactions
+            .get_ranking_score(sender, replay_protector)
+        {
             counters::core_mempool_txn_ranking_score(
                 counters::REMOVE_LABEL,
                 counters::COMMIT_ACCEPTED_LABEL,
@@ -77,16 +84,19 @@ impl Mempool {
     fn log_reject_transaction(
         &self,
         sender: &AccountAddress,
-        sequence_number: u64,
+        replay_protector: ReplayProtector,
         reason_label: &'static str,
     ) {
         trace!(
-            LogSchema::new(LogEntry::RemoveTxn).txns(TxnsLog::new_txn(*sender, sequence_number)),
+            LogSchema::new(LogEntry::RemoveTxn).txns(TxnsLog::new_txn(*sender, replay_protector)),
             is_rejected = true,
             label = reason_label,
         );
-        self.log_commit_rejected_latency(*sender, sequence_number, reason_label);
-        if let Some(ranking_score) = self.transactions.get_ranking_score(sender, sequence_number) {
+        self.log_commit_rejected_latency(*sender, replay_protector, reason_label);
+        if let Some(ranking_score) = self
+            .transactions
+            .get_ranking_score(sender, replay_protector)
+        {
             counters::core_mempool_txn_ranking_score(
                 counters::REMOVE_LABEL,
                 reason_label,
@@ -99,12 +109,12 @@ impl Mempool {
     pub(crate) fn reject_transaction(
         &mut self,
         sender: &AccountAddress,
-        sequence_number: u64,
+        replay_protector: ReplayProtector,
         hash: &HashValue,
         reason: &DiscardedVMStatus,
     ) {
         if *reason == DiscardedVMStatus::SEQUENCE_NUMBER_TOO_NEW {
-            self.log_reject_transaction(sender, sequence_number, counters::COMMIT_IGNORED_LABEL);
+            self.log_reject_transaction(sender, replay_protector, counters::COMMIT_IGNORED_LABEL);
             // Do not remove the transaction from mempool
             return;
         }
@@ -114,9 +124,9 @@ impl Mempool {
         } else {
             counters::COMMIT_REJECTED_LABEL
         };
-        self.log_reject_transaction(sender, sequence_number, label);
+        self.log_reject_transaction(sender, replay_protector, label);
         self.transactions
-            .reject_transaction(sender, sequence_number, hash);
+            .reject_transaction(sender, replay_protector, hash);
     }
 
     pub(crate) fn log_txn_latency(
@@ -134,10 +144,14 @@ impl Mempool {
         }
     }
 
-    fn log_consensus_pulled_latency(&self, account: AccountAddress, sequence_number: u64) {
+    fn log_consensus_pulled_latency(
+        &self,
+        account: AccountAddress,
+        replay_protector: ReplayProtector,
+    ) {
         if let Some((insertion_info, bucket)) = self
             .transactions
-            .get_insertion_info_and_bucket(&account, sequence_number)
+            .get_insertion_info_and_bucket(&account, replay_protector)
         {
             let prev_count = insertion_info
                 .consensus_pulled_counter
@@ -150,12 +164,12 @@ impl Mempool {
     fn log_commit_rejected_latency(
         &self,
         account: AccountAddress,
-        sequence_number: u64,
+        replay_protector: ReplayProtector,
         stage: &'static str,
     ) {
         if let Some((insertion_info, bucket)) = self
             .transactions
-            .get_insertion_info_and_bucket(&account, sequence_number)
+            .get_insertion_info_and_bucket(&account, replay_protector)
         {
             Self::log_txn_latency(insertion_info, bucket, stage);
         }
@@ -195,12 +209,12 @@ impl Mempool {
     fn log_commit_latency(
         &self,
         account: AccountAddress,
-        sequence_number: u64,
+        replay_protector: ReplayProtector,
         block_timestamp: Duration,
     ) {
         if let Some((insertion_info, bucket)) = self
             .transactions
-            .get_insertion_info_and_bucket(&account, sequence_number)
+            .get_insertion_info_and_bucket(&account, replay_protector)
         {
             Self::log_txn_latency(insertion_info, bucket, counters::COMMIT_ACCEPTED_LABEL);
             Self::log_commit_and_parked_latency(insertion_info, bucket);
@@ -234,17 +248,21 @@ impl Mempool {
     ) -> MempoolStatus {
         trace!(
             LogSchema::new(LogEntry::AddTxn)
-                .txns(TxnsLog::new_txn(txn.sender(), txn.sequence_number())),
+                .txns(TxnsLog::new_txn(txn.sender(), txn.replay_protector())),
             committed_seq_number = db_sequence_number
         );
 
-        // don't accept old transactions (e.g. seq is less than account's current seq_number)
-        if txn.sequence_number() < db_sequence_number {
-            return MempoolStatus::new(MempoolStatusCode::InvalidSeqNumber).with_message(format!(
-                "transaction sequence number is {}, current sequence number is  {}",
-                txn.sequence_number(),
-                db_sequence_number,
-            ));
+        // don't accept old transactions (e.g. seq is less than account's current seq_number).
+        // Orderless transactions are checked against the on-chain nonce history by the VM.
+        if let ReplayProtector::SequenceNumber(sequence_number) = txn.replay_protector() {
+            if sequence_number < db_sequence_number {
+                return MempoolStatus::new(MempoolStatusCode::InvalidSeqNumber).with_message(
+                    format!(
+                        "transaction sequence number is {}, current sequence number is  {}",
+                        sequence_number, db_sequence_number,
+                    ),
+                );
+            }
         }
 
         let now = SystemTime::now();
@@ -334,11 +352,23 @@ impl Mempool {
             ) {
                 continue;
             }
-            let tx_seq = txn.sequence_number.transaction_sequence_number;
+            let tx_seq = match txn.sequence_number.transaction_replay_protector {
+                ReplayProtector::SequenceNumber(sequence_number) => sequence_number,
+                // Orderless transactions don't depend on any other transaction of the account.
+                ReplayProtector::Nonce(_) => {
+                    let ptr = TxnPointer::from(txn);
+                    seen.insert(ptr, txn.gas_ranking_score);
+                    result.push(ptr);
+                    if (result.len() as u64) == max_txns {
+                        break;
+                    }
+                    continue;
+                },
+            };
             let account_sequence_number = self.transactions.get_sequence_number(&txn.address);
             let previous_txn_was_seen = tx_seq > 0
                 && Self::was_seen(
-                    &TxnPointer::new(txn.address, tx_seq - 1),
+                    &TxnPointer::new(txn.address, ReplayProtector::SequenceNumber(tx_seq - 1)),
                     &seen,
                     &upgraded,
                     &exclude_transactions,
@@ -355,14 +385,18 @@ impl Mempool {
 
                 // check if we can now include some transactions
                 // that were skipped before for given account
-                let mut skipped_txn = TxnPointer::new(txn.address, tx_seq + 1);
+                let mut skipped_seq = tx_seq + 1;
+                let mut skipped_txn =
+                    TxnPointer::new(txn.address, ReplayProtector::SequenceNumber(skipped_seq));
                 while skipped.contains(&skipped_txn) {
                     seen.insert(skipped_txn, txn.gas_ranking_score);
                     result.push(skipped_txn);
                     if (result.len() as u64) == max_txns {
                         break 'main;
                     }
-                    skipped_txn = TxnPointer::new(txn.address, skipped_txn.sequence_number + 1);
+                    skipped_seq += 1;
+                    skipped_txn =
+                        TxnPointer::new(txn.address, ReplayProtector::SequenceNumber(skipped_seq));
                 }
             } else {
                 skipped.insert(TxnPointer::from(txn));
@@ -377,7 +411,7 @@ impl Mempool {
         for txn_pointer in result {
             if let Some((txn, ranking_score)) = self
                 .transactions
-                .get_with_ranking_score(&txn_pointer.sender, txn_pointer.sequence_number)
+                .get_with_ranking_score(&txn_pointer.sender, txn_pointer.replay_protector)
             {
                 let txn_size = txn.txn_bytes_len() as u64;
                 if total_bytes + txn_size > max_bytes {
@@ -444,7 +478,7 @@ impl Mempool {
         counters::mempool_service_transactions(counters::GET_BLOCK_LABEL, block.len());
         counters::MEMPOOL_SERVICE_BYTES_GET_BLOCK.observe(total_bytes as f64);
         for transaction in &block {
-            self.log_consensus_pulled_latency(transaction.sender(), transaction.sequence_number());
+            self.log_consensus_pulled_latency(transaction.sender(), transaction.replay_protector());
         }
         block
     }
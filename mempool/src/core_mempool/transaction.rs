@@ -4,7 +4,10 @@
 
 use crate::{core_mempool::TXN_INDEX_ESTIMATED_BYTES, counters};
 use aptos_crypto::HashValue;
-use aptos_types::{account_address::AccountAddress, transaction::SignedTransaction};
+use aptos_types::{
+    account_address::AccountAddress,
+    transaction::{ReplayProtector, SignedTransaction},
+};
 use serde::{Deserialize, Serialize};
 use std::{
     mem::size_of,
@@ -39,7 +42,7 @@ impl MempoolTransaction {
     ) -> Self {
         Self {
             sequence_info: SequenceInfo {
-                transaction_sequence_number: txn.sequence_number(),
+                transaction_replay_protector: txn.replay_protector(),
                 account_sequence_number: seqno,
             },
             txn,
@@ -82,7 +85,7 @@ pub enum TimelineState {
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct SequenceInfo {
-    pub transaction_sequence_number: u64,
+    pub transaction_replay_protector: ReplayProtector,
     pub account_sequence_number: u64,
 }
 
@@ -5,8 +5,8 @@
 use crate::{
     core_mempool::{
         index::{
-            AccountTransactions, MultiBucketTimelineIndex, ParkingLotIndex, PriorityIndex,
-            PriorityQueueIter, TTLIndex,
+            AccountTransactions, MultiBucketTimelineIndex, OrderlessAccountTransactions,
+            ParkingLotIndex, PriorityIndex, PriorityQueueIter, TTLIndex,
         },
         mempool::Mempool,
         transaction::{InsertionInfo, MempoolTransaction, TimelineState},
@@ -23,7 +23,7 @@ use aptos_logger::{prelude::*, Level};
 use aptos_types::{
     account_address::AccountAddress,
     mempool_status::{MempoolStatus, MempoolStatusCode},
-    transaction::SignedTransaction,
+    transaction::{ReplayProtector, SignedTransaction},
 };
 use std::{
     cmp::max,
@@ -36,13 +36,17 @@ use std::{
 /// Estimated per-txn overhead of indexes. Needs to be updated if additional indexes are added.
 pub const TXN_INDEX_ESTIMATED_BYTES: usize = size_of::<crate::core_mempool::index::OrderedQueueKey>() // priority_index
     + size_of::<crate::core_mempool::index::TTLOrderingKey>() * 2 // expiration_time_index + system_ttl_index
-    + (size_of::<u64>() * 3 + size_of::<AccountAddress>()) // timeline_index
-    + (size_of::<HashValue>() + size_of::<u64>() + size_of::<AccountAddress>()); // hash_index
+    + (size_of::<u64>() * 2 + size_of::<ReplayProtector>() + size_of::<AccountAddress>()) // timeline_index
+    + (size_of::<HashValue>() + size_of::<ReplayProtector>() + size_of::<AccountAddress>()); // hash_index
 
 /// TransactionStore is in-memory storage for all transactions in mempool.
 pub struct TransactionStore {
     // main DS
     transactions: HashMap<AccountAddress, AccountTransactions>,
+    // Orderless transactions don't depend on any other transaction of the account, so they are
+    // kept apart from `transactions` and are ready for consensus and broadcast as soon as they
+    // are inserted.
+    orderless_transactions: HashMap<AccountAddress, OrderlessAccountTransactions>,
 
     // Sequence numbers for accounts with transactions
     sequence_numbers: HashMap<AccountAddress, u64>,
@@ -60,11 +64,11 @@ pub struct TransactionStore {
     // keeps track of "non-ready" txns (transactions that can't be included in next block)
     parking_lot_index: ParkingLotIndex,
     // Index for looking up transaction by hash.
-    // Transactions are stored by AccountAddress + sequence number.
-    // This index stores map of transaction committed hash to (AccountAddress, sequence number) pair.
+    // Transactions are stored by AccountAddress + sequence number (or nonce).
+    // This index stores map of transaction committed hash to (AccountAddress, replay protector) pair.
     // Using transaction commited hash because from end user's point view, a transaction should only have
     // one valid hash.
-    hash_index: HashMap<HashValue, (AccountAddress, u64)>,
+    hash_index: HashMap<HashValue, (AccountAddress, ReplayProtector)>,
     // estimated size in bytes
     size_bytes: usize,
     // keeps track of txns that were resubmitted with higher gas
@@ -86,6 +90,7 @@ impl TransactionStore {
         Self {
             // main DS
             transactions: HashMap::new(),
+            orderless_transactions: HashMap::new(),
             sequence_numbers: HashMap::new(),
 
             // various indexes
@@ -118,32 +123,58 @@ impl TransactionStore {
     fn get_mempool_txn(
         &self,
         address: &AccountAddress,
-        sequence_number: u64,
+        replay_protector: ReplayProtector,
     ) -> Option<&MempoolTransaction> {
-        self.transactions
-            .get(address)
-            .and_then(|txns| txns.get(&sequence_number))
+        match replay_protector {
+            ReplayProtector::SequenceNumber(sequence_number) => self
+                .transactions
+                .get(address)
+                .and_then(|txns| txns.get(&sequence_number)),
+            ReplayProtector::Nonce(nonce) => self
+                .orderless_transactions
+                .get(address)
+                .and_then(|txns| txns.get(&nonce)),
+        }
     }
 
-    /// Fetch transaction by account address + sequence_number.
+    /// Removes a transaction from the main DS. The caller is responsible for removing it from the
+    /// indexes as well.
+    fn remove_mempool_txn(
+        &mut self,
+        address: &AccountAddress,
+        replay_protector: ReplayProtector,
+    ) -> Option<MempoolTransaction> {
+        match replay_protector {
+            ReplayProtector::SequenceNumber(sequence_number) => self
+                .transactions
+                .get_mut(address)
+                .and_then(|txns| txns.remove(&sequence_number)),
+            ReplayProtector::Nonce(nonce) => self
+                .orderless_transactions
+                .get_mut(address)
+                .and_then(|txns| txns.remove(&nonce)),
+        }
+    }
+
+    /// Fetch transaction by account address + sequence_number (or nonce).
     pub(crate) fn get(
         &self,
         address: &AccountAddress,
-        sequence_number: u64,
+        replay_protector: ReplayProtector,
     ) -> Option<SignedTransaction> {
-        if let Some(txn) = self.get_mempool_txn(address, sequence_number) {
+        if let Some(txn) = self.get_mempool_txn(address, replay_protector) {
             return Some(txn.txn.clone());
         }
         None
     }
 
-    /// Fetch transaction by account address + sequence_number, including ranking score
+    /// Fetch transaction by account address + sequence_number (or nonce), including ranking score
     pub(crate) fn get_with_ranking_score(
         &self,
         address: &AccountAddress,
-        sequence_number: u64,
+        replay_protector: ReplayProtector,
     ) -> Option<(SignedTransaction, u64)> {
-        if let Some(txn) = self.get_mempool_txn(address, sequence_number) {
+        if let Some(txn) = self.get_mempool_txn(address, replay_protector) {
             return Some((txn.txn.clone(), txn.ranking_score));
         }
         None
@@ -151,7 +182,7 @@ impl TransactionStore {
 
     pub(crate) fn get_by_hash(&self, hash: HashValue) -> Option<SignedTransaction> {
         match self.hash_index.get(&hash) {
-            Some((address, seq)) => self.get(address, *seq),
+            Some((address, replay_protector)) => self.get(address, *replay_protector),
             None => None,
         }
     }
@@ -159,9 +190,9 @@ impl TransactionStore {
     pub(crate) fn get_insertion_info_and_bucket(
         &self,
         address: &AccountAddress,
-        sequence_number: u64,
+        replay_protector: ReplayProtector,
     ) -> Option<(&InsertionInfo, &str)> {
-        if let Some(txn) = self.get_mempool_txn(address, sequence_number) {
+        if let Some(txn) = self.get_mempool_txn(address, replay_protector) {
             return Some((&txn.insertion_info, self.get_bucket(txn.ranking_score)));
         }
         None
@@ -170,9 +201,9 @@ impl TransactionStore {
     pub(crate) fn get_ranking_score(
         &self,
         address: &AccountAddress,
-        sequence_number: u64,
+        replay_protector: ReplayProtector,
     ) -> Option<u64> {
-        if let Some(txn) = self.get_mempool_txn(address, sequence_number) {
+        if let Some(txn) = self.get_mempool_txn(address, replay_protector) {
             return Some(txn.ranking_score);
         }
         None
@@ -194,7 +225,7 @@ impl TransactionStore {
     /// Insert transaction into TransactionStore. Performs validation checks and updates indexes.
     pub(crate) fn insert(&mut self, txn: MempoolTransaction) -> MempoolStatus {
         let address = txn.get_sender();
-        let txn_seq_num = txn.sequence_info.transaction_sequence_number;
+        let replay_protector = txn.sequence_info.transaction_replay_protector;
         let acc_seq_num = txn.sequence_info.account_sequence_number;
         let mut gas_upgraded = false;
 
@@ -203,40 +234,39 @@ impl TransactionStore {
         //
         // Transactions with all the same inputs (but possibly signed differently) are idempotent
         // since the raw transaction is the same
-        if let Some(txns) = self.transactions.get_mut(&address) {
-            if let Some(current_version) = txns.get_mut(&txn_seq_num) {
-                if current_version.txn.payload() != txn.txn.payload() {
-                    return MempoolStatus::new(MempoolStatusCode::InvalidUpdate).with_message(
-                        "Transaction already in mempool with a different payload".to_string(),
-                    );
-                } else if current_version.txn.expiration_timestamp_secs()
-                    != txn.txn.expiration_timestamp_secs()
-                {
-                    return MempoolStatus::new(MempoolStatusCode::InvalidUpdate).with_message(
-                        "Transaction already in mempool with a different expiration timestamp"
-                            .to_string(),
-                    );
-                } else if current_version.txn.max_gas_amount() != txn.txn.max_gas_amount() {
-                    return MempoolStatus::new(MempoolStatusCode::InvalidUpdate).with_message(
-                        "Transaction already in mempool with a different max gas amount"
-                            .to_string(),
-                    );
-                } else if current_version.get_gas_price() < txn.get_gas_price() {
-                    // Update txn if gas unit price is a larger value than before
-                    if let Some(txn) = txns.remove(&txn_seq_num) {
-                        self.index_remove(&txn);
-                    };
-                    gas_upgraded = true;
-                } else if current_version.get_gas_price() > txn.get_gas_price() {
-                    return MempoolStatus::new(MempoolStatusCode::InvalidUpdate).with_message(
-                        "Transaction already in mempool with a higher gas price".to_string(),
-                    );
-                } else {
-                    // If the transaction is the same, it's an idempotent call
-                    // Updating signers is not supported, the previous submission must fail
-                    counters::CORE_MEMPOOL_IDEMPOTENT_TXNS.inc();
-                    return MempoolStatus::new(MempoolStatusCode::Accepted);
-                }
+        if let Some(current_version) = self.get_mempool_txn(&address, replay_protector) {
+            if current_version.txn.payload() != txn.txn.payload() {
+                return MempoolStatus::new(MempoolStatusCode::InvalidUpdate).with_message(
+                    "Transaction already in mempool with a different payload".to_string(),
+                );
+            } else if current_version.txn.expiration_timestamp_secs()
+                != txn.txn.expiration_timestamp_secs()
+            {
+                return MempoolStatus::new(MempoolStatusCode::InvalidUpdate).with_message(
+                    "Transaction already in mempool with a different expiration timestamp"
+                        .to_string(),
+                );
+            } else if current_version.txn.max_gas_amount() != txn.txn.max_gas_amount() {
+                return MempoolStatus::new(MempoolStatusCode::InvalidUpdate).with_message(
+                    "Transaction already in mempool with a different max gas amount".to_string(),
+                );
+            } else if current_version.get_gas_price() < txn.get_gas_price() {
+                // Update txn if gas unit price is a larger value than before
+                gas_upgraded = true;
+            } else if current_version.get_gas_price() > txn.get_gas_price() {
+                return MempoolStatus::new(MempoolStatusCode::InvalidUpdate).with_message(
+                    "Transaction already in mempool with a higher gas price".to_string(),
+                );
+            } else {
+                // If the transaction is the same, it's an idempotent call
+                // Updating signers is not supported, the previous submission must fail
+                counters::CORE_MEMPOOL_IDEMPOTENT_TXNS.inc();
+                return MempoolStatus::new(MempoolStatusCode::Accepted);
+            }
+        }
+        if gas_upgraded {
+            if let Some(txn) = self.remove_mempool_txn(&address, replay_protector) {
+                self.index_remove(&txn);
             }
         }
 
@@ -250,35 +280,61 @@ impl TransactionStore {
 
         self.clean_committed_transactions(&address, acc_seq_num);
 
-        self.transactions.entry(address).or_default();
-
-        if let Some(txns) = self.transactions.get_mut(&address) {
-            // capacity check
-            if txns.len() >= self.capacity_per_user {
-                return MempoolStatus::new(MempoolStatusCode::TooManyTransactions).with_message(
-                    format!(
-                        "Mempool over capacity for account. Number of transactions from account: {} Capacity per account: {}",
-                        txns.len(),
-                        self.capacity_per_user,
-                    ),
-                );
-            }
+        // capacity check
+        let num_account_txns = self.transactions.get(&address).map_or(0, |txns| txns.len())
+            + self
+                .orderless_transactions
+                .get(&address)
+                .map_or(0, |txns| txns.len());
+        if num_account_txns >= self.capacity_per_user {
+            return MempoolStatus::new(MempoolStatusCode::TooManyTransactions).with_message(
+                format!(
+                    "Mempool over capacity for account. Number of transactions from account: {} Capacity per account: {}",
+                    num_account_txns,
+                    self.capacity_per_user,
+                ),
+            );
+        }
 
-            // insert into storage and other indexes
-            self.system_ttl_index.insert(&txn);
-            self.expiration_time_index.insert(&txn);
-            self.hash_index
-                .insert(txn.get_committed_hash(), (txn.get_sender(), txn_seq_num));
-            self.sequence_numbers.insert(txn.get_sender(), acc_seq_num);
-            self.size_bytes += txn.get_estimated_bytes();
-            if gas_upgraded {
-                self.gas_upgraded_index
-                    .insert(TxnPointer::from(&txn), txn.get_gas_price());
-            }
-            txns.insert(txn_seq_num, txn);
-            self.track_indices();
+        // insert into storage and other indexes
+        self.system_ttl_index.insert(&txn);
+        self.expiration_time_index.insert(&txn);
+        self.hash_index
+            .insert(txn.get_committed_hash(), (address, replay_protector));
+        self.sequence_numbers.insert(address, acc_seq_num);
+        self.size_bytes += txn.get_estimated_bytes();
+        if gas_upgraded {
+            self.gas_upgraded_index
+                .insert(TxnPointer::from(&txn), txn.get_gas_price());
+        }
+        match replay_protector {
+            ReplayProtector::SequenceNumber(txn_seq_num) => {
+                self.transactions
+                    .entry(address)
+                    .or_default()
+                    .insert(txn_seq_num, txn);
+                self.track_indices();
+                self.process_ready_transactions(&address, acc_seq_num);
+            },
+            ReplayProtector::Nonce(nonce) => {
+                let txns = self.orderless_transactions.entry(address).or_default();
+                txns.insert(nonce, txn);
+                if let Some(txn) = txns.get_mut(&nonce) {
+                    self.priority_index.insert(txn);
+                    let process_broadcast_ready = txn.timeline_state == TimelineState::NotReady;
+                    if process_broadcast_ready {
+                        self.timeline_index.insert(txn);
+                    }
+                    Self::log_ready_transaction(
+                        txn.ranking_score,
+                        self.timeline_index.get_bucket(txn.ranking_score),
+                        &mut txn.insertion_info,
+                        process_broadcast_ready,
+                    );
+                }
+                self.track_indices();
+            },
         }
-        self.process_ready_transactions(&address, acc_seq_num);
         MempoolStatus::new(MempoolStatusCode::Accepted)
     }
 
@@ -326,15 +382,13 @@ impl TransactionStore {
         if self.is_full() && self.check_txn_ready(txn, curr_sequence_number) {
             // try to free some space in Mempool from ParkingLot by evicting a non-ready txn
             if let Some(txn_pointer) = self.parking_lot_index.get_poppable() {
-                if let Some(txn) = self
-                    .transactions
-                    .get_mut(&txn_pointer.sender)
-                    .and_then(|txns| txns.remove(&txn_pointer.sequence_number))
+                if let Some(txn) =
+                    self.remove_mempool_txn(&txn_pointer.sender, txn_pointer.replay_protector)
                 {
                     debug!(
                         LogSchema::new(LogEntry::MempoolFullEvictedTxn).txns(TxnsLog::new_txn(
                             txn.get_sender(),
-                            txn.sequence_info.transaction_sequence_number
+                            txn.sequence_info.transaction_replay_protector
                         ))
                     );
                     self.index_remove(&txn);
@@ -349,13 +403,16 @@ impl TransactionStore {
     }
 
     /// Check if a transaction would be ready for broadcast in mempool upon insertion (without inserting it).
-    /// Two ways this can happen:
+    /// Orderless transactions are always ready. Otherwise, two ways this can happen:
     /// 1. txn sequence number == curr_sequence_number
     /// (this handles both cases where, (1) txn is first possible txn for an account and (2) the
     /// previous txn is committed).
     /// 2. The txn before this is ready for broadcast but not yet committed.
     fn check_txn_ready(&self, txn: &MempoolTransaction, curr_sequence_number: u64) -> bool {
-        let tx_sequence_number = txn.sequence_info.transaction_sequence_number;
+        let tx_sequence_number = match txn.sequence_info.transaction_replay_protector {
+            ReplayProtector::SequenceNumber(sequence_number) => sequence_number,
+            ReplayProtector::Nonce(_) => return true,
+        };
         if tx_sequence_number == curr_sequence_number {
             return true;
         } else if tx_sequence_number == 0 {
@@ -494,7 +551,7 @@ impl TransactionStore {
             for transaction in txns_for_removal.values() {
                 rm_txns.add(
                     transaction.get_sender(),
-                    transaction.sequence_info.transaction_sequence_number,
+                    transaction.sequence_info.transaction_replay_protector,
                 );
                 self.index_remove(transaction);
             }
@@ -510,7 +567,21 @@ impl TransactionStore {
     /// Handles transaction commit.
     /// It includes deletion of all transactions with sequence number <= `account_sequence_number`
     /// and potential promotion of sequential txns to PriorityIndex/TimelineIndex.
-    pub fn commit_transaction(&mut self, account: &AccountAddress, sequence_number: u64) {
+    /// Committing an orderless transaction only removes that transaction.
+    pub fn commit_transaction(
+        &mut self,
+        account: &AccountAddress,
+        replay_protector: ReplayProtector,
+    ) {
+        let sequence_number = match replay_protector {
+            ReplayProtector::SequenceNumber(sequence_number) => sequence_number,
+            ReplayProtector::Nonce(_) => {
+                if let Some(txn) = self.remove_mempool_txn(account, replay_protector) {
+                    self.index_remove(&txn);
+                }
+                return;
+            },
+        };
         let current_seq_number = self.get_sequence_number(account).map_or(0, |v| *v);
         let new_seq_number = max(current_seq_number, sequence_number + 1);
         self.sequence_numbers.insert(*account, new_seq_number);
@@ -521,26 +592,24 @@ impl TransactionStore {
     pub fn reject_transaction(
         &mut self,
         account: &AccountAddress,
-        sequence_number: u64,
+        replay_protector: ReplayProtector,
         hash: &HashValue,
     ) {
         let mut txn_to_remove = None;
-        if let Some((indexed_account, indexed_sequence_number)) = self.hash_index.get(hash) {
-            if account == indexed_account && sequence_number == *indexed_sequence_number {
-                txn_to_remove = self.get_mempool_txn(account, sequence_number).cloned();
+        if let Some((indexed_account, indexed_replay_protector)) = self.hash_index.get(hash) {
+            if account == indexed_account && replay_protector == *indexed_replay_protector {
+                txn_to_remove = self.get_mempool_txn(account, replay_protector).cloned();
             }
         }
         if let Some(txn_to_remove) = txn_to_remove {
-            if let Some(txns) = self.transactions.get_mut(account) {
-                txns.remove(&sequence_number);
-            }
+            self.remove_mempool_txn(account, replay_protector);
             self.index_remove(&txn_to_remove);
 
             if aptos_logger::enabled!(Level::Trace) {
                 let mut txns_log = TxnsLog::new();
                 txns_log.add(
                     txn_to_remove.get_sender(),
-                    txn_to_remove.sequence_info.transaction_sequence_number,
+                    txn_to_remove.sequence_info.transaction_replay_protector,
                 );
                 trace!(LogSchema::new(LogEntry::CleanRejectedTxn).txns(txns_log));
             }
@@ -561,11 +630,24 @@ impl TransactionStore {
 
         // Remove account datastructures if there are no more transactions for the account.
         let address = &txn.get_sender();
-        if let Some(txns) = self.transactions.get(address) {
-            if txns.is_empty() {
-                self.transactions.remove(address);
-                self.sequence_numbers.remove(address);
-            }
+        if self
+            .transactions
+            .get(address)
+            .map_or(false, |txns| txns.is_empty())
+        {
+            self.transactions.remove(address);
+        }
+        if self
+            .orderless_transactions
+            .get(address)
+            .map_or(false, |txns| txns.is_empty())
+        {
+            self.orderless_transactions.remove(address);
+        }
+        if !self.transactions.contains_key(address)
+            && !self.orderless_transactions.contains_key(address)
+        {
+            self.sequence_numbers.remove(address);
         }
 
         self.track_indices();
@@ -591,8 +673,8 @@ impl TransactionStore {
             .enumerate()
             .rev()
         {
-            for (address, sequence_number) in bucket {
-                if let Some(txn) = self.get_mempool_txn(address, *sequence_number) {
+            for (address, replay_protector) in bucket {
+                if let Some(txn) = self.get_mempool_txn(address, *replay_protector) {
                     let transaction_bytes = txn.txn.raw_txn_bytes_len() as u64;
                     if batch_total_bytes.saturating_add(transaction_bytes) > self.max_batch_bytes {
                         break; // The batch is full
@@ -629,10 +711,8 @@ impl TransactionStore {
         self.timeline_index
             .timeline_range(start_end_pairs)
             .iter()
-            .filter_map(|(account, sequence_number)| {
-                self.transactions
-                    .get(account)
-                    .and_then(|txns| txns.get(sequence_number))
+            .filter_map(|(account, replay_protector)| {
+                self.get_mempool_txn(account, *replay_protector)
                     .map(|txn| txn.txn.clone())
             })
             .collect()
@@ -651,7 +731,7 @@ impl TransactionStore {
         let mut oldest_insertion_time = None;
         // Limit the worst-case linear search to 20.
         for key in self.system_ttl_index.iter().take(20) {
-            if let Some(txn) = self.get_mempool_txn(&key.address, key.sequence_number) {
+            if let Some(txn) = self.get_mempool_txn(&key.address, key.replay_protector) {
                 if !txn.was_parked {
                     oldest_insertion_time = Some(txn.insertion_info.insertion_time);
                     break;
@@ -699,7 +779,7 @@ impl TransactionStore {
 
         let mut gc_txns = index.gc(now);
         // sort the expired txns by order of sequence number per account
-        gc_txns.sort_by_key(|key| (key.address, key.sequence_number));
+        gc_txns.sort_by_key(|key| (key.address, key.replay_protector));
         let mut gc_iter = gc_txns.iter().peekable();
 
         let mut gc_txns_log = match aptos_logger::enabled!(Level::Trace) {
@@ -707,44 +787,56 @@ impl TransactionStore {
             false => TxnsLog::new_with_max(10),
         };
         while let Some(key) = gc_iter.next() {
-            if let Some(txns) = self.transactions.get_mut(&key.address) {
-                let park_range_start = Bound::Excluded(key.sequence_number);
-                let park_range_end = gc_iter
-                    .peek()
-                    .filter(|next_key| key.address == next_key.address)
-                    .map_or(Bound::Unbounded, |next_key| {
-                        Bound::Excluded(next_key.sequence_number)
-                    });
-                // mark all following txns as non-ready, i.e. park them
-                for (_, t) in txns.range_mut((park_range_start, park_range_end)) {
-                    self.parking_lot_index.insert(t);
-                    self.priority_index.remove(t);
-                    self.timeline_index.remove(t);
-                    if let TimelineState::Ready(_) = t.timeline_state {
-                        t.timeline_state = TimelineState::NotReady;
+            let removed_txn = match key.replay_protector {
+                ReplayProtector::SequenceNumber(sequence_number) => {
+                    match self.transactions.get_mut(&key.address) {
+                        Some(txns) => {
+                            let park_range_start = Bound::Excluded(sequence_number);
+                            let park_range_end = gc_iter
+                                .peek()
+                                .filter(|next_key| key.address == next_key.address)
+                                .and_then(|next_key| next_key.replay_protector.sequence_number())
+                                .map_or(Bound::Unbounded, Bound::Excluded);
+                            // mark all following txns as non-ready, i.e. park them
+                            for (_, t) in txns.range_mut((park_range_start, park_range_end)) {
+                                self.parking_lot_index.insert(t);
+                                self.priority_index.remove(t);
+                                self.timeline_index.remove(t);
+                                if let TimelineState::Ready(_) = t.timeline_state {
+                                    t.timeline_state = TimelineState::NotReady;
+                                }
+                            }
+                            txns.remove(&sequence_number)
+                        },
+                        None => None,
                     }
+                },
+                // Nothing depends on an orderless transaction, so there is nothing to park.
+                ReplayProtector::Nonce(nonce) => self
+                    .orderless_transactions
+                    .get_mut(&key.address)
+                    .and_then(|txns| txns.remove(&nonce)),
+            };
+            if let Some(txn) = removed_txn {
+                let is_active = self.priority_index.contains(&txn);
+                let status = if is_active {
+                    counters::GC_ACTIVE_TXN_LABEL
+                } else {
+                    counters::GC_PARKED_TXN_LABEL
+                };
+                let account = txn.get_sender();
+                let txn_replay_protector = txn.sequence_info.transaction_replay_protector;
+                gc_txns_log.add_with_status(account, txn_replay_protector, status);
+                if let Ok(time_delta) =
+                    SystemTime::now().duration_since(txn.insertion_info.insertion_time)
+                {
+                    counters::CORE_MEMPOOL_GC_LATENCY
+                        .with_label_values(&[metric_label, status])
+                        .observe(time_delta.as_secs_f64());
                 }
-                if let Some(txn) = txns.remove(&key.sequence_number) {
-                    let is_active = self.priority_index.contains(&txn);
-                    let status = if is_active {
-                        counters::GC_ACTIVE_TXN_LABEL
-                    } else {
-                        counters::GC_PARKED_TXN_LABEL
-                    };
-                    let account = txn.get_sender();
-                    let txn_sequence_number = txn.sequence_info.transaction_sequence_number;
-                    gc_txns_log.add_with_status(account, txn_sequence_number, status);
-                    if let Ok(time_delta) =
-                        SystemTime::now().duration_since(txn.insertion_info.insertion_time)
-                    {
-                        counters::CORE_MEMPOOL_GC_LATENCY
-                            .with_label_values(&[metric_label, status])
-                            .observe(time_delta.as_secs_f64());
-                    }
 
-                    // remove txn
-                    self.index_remove(&txn);
-                }
+                // remove txn
+                self.index_remove(&txn);
             }
         }
 
@@ -771,12 +863,22 @@ impl TransactionStore {
                 };
                 txns_log.add_full_metadata(
                     *account,
-                    *seq_num,
+                    ReplayProtector::SequenceNumber(*seq_num),
                     status,
                     txn.insertion_info.insertion_time,
                 );
             }
         }
+        for (account, txns) in self.orderless_transactions.iter() {
+            for (nonce, txn) in txns.iter() {
+                txns_log.add_full_metadata(
+                    *account,
+                    ReplayProtector::Nonce(*nonce),
+                    "ready",
+                    txn.insertion_info.insertion_time,
+                );
+            }
+        }
         txns_log
     }
 
@@ -225,7 +225,7 @@ fn handle_commit_notification<TransactionValidator>(
             .iter()
             .map(|txn| TransactionSummary {
                 sender: txn.sender,
-                sequence_number: txn.sequence_number,
+                replay_protector: txn.replay_protector,
             })
             .collect(),
         msg.block_timestamp_usecs,
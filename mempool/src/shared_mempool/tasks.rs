@@ -346,10 +346,10 @@ fn validate_and_add_transactions<NetworkClient, TransactionValidator>(
     let vm_validation_timer = counters::PROCESS_TXN_BREAKDOWN_LATENCY
         .with_label_values(&[counters::VM_VALIDATION_LABEL])
         .start_timer();
-    let validation_results = transactions
-        .iter()
-        .map(|t| smp.validator.read().validate_transaction(t.0.clone()))
-        .collect::<Vec<_>>();
+    let validation_results = smp
+        .validator
+        .read()
+        .validate_transactions(transactions.iter().map(|t| t.0.clone()).collect());
     vm_validation_timer.stop_and_record();
     {
         let mut mempool = smp.mempool.lock();
@@ -559,10 +559,10 @@ pub(crate) fn process_committed_transactions(
     for transaction in transactions {
         pool.log_commit_transaction(
             &transaction.sender,
-            transaction.sequence_number,
+            transaction.replay_protector,
             block_timestamp,
         );
-        pool.commit_transaction(&transaction.sender, transaction.sequence_number);
+        pool.commit_transaction(&transaction.sender, transaction.replay_protector);
     }
 
     if block_timestamp_usecs > 0 {
@@ -579,7 +579,7 @@ pub(crate) fn process_rejected_transactions(
     for transaction in transactions {
         pool.reject_transaction(
             &transaction.sender,
-            transaction.sequence_number,
+            transaction.replay_protector,
             &transaction.hash,
             &transaction.reason,
         );
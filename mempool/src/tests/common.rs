@@ -15,7 +15,7 @@ use aptos_types::{
     account_address::AccountAddress,
     chain_id::ChainId,
     mempool_status::MempoolStatusCode,
-    transaction::{RawTransaction, Script, SignedTransaction},
+    transaction::{RawTransaction, Script, SignedTransaction, TransactionExecutable},
 };
 use once_cell::sync::Lazy;
 use rand::{rngs::StdRng, SeedableRng};
@@ -51,6 +51,7 @@ pub struct TestTransaction {
     pub(crate) sequence_number: u64,
     pub(crate) gas_price: u64,
     pub(crate) account_seqno: u64,
+    pub(crate) nonce: Option<u64>,
 }
 
 impl TestTransaction {
@@ -60,6 +61,18 @@ impl TestTransaction {
             sequence_number,
             gas_price,
             account_seqno: 0,
+            nonce: None,
+        }
+    }
+
+    /// An orderless transaction, replay protected by `nonce` instead of a sequence number.
+    pub(crate) const fn new_orderless(address: usize, nonce: u64, gas_price: u64) -> Self {
+        Self {
+            address,
+            sequence_number: 0,
+            gas_price,
+            account_seqno: 0,
+            nonce: Some(nonce),
         }
     }
 
@@ -86,15 +99,26 @@ impl TestTransaction {
         max_gas_amount: u64,
         exp_timestamp_secs: u64,
     ) -> SignedTransaction {
-        let raw_txn = RawTransaction::new_script(
-            TestTransaction::get_address(self.address),
-            self.sequence_number,
-            Script::new(vec![], vec![], vec![]),
-            max_gas_amount,
-            self.gas_price,
-            exp_timestamp_secs,
-            ChainId::test(),
-        );
+        let raw_txn = match self.nonce {
+            Some(nonce) => RawTransaction::new_orderless(
+                TestTransaction::get_address(self.address),
+                nonce,
+                TransactionExecutable::Script(Script::new(vec![], vec![], vec![])),
+                max_gas_amount,
+                self.gas_price,
+                exp_timestamp_secs,
+                ChainId::test(),
+            ),
+            None => RawTransaction::new_script(
+                TestTransaction::get_address(self.address),
+                self.sequence_number,
+                Script::new(vec![], vec![], vec![]),
+                max_gas_amount,
+                self.gas_price,
+                exp_timestamp_secs,
+                ChainId::test(),
+            ),
+        };
         let mut seed: [u8; 32] = [0u8; 32];
         seed[..4].copy_from_slice(&[1, 2, 3, 4]);
         let mut rng: StdRng = StdRng::from_seed(seed);
@@ -180,7 +204,7 @@ impl ConsensusMock {
     ) -> Vec<SignedTransaction> {
         let block = mempool.get_batch(max_txns, max_bytes, true, true, self.0.clone());
         block.iter().for_each(|t| {
-            let txn_summary = TransactionSummary::new(t.sender(), t.sequence_number());
+            let txn_summary = TransactionSummary::new(t.sender(), t.replay_protector());
             let txn_info = TransactionInProgress::new(t.gas_unit_price());
             self.0.insert(txn_summary, txn_info);
         });
@@ -13,7 +13,9 @@ use aptos_config::config::NodeConfig;
 use aptos_consensus_types::common::{TransactionInProgress, TransactionSummary};
 use aptos_crypto::HashValue;
 use aptos_types::{
-    mempool_status::MempoolStatusCode, transaction::SignedTransaction, vm_status::DiscardedVMStatus,
+    mempool_status::MempoolStatusCode,
+    transaction::{ReplayProtector, SignedTransaction},
+    vm_status::DiscardedVMStatus,
 };
 use itertools::Itertools;
 use maplit::btreemap;
@@ -183,7 +185,10 @@ fn test_commit_transaction() {
         TestTransaction::new(0, 1, 2),
     ]);
     for txn in txns {
-        pool.commit_transaction(&txn.sender(), txn.sequence_number());
+        pool.commit_transaction(
+            &txn.sender(),
+            ReplayProtector::SequenceNumber(txn.sequence_number()),
+        );
     }
     let new_txns = add_txns_to_mempool(&mut pool, vec![
         TestTransaction::new(1, 0, 3),
@@ -212,68 +217,86 @@ fn test_reject_transaction() {
     // reject with wrong hash should have no effect
     pool.reject_transaction(
         &TestTransaction::get_address(0),
-        0,
+        ReplayProtector::SequenceNumber(0),
         &txns[1].clone().committed_hash(), // hash of other txn
         &DiscardedVMStatus::MALFORMED,
     );
     assert!(pool
         .get_transaction_store()
-        .get(&TestTransaction::get_address(0), 0)
+        .get(
+            &TestTransaction::get_address(0),
+            ReplayProtector::SequenceNumber(0)
+        )
         .is_some());
     pool.reject_transaction(
         &TestTransaction::get_address(0),
-        1,
+        ReplayProtector::SequenceNumber(1),
         &txns[0].clone().committed_hash(), // hash of other txn
         &DiscardedVMStatus::MALFORMED,
     );
     assert!(pool
         .get_transaction_store()
-        .get(&TestTransaction::get_address(0), 1)
+        .get(
+            &TestTransaction::get_address(0),
+            ReplayProtector::SequenceNumber(1)
+        )
         .is_some());
 
     // reject with sequence number too new should have no effect
     // reject with wrong hash should have no effect
     pool.reject_transaction(
         &TestTransaction::get_address(0),
-        0,
+        ReplayProtector::SequenceNumber(0),
         &txns[0].clone().committed_hash(),
         &DiscardedVMStatus::SEQUENCE_NUMBER_TOO_NEW,
     );
     assert!(pool
         .get_transaction_store()
-        .get(&TestTransaction::get_address(0), 0)
+        .get(
+            &TestTransaction::get_address(0),
+            ReplayProtector::SequenceNumber(0)
+        )
         .is_some());
     pool.reject_transaction(
         &TestTransaction::get_address(0),
-        1,
+        ReplayProtector::SequenceNumber(1),
         &txns[1].clone().committed_hash(),
         &DiscardedVMStatus::SEQUENCE_NUMBER_TOO_NEW,
     );
     assert!(pool
         .get_transaction_store()
-        .get(&TestTransaction::get_address(0), 1)
+        .get(
+            &TestTransaction::get_address(0),
+            ReplayProtector::SequenceNumber(1)
+        )
         .is_some());
 
     // reject with correct hash should have effect
     pool.reject_transaction(
         &TestTransaction::get_address(0),
-        0,
+        ReplayProtector::SequenceNumber(0),
         &txns[0].clone().committed_hash(),
         &DiscardedVMStatus::MALFORMED,
     );
     assert!(pool
         .get_transaction_store()
-        .get(&TestTransaction::get_address(0), 0)
+        .get(
+            &TestTransaction::get_address(0),
+            ReplayProtector::SequenceNumber(0)
+        )
         .is_none());
     pool.reject_transaction(
         &TestTransaction::get_address(0),
-        1,
+        ReplayProtector::SequenceNumber(1),
         &txns[1].clone().committed_hash(),
         &DiscardedVMStatus::MALFORMED,
     );
     assert!(pool
         .get_transaction_store()
-        .get(&TestTransaction::get_address(0), 1)
+        .get(
+            &TestTransaction::get_address(0),
+            ReplayProtector::SequenceNumber(1)
+        )
         .is_none());
 }
 
@@ -309,7 +332,10 @@ fn test_commit_callback() {
     // Check that pool is empty.
     assert!(pool.get_batch(1, 1024, true, false, btreemap![]).is_empty());
     // Transaction 5 got back from consensus.
-    pool.commit_transaction(&TestTransaction::get_address(1), 5);
+    pool.commit_transaction(
+        &TestTransaction::get_address(1),
+        ReplayProtector::SequenceNumber(5),
+    );
     // Verify that we can execute transaction 6.
     assert_eq!(
         pool.get_batch(1, 1024, true, false, btreemap![])[0],
@@ -317,6 +343,43 @@ fn test_commit_callback() {
     );
 }
 
+#[test]
+fn test_orderless_transactions_are_never_parked() {
+    let (mut pool, mut consensus) = setup_mempool();
+    let address = TestTransaction::get_address(1);
+
+    // The sequence number gap parks the ordered transaction, but not the orderless ones.
+    add_txn(&mut pool, TestTransaction::new(1, 1, 1)).unwrap();
+    let orderless_txns = add_txns_to_mempool(&mut pool, vec![
+        TestTransaction::new_orderless(1, 7, 1),
+        TestTransaction::new_orderless(1, 3, 2),
+    ]);
+    assert_eq!(pool.get_parking_lot_size(), 1);
+    let (timeline, _) = pool.read_timeline(&vec![0].into(), 10);
+    assert_eq!(timeline.len(), 2);
+    assert_eq!(consensus.get_block(&mut pool, 10, 10240), vec![
+        orderless_txns[1].clone(),
+        orderless_txns[0].clone()
+    ]);
+
+    // Committing an orderless transaction removes only that transaction.
+    pool.commit_transaction(&address, ReplayProtector::Nonce(7));
+    assert!(pool
+        .get_by_hash(orderless_txns[0].clone().committed_hash())
+        .is_none());
+    assert!(pool
+        .get_by_hash(orderless_txns[1].clone().committed_hash())
+        .is_some());
+    assert_eq!(pool.get_parking_lot_size(), 1);
+
+    // Committing the missing sequence number unparks the ordered transaction as usual.
+    pool.commit_transaction(&address, ReplayProtector::SequenceNumber(0));
+    assert_eq!(pool.get_parking_lot_size(), 0);
+    let block = consensus.get_block(&mut pool, 10, 10240);
+    assert_eq!(block.len(), 1);
+    assert_eq!(block[0].sequence_number(), 1);
+}
+
 #[test]
 fn test_reset_sequence_number_on_failure() {
     let mut pool = setup_mempool().0;
@@ -335,13 +398,13 @@ fn test_reset_sequence_number_on_failure() {
     // Notify mempool about failure in arbitrary order
     pool.reject_transaction(
         &TestTransaction::get_address(1),
-        0,
+        ReplayProtector::SequenceNumber(0),
         &hashes[0],
         &DiscardedVMStatus::MALFORMED,
     );
     pool.reject_transaction(
         &TestTransaction::get_address(1),
-        1,
+        ReplayProtector::SequenceNumber(1),
         &hashes[1],
         &DiscardedVMStatus::MALFORMED,
     );
@@ -384,7 +447,10 @@ fn test_timeline() {
     assert_eq!(view(timeline), vec![2, 3]);
 
     // Simulate callback from consensus to unblock txn 5.
-    pool.commit_transaction(&TestTransaction::get_address(1), 4);
+    pool.commit_transaction(
+        &TestTransaction::get_address(1),
+        ReplayProtector::SequenceNumber(4),
+    );
     let (timeline, _) = pool.read_timeline(&vec![0].into(), 10);
     assert_eq!(view(timeline), vec![5]);
     // check parking lot is empty
@@ -434,7 +500,10 @@ fn test_multi_bucket_timeline() {
     assert_eq!(view(timeline), vec![3]);
 
     // Simulate callback from consensus to unblock txn 5.
-    pool.commit_transaction(&TestTransaction::get_address(1), 4);
+    pool.commit_transaction(
+        &TestTransaction::get_address(1),
+        ReplayProtector::SequenceNumber(4),
+    );
     let (timeline, _) = pool.read_timeline(&vec![0, 0, 0].into(), 10);
     assert_eq!(view(timeline), vec![5]);
     // check parking lot is empty
@@ -488,19 +557,31 @@ fn test_multi_bucket_removal() {
     let (timeline, _) = pool.read_timeline(&vec![0, 0, 0].into(), 10);
     assert_eq!(view(timeline), vec![0, 1, 2, 3]);
 
-    pool.commit_transaction(&TestTransaction::get_address(1), 0);
+    pool.commit_transaction(
+        &TestTransaction::get_address(1),
+        ReplayProtector::SequenceNumber(0),
+    );
     let (timeline, _) = pool.read_timeline(&vec![0, 0, 0].into(), 10);
     assert_eq!(view(timeline), vec![1, 2, 3]);
 
-    pool.commit_transaction(&TestTransaction::get_address(1), 1);
+    pool.commit_transaction(
+        &TestTransaction::get_address(1),
+        ReplayProtector::SequenceNumber(1),
+    );
     let (timeline, _) = pool.read_timeline(&vec![0, 0, 0].into(), 10);
     assert_eq!(view(timeline), vec![2, 3]);
 
-    pool.commit_transaction(&TestTransaction::get_address(1), 2);
+    pool.commit_transaction(
+        &TestTransaction::get_address(1),
+        ReplayProtector::SequenceNumber(2),
+    );
     let (timeline, _) = pool.read_timeline(&vec![0, 0, 0].into(), 10);
     assert_eq!(view(timeline), vec![3]);
 
-    pool.commit_transaction(&TestTransaction::get_address(1), 3);
+    pool.commit_transaction(
+        &TestTransaction::get_address(1),
+        ReplayProtector::SequenceNumber(3),
+    );
     let (timeline, _) = pool.read_timeline(&vec![0, 0, 0].into(), 10);
     assert!(view(timeline).is_empty());
 }
@@ -517,7 +598,10 @@ fn test_capacity() {
     assert!(add_txn(&mut pool, TestTransaction::new(1, 1, 1)).is_err());
 
     // Commit transaction and free space.
-    pool.commit_transaction(&TestTransaction::get_address(1), 0);
+    pool.commit_transaction(
+        &TestTransaction::get_address(1),
+        ReplayProtector::SequenceNumber(0),
+    );
     assert!(add_txn(&mut pool, TestTransaction::new(1, 1, 1)).is_ok());
 
     // Fill it up and check that GC routine will clear space.
@@ -797,9 +881,18 @@ fn test_transaction_store_remove_account_if_empty() {
     add_txn(&mut pool, TestTransaction::new(2, 0, 1)).unwrap();
     assert_eq!(pool.get_transaction_store().get_transactions().len(), 2);
 
-    pool.commit_transaction(&TestTransaction::get_address(1), 0);
-    pool.commit_transaction(&TestTransaction::get_address(1), 1);
-    pool.commit_transaction(&TestTransaction::get_address(2), 0);
+    pool.commit_transaction(
+        &TestTransaction::get_address(1),
+        ReplayProtector::SequenceNumber(0),
+    );
+    pool.commit_transaction(
+        &TestTransaction::get_address(1),
+        ReplayProtector::SequenceNumber(1),
+    );
+    pool.commit_transaction(
+        &TestTransaction::get_address(2),
+        ReplayProtector::SequenceNumber(0),
+    );
     assert_eq!(pool.get_transaction_store().get_transactions().len(), 0);
 
     let txn = TestTransaction::new(2, 2, 1).make_signed_transaction();
@@ -809,7 +902,7 @@ fn test_transaction_store_remove_account_if_empty() {
 
     pool.reject_transaction(
         &TestTransaction::get_address(2),
-        2,
+        ReplayProtector::SequenceNumber(2),
         &hash,
         &DiscardedVMStatus::MALFORMED,
     );
@@ -824,9 +917,15 @@ fn test_sequence_number_behavior_at_capacity() {
 
     add_txn(&mut pool, TestTransaction::new(0, 0, 1)).unwrap();
     add_txn(&mut pool, TestTransaction::new(1, 0, 1)).unwrap();
-    pool.commit_transaction(&TestTransaction::get_address(1), 0);
+    pool.commit_transaction(
+        &TestTransaction::get_address(1),
+        ReplayProtector::SequenceNumber(0),
+    );
     add_txn(&mut pool, TestTransaction::new(2, 0, 1)).unwrap();
-    pool.commit_transaction(&TestTransaction::get_address(2), 0);
+    pool.commit_transaction(
+        &TestTransaction::get_address(2),
+        ReplayProtector::SequenceNumber(0),
+    );
 
     let batch = pool.get_batch(10, 10240, true, false, btreemap![]);
     assert_eq!(batch.len(), 1);
@@ -906,8 +1005,10 @@ fn test_include_gas_upgraded() {
     )
     .unwrap();
 
-    let low_gas_txn =
-        TransactionSummary::new(TestTransaction::get_address(address_index), sequence_number);
+    let low_gas_txn = TransactionSummary::new(
+        TestTransaction::get_address(address_index),
+        ReplayProtector::SequenceNumber(sequence_number),
+    );
     let batch = pool.get_batch(10, 10240, true, true, btreemap! {
         low_gas_txn => TransactionInProgress::new(low_gas_price)
     });
@@ -919,8 +1020,10 @@ fn test_include_gas_upgraded() {
         TestTransaction::new(address_index, sequence_number, high_gas_price),
     )
     .unwrap();
-    let high_gas_txn =
-        TransactionSummary::new(TestTransaction::get_address(address_index), sequence_number);
+    let high_gas_txn = TransactionSummary::new(
+        TestTransaction::get_address(address_index),
+        ReplayProtector::SequenceNumber(sequence_number),
+    );
 
     // When gas upgraded is allowed and the low gas txn (but not the high gas txn) is excluded, will the high gas txn be included.
     let batch = pool.get_batch(10, 10240, true, true, btreemap! {
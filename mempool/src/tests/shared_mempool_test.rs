@@ -36,7 +36,7 @@ async fn test_consensus_events_rejected_txns() {
 
     let transactions = vec![RejectedTransactionSummary {
         sender: rejected_txn.sender(),
-        sequence_number: rejected_txn.sequence_number(),
+        replay_protector: rejected_txn.replay_protector(),
         hash: rejected_txn.committed_hash(),
         reason: DiscardedVMStatus::MALFORMED,
     }];
@@ -71,6 +71,7 @@ pub enum ProtocolId {
     JWKConsensusRpcCompressed = 24,
     JWKConsensusRpcBcs = 25,
     JWKConsensusRpcJson = 26,
+    ConsensusObserver = 27,
 }
 
 /// The encoding types for Protocols
@@ -111,6 +112,7 @@ impl ProtocolId {
             JWKConsensusRpcCompressed => "JWKConsensusRpcCompressed",
             JWKConsensusRpcBcs => "JWKConsensusRpcBcs",
             JWKConsensusRpcJson => "JWKConsensusRpcJson",
+            ConsensusObserver => "ConsensusObserver",
         }
     }
 
@@ -144,6 +146,7 @@ impl ProtocolId {
             ProtocolId::JWKConsensusRpcCompressed,
             ProtocolId::JWKConsensusRpcBcs,
             ProtocolId::JWKConsensusRpcJson,
+            ProtocolId::ConsensusObserver,
         ]
     }
 
@@ -159,6 +162,7 @@ impl ProtocolId {
             },
             ProtocolId::JWKConsensusDirectSendCompressed
             | ProtocolId::JWKConsensusRpcCompressed => Encoding::CompressedBcs(RECURSION_LIMIT),
+            ProtocolId::ConsensusObserver => Encoding::CompressedBcs(RECURSION_LIMIT),
             ProtocolId::MempoolDirectSend => Encoding::CompressedBcs(USER_INPUT_RECURSION_LIMIT),
             ProtocolId::MempoolRpc => Encoding::Bcs(USER_INPUT_RECURSION_LIMIT),
             _ => Encoding::Bcs(RECURSION_LIMIT),
@@ -177,6 +181,7 @@ impl ProtocolId {
             },
             ProtocolId::JWKConsensusDirectSendCompressed
             | ProtocolId::JWKConsensusRpcCompressed => CompressionClient::JWKConsensus,
+            ProtocolId::ConsensusObserver => CompressionClient::ConsensusObserver,
             protocol_id => unreachable!(
                 "The given protocol ({:?}) should not be using compression!",
                 protocol_id
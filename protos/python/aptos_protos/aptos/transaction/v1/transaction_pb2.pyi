@@ -1044,6 +1044,7 @@ class AnySignature(_message.Message):
         "secp256k1_ecdsa",
         "webauthn",
         "keyless",
+        "secp256r1_ecdsa",
     ]
 
     class Type(int, metaclass=_enum_type_wrapper.EnumTypeWrapper):
@@ -1053,23 +1054,27 @@ class AnySignature(_message.Message):
         TYPE_SECP256K1_ECDSA: _ClassVar[AnySignature.Type]
         TYPE_WEBAUTHN: _ClassVar[AnySignature.Type]
         TYPE_KEYLESS: _ClassVar[AnySignature.Type]
+        TYPE_SECP256R1_ECDSA: _ClassVar[AnySignature.Type]
     TYPE_UNSPECIFIED: AnySignature.Type
     TYPE_ED25519: AnySignature.Type
     TYPE_SECP256K1_ECDSA: AnySignature.Type
     TYPE_WEBAUTHN: AnySignature.Type
     TYPE_KEYLESS: AnySignature.Type
+    TYPE_SECP256R1_ECDSA: AnySignature.Type
     TYPE_FIELD_NUMBER: _ClassVar[int]
     SIGNATURE_FIELD_NUMBER: _ClassVar[int]
     ED25519_FIELD_NUMBER: _ClassVar[int]
     SECP256K1_ECDSA_FIELD_NUMBER: _ClassVar[int]
     WEBAUTHN_FIELD_NUMBER: _ClassVar[int]
     KEYLESS_FIELD_NUMBER: _ClassVar[int]
+    SECP256R1_ECDSA_FIELD_NUMBER: _ClassVar[int]
     type: AnySignature.Type
     signature: bytes
     ed25519: Ed25519
     secp256k1_ecdsa: Secp256k1Ecdsa
     webauthn: WebAuthn
     keyless: Keyless
+    secp256r1_ecdsa: Secp256r1Ecdsa
     def __init__(
         self,
         type: _Optional[_Union[AnySignature.Type, str]] = ...,
@@ -1078,6 +1083,7 @@ class AnySignature(_message.Message):
         secp256k1_ecdsa: _Optional[_Union[Secp256k1Ecdsa, _Mapping]] = ...,
         webauthn: _Optional[_Union[WebAuthn, _Mapping]] = ...,
         keyless: _Optional[_Union[Keyless, _Mapping]] = ...,
+        secp256r1_ecdsa: _Optional[_Union[Secp256r1Ecdsa, _Mapping]] = ...,
     ) -> None: ...
 
 class Ed25519(_message.Message):
@@ -1104,6 +1110,12 @@ class Keyless(_message.Message):
     signature: bytes
     def __init__(self, signature: _Optional[bytes] = ...) -> None: ...
 
+class Secp256r1Ecdsa(_message.Message):
+    __slots__ = ["signature"]
+    SIGNATURE_FIELD_NUMBER: _ClassVar[int]
+    signature: bytes
+    def __init__(self, signature: _Optional[bytes] = ...) -> None: ...
+
 class SingleKeySignature(_message.Message):
     __slots__ = ["public_key", "signature"]
     PUBLIC_KEY_FIELD_NUMBER: _ClassVar[int]
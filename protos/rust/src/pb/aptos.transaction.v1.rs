@@ -913,7 +913,7 @@ pub struct AnySignature {
     #[prost(bytes="vec", tag="2")]
     pub signature: ::prost::alloc::vec::Vec<u8>,
     /// Support: >= 1.10.
-    #[prost(oneof="any_signature::SignatureVariant", tags="3, 4, 5, 6")]
+    #[prost(oneof="any_signature::SignatureVariant", tags="3, 4, 5, 6, 7")]
     pub signature_variant: ::core::option::Option<any_signature::SignatureVariant>,
 }
 /// Nested message and enum types in `AnySignature`.
@@ -926,6 +926,7 @@ pub mod any_signature {
         Secp256k1Ecdsa = 2,
         Webauthn = 3,
         Keyless = 4,
+        Secp256r1Ecdsa = 5,
     }
     impl Type {
         /// String value of the enum field names used in the ProtoBuf definition.
@@ -939,6 +940,7 @@ pub mod any_signature {
                 Type::Secp256k1Ecdsa => "TYPE_SECP256K1_ECDSA",
                 Type::Webauthn => "TYPE_WEBAUTHN",
                 Type::Keyless => "TYPE_KEYLESS",
+                Type::Secp256r1Ecdsa => "TYPE_SECP256R1_ECDSA",
             }
         }
         /// Creates an enum from field names used in the ProtoBuf definition.
@@ -949,6 +951,7 @@ pub mod any_signature {
                 "TYPE_SECP256K1_ECDSA" => Some(Self::Secp256k1Ecdsa),
                 "TYPE_WEBAUTHN" => Some(Self::Webauthn),
                 "TYPE_KEYLESS" => Some(Self::Keyless),
+                "TYPE_SECP256R1_ECDSA" => Some(Self::Secp256r1Ecdsa),
                 _ => None,
             }
         }
@@ -965,6 +968,8 @@ pub mod any_signature {
         Webauthn(super::WebAuthn),
         #[prost(message, tag="6")]
         Keyless(super::Keyless),
+        #[prost(message, tag="7")]
+        Secp256r1Ecdsa(super::Secp256r1Ecdsa),
     }
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -993,6 +998,12 @@ pub struct Keyless {
 }
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Secp256r1Ecdsa {
+    #[prost(bytes="vec", tag="1")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct SingleKeySignature {
     #[prost(message, optional, tag="1")]
     pub public_key: ::core::option::Option<AnyPublicKey>,
@@ -1215,7 +1226,7 @@ impl MoveAbility {
 }
 /// Encoded file descriptor set for the `aptos.transaction.v1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0xa0, 0x8f, 0x02, 0x0a, 0x26, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2f, 0x74, 0x72, 0x61, 0x6e,
+    0x0a, 0xff, 0x91, 0x02, 0x0a, 0x26, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2f, 0x74, 0x72, 0x61, 0x6e,
     0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2f, 0x76, 0x31, 0x2f, 0x74, 0x72, 0x61, 0x6e, 0x73,
     0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x14, 0x61, 0x70,
     0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e,
@@ -1912,7 +1923,7 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x44, 0x53, 0x41, 0x10, 0x02, 0x12, 0x18, 0x0a, 0x14, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x53, 0x45,
     0x43, 0x50, 0x32, 0x35, 0x36, 0x52, 0x31, 0x5f, 0x45, 0x43, 0x44, 0x53, 0x41, 0x10, 0x03, 0x12,
     0x10, 0x0a, 0x0c, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x4b, 0x45, 0x59, 0x4c, 0x45, 0x53, 0x53, 0x10,
-    0x04, 0x22, 0xf6, 0x03, 0x0a, 0x0c, 0x41, 0x6e, 0x79, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75,
+    0x04, 0x22, 0xe2, 0x04, 0x0a, 0x0c, 0x41, 0x6e, 0x79, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75,
     0x72, 0x65, 0x12, 0x3b, 0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0e,
     0x32, 0x27, 0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63,
     0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x6e, 0x79, 0x53, 0x69, 0x67, 0x6e, 0x61,
@@ -1935,1457 +1946,1479 @@ pub const FILE_DESCRIPTOR_SET: &[u8] = &[
     0x65, 0x79, 0x6c, 0x65, 0x73, 0x73, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1d, 0x2e, 0x61,
     0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e,
     0x2e, 0x76, 0x31, 0x2e, 0x4b, 0x65, 0x79, 0x6c, 0x65, 0x73, 0x73, 0x48, 0x00, 0x52, 0x07, 0x6b,
-    0x65, 0x79, 0x6c, 0x65, 0x73, 0x73, 0x22, 0x6d, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x14,
-    0x0a, 0x10, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49,
-    0x45, 0x44, 0x10, 0x00, 0x12, 0x10, 0x0a, 0x0c, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x45, 0x44, 0x32,
-    0x35, 0x35, 0x31, 0x39, 0x10, 0x01, 0x12, 0x18, 0x0a, 0x14, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x53,
-    0x45, 0x43, 0x50, 0x32, 0x35, 0x36, 0x4b, 0x31, 0x5f, 0x45, 0x43, 0x44, 0x53, 0x41, 0x10, 0x02,
-    0x12, 0x11, 0x0a, 0x0d, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x57, 0x45, 0x42, 0x41, 0x55, 0x54, 0x48,
-    0x4e, 0x10, 0x03, 0x12, 0x10, 0x0a, 0x0c, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x4b, 0x45, 0x59, 0x4c,
-    0x45, 0x53, 0x53, 0x10, 0x04, 0x42, 0x13, 0x0a, 0x11, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75,
-    0x72, 0x65, 0x5f, 0x76, 0x61, 0x72, 0x69, 0x61, 0x6e, 0x74, 0x22, 0x27, 0x0a, 0x07, 0x45, 0x64,
-    0x32, 0x35, 0x35, 0x31, 0x39, 0x12, 0x1c, 0x0a, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75,
-    0x72, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74,
-    0x75, 0x72, 0x65, 0x22, 0x2e, 0x0a, 0x0e, 0x53, 0x65, 0x63, 0x70, 0x32, 0x35, 0x36, 0x6b, 0x31,
-    0x45, 0x63, 0x64, 0x73, 0x61, 0x12, 0x1c, 0x0a, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75,
-    0x72, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74,
-    0x75, 0x72, 0x65, 0x22, 0x28, 0x0a, 0x08, 0x57, 0x65, 0x62, 0x41, 0x75, 0x74, 0x68, 0x6e, 0x12,
-    0x1c, 0x0a, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x01, 0x20, 0x01,
-    0x28, 0x0c, 0x52, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x22, 0x27, 0x0a,
-    0x07, 0x4b, 0x65, 0x79, 0x6c, 0x65, 0x73, 0x73, 0x12, 0x1c, 0x0a, 0x09, 0x73, 0x69, 0x67, 0x6e,
-    0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x73, 0x69, 0x67,
-    0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x22, 0x99, 0x01, 0x0a, 0x12, 0x53, 0x69, 0x6e, 0x67, 0x6c,
-    0x65, 0x4b, 0x65, 0x79, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x12, 0x41, 0x0a,
-    0x0a, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x5f, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28,
-    0x0b, 0x32, 0x22, 0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61,
-    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x6e, 0x79, 0x50, 0x75, 0x62, 0x6c,
-    0x69, 0x63, 0x4b, 0x65, 0x79, 0x52, 0x09, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79,
-    0x12, 0x40, 0x0a, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x02, 0x20,
-    0x01, 0x28, 0x0b, 0x32, 0x22, 0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e,
-    0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x6e, 0x79, 0x53, 0x69,
-    0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x52, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75,
-    0x72, 0x65, 0x22, 0x6a, 0x0a, 0x10, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x65, 0x64, 0x53, 0x69, 0x67,
-    0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18,
-    0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x05, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x40, 0x0a, 0x09,
+    0x65, 0x79, 0x6c, 0x65, 0x73, 0x73, 0x12, 0x4f, 0x0a, 0x0f, 0x73, 0x65, 0x63, 0x70, 0x32, 0x35,
+    0x36, 0x72, 0x31, 0x5f, 0x65, 0x63, 0x64, 0x73, 0x61, 0x18, 0x07, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x24, 0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
+    0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x65, 0x63, 0x70, 0x32, 0x35, 0x36, 0x72, 0x31,
+    0x45, 0x63, 0x64, 0x73, 0x61, 0x48, 0x00, 0x52, 0x0e, 0x73, 0x65, 0x63, 0x70, 0x32, 0x35, 0x36,
+    0x72, 0x31, 0x45, 0x63, 0x64, 0x73, 0x61, 0x22, 0x87, 0x01, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65,
+    0x12, 0x14, 0x0a, 0x10, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49,
+    0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x10, 0x0a, 0x0c, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x45,
+    0x44, 0x32, 0x35, 0x35, 0x31, 0x39, 0x10, 0x01, 0x12, 0x18, 0x0a, 0x14, 0x54, 0x59, 0x50, 0x45,
+    0x5f, 0x53, 0x45, 0x43, 0x50, 0x32, 0x35, 0x36, 0x4b, 0x31, 0x5f, 0x45, 0x43, 0x44, 0x53, 0x41,
+    0x10, 0x02, 0x12, 0x11, 0x0a, 0x0d, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x57, 0x45, 0x42, 0x41, 0x55,
+    0x54, 0x48, 0x4e, 0x10, 0x03, 0x12, 0x10, 0x0a, 0x0c, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x4b, 0x45,
+    0x59, 0x4c, 0x45, 0x53, 0x53, 0x10, 0x04, 0x12, 0x18, 0x0a, 0x14, 0x54, 0x59, 0x50, 0x45, 0x5f,
+    0x53, 0x45, 0x43, 0x50, 0x32, 0x35, 0x36, 0x52, 0x31, 0x5f, 0x45, 0x43, 0x44, 0x53, 0x41, 0x10,
+    0x05, 0x42, 0x13, 0x0a, 0x11, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x5f, 0x76,
+    0x61, 0x72, 0x69, 0x61, 0x6e, 0x74, 0x22, 0x27, 0x0a, 0x07, 0x45, 0x64, 0x32, 0x35, 0x35, 0x31,
+    0x39, 0x12, 0x1c, 0x0a, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x22,
+    0x2e, 0x0a, 0x0e, 0x53, 0x65, 0x63, 0x70, 0x32, 0x35, 0x36, 0x6b, 0x31, 0x45, 0x63, 0x64, 0x73,
+    0x61, 0x12, 0x1c, 0x0a, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x01,
+    0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x22,
+    0x28, 0x0a, 0x08, 0x57, 0x65, 0x62, 0x41, 0x75, 0x74, 0x68, 0x6e, 0x12, 0x1c, 0x0a, 0x09, 0x73,
+    0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09,
+    0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x22, 0x27, 0x0a, 0x07, 0x4b, 0x65, 0x79,
+    0x6c, 0x65, 0x73, 0x73, 0x12, 0x1c, 0x0a, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72,
+    0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75,
+    0x72, 0x65, 0x22, 0x2e, 0x0a, 0x0e, 0x53, 0x65, 0x63, 0x70, 0x32, 0x35, 0x36, 0x72, 0x31, 0x45,
+    0x63, 0x64, 0x73, 0x61, 0x12, 0x1c, 0x0a, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72,
+    0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0c, 0x52, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75,
+    0x72, 0x65, 0x22, 0x99, 0x01, 0x0a, 0x12, 0x53, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x4b, 0x65, 0x79,
+    0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x12, 0x41, 0x0a, 0x0a, 0x70, 0x75, 0x62,
+    0x6c, 0x69, 0x63, 0x5f, 0x6b, 0x65, 0x79, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x22, 0x2e,
+    0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x6e, 0x79, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65,
+    0x79, 0x52, 0x09, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x12, 0x40, 0x0a, 0x09,
     0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32,
     0x22, 0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
     0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x6e, 0x79, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74,
-    0x75, 0x72, 0x65, 0x52, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x22, 0xd1,
-    0x01, 0x0a, 0x11, 0x4d, 0x75, 0x6c, 0x74, 0x69, 0x4b, 0x65, 0x79, 0x53, 0x69, 0x67, 0x6e, 0x61,
-    0x74, 0x75, 0x72, 0x65, 0x12, 0x43, 0x0a, 0x0b, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x5f, 0x6b,
-    0x65, 0x79, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x22, 0x2e, 0x61, 0x70, 0x74, 0x6f,
-    0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31,
-    0x2e, 0x41, 0x6e, 0x79, 0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x52, 0x0a, 0x70,
-    0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x73, 0x12, 0x46, 0x0a, 0x0a, 0x73, 0x69, 0x67,
-    0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x26, 0x2e,
-    0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f,
-    0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x65, 0x64, 0x53, 0x69, 0x67, 0x6e,
-    0x61, 0x74, 0x75, 0x72, 0x65, 0x52, 0x0a, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65,
-    0x73, 0x12, 0x2f, 0x0a, 0x13, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x73, 0x5f,
-    0x72, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x12,
-    0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x69, 0x72,
-    0x65, 0x64, 0x22, 0x4e, 0x0a, 0x0c, 0x53, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x53, 0x65, 0x6e, 0x64,
-    0x65, 0x72, 0x12, 0x3e, 0x0a, 0x06, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01,
-    0x28, 0x0b, 0x32, 0x26, 0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73,
-    0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e,
-    0x74, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x52, 0x06, 0x73, 0x65, 0x6e, 0x64,
-    0x65, 0x72, 0x22, 0xa8, 0x04, 0x0a, 0x10, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x53, 0x69,
-    0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x12, 0x3f, 0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18,
-    0x01, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x2b, 0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72,
-    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x63, 0x63,
-    0x6f, 0x75, 0x6e, 0x74, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x2e, 0x54, 0x79,
-    0x70, 0x65, 0x52, 0x04, 0x74, 0x79, 0x70, 0x65, 0x12, 0x42, 0x0a, 0x07, 0x65, 0x64, 0x32, 0x35,
-    0x35, 0x31, 0x39, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x61, 0x70, 0x74, 0x6f,
-    0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31,
-    0x2e, 0x45, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72,
-    0x65, 0x48, 0x00, 0x52, 0x07, 0x65, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39, 0x12, 0x52, 0x0a, 0x0d,
-    0x6d, 0x75, 0x6c, 0x74, 0x69, 0x5f, 0x65, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39, 0x18, 0x03, 0x20,
-    0x01, 0x28, 0x0b, 0x32, 0x2b, 0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e,
-    0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x4d, 0x75, 0x6c, 0x74, 0x69,
-    0x45, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65,
-    0x48, 0x00, 0x52, 0x0c, 0x6d, 0x75, 0x6c, 0x74, 0x69, 0x45, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39,
-    0x12, 0x5c, 0x0a, 0x14, 0x73, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x5f, 0x6b, 0x65, 0x79, 0x5f, 0x73,
-    0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x28,
-    0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69,
-    0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x53, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x4b, 0x65, 0x79, 0x53,
-    0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x48, 0x00, 0x52, 0x12, 0x73, 0x69, 0x6e, 0x67,
-    0x6c, 0x65, 0x4b, 0x65, 0x79, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x12, 0x59,
-    0x0a, 0x13, 0x6d, 0x75, 0x6c, 0x74, 0x69, 0x5f, 0x6b, 0x65, 0x79, 0x5f, 0x73, 0x69, 0x67, 0x6e,
-    0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x70,
-    0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e,
-    0x76, 0x31, 0x2e, 0x4d, 0x75, 0x6c, 0x74, 0x69, 0x4b, 0x65, 0x79, 0x53, 0x69, 0x67, 0x6e, 0x61,
-    0x74, 0x75, 0x72, 0x65, 0x48, 0x00, 0x52, 0x11, 0x6d, 0x75, 0x6c, 0x74, 0x69, 0x4b, 0x65, 0x79,
-    0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x22, 0x75, 0x0a, 0x04, 0x54, 0x79, 0x70,
-    0x65, 0x12, 0x14, 0x0a, 0x10, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43,
-    0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x10, 0x0a, 0x0c, 0x54, 0x59, 0x50, 0x45, 0x5f,
-    0x45, 0x44, 0x32, 0x35, 0x35, 0x31, 0x39, 0x10, 0x01, 0x12, 0x16, 0x0a, 0x12, 0x54, 0x59, 0x50,
-    0x45, 0x5f, 0x4d, 0x55, 0x4c, 0x54, 0x49, 0x5f, 0x45, 0x44, 0x32, 0x35, 0x35, 0x31, 0x39, 0x10,
-    0x02, 0x12, 0x13, 0x0a, 0x0f, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x53, 0x49, 0x4e, 0x47, 0x4c, 0x45,
-    0x5f, 0x4b, 0x45, 0x59, 0x10, 0x04, 0x12, 0x12, 0x0a, 0x0e, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x4d,
-    0x55, 0x4c, 0x54, 0x49, 0x5f, 0x4b, 0x45, 0x59, 0x10, 0x05, 0x22, 0x04, 0x08, 0x03, 0x10, 0x03,
-    0x42, 0x0b, 0x0a, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x22, 0xe3, 0x01,
-    0x0a, 0x13, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x53, 0x69, 0x7a,
-    0x65, 0x49, 0x6e, 0x66, 0x6f, 0x12, 0x2b, 0x0a, 0x11, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63,
-    0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d,
-    0x52, 0x10, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x42, 0x79, 0x74,
-    0x65, 0x73, 0x12, 0x4b, 0x0a, 0x0f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x5f, 0x73, 0x69, 0x7a, 0x65,
-    0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x23, 0x2e, 0x61, 0x70,
-    0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e,
-    0x76, 0x31, 0x2e, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x53, 0x69, 0x7a, 0x65, 0x49, 0x6e, 0x66, 0x6f,
-    0x52, 0x0d, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x53, 0x69, 0x7a, 0x65, 0x49, 0x6e, 0x66, 0x6f, 0x12,
-    0x52, 0x0a, 0x12, 0x77, 0x72, 0x69, 0x74, 0x65, 0x5f, 0x6f, 0x70, 0x5f, 0x73, 0x69, 0x7a, 0x65,
-    0x5f, 0x69, 0x6e, 0x66, 0x6f, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x61, 0x70,
+    0x75, 0x72, 0x65, 0x52, 0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x22, 0x6a,
+    0x0a, 0x10, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x65, 0x64, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75,
+    0x72, 0x65, 0x12, 0x14, 0x0a, 0x05, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x0d, 0x52, 0x05, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x12, 0x40, 0x0a, 0x09, 0x73, 0x69, 0x67, 0x6e,
+    0x61, 0x74, 0x75, 0x72, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x22, 0x2e, 0x61, 0x70,
     0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e,
-    0x76, 0x31, 0x2e, 0x57, 0x72, 0x69, 0x74, 0x65, 0x4f, 0x70, 0x53, 0x69, 0x7a, 0x65, 0x49, 0x6e,
-    0x66, 0x6f, 0x52, 0x0f, 0x77, 0x72, 0x69, 0x74, 0x65, 0x4f, 0x70, 0x53, 0x69, 0x7a, 0x65, 0x49,
-    0x6e, 0x66, 0x6f, 0x22, 0x56, 0x0a, 0x0d, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x53, 0x69, 0x7a, 0x65,
-    0x49, 0x6e, 0x66, 0x6f, 0x12, 0x24, 0x0a, 0x0e, 0x74, 0x79, 0x70, 0x65, 0x5f, 0x74, 0x61, 0x67,
-    0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0c, 0x74, 0x79,
-    0x70, 0x65, 0x54, 0x61, 0x67, 0x42, 0x79, 0x74, 0x65, 0x73, 0x12, 0x1f, 0x0a, 0x0b, 0x74, 0x6f,
-    0x74, 0x61, 0x6c, 0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52,
-    0x0a, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x42, 0x79, 0x74, 0x65, 0x73, 0x22, 0x4f, 0x0a, 0x0f, 0x57,
-    0x72, 0x69, 0x74, 0x65, 0x4f, 0x70, 0x53, 0x69, 0x7a, 0x65, 0x49, 0x6e, 0x66, 0x6f, 0x12, 0x1b,
-    0x0a, 0x09, 0x6b, 0x65, 0x79, 0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28,
-    0x0d, 0x52, 0x08, 0x6b, 0x65, 0x79, 0x42, 0x79, 0x74, 0x65, 0x73, 0x12, 0x1f, 0x0a, 0x0b, 0x76,
-    0x61, 0x6c, 0x75, 0x65, 0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d,
-    0x52, 0x0a, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x42, 0x79, 0x74, 0x65, 0x73, 0x2a, 0xea, 0x02, 0x0a,
-    0x09, 0x4d, 0x6f, 0x76, 0x65, 0x54, 0x79, 0x70, 0x65, 0x73, 0x12, 0x1a, 0x0a, 0x16, 0x4d, 0x4f,
-    0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49,
-    0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x13, 0x0a, 0x0f, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54,
-    0x59, 0x50, 0x45, 0x53, 0x5f, 0x42, 0x4f, 0x4f, 0x4c, 0x10, 0x01, 0x12, 0x11, 0x0a, 0x0d, 0x4d,
-    0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x55, 0x38, 0x10, 0x02, 0x12, 0x12,
-    0x0a, 0x0e, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x55, 0x31, 0x36,
-    0x10, 0x0c, 0x12, 0x12, 0x0a, 0x0e, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53,
-    0x5f, 0x55, 0x33, 0x32, 0x10, 0x0d, 0x12, 0x12, 0x0a, 0x0e, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54,
-    0x59, 0x50, 0x45, 0x53, 0x5f, 0x55, 0x36, 0x34, 0x10, 0x03, 0x12, 0x13, 0x0a, 0x0f, 0x4d, 0x4f,
-    0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x55, 0x31, 0x32, 0x38, 0x10, 0x04, 0x12,
-    0x13, 0x0a, 0x0f, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x55, 0x32,
-    0x35, 0x36, 0x10, 0x0e, 0x12, 0x16, 0x0a, 0x12, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50,
-    0x45, 0x53, 0x5f, 0x41, 0x44, 0x44, 0x52, 0x45, 0x53, 0x53, 0x10, 0x05, 0x12, 0x15, 0x0a, 0x11,
-    0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x53, 0x49, 0x47, 0x4e, 0x45,
-    0x52, 0x10, 0x06, 0x12, 0x15, 0x0a, 0x11, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45,
-    0x53, 0x5f, 0x56, 0x45, 0x43, 0x54, 0x4f, 0x52, 0x10, 0x07, 0x12, 0x15, 0x0a, 0x11, 0x4d, 0x4f,
-    0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x53, 0x54, 0x52, 0x55, 0x43, 0x54, 0x10,
-    0x08, 0x12, 0x21, 0x0a, 0x1d, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f,
-    0x47, 0x45, 0x4e, 0x45, 0x52, 0x49, 0x43, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x50, 0x41, 0x52,
-    0x41, 0x4d, 0x10, 0x09, 0x12, 0x18, 0x0a, 0x14, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50,
-    0x45, 0x53, 0x5f, 0x52, 0x45, 0x46, 0x45, 0x52, 0x45, 0x4e, 0x43, 0x45, 0x10, 0x0a, 0x12, 0x19,
-    0x0a, 0x15, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x55, 0x4e, 0x50,
-    0x41, 0x52, 0x53, 0x41, 0x42, 0x4c, 0x45, 0x10, 0x0b, 0x2a, 0x87, 0x01, 0x0a, 0x0b, 0x4d, 0x6f,
-    0x76, 0x65, 0x41, 0x62, 0x69, 0x6c, 0x69, 0x74, 0x79, 0x12, 0x1c, 0x0a, 0x18, 0x4d, 0x4f, 0x56,
-    0x45, 0x5f, 0x41, 0x42, 0x49, 0x4c, 0x49, 0x54, 0x59, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43,
-    0x49, 0x46, 0x49, 0x45, 0x44, 0x10, 0x00, 0x12, 0x15, 0x0a, 0x11, 0x4d, 0x4f, 0x56, 0x45, 0x5f,
-    0x41, 0x42, 0x49, 0x4c, 0x49, 0x54, 0x59, 0x5f, 0x43, 0x4f, 0x50, 0x59, 0x10, 0x01, 0x12, 0x15,
-    0x0a, 0x11, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x41, 0x42, 0x49, 0x4c, 0x49, 0x54, 0x59, 0x5f, 0x44,
-    0x52, 0x4f, 0x50, 0x10, 0x02, 0x12, 0x16, 0x0a, 0x12, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x41, 0x42,
-    0x49, 0x4c, 0x49, 0x54, 0x59, 0x5f, 0x53, 0x54, 0x4f, 0x52, 0x45, 0x10, 0x03, 0x12, 0x14, 0x0a,
-    0x10, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x41, 0x42, 0x49, 0x4c, 0x49, 0x54, 0x59, 0x5f, 0x4b, 0x45,
-    0x59, 0x10, 0x04, 0x42, 0x9e, 0x01, 0x0a, 0x18, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x70, 0x74, 0x6f,
+    0x76, 0x31, 0x2e, 0x41, 0x6e, 0x79, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x52,
+    0x09, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x22, 0xd1, 0x01, 0x0a, 0x11, 0x4d,
+    0x75, 0x6c, 0x74, 0x69, 0x4b, 0x65, 0x79, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65,
+    0x12, 0x43, 0x0a, 0x0b, 0x70, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x5f, 0x6b, 0x65, 0x79, 0x73, 0x18,
+    0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x22, 0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72,
+    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x6e, 0x79,
+    0x50, 0x75, 0x62, 0x6c, 0x69, 0x63, 0x4b, 0x65, 0x79, 0x52, 0x0a, 0x70, 0x75, 0x62, 0x6c, 0x69,
+    0x63, 0x4b, 0x65, 0x79, 0x73, 0x12, 0x46, 0x0a, 0x0a, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75,
+    0x72, 0x65, 0x73, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x61, 0x70, 0x74, 0x6f,
     0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31,
-    0x42, 0x10, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x72, 0x6f,
-    0x74, 0x6f, 0x50, 0x01, 0xa2, 0x02, 0x03, 0x41, 0x54, 0x58, 0xaa, 0x02, 0x14, 0x41, 0x70, 0x74,
-    0x6f, 0x73, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x56,
-    0x31, 0xca, 0x02, 0x14, 0x41, 0x70, 0x74, 0x6f, 0x73, 0x5c, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61,
-    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x20, 0x41, 0x70, 0x74, 0x6f, 0x73,
-    0x5c, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x5c, 0x56, 0x31, 0x5c,
-    0x47, 0x50, 0x42, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x16, 0x41, 0x70,
-    0x74, 0x6f, 0x73, 0x3a, 0x3a, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e,
-    0x3a, 0x3a, 0x56, 0x31, 0x4a, 0xe4, 0xa1, 0x01, 0x0a, 0x07, 0x12, 0x05, 0x03, 0x00, 0xad, 0x04,
-    0x01, 0x0a, 0x4e, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x03, 0x00, 0x12, 0x32, 0x44, 0x20, 0x43, 0x6f,
-    0x70, 0x79, 0x72, 0x69, 0x67, 0x68, 0x74, 0x20, 0xc2, 0xa9, 0x20, 0x41, 0x70, 0x74, 0x6f, 0x73,
-    0x20, 0x46, 0x6f, 0x75, 0x6e, 0x64, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x20, 0x53, 0x50, 0x44,
-    0x58, 0x2d, 0x4c, 0x69, 0x63, 0x65, 0x6e, 0x73, 0x65, 0x2d, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x69,
-    0x66, 0x69, 0x65, 0x72, 0x3a, 0x20, 0x41, 0x70, 0x61, 0x63, 0x68, 0x65, 0x2d, 0x32, 0x2e, 0x30,
-    0x0a, 0x0a, 0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x05, 0x00, 0x1d, 0x0a, 0x09, 0x0a, 0x02, 0x03,
-    0x00, 0x12, 0x03, 0x07, 0x00, 0x2e, 0x0a, 0xa3, 0x05, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x12,
-    0x00, 0x20, 0x01, 0x1a, 0x96, 0x05, 0x20, 0x41, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6f,
-    0x6e, 0x20, 0x41, 0x70, 0x74, 0x6f, 0x73, 0x20, 0x68, 0x6f, 0x6c, 0x64, 0x73, 0x20, 0x74, 0x72,
-    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x69, 0x6e, 0x20, 0x63, 0x68,
-    0x72, 0x6f, 0x6e, 0x6f, 0x6c, 0x6f, 0x67, 0x69, 0x63, 0x61, 0x6c, 0x20, 0x6f, 0x72, 0x64, 0x65,
-    0x72, 0x20, 0x28, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61, 0x20,
-    0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x6d, 0x6f, 0x6e,
-    0x6f, 0x74, 0x6f, 0x6e, 0x69, 0x63, 0x61, 0x6c, 0x6c, 0x79, 0x20, 0x69, 0x6e, 0x63, 0x72, 0x65,
-    0x61, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x60, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x60, 0x20,
-    0x66, 0x69, 0x65, 0x6c, 0x64, 0x29, 0x0a, 0x20, 0x41, 0x6c, 0x6c, 0x20, 0x62, 0x6c, 0x6f, 0x63,
-    0x6b, 0x73, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x20,
-    0x60, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x54, 0x72,
-    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x60, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20,
-    0x61, 0x72, 0x65, 0x20, 0x66, 0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20,
-    0x7a, 0x65, 0x72, 0x6f, 0x20, 0x6f, 0x72, 0x20, 0x6d, 0x6f, 0x72, 0x65, 0x20, 0x74, 0x72, 0x61,
-    0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x2e, 0x0a, 0x20, 0x54, 0x68, 0x65, 0x20,
-    0x6e, 0x65, 0x78, 0x74, 0x20, 0x60, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x74, 0x61, 0x64,
-    0x61, 0x74, 0x61, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x60, 0x20,
-    0x64, 0x65, 0x6e, 0x6f, 0x74, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x64, 0x20,
-    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x62,
-    0x6c, 0x6f, 0x63, 0x6b, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74,
-    0x61, 0x72, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x78, 0x74, 0x20,
-    0x6f, 0x6e, 0x65, 0x2e, 0x0a, 0x0a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x42, 0x6c, 0x6f, 0x63, 0x6b,
-    0x20, 0x60, 0x68, 0x65, 0x69, 0x67, 0x68, 0x74, 0x60, 0x20, 0x69, 0x73, 0x20, 0x61, 0x20, 0x73,
-    0x74, 0x72, 0x69, 0x63, 0x74, 0x6c, 0x79, 0x20, 0x6d, 0x6f, 0x6e, 0x6f, 0x74, 0x6f, 0x6e, 0x69,
-    0x63, 0x61, 0x6c, 0x6c, 0x79, 0x20, 0x69, 0x6e, 0x63, 0x72, 0x65, 0x61, 0x73, 0x69, 0x6e, 0x67,
-    0x20, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x75,
-    0x6d, 0x62, 0x65, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x73, 0x2c, 0x0a,
-    0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x72, 0x65, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20,
-    0x6e, 0x65, 0x76, 0x65, 0x72, 0x20, 0x62, 0x65, 0x20, 0x61, 0x20, 0x67, 0x61, 0x70, 0x20, 0x69,
-    0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x2e, 0x20, 0x49,
-    0x74, 0x20, 0x69, 0x73, 0x20, 0x61, 0x6c, 0x73, 0x6f, 0x20, 0x61, 0x20, 0x75, 0x6e, 0x69, 0x71,
-    0x75, 0x65, 0x20, 0x69, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x3a, 0x20, 0x74,
-    0x68, 0x65, 0x72, 0x65, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x6e, 0x65, 0x76, 0x65, 0x72, 0x20,
-    0x62, 0x65, 0x20, 0x74, 0x77, 0x6f, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x73, 0x20, 0x77, 0x69,
-    0x74, 0x68, 0x0a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x60, 0x68, 0x65,
-    0x69, 0x67, 0x68, 0x74, 0x60, 0x2e, 0x0a, 0x0a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x47, 0x65, 0x6e,
-    0x65, 0x73, 0x69, 0x73, 0x20, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e,
-    0x20, 0x28, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x30, 0x29, 0x20, 0x69, 0x73, 0x20,
-    0x63, 0x6f, 0x6e, 0x74, 0x61, 0x69, 0x6e, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e,
-    0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
-    0x2c, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x68, 0x61, 0x73, 0x20, 0x61, 0x20, 0x68, 0x65,
-    0x69, 0x67, 0x68, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x60, 0x30, 0x60, 0x0a, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x00, 0x01, 0x12, 0x03, 0x12, 0x08, 0x0d, 0x0a, 0xde, 0x01, 0x0a, 0x04, 0x04, 0x00, 0x02,
-    0x00, 0x12, 0x03, 0x15, 0x02, 0x2f, 0x1a, 0xd0, 0x01, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74,
-    0x61, 0x6d, 0x70, 0x20, 0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x74,
-    0x68, 0x65, 0x20, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x60, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61,
-    0x74, 0x61, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x60, 0x20, 0x28,
-    0x6f, 0x72, 0x20, 0x60, 0x47, 0x65, 0x6e, 0x65, 0x73, 0x69, 0x73, 0x54, 0x72, 0x61, 0x6e, 0x73,
-    0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x60, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20,
-    0x67, 0x65, 0x6e, 0x65, 0x73, 0x69, 0x73, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x29, 0x0a, 0x20,
-    0x61, 0x6e, 0x64, 0x20, 0x65, 0x76, 0x65, 0x72, 0x79, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61,
-    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x74, 0x72,
-    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x60, 0x20, 0x77, 0x69, 0x6c, 0x6c,
-    0x20, 0x68, 0x61, 0x76, 0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x60,
-    0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x60, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x00, 0x06, 0x12, 0x03, 0x15, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x15, 0x21, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x15, 0x2d, 0x2e, 0x0a, 0x88, 0x01, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x18, 0x02,
-    0x29, 0x1a, 0x7b, 0x20, 0x48, 0x65, 0x69, 0x67, 0x68, 0x74, 0x20, 0x72, 0x65, 0x70, 0x72, 0x65,
-    0x73, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20,
-    0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x75, 0x6c, 0x74, 0x69, 0x6d,
-    0x61, 0x74, 0x65, 0x6c, 0x79, 0x2c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f,
-    0x75, 0x6e, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x60, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x74,
-    0x61, 0x64, 0x61, 0x74, 0x61, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e,
-    0x60, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x68, 0x61, 0x70, 0x70, 0x65, 0x6e, 0x65, 0x64, 0x20,
-    0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x05, 0x12, 0x03, 0x18, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x18, 0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
-    0x02, 0x01, 0x03, 0x12, 0x03, 0x18, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
-    0x08, 0x12, 0x03, 0x18, 0x14, 0x28, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x02, 0x01, 0x08, 0x06,
-    0x12, 0x03, 0x18, 0x15, 0x27, 0x0a, 0x87, 0x02, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03,
-    0x1c, 0x02, 0x28, 0x1a, 0xf9, 0x01, 0x20, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69,
-    0x6f, 0x6e, 0x73, 0x20, 0x68, 0x6f, 0x6c, 0x64, 0x73, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x74, 0x72,
-    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20,
-    0x68, 0x61, 0x70, 0x70, 0x65, 0x6e, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20,
-    0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x69, 0x73, 0x20,
-    0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x61,
-    0x74, 0x20, 0x68, 0x61, 0x70, 0x70, 0x65, 0x6e, 0x65, 0x64, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74,
-    0x69, 0x6e, 0x67, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x28, 0x61, 0x6e, 0x64, 0x20, 0x69, 0x6e,
-    0x63, 0x6c, 0x75, 0x64, 0x69, 0x6e, 0x67, 0x29, 0x0a, 0x20, 0x61, 0x20, 0x60, 0x42, 0x6c, 0x6f,
+    0x2e, 0x49, 0x6e, 0x64, 0x65, 0x78, 0x65, 0x64, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72,
+    0x65, 0x52, 0x0a, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x73, 0x12, 0x2f, 0x0a,
+    0x13, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x73, 0x5f, 0x72, 0x65, 0x71, 0x75,
+    0x69, 0x72, 0x65, 0x64, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x12, 0x73, 0x69, 0x67, 0x6e,
+    0x61, 0x74, 0x75, 0x72, 0x65, 0x73, 0x52, 0x65, 0x71, 0x75, 0x69, 0x72, 0x65, 0x64, 0x22, 0x4e,
+    0x0a, 0x0c, 0x53, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x53, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x12, 0x3e,
+    0x0a, 0x06, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x26,
+    0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69,
+    0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x53, 0x69, 0x67,
+    0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x52, 0x06, 0x73, 0x65, 0x6e, 0x64, 0x65, 0x72, 0x22, 0xa8,
+    0x04, 0x0a, 0x10, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74,
+    0x75, 0x72, 0x65, 0x12, 0x3f, 0x0a, 0x04, 0x74, 0x79, 0x70, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28,
+    0x0e, 0x32, 0x2b, 0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61,
+    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x41, 0x63, 0x63, 0x6f, 0x75, 0x6e, 0x74,
+    0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x2e, 0x54, 0x79, 0x70, 0x65, 0x52, 0x04,
+    0x74, 0x79, 0x70, 0x65, 0x12, 0x42, 0x0a, 0x07, 0x65, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39, 0x18,
+    0x02, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x26, 0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72,
+    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x45, 0x64, 0x32,
+    0x35, 0x35, 0x31, 0x39, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x48, 0x00, 0x52,
+    0x07, 0x65, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39, 0x12, 0x52, 0x0a, 0x0d, 0x6d, 0x75, 0x6c, 0x74,
+    0x69, 0x5f, 0x65, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32,
+    0x2b, 0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
+    0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x4d, 0x75, 0x6c, 0x74, 0x69, 0x45, 0x64, 0x32, 0x35,
+    0x35, 0x31, 0x39, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x48, 0x00, 0x52, 0x0c,
+    0x6d, 0x75, 0x6c, 0x74, 0x69, 0x45, 0x64, 0x32, 0x35, 0x35, 0x31, 0x39, 0x12, 0x5c, 0x0a, 0x14,
+    0x73, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x5f, 0x6b, 0x65, 0x79, 0x5f, 0x73, 0x69, 0x67, 0x6e, 0x61,
+    0x74, 0x75, 0x72, 0x65, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x28, 0x2e, 0x61, 0x70, 0x74,
+    0x6f, 0x73, 0x2e, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x76,
+    0x31, 0x2e, 0x53, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x4b, 0x65, 0x79, 0x53, 0x69, 0x67, 0x6e, 0x61,
+    0x74, 0x75, 0x72, 0x65, 0x48, 0x00, 0x52, 0x12, 0x73, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x4b, 0x65,
+    0x79, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x12, 0x59, 0x0a, 0x13, 0x6d, 0x75,
+    0x6c, 0x74, 0x69, 0x5f, 0x6b, 0x65, 0x79, 0x5f, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72,
+    0x65, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e,
+    0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x4d,
+    0x75, 0x6c, 0x74, 0x69, 0x4b, 0x65, 0x79, 0x53, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65,
+    0x48, 0x00, 0x52, 0x11, 0x6d, 0x75, 0x6c, 0x74, 0x69, 0x4b, 0x65, 0x79, 0x53, 0x69, 0x67, 0x6e,
+    0x61, 0x74, 0x75, 0x72, 0x65, 0x22, 0x75, 0x0a, 0x04, 0x54, 0x79, 0x70, 0x65, 0x12, 0x14, 0x0a,
+    0x10, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45,
+    0x44, 0x10, 0x00, 0x12, 0x10, 0x0a, 0x0c, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x45, 0x44, 0x32, 0x35,
+    0x35, 0x31, 0x39, 0x10, 0x01, 0x12, 0x16, 0x0a, 0x12, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x4d, 0x55,
+    0x4c, 0x54, 0x49, 0x5f, 0x45, 0x44, 0x32, 0x35, 0x35, 0x31, 0x39, 0x10, 0x02, 0x12, 0x13, 0x0a,
+    0x0f, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x53, 0x49, 0x4e, 0x47, 0x4c, 0x45, 0x5f, 0x4b, 0x45, 0x59,
+    0x10, 0x04, 0x12, 0x12, 0x0a, 0x0e, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x4d, 0x55, 0x4c, 0x54, 0x49,
+    0x5f, 0x4b, 0x45, 0x59, 0x10, 0x05, 0x22, 0x04, 0x08, 0x03, 0x10, 0x03, 0x42, 0x0b, 0x0a, 0x09,
+    0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x22, 0xe3, 0x01, 0x0a, 0x13, 0x54, 0x72,
+    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x53, 0x69, 0x7a, 0x65, 0x49, 0x6e, 0x66,
+    0x6f, 0x12, 0x2b, 0x0a, 0x11, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x10, 0x74, 0x72,
+    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x42, 0x79, 0x74, 0x65, 0x73, 0x12, 0x4b,
+    0x0a, 0x0f, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x5f, 0x69, 0x6e, 0x66,
+    0x6f, 0x18, 0x02, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x23, 0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e,
+    0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x45,
+    0x76, 0x65, 0x6e, 0x74, 0x53, 0x69, 0x7a, 0x65, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x0d, 0x65, 0x76,
+    0x65, 0x6e, 0x74, 0x53, 0x69, 0x7a, 0x65, 0x49, 0x6e, 0x66, 0x6f, 0x12, 0x52, 0x0a, 0x12, 0x77,
+    0x72, 0x69, 0x74, 0x65, 0x5f, 0x6f, 0x70, 0x5f, 0x73, 0x69, 0x7a, 0x65, 0x5f, 0x69, 0x6e, 0x66,
+    0x6f, 0x18, 0x03, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e,
+    0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x57,
+    0x72, 0x69, 0x74, 0x65, 0x4f, 0x70, 0x53, 0x69, 0x7a, 0x65, 0x49, 0x6e, 0x66, 0x6f, 0x52, 0x0f,
+    0x77, 0x72, 0x69, 0x74, 0x65, 0x4f, 0x70, 0x53, 0x69, 0x7a, 0x65, 0x49, 0x6e, 0x66, 0x6f, 0x22,
+    0x56, 0x0a, 0x0d, 0x45, 0x76, 0x65, 0x6e, 0x74, 0x53, 0x69, 0x7a, 0x65, 0x49, 0x6e, 0x66, 0x6f,
+    0x12, 0x24, 0x0a, 0x0e, 0x74, 0x79, 0x70, 0x65, 0x5f, 0x74, 0x61, 0x67, 0x5f, 0x62, 0x79, 0x74,
+    0x65, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0c, 0x74, 0x79, 0x70, 0x65, 0x54, 0x61,
+    0x67, 0x42, 0x79, 0x74, 0x65, 0x73, 0x12, 0x1f, 0x0a, 0x0b, 0x74, 0x6f, 0x74, 0x61, 0x6c, 0x5f,
+    0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x74, 0x6f, 0x74,
+    0x61, 0x6c, 0x42, 0x79, 0x74, 0x65, 0x73, 0x22, 0x4f, 0x0a, 0x0f, 0x57, 0x72, 0x69, 0x74, 0x65,
+    0x4f, 0x70, 0x53, 0x69, 0x7a, 0x65, 0x49, 0x6e, 0x66, 0x6f, 0x12, 0x1b, 0x0a, 0x09, 0x6b, 0x65,
+    0x79, 0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x08, 0x6b,
+    0x65, 0x79, 0x42, 0x79, 0x74, 0x65, 0x73, 0x12, 0x1f, 0x0a, 0x0b, 0x76, 0x61, 0x6c, 0x75, 0x65,
+    0x5f, 0x62, 0x79, 0x74, 0x65, 0x73, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0d, 0x52, 0x0a, 0x76, 0x61,
+    0x6c, 0x75, 0x65, 0x42, 0x79, 0x74, 0x65, 0x73, 0x2a, 0xea, 0x02, 0x0a, 0x09, 0x4d, 0x6f, 0x76,
+    0x65, 0x54, 0x79, 0x70, 0x65, 0x73, 0x12, 0x1a, 0x0a, 0x16, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54,
+    0x59, 0x50, 0x45, 0x53, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44,
+    0x10, 0x00, 0x12, 0x13, 0x0a, 0x0f, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53,
+    0x5f, 0x42, 0x4f, 0x4f, 0x4c, 0x10, 0x01, 0x12, 0x11, 0x0a, 0x0d, 0x4d, 0x4f, 0x56, 0x45, 0x5f,
+    0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x55, 0x38, 0x10, 0x02, 0x12, 0x12, 0x0a, 0x0e, 0x4d, 0x4f,
+    0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x55, 0x31, 0x36, 0x10, 0x0c, 0x12, 0x12,
+    0x0a, 0x0e, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x55, 0x33, 0x32,
+    0x10, 0x0d, 0x12, 0x12, 0x0a, 0x0e, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53,
+    0x5f, 0x55, 0x36, 0x34, 0x10, 0x03, 0x12, 0x13, 0x0a, 0x0f, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54,
+    0x59, 0x50, 0x45, 0x53, 0x5f, 0x55, 0x31, 0x32, 0x38, 0x10, 0x04, 0x12, 0x13, 0x0a, 0x0f, 0x4d,
+    0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x55, 0x32, 0x35, 0x36, 0x10, 0x0e,
+    0x12, 0x16, 0x0a, 0x12, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x41,
+    0x44, 0x44, 0x52, 0x45, 0x53, 0x53, 0x10, 0x05, 0x12, 0x15, 0x0a, 0x11, 0x4d, 0x4f, 0x56, 0x45,
+    0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x53, 0x49, 0x47, 0x4e, 0x45, 0x52, 0x10, 0x06, 0x12,
+    0x15, 0x0a, 0x11, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x56, 0x45,
+    0x43, 0x54, 0x4f, 0x52, 0x10, 0x07, 0x12, 0x15, 0x0a, 0x11, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54,
+    0x59, 0x50, 0x45, 0x53, 0x5f, 0x53, 0x54, 0x52, 0x55, 0x43, 0x54, 0x10, 0x08, 0x12, 0x21, 0x0a,
+    0x1d, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x47, 0x45, 0x4e, 0x45,
+    0x52, 0x49, 0x43, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x5f, 0x50, 0x41, 0x52, 0x41, 0x4d, 0x10, 0x09,
+    0x12, 0x18, 0x0a, 0x14, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x52,
+    0x45, 0x46, 0x45, 0x52, 0x45, 0x4e, 0x43, 0x45, 0x10, 0x0a, 0x12, 0x19, 0x0a, 0x15, 0x4d, 0x4f,
+    0x56, 0x45, 0x5f, 0x54, 0x59, 0x50, 0x45, 0x53, 0x5f, 0x55, 0x4e, 0x50, 0x41, 0x52, 0x53, 0x41,
+    0x42, 0x4c, 0x45, 0x10, 0x0b, 0x2a, 0x87, 0x01, 0x0a, 0x0b, 0x4d, 0x6f, 0x76, 0x65, 0x41, 0x62,
+    0x69, 0x6c, 0x69, 0x74, 0x79, 0x12, 0x1c, 0x0a, 0x18, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x41, 0x42,
+    0x49, 0x4c, 0x49, 0x54, 0x59, 0x5f, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45,
+    0x44, 0x10, 0x00, 0x12, 0x15, 0x0a, 0x11, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x41, 0x42, 0x49, 0x4c,
+    0x49, 0x54, 0x59, 0x5f, 0x43, 0x4f, 0x50, 0x59, 0x10, 0x01, 0x12, 0x15, 0x0a, 0x11, 0x4d, 0x4f,
+    0x56, 0x45, 0x5f, 0x41, 0x42, 0x49, 0x4c, 0x49, 0x54, 0x59, 0x5f, 0x44, 0x52, 0x4f, 0x50, 0x10,
+    0x02, 0x12, 0x16, 0x0a, 0x12, 0x4d, 0x4f, 0x56, 0x45, 0x5f, 0x41, 0x42, 0x49, 0x4c, 0x49, 0x54,
+    0x59, 0x5f, 0x53, 0x54, 0x4f, 0x52, 0x45, 0x10, 0x03, 0x12, 0x14, 0x0a, 0x10, 0x4d, 0x4f, 0x56,
+    0x45, 0x5f, 0x41, 0x42, 0x49, 0x4c, 0x49, 0x54, 0x59, 0x5f, 0x4b, 0x45, 0x59, 0x10, 0x04, 0x42,
+    0x9e, 0x01, 0x0a, 0x18, 0x63, 0x6f, 0x6d, 0x2e, 0x61, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x74, 0x72,
+    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x42, 0x10, 0x54, 0x72,
+    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x50, 0x72, 0x6f, 0x74, 0x6f, 0x50, 0x01,
+    0xa2, 0x02, 0x03, 0x41, 0x54, 0x58, 0xaa, 0x02, 0x14, 0x41, 0x70, 0x74, 0x6f, 0x73, 0x2e, 0x54,
+    0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x56, 0x31, 0xca, 0x02, 0x14,
+    0x41, 0x70, 0x74, 0x6f, 0x73, 0x5c, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x5c, 0x56, 0x31, 0xe2, 0x02, 0x20, 0x41, 0x70, 0x74, 0x6f, 0x73, 0x5c, 0x54, 0x72, 0x61,
+    0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x5c, 0x56, 0x31, 0x5c, 0x47, 0x50, 0x42, 0x4d,
+    0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0xea, 0x02, 0x16, 0x41, 0x70, 0x74, 0x6f, 0x73, 0x3a,
+    0x3a, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x3a, 0x3a, 0x56, 0x31,
+    0x4a, 0xa7, 0xa3, 0x01, 0x0a, 0x07, 0x12, 0x05, 0x03, 0x00, 0xb3, 0x04, 0x01, 0x0a, 0x4e, 0x0a,
+    0x01, 0x0c, 0x12, 0x03, 0x03, 0x00, 0x12, 0x32, 0x44, 0x20, 0x43, 0x6f, 0x70, 0x79, 0x72, 0x69,
+    0x67, 0x68, 0x74, 0x20, 0xc2, 0xa9, 0x20, 0x41, 0x70, 0x74, 0x6f, 0x73, 0x20, 0x46, 0x6f, 0x75,
+    0x6e, 0x64, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x20, 0x53, 0x50, 0x44, 0x58, 0x2d, 0x4c, 0x69,
+    0x63, 0x65, 0x6e, 0x73, 0x65, 0x2d, 0x49, 0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72,
+    0x3a, 0x20, 0x41, 0x70, 0x61, 0x63, 0x68, 0x65, 0x2d, 0x32, 0x2e, 0x30, 0x0a, 0x0a, 0x08, 0x0a,
+    0x01, 0x02, 0x12, 0x03, 0x05, 0x00, 0x1d, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x07,
+    0x00, 0x2e, 0x0a, 0xa3, 0x05, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x12, 0x00, 0x20, 0x01, 0x1a,
+    0x96, 0x05, 0x20, 0x41, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6f, 0x6e, 0x20, 0x41, 0x70,
+    0x74, 0x6f, 0x73, 0x20, 0x68, 0x6f, 0x6c, 0x64, 0x73, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61,
+    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x69, 0x6e, 0x20, 0x63, 0x68, 0x72, 0x6f, 0x6e, 0x6f,
+    0x6c, 0x6f, 0x67, 0x69, 0x63, 0x61, 0x6c, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20, 0x28, 0x6f,
+    0x72, 0x64, 0x65, 0x72, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x61, 0x20, 0x74, 0x72, 0x61, 0x6e,
+    0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x6d, 0x6f, 0x6e, 0x6f, 0x74, 0x6f, 0x6e,
+    0x69, 0x63, 0x61, 0x6c, 0x6c, 0x79, 0x20, 0x69, 0x6e, 0x63, 0x72, 0x65, 0x61, 0x73, 0x69, 0x6e,
+    0x67, 0x20, 0x60, 0x76, 0x65, 0x72, 0x73, 0x69, 0x6f, 0x6e, 0x60, 0x20, 0x66, 0x69, 0x65, 0x6c,
+    0x64, 0x29, 0x0a, 0x20, 0x41, 0x6c, 0x6c, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x73, 0x20, 0x73,
+    0x74, 0x61, 0x72, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x20, 0x60, 0x42, 0x6c, 0x6f,
     0x63, 0x6b, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61,
-    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x60, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x65, 0x76, 0x65, 0x72,
-    0x79, 0x20, 0x6f, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
-    0x69, 0x6f, 0x6e, 0x20, 0x75, 0x70, 0x20, 0x74, 0x6f, 0x20, 0x28, 0x62, 0x75, 0x74, 0x20, 0x65,
-    0x78, 0x63, 0x6c, 0x75, 0x64, 0x69, 0x6e, 0x67, 0x29, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65,
-    0x78, 0x74, 0x20, 0x60, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74,
-    0x61, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x60, 0x2e, 0x0a, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x1c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x00, 0x02, 0x02, 0x06, 0x12, 0x03, 0x1c, 0x0b, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1c, 0x17, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x02, 0x03, 0x12, 0x03, 0x1c, 0x26, 0x27, 0x0a, 0x99, 0x01, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03,
-    0x12, 0x03, 0x1f, 0x02, 0x16, 0x1a, 0x8b, 0x01, 0x20, 0x43, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x49,
-    0x44, 0x20, 0x69, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x73, 0x20, 0x75, 0x73, 0x20, 0x77, 0x68, 0x69,
-    0x63, 0x68, 0x20, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x77, 0x65, 0x27, 0x72, 0x65, 0x20, 0x74,
-    0x72, 0x79, 0x69, 0x6e, 0x67, 0x20, 0x74, 0x6f, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x2c, 0x20,
-    0x74, 0x68, 0x69, 0x73, 0x20, 0x69, 0x73, 0x20, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x61, 0x6e,
-    0x74, 0x20, 0x74, 0x6f, 0x20, 0x65, 0x6e, 0x73, 0x75, 0x72, 0x65, 0x20, 0x74, 0x68, 0x61, 0x74,
-    0x20, 0x77, 0x65, 0x27, 0x72, 0x65, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x6d, 0x69, 0x78, 0x69, 0x6e,
-    0x67, 0x20, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x73, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20,
-    0x61, 0x20, 0x73, 0x69, 0x6e, 0x67, 0x6c, 0x65, 0x20, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e,
-    0x65, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x1f, 0x02,
-    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x1f, 0x09, 0x11, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x1f, 0x14, 0x15, 0x0a, 0x8c, 0x04,
-    0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x27, 0x00, 0x42, 0x01, 0x1a, 0xff, 0x03, 0x20, 0x54, 0x72,
-    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x61, 0x73, 0x20, 0x69, 0x74, 0x20,
-    0x68, 0x61, 0x70, 0x70, 0x65, 0x6e, 0x65, 0x64, 0x20, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20,
-    0x63, 0x68, 0x61, 0x69, 0x6e, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x72, 0x65, 0x20, 0x61, 0x72, 0x65,
-    0x20, 0x34, 0x20, 0x74, 0x79, 0x70, 0x65, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x72, 0x61, 0x6e,
-    0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x3a, 0x0a, 0x20, 0x2d, 0x20, 0x55, 0x73, 0x65,
-    0x72, 0x20, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x3a, 0x20, 0x61,
-    0x20, 0x75, 0x73, 0x65, 0x72, 0x20, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x74, 0x65, 0x64, 0x20,
-    0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x6f, 0x20, 0x69,
-    0x6e, 0x74, 0x65, 0x72, 0x61, 0x63, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65,
-    0x20, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x0a, 0x20, 0x2d, 0x20, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x20,
-    0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x20, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63,
-    0x74, 0x69, 0x6f, 0x6e, 0x3a, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f,
-    0x6e, 0x73, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x74, 0x6f, 0x20, 0x67, 0x72, 0x6f,
-    0x75, 0x70, 0x20, 0x74, 0x6f, 0x67, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74, 0x72, 0x61, 0x6e,
-    0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x6d, 0x69, 0x6e, 0x67,
-    0x20, 0x61, 0x20, 0x22, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x22, 0x0a, 0x20, 0x2d, 0x20, 0x53, 0x74,
-    0x61, 0x74, 0x65, 0x20, 0x43, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x20, 0x54,
-    0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x3a, 0x20, 0x74, 0x72, 0x61, 0x6e,
-    0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74,
-    0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20,
-    0x73, 0x6f, 0x20, 0x77, 0x68, 0x65, 0x6e, 0x20, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x61, 0x74, 0x6f,
-    0x72, 0x20, 0x61, 0x67, 0x72, 0x65, 0x65, 0x64, 0x20, 0x6f, 0x6e, 0x20, 0x61, 0x20, 0x70, 0x61,
-    0x72, 0x74, 0x69, 0x63, 0x75, 0x6c, 0x61, 0x72, 0x20, 0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x20,
-    0x73, 0x74, 0x61, 0x74, 0x65, 0x0a, 0x20, 0x2d, 0x20, 0x47, 0x65, 0x6e, 0x65, 0x73, 0x69, 0x73,
-    0x20, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x3a, 0x20, 0x74, 0x68,
-    0x65, 0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
-    0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x69, 0x6e,
-    0x2c, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x63, 0x6f, 0x72, 0x65, 0x20,
-    0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x76, 0x61, 0x6c,
-    0x69, 0x64, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x69, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69,
-    0x6f, 0x6e, 0x20, 0x62, 0x61, 0x6b, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x01, 0x01, 0x12, 0x03, 0x27, 0x08, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00,
-    0x12, 0x03, 0x28, 0x02, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03,
-    0x28, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x28, 0x21,
-    0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x28, 0x2d, 0x2e, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x29, 0x02, 0x2a, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x01, 0x05, 0x12, 0x03, 0x29, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x29, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x29, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x08, 0x12,
-    0x03, 0x29, 0x15, 0x29, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x02, 0x01, 0x08, 0x06, 0x12, 0x03,
-    0x29, 0x16, 0x28, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x2a, 0x02, 0x1b,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x06, 0x12, 0x03, 0x2a, 0x02, 0x11, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x2a, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x2a, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01,
-    0x02, 0x03, 0x12, 0x03, 0x2b, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x05,
-    0x12, 0x03, 0x2b, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01, 0x12, 0x03,
-    0x2b, 0x09, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x03, 0x2b, 0x11,
-    0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x08, 0x12, 0x03, 0x2b, 0x13, 0x27, 0x0a,
-    0x0d, 0x0a, 0x06, 0x04, 0x01, 0x02, 0x03, 0x08, 0x06, 0x12, 0x03, 0x2b, 0x14, 0x26, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x01, 0x02, 0x04, 0x12, 0x03, 0x2c, 0x02, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x04, 0x05, 0x12, 0x03, 0x2c, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x04, 0x01, 0x12, 0x03, 0x2c, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x03,
-    0x12, 0x03, 0x2c, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x08, 0x12, 0x03,
-    0x2c, 0x1a, 0x2e, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x02, 0x04, 0x08, 0x06, 0x12, 0x03, 0x2c,
-    0x1b, 0x2d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x01, 0x04, 0x00, 0x12, 0x04, 0x2e, 0x02, 0x35, 0x03,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x04, 0x00, 0x01, 0x12, 0x03, 0x2e, 0x07, 0x16, 0x0a, 0x0d,
-    0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x2f, 0x04, 0x25, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2f, 0x04, 0x20, 0x0a, 0x0e, 0x0a,
-    0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x2f, 0x23, 0x24, 0x0a, 0x0d, 0x0a,
-    0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x30, 0x04, 0x21, 0x0a, 0x0e, 0x0a, 0x07,
-    0x04, 0x01, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x30, 0x04, 0x1c, 0x0a, 0x0e, 0x0a, 0x07,
-    0x04, 0x01, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x30, 0x1f, 0x20, 0x0a, 0x0d, 0x0a, 0x06,
-    0x04, 0x01, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x31, 0x04, 0x28, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
-    0x01, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x31, 0x04, 0x23, 0x0a, 0x0e, 0x0a, 0x07, 0x04,
-    0x01, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x31, 0x26, 0x27, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
-    0x01, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x32, 0x04, 0x2a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01,
-    0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x32, 0x04, 0x25, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01,
-    0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x32, 0x28, 0x29, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01,
-    0x04, 0x00, 0x02, 0x04, 0x12, 0x03, 0x33, 0x04, 0x1e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04,
-    0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x33, 0x04, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04,
-    0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x33, 0x1c, 0x1d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04,
-    0x00, 0x02, 0x05, 0x12, 0x03, 0x34, 0x04, 0x24, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00,
-    0x02, 0x05, 0x01, 0x12, 0x03, 0x34, 0x04, 0x1e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00,
-    0x02, 0x05, 0x02, 0x12, 0x03, 0x34, 0x21, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x05,
-    0x12, 0x03, 0x37, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x06, 0x12, 0x03,
-    0x37, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x01, 0x12, 0x03, 0x37, 0x12,
-    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x03, 0x12, 0x03, 0x37, 0x19, 0x1a, 0x0a,
-    0x0c, 0x0a, 0x04, 0x04, 0x01, 0x08, 0x00, 0x12, 0x04, 0x39, 0x02, 0x3f, 0x03, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x08, 0x00, 0x01, 0x12, 0x03, 0x39, 0x08, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x01, 0x02, 0x06, 0x12, 0x03, 0x3a, 0x04, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06,
-    0x06, 0x12, 0x03, 0x3a, 0x04, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x01, 0x12,
-    0x03, 0x3a, 0x1d, 0x2b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x03, 0x12, 0x03, 0x3a,
-    0x2e, 0x2f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x07, 0x12, 0x03, 0x3b, 0x04, 0x23, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x06, 0x12, 0x03, 0x3b, 0x04, 0x16, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x07, 0x01, 0x12, 0x03, 0x3b, 0x17, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x07, 0x03, 0x12, 0x03, 0x3b, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
-    0x08, 0x12, 0x03, 0x3c, 0x04, 0x34, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x06, 0x12,
-    0x03, 0x3c, 0x04, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x01, 0x12, 0x03, 0x3c,
-    0x1f, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x03, 0x12, 0x03, 0x3c, 0x32, 0x33,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x09, 0x12, 0x03, 0x3d, 0x04, 0x1e, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x09, 0x06, 0x12, 0x03, 0x3d, 0x04, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x09, 0x01, 0x12, 0x03, 0x3d, 0x14, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x09, 0x03, 0x12, 0x03, 0x3d, 0x1b, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0a, 0x12,
-    0x03, 0x3e, 0x04, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x06, 0x12, 0x03, 0x3e,
-    0x04, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x01, 0x12, 0x03, 0x3e, 0x19, 0x22,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x03, 0x12, 0x03, 0x3e, 0x25, 0x27, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x01, 0x02, 0x0b, 0x12, 0x03, 0x41, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x0b, 0x06, 0x12, 0x03, 0x41, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x0b, 0x01, 0x12, 0x03, 0x41, 0x16, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0b, 0x03,
-    0x12, 0x03, 0x41, 0x22, 0x24, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x44, 0x00, 0x4b,
-    0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x44, 0x08, 0x20, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x45, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x00, 0x05, 0x12, 0x03, 0x45, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00,
-    0x01, 0x12, 0x03, 0x45, 0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12,
-    0x03, 0x45, 0x0e, 0x0f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x46, 0x02,
-    0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x46, 0x02, 0x08, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x46, 0x09, 0x0e, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x46, 0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x02, 0x02, 0x01, 0x08, 0x12, 0x03, 0x46, 0x13, 0x27, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x02, 0x02,
-    0x01, 0x08, 0x06, 0x12, 0x03, 0x46, 0x14, 0x26, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x02,
-    0x12, 0x03, 0x47, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x04, 0x12, 0x03,
-    0x47, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x06, 0x12, 0x03, 0x47, 0x0b,
-    0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x47, 0x11, 0x17, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x47, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x02, 0x02, 0x03, 0x12, 0x03, 0x48, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02,
-    0x02, 0x03, 0x05, 0x12, 0x03, 0x48, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03,
-    0x01, 0x12, 0x03, 0x48, 0x08, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x03, 0x12,
-    0x03, 0x48, 0x26, 0x27, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x04, 0x12, 0x03, 0x49, 0x02,
-    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x04, 0x05, 0x12, 0x03, 0x49, 0x02, 0x08, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x04, 0x01, 0x12, 0x03, 0x49, 0x09, 0x11, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x02, 0x02, 0x04, 0x03, 0x12, 0x03, 0x49, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x02, 0x02, 0x05, 0x12, 0x03, 0x4a, 0x02, 0x2e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05,
-    0x04, 0x12, 0x03, 0x4a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x05, 0x12,
-    0x03, 0x4a, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x01, 0x12, 0x03, 0x4a,
-    0x12, 0x29, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x03, 0x12, 0x03, 0x4a, 0x2c, 0x2d,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x04, 0x4d, 0x00, 0x50, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x03, 0x01, 0x12, 0x03, 0x4d, 0x08, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00,
-    0x12, 0x03, 0x4e, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03,
-    0x4e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4e, 0x0b,
-    0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4e, 0x15, 0x16, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x01, 0x12, 0x03, 0x4f, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x03, 0x02, 0x01, 0x04, 0x12, 0x03, 0x4f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03,
-    0x02, 0x01, 0x06, 0x12, 0x03, 0x4f, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01,
-    0x01, 0x12, 0x03, 0x4f, 0x11, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12,
-    0x03, 0x4f, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x52, 0x00, 0x53, 0x01,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x04, 0x01, 0x12, 0x03, 0x52, 0x08, 0x22, 0x0a, 0x0a, 0x0a, 0x02,
-    0x04, 0x05, 0x12, 0x04, 0x55, 0x00, 0x56, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12,
-    0x03, 0x55, 0x08, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x58, 0x00, 0x5b, 0x01,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x06, 0x01, 0x12, 0x03, 0x58, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x06, 0x02, 0x00, 0x12, 0x03, 0x59, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02,
-    0x00, 0x06, 0x12, 0x03, 0x59, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01,
-    0x12, 0x03, 0x59, 0x19, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03,
-    0x59, 0x23, 0x24, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x5a, 0x02, 0x1c,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x04, 0x12, 0x03, 0x5a, 0x02, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x06, 0x12, 0x03, 0x5a, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x06, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5a, 0x11, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
-    0x02, 0x01, 0x03, 0x12, 0x03, 0x5a, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04,
-    0x5d, 0x00, 0x63, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x5d, 0x08, 0x0d,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x5e, 0x02, 0x13, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x07, 0x02, 0x00, 0x06, 0x12, 0x03, 0x5e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x07, 0x02, 0x00, 0x01, 0x12, 0x03, 0x5e, 0x0b, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
-    0x00, 0x03, 0x12, 0x03, 0x5e, 0x11, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x01, 0x12,
-    0x03, 0x5f, 0x02, 0x32, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x05, 0x12, 0x03, 0x5f,
-    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5f, 0x09, 0x18,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x5f, 0x1b, 0x1c, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x08, 0x12, 0x03, 0x5f, 0x1d, 0x31, 0x0a, 0x0d, 0x0a, 0x06,
-    0x04, 0x07, 0x02, 0x01, 0x08, 0x06, 0x12, 0x03, 0x5f, 0x1e, 0x30, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x07, 0x02, 0x02, 0x12, 0x03, 0x60, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02,
-    0x06, 0x12, 0x03, 0x60, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x01, 0x12,
-    0x03, 0x60, 0x0b, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x03, 0x12, 0x03, 0x60,
-    0x12, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x03, 0x12, 0x03, 0x61, 0x02, 0x16, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x05, 0x12, 0x03, 0x61, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x07, 0x02, 0x03, 0x01, 0x12, 0x03, 0x61, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x07, 0x02, 0x03, 0x03, 0x12, 0x03, 0x61, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02,
-    0x04, 0x12, 0x03, 0x62, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x05, 0x12,
-    0x03, 0x62, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x01, 0x12, 0x03, 0x62,
-    0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x03, 0x12, 0x03, 0x62, 0x10, 0x11,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x08, 0x12, 0x04, 0x65, 0x00, 0x6f, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x08, 0x01, 0x12, 0x03, 0x65, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00,
-    0x12, 0x03, 0x66, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03,
-    0x66, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x66, 0x08,
-    0x0c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x66, 0x0f, 0x10, 0x0a,
-    0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x01, 0x12, 0x03, 0x67, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05,
-    0x04, 0x08, 0x02, 0x01, 0x05, 0x12, 0x03, 0x67, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
-    0x02, 0x01, 0x01, 0x12, 0x03, 0x67, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01,
-    0x03, 0x12, 0x03, 0x67, 0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x02, 0x12, 0x03,
-    0x68, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x03, 0x68, 0x02,
-    0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x01, 0x12, 0x03, 0x68, 0x08, 0x17, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x03, 0x12, 0x03, 0x68, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x08, 0x02, 0x03, 0x12, 0x03, 0x69, 0x02, 0x2b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
-    0x02, 0x03, 0x04, 0x12, 0x03, 0x69, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03,
-    0x05, 0x12, 0x03, 0x69, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x01, 0x12,
-    0x03, 0x69, 0x11, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x03, 0x12, 0x03, 0x69,
-    0x29, 0x2a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x04, 0x12, 0x03, 0x6a, 0x02, 0x2b, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x05, 0x12, 0x03, 0x6a, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x08, 0x02, 0x04, 0x01, 0x12, 0x03, 0x6a, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x08, 0x02, 0x04, 0x03, 0x12, 0x03, 0x6a, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
-    0x04, 0x08, 0x12, 0x03, 0x6a, 0x16, 0x2a, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x08, 0x02, 0x04, 0x08,
-    0x06, 0x12, 0x03, 0x6a, 0x17, 0x29, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x05, 0x12, 0x03,
-    0x6b, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x05, 0x12, 0x03, 0x6b, 0x02,
-    0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x01, 0x12, 0x03, 0x6b, 0x07, 0x0e, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x03, 0x12, 0x03, 0x6b, 0x11, 0x12, 0x0a, 0x0b, 0x0a,
-    0x04, 0x04, 0x08, 0x02, 0x06, 0x12, 0x03, 0x6c, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08,
-    0x02, 0x06, 0x05, 0x12, 0x03, 0x6c, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06,
-    0x01, 0x12, 0x03, 0x6c, 0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x03, 0x12,
-    0x03, 0x6c, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x07, 0x12, 0x03, 0x6d, 0x02,
-    0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x07, 0x05, 0x12, 0x03, 0x6d, 0x02, 0x07, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x07, 0x01, 0x12, 0x03, 0x6d, 0x08, 0x1d, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x08, 0x02, 0x07, 0x03, 0x12, 0x03, 0x6d, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x08, 0x02, 0x08, 0x12, 0x03, 0x6e, 0x02, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x08,
-    0x04, 0x12, 0x03, 0x6e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x08, 0x06, 0x12,
-    0x03, 0x6e, 0x0b, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x08, 0x01, 0x12, 0x03, 0x6e,
-    0x1a, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x08, 0x03, 0x12, 0x03, 0x6e, 0x24, 0x25,
-    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x09, 0x12, 0x04, 0x71, 0x00, 0x74, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
-    0x04, 0x09, 0x01, 0x12, 0x03, 0x71, 0x08, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00,
-    0x12, 0x03, 0x72, 0x02, 0x32, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x05, 0x12, 0x03,
-    0x72, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x72, 0x09,
-    0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x72, 0x1b, 0x1c, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x08, 0x12, 0x03, 0x72, 0x1d, 0x31, 0x0a, 0x0d, 0x0a,
-    0x06, 0x04, 0x09, 0x02, 0x00, 0x08, 0x06, 0x12, 0x03, 0x72, 0x1e, 0x30, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x09, 0x02, 0x01, 0x12, 0x03, 0x73, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02,
-    0x01, 0x05, 0x12, 0x03, 0x73, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01,
-    0x12, 0x03, 0x73, 0x09, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03,
-    0x73, 0x1b, 0x1c, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x76, 0x00, 0x7e, 0x01, 0x0a,
-    0x0a, 0x0a, 0x03, 0x04, 0x0a, 0x01, 0x12, 0x03, 0x76, 0x08, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
-    0x0a, 0x02, 0x00, 0x12, 0x03, 0x77, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00,
-    0x05, 0x12, 0x03, 0x77, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12,
-    0x03, 0x77, 0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x77,
-    0x12, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x03, 0x78, 0x02, 0x32, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x05, 0x12, 0x03, 0x78, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0a, 0x02, 0x01, 0x01, 0x12, 0x03, 0x78, 0x09, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0a, 0x02, 0x01, 0x03, 0x12, 0x03, 0x78, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
-    0x01, 0x08, 0x12, 0x03, 0x78, 0x1d, 0x31, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x02, 0x01, 0x08,
-    0x06, 0x12, 0x03, 0x78, 0x1e, 0x30, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x02, 0x12, 0x03,
-    0x79, 0x02, 0x31, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x05, 0x12, 0x03, 0x79, 0x02,
-    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x01, 0x12, 0x03, 0x79, 0x09, 0x17, 0x0a,
-    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x03, 0x12, 0x03, 0x79, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x0a, 0x02, 0x02, 0x08, 0x12, 0x03, 0x79, 0x1c, 0x30, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
-    0x0a, 0x02, 0x02, 0x08, 0x06, 0x12, 0x03, 0x79, 0x1d, 0x2f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a,
-    0x02, 0x03, 0x12, 0x03, 0x7a, 0x02, 0x31, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x05,
-    0x12, 0x03, 0x7a, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03,
-    0x7a, 0x09, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x03, 0x7a, 0x1a,
-    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x08, 0x12, 0x03, 0x7a, 0x1c, 0x30, 0x0a,
-    0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x02, 0x03, 0x08, 0x06, 0x12, 0x03, 0x7a, 0x1d, 0x2f, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x0a, 0x02, 0x04, 0x12, 0x03, 0x7b, 0x02, 0x3f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x0a, 0x02, 0x04, 0x06, 0x12, 0x03, 0x7b, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
-    0x04, 0x01, 0x12, 0x03, 0x7b, 0x21, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x03,
-    0x12, 0x03, 0x7b, 0x3d, 0x3e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x05, 0x12, 0x03, 0x7c,
-    0x02, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x06, 0x12, 0x03, 0x7c, 0x02, 0x14,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x03, 0x7c, 0x15, 0x1c, 0x0a, 0x0c,
-    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x03, 0x12, 0x03, 0x7c, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x0a, 0x02, 0x06, 0x12, 0x03, 0x7d, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
-    0x06, 0x06, 0x12, 0x03, 0x7d, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x01,
-    0x12, 0x03, 0x7d, 0x0c, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x03, 0x12, 0x03,
-    0x7d, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x06, 0x80, 0x01, 0x00, 0x8d, 0x01,
-    0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0b, 0x01, 0x12, 0x04, 0x80, 0x01, 0x08, 0x10, 0x0a, 0x0e,
-    0x0a, 0x04, 0x04, 0x0b, 0x04, 0x00, 0x12, 0x06, 0x82, 0x01, 0x02, 0x86, 0x01, 0x03, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x0b, 0x04, 0x00, 0x01, 0x12, 0x04, 0x82, 0x01, 0x07, 0x13, 0x0a, 0x0e, 0x0a,
-    0x06, 0x04, 0x0b, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0x83, 0x01, 0x04, 0x23, 0x0a, 0x0f, 0x0a,
-    0x07, 0x04, 0x0b, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x83, 0x01, 0x04, 0x1e, 0x0a, 0x0f,
-    0x0a, 0x07, 0x04, 0x0b, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0x83, 0x01, 0x21, 0x22, 0x0a,
-    0x0e, 0x0a, 0x06, 0x04, 0x0b, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0x84, 0x01, 0x04, 0x28, 0x0a,
-    0x0f, 0x0a, 0x07, 0x04, 0x0b, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x84, 0x01, 0x04, 0x23,
-    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0b, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x84, 0x01, 0x26,
-    0x27, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0b, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0x85, 0x01, 0x04,
-    0x28, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0b, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x85, 0x01,
-    0x04, 0x23, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0b, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x85,
-    0x01, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x04, 0x88, 0x01, 0x02,
-    0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x06, 0x12, 0x04, 0x88, 0x01, 0x02, 0x0e,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x04, 0x88, 0x01, 0x0f, 0x1d, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x00, 0x03, 0x12, 0x04, 0x88, 0x01, 0x20, 0x21, 0x0a, 0x0e,
-    0x0a, 0x04, 0x04, 0x0b, 0x08, 0x00, 0x12, 0x06, 0x89, 0x01, 0x02, 0x8c, 0x01, 0x03, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x0b, 0x08, 0x00, 0x01, 0x12, 0x04, 0x89, 0x01, 0x08, 0x11, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x0b, 0x02, 0x01, 0x12, 0x04, 0x8a, 0x01, 0x04, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x0b, 0x02, 0x01, 0x06, 0x12, 0x04, 0x8a, 0x01, 0x04, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b,
-    0x02, 0x01, 0x01, 0x12, 0x04, 0x8a, 0x01, 0x13, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02,
-    0x01, 0x03, 0x12, 0x04, 0x8a, 0x01, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x02,
-    0x12, 0x04, 0x8b, 0x01, 0x04, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x06, 0x12,
-    0x04, 0x8b, 0x01, 0x04, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01, 0x12, 0x04,
-    0x8b, 0x01, 0x13, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x04, 0x8b,
-    0x01, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x06, 0x8f, 0x01, 0x00, 0x92, 0x01,
-    0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0c, 0x01, 0x12, 0x04, 0x8f, 0x01, 0x08, 0x16, 0x0a, 0x0c,
-    0x0a, 0x04, 0x04, 0x0c, 0x02, 0x00, 0x12, 0x04, 0x90, 0x01, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x0c, 0x02, 0x00, 0x05, 0x12, 0x04, 0x90, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x0c, 0x02, 0x00, 0x01, 0x12, 0x04, 0x90, 0x01, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c,
-    0x02, 0x00, 0x03, 0x12, 0x04, 0x90, 0x01, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0c, 0x02,
-    0x01, 0x12, 0x04, 0x91, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x06,
-    0x12, 0x04, 0x91, 0x01, 0x02, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x01, 0x12,
-    0x04, 0x91, 0x01, 0x10, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03, 0x12, 0x04,
-    0x91, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x06, 0x94, 0x01, 0x00, 0x97,
-    0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x04, 0x94, 0x01, 0x08, 0x16, 0x0a,
-    0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x00, 0x12, 0x04, 0x95, 0x01, 0x02, 0x2f, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x0d, 0x02, 0x00, 0x04, 0x12, 0x04, 0x95, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x0d, 0x02, 0x00, 0x06, 0x12, 0x04, 0x95, 0x01, 0x0b, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x0d, 0x02, 0x00, 0x01, 0x12, 0x04, 0x95, 0x01, 0x1a, 0x2a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d,
-    0x02, 0x00, 0x03, 0x12, 0x04, 0x95, 0x01, 0x2d, 0x2e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02,
-    0x01, 0x12, 0x04, 0x96, 0x01, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x04,
-    0x12, 0x04, 0x96, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x06, 0x12,
-    0x04, 0x96, 0x01, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x01, 0x12, 0x04,
-    0x96, 0x01, 0x11, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x03, 0x12, 0x04, 0x96,
-    0x01, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x06, 0x99, 0x01, 0x00, 0xaf, 0x01,
-    0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0e, 0x01, 0x12, 0x04, 0x99, 0x01, 0x08, 0x16, 0x0a, 0x0e,
-    0x0a, 0x04, 0x04, 0x0e, 0x04, 0x00, 0x12, 0x06, 0x9b, 0x01, 0x02, 0xa3, 0x01, 0x03, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x0e, 0x04, 0x00, 0x01, 0x12, 0x04, 0x9b, 0x01, 0x07, 0x0b, 0x0a, 0x0e, 0x0a,
-    0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0x9c, 0x01, 0x04, 0x19, 0x0a, 0x0f, 0x0a,
-    0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x9c, 0x01, 0x04, 0x14, 0x0a, 0x0f,
-    0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0x9c, 0x01, 0x17, 0x18, 0x0a,
-    0x0e, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0x9d, 0x01, 0x04, 0x1b, 0x0a,
-    0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x9d, 0x01, 0x04, 0x16,
-    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x9d, 0x01, 0x19,
-    0x1a, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0x9e, 0x01, 0x04,
-    0x1d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x9e, 0x01,
-    0x04, 0x18, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x9e,
-    0x01, 0x1b, 0x1c, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0x9f,
-    0x01, 0x04, 0x1f, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04,
-    0x9f, 0x01, 0x04, 0x1a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12,
-    0x04, 0x9f, 0x01, 0x1d, 0x1e, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x04, 0x12,
-    0x04, 0xa0, 0x01, 0x04, 0x1a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x04, 0x01,
-    0x12, 0x04, 0xa0, 0x01, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x04,
-    0x02, 0x12, 0x04, 0xa0, 0x01, 0x18, 0x19, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00, 0x02,
-    0x05, 0x12, 0x04, 0xa1, 0x01, 0x04, 0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02,
-    0x05, 0x01, 0x12, 0x04, 0xa1, 0x01, 0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00,
-    0x02, 0x05, 0x02, 0x12, 0x04, 0xa1, 0x01, 0x1a, 0x1b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0e, 0x04,
-    0x00, 0x02, 0x06, 0x12, 0x04, 0xa2, 0x01, 0x04, 0x1e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04,
-    0x00, 0x02, 0x06, 0x01, 0x12, 0x04, 0xa2, 0x01, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e,
-    0x04, 0x00, 0x02, 0x06, 0x02, 0x12, 0x04, 0xa2, 0x01, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x0e, 0x02, 0x00, 0x12, 0x04, 0xa5, 0x01, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
-    0x00, 0x06, 0x12, 0x04, 0xa5, 0x01, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00,
-    0x01, 0x12, 0x04, 0xa5, 0x01, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x03,
-    0x12, 0x04, 0xa5, 0x01, 0x0e, 0x0f, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x0e, 0x08, 0x00, 0x12, 0x06,
-    0xa7, 0x01, 0x02, 0xae, 0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x08, 0x00, 0x01, 0x12,
-    0x04, 0xa7, 0x01, 0x08, 0x0e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x01, 0x12, 0x04, 0xa8,
-    0x01, 0x04, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x06, 0x12, 0x04, 0xa8, 0x01,
-    0x04, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa8, 0x01, 0x11,
-    0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa8, 0x01, 0x21, 0x22,
-    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x02, 0x12, 0x04, 0xa9, 0x01, 0x04, 0x27, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02, 0x06, 0x12, 0x04, 0xa9, 0x01, 0x04, 0x12, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x0e, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa9, 0x01, 0x13, 0x22, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x0e, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa9, 0x01, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x0e, 0x02, 0x03, 0x12, 0x04, 0xaa, 0x01, 0x04, 0x2a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
-    0x03, 0x06, 0x12, 0x04, 0xaa, 0x01, 0x04, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03,
-    0x01, 0x12, 0x04, 0xaa, 0x01, 0x14, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x03,
-    0x12, 0x04, 0xaa, 0x01, 0x28, 0x29, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x04, 0x12, 0x04,
-    0xab, 0x01, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x06, 0x12, 0x04, 0xab,
-    0x01, 0x04, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x01, 0x12, 0x04, 0xab, 0x01,
-    0x10, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x03, 0x12, 0x04, 0xab, 0x01, 0x1f,
-    0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x05, 0x12, 0x04, 0xac, 0x01, 0x04, 0x25, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x06, 0x12, 0x04, 0xac, 0x01, 0x04, 0x11, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x05, 0x01, 0x12, 0x04, 0xac, 0x01, 0x12, 0x20, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x0e, 0x02, 0x05, 0x03, 0x12, 0x04, 0xac, 0x01, 0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x04,
-    0x04, 0x0e, 0x02, 0x06, 0x12, 0x04, 0xad, 0x01, 0x04, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
-    0x02, 0x06, 0x06, 0x12, 0x04, 0xad, 0x01, 0x04, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
-    0x06, 0x01, 0x12, 0x04, 0xad, 0x01, 0x13, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06,
-    0x03, 0x12, 0x04, 0xad, 0x01, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x06, 0xb1,
-    0x01, 0x00, 0xb5, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x04, 0xb1, 0x01,
-    0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x04, 0xb2, 0x01, 0x02, 0x15,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x05, 0x12, 0x04, 0xb2, 0x01, 0x02, 0x08, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb2, 0x01, 0x09, 0x10, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x0f, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb2, 0x01, 0x13, 0x14, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x0f, 0x02, 0x01, 0x12, 0x04, 0xb3, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x0f, 0x02, 0x01, 0x05, 0x12, 0x04, 0xb3, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f,
-    0x02, 0x01, 0x01, 0x12, 0x04, 0xb3, 0x01, 0x08, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02,
-    0x01, 0x03, 0x12, 0x04, 0xb3, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x02,
-    0x12, 0x04, 0xb4, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x06, 0x12,
-    0x04, 0xb4, 0x01, 0x02, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x01, 0x12, 0x04,
-    0xb4, 0x01, 0x0f, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x03, 0x12, 0x04, 0xb4,
-    0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x10, 0x12, 0x06, 0xb7, 0x01, 0x00, 0xbc, 0x01,
-    0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x10, 0x01, 0x12, 0x04, 0xb7, 0x01, 0x08, 0x16, 0x0a, 0x0c,
-    0x0a, 0x04, 0x04, 0x10, 0x02, 0x00, 0x12, 0x04, 0xb8, 0x01, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x10, 0x02, 0x00, 0x05, 0x12, 0x04, 0xb8, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x10, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb8, 0x01, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10,
-    0x02, 0x00, 0x03, 0x12, 0x04, 0xb8, 0x01, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02,
-    0x01, 0x12, 0x04, 0xb9, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x05,
-    0x12, 0x04, 0xb9, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x01, 0x12,
-    0x04, 0xb9, 0x01, 0x08, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x03, 0x12, 0x04,
-    0xb9, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x02, 0x12, 0x04, 0xba, 0x01,
-    0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x06, 0x12, 0x04, 0xba, 0x01, 0x02,
-    0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x01, 0x12, 0x04, 0xba, 0x01, 0x10, 0x14,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x03, 0x12, 0x04, 0xba, 0x01, 0x17, 0x18, 0x0a,
-    0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x03, 0x12, 0x04, 0xbb, 0x01, 0x02, 0x16, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x10, 0x02, 0x03, 0x05, 0x12, 0x04, 0xbb, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x10, 0x02, 0x03, 0x01, 0x12, 0x04, 0xbb, 0x01, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x10, 0x02, 0x03, 0x03, 0x12, 0x04, 0xbb, 0x01, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x11,
-    0x12, 0x06, 0xbe, 0x01, 0x00, 0xc3, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x11, 0x01, 0x12,
-    0x04, 0xbe, 0x01, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x00, 0x12, 0x04, 0xbf,
-    0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x05, 0x12, 0x04, 0xbf, 0x01,
-    0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x01, 0x12, 0x04, 0xbf, 0x01, 0x08,
-    0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x03, 0x12, 0x04, 0xbf, 0x01, 0x19, 0x1a,
-    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x01, 0x12, 0x04, 0xc0, 0x01, 0x02, 0x14, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x11, 0x02, 0x01, 0x05, 0x12, 0x04, 0xc0, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x11, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc0, 0x01, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x11, 0x02, 0x01, 0x03, 0x12, 0x04, 0xc0, 0x01, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x11, 0x02, 0x02, 0x12, 0x04, 0xc1, 0x01, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02,
-    0x02, 0x05, 0x12, 0x04, 0xc1, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02,
-    0x01, 0x12, 0x04, 0xc1, 0x01, 0x09, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x03,
-    0x12, 0x04, 0xc1, 0x01, 0x0f, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x03, 0x12, 0x04,
-    0xc2, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x03, 0x06, 0x12, 0x04, 0xc2,
-    0x01, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x03, 0x01, 0x12, 0x04, 0xc2, 0x01,
-    0x12, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x03, 0x03, 0x12, 0x04, 0xc2, 0x01, 0x19,
-    0x1a, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x12, 0x12, 0x06, 0xc5, 0x01, 0x00, 0xc8, 0x01, 0x01, 0x0a,
-    0x0b, 0x0a, 0x03, 0x04, 0x12, 0x01, 0x12, 0x04, 0xc5, 0x01, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04,
-    0x04, 0x12, 0x02, 0x00, 0x12, 0x04, 0xc6, 0x01, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12,
-    0x02, 0x00, 0x05, 0x12, 0x04, 0xc6, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02,
-    0x00, 0x01, 0x12, 0x04, 0xc6, 0x01, 0x09, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00,
-    0x03, 0x12, 0x04, 0xc6, 0x01, 0x0f, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x01, 0x12,
-    0x04, 0xc7, 0x01, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x05, 0x12, 0x04,
-    0xc7, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc7,
-    0x01, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x03, 0x12, 0x04, 0xc7, 0x01,
-    0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x13, 0x12, 0x06, 0xca, 0x01, 0x00, 0xce, 0x01, 0x01,
-    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x13, 0x01, 0x12, 0x04, 0xca, 0x01, 0x08, 0x13, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x13, 0x02, 0x00, 0x12, 0x04, 0xcb, 0x01, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x13, 0x02, 0x00, 0x05, 0x12, 0x04, 0xcb, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13,
-    0x02, 0x00, 0x01, 0x12, 0x04, 0xcb, 0x01, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02,
-    0x00, 0x03, 0x12, 0x04, 0xcb, 0x01, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x01,
-    0x12, 0x04, 0xcc, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x05, 0x12,
-    0x04, 0xcc, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x01, 0x12, 0x04,
-    0xcc, 0x01, 0x08, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x03, 0x12, 0x04, 0xcc,
-    0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x02, 0x12, 0x04, 0xcd, 0x01, 0x02,
-    0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x06, 0x12, 0x04, 0xcd, 0x01, 0x02, 0x14,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x01, 0x12, 0x04, 0xcd, 0x01, 0x15, 0x19, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x02, 0x03, 0x12, 0x04, 0xcd, 0x01, 0x1c, 0x1d, 0x0a, 0x0c,
-    0x0a, 0x02, 0x04, 0x14, 0x12, 0x06, 0xd0, 0x01, 0x00, 0xd6, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03,
-    0x04, 0x14, 0x01, 0x12, 0x04, 0xd0, 0x01, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02,
-    0x00, 0x12, 0x04, 0xd1, 0x01, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x05,
-    0x12, 0x04, 0xd1, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x01, 0x12,
-    0x04, 0xd1, 0x01, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x03, 0x12, 0x04,
-    0xd1, 0x01, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x01, 0x12, 0x04, 0xd2, 0x01,
-    0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x05, 0x12, 0x04, 0xd2, 0x01, 0x02,
-    0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd2, 0x01, 0x08, 0x16,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x03, 0x12, 0x04, 0xd2, 0x01, 0x19, 0x1a, 0x0a,
-    0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x02, 0x12, 0x04, 0xd3, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x14, 0x02, 0x02, 0x06, 0x12, 0x04, 0xd3, 0x01, 0x02, 0x0f, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x14, 0x02, 0x02, 0x01, 0x12, 0x04, 0xd3, 0x01, 0x10, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x14, 0x02, 0x02, 0x03, 0x12, 0x04, 0xd3, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14,
-    0x02, 0x03, 0x12, 0x04, 0xd4, 0x01, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03,
-    0x05, 0x12, 0x04, 0xd4, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x01,
-    0x12, 0x04, 0xd4, 0x01, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x03, 0x12,
-    0x04, 0xd4, 0x01, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x04, 0x12, 0x04, 0xd5,
-    0x01, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x05, 0x12, 0x04, 0xd5, 0x01,
-    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x01, 0x12, 0x04, 0xd5, 0x01, 0x09,
-    0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x03, 0x12, 0x04, 0xd5, 0x01, 0x10, 0x11,
-    0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x15, 0x12, 0x06, 0xd8, 0x01, 0x00, 0xdd, 0x01, 0x01, 0x0a, 0x0b,
-    0x0a, 0x03, 0x04, 0x15, 0x01, 0x12, 0x04, 0xd8, 0x01, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x15, 0x02, 0x00, 0x12, 0x04, 0xd9, 0x01, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02,
-    0x00, 0x05, 0x12, 0x04, 0xd9, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00,
-    0x01, 0x12, 0x04, 0xd9, 0x01, 0x09, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x03,
-    0x12, 0x04, 0xd9, 0x01, 0x0f, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x01, 0x12, 0x04,
-    0xda, 0x01, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x05, 0x12, 0x04, 0xda,
-    0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x01, 0x12, 0x04, 0xda, 0x01,
-    0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x03, 0x12, 0x04, 0xda, 0x01, 0x14,
-    0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x02, 0x12, 0x04, 0xdb, 0x01, 0x02, 0x13, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x02, 0x05, 0x12, 0x04, 0xdb, 0x01, 0x02, 0x08, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x15, 0x02, 0x02, 0x01, 0x12, 0x04, 0xdb, 0x01, 0x09, 0x0e, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x15, 0x02, 0x02, 0x03, 0x12, 0x04, 0xdb, 0x01, 0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x04,
-    0x04, 0x15, 0x02, 0x03, 0x12, 0x04, 0xdc, 0x01, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15,
-    0x02, 0x03, 0x05, 0x12, 0x04, 0xdc, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02,
-    0x03, 0x01, 0x12, 0x04, 0xdc, 0x01, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x03,
-    0x03, 0x12, 0x04, 0xdc, 0x01, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x16, 0x12, 0x06, 0xdf,
-    0x01, 0x00, 0xe4, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x16, 0x01, 0x12, 0x04, 0xdf, 0x01,
-    0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x00, 0x12, 0x04, 0xe0, 0x01, 0x02, 0x1b,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x05, 0x12, 0x04, 0xe0, 0x01, 0x02, 0x07, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe0, 0x01, 0x08, 0x16, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x16, 0x02, 0x00, 0x03, 0x12, 0x04, 0xe0, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x16, 0x02, 0x01, 0x12, 0x04, 0xe1, 0x01, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x16, 0x02, 0x01, 0x05, 0x12, 0x04, 0xe1, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16,
-    0x02, 0x01, 0x01, 0x12, 0x04, 0xe1, 0x01, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02,
-    0x01, 0x03, 0x12, 0x04, 0xe1, 0x01, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x02,
-    0x12, 0x04, 0xe2, 0x01, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x02, 0x05, 0x12,
-    0x04, 0xe2, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x02, 0x01, 0x12, 0x04,
-    0xe2, 0x01, 0x09, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x02, 0x03, 0x12, 0x04, 0xe2,
-    0x01, 0x0f, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x03, 0x12, 0x04, 0xe3, 0x01, 0x02,
-    0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x03, 0x06, 0x12, 0x04, 0xe3, 0x01, 0x02, 0x10,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x03, 0x01, 0x12, 0x04, 0xe3, 0x01, 0x11, 0x15, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x03, 0x03, 0x12, 0x04, 0xe3, 0x01, 0x18, 0x19, 0x0a, 0x0c,
-    0x0a, 0x02, 0x04, 0x17, 0x12, 0x06, 0xe6, 0x01, 0x00, 0xf9, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03,
-    0x04, 0x17, 0x01, 0x12, 0x04, 0xe6, 0x01, 0x08, 0x1a, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x17, 0x04,
-    0x00, 0x12, 0x06, 0xe7, 0x01, 0x02, 0xee, 0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x04,
-    0x00, 0x01, 0x12, 0x04, 0xe7, 0x01, 0x07, 0x0b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x17, 0x04, 0x00,
-    0x02, 0x00, 0x12, 0x04, 0xe8, 0x01, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x04, 0x00,
-    0x02, 0x00, 0x01, 0x12, 0x04, 0xe8, 0x01, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x04,
-    0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0xe8, 0x01, 0x17, 0x18, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x17,
-    0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0xe9, 0x01, 0x04, 0x24, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17,
-    0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xe9, 0x01, 0x04, 0x1f, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
-    0x17, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0xe9, 0x01, 0x22, 0x23, 0x0a, 0x0e, 0x0a, 0x06,
-    0x04, 0x17, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0xea, 0x01, 0x04, 0x1c, 0x0a, 0x0f, 0x0a, 0x07,
-    0x04, 0x17, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0xea, 0x01, 0x04, 0x17, 0x0a, 0x0f, 0x0a,
-    0x07, 0x04, 0x17, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0xea, 0x01, 0x1a, 0x1b, 0x0a, 0x0e,
-    0x0a, 0x06, 0x04, 0x17, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0xeb, 0x01, 0x04, 0x1f, 0x0a, 0x0f,
-    0x0a, 0x07, 0x04, 0x17, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0xeb, 0x01, 0x04, 0x1a, 0x0a,
-    0x0f, 0x0a, 0x07, 0x04, 0x17, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0xeb, 0x01, 0x1d, 0x1e,
-    0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x17, 0x04, 0x00, 0x02, 0x04, 0x12, 0x04, 0xec, 0x01, 0x04, 0x1e,
-    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0xec, 0x01, 0x04,
-    0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0xec, 0x01,
-    0x1c, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x04, 0x00, 0x04, 0x12, 0x04, 0xed, 0x01, 0x04,
-    0x0f, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x17, 0x04, 0x00, 0x04, 0x00, 0x12, 0x04, 0xed, 0x01, 0x0d,
-    0x0e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x04, 0x00, 0x04, 0x00, 0x01, 0x12, 0x04, 0xed, 0x01,
-    0x0d, 0x0e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x04, 0x00, 0x04, 0x00, 0x02, 0x12, 0x04, 0xed,
-    0x01, 0x0d, 0x0e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x00, 0x12, 0x04, 0xf0, 0x01, 0x02,
-    0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x06, 0x12, 0x04, 0xf0, 0x01, 0x02, 0x06,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf0, 0x01, 0x07, 0x0b, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x00, 0x03, 0x12, 0x04, 0xf0, 0x01, 0x0e, 0x0f, 0x0a, 0x0e,
-    0x0a, 0x04, 0x04, 0x17, 0x08, 0x00, 0x12, 0x06, 0xf2, 0x01, 0x02, 0xf7, 0x01, 0x03, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x17, 0x08, 0x00, 0x01, 0x12, 0x04, 0xf2, 0x01, 0x08, 0x0f, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x17, 0x02, 0x01, 0x12, 0x04, 0xf3, 0x01, 0x04, 0x34, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x17, 0x02, 0x01, 0x06, 0x12, 0x04, 0xf3, 0x01, 0x04, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17,
-    0x02, 0x01, 0x01, 0x12, 0x04, 0xf3, 0x01, 0x19, 0x2f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02,
-    0x01, 0x03, 0x12, 0x04, 0xf3, 0x01, 0x32, 0x33, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x02,
-    0x12, 0x04, 0xf4, 0x01, 0x04, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x02, 0x06, 0x12,
-    0x04, 0xf4, 0x01, 0x04, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x02, 0x01, 0x12, 0x04,
-    0xf4, 0x01, 0x12, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x02, 0x03, 0x12, 0x04, 0xf4,
-    0x01, 0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x03, 0x12, 0x04, 0xf5, 0x01, 0x04,
-    0x2a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x03, 0x06, 0x12, 0x04, 0xf5, 0x01, 0x04, 0x13,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x03, 0x01, 0x12, 0x04, 0xf5, 0x01, 0x14, 0x25, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x03, 0x03, 0x12, 0x04, 0xf5, 0x01, 0x28, 0x29, 0x0a, 0x0c,
-    0x0a, 0x04, 0x04, 0x17, 0x02, 0x04, 0x12, 0x04, 0xf6, 0x01, 0x04, 0x29, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x17, 0x02, 0x04, 0x06, 0x12, 0x04, 0xf6, 0x01, 0x04, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x17, 0x02, 0x04, 0x01, 0x12, 0x04, 0xf6, 0x01, 0x14, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17,
-    0x02, 0x04, 0x03, 0x12, 0x04, 0xf6, 0x01, 0x27, 0x28, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x17, 0x09,
-    0x12, 0x04, 0xf8, 0x01, 0x02, 0x0d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x09, 0x00, 0x12, 0x04,
-    0xf8, 0x01, 0x0b, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x09, 0x00, 0x01, 0x12, 0x04, 0xf8,
-    0x01, 0x0b, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x09, 0x00, 0x02, 0x12, 0x04, 0xf8, 0x01,
-    0x0b, 0x0c, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x18, 0x12, 0x06, 0xfb, 0x01, 0x00, 0x80, 0x02, 0x01,
-    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x18, 0x01, 0x12, 0x04, 0xfb, 0x01, 0x08, 0x1c, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x18, 0x02, 0x00, 0x12, 0x04, 0xfc, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x18, 0x02, 0x00, 0x06, 0x12, 0x04, 0xfc, 0x01, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18,
-    0x02, 0x00, 0x01, 0x12, 0x04, 0xfc, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02,
-    0x00, 0x03, 0x12, 0x04, 0xfc, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x01,
-    0x12, 0x04, 0xfd, 0x01, 0x02, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x04, 0x12,
-    0x04, 0xfd, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x06, 0x12, 0x04,
-    0xfd, 0x01, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x01, 0x12, 0x04, 0xfd,
-    0x01, 0x14, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x03, 0x12, 0x04, 0xfd, 0x01,
-    0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x02, 0x12, 0x04, 0xfe, 0x01, 0x02, 0x20,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x02, 0x04, 0x12, 0x04, 0xfe, 0x01, 0x02, 0x0a, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x02, 0x05, 0x12, 0x04, 0xfe, 0x01, 0x0b, 0x11, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x18, 0x02, 0x02, 0x01, 0x12, 0x04, 0xfe, 0x01, 0x12, 0x1b, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x18, 0x02, 0x02, 0x03, 0x12, 0x04, 0xfe, 0x01, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04,
-    0x04, 0x18, 0x02, 0x03, 0x12, 0x04, 0xff, 0x01, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18,
-    0x02, 0x03, 0x05, 0x12, 0x04, 0xff, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02,
-    0x03, 0x01, 0x12, 0x04, 0xff, 0x01, 0x09, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x03,
-    0x03, 0x12, 0x04, 0xff, 0x01, 0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x19, 0x12, 0x06, 0x82,
-    0x02, 0x00, 0x85, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x19, 0x01, 0x12, 0x04, 0x82, 0x02,
-    0x08, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x19, 0x02, 0x00, 0x12, 0x04, 0x83, 0x02, 0x02, 0x15,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x05, 0x12, 0x04, 0x83, 0x02, 0x02, 0x07, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x01, 0x12, 0x04, 0x83, 0x02, 0x08, 0x10, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x19, 0x02, 0x00, 0x03, 0x12, 0x04, 0x83, 0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x19, 0x02, 0x01, 0x12, 0x04, 0x84, 0x02, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x19, 0x02, 0x01, 0x06, 0x12, 0x04, 0x84, 0x02, 0x02, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19,
-    0x02, 0x01, 0x01, 0x12, 0x04, 0x84, 0x02, 0x0f, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02,
-    0x01, 0x03, 0x12, 0x04, 0x84, 0x02, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1a, 0x12, 0x06,
-    0x87, 0x02, 0x00, 0x8b, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1a, 0x01, 0x12, 0x04, 0x87,
-    0x02, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x00, 0x12, 0x04, 0x88, 0x02, 0x02,
-    0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x06, 0x12, 0x04, 0x88, 0x02, 0x02, 0x14,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x01, 0x12, 0x04, 0x88, 0x02, 0x15, 0x19, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x00, 0x03, 0x12, 0x04, 0x88, 0x02, 0x1c, 0x1d, 0x0a, 0x0c,
-    0x0a, 0x04, 0x04, 0x1a, 0x02, 0x01, 0x12, 0x04, 0x89, 0x02, 0x02, 0x27, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x1a, 0x02, 0x01, 0x04, 0x12, 0x04, 0x89, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x1a, 0x02, 0x01, 0x06, 0x12, 0x04, 0x89, 0x02, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a,
-    0x02, 0x01, 0x01, 0x12, 0x04, 0x89, 0x02, 0x14, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02,
-    0x01, 0x03, 0x12, 0x04, 0x89, 0x02, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x02,
-    0x12, 0x04, 0x8a, 0x02, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x04, 0x12,
-    0x04, 0x8a, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x05, 0x12, 0x04,
-    0x8a, 0x02, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x01, 0x12, 0x04, 0x8a,
-    0x02, 0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x03, 0x12, 0x04, 0x8a, 0x02,
-    0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1b, 0x12, 0x06, 0x8d, 0x02, 0x00, 0x90, 0x02, 0x01,
-    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1b, 0x01, 0x12, 0x04, 0x8d, 0x02, 0x08, 0x17, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x1b, 0x02, 0x00, 0x12, 0x04, 0x8e, 0x02, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x1b, 0x02, 0x00, 0x05, 0x12, 0x04, 0x8e, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b,
-    0x02, 0x00, 0x01, 0x12, 0x04, 0x8e, 0x02, 0x09, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02,
-    0x00, 0x03, 0x12, 0x04, 0x8e, 0x02, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x01,
-    0x12, 0x04, 0x8f, 0x02, 0x02, 0x3e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x04, 0x12,
-    0x04, 0x8f, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x06, 0x12, 0x04,
-    0x8f, 0x02, 0x0b, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8f,
-    0x02, 0x26, 0x39, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8f, 0x02,
-    0x3c, 0x3d, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1c, 0x12, 0x06, 0x92, 0x02, 0x00, 0x9d, 0x02, 0x01,
-    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1c, 0x01, 0x12, 0x04, 0x92, 0x02, 0x08, 0x22, 0x0a, 0x0e, 0x0a,
-    0x04, 0x04, 0x1c, 0x04, 0x00, 0x12, 0x06, 0x93, 0x02, 0x02, 0x96, 0x02, 0x03, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x1c, 0x04, 0x00, 0x01, 0x12, 0x04, 0x93, 0x02, 0x07, 0x0b, 0x0a, 0x0e, 0x0a, 0x06,
-    0x04, 0x1c, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0x94, 0x02, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07,
-    0x04, 0x1c, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x94, 0x02, 0x04, 0x14, 0x0a, 0x0f, 0x0a,
-    0x07, 0x04, 0x1c, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0x94, 0x02, 0x17, 0x18, 0x0a, 0x0e,
-    0x0a, 0x06, 0x04, 0x1c, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0x95, 0x02, 0x04, 0x24, 0x0a, 0x0f,
-    0x0a, 0x07, 0x04, 0x1c, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x95, 0x02, 0x04, 0x1f, 0x0a,
-    0x0f, 0x0a, 0x07, 0x04, 0x1c, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x95, 0x02, 0x22, 0x23,
-    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x00, 0x12, 0x04, 0x98, 0x02, 0x02, 0x10, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00, 0x06, 0x12, 0x04, 0x98, 0x02, 0x02, 0x06, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x1c, 0x02, 0x00, 0x01, 0x12, 0x04, 0x98, 0x02, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x1c, 0x02, 0x00, 0x03, 0x12, 0x04, 0x98, 0x02, 0x0e, 0x0f, 0x0a, 0x0e, 0x0a, 0x04, 0x04,
-    0x1c, 0x08, 0x00, 0x12, 0x06, 0x9a, 0x02, 0x02, 0x9c, 0x02, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x1c, 0x08, 0x00, 0x01, 0x12, 0x04, 0x9a, 0x02, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1c,
-    0x02, 0x01, 0x12, 0x04, 0x9b, 0x02, 0x04, 0x34, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01,
-    0x06, 0x12, 0x04, 0x9b, 0x02, 0x04, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x01,
-    0x12, 0x04, 0x9b, 0x02, 0x19, 0x2f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x03, 0x12,
-    0x04, 0x9b, 0x02, 0x32, 0x33, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1d, 0x12, 0x06, 0x9f, 0x02, 0x00,
-    0xa2, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1d, 0x01, 0x12, 0x04, 0x9f, 0x02, 0x08, 0x1a,
-    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1d, 0x02, 0x00, 0x12, 0x04, 0xa0, 0x02, 0x02, 0x15, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00, 0x05, 0x12, 0x04, 0xa0, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x1d, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa0, 0x02, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x1d, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa0, 0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x1d, 0x02, 0x01, 0x12, 0x04, 0xa1, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02,
-    0x01, 0x06, 0x12, 0x04, 0xa1, 0x02, 0x02, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x01,
-    0x01, 0x12, 0x04, 0xa1, 0x02, 0x0d, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x01, 0x03,
-    0x12, 0x04, 0xa1, 0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1e, 0x12, 0x06, 0xa4, 0x02,
-    0x00, 0xaa, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1e, 0x01, 0x12, 0x04, 0xa4, 0x02, 0x08,
-    0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x00, 0x12, 0x04, 0xa5, 0x02, 0x02, 0x15, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x05, 0x12, 0x04, 0xa5, 0x02, 0x02, 0x08, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x1e, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa5, 0x02, 0x09, 0x10, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x1e, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa5, 0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04,
-    0x04, 0x1e, 0x02, 0x01, 0x12, 0x04, 0xa6, 0x02, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e,
-    0x02, 0x01, 0x05, 0x12, 0x04, 0xa6, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02,
-    0x01, 0x01, 0x12, 0x04, 0xa6, 0x02, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x01,
-    0x03, 0x12, 0x04, 0xa6, 0x02, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x02, 0x12,
-    0x04, 0xa7, 0x02, 0x02, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x04, 0x12, 0x04,
-    0xa7, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x06, 0x12, 0x04, 0xa7,
-    0x02, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa7, 0x02,
-    0x18, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa7, 0x02, 0x22,
-    0x23, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x03, 0x12, 0x04, 0xa8, 0x02, 0x02, 0x2e, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x03, 0x04, 0x12, 0x04, 0xa8, 0x02, 0x02, 0x0a, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x1e, 0x02, 0x03, 0x06, 0x12, 0x04, 0xa8, 0x02, 0x0b, 0x17, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x1e, 0x02, 0x03, 0x01, 0x12, 0x04, 0xa8, 0x02, 0x18, 0x29, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x1e, 0x02, 0x03, 0x03, 0x12, 0x04, 0xa8, 0x02, 0x2c, 0x2d, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x1e, 0x02, 0x04, 0x12, 0x04, 0xa9, 0x02, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02,
-    0x04, 0x04, 0x12, 0x04, 0xa9, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x04,
-    0x06, 0x12, 0x04, 0xa9, 0x02, 0x0b, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x04, 0x01,
-    0x12, 0x04, 0xa9, 0x02, 0x16, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x04, 0x03, 0x12,
-    0x04, 0xa9, 0x02, 0x20, 0x21, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1f, 0x12, 0x06, 0xab, 0x02, 0x00,
-    0xb8, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1f, 0x01, 0x12, 0x04, 0xab, 0x02, 0x08, 0x14,
-    0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x1f, 0x04, 0x00, 0x12, 0x06, 0xac, 0x02, 0x02, 0xb1, 0x02, 0x03,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x04, 0x00, 0x01, 0x12, 0x04, 0xac, 0x02, 0x07, 0x11, 0x0a,
-    0x0e, 0x0a, 0x06, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0xad, 0x02, 0x04, 0x1f, 0x0a,
-    0x0f, 0x0a, 0x07, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xad, 0x02, 0x04, 0x1a,
-    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0xad, 0x02, 0x1d,
-    0x1e, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0xae, 0x02, 0x04,
-    0x1b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xae, 0x02,
-    0x04, 0x16, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0xae,
-    0x02, 0x19, 0x1a, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0xaf,
-    0x02, 0x04, 0x1a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04,
-    0xaf, 0x02, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12,
-    0x04, 0xaf, 0x02, 0x18, 0x19, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x03, 0x12,
-    0x04, 0xb0, 0x02, 0x04, 0x1a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x03, 0x01,
-    0x12, 0x04, 0xb0, 0x02, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x03,
-    0x02, 0x12, 0x04, 0xb0, 0x02, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1f, 0x02, 0x00, 0x12,
-    0x04, 0xb2, 0x02, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x05, 0x12, 0x04,
-    0xb2, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb2,
-    0x02, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb2, 0x02,
-    0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1f, 0x02, 0x01, 0x12, 0x04, 0xb3, 0x02, 0x02, 0x29,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x01, 0x06, 0x12, 0x04, 0xb3, 0x02, 0x02, 0x19, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x01, 0x01, 0x12, 0x04, 0xb3, 0x02, 0x1a, 0x24, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x1f, 0x02, 0x01, 0x03, 0x12, 0x04, 0xb3, 0x02, 0x27, 0x28, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x1f, 0x02, 0x02, 0x12, 0x04, 0xb4, 0x02, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x1f, 0x02, 0x02, 0x05, 0x12, 0x04, 0xb4, 0x02, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f,
-    0x02, 0x02, 0x01, 0x12, 0x04, 0xb4, 0x02, 0x07, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02,
-    0x02, 0x03, 0x12, 0x04, 0xb4, 0x02, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1f, 0x02, 0x03,
-    0x12, 0x04, 0xb5, 0x02, 0x02, 0x40, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x03, 0x04, 0x12,
-    0x04, 0xb5, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x03, 0x06, 0x12, 0x04,
-    0xb5, 0x02, 0x0b, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x03, 0x01, 0x12, 0x04, 0xb5,
-    0x02, 0x28, 0x3b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x03, 0x03, 0x12, 0x04, 0xb5, 0x02,
-    0x3e, 0x3f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1f, 0x02, 0x04, 0x12, 0x04, 0xb6, 0x02, 0x02, 0x1f,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x04, 0x04, 0x12, 0x04, 0xb6, 0x02, 0x02, 0x0a, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x04, 0x06, 0x12, 0x04, 0xb6, 0x02, 0x0b, 0x13, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x1f, 0x02, 0x04, 0x01, 0x12, 0x04, 0xb6, 0x02, 0x14, 0x1a, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x1f, 0x02, 0x04, 0x03, 0x12, 0x04, 0xb6, 0x02, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04,
-    0x04, 0x1f, 0x02, 0x05, 0x12, 0x04, 0xb7, 0x02, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f,
-    0x02, 0x05, 0x04, 0x12, 0x04, 0xb7, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02,
-    0x05, 0x06, 0x12, 0x04, 0xb7, 0x02, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x05,
-    0x01, 0x12, 0x04, 0xb7, 0x02, 0x14, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x05, 0x03,
-    0x12, 0x04, 0xb7, 0x02, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x20, 0x12, 0x06, 0xba, 0x02,
-    0x00, 0xc0, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x20, 0x01, 0x12, 0x04, 0xba, 0x02, 0x08,
-    0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x20, 0x02, 0x00, 0x12, 0x04, 0xbb, 0x02, 0x02, 0x12, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x00, 0x05, 0x12, 0x04, 0xbb, 0x02, 0x02, 0x08, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x20, 0x02, 0x00, 0x01, 0x12, 0x04, 0xbb, 0x02, 0x09, 0x0d, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x20, 0x02, 0x00, 0x03, 0x12, 0x04, 0xbb, 0x02, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04,
-    0x04, 0x20, 0x02, 0x01, 0x12, 0x04, 0xbc, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20,
-    0x02, 0x01, 0x05, 0x12, 0x04, 0xbc, 0x02, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02,
-    0x01, 0x01, 0x12, 0x04, 0xbc, 0x02, 0x07, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x01,
-    0x03, 0x12, 0x04, 0xbc, 0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x20, 0x02, 0x02, 0x12,
-    0x04, 0xbd, 0x02, 0x02, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x02, 0x04, 0x12, 0x04,
-    0xbd, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x02, 0x06, 0x12, 0x04, 0xbd,
-    0x02, 0x0b, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x02, 0x01, 0x12, 0x04, 0xbd, 0x02,
-    0x17, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x02, 0x03, 0x12, 0x04, 0xbd, 0x02, 0x23,
-    0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x20, 0x02, 0x03, 0x12, 0x04, 0xbe, 0x02, 0x02, 0x3e, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x03, 0x04, 0x12, 0x04, 0xbe, 0x02, 0x02, 0x0a, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x20, 0x02, 0x03, 0x06, 0x12, 0x04, 0xbe, 0x02, 0x0b, 0x25, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x20, 0x02, 0x03, 0x01, 0x12, 0x04, 0xbe, 0x02, 0x26, 0x39, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x20, 0x02, 0x03, 0x03, 0x12, 0x04, 0xbe, 0x02, 0x3c, 0x3d, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x20, 0x02, 0x04, 0x12, 0x04, 0xbf, 0x02, 0x02, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02,
-    0x04, 0x04, 0x12, 0x04, 0xbf, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x04,
-    0x06, 0x12, 0x04, 0xbf, 0x02, 0x0b, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x04, 0x01,
-    0x12, 0x04, 0xbf, 0x02, 0x1b, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x04, 0x03, 0x12,
-    0x04, 0xbf, 0x02, 0x24, 0x25, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x21, 0x12, 0x06, 0xc2, 0x02, 0x00,
-    0xc5, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x21, 0x01, 0x12, 0x04, 0xc2, 0x02, 0x08, 0x22,
-    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x21, 0x02, 0x00, 0x12, 0x04, 0xc3, 0x02, 0x02, 0x27, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x04, 0x12, 0x04, 0xc3, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x21, 0x02, 0x00, 0x06, 0x12, 0x04, 0xc3, 0x02, 0x0b, 0x16, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x21, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc3, 0x02, 0x17, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x21, 0x02, 0x00, 0x03, 0x12, 0x04, 0xc3, 0x02, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x21,
-    0x02, 0x01, 0x12, 0x04, 0xc4, 0x02, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x01,
-    0x05, 0x12, 0x04, 0xc4, 0x02, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x01, 0x01,
-    0x12, 0x04, 0xc4, 0x02, 0x07, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x01, 0x03, 0x12,
-    0x04, 0xc4, 0x02, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x22, 0x12, 0x06, 0xc7, 0x02, 0x00,
-    0xca, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x22, 0x01, 0x12, 0x04, 0xc7, 0x02, 0x08, 0x17,
-    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x22, 0x02, 0x00, 0x12, 0x04, 0xc8, 0x02, 0x02, 0x12, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x22, 0x02, 0x00, 0x05, 0x12, 0x04, 0xc8, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x22, 0x02, 0x00, 0x01, 0x12, 0x04, 0xc8, 0x02, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x22, 0x02, 0x00, 0x03, 0x12, 0x04, 0xc8, 0x02, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x22, 0x02, 0x01, 0x12, 0x04, 0xc9, 0x02, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02,
-    0x01, 0x06, 0x12, 0x04, 0xc9, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x01,
-    0x01, 0x12, 0x04, 0xc9, 0x02, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x01, 0x03,
-    0x12, 0x04, 0xc9, 0x02, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x23, 0x12, 0x06, 0xcc, 0x02,
-    0x00, 0xce, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x23, 0x01, 0x12, 0x04, 0xcc, 0x02, 0x08,
-    0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x23, 0x02, 0x00, 0x12, 0x04, 0xcd, 0x02, 0x02, 0x27, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x00, 0x04, 0x12, 0x04, 0xcd, 0x02, 0x02, 0x0a, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x23, 0x02, 0x00, 0x06, 0x12, 0x04, 0xcd, 0x02, 0x0b, 0x16, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x23, 0x02, 0x00, 0x01, 0x12, 0x04, 0xcd, 0x02, 0x17, 0x22, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x23, 0x02, 0x00, 0x03, 0x12, 0x04, 0xcd, 0x02, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x02, 0x05,
-    0x00, 0x12, 0x06, 0xd0, 0x02, 0x00, 0xe0, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x05, 0x00, 0x01,
-    0x12, 0x04, 0xd0, 0x02, 0x05, 0x0e, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x04,
-    0xd1, 0x02, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd1,
-    0x02, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0xd1, 0x02,
-    0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x04, 0xd2, 0x02, 0x02, 0x16,
-    0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd2, 0x02, 0x02, 0x11, 0x0a,
-    0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0xd2, 0x02, 0x14, 0x15, 0x0a, 0x0c,
-    0x0a, 0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x04, 0xd3, 0x02, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05,
-    0x05, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0xd3, 0x02, 0x02, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x05,
-    0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0xd3, 0x02, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x00,
-    0x02, 0x03, 0x12, 0x04, 0xd4, 0x02, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03,
-    0x01, 0x12, 0x04, 0xd4, 0x02, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02,
-    0x12, 0x04, 0xd4, 0x02, 0x13, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x04, 0x12, 0x04,
-    0xd5, 0x02, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0xd5,
-    0x02, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0xd5, 0x02,
-    0x13, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x05, 0x12, 0x04, 0xd6, 0x02, 0x02, 0x15,
-    0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x05, 0x01, 0x12, 0x04, 0xd6, 0x02, 0x02, 0x10, 0x0a,
-    0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x05, 0x02, 0x12, 0x04, 0xd6, 0x02, 0x13, 0x14, 0x0a, 0x0c,
-    0x0a, 0x04, 0x05, 0x00, 0x02, 0x06, 0x12, 0x04, 0xd7, 0x02, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05,
-    0x05, 0x00, 0x02, 0x06, 0x01, 0x12, 0x04, 0xd7, 0x02, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x05,
-    0x00, 0x02, 0x06, 0x02, 0x12, 0x04, 0xd7, 0x02, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x00,
-    0x02, 0x07, 0x12, 0x04, 0xd8, 0x02, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x07,
-    0x01, 0x12, 0x04, 0xd8, 0x02, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x07, 0x02,
-    0x12, 0x04, 0xd8, 0x02, 0x14, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x08, 0x12, 0x04,
-    0xd9, 0x02, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x08, 0x01, 0x12, 0x04, 0xd9,
-    0x02, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x08, 0x02, 0x12, 0x04, 0xd9, 0x02,
-    0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x09, 0x12, 0x04, 0xda, 0x02, 0x02, 0x18,
-    0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x09, 0x01, 0x12, 0x04, 0xda, 0x02, 0x02, 0x13, 0x0a,
-    0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x09, 0x02, 0x12, 0x04, 0xda, 0x02, 0x16, 0x17, 0x0a, 0x2b,
-    0x0a, 0x04, 0x05, 0x00, 0x02, 0x0a, 0x12, 0x04, 0xdb, 0x02, 0x02, 0x18, 0x22, 0x1d, 0x20, 0x60,
-    0x7b, 0x20, 0x69, 0x74, 0x65, 0x6d, 0x73, 0x3a, 0x20, 0x42, 0x6f, 0x78, 0x3c, 0x4d, 0x6f, 0x76,
-    0x65, 0x54, 0x79, 0x70, 0x65, 0x3e, 0x20, 0x7d, 0x60, 0x2c, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05,
-    0x00, 0x02, 0x0a, 0x01, 0x12, 0x04, 0xdb, 0x02, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00,
-    0x02, 0x0a, 0x02, 0x12, 0x04, 0xdb, 0x02, 0x16, 0x17, 0x0a, 0x22, 0x0a, 0x04, 0x05, 0x00, 0x02,
-    0x0b, 0x12, 0x04, 0xdc, 0x02, 0x02, 0x18, 0x22, 0x14, 0x20, 0x60, 0x28, 0x4d, 0x6f, 0x76, 0x65,
-    0x53, 0x74, 0x72, 0x75, 0x63, 0x74, 0x54, 0x61, 0x67, 0x29, 0x60, 0x2c, 0x0a, 0x0a, 0x0d, 0x0a,
-    0x05, 0x05, 0x00, 0x02, 0x0b, 0x01, 0x12, 0x04, 0xdc, 0x02, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05,
-    0x05, 0x00, 0x02, 0x0b, 0x02, 0x12, 0x04, 0xdc, 0x02, 0x16, 0x17, 0x0a, 0x22, 0x0a, 0x04, 0x05,
-    0x00, 0x02, 0x0c, 0x12, 0x04, 0xdd, 0x02, 0x02, 0x24, 0x22, 0x14, 0x20, 0x60, 0x7b, 0x20, 0x69,
-    0x6e, 0x64, 0x65, 0x78, 0x3a, 0x20, 0x75, 0x31, 0x36, 0x20, 0x7d, 0x60, 0x60, 0x2c, 0x0a, 0x0a,
-    0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x0c, 0x01, 0x12, 0x04, 0xdd, 0x02, 0x02, 0x1f, 0x0a, 0x0d,
-    0x0a, 0x05, 0x05, 0x00, 0x02, 0x0c, 0x02, 0x12, 0x04, 0xdd, 0x02, 0x22, 0x23, 0x0a, 0x37, 0x0a,
-    0x04, 0x05, 0x00, 0x02, 0x0d, 0x12, 0x04, 0xde, 0x02, 0x02, 0x1c, 0x22, 0x29, 0x20, 0x60, 0x7b,
-    0x20, 0x6d, 0x75, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x3a, 0x20, 0x62, 0x6f, 0x6f, 0x6c, 0x2c, 0x20,
-    0x74, 0x6f, 0x3a, 0x20, 0x42, 0x6f, 0x78, 0x3c, 0x4d, 0x6f, 0x76, 0x65, 0x54, 0x79, 0x70, 0x65,
-    0x3e, 0x20, 0x7d, 0x60, 0x2c, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x0d, 0x01, 0x12,
-    0x04, 0xde, 0x02, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x0d, 0x02, 0x12, 0x04,
-    0xde, 0x02, 0x19, 0x1b, 0x0a, 0x1b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x0e, 0x12, 0x04, 0xdf, 0x02,
-    0x02, 0x1d, 0x22, 0x0d, 0x20, 0x60, 0x28, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x29, 0x60, 0x2c,
-    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x0e, 0x01, 0x12, 0x04, 0xdf, 0x02, 0x02, 0x17,
-    0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x0e, 0x02, 0x12, 0x04, 0xdf, 0x02, 0x1a, 0x1c, 0x0a,
-    0x0c, 0x0a, 0x02, 0x04, 0x24, 0x12, 0x06, 0xe2, 0x02, 0x00, 0xf1, 0x02, 0x01, 0x0a, 0x0b, 0x0a,
-    0x03, 0x04, 0x24, 0x01, 0x12, 0x04, 0xe2, 0x02, 0x08, 0x10, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x24,
-    0x03, 0x00, 0x12, 0x06, 0xe4, 0x02, 0x02, 0xe7, 0x02, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24,
-    0x03, 0x00, 0x01, 0x12, 0x04, 0xe4, 0x02, 0x0a, 0x17, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x24, 0x03,
-    0x00, 0x02, 0x00, 0x12, 0x04, 0xe5, 0x02, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x24, 0x03,
-    0x00, 0x02, 0x00, 0x05, 0x12, 0x04, 0xe5, 0x02, 0x04, 0x08, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x24,
-    0x03, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe5, 0x02, 0x09, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
-    0x24, 0x03, 0x00, 0x02, 0x00, 0x03, 0x12, 0x04, 0xe5, 0x02, 0x13, 0x14, 0x0a, 0x0e, 0x0a, 0x06,
-    0x04, 0x24, 0x03, 0x00, 0x02, 0x01, 0x12, 0x04, 0xe6, 0x02, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07,
-    0x04, 0x24, 0x03, 0x00, 0x02, 0x01, 0x06, 0x12, 0x04, 0xe6, 0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a,
-    0x07, 0x04, 0x24, 0x03, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xe6, 0x02, 0x0d, 0x0f, 0x0a, 0x0f,
-    0x0a, 0x07, 0x04, 0x24, 0x03, 0x00, 0x02, 0x01, 0x03, 0x12, 0x04, 0xe6, 0x02, 0x12, 0x13, 0x0a,
-    0x0c, 0x0a, 0x04, 0x04, 0x24, 0x02, 0x00, 0x12, 0x04, 0xe9, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x24, 0x02, 0x00, 0x06, 0x12, 0x04, 0xe9, 0x02, 0x02, 0x0b, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x24, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe9, 0x02, 0x0c, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x24, 0x02, 0x00, 0x03, 0x12, 0x04, 0xe9, 0x02, 0x13, 0x14, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x24,
-    0x08, 0x00, 0x12, 0x06, 0xea, 0x02, 0x02, 0xf0, 0x02, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24,
-    0x08, 0x00, 0x01, 0x12, 0x04, 0xea, 0x02, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x24, 0x02,
-    0x01, 0x12, 0x04, 0xeb, 0x02, 0x04, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x01, 0x06,
-    0x12, 0x04, 0xeb, 0x02, 0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x01, 0x01, 0x12,
-    0x04, 0xeb, 0x02, 0x0d, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x01, 0x03, 0x12, 0x04,
-    0xeb, 0x02, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x24, 0x02, 0x02, 0x12, 0x04, 0xec, 0x02,
-    0x04, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x02, 0x06, 0x12, 0x04, 0xec, 0x02, 0x04,
-    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x02, 0x01, 0x12, 0x04, 0xec, 0x02, 0x12, 0x18,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x02, 0x03, 0x12, 0x04, 0xec, 0x02, 0x1b, 0x1c, 0x0a,
-    0x0c, 0x0a, 0x04, 0x04, 0x24, 0x02, 0x03, 0x12, 0x04, 0xed, 0x02, 0x04, 0x28, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x24, 0x02, 0x03, 0x05, 0x12, 0x04, 0xed, 0x02, 0x04, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x24, 0x02, 0x03, 0x01, 0x12, 0x04, 0xed, 0x02, 0x0b, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x24, 0x02, 0x03, 0x03, 0x12, 0x04, 0xed, 0x02, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x24,
-    0x02, 0x04, 0x12, 0x04, 0xee, 0x02, 0x04, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x04,
-    0x06, 0x12, 0x04, 0xee, 0x02, 0x04, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x04, 0x01,
-    0x12, 0x04, 0xee, 0x02, 0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x04, 0x03, 0x12,
-    0x04, 0xee, 0x02, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x24, 0x02, 0x05, 0x12, 0x04, 0xef,
-    0x02, 0x04, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x05, 0x05, 0x12, 0x04, 0xef, 0x02,
-    0x04, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x05, 0x01, 0x12, 0x04, 0xef, 0x02, 0x0b,
-    0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x05, 0x03, 0x12, 0x04, 0xef, 0x02, 0x18, 0x19,
-    0x0a, 0x0c, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x06, 0xf3, 0x02, 0x00, 0xf9, 0x02, 0x01, 0x0a, 0x0b,
-    0x0a, 0x03, 0x05, 0x01, 0x01, 0x12, 0x04, 0xf3, 0x02, 0x05, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x05,
-    0x01, 0x02, 0x00, 0x12, 0x04, 0xf4, 0x02, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x01, 0x02,
-    0x00, 0x01, 0x12, 0x04, 0xf4, 0x02, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00,
-    0x02, 0x12, 0x04, 0xf4, 0x02, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x01, 0x12,
-    0x04, 0xf5, 0x02, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x01, 0x12, 0x04,
-    0xf5, 0x02, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02, 0x12, 0x04, 0xf5,
-    0x02, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x02, 0x12, 0x04, 0xf6, 0x02, 0x02,
-    0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x02, 0x01, 0x12, 0x04, 0xf6, 0x02, 0x02, 0x13,
-    0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x02, 0x02, 0x12, 0x04, 0xf6, 0x02, 0x16, 0x17, 0x0a,
-    0x0c, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x03, 0x12, 0x04, 0xf7, 0x02, 0x02, 0x19, 0x0a, 0x0d, 0x0a,
-    0x05, 0x05, 0x01, 0x02, 0x03, 0x01, 0x12, 0x04, 0xf7, 0x02, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05,
-    0x05, 0x01, 0x02, 0x03, 0x02, 0x12, 0x04, 0xf7, 0x02, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x05,
-    0x01, 0x02, 0x04, 0x12, 0x04, 0xf8, 0x02, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x01, 0x02,
-    0x04, 0x01, 0x12, 0x04, 0xf8, 0x02, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x04,
-    0x02, 0x12, 0x04, 0xf8, 0x02, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x25, 0x12, 0x06, 0xfb,
-    0x02, 0x00, 0xfd, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x25, 0x01, 0x12, 0x04, 0xfb, 0x02,
-    0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x25, 0x02, 0x00, 0x12, 0x04, 0xfc, 0x02, 0x02, 0x19,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x06, 0x12, 0x04, 0xfc, 0x02, 0x02, 0x0a, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x01, 0x12, 0x04, 0xfc, 0x02, 0x0b, 0x14, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x25, 0x02, 0x00, 0x03, 0x12, 0x04, 0xfc, 0x02, 0x17, 0x18, 0x0a, 0x0c, 0x0a,
-    0x02, 0x04, 0x26, 0x12, 0x06, 0xff, 0x02, 0x00, 0x82, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
-    0x26, 0x01, 0x12, 0x04, 0xff, 0x02, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x26, 0x02, 0x00,
-    0x12, 0x04, 0x80, 0x03, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x00, 0x06, 0x12,
-    0x04, 0x80, 0x03, 0x02, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x00, 0x01, 0x12, 0x04,
-    0x80, 0x03, 0x0f, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x00, 0x03, 0x12, 0x04, 0x80,
-    0x03, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x26, 0x02, 0x01, 0x12, 0x04, 0x81, 0x03, 0x02,
-    0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x01, 0x05, 0x12, 0x04, 0x81, 0x03, 0x02, 0x08,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x01, 0x01, 0x12, 0x04, 0x81, 0x03, 0x09, 0x0d, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x01, 0x03, 0x12, 0x04, 0x81, 0x03, 0x10, 0x11, 0x0a, 0x0c,
-    0x0a, 0x02, 0x04, 0x27, 0x12, 0x06, 0x84, 0x03, 0x00, 0x87, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03,
-    0x04, 0x27, 0x01, 0x12, 0x04, 0x84, 0x03, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x27, 0x02,
-    0x00, 0x12, 0x04, 0x85, 0x03, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x00, 0x05,
-    0x12, 0x04, 0x85, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x00, 0x01, 0x12,
-    0x04, 0x85, 0x03, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x00, 0x03, 0x12, 0x04,
-    0x85, 0x03, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x27, 0x02, 0x01, 0x12, 0x04, 0x86, 0x03,
-    0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x01, 0x05, 0x12, 0x04, 0x86, 0x03, 0x02,
-    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x01, 0x01, 0x12, 0x04, 0x86, 0x03, 0x09, 0x0d,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x01, 0x03, 0x12, 0x04, 0x86, 0x03, 0x10, 0x11, 0x0a,
-    0x0c, 0x0a, 0x02, 0x04, 0x28, 0x12, 0x06, 0x89, 0x03, 0x00, 0x8e, 0x03, 0x01, 0x0a, 0x0b, 0x0a,
-    0x03, 0x04, 0x28, 0x01, 0x12, 0x04, 0x89, 0x03, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x28,
-    0x02, 0x00, 0x12, 0x04, 0x8a, 0x03, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x00,
-    0x05, 0x12, 0x04, 0x8a, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x00, 0x01,
-    0x12, 0x04, 0x8a, 0x03, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x00, 0x03, 0x12,
-    0x04, 0x8a, 0x03, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x28, 0x02, 0x01, 0x12, 0x04, 0x8b,
-    0x03, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x01, 0x05, 0x12, 0x04, 0x8b, 0x03,
-    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8b, 0x03, 0x09,
-    0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8b, 0x03, 0x12, 0x13,
-    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x28, 0x02, 0x02, 0x12, 0x04, 0x8c, 0x03, 0x02, 0x12, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x28, 0x02, 0x02, 0x05, 0x12, 0x04, 0x8c, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x28, 0x02, 0x02, 0x01, 0x12, 0x04, 0x8c, 0x03, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x28, 0x02, 0x02, 0x03, 0x12, 0x04, 0x8c, 0x03, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x28, 0x02, 0x03, 0x12, 0x04, 0x8d, 0x03, 0x02, 0x2c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02,
-    0x03, 0x04, 0x12, 0x04, 0x8d, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x03,
-    0x06, 0x12, 0x04, 0x8d, 0x03, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x03, 0x01,
-    0x12, 0x04, 0x8d, 0x03, 0x14, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x03, 0x03, 0x12,
-    0x04, 0x8d, 0x03, 0x2a, 0x2b, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x29, 0x12, 0x06, 0x90, 0x03, 0x00,
-    0xa5, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x29, 0x01, 0x12, 0x04, 0x90, 0x03, 0x08, 0x11,
-    0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x29, 0x04, 0x00, 0x12, 0x06, 0x92, 0x03, 0x02, 0x9a, 0x03, 0x03,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x04, 0x00, 0x01, 0x12, 0x04, 0x92, 0x03, 0x07, 0x0b, 0x0a,
-    0x0e, 0x0a, 0x06, 0x04, 0x29, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0x93, 0x03, 0x04, 0x19, 0x0a,
-    0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x93, 0x03, 0x04, 0x14,
-    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0x93, 0x03, 0x17,
-    0x18, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x29, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0x94, 0x03, 0x04,
-    0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x94, 0x03,
-    0x04, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x94,
-    0x03, 0x13, 0x14, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x29, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0x95,
-    0x03, 0x04, 0x1b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04,
-    0x95, 0x03, 0x04, 0x16, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12,
-    0x04, 0x95, 0x03, 0x19, 0x1a, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x29, 0x04, 0x00, 0x02, 0x03, 0x12,
-    0x04, 0x96, 0x03, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x03, 0x01,
-    0x12, 0x04, 0x96, 0x03, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x03,
-    0x02, 0x12, 0x04, 0x96, 0x03, 0x17, 0x18, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x29, 0x04, 0x00, 0x02,
-    0x04, 0x12, 0x04, 0x97, 0x03, 0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02,
-    0x04, 0x01, 0x12, 0x04, 0x97, 0x03, 0x04, 0x12, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00,
-    0x02, 0x04, 0x02, 0x12, 0x04, 0x97, 0x03, 0x15, 0x16, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x29, 0x04,
-    0x00, 0x02, 0x05, 0x12, 0x04, 0x98, 0x03, 0x04, 0x1b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04,
-    0x00, 0x02, 0x05, 0x01, 0x12, 0x04, 0x98, 0x03, 0x04, 0x16, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29,
-    0x04, 0x00, 0x02, 0x05, 0x02, 0x12, 0x04, 0x98, 0x03, 0x19, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x29, 0x04, 0x00, 0x04, 0x12, 0x04, 0x99, 0x03, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x29,
-    0x04, 0x00, 0x04, 0x00, 0x12, 0x04, 0x99, 0x03, 0x0d, 0x0e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29,
-    0x04, 0x00, 0x04, 0x00, 0x01, 0x12, 0x04, 0x99, 0x03, 0x0d, 0x0e, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
-    0x29, 0x04, 0x00, 0x04, 0x00, 0x02, 0x12, 0x04, 0x99, 0x03, 0x0d, 0x0e, 0x0a, 0x0c, 0x0a, 0x04,
-    0x04, 0x29, 0x02, 0x00, 0x12, 0x04, 0x9c, 0x03, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29,
-    0x02, 0x00, 0x06, 0x12, 0x04, 0x9c, 0x03, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02,
-    0x00, 0x01, 0x12, 0x04, 0x9c, 0x03, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x00,
-    0x03, 0x12, 0x04, 0x9c, 0x03, 0x0e, 0x0f, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x29, 0x08, 0x00, 0x12,
-    0x06, 0x9d, 0x03, 0x02, 0xa4, 0x03, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x08, 0x00, 0x01,
-    0x12, 0x04, 0x9d, 0x03, 0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x29, 0x02, 0x01, 0x12, 0x04,
-    0x9e, 0x03, 0x04, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x01, 0x06, 0x12, 0x04, 0x9e,
-    0x03, 0x04, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x01, 0x01, 0x12, 0x04, 0x9e, 0x03,
-    0x15, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x01, 0x03, 0x12, 0x04, 0x9e, 0x03, 0x1f,
-    0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x29, 0x02, 0x02, 0x12, 0x04, 0x9f, 0x03, 0x04, 0x2c, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x02, 0x06, 0x12, 0x04, 0x9f, 0x03, 0x04, 0x19, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x29, 0x02, 0x02, 0x01, 0x12, 0x04, 0x9f, 0x03, 0x1a, 0x27, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x29, 0x02, 0x02, 0x03, 0x12, 0x04, 0x9f, 0x03, 0x2a, 0x2b, 0x0a, 0x0c, 0x0a, 0x04,
-    0x04, 0x29, 0x02, 0x03, 0x12, 0x04, 0xa0, 0x03, 0x04, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29,
-    0x02, 0x03, 0x06, 0x12, 0x04, 0xa0, 0x03, 0x04, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02,
-    0x03, 0x01, 0x12, 0x04, 0xa0, 0x03, 0x18, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x03,
-    0x03, 0x12, 0x04, 0xa0, 0x03, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x29, 0x02, 0x04, 0x12,
-    0x04, 0xa1, 0x03, 0x04, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x04, 0x06, 0x12, 0x04,
-    0xa1, 0x03, 0x04, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x04, 0x01, 0x12, 0x04, 0xa1,
-    0x03, 0x16, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x04, 0x03, 0x12, 0x04, 0xa1, 0x03,
-    0x22, 0x23, 0x0a, 0x1e, 0x0a, 0x04, 0x04, 0x29, 0x02, 0x05, 0x12, 0x04, 0xa3, 0x03, 0x04, 0x23,
-    0x1a, 0x10, 0x20, 0x36, 0x20, 0x69, 0x73, 0x20, 0x72, 0x65, 0x73, 0x65, 0x72, 0x76, 0x65, 0x64,
-    0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x05, 0x06, 0x12, 0x04, 0xa3, 0x03, 0x04,
-    0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x05, 0x01, 0x12, 0x04, 0xa3, 0x03, 0x11, 0x1e,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x05, 0x03, 0x12, 0x04, 0xa3, 0x03, 0x21, 0x22, 0x0a,
-    0x0c, 0x0a, 0x02, 0x04, 0x2a, 0x12, 0x06, 0xa7, 0x03, 0x00, 0xaa, 0x03, 0x01, 0x0a, 0x0b, 0x0a,
-    0x03, 0x04, 0x2a, 0x01, 0x12, 0x04, 0xa7, 0x03, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2a,
-    0x02, 0x00, 0x12, 0x04, 0xa8, 0x03, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x00,
-    0x05, 0x12, 0x04, 0xa8, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x00, 0x01,
-    0x12, 0x04, 0xa8, 0x03, 0x08, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x00, 0x03, 0x12,
-    0x04, 0xa8, 0x03, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2a, 0x02, 0x01, 0x12, 0x04, 0xa9,
-    0x03, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x01, 0x05, 0x12, 0x04, 0xa9, 0x03,
-    0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa9, 0x03, 0x08,
-    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa9, 0x03, 0x14, 0x15,
-    0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x2b, 0x12, 0x06, 0xac, 0x03, 0x00, 0xb1, 0x03, 0x01, 0x0a, 0x0b,
-    0x0a, 0x03, 0x04, 0x2b, 0x01, 0x12, 0x04, 0xac, 0x03, 0x08, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x2b, 0x02, 0x00, 0x12, 0x04, 0xad, 0x03, 0x02, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02,
-    0x00, 0x04, 0x12, 0x04, 0xad, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x00,
-    0x05, 0x12, 0x04, 0xad, 0x03, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x00, 0x01,
-    0x12, 0x04, 0xad, 0x03, 0x11, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x00, 0x03, 0x12,
-    0x04, 0xad, 0x03, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2b, 0x02, 0x01, 0x12, 0x04, 0xae,
-    0x03, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x01, 0x04, 0x12, 0x04, 0xae, 0x03,
-    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x01, 0x05, 0x12, 0x04, 0xae, 0x03, 0x0b,
-    0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x01, 0x01, 0x12, 0x04, 0xae, 0x03, 0x11, 0x1b,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x01, 0x03, 0x12, 0x04, 0xae, 0x03, 0x1e, 0x1f, 0x0a,
-    0x0c, 0x0a, 0x04, 0x04, 0x2b, 0x02, 0x02, 0x12, 0x04, 0xaf, 0x03, 0x02, 0x17, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x2b, 0x02, 0x02, 0x05, 0x12, 0x04, 0xaf, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x2b, 0x02, 0x02, 0x01, 0x12, 0x04, 0xaf, 0x03, 0x09, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x2b, 0x02, 0x02, 0x03, 0x12, 0x04, 0xaf, 0x03, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2b,
-    0x02, 0x03, 0x12, 0x04, 0xb0, 0x03, 0x02, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x03,
-    0x04, 0x12, 0x04, 0xb0, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x03, 0x05,
-    0x12, 0x04, 0xb0, 0x03, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x03, 0x01, 0x12,
-    0x04, 0xb0, 0x03, 0x12, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x03, 0x03, 0x12, 0x04,
-    0xb0, 0x03, 0x27, 0x28, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x2c, 0x12, 0x06, 0xb3, 0x03, 0x00, 0xb7,
-    0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x2c, 0x01, 0x12, 0x04, 0xb3, 0x03, 0x08, 0x1b, 0x0a,
-    0x0c, 0x0a, 0x04, 0x04, 0x2c, 0x02, 0x00, 0x12, 0x04, 0xb4, 0x03, 0x02, 0x1e, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x2c, 0x02, 0x00, 0x06, 0x12, 0x04, 0xb4, 0x03, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x2c, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb4, 0x03, 0x13, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x2c, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb4, 0x03, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2c,
-    0x02, 0x01, 0x12, 0x04, 0xb5, 0x03, 0x02, 0x31, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x01,
-    0x04, 0x12, 0x04, 0xb5, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x01, 0x05,
-    0x12, 0x04, 0xb5, 0x03, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x01, 0x01, 0x12,
-    0x04, 0xb5, 0x03, 0x12, 0x2c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x01, 0x03, 0x12, 0x04,
-    0xb5, 0x03, 0x2f, 0x30, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2c, 0x02, 0x02, 0x12, 0x04, 0xb6, 0x03,
-    0x02, 0x32, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x02, 0x04, 0x12, 0x04, 0xb6, 0x03, 0x02,
-    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x02, 0x06, 0x12, 0x04, 0xb6, 0x03, 0x0b, 0x1b,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x02, 0x01, 0x12, 0x04, 0xb6, 0x03, 0x1c, 0x2d, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x02, 0x03, 0x12, 0x04, 0xb6, 0x03, 0x30, 0x31, 0x0a, 0x0c,
-    0x0a, 0x02, 0x04, 0x2d, 0x12, 0x06, 0xb9, 0x03, 0x00, 0xbf, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03,
-    0x04, 0x2d, 0x01, 0x12, 0x04, 0xb9, 0x03, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2d, 0x02,
-    0x00, 0x12, 0x04, 0xba, 0x03, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x00, 0x06,
-    0x12, 0x04, 0xba, 0x03, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x00, 0x01, 0x12,
-    0x04, 0xba, 0x03, 0x13, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x00, 0x03, 0x12, 0x04,
-    0xba, 0x03, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2d, 0x02, 0x01, 0x12, 0x04, 0xbb, 0x03,
-    0x02, 0x31, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x01, 0x04, 0x12, 0x04, 0xbb, 0x03, 0x02,
-    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x01, 0x05, 0x12, 0x04, 0xbb, 0x03, 0x0b, 0x11,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x01, 0x01, 0x12, 0x04, 0xbb, 0x03, 0x12, 0x2c, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x01, 0x03, 0x12, 0x04, 0xbb, 0x03, 0x2f, 0x30, 0x0a, 0x0c,
-    0x0a, 0x04, 0x04, 0x2d, 0x02, 0x02, 0x12, 0x04, 0xbc, 0x03, 0x02, 0x32, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x2d, 0x02, 0x02, 0x04, 0x12, 0x04, 0xbc, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x2d, 0x02, 0x02, 0x06, 0x12, 0x04, 0xbc, 0x03, 0x0b, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d,
-    0x02, 0x02, 0x01, 0x12, 0x04, 0xbc, 0x03, 0x1c, 0x2d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02,
-    0x02, 0x03, 0x12, 0x04, 0xbc, 0x03, 0x30, 0x31, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2d, 0x02, 0x03,
-    0x12, 0x04, 0xbd, 0x03, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x03, 0x05, 0x12,
-    0x04, 0xbd, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x03, 0x01, 0x12, 0x04,
-    0xbd, 0x03, 0x09, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x03, 0x03, 0x12, 0x04, 0xbd,
-    0x03, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2d, 0x02, 0x04, 0x12, 0x04, 0xbe, 0x03, 0x02,
-    0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x04, 0x06, 0x12, 0x04, 0xbe, 0x03, 0x02, 0x12,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x04, 0x01, 0x12, 0x04, 0xbe, 0x03, 0x13, 0x23, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x04, 0x03, 0x12, 0x04, 0xbe, 0x03, 0x26, 0x27, 0x0a, 0x0c,
-    0x0a, 0x02, 0x04, 0x2e, 0x12, 0x06, 0xc1, 0x03, 0x00, 0xcc, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03,
-    0x04, 0x2e, 0x01, 0x12, 0x04, 0xc1, 0x03, 0x08, 0x14, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x2e, 0x04,
-    0x00, 0x12, 0x06, 0xc2, 0x03, 0x02, 0xc8, 0x03, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2e, 0x04,
-    0x00, 0x01, 0x12, 0x04, 0xc2, 0x03, 0x07, 0x0b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2e, 0x04, 0x00,
-    0x02, 0x00, 0x12, 0x04, 0xc3, 0x03, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2e, 0x04, 0x00,
-    0x02, 0x00, 0x01, 0x12, 0x04, 0xc3, 0x03, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2e, 0x04,
-    0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0xc3, 0x03, 0x17, 0x18, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2e,
-    0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0xc4, 0x03, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2e,
-    0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc4, 0x03, 0x04, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
-    0x2e, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0xc4, 0x03, 0x13, 0x14, 0x0a, 0x0e, 0x0a, 0x06,
-    0x04, 0x2e, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0xc5, 0x03, 0x04, 0x1d, 0x0a, 0x0f, 0x0a, 0x07,
-    0x04, 0x2e, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0xc5, 0x03, 0x04, 0x18, 0x0a, 0x0f, 0x0a,
-    0x07, 0x04, 0x2e, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0xc5, 0x03, 0x1b, 0x1c, 0x0a, 0x0e,
-    0x0a, 0x06, 0x04, 0x2e, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0xc6, 0x03, 0x04, 0x1d, 0x0a, 0x0f,
-    0x0a, 0x07, 0x04, 0x2e, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0xc6, 0x03, 0x04, 0x18, 0x0a,
-    0x0f, 0x0a, 0x07, 0x04, 0x2e, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0xc6, 0x03, 0x1b, 0x1c,
-    0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2e, 0x04, 0x00, 0x02, 0x04, 0x12, 0x04, 0xc7, 0x03, 0x04, 0x15,
-    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2e, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0xc7, 0x03, 0x04,
-    0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2e, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0xc7, 0x03,
-    0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2e, 0x02, 0x00, 0x12, 0x04, 0xca, 0x03, 0x02, 0x10,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2e, 0x02, 0x00, 0x06, 0x12, 0x04, 0xca, 0x03, 0x02, 0x06, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x2e, 0x02, 0x00, 0x01, 0x12, 0x04, 0xca, 0x03, 0x07, 0x0b, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x2e, 0x02, 0x00, 0x03, 0x12, 0x04, 0xca, 0x03, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x2e, 0x02, 0x01, 0x12, 0x04, 0xcb, 0x03, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x2e, 0x02, 0x01, 0x05, 0x12, 0x04, 0xcb, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2e,
-    0x02, 0x01, 0x01, 0x12, 0x04, 0xcb, 0x03, 0x08, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2e, 0x02,
-    0x01, 0x03, 0x12, 0x04, 0xcb, 0x03, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x2f, 0x12, 0x06,
-    0xce, 0x03, 0x00, 0xe4, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x2f, 0x01, 0x12, 0x04, 0xce,
-    0x03, 0x08, 0x14, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x2f, 0x04, 0x00, 0x12, 0x06, 0xcf, 0x03, 0x02,
-    0xd5, 0x03, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x04, 0x00, 0x01, 0x12, 0x04, 0xcf, 0x03,
-    0x07, 0x0b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0xd0, 0x03,
-    0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd0,
-    0x03, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04,
-    0xd0, 0x03, 0x17, 0x18, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04,
-    0xd1, 0x03, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12,
-    0x04, 0xd1, 0x03, 0x04, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x01, 0x02,
-    0x12, 0x04, 0xd1, 0x03, 0x13, 0x14, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x02,
-    0x12, 0x04, 0xd2, 0x03, 0x04, 0x1d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x02,
-    0x01, 0x12, 0x04, 0xd2, 0x03, 0x04, 0x18, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00, 0x02,
-    0x02, 0x02, 0x12, 0x04, 0xd2, 0x03, 0x1b, 0x1c, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2f, 0x04, 0x00,
-    0x02, 0x03, 0x12, 0x04, 0xd3, 0x03, 0x04, 0x16, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00,
-    0x02, 0x03, 0x01, 0x12, 0x04, 0xd3, 0x03, 0x04, 0x11, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04,
-    0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0xd3, 0x03, 0x14, 0x15, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2f,
-    0x04, 0x00, 0x02, 0x04, 0x12, 0x04, 0xd4, 0x03, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f,
-    0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0xd4, 0x03, 0x04, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
-    0x2f, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0xd4, 0x03, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04,
-    0x04, 0x2f, 0x02, 0x00, 0x12, 0x04, 0xd7, 0x03, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f,
-    0x02, 0x00, 0x06, 0x12, 0x04, 0xd7, 0x03, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02,
-    0x00, 0x01, 0x12, 0x04, 0xd7, 0x03, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x00,
-    0x03, 0x12, 0x04, 0xd7, 0x03, 0x0e, 0x0f, 0x0a, 0x64, 0x0a, 0x04, 0x04, 0x2f, 0x02, 0x01, 0x12,
-    0x04, 0xdb, 0x03, 0x02, 0x2a, 0x1a, 0x56, 0x20, 0x44, 0x65, 0x70, 0x72, 0x65, 0x63, 0x61, 0x74,
-    0x65, 0x64, 0x3a, 0x20, 0x75, 0x73, 0x65, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72,
-    0x65, 0x5f, 0x76, 0x61, 0x72, 0x69, 0x61, 0x6e, 0x74, 0x20, 0x69, 0x6e, 0x73, 0x74, 0x65, 0x61,
-    0x64, 0x2e, 0x0a, 0x20, 0x4e, 0x6f, 0x74, 0x65, 0x3a, 0x20, 0x3e, 0x3d, 0x20, 0x31, 0x2e, 0x31,
-    0x30, 0x2c, 0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x20, 0x69, 0x73,
-    0x20, 0x64, 0x65, 0x70, 0x72, 0x65, 0x63, 0x61, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x2f, 0x02, 0x01, 0x05, 0x12, 0x04, 0xdb, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x2f, 0x02, 0x01, 0x01, 0x12, 0x04, 0xdb, 0x03, 0x08, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x2f, 0x02, 0x01, 0x03, 0x12, 0x04, 0xdb, 0x03, 0x14, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f,
-    0x02, 0x01, 0x08, 0x12, 0x04, 0xdb, 0x03, 0x16, 0x29, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2f, 0x02,
-    0x01, 0x08, 0x03, 0x12, 0x04, 0xdb, 0x03, 0x17, 0x28, 0x0a, 0x23, 0x0a, 0x04, 0x04, 0x2f, 0x08,
-    0x00, 0x12, 0x06, 0xde, 0x03, 0x02, 0xe3, 0x03, 0x03, 0x1a, 0x13, 0x20, 0x53, 0x75, 0x70, 0x70,
-    0x6f, 0x72, 0x74, 0x3a, 0x20, 0x3e, 0x3d, 0x20, 0x31, 0x2e, 0x31, 0x30, 0x2e, 0x0a, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x2f, 0x08, 0x00, 0x01, 0x12, 0x04, 0xde, 0x03, 0x08, 0x19, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x2f, 0x02, 0x02, 0x12, 0x04, 0xdf, 0x03, 0x04, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x2f, 0x02, 0x02, 0x06, 0x12, 0x04, 0xdf, 0x03, 0x04, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f,
-    0x02, 0x02, 0x01, 0x12, 0x04, 0xdf, 0x03, 0x0c, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02,
-    0x02, 0x03, 0x12, 0x04, 0xdf, 0x03, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2f, 0x02, 0x03,
-    0x12, 0x04, 0xe0, 0x03, 0x04, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x03, 0x06, 0x12,
-    0x04, 0xe0, 0x03, 0x04, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x03, 0x01, 0x12, 0x04,
-    0xe0, 0x03, 0x13, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x03, 0x03, 0x12, 0x04, 0xe0,
-    0x03, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2f, 0x02, 0x04, 0x12, 0x04, 0xe1, 0x03, 0x04,
-    0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x04, 0x06, 0x12, 0x04, 0xe1, 0x03, 0x04, 0x0c,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x04, 0x01, 0x12, 0x04, 0xe1, 0x03, 0x0d, 0x15, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x04, 0x03, 0x12, 0x04, 0xe1, 0x03, 0x18, 0x19, 0x0a, 0x0c,
-    0x0a, 0x04, 0x04, 0x2f, 0x02, 0x05, 0x12, 0x04, 0xe2, 0x03, 0x04, 0x18, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x2f, 0x02, 0x05, 0x06, 0x12, 0x04, 0xe2, 0x03, 0x04, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x2f, 0x02, 0x05, 0x01, 0x12, 0x04, 0xe2, 0x03, 0x0c, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f,
-    0x02, 0x05, 0x03, 0x12, 0x04, 0xe2, 0x03, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x30, 0x12,
-    0x06, 0xe6, 0x03, 0x00, 0xe8, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x30, 0x01, 0x12, 0x04,
-    0xe6, 0x03, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x30, 0x02, 0x00, 0x12, 0x04, 0xe7, 0x03,
-    0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x00, 0x05, 0x12, 0x04, 0xe7, 0x03, 0x02,
-    0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe7, 0x03, 0x08, 0x11,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02, 0x00, 0x03, 0x12, 0x04, 0xe7, 0x03, 0x14, 0x15, 0x0a,
-    0x0c, 0x0a, 0x02, 0x04, 0x31, 0x12, 0x06, 0xea, 0x03, 0x00, 0xec, 0x03, 0x01, 0x0a, 0x0b, 0x0a,
-    0x03, 0x04, 0x31, 0x01, 0x12, 0x04, 0xea, 0x03, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x31,
-    0x02, 0x00, 0x12, 0x04, 0xeb, 0x03, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x31, 0x02, 0x00,
-    0x05, 0x12, 0x04, 0xeb, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x31, 0x02, 0x00, 0x01,
-    0x12, 0x04, 0xeb, 0x03, 0x08, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x31, 0x02, 0x00, 0x03, 0x12,
-    0x04, 0xeb, 0x03, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x32, 0x12, 0x06, 0xee, 0x03, 0x00,
-    0xf0, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x32, 0x01, 0x12, 0x04, 0xee, 0x03, 0x08, 0x10,
-    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x32, 0x02, 0x00, 0x12, 0x04, 0xef, 0x03, 0x02, 0x16, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x32, 0x02, 0x00, 0x05, 0x12, 0x04, 0xef, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x32, 0x02, 0x00, 0x01, 0x12, 0x04, 0xef, 0x03, 0x08, 0x11, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x32, 0x02, 0x00, 0x03, 0x12, 0x04, 0xef, 0x03, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
-    0x33, 0x12, 0x06, 0xf2, 0x03, 0x00, 0xf4, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x33, 0x01,
-    0x12, 0x04, 0xf2, 0x03, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x33, 0x02, 0x00, 0x12, 0x04,
-    0xf3, 0x03, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x33, 0x02, 0x00, 0x05, 0x12, 0x04, 0xf3,
-    0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x33, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf3, 0x03,
-    0x08, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x33, 0x02, 0x00, 0x03, 0x12, 0x04, 0xf3, 0x03, 0x14,
-    0x15, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x34, 0x12, 0x06, 0xf6, 0x03, 0x00, 0xf9, 0x03, 0x01, 0x0a,
-    0x0b, 0x0a, 0x03, 0x04, 0x34, 0x01, 0x12, 0x04, 0xf6, 0x03, 0x08, 0x1a, 0x0a, 0x0c, 0x0a, 0x04,
-    0x04, 0x34, 0x02, 0x00, 0x12, 0x04, 0xf7, 0x03, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34,
-    0x02, 0x00, 0x06, 0x12, 0x04, 0xf7, 0x03, 0x02, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34, 0x02,
-    0x00, 0x01, 0x12, 0x04, 0xf7, 0x03, 0x0f, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34, 0x02, 0x00,
-    0x03, 0x12, 0x04, 0xf7, 0x03, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x34, 0x02, 0x01, 0x12,
-    0x04, 0xf8, 0x03, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34, 0x02, 0x01, 0x06, 0x12, 0x04,
-    0xf8, 0x03, 0x02, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34, 0x02, 0x01, 0x01, 0x12, 0x04, 0xf8,
-    0x03, 0x0f, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34, 0x02, 0x01, 0x03, 0x12, 0x04, 0xf8, 0x03,
-    0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x35, 0x12, 0x06, 0xfb, 0x03, 0x00, 0xfe, 0x03, 0x01,
-    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x35, 0x01, 0x12, 0x04, 0xfb, 0x03, 0x08, 0x18, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x35, 0x02, 0x00, 0x12, 0x04, 0xfc, 0x03, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x35, 0x02, 0x00, 0x05, 0x12, 0x04, 0xfc, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x35,
-    0x02, 0x00, 0x01, 0x12, 0x04, 0xfc, 0x03, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x35, 0x02,
-    0x00, 0x03, 0x12, 0x04, 0xfc, 0x03, 0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x35, 0x02, 0x01,
-    0x12, 0x04, 0xfd, 0x03, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x35, 0x02, 0x01, 0x06, 0x12,
-    0x04, 0xfd, 0x03, 0x02, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x35, 0x02, 0x01, 0x01, 0x12, 0x04,
-    0xfd, 0x03, 0x0f, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x35, 0x02, 0x01, 0x03, 0x12, 0x04, 0xfd,
-    0x03, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x36, 0x12, 0x06, 0x80, 0x04, 0x00, 0x84, 0x04,
-    0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x36, 0x01, 0x12, 0x04, 0x80, 0x04, 0x08, 0x19, 0x0a, 0x0c,
-    0x0a, 0x04, 0x04, 0x36, 0x02, 0x00, 0x12, 0x04, 0x81, 0x04, 0x02, 0x28, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x36, 0x02, 0x00, 0x04, 0x12, 0x04, 0x81, 0x04, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x36, 0x02, 0x00, 0x06, 0x12, 0x04, 0x81, 0x04, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x36,
-    0x02, 0x00, 0x01, 0x12, 0x04, 0x81, 0x04, 0x18, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x36, 0x02,
-    0x00, 0x03, 0x12, 0x04, 0x81, 0x04, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x36, 0x02, 0x01,
-    0x12, 0x04, 0x82, 0x04, 0x02, 0x2b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x36, 0x02, 0x01, 0x04, 0x12,
-    0x04, 0x82, 0x04, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x36, 0x02, 0x01, 0x06, 0x12, 0x04,
-    0x82, 0x04, 0x0b, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x36, 0x02, 0x01, 0x01, 0x12, 0x04, 0x82,
-    0x04, 0x1c, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x36, 0x02, 0x01, 0x03, 0x12, 0x04, 0x82, 0x04,
-    0x29, 0x2a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x36, 0x02, 0x02, 0x12, 0x04, 0x83, 0x04, 0x02, 0x21,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x36, 0x02, 0x02, 0x05, 0x12, 0x04, 0x83, 0x04, 0x02, 0x08, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x36, 0x02, 0x02, 0x01, 0x12, 0x04, 0x83, 0x04, 0x09, 0x1c, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x36, 0x02, 0x02, 0x03, 0x12, 0x04, 0x83, 0x04, 0x1f, 0x20, 0x0a, 0x0c, 0x0a,
-    0x02, 0x04, 0x37, 0x12, 0x06, 0x86, 0x04, 0x00, 0x88, 0x04, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
-    0x37, 0x01, 0x12, 0x04, 0x86, 0x04, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x37, 0x02, 0x00,
-    0x12, 0x04, 0x87, 0x04, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x37, 0x02, 0x00, 0x06, 0x12,
-    0x04, 0x87, 0x04, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x37, 0x02, 0x00, 0x01, 0x12, 0x04,
-    0x87, 0x04, 0x13, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x37, 0x02, 0x00, 0x03, 0x12, 0x04, 0x87,
-    0x04, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x38, 0x12, 0x06, 0x8a, 0x04, 0x00, 0x9d, 0x04,
-    0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x38, 0x01, 0x12, 0x04, 0x8a, 0x04, 0x08, 0x18, 0x0a, 0x0e,
-    0x0a, 0x04, 0x04, 0x38, 0x04, 0x00, 0x12, 0x06, 0x8b, 0x04, 0x02, 0x93, 0x04, 0x03, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x38, 0x04, 0x00, 0x01, 0x12, 0x04, 0x8b, 0x04, 0x07, 0x0b, 0x0a, 0x0e, 0x0a,
-    0x06, 0x04, 0x38, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0x8c, 0x04, 0x04, 0x19, 0x0a, 0x0f, 0x0a,
-    0x07, 0x04, 0x38, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8c, 0x04, 0x04, 0x14, 0x0a, 0x0f,
-    0x0a, 0x07, 0x04, 0x38, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0x8c, 0x04, 0x17, 0x18, 0x0a,
-    0x0e, 0x0a, 0x06, 0x04, 0x38, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0x8d, 0x04, 0x04, 0x15, 0x0a,
-    0x0f, 0x0a, 0x07, 0x04, 0x38, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8d, 0x04, 0x04, 0x10,
-    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x38, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x8d, 0x04, 0x13,
-    0x14, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x38, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0x8e, 0x04, 0x04,
-    0x1b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x38, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x8e, 0x04,
-    0x04, 0x16, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x38, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x8e,
-    0x04, 0x19, 0x1a, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x38, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0x8f,
-    0x04, 0x04, 0x18, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x38, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04,
-    0x8f, 0x04, 0x04, 0x13, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x38, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12,
-    0x04, 0x8f, 0x04, 0x16, 0x17, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x38, 0x04, 0x00, 0x02, 0x04, 0x12,
-    0x04, 0x90, 0x04, 0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x38, 0x04, 0x00, 0x02, 0x04, 0x01,
-    0x12, 0x04, 0x90, 0x04, 0x04, 0x12, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x38, 0x04, 0x00, 0x02, 0x04,
-    0x02, 0x12, 0x04, 0x90, 0x04, 0x15, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x38, 0x04, 0x00, 0x04,
-    0x12, 0x04, 0x92, 0x04, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x38, 0x04, 0x00, 0x04, 0x00,
-    0x12, 0x04, 0x92, 0x04, 0x0d, 0x0e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x38, 0x04, 0x00, 0x04, 0x00,
-    0x01, 0x12, 0x04, 0x92, 0x04, 0x0d, 0x0e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x38, 0x04, 0x00, 0x04,
-    0x00, 0x02, 0x12, 0x04, 0x92, 0x04, 0x0d, 0x0e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x38, 0x02, 0x00,
-    0x12, 0x04, 0x95, 0x04, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x38, 0x02, 0x00, 0x06, 0x12,
-    0x04, 0x95, 0x04, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x38, 0x02, 0x00, 0x01, 0x12, 0x04,
-    0x95, 0x04, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x38, 0x02, 0x00, 0x03, 0x12, 0x04, 0x95,
-    0x04, 0x0e, 0x0f, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x38, 0x08, 0x00, 0x12, 0x06, 0x96, 0x04, 0x02,
-    0x9c, 0x04, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x38, 0x08, 0x00, 0x01, 0x12, 0x04, 0x96, 0x04,
-    0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x38, 0x02, 0x01, 0x12, 0x04, 0x97, 0x04, 0x04, 0x21,
-    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x38, 0x02, 0x01, 0x06, 0x12, 0x04, 0x97, 0x04, 0x04, 0x14, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x38, 0x02, 0x01, 0x01, 0x12, 0x04, 0x97, 0x04, 0x15, 0x1c, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x38, 0x02, 0x01, 0x03, 0x12, 0x04, 0x97, 0x04, 0x1f, 0x20, 0x0a, 0x0c, 0x0a,
-    0x04, 0x04, 0x38, 0x02, 0x02, 0x12, 0x04, 0x98, 0x04, 0x04, 0x2c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x38, 0x02, 0x02, 0x06, 0x12, 0x04, 0x98, 0x04, 0x04, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x38,
-    0x02, 0x02, 0x01, 0x12, 0x04, 0x98, 0x04, 0x1a, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x38, 0x02,
-    0x02, 0x03, 0x12, 0x04, 0x98, 0x04, 0x2a, 0x2b, 0x0a, 0x1e, 0x0a, 0x04, 0x04, 0x38, 0x02, 0x03,
-    0x12, 0x04, 0x9a, 0x04, 0x04, 0x30, 0x1a, 0x10, 0x20, 0x34, 0x20, 0x69, 0x73, 0x20, 0x72, 0x65,
-    0x73, 0x65, 0x72, 0x76, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x38, 0x02, 0x03,
-    0x06, 0x12, 0x04, 0x9a, 0x04, 0x04, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x38, 0x02, 0x03, 0x01,
-    0x12, 0x04, 0x9a, 0x04, 0x17, 0x2b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x38, 0x02, 0x03, 0x03, 0x12,
-    0x04, 0x9a, 0x04, 0x2e, 0x2f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x38, 0x02, 0x04, 0x12, 0x04, 0x9b,
-    0x04, 0x04, 0x2e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x38, 0x02, 0x04, 0x06, 0x12, 0x04, 0x9b, 0x04,
-    0x04, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x38, 0x02, 0x04, 0x01, 0x12, 0x04, 0x9b, 0x04, 0x16,
-    0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x38, 0x02, 0x04, 0x03, 0x12, 0x04, 0x9b, 0x04, 0x2c, 0x2d,
-    0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x39, 0x12, 0x06, 0x9f, 0x04, 0x00, 0xa3, 0x04, 0x01, 0x0a, 0x0b,
-    0x0a, 0x03, 0x04, 0x39, 0x01, 0x12, 0x04, 0x9f, 0x04, 0x08, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
-    0x39, 0x02, 0x00, 0x12, 0x04, 0xa0, 0x04, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02,
-    0x00, 0x05, 0x12, 0x04, 0xa0, 0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02, 0x00,
-    0x01, 0x12, 0x04, 0xa0, 0x04, 0x09, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02, 0x00, 0x03,
-    0x12, 0x04, 0xa0, 0x04, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x39, 0x02, 0x01, 0x12, 0x04,
-    0xa1, 0x04, 0x02, 0x2d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02, 0x01, 0x04, 0x12, 0x04, 0xa1,
-    0x04, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02, 0x01, 0x06, 0x12, 0x04, 0xa1, 0x04,
-    0x0b, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa1, 0x04, 0x19,
-    0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa1, 0x04, 0x2b, 0x2c,
-    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x39, 0x02, 0x02, 0x12, 0x04, 0xa2, 0x04, 0x02, 0x32, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x39, 0x02, 0x02, 0x04, 0x12, 0x04, 0xa2, 0x04, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x39, 0x02, 0x02, 0x06, 0x12, 0x04, 0xa2, 0x04, 0x0b, 0x1a, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x39, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa2, 0x04, 0x1b, 0x2d, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
-    0x39, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa2, 0x04, 0x30, 0x31, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x3a,
-    0x12, 0x06, 0xa5, 0x04, 0x00, 0xa8, 0x04, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x3a, 0x01, 0x12,
-    0x04, 0xa5, 0x04, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x3a, 0x02, 0x00, 0x12, 0x04, 0xa6,
-    0x04, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3a, 0x02, 0x00, 0x05, 0x12, 0x04, 0xa6, 0x04,
-    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3a, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa6, 0x04, 0x09,
-    0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3a, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa6, 0x04, 0x1a, 0x1b,
-    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x3a, 0x02, 0x01, 0x12, 0x04, 0xa7, 0x04, 0x02, 0x19, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x3a, 0x02, 0x01, 0x05, 0x12, 0x04, 0xa7, 0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x3a, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa7, 0x04, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05,
-    0x04, 0x3a, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa7, 0x04, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
-    0x3b, 0x12, 0x06, 0xaa, 0x04, 0x00, 0xad, 0x04, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x3b, 0x01,
-    0x12, 0x04, 0xaa, 0x04, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x3b, 0x02, 0x00, 0x12, 0x04,
-    0xab, 0x04, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3b, 0x02, 0x00, 0x05, 0x12, 0x04, 0xab,
-    0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3b, 0x02, 0x00, 0x01, 0x12, 0x04, 0xab, 0x04,
-    0x09, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3b, 0x02, 0x00, 0x03, 0x12, 0x04, 0xab, 0x04, 0x15,
-    0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x3b, 0x02, 0x01, 0x12, 0x04, 0xac, 0x04, 0x02, 0x19, 0x0a,
-    0x0d, 0x0a, 0x05, 0x04, 0x3b, 0x02, 0x01, 0x05, 0x12, 0x04, 0xac, 0x04, 0x02, 0x08, 0x0a, 0x0d,
-    0x0a, 0x05, 0x04, 0x3b, 0x02, 0x01, 0x01, 0x12, 0x04, 0xac, 0x04, 0x09, 0x14, 0x0a, 0x0d, 0x0a,
-    0x05, 0x04, 0x3b, 0x02, 0x01, 0x03, 0x12, 0x04, 0xac, 0x04, 0x17, 0x18, 0x62, 0x06, 0x70, 0x72,
-    0x6f, 0x74, 0x6f, 0x33,
+    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x60, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x61, 0x72, 0x65, 0x20,
+    0x66, 0x6f, 0x6c, 0x6c, 0x6f, 0x77, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x7a, 0x65, 0x72, 0x6f,
+    0x20, 0x6f, 0x72, 0x20, 0x6d, 0x6f, 0x72, 0x65, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x73, 0x2e, 0x0a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x78, 0x74,
+    0x20, 0x60, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x54,
+    0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x60, 0x20, 0x64, 0x65, 0x6e, 0x6f,
+    0x74, 0x65, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x6e, 0x64, 0x20, 0x6f, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x63, 0x75, 0x72, 0x72, 0x65, 0x6e, 0x74, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x78, 0x74, 0x20, 0x6f, 0x6e, 0x65, 0x2e,
+    0x0a, 0x0a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x60, 0x68, 0x65,
+    0x69, 0x67, 0x68, 0x74, 0x60, 0x20, 0x69, 0x73, 0x20, 0x61, 0x20, 0x73, 0x74, 0x72, 0x69, 0x63,
+    0x74, 0x6c, 0x79, 0x20, 0x6d, 0x6f, 0x6e, 0x6f, 0x74, 0x6f, 0x6e, 0x69, 0x63, 0x61, 0x6c, 0x6c,
+    0x79, 0x20, 0x69, 0x6e, 0x63, 0x72, 0x65, 0x61, 0x73, 0x69, 0x6e, 0x67, 0x20, 0x63, 0x6f, 0x75,
+    0x6e, 0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72,
+    0x20, 0x6f, 0x66, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x73, 0x2c, 0x0a, 0x20, 0x61, 0x6e, 0x64,
+    0x20, 0x74, 0x68, 0x65, 0x72, 0x65, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x6e, 0x65, 0x76, 0x65,
+    0x72, 0x20, 0x62, 0x65, 0x20, 0x61, 0x20, 0x67, 0x61, 0x70, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x73, 0x2e, 0x20, 0x49, 0x74, 0x20, 0x69, 0x73,
+    0x20, 0x61, 0x6c, 0x73, 0x6f, 0x20, 0x61, 0x20, 0x75, 0x6e, 0x69, 0x71, 0x75, 0x65, 0x20, 0x69,
+    0x64, 0x65, 0x6e, 0x74, 0x69, 0x66, 0x69, 0x65, 0x72, 0x3a, 0x20, 0x74, 0x68, 0x65, 0x72, 0x65,
+    0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x6e, 0x65, 0x76, 0x65, 0x72, 0x20, 0x62, 0x65, 0x20, 0x74,
+    0x77, 0x6f, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x73, 0x20, 0x77, 0x69, 0x74, 0x68, 0x0a, 0x20,
+    0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x60, 0x68, 0x65, 0x69, 0x67, 0x68, 0x74,
+    0x60, 0x2e, 0x0a, 0x0a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x47, 0x65, 0x6e, 0x65, 0x73, 0x69, 0x73,
+    0x20, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x28, 0x76, 0x65,
+    0x72, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x30, 0x29, 0x20, 0x69, 0x73, 0x20, 0x63, 0x6f, 0x6e, 0x74,
+    0x61, 0x69, 0x6e, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65,
+    0x20, 0x66, 0x69, 0x72, 0x73, 0x74, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x2c, 0x20, 0x77, 0x68,
+    0x69, 0x63, 0x68, 0x20, 0x68, 0x61, 0x73, 0x20, 0x61, 0x20, 0x68, 0x65, 0x69, 0x67, 0x68, 0x74,
+    0x20, 0x6f, 0x66, 0x20, 0x60, 0x30, 0x60, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12,
+    0x03, 0x12, 0x08, 0x0d, 0x0a, 0xde, 0x01, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x15,
+    0x02, 0x2f, 0x1a, 0xd0, 0x01, 0x20, 0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20,
+    0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x74,
+    0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x60, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x54, 0x72,
+    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x60, 0x20, 0x28, 0x6f, 0x72, 0x20, 0x60,
+    0x47, 0x65, 0x6e, 0x65, 0x73, 0x69, 0x73, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69,
+    0x6f, 0x6e, 0x60, 0x20, 0x66, 0x6f, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x67, 0x65, 0x6e, 0x65,
+    0x73, 0x69, 0x73, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x29, 0x0a, 0x20, 0x61, 0x6e, 0x64, 0x20,
+    0x65, 0x76, 0x65, 0x72, 0x79, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x60, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61,
+    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x60, 0x20, 0x77, 0x69, 0x6c, 0x6c, 0x20, 0x68, 0x61, 0x76,
+    0x65, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x61, 0x6d, 0x65, 0x20, 0x60, 0x74, 0x69, 0x6d, 0x65,
+    0x73, 0x74, 0x61, 0x6d, 0x70, 0x60, 0x20, 0x61, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c,
+    0x6f, 0x63, 0x6b, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06, 0x12, 0x03,
+    0x15, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x15, 0x21,
+    0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x15, 0x2d, 0x2e, 0x0a,
+    0x88, 0x01, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x01, 0x12, 0x03, 0x18, 0x02, 0x29, 0x1a, 0x7b, 0x20,
+    0x48, 0x65, 0x69, 0x67, 0x68, 0x74, 0x20, 0x72, 0x65, 0x70, 0x72, 0x65, 0x73, 0x65, 0x6e, 0x74,
+    0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x6e, 0x75, 0x6d, 0x62,
+    0x65, 0x72, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x75, 0x6c, 0x74, 0x69, 0x6d, 0x61, 0x74, 0x65, 0x6c,
+    0x79, 0x2c, 0x20, 0x69, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x75, 0x6e, 0x74, 0x20,
+    0x6f, 0x66, 0x20, 0x60, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74,
+    0x61, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x60, 0x20, 0x74, 0x68,
+    0x61, 0x74, 0x20, 0x68, 0x61, 0x70, 0x70, 0x65, 0x6e, 0x65, 0x64, 0x20, 0x6f, 0x6e, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00,
+    0x02, 0x01, 0x05, 0x12, 0x03, 0x18, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x18, 0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x18, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x01, 0x08, 0x12, 0x03, 0x18,
+    0x14, 0x28, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x00, 0x02, 0x01, 0x08, 0x06, 0x12, 0x03, 0x18, 0x15,
+    0x27, 0x0a, 0x87, 0x02, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x02, 0x12, 0x03, 0x1c, 0x02, 0x28, 0x1a,
+    0xf9, 0x01, 0x20, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20,
+    0x68, 0x6f, 0x6c, 0x64, 0x73, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61,
+    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x68, 0x61, 0x70, 0x70,
+    0x65, 0x6e, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x42, 0x6c, 0x6f, 0x63,
+    0x6b, 0x2c, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x69, 0x73, 0x20, 0x74, 0x72, 0x61, 0x6e,
+    0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x68, 0x61,
+    0x70, 0x70, 0x65, 0x6e, 0x65, 0x64, 0x20, 0x73, 0x74, 0x61, 0x72, 0x74, 0x69, 0x6e, 0x67, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x20, 0x28, 0x61, 0x6e, 0x64, 0x20, 0x69, 0x6e, 0x63, 0x6c, 0x75, 0x64,
+    0x69, 0x6e, 0x67, 0x29, 0x0a, 0x20, 0x61, 0x20, 0x60, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65,
+    0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f,
+    0x6e, 0x60, 0x2c, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x65, 0x76, 0x65, 0x72, 0x79, 0x20, 0x6f, 0x74,
+    0x68, 0x65, 0x72, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20,
+    0x75, 0x70, 0x20, 0x74, 0x6f, 0x20, 0x28, 0x62, 0x75, 0x74, 0x20, 0x65, 0x78, 0x63, 0x6c, 0x75,
+    0x64, 0x69, 0x6e, 0x67, 0x29, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6e, 0x65, 0x78, 0x74, 0x20, 0x60,
+    0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x4d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61, 0x54, 0x72, 0x61,
+    0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x60, 0x2e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x02, 0x04, 0x12, 0x03, 0x1c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x02, 0x06, 0x12, 0x03, 0x1c, 0x0b, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x01,
+    0x12, 0x03, 0x1c, 0x17, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x02, 0x03, 0x12, 0x03,
+    0x1c, 0x26, 0x27, 0x0a, 0x99, 0x01, 0x0a, 0x04, 0x04, 0x00, 0x02, 0x03, 0x12, 0x03, 0x1f, 0x02,
+    0x16, 0x1a, 0x8b, 0x01, 0x20, 0x43, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x49, 0x44, 0x20, 0x69, 0x6e,
+    0x66, 0x6f, 0x72, 0x6d, 0x73, 0x20, 0x75, 0x73, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x63,
+    0x68, 0x61, 0x69, 0x6e, 0x20, 0x77, 0x65, 0x27, 0x72, 0x65, 0x20, 0x74, 0x72, 0x79, 0x69, 0x6e,
+    0x67, 0x20, 0x74, 0x6f, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x2c, 0x20, 0x74, 0x68, 0x69, 0x73,
+    0x20, 0x69, 0x73, 0x20, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x61, 0x6e, 0x74, 0x20, 0x74, 0x6f,
+    0x20, 0x65, 0x6e, 0x73, 0x75, 0x72, 0x65, 0x20, 0x74, 0x68, 0x61, 0x74, 0x20, 0x77, 0x65, 0x27,
+    0x72, 0x65, 0x20, 0x6e, 0x6f, 0x74, 0x20, 0x6d, 0x69, 0x78, 0x69, 0x6e, 0x67, 0x20, 0x63, 0x68,
+    0x61, 0x69, 0x6e, 0x73, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x61, 0x20, 0x73, 0x69,
+    0x6e, 0x67, 0x6c, 0x65, 0x20, 0x70, 0x69, 0x70, 0x65, 0x6c, 0x69, 0x6e, 0x65, 0x2e, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x03, 0x05, 0x12, 0x03, 0x1f, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x1f, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x03, 0x03, 0x12, 0x03, 0x1f, 0x14, 0x15, 0x0a, 0x8c, 0x04, 0x0a, 0x02, 0x04, 0x01,
+    0x12, 0x04, 0x27, 0x00, 0x42, 0x01, 0x1a, 0xff, 0x03, 0x20, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61,
+    0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x61, 0x73, 0x20, 0x69, 0x74, 0x20, 0x68, 0x61, 0x70, 0x70,
+    0x65, 0x6e, 0x65, 0x64, 0x20, 0x6f, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x69,
+    0x6e, 0x2c, 0x20, 0x74, 0x68, 0x65, 0x72, 0x65, 0x20, 0x61, 0x72, 0x65, 0x20, 0x34, 0x20, 0x74,
+    0x79, 0x70, 0x65, 0x73, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
+    0x69, 0x6f, 0x6e, 0x73, 0x3a, 0x0a, 0x20, 0x2d, 0x20, 0x55, 0x73, 0x65, 0x72, 0x20, 0x54, 0x72,
+    0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x3a, 0x20, 0x61, 0x20, 0x75, 0x73, 0x65,
+    0x72, 0x20, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x74, 0x65, 0x64, 0x20, 0x74, 0x72, 0x61, 0x6e,
+    0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x74, 0x6f, 0x20, 0x69, 0x6e, 0x74, 0x65, 0x72,
+    0x61, 0x63, 0x74, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61,
+    0x69, 0x6e, 0x0a, 0x20, 0x2d, 0x20, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x4d, 0x65, 0x74, 0x61,
+    0x64, 0x61, 0x74, 0x61, 0x20, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e,
+    0x3a, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x20, 0x67,
+    0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x74, 0x6f, 0x20, 0x67, 0x72, 0x6f, 0x75, 0x70, 0x20, 0x74,
+    0x6f, 0x67, 0x65, 0x74, 0x68, 0x65, 0x72, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
+    0x69, 0x6f, 0x6e, 0x73, 0x20, 0x66, 0x6f, 0x72, 0x6d, 0x69, 0x6e, 0x67, 0x20, 0x61, 0x20, 0x22,
+    0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x22, 0x0a, 0x20, 0x2d, 0x20, 0x53, 0x74, 0x61, 0x74, 0x65, 0x20,
+    0x43, 0x68, 0x65, 0x63, 0x6b, 0x70, 0x6f, 0x69, 0x6e, 0x74, 0x20, 0x54, 0x72, 0x61, 0x6e, 0x73,
+    0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x3a, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74,
+    0x69, 0x6f, 0x6e, 0x73, 0x20, 0x67, 0x65, 0x6e, 0x65, 0x72, 0x61, 0x74, 0x65, 0x64, 0x20, 0x62,
+    0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x20, 0x73, 0x6f, 0x20, 0x77,
+    0x68, 0x65, 0x6e, 0x20, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x61, 0x74, 0x6f, 0x72, 0x20, 0x61, 0x67,
+    0x72, 0x65, 0x65, 0x64, 0x20, 0x6f, 0x6e, 0x20, 0x61, 0x20, 0x70, 0x61, 0x72, 0x74, 0x69, 0x63,
+    0x75, 0x6c, 0x61, 0x72, 0x20, 0x67, 0x6c, 0x6f, 0x62, 0x61, 0x6c, 0x20, 0x73, 0x74, 0x61, 0x74,
+    0x65, 0x0a, 0x20, 0x2d, 0x20, 0x47, 0x65, 0x6e, 0x65, 0x73, 0x69, 0x73, 0x20, 0x54, 0x72, 0x61,
+    0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x3a, 0x20, 0x74, 0x68, 0x65, 0x20, 0x66, 0x69,
+    0x72, 0x73, 0x74, 0x20, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20,
+    0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x68, 0x61, 0x69, 0x6e, 0x2c, 0x20, 0x77, 0x69,
+    0x74, 0x68, 0x20, 0x61, 0x6c, 0x6c, 0x20, 0x63, 0x6f, 0x72, 0x65, 0x20, 0x63, 0x6f, 0x6e, 0x74,
+    0x72, 0x61, 0x63, 0x74, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x76, 0x61, 0x6c, 0x69, 0x64, 0x61, 0x74,
+    0x6f, 0x72, 0x20, 0x69, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x62,
+    0x61, 0x6b, 0x65, 0x64, 0x20, 0x69, 0x6e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12,
+    0x03, 0x27, 0x08, 0x13, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x00, 0x12, 0x03, 0x28, 0x02,
+    0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x06, 0x12, 0x03, 0x28, 0x02, 0x20, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x28, 0x21, 0x2a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x28, 0x2d, 0x2e, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x01, 0x02, 0x01, 0x12, 0x03, 0x29, 0x02, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01,
+    0x05, 0x12, 0x03, 0x29, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x29, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x29,
+    0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x08, 0x12, 0x03, 0x29, 0x15, 0x29,
+    0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x02, 0x01, 0x08, 0x06, 0x12, 0x03, 0x29, 0x16, 0x28, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12, 0x03, 0x2a, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x02, 0x06, 0x12, 0x03, 0x2a, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x02, 0x01, 0x12, 0x03, 0x2a, 0x12, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02,
+    0x03, 0x12, 0x03, 0x2a, 0x19, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x03,
+    0x2b, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x05, 0x12, 0x03, 0x2b, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01, 0x12, 0x03, 0x2b, 0x09, 0x0e, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x03, 0x2b, 0x11, 0x12, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x03, 0x08, 0x12, 0x03, 0x2b, 0x13, 0x27, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
+    0x01, 0x02, 0x03, 0x08, 0x06, 0x12, 0x03, 0x2b, 0x14, 0x26, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x04, 0x12, 0x03, 0x2c, 0x02, 0x2f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x05,
+    0x12, 0x03, 0x2c, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03,
+    0x2c, 0x09, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x03, 0x12, 0x03, 0x2c, 0x18,
+    0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x08, 0x12, 0x03, 0x2c, 0x1a, 0x2e, 0x0a,
+    0x0d, 0x0a, 0x06, 0x04, 0x01, 0x02, 0x04, 0x08, 0x06, 0x12, 0x03, 0x2c, 0x1b, 0x2d, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x01, 0x04, 0x00, 0x12, 0x04, 0x2e, 0x02, 0x35, 0x03, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x04, 0x00, 0x01, 0x12, 0x03, 0x2e, 0x07, 0x16, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01,
+    0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x2f, 0x04, 0x25, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04,
+    0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2f, 0x04, 0x20, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04,
+    0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x2f, 0x23, 0x24, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04,
+    0x00, 0x02, 0x01, 0x12, 0x03, 0x30, 0x04, 0x21, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00,
+    0x02, 0x01, 0x01, 0x12, 0x03, 0x30, 0x04, 0x1c, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00,
+    0x02, 0x01, 0x02, 0x12, 0x03, 0x30, 0x1f, 0x20, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00,
+    0x02, 0x02, 0x12, 0x03, 0x31, 0x04, 0x28, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02,
+    0x02, 0x01, 0x12, 0x03, 0x31, 0x04, 0x23, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02,
+    0x02, 0x02, 0x12, 0x03, 0x31, 0x26, 0x27, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02,
+    0x03, 0x12, 0x03, 0x32, 0x04, 0x2a, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x03,
+    0x01, 0x12, 0x03, 0x32, 0x04, 0x25, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x03,
+    0x02, 0x12, 0x03, 0x32, 0x28, 0x29, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x04,
+    0x12, 0x03, 0x33, 0x04, 0x1e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x04, 0x01,
+    0x12, 0x03, 0x33, 0x04, 0x19, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x04, 0x02,
+    0x12, 0x03, 0x33, 0x1c, 0x1d, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x01, 0x04, 0x00, 0x02, 0x05, 0x12,
+    0x03, 0x34, 0x04, 0x24, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12,
+    0x03, 0x34, 0x04, 0x1e, 0x0a, 0x0e, 0x0a, 0x07, 0x04, 0x01, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12,
+    0x03, 0x34, 0x21, 0x23, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x05, 0x12, 0x03, 0x37, 0x02,
+    0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x06, 0x12, 0x03, 0x37, 0x02, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x01, 0x12, 0x03, 0x37, 0x12, 0x16, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x05, 0x03, 0x12, 0x03, 0x37, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x01, 0x08, 0x00, 0x12, 0x04, 0x39, 0x02, 0x3f, 0x03, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x08,
+    0x00, 0x01, 0x12, 0x03, 0x39, 0x08, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x06, 0x12,
+    0x03, 0x3a, 0x04, 0x30, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x06, 0x12, 0x03, 0x3a,
+    0x04, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x01, 0x12, 0x03, 0x3a, 0x1d, 0x2b,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x03, 0x12, 0x03, 0x3a, 0x2e, 0x2f, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x01, 0x02, 0x07, 0x12, 0x03, 0x3b, 0x04, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x07, 0x06, 0x12, 0x03, 0x3b, 0x04, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x07, 0x01, 0x12, 0x03, 0x3b, 0x17, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x03,
+    0x12, 0x03, 0x3b, 0x21, 0x22, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x08, 0x12, 0x03, 0x3c,
+    0x04, 0x34, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x06, 0x12, 0x03, 0x3c, 0x04, 0x1e,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x01, 0x12, 0x03, 0x3c, 0x1f, 0x2f, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x03, 0x12, 0x03, 0x3c, 0x32, 0x33, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x01, 0x02, 0x09, 0x12, 0x03, 0x3d, 0x04, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x09, 0x06, 0x12, 0x03, 0x3d, 0x04, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x01,
+    0x12, 0x03, 0x3d, 0x14, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x03, 0x12, 0x03,
+    0x3d, 0x1b, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0a, 0x12, 0x03, 0x3e, 0x04, 0x28,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x06, 0x12, 0x03, 0x3e, 0x04, 0x18, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x01, 0x12, 0x03, 0x3e, 0x19, 0x22, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x0a, 0x03, 0x12, 0x03, 0x3e, 0x25, 0x27, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01,
+    0x02, 0x0b, 0x12, 0x03, 0x41, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0b, 0x06,
+    0x12, 0x03, 0x41, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0b, 0x01, 0x12, 0x03,
+    0x41, 0x16, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0b, 0x03, 0x12, 0x03, 0x41, 0x22,
+    0x24, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x44, 0x00, 0x4b, 0x01, 0x0a, 0x0a, 0x0a,
+    0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x44, 0x08, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02,
+    0x00, 0x12, 0x03, 0x45, 0x02, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12,
+    0x03, 0x45, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x45,
+    0x09, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x45, 0x0e, 0x0f,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x01, 0x12, 0x03, 0x46, 0x02, 0x28, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12, 0x03, 0x46, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x46, 0x09, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x01, 0x03, 0x12, 0x03, 0x46, 0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x08,
+    0x12, 0x03, 0x46, 0x13, 0x27, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x02, 0x02, 0x01, 0x08, 0x06, 0x12,
+    0x03, 0x46, 0x14, 0x26, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x02, 0x12, 0x03, 0x47, 0x02,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x04, 0x12, 0x03, 0x47, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x02, 0x06, 0x12, 0x03, 0x47, 0x0b, 0x10, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x47, 0x11, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x02, 0x03, 0x12, 0x03, 0x47, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02,
+    0x03, 0x12, 0x03, 0x48, 0x02, 0x28, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x05, 0x12,
+    0x03, 0x48, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x48,
+    0x08, 0x23, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x03, 0x12, 0x03, 0x48, 0x26, 0x27,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x04, 0x12, 0x03, 0x49, 0x02, 0x16, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x04, 0x05, 0x12, 0x03, 0x49, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x04, 0x01, 0x12, 0x03, 0x49, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x04, 0x03, 0x12, 0x03, 0x49, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x05, 0x12,
+    0x03, 0x4a, 0x02, 0x2e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x04, 0x12, 0x03, 0x4a,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x05, 0x12, 0x03, 0x4a, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x01, 0x12, 0x03, 0x4a, 0x12, 0x29, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x02, 0x02, 0x05, 0x03, 0x12, 0x03, 0x4a, 0x2c, 0x2d, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x03, 0x12, 0x04, 0x4d, 0x00, 0x50, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x03, 0x01, 0x12,
+    0x03, 0x4d, 0x08, 0x1a, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x03, 0x02, 0x00, 0x12, 0x03, 0x4e, 0x02,
+    0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x06, 0x12, 0x03, 0x4e, 0x02, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x4e, 0x0b, 0x12, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x03, 0x02, 0x00, 0x03, 0x12, 0x03, 0x4e, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x03, 0x02, 0x01, 0x12, 0x03, 0x4f, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01,
+    0x04, 0x12, 0x03, 0x4f, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x06, 0x12,
+    0x03, 0x4f, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4f,
+    0x11, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x03, 0x02, 0x01, 0x03, 0x12, 0x03, 0x4f, 0x1a, 0x1b,
+    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x04, 0x12, 0x04, 0x52, 0x00, 0x53, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x04, 0x01, 0x12, 0x03, 0x52, 0x08, 0x22, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x05, 0x12, 0x04,
+    0x55, 0x00, 0x56, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x05, 0x01, 0x12, 0x03, 0x55, 0x08, 0x1c,
+    0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x06, 0x12, 0x04, 0x58, 0x00, 0x5b, 0x01, 0x0a, 0x0a, 0x0a, 0x03,
+    0x04, 0x06, 0x01, 0x12, 0x03, 0x58, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x00,
+    0x12, 0x03, 0x59, 0x02, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x06, 0x12, 0x03,
+    0x59, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x01, 0x12, 0x03, 0x59, 0x19,
+    0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x00, 0x03, 0x12, 0x03, 0x59, 0x23, 0x24, 0x0a,
+    0x0b, 0x0a, 0x04, 0x04, 0x06, 0x02, 0x01, 0x12, 0x03, 0x5a, 0x02, 0x1c, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x06, 0x02, 0x01, 0x04, 0x12, 0x03, 0x5a, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06,
+    0x02, 0x01, 0x06, 0x12, 0x03, 0x5a, 0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01,
+    0x01, 0x12, 0x03, 0x5a, 0x11, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x06, 0x02, 0x01, 0x03, 0x12,
+    0x03, 0x5a, 0x1a, 0x1b, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x07, 0x12, 0x04, 0x5d, 0x00, 0x63, 0x01,
+    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x07, 0x01, 0x12, 0x03, 0x5d, 0x08, 0x0d, 0x0a, 0x0b, 0x0a, 0x04,
+    0x04, 0x07, 0x02, 0x00, 0x12, 0x03, 0x5e, 0x02, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x00, 0x06, 0x12, 0x03, 0x5e, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x5e, 0x0b, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x5e, 0x11, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x01, 0x12, 0x03, 0x5f, 0x02, 0x32,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x05, 0x12, 0x03, 0x5f, 0x02, 0x08, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x01, 0x01, 0x12, 0x03, 0x5f, 0x09, 0x18, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x07, 0x02, 0x01, 0x03, 0x12, 0x03, 0x5f, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07,
+    0x02, 0x01, 0x08, 0x12, 0x03, 0x5f, 0x1d, 0x31, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x07, 0x02, 0x01,
+    0x08, 0x06, 0x12, 0x03, 0x5f, 0x1e, 0x30, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x02, 0x12,
+    0x03, 0x60, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x06, 0x12, 0x03, 0x60,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x01, 0x12, 0x03, 0x60, 0x0b, 0x0f,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x02, 0x03, 0x12, 0x03, 0x60, 0x12, 0x13, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x07, 0x02, 0x03, 0x12, 0x03, 0x61, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x07, 0x02, 0x03, 0x05, 0x12, 0x03, 0x61, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02,
+    0x03, 0x01, 0x12, 0x03, 0x61, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x03, 0x03,
+    0x12, 0x03, 0x61, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x07, 0x02, 0x04, 0x12, 0x03, 0x62,
+    0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x05, 0x12, 0x03, 0x62, 0x02, 0x08,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x01, 0x12, 0x03, 0x62, 0x09, 0x0d, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x07, 0x02, 0x04, 0x03, 0x12, 0x03, 0x62, 0x10, 0x11, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x08, 0x12, 0x04, 0x65, 0x00, 0x6f, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x08, 0x01, 0x12,
+    0x03, 0x65, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x00, 0x12, 0x03, 0x66, 0x02,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x05, 0x12, 0x03, 0x66, 0x02, 0x07, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x00, 0x01, 0x12, 0x03, 0x66, 0x08, 0x0c, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x00, 0x03, 0x12, 0x03, 0x66, 0x0f, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x08, 0x02, 0x01, 0x12, 0x03, 0x67, 0x02, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01,
+    0x05, 0x12, 0x03, 0x67, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x01, 0x12,
+    0x03, 0x67, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x01, 0x03, 0x12, 0x03, 0x67,
+    0x1c, 0x1d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x02, 0x12, 0x03, 0x68, 0x02, 0x1c, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x02, 0x05, 0x12, 0x03, 0x68, 0x02, 0x07, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x02, 0x01, 0x12, 0x03, 0x68, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x02, 0x03, 0x12, 0x03, 0x68, 0x1a, 0x1b, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02,
+    0x03, 0x12, 0x03, 0x69, 0x02, 0x2b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x04, 0x12,
+    0x03, 0x69, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x05, 0x12, 0x03, 0x69,
+    0x0b, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x01, 0x12, 0x03, 0x69, 0x11, 0x26,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x03, 0x03, 0x12, 0x03, 0x69, 0x29, 0x2a, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x08, 0x02, 0x04, 0x12, 0x03, 0x6a, 0x02, 0x2b, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x04, 0x05, 0x12, 0x03, 0x6a, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x04, 0x01, 0x12, 0x03, 0x6a, 0x09, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x03,
+    0x12, 0x03, 0x6a, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x04, 0x08, 0x12, 0x03,
+    0x6a, 0x16, 0x2a, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x08, 0x02, 0x04, 0x08, 0x06, 0x12, 0x03, 0x6a,
+    0x17, 0x29, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x05, 0x12, 0x03, 0x6b, 0x02, 0x13, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x05, 0x05, 0x12, 0x03, 0x6b, 0x02, 0x06, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x05, 0x01, 0x12, 0x03, 0x6b, 0x07, 0x0e, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x05, 0x03, 0x12, 0x03, 0x6b, 0x11, 0x12, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02,
+    0x06, 0x12, 0x03, 0x6c, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x05, 0x12,
+    0x03, 0x6c, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x01, 0x12, 0x03, 0x6c,
+    0x09, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x06, 0x03, 0x12, 0x03, 0x6c, 0x15, 0x16,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x07, 0x12, 0x03, 0x6d, 0x02, 0x22, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x08, 0x02, 0x07, 0x05, 0x12, 0x03, 0x6d, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x08, 0x02, 0x07, 0x01, 0x12, 0x03, 0x6d, 0x08, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02,
+    0x07, 0x03, 0x12, 0x03, 0x6d, 0x20, 0x21, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x08, 0x02, 0x08, 0x12,
+    0x03, 0x6e, 0x02, 0x26, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x08, 0x04, 0x12, 0x03, 0x6e,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x08, 0x06, 0x12, 0x03, 0x6e, 0x0b, 0x19,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x08, 0x02, 0x08, 0x01, 0x12, 0x03, 0x6e, 0x1a, 0x21, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x08, 0x02, 0x08, 0x03, 0x12, 0x03, 0x6e, 0x24, 0x25, 0x0a, 0x0a, 0x0a, 0x02,
+    0x04, 0x09, 0x12, 0x04, 0x71, 0x00, 0x74, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x09, 0x01, 0x12,
+    0x03, 0x71, 0x08, 0x10, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x00, 0x12, 0x03, 0x72, 0x02,
+    0x32, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x05, 0x12, 0x03, 0x72, 0x02, 0x08, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x00, 0x01, 0x12, 0x03, 0x72, 0x09, 0x18, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x09, 0x02, 0x00, 0x03, 0x12, 0x03, 0x72, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x09, 0x02, 0x00, 0x08, 0x12, 0x03, 0x72, 0x1d, 0x31, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x09, 0x02,
+    0x00, 0x08, 0x06, 0x12, 0x03, 0x72, 0x1e, 0x30, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x09, 0x02, 0x01,
+    0x12, 0x03, 0x73, 0x02, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x05, 0x12, 0x03,
+    0x73, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x01, 0x12, 0x03, 0x73, 0x09,
+    0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x09, 0x02, 0x01, 0x03, 0x12, 0x03, 0x73, 0x1b, 0x1c, 0x0a,
+    0x0a, 0x0a, 0x02, 0x04, 0x0a, 0x12, 0x04, 0x76, 0x00, 0x7e, 0x01, 0x0a, 0x0a, 0x0a, 0x03, 0x04,
+    0x0a, 0x01, 0x12, 0x03, 0x76, 0x08, 0x1e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x00, 0x12,
+    0x03, 0x77, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x05, 0x12, 0x03, 0x77,
+    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x01, 0x12, 0x03, 0x77, 0x09, 0x0f,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x00, 0x03, 0x12, 0x03, 0x77, 0x12, 0x13, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x0a, 0x02, 0x01, 0x12, 0x03, 0x78, 0x02, 0x32, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x01, 0x05, 0x12, 0x03, 0x78, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
+    0x01, 0x01, 0x12, 0x03, 0x78, 0x09, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x03,
+    0x12, 0x03, 0x78, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x01, 0x08, 0x12, 0x03,
+    0x78, 0x1d, 0x31, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x02, 0x01, 0x08, 0x06, 0x12, 0x03, 0x78,
+    0x1e, 0x30, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x02, 0x12, 0x03, 0x79, 0x02, 0x31, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x02, 0x05, 0x12, 0x03, 0x79, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x02, 0x01, 0x12, 0x03, 0x79, 0x09, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x0a, 0x02, 0x02, 0x03, 0x12, 0x03, 0x79, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02,
+    0x02, 0x08, 0x12, 0x03, 0x79, 0x1c, 0x30, 0x0a, 0x0d, 0x0a, 0x06, 0x04, 0x0a, 0x02, 0x02, 0x08,
+    0x06, 0x12, 0x03, 0x79, 0x1d, 0x2f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x03, 0x12, 0x03,
+    0x7a, 0x02, 0x31, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x05, 0x12, 0x03, 0x7a, 0x02,
+    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03, 0x7a, 0x09, 0x17, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x03, 0x03, 0x12, 0x03, 0x7a, 0x1a, 0x1b, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x0a, 0x02, 0x03, 0x08, 0x12, 0x03, 0x7a, 0x1c, 0x30, 0x0a, 0x0d, 0x0a, 0x06, 0x04,
+    0x0a, 0x02, 0x03, 0x08, 0x06, 0x12, 0x03, 0x7a, 0x1d, 0x2f, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a,
+    0x02, 0x04, 0x12, 0x03, 0x7b, 0x02, 0x3f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x06,
+    0x12, 0x03, 0x7b, 0x02, 0x20, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x03,
+    0x7b, 0x21, 0x3a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x04, 0x03, 0x12, 0x03, 0x7b, 0x3d,
+    0x3e, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x05, 0x12, 0x03, 0x7c, 0x02, 0x21, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x0a, 0x02, 0x05, 0x06, 0x12, 0x03, 0x7c, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x03, 0x7c, 0x15, 0x1c, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a,
+    0x02, 0x05, 0x03, 0x12, 0x03, 0x7c, 0x1f, 0x20, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x0a, 0x02, 0x06,
+    0x12, 0x03, 0x7d, 0x02, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x06, 0x12, 0x03,
+    0x7d, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x01, 0x12, 0x03, 0x7d, 0x0c,
+    0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x0a, 0x02, 0x06, 0x03, 0x12, 0x03, 0x7d, 0x18, 0x19, 0x0a,
+    0x0c, 0x0a, 0x02, 0x04, 0x0b, 0x12, 0x06, 0x80, 0x01, 0x00, 0x8d, 0x01, 0x01, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x0b, 0x01, 0x12, 0x04, 0x80, 0x01, 0x08, 0x10, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x0b,
+    0x04, 0x00, 0x12, 0x06, 0x82, 0x01, 0x02, 0x86, 0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b,
+    0x04, 0x00, 0x01, 0x12, 0x04, 0x82, 0x01, 0x07, 0x13, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0b, 0x04,
+    0x00, 0x02, 0x00, 0x12, 0x04, 0x83, 0x01, 0x04, 0x23, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0b, 0x04,
+    0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x83, 0x01, 0x04, 0x1e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0b,
+    0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0x83, 0x01, 0x21, 0x22, 0x0a, 0x0e, 0x0a, 0x06, 0x04,
+    0x0b, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0x84, 0x01, 0x04, 0x28, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x0b, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x84, 0x01, 0x04, 0x23, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x0b, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x84, 0x01, 0x26, 0x27, 0x0a, 0x0e, 0x0a,
+    0x06, 0x04, 0x0b, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0x85, 0x01, 0x04, 0x28, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x0b, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x85, 0x01, 0x04, 0x23, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x0b, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x85, 0x01, 0x26, 0x27, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x00, 0x12, 0x04, 0x88, 0x01, 0x02, 0x22, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0b, 0x02, 0x00, 0x06, 0x12, 0x04, 0x88, 0x01, 0x02, 0x0e, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0b, 0x02, 0x00, 0x01, 0x12, 0x04, 0x88, 0x01, 0x0f, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0b, 0x02, 0x00, 0x03, 0x12, 0x04, 0x88, 0x01, 0x20, 0x21, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x0b,
+    0x08, 0x00, 0x12, 0x06, 0x89, 0x01, 0x02, 0x8c, 0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b,
+    0x08, 0x00, 0x01, 0x12, 0x04, 0x89, 0x01, 0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0b, 0x02,
+    0x01, 0x12, 0x04, 0x8a, 0x01, 0x04, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x06,
+    0x12, 0x04, 0x8a, 0x01, 0x04, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0x8a, 0x01, 0x13, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0x8a, 0x01, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0b, 0x02, 0x02, 0x12, 0x04, 0x8b, 0x01,
+    0x04, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x06, 0x12, 0x04, 0x8b, 0x01, 0x04,
+    0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x01, 0x12, 0x04, 0x8b, 0x01, 0x13, 0x23,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0b, 0x02, 0x02, 0x03, 0x12, 0x04, 0x8b, 0x01, 0x26, 0x27, 0x0a,
+    0x0c, 0x0a, 0x02, 0x04, 0x0c, 0x12, 0x06, 0x8f, 0x01, 0x00, 0x92, 0x01, 0x01, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x0c, 0x01, 0x12, 0x04, 0x8f, 0x01, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0c,
+    0x02, 0x00, 0x12, 0x04, 0x90, 0x01, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00,
+    0x05, 0x12, 0x04, 0x90, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0x90, 0x01, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0x90, 0x01, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0c, 0x02, 0x01, 0x12, 0x04, 0x91,
+    0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x06, 0x12, 0x04, 0x91, 0x01,
+    0x02, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x01, 0x12, 0x04, 0x91, 0x01, 0x10,
+    0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0c, 0x02, 0x01, 0x03, 0x12, 0x04, 0x91, 0x01, 0x19, 0x1a,
+    0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0d, 0x12, 0x06, 0x94, 0x01, 0x00, 0x97, 0x01, 0x01, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x0d, 0x01, 0x12, 0x04, 0x94, 0x01, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x0d, 0x02, 0x00, 0x12, 0x04, 0x95, 0x01, 0x02, 0x2f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02,
+    0x00, 0x04, 0x12, 0x04, 0x95, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00,
+    0x06, 0x12, 0x04, 0x95, 0x01, 0x0b, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0x95, 0x01, 0x1a, 0x2a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0x95, 0x01, 0x2d, 0x2e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0d, 0x02, 0x01, 0x12, 0x04, 0x96,
+    0x01, 0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x04, 0x12, 0x04, 0x96, 0x01,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x06, 0x12, 0x04, 0x96, 0x01, 0x0b,
+    0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x01, 0x12, 0x04, 0x96, 0x01, 0x11, 0x17,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0d, 0x02, 0x01, 0x03, 0x12, 0x04, 0x96, 0x01, 0x1a, 0x1b, 0x0a,
+    0x0c, 0x0a, 0x02, 0x04, 0x0e, 0x12, 0x06, 0x99, 0x01, 0x00, 0xaf, 0x01, 0x01, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x0e, 0x01, 0x12, 0x04, 0x99, 0x01, 0x08, 0x16, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x0e,
+    0x04, 0x00, 0x12, 0x06, 0x9b, 0x01, 0x02, 0xa3, 0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x04, 0x00, 0x01, 0x12, 0x04, 0x9b, 0x01, 0x07, 0x0b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0e, 0x04,
+    0x00, 0x02, 0x00, 0x12, 0x04, 0x9c, 0x01, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04,
+    0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x9c, 0x01, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e,
+    0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0x9c, 0x01, 0x17, 0x18, 0x0a, 0x0e, 0x0a, 0x06, 0x04,
+    0x0e, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0x9d, 0x01, 0x04, 0x1b, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x0e, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x9d, 0x01, 0x04, 0x16, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x0e, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x9d, 0x01, 0x19, 0x1a, 0x0a, 0x0e, 0x0a,
+    0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0x9e, 0x01, 0x04, 0x1d, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x9e, 0x01, 0x04, 0x18, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x9e, 0x01, 0x1b, 0x1c, 0x0a,
+    0x0e, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0x9f, 0x01, 0x04, 0x1f, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0x9f, 0x01, 0x04, 0x1a,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0x9f, 0x01, 0x1d,
+    0x1e, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x04, 0x12, 0x04, 0xa0, 0x01, 0x04,
+    0x1a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0xa0, 0x01,
+    0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0xa0,
+    0x01, 0x18, 0x19, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x05, 0x12, 0x04, 0xa1,
+    0x01, 0x04, 0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x05, 0x01, 0x12, 0x04,
+    0xa1, 0x01, 0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x05, 0x02, 0x12,
+    0x04, 0xa1, 0x01, 0x1a, 0x1b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x06, 0x12,
+    0x04, 0xa2, 0x01, 0x04, 0x1e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x06, 0x01,
+    0x12, 0x04, 0xa2, 0x01, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x0e, 0x04, 0x00, 0x02, 0x06,
+    0x02, 0x12, 0x04, 0xa2, 0x01, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x00, 0x12,
+    0x04, 0xa5, 0x01, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x06, 0x12, 0x04,
+    0xa5, 0x01, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa5,
+    0x01, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa5, 0x01,
+    0x0e, 0x0f, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x0e, 0x08, 0x00, 0x12, 0x06, 0xa7, 0x01, 0x02, 0xae,
+    0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x08, 0x00, 0x01, 0x12, 0x04, 0xa7, 0x01, 0x08,
+    0x0e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x01, 0x12, 0x04, 0xa8, 0x01, 0x04, 0x23, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x06, 0x12, 0x04, 0xa8, 0x01, 0x04, 0x10, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa8, 0x01, 0x11, 0x1e, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x0e, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa8, 0x01, 0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x0e, 0x02, 0x02, 0x12, 0x04, 0xa9, 0x01, 0x04, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x02, 0x06, 0x12, 0x04, 0xa9, 0x01, 0x04, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0xa9, 0x01, 0x13, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0xa9, 0x01, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x03, 0x12,
+    0x04, 0xaa, 0x01, 0x04, 0x2a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x06, 0x12, 0x04,
+    0xaa, 0x01, 0x04, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x01, 0x12, 0x04, 0xaa,
+    0x01, 0x14, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x03, 0x03, 0x12, 0x04, 0xaa, 0x01,
+    0x28, 0x29, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x04, 0x12, 0x04, 0xab, 0x01, 0x04, 0x21,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x06, 0x12, 0x04, 0xab, 0x01, 0x04, 0x0f, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x01, 0x12, 0x04, 0xab, 0x01, 0x10, 0x1c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x0e, 0x02, 0x04, 0x03, 0x12, 0x04, 0xab, 0x01, 0x1f, 0x20, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x0e, 0x02, 0x05, 0x12, 0x04, 0xac, 0x01, 0x04, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0e, 0x02, 0x05, 0x06, 0x12, 0x04, 0xac, 0x01, 0x04, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e,
+    0x02, 0x05, 0x01, 0x12, 0x04, 0xac, 0x01, 0x12, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02,
+    0x05, 0x03, 0x12, 0x04, 0xac, 0x01, 0x23, 0x24, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0e, 0x02, 0x06,
+    0x12, 0x04, 0xad, 0x01, 0x04, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x06, 0x12,
+    0x04, 0xad, 0x01, 0x04, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x01, 0x12, 0x04,
+    0xad, 0x01, 0x13, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0e, 0x02, 0x06, 0x03, 0x12, 0x04, 0xad,
+    0x01, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x0f, 0x12, 0x06, 0xb1, 0x01, 0x00, 0xb5, 0x01,
+    0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x0f, 0x01, 0x12, 0x04, 0xb1, 0x01, 0x08, 0x14, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x0f, 0x02, 0x00, 0x12, 0x04, 0xb2, 0x01, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x0f, 0x02, 0x00, 0x05, 0x12, 0x04, 0xb2, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x0f, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb2, 0x01, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0xb2, 0x01, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02,
+    0x01, 0x12, 0x04, 0xb3, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0xb3, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0xb3, 0x01, 0x08, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0xb3, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x0f, 0x02, 0x02, 0x12, 0x04, 0xb4, 0x01,
+    0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x06, 0x12, 0x04, 0xb4, 0x01, 0x02,
+    0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x01, 0x12, 0x04, 0xb4, 0x01, 0x0f, 0x15,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x0f, 0x02, 0x02, 0x03, 0x12, 0x04, 0xb4, 0x01, 0x18, 0x19, 0x0a,
+    0x0c, 0x0a, 0x02, 0x04, 0x10, 0x12, 0x06, 0xb7, 0x01, 0x00, 0xbc, 0x01, 0x01, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x10, 0x01, 0x12, 0x04, 0xb7, 0x01, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10,
+    0x02, 0x00, 0x12, 0x04, 0xb8, 0x01, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00,
+    0x05, 0x12, 0x04, 0xb8, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0xb8, 0x01, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0xb8, 0x01, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x01, 0x12, 0x04, 0xb9,
+    0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x05, 0x12, 0x04, 0xb9, 0x01,
+    0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x01, 0x12, 0x04, 0xb9, 0x01, 0x08,
+    0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x01, 0x03, 0x12, 0x04, 0xb9, 0x01, 0x19, 0x1a,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x10, 0x02, 0x02, 0x12, 0x04, 0xba, 0x01, 0x02, 0x19, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x10, 0x02, 0x02, 0x06, 0x12, 0x04, 0xba, 0x01, 0x02, 0x0f, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x10, 0x02, 0x02, 0x01, 0x12, 0x04, 0xba, 0x01, 0x10, 0x14, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x10, 0x02, 0x02, 0x03, 0x12, 0x04, 0xba, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x10, 0x02, 0x03, 0x12, 0x04, 0xbb, 0x01, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02,
+    0x03, 0x05, 0x12, 0x04, 0xbb, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03,
+    0x01, 0x12, 0x04, 0xbb, 0x01, 0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x10, 0x02, 0x03, 0x03,
+    0x12, 0x04, 0xbb, 0x01, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x11, 0x12, 0x06, 0xbe, 0x01,
+    0x00, 0xc3, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x11, 0x01, 0x12, 0x04, 0xbe, 0x01, 0x08,
+    0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x00, 0x12, 0x04, 0xbf, 0x01, 0x02, 0x1b, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x05, 0x12, 0x04, 0xbf, 0x01, 0x02, 0x07, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x11, 0x02, 0x00, 0x01, 0x12, 0x04, 0xbf, 0x01, 0x08, 0x16, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x11, 0x02, 0x00, 0x03, 0x12, 0x04, 0xbf, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x11, 0x02, 0x01, 0x12, 0x04, 0xc0, 0x01, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11,
+    0x02, 0x01, 0x05, 0x12, 0x04, 0xc0, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02,
+    0x01, 0x01, 0x12, 0x04, 0xc0, 0x01, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x01,
+    0x03, 0x12, 0x04, 0xc0, 0x01, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x02, 0x12,
+    0x04, 0xc1, 0x01, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x05, 0x12, 0x04,
+    0xc1, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x01, 0x12, 0x04, 0xc1,
+    0x01, 0x09, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x02, 0x03, 0x12, 0x04, 0xc1, 0x01,
+    0x0f, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x11, 0x02, 0x03, 0x12, 0x04, 0xc2, 0x01, 0x02, 0x1b,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x03, 0x06, 0x12, 0x04, 0xc2, 0x01, 0x02, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x11, 0x02, 0x03, 0x01, 0x12, 0x04, 0xc2, 0x01, 0x12, 0x16, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x11, 0x02, 0x03, 0x03, 0x12, 0x04, 0xc2, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a,
+    0x02, 0x04, 0x12, 0x12, 0x06, 0xc5, 0x01, 0x00, 0xc8, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04,
+    0x12, 0x01, 0x12, 0x04, 0xc5, 0x01, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x00,
+    0x12, 0x04, 0xc6, 0x01, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x05, 0x12,
+    0x04, 0xc6, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0xc6, 0x01, 0x09, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x00, 0x03, 0x12, 0x04, 0xc6,
+    0x01, 0x0f, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x12, 0x02, 0x01, 0x12, 0x04, 0xc7, 0x01, 0x02,
+    0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x05, 0x12, 0x04, 0xc7, 0x01, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc7, 0x01, 0x09, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x12, 0x02, 0x01, 0x03, 0x12, 0x04, 0xc7, 0x01, 0x14, 0x15, 0x0a, 0x0c,
+    0x0a, 0x02, 0x04, 0x13, 0x12, 0x06, 0xca, 0x01, 0x00, 0xce, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x13, 0x01, 0x12, 0x04, 0xca, 0x01, 0x08, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02,
+    0x00, 0x12, 0x04, 0xcb, 0x01, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x05,
+    0x12, 0x04, 0xcb, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xcb, 0x01, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xcb, 0x01, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x01, 0x12, 0x04, 0xcc, 0x01,
+    0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x05, 0x12, 0x04, 0xcc, 0x01, 0x02,
+    0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x01, 0x12, 0x04, 0xcc, 0x01, 0x08, 0x16,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x13, 0x02, 0x01, 0x03, 0x12, 0x04, 0xcc, 0x01, 0x19, 0x1a, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x13, 0x02, 0x02, 0x12, 0x04, 0xcd, 0x01, 0x02, 0x1e, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x13, 0x02, 0x02, 0x06, 0x12, 0x04, 0xcd, 0x01, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x13, 0x02, 0x02, 0x01, 0x12, 0x04, 0xcd, 0x01, 0x15, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x13, 0x02, 0x02, 0x03, 0x12, 0x04, 0xcd, 0x01, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x14,
+    0x12, 0x06, 0xd0, 0x01, 0x00, 0xd6, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x14, 0x01, 0x12,
+    0x04, 0xd0, 0x01, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x00, 0x12, 0x04, 0xd1,
+    0x01, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x05, 0x12, 0x04, 0xd1, 0x01,
+    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd1, 0x01, 0x09,
+    0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd1, 0x01, 0x13, 0x14,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x01, 0x12, 0x04, 0xd2, 0x01, 0x02, 0x1b, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x14, 0x02, 0x01, 0x05, 0x12, 0x04, 0xd2, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x14, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd2, 0x01, 0x08, 0x16, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x14, 0x02, 0x01, 0x03, 0x12, 0x04, 0xd2, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x14, 0x02, 0x02, 0x12, 0x04, 0xd3, 0x01, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02,
+    0x02, 0x06, 0x12, 0x04, 0xd3, 0x01, 0x02, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0xd3, 0x01, 0x10, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x02, 0x03,
+    0x12, 0x04, 0xd3, 0x01, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x03, 0x12, 0x04,
+    0xd4, 0x01, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x05, 0x12, 0x04, 0xd4,
+    0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x01, 0x12, 0x04, 0xd4, 0x01,
+    0x09, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x03, 0x03, 0x12, 0x04, 0xd4, 0x01, 0x14,
+    0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x14, 0x02, 0x04, 0x12, 0x04, 0xd5, 0x01, 0x02, 0x12, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x05, 0x12, 0x04, 0xd5, 0x01, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x14, 0x02, 0x04, 0x01, 0x12, 0x04, 0xd5, 0x01, 0x09, 0x0d, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x14, 0x02, 0x04, 0x03, 0x12, 0x04, 0xd5, 0x01, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x02,
+    0x04, 0x15, 0x12, 0x06, 0xd8, 0x01, 0x00, 0xdd, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x15,
+    0x01, 0x12, 0x04, 0xd8, 0x01, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x00, 0x12,
+    0x04, 0xd9, 0x01, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x05, 0x12, 0x04,
+    0xd9, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd9,
+    0x01, 0x09, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x00, 0x03, 0x12, 0x04, 0xd9, 0x01,
+    0x0f, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x01, 0x12, 0x04, 0xda, 0x01, 0x02, 0x16,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x05, 0x12, 0x04, 0xda, 0x01, 0x02, 0x08, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x01, 0x12, 0x04, 0xda, 0x01, 0x09, 0x11, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x15, 0x02, 0x01, 0x03, 0x12, 0x04, 0xda, 0x01, 0x14, 0x15, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x15, 0x02, 0x02, 0x12, 0x04, 0xdb, 0x01, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x15, 0x02, 0x02, 0x05, 0x12, 0x04, 0xdb, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15,
+    0x02, 0x02, 0x01, 0x12, 0x04, 0xdb, 0x01, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02,
+    0x02, 0x03, 0x12, 0x04, 0xdb, 0x01, 0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x15, 0x02, 0x03,
+    0x12, 0x04, 0xdc, 0x01, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x03, 0x05, 0x12,
+    0x04, 0xdc, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x03, 0x01, 0x12, 0x04,
+    0xdc, 0x01, 0x09, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x15, 0x02, 0x03, 0x03, 0x12, 0x04, 0xdc,
+    0x01, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x16, 0x12, 0x06, 0xdf, 0x01, 0x00, 0xe4, 0x01,
+    0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x16, 0x01, 0x12, 0x04, 0xdf, 0x01, 0x08, 0x16, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x16, 0x02, 0x00, 0x12, 0x04, 0xe0, 0x01, 0x02, 0x1b, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x16, 0x02, 0x00, 0x05, 0x12, 0x04, 0xe0, 0x01, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x16, 0x02, 0x00, 0x01, 0x12, 0x04, 0xe0, 0x01, 0x08, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0xe0, 0x01, 0x19, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02,
+    0x01, 0x12, 0x04, 0xe1, 0x01, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0xe1, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0xe1, 0x01, 0x09, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0xe1, 0x01, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x02, 0x12, 0x04, 0xe2, 0x01,
+    0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x02, 0x05, 0x12, 0x04, 0xe2, 0x01, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x02, 0x01, 0x12, 0x04, 0xe2, 0x01, 0x09, 0x0c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x16, 0x02, 0x02, 0x03, 0x12, 0x04, 0xe2, 0x01, 0x0f, 0x10, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x16, 0x02, 0x03, 0x12, 0x04, 0xe3, 0x01, 0x02, 0x1a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x16, 0x02, 0x03, 0x06, 0x12, 0x04, 0xe3, 0x01, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x16, 0x02, 0x03, 0x01, 0x12, 0x04, 0xe3, 0x01, 0x11, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x16, 0x02, 0x03, 0x03, 0x12, 0x04, 0xe3, 0x01, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x17,
+    0x12, 0x06, 0xe6, 0x01, 0x00, 0xf9, 0x01, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x17, 0x01, 0x12,
+    0x04, 0xe6, 0x01, 0x08, 0x1a, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x17, 0x04, 0x00, 0x12, 0x06, 0xe7,
+    0x01, 0x02, 0xee, 0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x04, 0x00, 0x01, 0x12, 0x04,
+    0xe7, 0x01, 0x07, 0x0b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x17, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04,
+    0xe8, 0x01, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xe8, 0x01, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x04, 0x00, 0x02, 0x00, 0x02,
+    0x12, 0x04, 0xe8, 0x01, 0x17, 0x18, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x17, 0x04, 0x00, 0x02, 0x01,
+    0x12, 0x04, 0xe9, 0x01, 0x04, 0x24, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x04, 0x00, 0x02, 0x01,
+    0x01, 0x12, 0x04, 0xe9, 0x01, 0x04, 0x1f, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x04, 0x00, 0x02,
+    0x01, 0x02, 0x12, 0x04, 0xe9, 0x01, 0x22, 0x23, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x17, 0x04, 0x00,
+    0x02, 0x02, 0x12, 0x04, 0xea, 0x01, 0x04, 0x1c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x04, 0x00,
+    0x02, 0x02, 0x01, 0x12, 0x04, 0xea, 0x01, 0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17, 0x04,
+    0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0xea, 0x01, 0x1a, 0x1b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x17,
+    0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0xeb, 0x01, 0x04, 0x1f, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x17,
+    0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0xeb, 0x01, 0x04, 0x1a, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x17, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0xeb, 0x01, 0x1d, 0x1e, 0x0a, 0x0e, 0x0a, 0x06,
+    0x04, 0x17, 0x04, 0x00, 0x02, 0x04, 0x12, 0x04, 0xec, 0x01, 0x04, 0x1e, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x17, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0xec, 0x01, 0x04, 0x19, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x17, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0xec, 0x01, 0x1c, 0x1d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x17, 0x04, 0x00, 0x04, 0x12, 0x04, 0xed, 0x01, 0x04, 0x0f, 0x0a, 0x0e, 0x0a,
+    0x06, 0x04, 0x17, 0x04, 0x00, 0x04, 0x00, 0x12, 0x04, 0xed, 0x01, 0x0d, 0x0e, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x17, 0x04, 0x00, 0x04, 0x00, 0x01, 0x12, 0x04, 0xed, 0x01, 0x0d, 0x0e, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x17, 0x04, 0x00, 0x04, 0x00, 0x02, 0x12, 0x04, 0xed, 0x01, 0x0d, 0x0e, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x00, 0x12, 0x04, 0xf0, 0x01, 0x02, 0x10, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x17, 0x02, 0x00, 0x06, 0x12, 0x04, 0xf0, 0x01, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x17, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf0, 0x01, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x17, 0x02, 0x00, 0x03, 0x12, 0x04, 0xf0, 0x01, 0x0e, 0x0f, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x17,
+    0x08, 0x00, 0x12, 0x06, 0xf2, 0x01, 0x02, 0xf7, 0x01, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17,
+    0x08, 0x00, 0x01, 0x12, 0x04, 0xf2, 0x01, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02,
+    0x01, 0x12, 0x04, 0xf3, 0x01, 0x04, 0x34, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x06,
+    0x12, 0x04, 0xf3, 0x01, 0x04, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0xf3, 0x01, 0x19, 0x2f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0xf3, 0x01, 0x32, 0x33, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x02, 0x12, 0x04, 0xf4, 0x01,
+    0x04, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x02, 0x06, 0x12, 0x04, 0xf4, 0x01, 0x04,
+    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x02, 0x01, 0x12, 0x04, 0xf4, 0x01, 0x12, 0x20,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x02, 0x03, 0x12, 0x04, 0xf4, 0x01, 0x23, 0x24, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x17, 0x02, 0x03, 0x12, 0x04, 0xf5, 0x01, 0x04, 0x2a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x17, 0x02, 0x03, 0x06, 0x12, 0x04, 0xf5, 0x01, 0x04, 0x13, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x17, 0x02, 0x03, 0x01, 0x12, 0x04, 0xf5, 0x01, 0x14, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x17, 0x02, 0x03, 0x03, 0x12, 0x04, 0xf5, 0x01, 0x28, 0x29, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17,
+    0x02, 0x04, 0x12, 0x04, 0xf6, 0x01, 0x04, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x04,
+    0x06, 0x12, 0x04, 0xf6, 0x01, 0x04, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x04, 0x01,
+    0x12, 0x04, 0xf6, 0x01, 0x14, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x02, 0x04, 0x03, 0x12,
+    0x04, 0xf6, 0x01, 0x27, 0x28, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x17, 0x09, 0x12, 0x04, 0xf8, 0x01,
+    0x02, 0x0d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x17, 0x09, 0x00, 0x12, 0x04, 0xf8, 0x01, 0x0b, 0x0c,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x17, 0x09, 0x00, 0x01, 0x12, 0x04, 0xf8, 0x01, 0x0b, 0x0c, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x17, 0x09, 0x00, 0x02, 0x12, 0x04, 0xf8, 0x01, 0x0b, 0x0c, 0x0a, 0x0c,
+    0x0a, 0x02, 0x04, 0x18, 0x12, 0x06, 0xfb, 0x01, 0x00, 0x80, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x18, 0x01, 0x12, 0x04, 0xfb, 0x01, 0x08, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18, 0x02,
+    0x00, 0x12, 0x04, 0xfc, 0x01, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x06,
+    0x12, 0x04, 0xfc, 0x01, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xfc, 0x01, 0x12, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xfc, 0x01, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x01, 0x12, 0x04, 0xfd, 0x01,
+    0x02, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x04, 0x12, 0x04, 0xfd, 0x01, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x06, 0x12, 0x04, 0xfd, 0x01, 0x0b, 0x13,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x01, 0x12, 0x04, 0xfd, 0x01, 0x14, 0x22, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x01, 0x03, 0x12, 0x04, 0xfd, 0x01, 0x25, 0x26, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x18, 0x02, 0x02, 0x12, 0x04, 0xfe, 0x01, 0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x18, 0x02, 0x02, 0x04, 0x12, 0x04, 0xfe, 0x01, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x18, 0x02, 0x02, 0x05, 0x12, 0x04, 0xfe, 0x01, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18,
+    0x02, 0x02, 0x01, 0x12, 0x04, 0xfe, 0x01, 0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02,
+    0x02, 0x03, 0x12, 0x04, 0xfe, 0x01, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x18, 0x02, 0x03,
+    0x12, 0x04, 0xff, 0x01, 0x02, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x03, 0x05, 0x12,
+    0x04, 0xff, 0x01, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x03, 0x01, 0x12, 0x04,
+    0xff, 0x01, 0x09, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x18, 0x02, 0x03, 0x03, 0x12, 0x04, 0xff,
+    0x01, 0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x19, 0x12, 0x06, 0x82, 0x02, 0x00, 0x85, 0x02,
+    0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x19, 0x01, 0x12, 0x04, 0x82, 0x02, 0x08, 0x1a, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x19, 0x02, 0x00, 0x12, 0x04, 0x83, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x19, 0x02, 0x00, 0x05, 0x12, 0x04, 0x83, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x19, 0x02, 0x00, 0x01, 0x12, 0x04, 0x83, 0x02, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0x83, 0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x19, 0x02,
+    0x01, 0x12, 0x04, 0x84, 0x02, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x01, 0x06,
+    0x12, 0x04, 0x84, 0x02, 0x02, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0x84, 0x02, 0x0f, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x19, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0x84, 0x02, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1a, 0x12, 0x06, 0x87, 0x02, 0x00, 0x8b,
+    0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1a, 0x01, 0x12, 0x04, 0x87, 0x02, 0x08, 0x15, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x00, 0x12, 0x04, 0x88, 0x02, 0x02, 0x1e, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x1a, 0x02, 0x00, 0x06, 0x12, 0x04, 0x88, 0x02, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1a, 0x02, 0x00, 0x01, 0x12, 0x04, 0x88, 0x02, 0x15, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1a, 0x02, 0x00, 0x03, 0x12, 0x04, 0x88, 0x02, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a,
+    0x02, 0x01, 0x12, 0x04, 0x89, 0x02, 0x02, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01,
+    0x04, 0x12, 0x04, 0x89, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01, 0x06,
+    0x12, 0x04, 0x89, 0x02, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0x89, 0x02, 0x14, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0x89, 0x02, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1a, 0x02, 0x02, 0x12, 0x04, 0x8a, 0x02,
+    0x02, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x04, 0x12, 0x04, 0x8a, 0x02, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x05, 0x12, 0x04, 0x8a, 0x02, 0x0b, 0x11,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x01, 0x12, 0x04, 0x8a, 0x02, 0x12, 0x1b, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1a, 0x02, 0x02, 0x03, 0x12, 0x04, 0x8a, 0x02, 0x1e, 0x1f, 0x0a, 0x0c,
+    0x0a, 0x02, 0x04, 0x1b, 0x12, 0x06, 0x8d, 0x02, 0x00, 0x90, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x1b, 0x01, 0x12, 0x04, 0x8d, 0x02, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02,
+    0x00, 0x12, 0x04, 0x8e, 0x02, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x05,
+    0x12, 0x04, 0x8e, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0x8e, 0x02, 0x09, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0x8e, 0x02, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1b, 0x02, 0x01, 0x12, 0x04, 0x8f, 0x02,
+    0x02, 0x3e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x04, 0x12, 0x04, 0x8f, 0x02, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x06, 0x12, 0x04, 0x8f, 0x02, 0x0b, 0x25,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8f, 0x02, 0x26, 0x39, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1b, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8f, 0x02, 0x3c, 0x3d, 0x0a, 0x0c,
+    0x0a, 0x02, 0x04, 0x1c, 0x12, 0x06, 0x92, 0x02, 0x00, 0x9d, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x1c, 0x01, 0x12, 0x04, 0x92, 0x02, 0x08, 0x22, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x1c, 0x04,
+    0x00, 0x12, 0x06, 0x93, 0x02, 0x02, 0x96, 0x02, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x04,
+    0x00, 0x01, 0x12, 0x04, 0x93, 0x02, 0x07, 0x0b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x1c, 0x04, 0x00,
+    0x02, 0x00, 0x12, 0x04, 0x94, 0x02, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1c, 0x04, 0x00,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0x94, 0x02, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1c, 0x04,
+    0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0x94, 0x02, 0x17, 0x18, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x1c,
+    0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0x95, 0x02, 0x04, 0x24, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1c,
+    0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x95, 0x02, 0x04, 0x1f, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x1c, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x95, 0x02, 0x22, 0x23, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x1c, 0x02, 0x00, 0x12, 0x04, 0x98, 0x02, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c,
+    0x02, 0x00, 0x06, 0x12, 0x04, 0x98, 0x02, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0x98, 0x02, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0x98, 0x02, 0x0e, 0x0f, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x1c, 0x08, 0x00, 0x12,
+    0x06, 0x9a, 0x02, 0x02, 0x9c, 0x02, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x08, 0x00, 0x01,
+    0x12, 0x04, 0x9a, 0x02, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1c, 0x02, 0x01, 0x12, 0x04,
+    0x9b, 0x02, 0x04, 0x34, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x06, 0x12, 0x04, 0x9b,
+    0x02, 0x04, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x01, 0x12, 0x04, 0x9b, 0x02,
+    0x19, 0x2f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1c, 0x02, 0x01, 0x03, 0x12, 0x04, 0x9b, 0x02, 0x32,
+    0x33, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1d, 0x12, 0x06, 0x9f, 0x02, 0x00, 0xa2, 0x02, 0x01, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x1d, 0x01, 0x12, 0x04, 0x9f, 0x02, 0x08, 0x1a, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x1d, 0x02, 0x00, 0x12, 0x04, 0xa0, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0xa0, 0x02, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0xa0, 0x02, 0x08, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0xa0, 0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1d, 0x02, 0x01, 0x12,
+    0x04, 0xa1, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x01, 0x06, 0x12, 0x04,
+    0xa1, 0x02, 0x02, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa1,
+    0x02, 0x0d, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1d, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa1, 0x02,
+    0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1e, 0x12, 0x06, 0xa4, 0x02, 0x00, 0xaa, 0x02, 0x01,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x1e, 0x01, 0x12, 0x04, 0xa4, 0x02, 0x08, 0x12, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x1e, 0x02, 0x00, 0x12, 0x04, 0xa5, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1e, 0x02, 0x00, 0x05, 0x12, 0x04, 0xa5, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0xa5, 0x02, 0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0xa5, 0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x01,
+    0x12, 0x04, 0xa6, 0x02, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x01, 0x05, 0x12,
+    0x04, 0xa6, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x01, 0x01, 0x12, 0x04,
+    0xa6, 0x02, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa6,
+    0x02, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x02, 0x12, 0x04, 0xa7, 0x02, 0x02,
+    0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x04, 0x12, 0x04, 0xa7, 0x02, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x06, 0x12, 0x04, 0xa7, 0x02, 0x0b, 0x17, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa7, 0x02, 0x18, 0x1f, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x1e, 0x02, 0x02, 0x03, 0x12, 0x04, 0xa7, 0x02, 0x22, 0x23, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x1e, 0x02, 0x03, 0x12, 0x04, 0xa8, 0x02, 0x02, 0x2e, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1e, 0x02, 0x03, 0x04, 0x12, 0x04, 0xa8, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e,
+    0x02, 0x03, 0x06, 0x12, 0x04, 0xa8, 0x02, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02,
+    0x03, 0x01, 0x12, 0x04, 0xa8, 0x02, 0x18, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x03,
+    0x03, 0x12, 0x04, 0xa8, 0x02, 0x2c, 0x2d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1e, 0x02, 0x04, 0x12,
+    0x04, 0xa9, 0x02, 0x02, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x04, 0x04, 0x12, 0x04,
+    0xa9, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x04, 0x06, 0x12, 0x04, 0xa9,
+    0x02, 0x0b, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x04, 0x01, 0x12, 0x04, 0xa9, 0x02,
+    0x16, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1e, 0x02, 0x04, 0x03, 0x12, 0x04, 0xa9, 0x02, 0x20,
+    0x21, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x1f, 0x12, 0x06, 0xab, 0x02, 0x00, 0xb8, 0x02, 0x01, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x1f, 0x01, 0x12, 0x04, 0xab, 0x02, 0x08, 0x14, 0x0a, 0x0e, 0x0a, 0x04,
+    0x04, 0x1f, 0x04, 0x00, 0x12, 0x06, 0xac, 0x02, 0x02, 0xb1, 0x02, 0x03, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1f, 0x04, 0x00, 0x01, 0x12, 0x04, 0xac, 0x02, 0x07, 0x11, 0x0a, 0x0e, 0x0a, 0x06, 0x04,
+    0x1f, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0xad, 0x02, 0x04, 0x1f, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x1f, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xad, 0x02, 0x04, 0x1a, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x1f, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0xad, 0x02, 0x1d, 0x1e, 0x0a, 0x0e, 0x0a,
+    0x06, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0xae, 0x02, 0x04, 0x1b, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xae, 0x02, 0x04, 0x16, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0xae, 0x02, 0x19, 0x1a, 0x0a,
+    0x0e, 0x0a, 0x06, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0xaf, 0x02, 0x04, 0x1a, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0xaf, 0x02, 0x04, 0x15,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0xaf, 0x02, 0x18,
+    0x19, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0xb0, 0x02, 0x04,
+    0x1a, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0xb0, 0x02,
+    0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x1f, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0xb0,
+    0x02, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1f, 0x02, 0x00, 0x12, 0x04, 0xb2, 0x02, 0x02,
+    0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x05, 0x12, 0x04, 0xb2, 0x02, 0x02, 0x08,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb2, 0x02, 0x09, 0x0d, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb2, 0x02, 0x10, 0x11, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x1f, 0x02, 0x01, 0x12, 0x04, 0xb3, 0x02, 0x02, 0x29, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1f, 0x02, 0x01, 0x06, 0x12, 0x04, 0xb3, 0x02, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1f, 0x02, 0x01, 0x01, 0x12, 0x04, 0xb3, 0x02, 0x1a, 0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f,
+    0x02, 0x01, 0x03, 0x12, 0x04, 0xb3, 0x02, 0x27, 0x28, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1f, 0x02,
+    0x02, 0x12, 0x04, 0xb4, 0x02, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x02, 0x05,
+    0x12, 0x04, 0xb4, 0x02, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x02, 0x01, 0x12,
+    0x04, 0xb4, 0x02, 0x07, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x02, 0x03, 0x12, 0x04,
+    0xb4, 0x02, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1f, 0x02, 0x03, 0x12, 0x04, 0xb5, 0x02,
+    0x02, 0x40, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x03, 0x04, 0x12, 0x04, 0xb5, 0x02, 0x02,
+    0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x03, 0x06, 0x12, 0x04, 0xb5, 0x02, 0x0b, 0x27,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x03, 0x01, 0x12, 0x04, 0xb5, 0x02, 0x28, 0x3b, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x03, 0x03, 0x12, 0x04, 0xb5, 0x02, 0x3e, 0x3f, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x1f, 0x02, 0x04, 0x12, 0x04, 0xb6, 0x02, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x1f, 0x02, 0x04, 0x04, 0x12, 0x04, 0xb6, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x1f, 0x02, 0x04, 0x06, 0x12, 0x04, 0xb6, 0x02, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f,
+    0x02, 0x04, 0x01, 0x12, 0x04, 0xb6, 0x02, 0x14, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02,
+    0x04, 0x03, 0x12, 0x04, 0xb6, 0x02, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x1f, 0x02, 0x05,
+    0x12, 0x04, 0xb7, 0x02, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x05, 0x04, 0x12,
+    0x04, 0xb7, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x05, 0x06, 0x12, 0x04,
+    0xb7, 0x02, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x05, 0x01, 0x12, 0x04, 0xb7,
+    0x02, 0x14, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x1f, 0x02, 0x05, 0x03, 0x12, 0x04, 0xb7, 0x02,
+    0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x20, 0x12, 0x06, 0xba, 0x02, 0x00, 0xc0, 0x02, 0x01,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x20, 0x01, 0x12, 0x04, 0xba, 0x02, 0x08, 0x12, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x20, 0x02, 0x00, 0x12, 0x04, 0xbb, 0x02, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x20, 0x02, 0x00, 0x05, 0x12, 0x04, 0xbb, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0xbb, 0x02, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0xbb, 0x02, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x20, 0x02, 0x01,
+    0x12, 0x04, 0xbc, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x01, 0x05, 0x12,
+    0x04, 0xbc, 0x02, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x01, 0x01, 0x12, 0x04,
+    0xbc, 0x02, 0x07, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x01, 0x03, 0x12, 0x04, 0xbc,
+    0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x20, 0x02, 0x02, 0x12, 0x04, 0xbd, 0x02, 0x02,
+    0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x02, 0x04, 0x12, 0x04, 0xbd, 0x02, 0x02, 0x0a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x02, 0x06, 0x12, 0x04, 0xbd, 0x02, 0x0b, 0x16, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x02, 0x01, 0x12, 0x04, 0xbd, 0x02, 0x17, 0x20, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x20, 0x02, 0x02, 0x03, 0x12, 0x04, 0xbd, 0x02, 0x23, 0x24, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x20, 0x02, 0x03, 0x12, 0x04, 0xbe, 0x02, 0x02, 0x3e, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x20, 0x02, 0x03, 0x04, 0x12, 0x04, 0xbe, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20,
+    0x02, 0x03, 0x06, 0x12, 0x04, 0xbe, 0x02, 0x0b, 0x25, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02,
+    0x03, 0x01, 0x12, 0x04, 0xbe, 0x02, 0x26, 0x39, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x03,
+    0x03, 0x12, 0x04, 0xbe, 0x02, 0x3c, 0x3d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x20, 0x02, 0x04, 0x12,
+    0x04, 0xbf, 0x02, 0x02, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x04, 0x04, 0x12, 0x04,
+    0xbf, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x04, 0x06, 0x12, 0x04, 0xbf,
+    0x02, 0x0b, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x04, 0x01, 0x12, 0x04, 0xbf, 0x02,
+    0x1b, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x20, 0x02, 0x04, 0x03, 0x12, 0x04, 0xbf, 0x02, 0x24,
+    0x25, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x21, 0x12, 0x06, 0xc2, 0x02, 0x00, 0xc5, 0x02, 0x01, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x21, 0x01, 0x12, 0x04, 0xc2, 0x02, 0x08, 0x22, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x21, 0x02, 0x00, 0x12, 0x04, 0xc3, 0x02, 0x02, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21,
+    0x02, 0x00, 0x04, 0x12, 0x04, 0xc3, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02,
+    0x00, 0x06, 0x12, 0x04, 0xc3, 0x02, 0x0b, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0xc3, 0x02, 0x17, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0xc3, 0x02, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x21, 0x02, 0x01, 0x12, 0x04,
+    0xc4, 0x02, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x01, 0x05, 0x12, 0x04, 0xc4,
+    0x02, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc4, 0x02,
+    0x07, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x21, 0x02, 0x01, 0x03, 0x12, 0x04, 0xc4, 0x02, 0x14,
+    0x15, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x22, 0x12, 0x06, 0xc7, 0x02, 0x00, 0xca, 0x02, 0x01, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x22, 0x01, 0x12, 0x04, 0xc7, 0x02, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x22, 0x02, 0x00, 0x12, 0x04, 0xc8, 0x02, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0xc8, 0x02, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0xc8, 0x02, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0xc8, 0x02, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x22, 0x02, 0x01, 0x12,
+    0x04, 0xc9, 0x02, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x01, 0x06, 0x12, 0x04,
+    0xc9, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x01, 0x01, 0x12, 0x04, 0xc9,
+    0x02, 0x0b, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x22, 0x02, 0x01, 0x03, 0x12, 0x04, 0xc9, 0x02,
+    0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x23, 0x12, 0x06, 0xcc, 0x02, 0x00, 0xce, 0x02, 0x01,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x23, 0x01, 0x12, 0x04, 0xcc, 0x02, 0x08, 0x24, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x23, 0x02, 0x00, 0x12, 0x04, 0xcd, 0x02, 0x02, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x23, 0x02, 0x00, 0x04, 0x12, 0x04, 0xcd, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23,
+    0x02, 0x00, 0x06, 0x12, 0x04, 0xcd, 0x02, 0x0b, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0xcd, 0x02, 0x17, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x23, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0xcd, 0x02, 0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x06, 0xd0,
+    0x02, 0x00, 0xe0, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x04, 0xd0, 0x02,
+    0x05, 0x0e, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x04, 0xd1, 0x02, 0x02, 0x1d,
+    0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd1, 0x02, 0x02, 0x18, 0x0a,
+    0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0xd1, 0x02, 0x1b, 0x1c, 0x0a, 0x0c,
+    0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x04, 0xd2, 0x02, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd2, 0x02, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x05,
+    0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0xd2, 0x02, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x00,
+    0x02, 0x02, 0x12, 0x04, 0xd3, 0x02, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0xd3, 0x02, 0x02, 0x0f, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x02,
+    0x12, 0x04, 0xd3, 0x02, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x03, 0x12, 0x04,
+    0xd4, 0x02, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0xd4,
+    0x02, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0xd4, 0x02,
+    0x13, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x04, 0x12, 0x04, 0xd5, 0x02, 0x02, 0x16,
+    0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0xd5, 0x02, 0x02, 0x10, 0x0a,
+    0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0xd5, 0x02, 0x13, 0x15, 0x0a, 0x0c,
+    0x0a, 0x04, 0x05, 0x00, 0x02, 0x05, 0x12, 0x04, 0xd6, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x05, 0x01, 0x12, 0x04, 0xd6, 0x02, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x05,
+    0x00, 0x02, 0x05, 0x02, 0x12, 0x04, 0xd6, 0x02, 0x13, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x00,
+    0x02, 0x06, 0x12, 0x04, 0xd7, 0x02, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x06,
+    0x01, 0x12, 0x04, 0xd7, 0x02, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x06, 0x02,
+    0x12, 0x04, 0xd7, 0x02, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x07, 0x12, 0x04,
+    0xd8, 0x02, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x07, 0x01, 0x12, 0x04, 0xd8,
+    0x02, 0x02, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x07, 0x02, 0x12, 0x04, 0xd8, 0x02,
+    0x14, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x08, 0x12, 0x04, 0xd9, 0x02, 0x02, 0x19,
+    0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x08, 0x01, 0x12, 0x04, 0xd9, 0x02, 0x02, 0x14, 0x0a,
+    0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x08, 0x02, 0x12, 0x04, 0xd9, 0x02, 0x17, 0x18, 0x0a, 0x0c,
+    0x0a, 0x04, 0x05, 0x00, 0x02, 0x09, 0x12, 0x04, 0xda, 0x02, 0x02, 0x18, 0x0a, 0x0d, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x09, 0x01, 0x12, 0x04, 0xda, 0x02, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x05,
+    0x00, 0x02, 0x09, 0x02, 0x12, 0x04, 0xda, 0x02, 0x16, 0x17, 0x0a, 0x2b, 0x0a, 0x04, 0x05, 0x00,
+    0x02, 0x0a, 0x12, 0x04, 0xdb, 0x02, 0x02, 0x18, 0x22, 0x1d, 0x20, 0x60, 0x7b, 0x20, 0x69, 0x74,
+    0x65, 0x6d, 0x73, 0x3a, 0x20, 0x42, 0x6f, 0x78, 0x3c, 0x4d, 0x6f, 0x76, 0x65, 0x54, 0x79, 0x70,
+    0x65, 0x3e, 0x20, 0x7d, 0x60, 0x2c, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x0a, 0x01,
+    0x12, 0x04, 0xdb, 0x02, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x0a, 0x02, 0x12,
+    0x04, 0xdb, 0x02, 0x16, 0x17, 0x0a, 0x22, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x0b, 0x12, 0x04, 0xdc,
+    0x02, 0x02, 0x18, 0x22, 0x14, 0x20, 0x60, 0x28, 0x4d, 0x6f, 0x76, 0x65, 0x53, 0x74, 0x72, 0x75,
+    0x63, 0x74, 0x54, 0x61, 0x67, 0x29, 0x60, 0x2c, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02,
+    0x0b, 0x01, 0x12, 0x04, 0xdc, 0x02, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x0b,
+    0x02, 0x12, 0x04, 0xdc, 0x02, 0x16, 0x17, 0x0a, 0x22, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x0c, 0x12,
+    0x04, 0xdd, 0x02, 0x02, 0x24, 0x22, 0x14, 0x20, 0x60, 0x7b, 0x20, 0x69, 0x6e, 0x64, 0x65, 0x78,
+    0x3a, 0x20, 0x75, 0x31, 0x36, 0x20, 0x7d, 0x60, 0x60, 0x2c, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05,
+    0x00, 0x02, 0x0c, 0x01, 0x12, 0x04, 0xdd, 0x02, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00,
+    0x02, 0x0c, 0x02, 0x12, 0x04, 0xdd, 0x02, 0x22, 0x23, 0x0a, 0x37, 0x0a, 0x04, 0x05, 0x00, 0x02,
+    0x0d, 0x12, 0x04, 0xde, 0x02, 0x02, 0x1c, 0x22, 0x29, 0x20, 0x60, 0x7b, 0x20, 0x6d, 0x75, 0x74,
+    0x61, 0x62, 0x6c, 0x65, 0x3a, 0x20, 0x62, 0x6f, 0x6f, 0x6c, 0x2c, 0x20, 0x74, 0x6f, 0x3a, 0x20,
+    0x42, 0x6f, 0x78, 0x3c, 0x4d, 0x6f, 0x76, 0x65, 0x54, 0x79, 0x70, 0x65, 0x3e, 0x20, 0x7d, 0x60,
+    0x2c, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x0d, 0x01, 0x12, 0x04, 0xde, 0x02, 0x02,
+    0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x0d, 0x02, 0x12, 0x04, 0xde, 0x02, 0x19, 0x1b,
+    0x0a, 0x1b, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x0e, 0x12, 0x04, 0xdf, 0x02, 0x02, 0x1d, 0x22, 0x0d,
+    0x20, 0x60, 0x28, 0x53, 0x74, 0x72, 0x69, 0x6e, 0x67, 0x29, 0x60, 0x2c, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x05, 0x00, 0x02, 0x0e, 0x01, 0x12, 0x04, 0xdf, 0x02, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x0e, 0x02, 0x12, 0x04, 0xdf, 0x02, 0x1a, 0x1c, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
+    0x24, 0x12, 0x06, 0xe2, 0x02, 0x00, 0xf1, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x24, 0x01,
+    0x12, 0x04, 0xe2, 0x02, 0x08, 0x10, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x24, 0x03, 0x00, 0x12, 0x06,
+    0xe4, 0x02, 0x02, 0xe7, 0x02, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x03, 0x00, 0x01, 0x12,
+    0x04, 0xe4, 0x02, 0x0a, 0x17, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x24, 0x03, 0x00, 0x02, 0x00, 0x12,
+    0x04, 0xe5, 0x02, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x24, 0x03, 0x00, 0x02, 0x00, 0x05,
+    0x12, 0x04, 0xe5, 0x02, 0x04, 0x08, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x24, 0x03, 0x00, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0xe5, 0x02, 0x09, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x24, 0x03, 0x00, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0xe5, 0x02, 0x13, 0x14, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x24, 0x03, 0x00,
+    0x02, 0x01, 0x12, 0x04, 0xe6, 0x02, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x24, 0x03, 0x00,
+    0x02, 0x01, 0x06, 0x12, 0x04, 0xe6, 0x02, 0x04, 0x0c, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x24, 0x03,
+    0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xe6, 0x02, 0x0d, 0x0f, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x24,
+    0x03, 0x00, 0x02, 0x01, 0x03, 0x12, 0x04, 0xe6, 0x02, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x24, 0x02, 0x00, 0x12, 0x04, 0xe9, 0x02, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02,
+    0x00, 0x06, 0x12, 0x04, 0xe9, 0x02, 0x02, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0xe9, 0x02, 0x0c, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0xe9, 0x02, 0x13, 0x14, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x24, 0x08, 0x00, 0x12, 0x06,
+    0xea, 0x02, 0x02, 0xf0, 0x02, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x08, 0x00, 0x01, 0x12,
+    0x04, 0xea, 0x02, 0x08, 0x0f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x24, 0x02, 0x01, 0x12, 0x04, 0xeb,
+    0x02, 0x04, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x01, 0x06, 0x12, 0x04, 0xeb, 0x02,
+    0x04, 0x0c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x01, 0x01, 0x12, 0x04, 0xeb, 0x02, 0x0d,
+    0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x01, 0x03, 0x12, 0x04, 0xeb, 0x02, 0x16, 0x17,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x24, 0x02, 0x02, 0x12, 0x04, 0xec, 0x02, 0x04, 0x1d, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x24, 0x02, 0x02, 0x06, 0x12, 0x04, 0xec, 0x02, 0x04, 0x11, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x24, 0x02, 0x02, 0x01, 0x12, 0x04, 0xec, 0x02, 0x12, 0x18, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x24, 0x02, 0x02, 0x03, 0x12, 0x04, 0xec, 0x02, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x24, 0x02, 0x03, 0x12, 0x04, 0xed, 0x02, 0x04, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02,
+    0x03, 0x05, 0x12, 0x04, 0xed, 0x02, 0x04, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x03,
+    0x01, 0x12, 0x04, 0xed, 0x02, 0x0b, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x03, 0x03,
+    0x12, 0x04, 0xed, 0x02, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x24, 0x02, 0x04, 0x12, 0x04,
+    0xee, 0x02, 0x04, 0x20, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x04, 0x06, 0x12, 0x04, 0xee,
+    0x02, 0x04, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x04, 0x01, 0x12, 0x04, 0xee, 0x02,
+    0x12, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x04, 0x03, 0x12, 0x04, 0xee, 0x02, 0x1e,
+    0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x24, 0x02, 0x05, 0x12, 0x04, 0xef, 0x02, 0x04, 0x1a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x24, 0x02, 0x05, 0x05, 0x12, 0x04, 0xef, 0x02, 0x04, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x24, 0x02, 0x05, 0x01, 0x12, 0x04, 0xef, 0x02, 0x0b, 0x15, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x24, 0x02, 0x05, 0x03, 0x12, 0x04, 0xef, 0x02, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x02,
+    0x05, 0x01, 0x12, 0x06, 0xf3, 0x02, 0x00, 0xf9, 0x02, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x05, 0x01,
+    0x01, 0x12, 0x04, 0xf3, 0x02, 0x05, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x00, 0x12,
+    0x04, 0xf4, 0x02, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0xf4, 0x02, 0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x00, 0x02, 0x12, 0x04, 0xf4,
+    0x02, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x01, 0x12, 0x04, 0xf5, 0x02, 0x02,
+    0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x01, 0x12, 0x04, 0xf5, 0x02, 0x02, 0x13,
+    0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02, 0x12, 0x04, 0xf5, 0x02, 0x16, 0x17, 0x0a,
+    0x0c, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x02, 0x12, 0x04, 0xf6, 0x02, 0x02, 0x18, 0x0a, 0x0d, 0x0a,
+    0x05, 0x05, 0x01, 0x02, 0x02, 0x01, 0x12, 0x04, 0xf6, 0x02, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05,
+    0x05, 0x01, 0x02, 0x02, 0x02, 0x12, 0x04, 0xf6, 0x02, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x05,
+    0x01, 0x02, 0x03, 0x12, 0x04, 0xf7, 0x02, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x01, 0x02,
+    0x03, 0x01, 0x12, 0x04, 0xf7, 0x02, 0x02, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x03,
+    0x02, 0x12, 0x04, 0xf7, 0x02, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x04, 0x12,
+    0x04, 0xf8, 0x02, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x04, 0x01, 0x12, 0x04,
+    0xf8, 0x02, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x04, 0x02, 0x12, 0x04, 0xf8,
+    0x02, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x25, 0x12, 0x06, 0xfb, 0x02, 0x00, 0xfd, 0x02,
+    0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x25, 0x01, 0x12, 0x04, 0xfb, 0x02, 0x08, 0x17, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x25, 0x02, 0x00, 0x12, 0x04, 0xfc, 0x02, 0x02, 0x19, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x25, 0x02, 0x00, 0x06, 0x12, 0x04, 0xfc, 0x02, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x25, 0x02, 0x00, 0x01, 0x12, 0x04, 0xfc, 0x02, 0x0b, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x25,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0xfc, 0x02, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x26, 0x12,
+    0x06, 0xff, 0x02, 0x00, 0x82, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x26, 0x01, 0x12, 0x04,
+    0xff, 0x02, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x26, 0x02, 0x00, 0x12, 0x04, 0x80, 0x03,
+    0x02, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x00, 0x06, 0x12, 0x04, 0x80, 0x03, 0x02,
+    0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x00, 0x01, 0x12, 0x04, 0x80, 0x03, 0x0f, 0x15,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x26, 0x02, 0x00, 0x03, 0x12, 0x04, 0x80, 0x03, 0x18, 0x19, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x26, 0x02, 0x01, 0x12, 0x04, 0x81, 0x03, 0x02, 0x12, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x26, 0x02, 0x01, 0x05, 0x12, 0x04, 0x81, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x26, 0x02, 0x01, 0x01, 0x12, 0x04, 0x81, 0x03, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x26, 0x02, 0x01, 0x03, 0x12, 0x04, 0x81, 0x03, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x27,
+    0x12, 0x06, 0x84, 0x03, 0x00, 0x87, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x27, 0x01, 0x12,
+    0x04, 0x84, 0x03, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x27, 0x02, 0x00, 0x12, 0x04, 0x85,
+    0x03, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x00, 0x05, 0x12, 0x04, 0x85, 0x03,
+    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x00, 0x01, 0x12, 0x04, 0x85, 0x03, 0x09,
+    0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x27, 0x02, 0x00, 0x03, 0x12, 0x04, 0x85, 0x03, 0x13, 0x14,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x27, 0x02, 0x01, 0x12, 0x04, 0x86, 0x03, 0x02, 0x12, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x27, 0x02, 0x01, 0x05, 0x12, 0x04, 0x86, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x27, 0x02, 0x01, 0x01, 0x12, 0x04, 0x86, 0x03, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x27, 0x02, 0x01, 0x03, 0x12, 0x04, 0x86, 0x03, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
+    0x28, 0x12, 0x06, 0x89, 0x03, 0x00, 0x8e, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x28, 0x01,
+    0x12, 0x04, 0x89, 0x03, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x28, 0x02, 0x00, 0x12, 0x04,
+    0x8a, 0x03, 0x02, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x00, 0x05, 0x12, 0x04, 0x8a,
+    0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8a, 0x03,
+    0x09, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8a, 0x03, 0x13,
+    0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x28, 0x02, 0x01, 0x12, 0x04, 0x8b, 0x03, 0x02, 0x14, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x01, 0x05, 0x12, 0x04, 0x8b, 0x03, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x28, 0x02, 0x01, 0x01, 0x12, 0x04, 0x8b, 0x03, 0x09, 0x0f, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x28, 0x02, 0x01, 0x03, 0x12, 0x04, 0x8b, 0x03, 0x12, 0x13, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x28, 0x02, 0x02, 0x12, 0x04, 0x8c, 0x03, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28,
+    0x02, 0x02, 0x05, 0x12, 0x04, 0x8c, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0x8c, 0x03, 0x09, 0x0d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0x8c, 0x03, 0x10, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x28, 0x02, 0x03, 0x12,
+    0x04, 0x8d, 0x03, 0x02, 0x2c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x03, 0x04, 0x12, 0x04,
+    0x8d, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x03, 0x06, 0x12, 0x04, 0x8d,
+    0x03, 0x0b, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x03, 0x01, 0x12, 0x04, 0x8d, 0x03,
+    0x14, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x28, 0x02, 0x03, 0x03, 0x12, 0x04, 0x8d, 0x03, 0x2a,
+    0x2b, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x29, 0x12, 0x06, 0x90, 0x03, 0x00, 0xa5, 0x03, 0x01, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x29, 0x01, 0x12, 0x04, 0x90, 0x03, 0x08, 0x11, 0x0a, 0x0e, 0x0a, 0x04,
+    0x04, 0x29, 0x04, 0x00, 0x12, 0x06, 0x92, 0x03, 0x02, 0x9a, 0x03, 0x03, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x29, 0x04, 0x00, 0x01, 0x12, 0x04, 0x92, 0x03, 0x07, 0x0b, 0x0a, 0x0e, 0x0a, 0x06, 0x04,
+    0x29, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0x93, 0x03, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x29, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x93, 0x03, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x29, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0x93, 0x03, 0x17, 0x18, 0x0a, 0x0e, 0x0a,
+    0x06, 0x04, 0x29, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0x94, 0x03, 0x04, 0x15, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x94, 0x03, 0x04, 0x10, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x94, 0x03, 0x13, 0x14, 0x0a,
+    0x0e, 0x0a, 0x06, 0x04, 0x29, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0x95, 0x03, 0x04, 0x1b, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x95, 0x03, 0x04, 0x16,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x95, 0x03, 0x19,
+    0x1a, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x29, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0x96, 0x03, 0x04,
+    0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0x96, 0x03,
+    0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0x96,
+    0x03, 0x17, 0x18, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x29, 0x04, 0x00, 0x02, 0x04, 0x12, 0x04, 0x97,
+    0x03, 0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04,
+    0x97, 0x03, 0x04, 0x12, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12,
+    0x04, 0x97, 0x03, 0x15, 0x16, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x29, 0x04, 0x00, 0x02, 0x05, 0x12,
+    0x04, 0x98, 0x03, 0x04, 0x1b, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x05, 0x01,
+    0x12, 0x04, 0x98, 0x03, 0x04, 0x16, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x02, 0x05,
+    0x02, 0x12, 0x04, 0x98, 0x03, 0x19, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x04, 0x00, 0x04,
+    0x12, 0x04, 0x99, 0x03, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x29, 0x04, 0x00, 0x04, 0x00,
+    0x12, 0x04, 0x99, 0x03, 0x0d, 0x0e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x04, 0x00,
+    0x01, 0x12, 0x04, 0x99, 0x03, 0x0d, 0x0e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x29, 0x04, 0x00, 0x04,
+    0x00, 0x02, 0x12, 0x04, 0x99, 0x03, 0x0d, 0x0e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x29, 0x02, 0x00,
+    0x12, 0x04, 0x9c, 0x03, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x00, 0x06, 0x12,
+    0x04, 0x9c, 0x03, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x00, 0x01, 0x12, 0x04,
+    0x9c, 0x03, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x00, 0x03, 0x12, 0x04, 0x9c,
+    0x03, 0x0e, 0x0f, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x29, 0x08, 0x00, 0x12, 0x06, 0x9d, 0x03, 0x02,
+    0xa4, 0x03, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x08, 0x00, 0x01, 0x12, 0x04, 0x9d, 0x03,
+    0x08, 0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x29, 0x02, 0x01, 0x12, 0x04, 0x9e, 0x03, 0x04, 0x21,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x01, 0x06, 0x12, 0x04, 0x9e, 0x03, 0x04, 0x14, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x01, 0x01, 0x12, 0x04, 0x9e, 0x03, 0x15, 0x1c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x29, 0x02, 0x01, 0x03, 0x12, 0x04, 0x9e, 0x03, 0x1f, 0x20, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x29, 0x02, 0x02, 0x12, 0x04, 0x9f, 0x03, 0x04, 0x2c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x29, 0x02, 0x02, 0x06, 0x12, 0x04, 0x9f, 0x03, 0x04, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29,
+    0x02, 0x02, 0x01, 0x12, 0x04, 0x9f, 0x03, 0x1a, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02,
+    0x02, 0x03, 0x12, 0x04, 0x9f, 0x03, 0x2a, 0x2b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x29, 0x02, 0x03,
+    0x12, 0x04, 0xa0, 0x03, 0x04, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x03, 0x06, 0x12,
+    0x04, 0xa0, 0x03, 0x04, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x03, 0x01, 0x12, 0x04,
+    0xa0, 0x03, 0x18, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x03, 0x03, 0x12, 0x04, 0xa0,
+    0x03, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x29, 0x02, 0x04, 0x12, 0x04, 0xa1, 0x03, 0x04,
+    0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x04, 0x06, 0x12, 0x04, 0xa1, 0x03, 0x04, 0x15,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x04, 0x01, 0x12, 0x04, 0xa1, 0x03, 0x16, 0x1f, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x29, 0x02, 0x04, 0x03, 0x12, 0x04, 0xa1, 0x03, 0x22, 0x23, 0x0a, 0x1e,
+    0x0a, 0x04, 0x04, 0x29, 0x02, 0x05, 0x12, 0x04, 0xa3, 0x03, 0x04, 0x23, 0x1a, 0x10, 0x20, 0x36,
+    0x20, 0x69, 0x73, 0x20, 0x72, 0x65, 0x73, 0x65, 0x72, 0x76, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x29, 0x02, 0x05, 0x06, 0x12, 0x04, 0xa3, 0x03, 0x04, 0x10, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x29, 0x02, 0x05, 0x01, 0x12, 0x04, 0xa3, 0x03, 0x11, 0x1e, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x29, 0x02, 0x05, 0x03, 0x12, 0x04, 0xa3, 0x03, 0x21, 0x22, 0x0a, 0x0c, 0x0a, 0x02, 0x04,
+    0x2a, 0x12, 0x06, 0xa7, 0x03, 0x00, 0xaa, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x2a, 0x01,
+    0x12, 0x04, 0xa7, 0x03, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2a, 0x02, 0x00, 0x12, 0x04,
+    0xa8, 0x03, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x00, 0x05, 0x12, 0x04, 0xa8,
+    0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x00, 0x01, 0x12, 0x04, 0xa8, 0x03,
+    0x08, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x00, 0x03, 0x12, 0x04, 0xa8, 0x03, 0x15,
+    0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2a, 0x02, 0x01, 0x12, 0x04, 0xa9, 0x03, 0x02, 0x16, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x2a, 0x02, 0x01, 0x05, 0x12, 0x04, 0xa9, 0x03, 0x02, 0x07, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x2a, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa9, 0x03, 0x08, 0x11, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x2a, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa9, 0x03, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x02,
+    0x04, 0x2b, 0x12, 0x06, 0xac, 0x03, 0x00, 0xb1, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x2b,
+    0x01, 0x12, 0x04, 0xac, 0x03, 0x08, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2b, 0x02, 0x00, 0x12,
+    0x04, 0xad, 0x03, 0x02, 0x21, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x00, 0x04, 0x12, 0x04,
+    0xad, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x00, 0x05, 0x12, 0x04, 0xad,
+    0x03, 0x0b, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x00, 0x01, 0x12, 0x04, 0xad, 0x03,
+    0x11, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x00, 0x03, 0x12, 0x04, 0xad, 0x03, 0x1f,
+    0x20, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2b, 0x02, 0x01, 0x12, 0x04, 0xae, 0x03, 0x02, 0x20, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x01, 0x04, 0x12, 0x04, 0xae, 0x03, 0x02, 0x0a, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x2b, 0x02, 0x01, 0x05, 0x12, 0x04, 0xae, 0x03, 0x0b, 0x10, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x2b, 0x02, 0x01, 0x01, 0x12, 0x04, 0xae, 0x03, 0x11, 0x1b, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x2b, 0x02, 0x01, 0x03, 0x12, 0x04, 0xae, 0x03, 0x1e, 0x1f, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x2b, 0x02, 0x02, 0x12, 0x04, 0xaf, 0x03, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02,
+    0x02, 0x05, 0x12, 0x04, 0xaf, 0x03, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0xaf, 0x03, 0x09, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x02, 0x03,
+    0x12, 0x04, 0xaf, 0x03, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2b, 0x02, 0x03, 0x12, 0x04,
+    0xb0, 0x03, 0x02, 0x29, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x03, 0x04, 0x12, 0x04, 0xb0,
+    0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x03, 0x05, 0x12, 0x04, 0xb0, 0x03,
+    0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x03, 0x01, 0x12, 0x04, 0xb0, 0x03, 0x12,
+    0x24, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2b, 0x02, 0x03, 0x03, 0x12, 0x04, 0xb0, 0x03, 0x27, 0x28,
+    0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x2c, 0x12, 0x06, 0xb3, 0x03, 0x00, 0xb7, 0x03, 0x01, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x2c, 0x01, 0x12, 0x04, 0xb3, 0x03, 0x08, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x2c, 0x02, 0x00, 0x12, 0x04, 0xb4, 0x03, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02,
+    0x00, 0x06, 0x12, 0x04, 0xb4, 0x03, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0xb4, 0x03, 0x13, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0xb4, 0x03, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2c, 0x02, 0x01, 0x12, 0x04,
+    0xb5, 0x03, 0x02, 0x31, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x01, 0x04, 0x12, 0x04, 0xb5,
+    0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x01, 0x05, 0x12, 0x04, 0xb5, 0x03,
+    0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x01, 0x01, 0x12, 0x04, 0xb5, 0x03, 0x12,
+    0x2c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2c, 0x02, 0x01, 0x03, 0x12, 0x04, 0xb5, 0x03, 0x2f, 0x30,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2c, 0x02, 0x02, 0x12, 0x04, 0xb6, 0x03, 0x02, 0x32, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x2c, 0x02, 0x02, 0x04, 0x12, 0x04, 0xb6, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x2c, 0x02, 0x02, 0x06, 0x12, 0x04, 0xb6, 0x03, 0x0b, 0x1b, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x2c, 0x02, 0x02, 0x01, 0x12, 0x04, 0xb6, 0x03, 0x1c, 0x2d, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x2c, 0x02, 0x02, 0x03, 0x12, 0x04, 0xb6, 0x03, 0x30, 0x31, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x2d,
+    0x12, 0x06, 0xb9, 0x03, 0x00, 0xbf, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x2d, 0x01, 0x12,
+    0x04, 0xb9, 0x03, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2d, 0x02, 0x00, 0x12, 0x04, 0xba,
+    0x03, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x00, 0x06, 0x12, 0x04, 0xba, 0x03,
+    0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x00, 0x01, 0x12, 0x04, 0xba, 0x03, 0x13,
+    0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x00, 0x03, 0x12, 0x04, 0xba, 0x03, 0x1c, 0x1d,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2d, 0x02, 0x01, 0x12, 0x04, 0xbb, 0x03, 0x02, 0x31, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x2d, 0x02, 0x01, 0x04, 0x12, 0x04, 0xbb, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x2d, 0x02, 0x01, 0x05, 0x12, 0x04, 0xbb, 0x03, 0x0b, 0x11, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x2d, 0x02, 0x01, 0x01, 0x12, 0x04, 0xbb, 0x03, 0x12, 0x2c, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x2d, 0x02, 0x01, 0x03, 0x12, 0x04, 0xbb, 0x03, 0x2f, 0x30, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2d,
+    0x02, 0x02, 0x12, 0x04, 0xbc, 0x03, 0x02, 0x32, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x02,
+    0x04, 0x12, 0x04, 0xbc, 0x03, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x02, 0x06,
+    0x12, 0x04, 0xbc, 0x03, 0x0b, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x02, 0x01, 0x12,
+    0x04, 0xbc, 0x03, 0x1c, 0x2d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x02, 0x03, 0x12, 0x04,
+    0xbc, 0x03, 0x30, 0x31, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2d, 0x02, 0x03, 0x12, 0x04, 0xbd, 0x03,
+    0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x03, 0x05, 0x12, 0x04, 0xbd, 0x03, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x03, 0x01, 0x12, 0x04, 0xbd, 0x03, 0x09, 0x1a,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2d, 0x02, 0x03, 0x03, 0x12, 0x04, 0xbd, 0x03, 0x1d, 0x1e, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x2d, 0x02, 0x04, 0x12, 0x04, 0xbe, 0x03, 0x02, 0x28, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x2d, 0x02, 0x04, 0x06, 0x12, 0x04, 0xbe, 0x03, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x2d, 0x02, 0x04, 0x01, 0x12, 0x04, 0xbe, 0x03, 0x13, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x2d, 0x02, 0x04, 0x03, 0x12, 0x04, 0xbe, 0x03, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x2e,
+    0x12, 0x06, 0xc1, 0x03, 0x00, 0xcc, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x2e, 0x01, 0x12,
+    0x04, 0xc1, 0x03, 0x08, 0x14, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x2e, 0x04, 0x00, 0x12, 0x06, 0xc2,
+    0x03, 0x02, 0xc8, 0x03, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2e, 0x04, 0x00, 0x01, 0x12, 0x04,
+    0xc2, 0x03, 0x07, 0x0b, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2e, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04,
+    0xc3, 0x03, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2e, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xc3, 0x03, 0x04, 0x14, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2e, 0x04, 0x00, 0x02, 0x00, 0x02,
+    0x12, 0x04, 0xc3, 0x03, 0x17, 0x18, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2e, 0x04, 0x00, 0x02, 0x01,
+    0x12, 0x04, 0xc4, 0x03, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2e, 0x04, 0x00, 0x02, 0x01,
+    0x01, 0x12, 0x04, 0xc4, 0x03, 0x04, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2e, 0x04, 0x00, 0x02,
+    0x01, 0x02, 0x12, 0x04, 0xc4, 0x03, 0x13, 0x14, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2e, 0x04, 0x00,
+    0x02, 0x02, 0x12, 0x04, 0xc5, 0x03, 0x04, 0x1d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2e, 0x04, 0x00,
+    0x02, 0x02, 0x01, 0x12, 0x04, 0xc5, 0x03, 0x04, 0x18, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2e, 0x04,
+    0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0xc5, 0x03, 0x1b, 0x1c, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2e,
+    0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0xc6, 0x03, 0x04, 0x1d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2e,
+    0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0xc6, 0x03, 0x04, 0x18, 0x0a, 0x0f, 0x0a, 0x07, 0x04,
+    0x2e, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04, 0xc6, 0x03, 0x1b, 0x1c, 0x0a, 0x0e, 0x0a, 0x06,
+    0x04, 0x2e, 0x04, 0x00, 0x02, 0x04, 0x12, 0x04, 0xc7, 0x03, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x2e, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12, 0x04, 0xc7, 0x03, 0x04, 0x10, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x2e, 0x04, 0x00, 0x02, 0x04, 0x02, 0x12, 0x04, 0xc7, 0x03, 0x13, 0x14, 0x0a, 0x0c,
+    0x0a, 0x04, 0x04, 0x2e, 0x02, 0x00, 0x12, 0x04, 0xca, 0x03, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x2e, 0x02, 0x00, 0x06, 0x12, 0x04, 0xca, 0x03, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x2e, 0x02, 0x00, 0x01, 0x12, 0x04, 0xca, 0x03, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2e,
+    0x02, 0x00, 0x03, 0x12, 0x04, 0xca, 0x03, 0x0e, 0x0f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2e, 0x02,
+    0x01, 0x12, 0x04, 0xcb, 0x03, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2e, 0x02, 0x01, 0x05,
+    0x12, 0x04, 0xcb, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2e, 0x02, 0x01, 0x01, 0x12,
+    0x04, 0xcb, 0x03, 0x08, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2e, 0x02, 0x01, 0x03, 0x12, 0x04,
+    0xcb, 0x03, 0x15, 0x16, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x2f, 0x12, 0x06, 0xce, 0x03, 0x00, 0xe6,
+    0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x2f, 0x01, 0x12, 0x04, 0xce, 0x03, 0x08, 0x14, 0x0a,
+    0x0e, 0x0a, 0x04, 0x04, 0x2f, 0x04, 0x00, 0x12, 0x06, 0xcf, 0x03, 0x02, 0xd6, 0x03, 0x03, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x04, 0x00, 0x01, 0x12, 0x04, 0xcf, 0x03, 0x07, 0x0b, 0x0a, 0x0e,
+    0x0a, 0x06, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0xd0, 0x03, 0x04, 0x19, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0xd0, 0x03, 0x04, 0x14, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0xd0, 0x03, 0x17, 0x18,
+    0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0xd1, 0x03, 0x04, 0x15,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0xd1, 0x03, 0x04,
+    0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0xd1, 0x03,
+    0x13, 0x14, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0xd2, 0x03,
+    0x04, 0x1d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0xd2,
+    0x03, 0x04, 0x18, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04,
+    0xd2, 0x03, 0x1b, 0x1c, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04,
+    0xd3, 0x03, 0x04, 0x16, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12,
+    0x04, 0xd3, 0x03, 0x04, 0x11, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x03, 0x02,
+    0x12, 0x04, 0xd3, 0x03, 0x14, 0x15, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x04,
+    0x12, 0x04, 0xd4, 0x03, 0x04, 0x15, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00, 0x02, 0x04,
+    0x01, 0x12, 0x04, 0xd4, 0x03, 0x04, 0x10, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00, 0x02,
+    0x04, 0x02, 0x12, 0x04, 0xd4, 0x03, 0x13, 0x14, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2f, 0x04, 0x00,
+    0x02, 0x05, 0x12, 0x04, 0xd5, 0x03, 0x04, 0x1d, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04, 0x00,
+    0x02, 0x05, 0x01, 0x12, 0x04, 0xd5, 0x03, 0x04, 0x18, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x2f, 0x04,
+    0x00, 0x02, 0x05, 0x02, 0x12, 0x04, 0xd5, 0x03, 0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2f,
+    0x02, 0x00, 0x12, 0x04, 0xd8, 0x03, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x00,
+    0x06, 0x12, 0x04, 0xd8, 0x03, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0xd8, 0x03, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0xd8, 0x03, 0x0e, 0x0f, 0x0a, 0x64, 0x0a, 0x04, 0x04, 0x2f, 0x02, 0x01, 0x12, 0x04, 0xdc,
+    0x03, 0x02, 0x2a, 0x1a, 0x56, 0x20, 0x44, 0x65, 0x70, 0x72, 0x65, 0x63, 0x61, 0x74, 0x65, 0x64,
+    0x3a, 0x20, 0x75, 0x73, 0x65, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x61, 0x74, 0x75, 0x72, 0x65, 0x5f,
+    0x76, 0x61, 0x72, 0x69, 0x61, 0x6e, 0x74, 0x20, 0x69, 0x6e, 0x73, 0x74, 0x65, 0x61, 0x64, 0x2e,
+    0x0a, 0x20, 0x4e, 0x6f, 0x74, 0x65, 0x3a, 0x20, 0x3e, 0x3d, 0x20, 0x31, 0x2e, 0x31, 0x30, 0x2c,
+    0x20, 0x74, 0x68, 0x69, 0x73, 0x20, 0x66, 0x69, 0x65, 0x6c, 0x64, 0x20, 0x69, 0x73, 0x20, 0x64,
+    0x65, 0x70, 0x72, 0x65, 0x63, 0x61, 0x74, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x2f, 0x02, 0x01, 0x05, 0x12, 0x04, 0xdc, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f,
+    0x02, 0x01, 0x01, 0x12, 0x04, 0xdc, 0x03, 0x08, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02,
+    0x01, 0x03, 0x12, 0x04, 0xdc, 0x03, 0x14, 0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x01,
+    0x08, 0x12, 0x04, 0xdc, 0x03, 0x16, 0x29, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x2f, 0x02, 0x01, 0x08,
+    0x03, 0x12, 0x04, 0xdc, 0x03, 0x17, 0x28, 0x0a, 0x23, 0x0a, 0x04, 0x04, 0x2f, 0x08, 0x00, 0x12,
+    0x06, 0xdf, 0x03, 0x02, 0xe5, 0x03, 0x03, 0x1a, 0x13, 0x20, 0x53, 0x75, 0x70, 0x70, 0x6f, 0x72,
+    0x74, 0x3a, 0x20, 0x3e, 0x3d, 0x20, 0x31, 0x2e, 0x31, 0x30, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x2f, 0x08, 0x00, 0x01, 0x12, 0x04, 0xdf, 0x03, 0x08, 0x19, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x2f, 0x02, 0x02, 0x12, 0x04, 0xe0, 0x03, 0x04, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02,
+    0x02, 0x06, 0x12, 0x04, 0xe0, 0x03, 0x04, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x02,
+    0x01, 0x12, 0x04, 0xe0, 0x03, 0x0c, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x02, 0x03,
+    0x12, 0x04, 0xe0, 0x03, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2f, 0x02, 0x03, 0x12, 0x04,
+    0xe1, 0x03, 0x04, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x03, 0x06, 0x12, 0x04, 0xe1,
+    0x03, 0x04, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x03, 0x01, 0x12, 0x04, 0xe1, 0x03,
+    0x13, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x03, 0x03, 0x12, 0x04, 0xe1, 0x03, 0x25,
+    0x26, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2f, 0x02, 0x04, 0x12, 0x04, 0xe2, 0x03, 0x04, 0x1a, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x04, 0x06, 0x12, 0x04, 0xe2, 0x03, 0x04, 0x0c, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x2f, 0x02, 0x04, 0x01, 0x12, 0x04, 0xe2, 0x03, 0x0d, 0x15, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x2f, 0x02, 0x04, 0x03, 0x12, 0x04, 0xe2, 0x03, 0x18, 0x19, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x2f, 0x02, 0x05, 0x12, 0x04, 0xe3, 0x03, 0x04, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f,
+    0x02, 0x05, 0x06, 0x12, 0x04, 0xe3, 0x03, 0x04, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02,
+    0x05, 0x01, 0x12, 0x04, 0xe3, 0x03, 0x0c, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x05,
+    0x03, 0x12, 0x04, 0xe3, 0x03, 0x16, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x2f, 0x02, 0x06, 0x12,
+    0x04, 0xe4, 0x03, 0x04, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x06, 0x06, 0x12, 0x04,
+    0xe4, 0x03, 0x04, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x06, 0x01, 0x12, 0x04, 0xe4,
+    0x03, 0x13, 0x22, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x2f, 0x02, 0x06, 0x03, 0x12, 0x04, 0xe4, 0x03,
+    0x25, 0x26, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x30, 0x12, 0x06, 0xe8, 0x03, 0x00, 0xea, 0x03, 0x01,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x30, 0x01, 0x12, 0x04, 0xe8, 0x03, 0x08, 0x0f, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x30, 0x02, 0x00, 0x12, 0x04, 0xe9, 0x03, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x30, 0x02, 0x00, 0x05, 0x12, 0x04, 0xe9, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30,
+    0x02, 0x00, 0x01, 0x12, 0x04, 0xe9, 0x03, 0x08, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x30, 0x02,
+    0x00, 0x03, 0x12, 0x04, 0xe9, 0x03, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x31, 0x12, 0x06,
+    0xec, 0x03, 0x00, 0xee, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x31, 0x01, 0x12, 0x04, 0xec,
+    0x03, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x31, 0x02, 0x00, 0x12, 0x04, 0xed, 0x03, 0x02,
+    0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x31, 0x02, 0x00, 0x05, 0x12, 0x04, 0xed, 0x03, 0x02, 0x07,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x31, 0x02, 0x00, 0x01, 0x12, 0x04, 0xed, 0x03, 0x08, 0x11, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x31, 0x02, 0x00, 0x03, 0x12, 0x04, 0xed, 0x03, 0x14, 0x15, 0x0a, 0x0c,
+    0x0a, 0x02, 0x04, 0x32, 0x12, 0x06, 0xf0, 0x03, 0x00, 0xf2, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03,
+    0x04, 0x32, 0x01, 0x12, 0x04, 0xf0, 0x03, 0x08, 0x10, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x32, 0x02,
+    0x00, 0x12, 0x04, 0xf1, 0x03, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x00, 0x05,
+    0x12, 0x04, 0xf1, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x00, 0x01, 0x12,
+    0x04, 0xf1, 0x03, 0x08, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x32, 0x02, 0x00, 0x03, 0x12, 0x04,
+    0xf1, 0x03, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x33, 0x12, 0x06, 0xf4, 0x03, 0x00, 0xf6,
+    0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x33, 0x01, 0x12, 0x04, 0xf4, 0x03, 0x08, 0x0f, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x33, 0x02, 0x00, 0x12, 0x04, 0xf5, 0x03, 0x02, 0x16, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x33, 0x02, 0x00, 0x05, 0x12, 0x04, 0xf5, 0x03, 0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x33, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf5, 0x03, 0x08, 0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x33, 0x02, 0x00, 0x03, 0x12, 0x04, 0xf5, 0x03, 0x14, 0x15, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x34,
+    0x12, 0x06, 0xf8, 0x03, 0x00, 0xfa, 0x03, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x34, 0x01, 0x12,
+    0x04, 0xf8, 0x03, 0x08, 0x16, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x34, 0x02, 0x00, 0x12, 0x04, 0xf9,
+    0x03, 0x02, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34, 0x02, 0x00, 0x05, 0x12, 0x04, 0xf9, 0x03,
+    0x02, 0x07, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34, 0x02, 0x00, 0x01, 0x12, 0x04, 0xf9, 0x03, 0x08,
+    0x11, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x34, 0x02, 0x00, 0x03, 0x12, 0x04, 0xf9, 0x03, 0x14, 0x15,
+    0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x35, 0x12, 0x06, 0xfc, 0x03, 0x00, 0xff, 0x03, 0x01, 0x0a, 0x0b,
+    0x0a, 0x03, 0x04, 0x35, 0x01, 0x12, 0x04, 0xfc, 0x03, 0x08, 0x1a, 0x0a, 0x0c, 0x0a, 0x04, 0x04,
+    0x35, 0x02, 0x00, 0x12, 0x04, 0xfd, 0x03, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x35, 0x02,
+    0x00, 0x06, 0x12, 0x04, 0xfd, 0x03, 0x02, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x35, 0x02, 0x00,
+    0x01, 0x12, 0x04, 0xfd, 0x03, 0x0f, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x35, 0x02, 0x00, 0x03,
+    0x12, 0x04, 0xfd, 0x03, 0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x35, 0x02, 0x01, 0x12, 0x04,
+    0xfe, 0x03, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x35, 0x02, 0x01, 0x06, 0x12, 0x04, 0xfe,
+    0x03, 0x02, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x35, 0x02, 0x01, 0x01, 0x12, 0x04, 0xfe, 0x03,
+    0x0f, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x35, 0x02, 0x01, 0x03, 0x12, 0x04, 0xfe, 0x03, 0x1b,
+    0x1c, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x36, 0x12, 0x06, 0x81, 0x04, 0x00, 0x84, 0x04, 0x01, 0x0a,
+    0x0b, 0x0a, 0x03, 0x04, 0x36, 0x01, 0x12, 0x04, 0x81, 0x04, 0x08, 0x18, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x36, 0x02, 0x00, 0x12, 0x04, 0x82, 0x04, 0x02, 0x13, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x36,
+    0x02, 0x00, 0x05, 0x12, 0x04, 0x82, 0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x36, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0x82, 0x04, 0x09, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x36, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0x82, 0x04, 0x11, 0x12, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x36, 0x02, 0x01, 0x12,
+    0x04, 0x83, 0x04, 0x02, 0x1d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x36, 0x02, 0x01, 0x06, 0x12, 0x04,
+    0x83, 0x04, 0x02, 0x0e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x36, 0x02, 0x01, 0x01, 0x12, 0x04, 0x83,
+    0x04, 0x0f, 0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x36, 0x02, 0x01, 0x03, 0x12, 0x04, 0x83, 0x04,
+    0x1b, 0x1c, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x37, 0x12, 0x06, 0x86, 0x04, 0x00, 0x8a, 0x04, 0x01,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x37, 0x01, 0x12, 0x04, 0x86, 0x04, 0x08, 0x19, 0x0a, 0x0c, 0x0a,
+    0x04, 0x04, 0x37, 0x02, 0x00, 0x12, 0x04, 0x87, 0x04, 0x02, 0x28, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x37, 0x02, 0x00, 0x04, 0x12, 0x04, 0x87, 0x04, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x37,
+    0x02, 0x00, 0x06, 0x12, 0x04, 0x87, 0x04, 0x0b, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x37, 0x02,
+    0x00, 0x01, 0x12, 0x04, 0x87, 0x04, 0x18, 0x23, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x37, 0x02, 0x00,
+    0x03, 0x12, 0x04, 0x87, 0x04, 0x26, 0x27, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x37, 0x02, 0x01, 0x12,
+    0x04, 0x88, 0x04, 0x02, 0x2b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x37, 0x02, 0x01, 0x04, 0x12, 0x04,
+    0x88, 0x04, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x37, 0x02, 0x01, 0x06, 0x12, 0x04, 0x88,
+    0x04, 0x0b, 0x1b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x37, 0x02, 0x01, 0x01, 0x12, 0x04, 0x88, 0x04,
+    0x1c, 0x26, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x37, 0x02, 0x01, 0x03, 0x12, 0x04, 0x88, 0x04, 0x29,
+    0x2a, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x37, 0x02, 0x02, 0x12, 0x04, 0x89, 0x04, 0x02, 0x21, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x37, 0x02, 0x02, 0x05, 0x12, 0x04, 0x89, 0x04, 0x02, 0x08, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x37, 0x02, 0x02, 0x01, 0x12, 0x04, 0x89, 0x04, 0x09, 0x1c, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x37, 0x02, 0x02, 0x03, 0x12, 0x04, 0x89, 0x04, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x02,
+    0x04, 0x38, 0x12, 0x06, 0x8c, 0x04, 0x00, 0x8e, 0x04, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x38,
+    0x01, 0x12, 0x04, 0x8c, 0x04, 0x08, 0x14, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x38, 0x02, 0x00, 0x12,
+    0x04, 0x8d, 0x04, 0x02, 0x1e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x38, 0x02, 0x00, 0x06, 0x12, 0x04,
+    0x8d, 0x04, 0x02, 0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x38, 0x02, 0x00, 0x01, 0x12, 0x04, 0x8d,
+    0x04, 0x13, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x38, 0x02, 0x00, 0x03, 0x12, 0x04, 0x8d, 0x04,
+    0x1c, 0x1d, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x39, 0x12, 0x06, 0x90, 0x04, 0x00, 0xa3, 0x04, 0x01,
+    0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x39, 0x01, 0x12, 0x04, 0x90, 0x04, 0x08, 0x18, 0x0a, 0x0e, 0x0a,
+    0x04, 0x04, 0x39, 0x04, 0x00, 0x12, 0x06, 0x91, 0x04, 0x02, 0x99, 0x04, 0x03, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x39, 0x04, 0x00, 0x01, 0x12, 0x04, 0x91, 0x04, 0x07, 0x0b, 0x0a, 0x0e, 0x0a, 0x06,
+    0x04, 0x39, 0x04, 0x00, 0x02, 0x00, 0x12, 0x04, 0x92, 0x04, 0x04, 0x19, 0x0a, 0x0f, 0x0a, 0x07,
+    0x04, 0x39, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x04, 0x92, 0x04, 0x04, 0x14, 0x0a, 0x0f, 0x0a,
+    0x07, 0x04, 0x39, 0x04, 0x00, 0x02, 0x00, 0x02, 0x12, 0x04, 0x92, 0x04, 0x17, 0x18, 0x0a, 0x0e,
+    0x0a, 0x06, 0x04, 0x39, 0x04, 0x00, 0x02, 0x01, 0x12, 0x04, 0x93, 0x04, 0x04, 0x15, 0x0a, 0x0f,
+    0x0a, 0x07, 0x04, 0x39, 0x04, 0x00, 0x02, 0x01, 0x01, 0x12, 0x04, 0x93, 0x04, 0x04, 0x10, 0x0a,
+    0x0f, 0x0a, 0x07, 0x04, 0x39, 0x04, 0x00, 0x02, 0x01, 0x02, 0x12, 0x04, 0x93, 0x04, 0x13, 0x14,
+    0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x39, 0x04, 0x00, 0x02, 0x02, 0x12, 0x04, 0x94, 0x04, 0x04, 0x1b,
+    0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x39, 0x04, 0x00, 0x02, 0x02, 0x01, 0x12, 0x04, 0x94, 0x04, 0x04,
+    0x16, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x39, 0x04, 0x00, 0x02, 0x02, 0x02, 0x12, 0x04, 0x94, 0x04,
+    0x19, 0x1a, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x39, 0x04, 0x00, 0x02, 0x03, 0x12, 0x04, 0x95, 0x04,
+    0x04, 0x18, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x39, 0x04, 0x00, 0x02, 0x03, 0x01, 0x12, 0x04, 0x95,
+    0x04, 0x04, 0x13, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x39, 0x04, 0x00, 0x02, 0x03, 0x02, 0x12, 0x04,
+    0x95, 0x04, 0x16, 0x17, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x39, 0x04, 0x00, 0x02, 0x04, 0x12, 0x04,
+    0x96, 0x04, 0x04, 0x17, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x39, 0x04, 0x00, 0x02, 0x04, 0x01, 0x12,
+    0x04, 0x96, 0x04, 0x04, 0x12, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x39, 0x04, 0x00, 0x02, 0x04, 0x02,
+    0x12, 0x04, 0x96, 0x04, 0x15, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x04, 0x00, 0x04, 0x12,
+    0x04, 0x98, 0x04, 0x04, 0x0f, 0x0a, 0x0e, 0x0a, 0x06, 0x04, 0x39, 0x04, 0x00, 0x04, 0x00, 0x12,
+    0x04, 0x98, 0x04, 0x0d, 0x0e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x39, 0x04, 0x00, 0x04, 0x00, 0x01,
+    0x12, 0x04, 0x98, 0x04, 0x0d, 0x0e, 0x0a, 0x0f, 0x0a, 0x07, 0x04, 0x39, 0x04, 0x00, 0x04, 0x00,
+    0x02, 0x12, 0x04, 0x98, 0x04, 0x0d, 0x0e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x39, 0x02, 0x00, 0x12,
+    0x04, 0x9b, 0x04, 0x02, 0x10, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02, 0x00, 0x06, 0x12, 0x04,
+    0x9b, 0x04, 0x02, 0x06, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02, 0x00, 0x01, 0x12, 0x04, 0x9b,
+    0x04, 0x07, 0x0b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02, 0x00, 0x03, 0x12, 0x04, 0x9b, 0x04,
+    0x0e, 0x0f, 0x0a, 0x0e, 0x0a, 0x04, 0x04, 0x39, 0x08, 0x00, 0x12, 0x06, 0x9c, 0x04, 0x02, 0xa2,
+    0x04, 0x03, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x08, 0x00, 0x01, 0x12, 0x04, 0x9c, 0x04, 0x08,
+    0x11, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x39, 0x02, 0x01, 0x12, 0x04, 0x9d, 0x04, 0x04, 0x21, 0x0a,
+    0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02, 0x01, 0x06, 0x12, 0x04, 0x9d, 0x04, 0x04, 0x14, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x39, 0x02, 0x01, 0x01, 0x12, 0x04, 0x9d, 0x04, 0x15, 0x1c, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x39, 0x02, 0x01, 0x03, 0x12, 0x04, 0x9d, 0x04, 0x1f, 0x20, 0x0a, 0x0c, 0x0a, 0x04,
+    0x04, 0x39, 0x02, 0x02, 0x12, 0x04, 0x9e, 0x04, 0x04, 0x2c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39,
+    0x02, 0x02, 0x06, 0x12, 0x04, 0x9e, 0x04, 0x04, 0x19, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02,
+    0x02, 0x01, 0x12, 0x04, 0x9e, 0x04, 0x1a, 0x27, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02, 0x02,
+    0x03, 0x12, 0x04, 0x9e, 0x04, 0x2a, 0x2b, 0x0a, 0x1e, 0x0a, 0x04, 0x04, 0x39, 0x02, 0x03, 0x12,
+    0x04, 0xa0, 0x04, 0x04, 0x30, 0x1a, 0x10, 0x20, 0x34, 0x20, 0x69, 0x73, 0x20, 0x72, 0x65, 0x73,
+    0x65, 0x72, 0x76, 0x65, 0x64, 0x2e, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02, 0x03, 0x06,
+    0x12, 0x04, 0xa0, 0x04, 0x04, 0x16, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02, 0x03, 0x01, 0x12,
+    0x04, 0xa0, 0x04, 0x17, 0x2b, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02, 0x03, 0x03, 0x12, 0x04,
+    0xa0, 0x04, 0x2e, 0x2f, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x39, 0x02, 0x04, 0x12, 0x04, 0xa1, 0x04,
+    0x04, 0x2e, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02, 0x04, 0x06, 0x12, 0x04, 0xa1, 0x04, 0x04,
+    0x15, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02, 0x04, 0x01, 0x12, 0x04, 0xa1, 0x04, 0x16, 0x29,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x39, 0x02, 0x04, 0x03, 0x12, 0x04, 0xa1, 0x04, 0x2c, 0x2d, 0x0a,
+    0x0c, 0x0a, 0x02, 0x04, 0x3a, 0x12, 0x06, 0xa5, 0x04, 0x00, 0xa9, 0x04, 0x01, 0x0a, 0x0b, 0x0a,
+    0x03, 0x04, 0x3a, 0x01, 0x12, 0x04, 0xa5, 0x04, 0x08, 0x1b, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x3a,
+    0x02, 0x00, 0x12, 0x04, 0xa6, 0x04, 0x02, 0x1f, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3a, 0x02, 0x00,
+    0x05, 0x12, 0x04, 0xa6, 0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3a, 0x02, 0x00, 0x01,
+    0x12, 0x04, 0xa6, 0x04, 0x09, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3a, 0x02, 0x00, 0x03, 0x12,
+    0x04, 0xa6, 0x04, 0x1d, 0x1e, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x3a, 0x02, 0x01, 0x12, 0x04, 0xa7,
+    0x04, 0x02, 0x2d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3a, 0x02, 0x01, 0x04, 0x12, 0x04, 0xa7, 0x04,
+    0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3a, 0x02, 0x01, 0x06, 0x12, 0x04, 0xa7, 0x04, 0x0b,
+    0x18, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3a, 0x02, 0x01, 0x01, 0x12, 0x04, 0xa7, 0x04, 0x19, 0x28,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3a, 0x02, 0x01, 0x03, 0x12, 0x04, 0xa7, 0x04, 0x2b, 0x2c, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x3a, 0x02, 0x02, 0x12, 0x04, 0xa8, 0x04, 0x02, 0x32, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x3a, 0x02, 0x02, 0x04, 0x12, 0x04, 0xa8, 0x04, 0x02, 0x0a, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x3a, 0x02, 0x02, 0x06, 0x12, 0x04, 0xa8, 0x04, 0x0b, 0x1a, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x3a, 0x02, 0x02, 0x01, 0x12, 0x04, 0xa8, 0x04, 0x1b, 0x2d, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3a,
+    0x02, 0x02, 0x03, 0x12, 0x04, 0xa8, 0x04, 0x30, 0x31, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x3b, 0x12,
+    0x06, 0xab, 0x04, 0x00, 0xae, 0x04, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x3b, 0x01, 0x12, 0x04,
+    0xab, 0x04, 0x08, 0x15, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x3b, 0x02, 0x00, 0x12, 0x04, 0xac, 0x04,
+    0x02, 0x1c, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3b, 0x02, 0x00, 0x05, 0x12, 0x04, 0xac, 0x04, 0x02,
+    0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3b, 0x02, 0x00, 0x01, 0x12, 0x04, 0xac, 0x04, 0x09, 0x17,
+    0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3b, 0x02, 0x00, 0x03, 0x12, 0x04, 0xac, 0x04, 0x1a, 0x1b, 0x0a,
+    0x0c, 0x0a, 0x04, 0x04, 0x3b, 0x02, 0x01, 0x12, 0x04, 0xad, 0x04, 0x02, 0x19, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x3b, 0x02, 0x01, 0x05, 0x12, 0x04, 0xad, 0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x3b, 0x02, 0x01, 0x01, 0x12, 0x04, 0xad, 0x04, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05, 0x04,
+    0x3b, 0x02, 0x01, 0x03, 0x12, 0x04, 0xad, 0x04, 0x17, 0x18, 0x0a, 0x0c, 0x0a, 0x02, 0x04, 0x3c,
+    0x12, 0x06, 0xb0, 0x04, 0x00, 0xb3, 0x04, 0x01, 0x0a, 0x0b, 0x0a, 0x03, 0x04, 0x3c, 0x01, 0x12,
+    0x04, 0xb0, 0x04, 0x08, 0x17, 0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x3c, 0x02, 0x00, 0x12, 0x04, 0xb1,
+    0x04, 0x02, 0x17, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3c, 0x02, 0x00, 0x05, 0x12, 0x04, 0xb1, 0x04,
+    0x02, 0x08, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3c, 0x02, 0x00, 0x01, 0x12, 0x04, 0xb1, 0x04, 0x09,
+    0x12, 0x0a, 0x0d, 0x0a, 0x05, 0x04, 0x3c, 0x02, 0x00, 0x03, 0x12, 0x04, 0xb1, 0x04, 0x15, 0x16,
+    0x0a, 0x0c, 0x0a, 0x04, 0x04, 0x3c, 0x02, 0x01, 0x12, 0x04, 0xb2, 0x04, 0x02, 0x19, 0x0a, 0x0d,
+    0x0a, 0x05, 0x04, 0x3c, 0x02, 0x01, 0x05, 0x12, 0x04, 0xb2, 0x04, 0x02, 0x08, 0x0a, 0x0d, 0x0a,
+    0x05, 0x04, 0x3c, 0x02, 0x01, 0x01, 0x12, 0x04, 0xb2, 0x04, 0x09, 0x14, 0x0a, 0x0d, 0x0a, 0x05,
+    0x04, 0x3c, 0x02, 0x01, 0x03, 0x12, 0x04, 0xb2, 0x04, 0x17, 0x18, 0x62, 0x06, 0x70, 0x72, 0x6f,
+    0x74, 0x6f, 0x33,
 ];
 include!("aptos.transaction.v1.serde.rs");
 // @@protoc_insertion_point(module)
@@ -476,6 +476,9 @@ impl serde::Serialize for AnySignature {
                 any_signature::SignatureVariant::Keyless(v) => {
                     struct_ser.serialize_field("keyless", v)?;
                 }
+                any_signature::SignatureVariant::Secp256r1Ecdsa(v) => {
+                    struct_ser.serialize_field("secp256r1Ecdsa", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -495,6 +498,8 @@ impl<'de> serde::Deserialize<'de> for AnySignature {
             "secp256k1Ecdsa",
             "webauthn",
             "keyless",
+            "secp256r1_ecdsa",
+            "secp256r1Ecdsa",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -505,6 +510,7 @@ impl<'de> serde::Deserialize<'de> for AnySignature {
             Secp256k1Ecdsa,
             Webauthn,
             Keyless,
+            Secp256r1Ecdsa,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -532,6 +538,7 @@ impl<'de> serde::Deserialize<'de> for AnySignature {
                             "secp256k1Ecdsa" | "secp256k1_ecdsa" => Ok(GeneratedField::Secp256k1Ecdsa),
                             "webauthn" => Ok(GeneratedField::Webauthn),
                             "keyless" => Ok(GeneratedField::Keyless),
+                            "secp256r1Ecdsa" | "secp256r1_ecdsa" => Ok(GeneratedField::Secp256r1Ecdsa),
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -596,6 +603,13 @@ impl<'de> serde::Deserialize<'de> for AnySignature {
                                 return Err(serde::de::Error::duplicate_field("keyless"));
                             }
                             signature_variant__ = map.next_value::<::std::option::Option<_>>()?.map(any_signature::SignatureVariant::Keyless)
+;
+                        }
+                        GeneratedField::Secp256r1Ecdsa => {
+                            if signature_variant__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("secp256r1Ecdsa"));
+                            }
+                            signature_variant__ = map.next_value::<::std::option::Option<_>>()?.map(any_signature::SignatureVariant::Secp256r1Ecdsa)
 ;
                         }
                     }
@@ -622,6 +636,7 @@ impl serde::Serialize for any_signature::Type {
             Self::Secp256k1Ecdsa => "TYPE_SECP256K1_ECDSA",
             Self::Webauthn => "TYPE_WEBAUTHN",
             Self::Keyless => "TYPE_KEYLESS",
+            Self::Secp256r1Ecdsa => "TYPE_SECP256R1_ECDSA",
         };
         serializer.serialize_str(variant)
     }
@@ -638,6 +653,7 @@ impl<'de> serde::Deserialize<'de> for any_signature::Type {
             "TYPE_SECP256K1_ECDSA",
             "TYPE_WEBAUTHN",
             "TYPE_KEYLESS",
+            "TYPE_SECP256R1_ECDSA",
         ];
 
         struct GeneratedVisitor;
@@ -685,6 +701,7 @@ impl<'de> serde::Deserialize<'de> for any_signature::Type {
                     "TYPE_SECP256K1_ECDSA" => Ok(any_signature::Type::Secp256k1Ecdsa),
                     "TYPE_WEBAUTHN" => Ok(any_signature::Type::Webauthn),
                     "TYPE_KEYLESS" => Ok(any_signature::Type::Keyless),
+                    "TYPE_SECP256R1_ECDSA" => Ok(any_signature::Type::Secp256r1Ecdsa),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -5859,6 +5876,99 @@ impl<'de> serde::Deserialize<'de> for Secp256k1Ecdsa {
         deserializer.deserialize_struct("aptos.transaction.v1.Secp256k1Ecdsa", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for Secp256r1Ecdsa {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.signature.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("aptos.transaction.v1.Secp256r1Ecdsa", len)?;
+        if !self.signature.is_empty() {
+            struct_ser.serialize_field("signature", pbjson::private::base64::encode(&self.signature).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Secp256r1Ecdsa {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "signature",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Signature,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "signature" => Ok(GeneratedField::Signature),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Secp256r1Ecdsa;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct aptos.transaction.v1.Secp256r1Ecdsa")
+            }
+
+            fn visit_map<V>(self, mut map: V) -> std::result::Result<Secp256r1Ecdsa, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut signature__ = None;
+                while let Some(k) = map.next_key()? {
+                    match k {
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
+                            }
+                            signature__ =
+                                Some(map.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(Secp256r1Ecdsa {
+                    signature: signature__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("aptos.transaction.v1.Secp256r1Ecdsa", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for Signature {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -934,6 +934,7 @@ export interface AnySignature {
   secp256k1Ecdsa?: Secp256k1Ecdsa | undefined;
   webauthn?: WebAuthn | undefined;
   keyless?: Keyless | undefined;
+  secp256r1Ecdsa?: Secp256r1Ecdsa | undefined;
 }
 
 export enum AnySignature_Type {
@@ -942,6 +943,7 @@ export enum AnySignature_Type {
   TYPE_SECP256K1_ECDSA = 2,
   TYPE_WEBAUTHN = 3,
   TYPE_KEYLESS = 4,
+  TYPE_SECP256R1_ECDSA = 5,
   UNRECOGNIZED = -1,
 }
 
@@ -962,6 +964,9 @@ export function anySignature_TypeFromJSON(object: any): AnySignature_Type {
     case 4:
     case "TYPE_KEYLESS":
       return AnySignature_Type.TYPE_KEYLESS;
+    case 5:
+    case "TYPE_SECP256R1_ECDSA":
+      return AnySignature_Type.TYPE_SECP256R1_ECDSA;
     case -1:
     case "UNRECOGNIZED":
     default:
@@ -981,6 +986,8 @@ export function anySignature_TypeToJSON(object: AnySignature_Type): string {
       return "TYPE_WEBAUTHN";
     case AnySignature_Type.TYPE_KEYLESS:
       return "TYPE_KEYLESS";
+    case AnySignature_Type.TYPE_SECP256R1_ECDSA:
+      return "TYPE_SECP256R1_ECDSA";
     case AnySignature_Type.UNRECOGNIZED:
     default:
       return "UNRECOGNIZED";
@@ -1003,6 +1010,10 @@ export interface Keyless {
   signature?: Uint8Array | undefined;
 }
 
+export interface Secp256r1Ecdsa {
+  signature?: Uint8Array | undefined;
+}
+
 export interface SingleKeySignature {
   publicKey?: AnyPublicKey | undefined;
   signature?: AnySignature | undefined;
@@ -7755,6 +7766,7 @@ function createBaseAnySignature(): AnySignature {
     secp256k1Ecdsa: undefined,
     webauthn: undefined,
     keyless: undefined,
+    secp256r1Ecdsa: undefined,
   };
 }
 
@@ -7778,6 +7790,9 @@ export const AnySignature = {
     if (message.keyless !== undefined) {
       Keyless.encode(message.keyless, writer.uint32(50).fork()).ldelim();
     }
+    if (message.secp256r1Ecdsa !== undefined) {
+      Secp256r1Ecdsa.encode(message.secp256r1Ecdsa, writer.uint32(58).fork()).ldelim();
+    }
     return writer;
   },
 
@@ -7830,6 +7845,13 @@ export const AnySignature = {
 
           message.keyless = Keyless.decode(reader, reader.uint32());
           continue;
+        case 7:
+          if (tag !== 58) {
+            break;
+          }
+
+          message.secp256r1Ecdsa = Secp256r1Ecdsa.decode(reader, reader.uint32());
+          continue;
       }
       if ((tag & 7) === 4 || tag === 0) {
         break;
@@ -7879,6 +7901,7 @@ export const AnySignature = {
       secp256k1Ecdsa: isSet(object.secp256k1Ecdsa) ? Secp256k1Ecdsa.fromJSON(object.secp256k1Ecdsa) : undefined,
       webauthn: isSet(object.webauthn) ? WebAuthn.fromJSON(object.webauthn) : undefined,
       keyless: isSet(object.keyless) ? Keyless.fromJSON(object.keyless) : undefined,
+      secp256r1Ecdsa: isSet(object.secp256r1Ecdsa) ? Secp256r1Ecdsa.fromJSON(object.secp256r1Ecdsa) : undefined,
     };
   },
 
@@ -7902,6 +7925,9 @@ export const AnySignature = {
     if (message.keyless !== undefined) {
       obj.keyless = Keyless.toJSON(message.keyless);
     }
+    if (message.secp256r1Ecdsa !== undefined) {
+      obj.secp256r1Ecdsa = Secp256r1Ecdsa.toJSON(message.secp256r1Ecdsa);
+    }
     return obj;
   },
 
@@ -7924,6 +7950,9 @@ export const AnySignature = {
     message.keyless = (object.keyless !== undefined && object.keyless !== null)
       ? Keyless.fromPartial(object.keyless)
       : undefined;
+    message.secp256r1Ecdsa = (object.secp256r1Ecdsa !== undefined && object.secp256r1Ecdsa !== null)
+      ? Secp256r1Ecdsa.fromPartial(object.secp256r1Ecdsa)
+      : undefined;
     return message;
   },
 };
@@ -8284,6 +8313,95 @@ export const Keyless = {
   },
 };
 
+function createBaseSecp256r1Ecdsa(): Secp256r1Ecdsa {
+  return { signature: new Uint8Array(0) };
+}
+
+export const Secp256r1Ecdsa = {
+  encode(message: Secp256r1Ecdsa, writer: _m0.Writer = _m0.Writer.create()): _m0.Writer {
+    if (message.signature !== undefined && message.signature.length !== 0) {
+      writer.uint32(10).bytes(message.signature);
+    }
+    return writer;
+  },
+
+  decode(input: _m0.Reader | Uint8Array, length?: number): Secp256r1Ecdsa {
+    const reader = input instanceof _m0.Reader ? input : _m0.Reader.create(input);
+    let end = length === undefined ? reader.len : reader.pos + length;
+    const message = createBaseSecp256r1Ecdsa();
+    while (reader.pos < end) {
+      const tag = reader.uint32();
+      switch (tag >>> 3) {
+        case 1:
+          if (tag !== 10) {
+            break;
+          }
+
+          message.signature = reader.bytes();
+          continue;
+      }
+      if ((tag & 7) === 4 || tag === 0) {
+        break;
+      }
+      reader.skipType(tag & 7);
+    }
+    return message;
+  },
+
+  // encodeTransform encodes a source of message objects.
+  // Transform<Secp256r1Ecdsa, Uint8Array>
+  async *encodeTransform(
+    source: AsyncIterable<Secp256r1Ecdsa | Secp256r1Ecdsa[]> | Iterable<Secp256r1Ecdsa | Secp256r1Ecdsa[]>,
+  ): AsyncIterable<Uint8Array> {
+    for await (const pkt of source) {
+      if (globalThis.Array.isArray(pkt)) {
+        for (const p of (pkt as any)) {
+          yield* [Secp256r1Ecdsa.encode(p).finish()];
+        }
+      } else {
+        yield* [Secp256r1Ecdsa.encode(pkt as any).finish()];
+      }
+    }
+  },
+
+  // decodeTransform decodes a source of encoded messages.
+  // Transform<Uint8Array, Secp256r1Ecdsa>
+  async *decodeTransform(
+    source: AsyncIterable<Uint8Array | Uint8Array[]> | Iterable<Uint8Array | Uint8Array[]>,
+  ): AsyncIterable<Secp256r1Ecdsa> {
+    for await (const pkt of source) {
+      if (globalThis.Array.isArray(pkt)) {
+        for (const p of (pkt as any)) {
+          yield* [Secp256r1Ecdsa.decode(p)];
+        }
+      } else {
+        yield* [Secp256r1Ecdsa.decode(pkt as any)];
+      }
+    }
+  },
+
+  fromJSON(object: any): Secp256r1Ecdsa {
+    return { signature: isSet(object.signature) ? bytesFromBase64(object.signature) : new Uint8Array(0) };
+  },
+
+  toJSON(message: Secp256r1Ecdsa): unknown {
+    const obj: any = {};
+    if (message.signature !== undefined && message.signature.length !== 0) {
+      obj.signature = base64FromBytes(message.signature);
+    }
+    return obj;
+  },
+
+  create(base?: DeepPartial<Secp256r1Ecdsa>): Secp256r1Ecdsa {
+    return Secp256r1Ecdsa.fromPartial(base ?? {});
+  },
+  fromPartial(object: DeepPartial<Secp256r1Ecdsa>): Secp256r1Ecdsa {
+    const message = createBaseSecp256r1Ecdsa();
+    message.signature = object.signature ?? new Uint8Array(0);
+    return message;
+  },
+};
+
 function createBaseSingleKeySignature(): SingleKeySignature {
   return { publicKey: undefined, signature: undefined };
 }
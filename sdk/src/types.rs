@@ -15,7 +15,7 @@ use crate::{
     },
 };
 use anyhow::Result;
-use aptos_crypto::{ed25519::Ed25519Signature, PrivateKey, SigningKey};
+use aptos_crypto::{ed25519::Ed25519Signature, secp256r1_ecdsa, PrivateKey, SigningKey};
 use aptos_ledger::AptosLedgerError;
 pub use aptos_types::*;
 use aptos_types::{
@@ -38,6 +38,7 @@ use std::{
 enum LocalAccountAuthenticator {
     PrivateKey(AccountKey),
     Keyless(KeylessAccount),
+    Secp256r1Ecdsa(Secp256r1EcdsaAccountKey),
     // TODO: Add support for keyless authentication
 }
 
@@ -69,6 +70,10 @@ impl LocalAccountAuthenticator {
 
                 SignedTransaction::new_keyless(txn, keyless_account.public_key.clone(), sig)
             },
+            LocalAccountAuthenticator::Secp256r1Ecdsa(key) => txn
+                .sign_secp256r1_ecdsa(key.private_key(), key.public_key().clone())
+                .expect("Signing a txn can't fail")
+                .into_inner(),
         }
     }
 }
@@ -116,6 +121,32 @@ impl LocalAccount {
         }
     }
 
+    /// Create a new representation of an account whose transactions are signed with a raw
+    /// secp256r1 (P-256) ECDSA key, e.g. a hardware or cloud KMS key.
+    pub fn new_secp256r1_ecdsa(
+        address: AccountAddress,
+        key: Secp256r1EcdsaAccountKey,
+        sequence_number: u64,
+    ) -> Self {
+        Self {
+            address,
+            auth: LocalAccountAuthenticator::Secp256r1Ecdsa(key),
+            sequence_number: AtomicU64::new(sequence_number),
+        }
+    }
+
+    /// Generate a new secp256r1 ECDSA account locally. Like `generate`, this does not create
+    /// the account on the Aptos blockchain.
+    pub fn generate_secp256r1_ecdsa<R>(rng: &mut R) -> Self
+    where
+        R: ::rand_core::RngCore + ::rand_core::CryptoRng,
+    {
+        let key = Secp256r1EcdsaAccountKey::generate(rng);
+        let address = key.authentication_key().account_address();
+
+        Self::new_secp256r1_ecdsa(address, key, 0)
+    }
+
     /// Recover an account from derive path (e.g. m/44'/637'/0'/0'/0') and mnemonic phrase,
     pub fn from_derive_path(
         derive_path: &str,
@@ -221,17 +252,47 @@ impl LocalAccount {
         self.address
     }
 
+    /// Returns the Ed25519 private key of the account.
+    ///
+    /// Panics if the account does not sign with an Ed25519 key, see `try_private_key`.
     pub fn private_key(&self) -> &Ed25519PrivateKey {
+        self.try_private_key()
+            .expect("Account does not have an Ed25519 private key")
+    }
+
+    /// Returns the Ed25519 private key of the account, or an error if the account does not
+    /// sign with an Ed25519 key.
+    pub fn try_private_key(&self) -> Result<&Ed25519PrivateKey> {
         match &self.auth {
-            LocalAccountAuthenticator::PrivateKey(key) => key.private_key(),
-            LocalAccountAuthenticator::Keyless(_) => todo!(),
+            LocalAccountAuthenticator::PrivateKey(key) => Ok(key.private_key()),
+            LocalAccountAuthenticator::Keyless(_) => {
+                Err(anyhow::anyhow!("Keyless accounts have no private key"))
+            },
+            LocalAccountAuthenticator::Secp256r1Ecdsa(_) => Err(anyhow::anyhow!(
+                "Secp256r1 ECDSA accounts have no Ed25519 private key"
+            )),
         }
     }
 
+    /// Returns the Ed25519 public key of the account.
+    ///
+    /// Panics if the account does not sign with an Ed25519 key, see `try_public_key`.
     pub fn public_key(&self) -> &Ed25519PublicKey {
+        self.try_public_key()
+            .expect("Account does not have an Ed25519 public key")
+    }
+
+    /// Returns the Ed25519 public key of the account, or an error if the account does not
+    /// sign with an Ed25519 key.
+    pub fn try_public_key(&self) -> Result<&Ed25519PublicKey> {
         match &self.auth {
-            LocalAccountAuthenticator::PrivateKey(key) => key.public_key(),
-            LocalAccountAuthenticator::Keyless(_) => todo!(),
+            LocalAccountAuthenticator::PrivateKey(key) => Ok(key.public_key()),
+            LocalAccountAuthenticator::Keyless(_) => Err(anyhow::anyhow!(
+                "Keyless accounts have no Ed25519 public key"
+            )),
+            LocalAccountAuthenticator::Secp256r1Ecdsa(_) => Err(anyhow::anyhow!(
+                "Secp256r1 ECDSA accounts have no Ed25519 public key"
+            )),
         }
     }
 
@@ -241,6 +302,7 @@ impl LocalAccount {
             LocalAccountAuthenticator::Keyless(keyless_account) => {
                 keyless_account.authentication_key()
             },
+            LocalAccountAuthenticator::Secp256r1Ecdsa(key) => key.authentication_key(),
         }
     }
 
@@ -261,10 +323,27 @@ impl LocalAccount {
             .store(sequence_number, Ordering::SeqCst);
     }
 
+    /// Replaces the Ed25519 key of the account, returning the previous one.
+    ///
+    /// Panics if the account does not sign with an Ed25519 key, see `try_rotate_key`.
     pub fn rotate_key<T: Into<AccountKey>>(&mut self, new_key: T) -> AccountKey {
+        self.try_rotate_key(new_key)
+            .expect("Account does not have an Ed25519 key to rotate")
+    }
+
+    /// Replaces the Ed25519 key of the account, returning the previous one, or an error if the
+    /// account does not sign with an Ed25519 key.
+    pub fn try_rotate_key<T: Into<AccountKey>>(&mut self, new_key: T) -> Result<AccountKey> {
         match &mut self.auth {
-            LocalAccountAuthenticator::PrivateKey(key) => std::mem::replace(key, new_key.into()),
-            LocalAccountAuthenticator::Keyless(_) => todo!(),
+            LocalAccountAuthenticator::PrivateKey(key) => {
+                Ok(std::mem::replace(key, new_key.into()))
+            },
+            LocalAccountAuthenticator::Keyless(_) => {
+                Err(anyhow::anyhow!("Keyless accounts have no key to rotate"))
+            },
+            LocalAccountAuthenticator::Secp256r1Ecdsa(_) => Err(anyhow::anyhow!(
+                "Secp256r1 ECDSA accounts have no Ed25519 key to rotate"
+            )),
         }
     }
 
@@ -451,6 +530,53 @@ impl From<Ed25519PrivateKey> for AccountKey {
     }
 }
 
+#[derive(Debug)]
+pub struct Secp256r1EcdsaAccountKey {
+    private_key: secp256r1_ecdsa::PrivateKey,
+    public_key: secp256r1_ecdsa::PublicKey,
+    authentication_key: AuthenticationKey,
+}
+
+impl Secp256r1EcdsaAccountKey {
+    pub fn generate<R>(rng: &mut R) -> Self
+    where
+        R: ::rand_core::RngCore + ::rand_core::CryptoRng,
+    {
+        let private_key = secp256r1_ecdsa::PrivateKey::generate(rng);
+        Self::from_private_key(private_key)
+    }
+
+    pub fn from_private_key(private_key: secp256r1_ecdsa::PrivateKey) -> Self {
+        let public_key = secp256r1_ecdsa::PublicKey::from(&private_key);
+        let authentication_key =
+            AuthenticationKey::any_key(AnyPublicKey::secp256r1_ecdsa(public_key.clone()));
+
+        Self {
+            private_key,
+            public_key,
+            authentication_key,
+        }
+    }
+
+    pub fn private_key(&self) -> &secp256r1_ecdsa::PrivateKey {
+        &self.private_key
+    }
+
+    pub fn public_key(&self) -> &secp256r1_ecdsa::PublicKey {
+        &self.public_key
+    }
+
+    pub fn authentication_key(&self) -> AuthenticationKey {
+        self.authentication_key
+    }
+}
+
+impl From<secp256r1_ecdsa::PrivateKey> for Secp256r1EcdsaAccountKey {
+    fn from(private_key: secp256r1_ecdsa::PrivateKey) -> Self {
+        Self::from_private_key(private_key)
+    }
+}
+
 #[derive(Debug)]
 pub struct EphemeralKeyPair {
     private_key: Ed25519PrivateKey,
@@ -568,4 +694,21 @@ mod tests {
         // Return an error for empty mnemonic phrase.
         assert!(LocalAccount::from_derive_path(derive_path, "", 0).is_err());
     }
+
+    #[test]
+    fn test_secp256r1_ecdsa_account_has_no_ed25519_key() {
+        let mut rng = rand::rngs::OsRng;
+        let mut account = LocalAccount::generate_secp256r1_ecdsa(&mut rng);
+        assert!(account.try_private_key().is_err());
+        assert!(account.try_public_key().is_err());
+        assert!(account
+            .try_rotate_key(AccountKey::generate(&mut rng))
+            .is_err());
+
+        let mut account = LocalAccount::generate(&mut rng);
+        let new_key = AccountKey::generate(&mut rng);
+        let new_public_key = new_key.public_key().clone();
+        assert!(account.try_rotate_key(new_key).is_ok());
+        assert_eq!(account.try_public_key().unwrap(), &new_public_key);
+    }
 }
@@ -14,11 +14,15 @@
 //! server.
 //!
 //! Internally both the client and server leverage a NetworkStream that communications in blocks
-//! where a block is a length prefixed array of bytes.
+//! where a block is a length prefixed array of bytes. Optionally, the stream can be authenticated
+//! and encrypted using the Noise IK handshake (see `noise`).
 
 pub mod grpc_network_service;
 pub mod network_controller;
+mod noise;
 
+pub use crate::noise::{NoiseClientConfig, NoiseServerConfig};
+use aptos_crypto::noise::{NoiseError, NoiseSession};
 use aptos_logger::{info, trace, warn, Schema};
 use aptos_metrics_core::{register_int_counter_vec, IntCounterVec};
 use once_cell::sync::Lazy;
@@ -59,6 +63,7 @@ enum LogEvent {
     ConnectionAttempt,
     ConnectionSuccessful,
     ConnectionFailed,
+    HandshakeFailed,
     DisconnectedPeerOnRead,
     DisconnectedPeerOnWrite,
     Shutdown,
@@ -139,12 +144,16 @@ pub enum Error {
     DataTooLarge(usize),
     #[error("Internal network error:")]
     NetworkError(#[from] std::io::Error),
+    #[error("Noise handshake or encryption error: {0}")]
+    NoiseError(#[from] NoiseError),
     #[error("No active stream")]
     NoActiveStream,
     #[error("Overflow error: {0}")]
     OverflowError(String),
     #[error("Remote stream cleanly closed")]
     RemoteStreamClosed,
+    #[error("Untrusted peer public key: {0}")]
+    UntrustedPeer(String),
 }
 
 pub struct NetworkClient {
@@ -153,6 +162,8 @@ pub struct NetworkClient {
     stream: Option<NetworkStream>,
     /// Read, Write, Connect timeout in milliseconds.
     timeout_ms: u64,
+    /// If set, every connection is authenticated and encrypted using Noise.
+    noise_config: Option<NoiseClientConfig>,
 }
 
 impl NetworkClient {
@@ -162,6 +173,19 @@ impl NetworkClient {
             server,
             stream: None,
             timeout_ms,
+            noise_config: None,
+        }
+    }
+
+    pub fn new_with_noise(
+        service: String,
+        server: SocketAddr,
+        timeout_ms: u64,
+        noise_config: NoiseClientConfig,
+    ) -> Self {
+        Self {
+            noise_config: Some(noise_config),
+            ..Self::new(service, server, timeout_ms)
         }
     }
 
@@ -256,7 +280,24 @@ impl NetworkClient {
 
             let stream = stream?;
             stream.set_nodelay(true)?;
-            self.stream = Some(NetworkStream::new(stream, self.server, self.timeout_ms));
+            let mut stream = NetworkStream::new(stream, self.server, self.timeout_ms);
+            if let Some(noise_config) = &self.noise_config {
+                match noise_config.handshake(&self.service, &mut stream) {
+                    Ok(session) => stream.session = Some(session),
+                    Err(err) => {
+                        self.increment_counter(Method::Connect, MethodResult::Failure);
+                        warn!(SecureNetLogSchema::new(
+                            &self.service,
+                            NetworkMode::Client,
+                            LogEvent::HandshakeFailed,
+                        )
+                        .error(&err)
+                        .remote_peer(&self.server));
+                        return Err(err);
+                    },
+                }
+            }
+            self.stream = Some(stream);
             self.increment_counter(Method::Connect, MethodResult::Success);
             info!(SecureNetLogSchema::new(
                 &self.service,
@@ -276,6 +317,8 @@ pub struct NetworkServer {
     stream: Option<NetworkStream>,
     /// Read, Write, Connect timeout in milliseconds.
     timeout_ms: u64,
+    /// If set, only authenticated clients are accepted and every connection is encrypted.
+    noise_config: Option<NoiseServerConfig>,
 }
 
 impl NetworkServer {
@@ -286,6 +329,19 @@ impl NetworkServer {
             listener: Some(listener.unwrap()),
             stream: None,
             timeout_ms,
+            noise_config: None,
+        }
+    }
+
+    pub fn new_with_noise(
+        service: String,
+        listen: SocketAddr,
+        timeout_ms: u64,
+        noise_config: NoiseServerConfig,
+    ) -> Self {
+        Self {
+            noise_config: Some(noise_config),
+            ..Self::new(service, listen, timeout_ms)
         }
     }
 
@@ -389,6 +445,25 @@ impl NetworkServer {
                 },
             };
 
+            stream.set_nodelay(true)?;
+            let mut stream = NetworkStream::new(stream, stream_addr, self.timeout_ms);
+            if let Some(noise_config) = &self.noise_config {
+                match noise_config.handshake(&self.service, &mut stream) {
+                    Ok(session) => stream.session = Some(session),
+                    Err(err) => {
+                        self.increment_counter(Method::Connect, MethodResult::Failure);
+                        warn!(SecureNetLogSchema::new(
+                            &self.service,
+                            NetworkMode::Server,
+                            LogEvent::HandshakeFailed,
+                        )
+                        .error(&err)
+                        .remote_peer(&stream_addr));
+                        return Err(err);
+                    },
+                }
+            }
+
             self.increment_counter(Method::Connect, MethodResult::Success);
             info!(SecureNetLogSchema::new(
                 &self.service,
@@ -396,9 +471,7 @@ impl NetworkServer {
                 LogEvent::ConnectionSuccessful,
             )
             .remote_peer(&stream_addr));
-
-            stream.set_nodelay(true)?;
-            self.stream = Some(NetworkStream::new(stream, stream_addr, self.timeout_ms));
+            self.stream = Some(stream);
         }
 
         self.stream.as_mut().ok_or(Error::NoActiveStream)
@@ -410,6 +483,8 @@ struct NetworkStream {
     remote: SocketAddr,
     buffer: Vec<u8>,
     temp_buffer: [u8; 1024],
+    /// The noise session used to encrypt and decrypt messages (if any)
+    session: Option<NoiseSession>,
 }
 
 impl NetworkStream {
@@ -424,11 +499,32 @@ impl NetworkStream {
             remote,
             buffer: Vec::new(),
             temp_buffer: [0; 1024],
+            session: None,
         }
     }
 
     /// Blocking read until able to successfully read an entire message
     pub fn read(&mut self) -> Result<Vec<u8>, Error> {
+        let block = self.read_block()?;
+        match &mut self.session {
+            Some(session) => noise::decrypt(session, block),
+            None => Ok(block),
+        }
+    }
+
+    /// Blocking write until able to successfully send an entire message
+    pub fn write(&mut self, data: &[u8]) -> Result<(), Error> {
+        match &mut self.session {
+            Some(session) => {
+                let ciphertext = noise::encrypt(session, data)?;
+                self.write_block(&ciphertext)
+            },
+            None => self.write_block(data),
+        }
+    }
+
+    /// Blocking read until able to successfully read an entire (unencrypted) block
+    fn read_block(&mut self) -> Result<Vec<u8>, Error> {
         let result = self.read_buffer();
         if !result.is_empty() {
             return Ok(result);
@@ -456,8 +552,8 @@ impl NetworkStream {
         Ok(self.stream.shutdown(Shutdown::Both)?)
     }
 
-    /// Blocking write until able to successfully send an entire message
-    pub fn write(&mut self, data: &[u8]) -> Result<(), Error> {
+    /// Blocking write until able to successfully send an entire (unencrypted) block
+    fn write_block(&mut self, data: &[u8]) -> Result<(), Error> {
         let u32_max = u32::max_value() as usize;
         if u32_max <= data.len() {
             return Err(Error::DataTooLarge(data.len()));
@@ -517,7 +613,12 @@ impl NetworkStream {
 mod test {
     use super::*;
     use aptos_config::utils;
-    use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+    use aptos_crypto::{x25519, Uniform};
+    use rand::{rngs::StdRng, SeedableRng};
+    use std::{
+        collections::HashSet,
+        net::{IpAddr, Ipv4Addr, SocketAddr},
+    };
 
     /// Read, Write, Connect timeout in milliseconds.
     const TIMEOUT: u64 = 5_000;
@@ -655,4 +756,70 @@ mod test {
         let result2 = server2.read().unwrap();
         assert_eq!(data2, result2);
     }
+
+    /// Creates a server and a client that communicate using noise. If
+    /// `trust_client` is false, the server doesn't trust the client key.
+    fn new_noise_server_and_client(trust_client: bool) -> (NetworkServer, NetworkClient) {
+        let mut rng = StdRng::from_seed([0u8; 32]);
+        let server_private_key = x25519::PrivateKey::generate(&mut rng);
+        let server_public_key = server_private_key.public_key();
+        let client_private_key = x25519::PrivateKey::generate(&mut rng);
+        let mut trusted_client_keys = HashSet::new();
+        if trust_client {
+            trusted_client_keys.insert(client_private_key.public_key());
+        }
+
+        let server_port = utils::get_available_port();
+        let server_addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), server_port);
+        let server = NetworkServer::new_with_noise(
+            "test".to_string(),
+            server_addr,
+            TIMEOUT,
+            NoiseServerConfig::new(server_private_key, trusted_client_keys),
+        );
+        let client = NetworkClient::new_with_noise(
+            "test".to_string(),
+            server_addr,
+            TIMEOUT,
+            NoiseClientConfig::new(client_private_key, server_public_key),
+        );
+        (server, client)
+    }
+
+    #[test]
+    fn test_noise_ping() {
+        let (mut server, mut client) = new_noise_server_and_client(true);
+
+        // The client connects (and performs the handshake) on the first write, so
+        // the server must accept the connection concurrently.
+        let data = vec![0, 1, 2, 3];
+        let server_thread = thread::spawn(move || {
+            let result = server.read().unwrap();
+            server.write(&result).unwrap();
+
+            // Messages larger than the maximum noise message size are split into chunks
+            let result = server.read().unwrap();
+            server.write(&result).unwrap();
+        });
+        client.write(&data).unwrap();
+        assert_eq!(data, client.read().unwrap());
+
+        let data: Vec<u8> = (0..200_000).map(|i| i as u8).collect();
+        client.write(&data).unwrap();
+        assert_eq!(data, client.read().unwrap());
+        server_thread.join().unwrap();
+    }
+
+    #[test]
+    fn test_noise_untrusted_client() {
+        let (mut server, mut client) = new_noise_server_and_client(false);
+
+        let server_thread = thread::spawn(move || {
+            let error = server.read().unwrap_err();
+            assert!(matches!(error, Error::UntrustedPeer(_)));
+        });
+        // The server drops the connection without responding to the handshake
+        client.write(&[0, 1, 2, 3]).unwrap_err();
+        server_thread.join().unwrap();
+    }
 }
@@ -0,0 +1,122 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Authentication and encryption of a NetworkStream using the Noise IK handshake (see
+//! `aptos_crypto::noise`). The client must know (i.e., pin) the static public key of the server,
+//! and the server only accepts clients whose static public keys are trusted. Once the handshake
+//! completes, every message is encrypted (in chunks of at most `MAX_SIZE_NOISE_MSG` bytes) and
+//! sent as a single length-prefixed block.
+
+use crate::{Error, NetworkStream};
+use aptos_crypto::{
+    noise::{self, NoiseConfig, NoiseSession, AES_GCM_TAGLEN, MAX_SIZE_NOISE_MSG},
+    x25519,
+};
+use std::collections::HashSet;
+
+/// The maximum size of the plaintext encrypted in a single noise message
+const MAX_PLAINTEXT_CHUNK_SIZE: usize = MAX_SIZE_NOISE_MSG - AES_GCM_TAGLEN;
+
+/// The noise configuration of a NetworkClient
+pub struct NoiseClientConfig {
+    noise_config: NoiseConfig,
+    server_public_key: x25519::PublicKey,
+}
+
+impl NoiseClientConfig {
+    pub fn new(private_key: x25519::PrivateKey, server_public_key: x25519::PublicKey) -> Self {
+        Self {
+            noise_config: NoiseConfig::new(private_key),
+            server_public_key,
+        }
+    }
+
+    /// Performs the initiator side of the handshake (the service name is used as the prologue)
+    pub(crate) fn handshake(
+        &self,
+        service: &str,
+        stream: &mut NetworkStream,
+    ) -> Result<NoiseSession, Error> {
+        let mut rng = rand::rngs::OsRng;
+        let mut init_message = vec![0; noise::handshake_init_msg_len(0)];
+        let handshake_state = self.noise_config.initiate_connection(
+            &mut rng,
+            service.as_bytes(),
+            self.server_public_key,
+            None,
+            &mut init_message,
+        )?;
+        stream.write_block(&init_message)?;
+
+        let response_message = stream.read_block()?;
+        let (_, session) = self
+            .noise_config
+            .finalize_connection(handshake_state, &response_message)?;
+        Ok(session)
+    }
+}
+
+/// The noise configuration of a NetworkServer
+pub struct NoiseServerConfig {
+    noise_config: NoiseConfig,
+    trusted_client_keys: HashSet<x25519::PublicKey>,
+}
+
+impl NoiseServerConfig {
+    pub fn new(
+        private_key: x25519::PrivateKey,
+        trusted_client_keys: HashSet<x25519::PublicKey>,
+    ) -> Self {
+        Self {
+            noise_config: NoiseConfig::new(private_key),
+            trusted_client_keys,
+        }
+    }
+
+    /// Performs the responder side of the handshake, and rejects untrusted clients
+    pub(crate) fn handshake(
+        &self,
+        service: &str,
+        stream: &mut NetworkStream,
+    ) -> Result<NoiseSession, Error> {
+        let init_message = stream.read_block()?;
+        let (client_public_key, handshake_state, _) = self
+            .noise_config
+            .parse_client_init_message(service.as_bytes(), &init_message)?;
+        if !self.trusted_client_keys.contains(&client_public_key) {
+            return Err(Error::UntrustedPeer(client_public_key.to_string()));
+        }
+
+        let mut rng = rand::rngs::OsRng;
+        let mut response_message = vec![0; noise::handshake_resp_msg_len(0)];
+        let session = self.noise_config.respond_to_client(
+            &mut rng,
+            handshake_state,
+            None,
+            &mut response_message,
+        )?;
+        stream.write_block(&response_message)?;
+        Ok(session)
+    }
+}
+
+/// Encrypts the given message, chunk by chunk
+pub(crate) fn encrypt(session: &mut NoiseSession, data: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut ciphertext = Vec::with_capacity(noise::encrypted_len(data.len()));
+    for chunk in data.chunks(MAX_PLAINTEXT_CHUNK_SIZE) {
+        let mut encrypted_chunk = chunk.to_vec();
+        let auth_tag = session.write_message_in_place(&mut encrypted_chunk)?;
+        ciphertext.extend_from_slice(&encrypted_chunk);
+        ciphertext.extend_from_slice(&auth_tag);
+    }
+    Ok(ciphertext)
+}
+
+/// Decrypts the given message (encrypted with `encrypt`), chunk by chunk
+pub(crate) fn decrypt(session: &mut NoiseSession, mut data: Vec<u8>) -> Result<Vec<u8>, Error> {
+    let mut plaintext = Vec::with_capacity(data.len());
+    for chunk in data.chunks_mut(MAX_SIZE_NOISE_MSG) {
+        plaintext.extend_from_slice(session.read_message_in_place(chunk)?);
+    }
+    Ok(plaintext)
+}
@@ -18,12 +18,13 @@ use aptos_storage_service_types::{
     requests::{
         DataRequest, EpochEndingLedgerInfoRequest, NewTransactionOutputsWithProofRequest,
         NewTransactionsOrOutputsWithProofRequest, NewTransactionsWithProofRequest,
-        StateValuesWithProofRequest, SubscribeTransactionOutputsWithProofRequest,
+        StateValueWithProofRequest, StateValuesWithProofRequest,
+        SubscribeTransactionOutputsWithProofRequest,
         SubscribeTransactionsOrOutputsWithProofRequest, SubscribeTransactionsWithProofRequest,
         SubscriptionStreamMetadata, TransactionOutputsWithProofRequest,
         TransactionsOrOutputsWithProofRequest, TransactionsWithProofRequest,
     },
-    responses::{CompleteDataRange, TransactionOrOutputListWithProof},
+    responses::{CompleteDataRange, StateValueWithProof, TransactionOrOutputListWithProof},
     Epoch,
 };
 use aptos_types::{
@@ -33,7 +34,7 @@ use aptos_types::{
     chain_id::ChainId,
     epoch_state::EpochState,
     ledger_info::{LedgerInfo, LedgerInfoWithSignatures},
-    proof::SparseMerkleRangeProof,
+    proof::{SparseMerkleProof, SparseMerkleRangeProof},
     state_store::{
         state_key::StateKey,
         state_value::{StateValue, StateValueChunkWithProof},
@@ -327,6 +328,30 @@ impl AptosDataClientInterface for MockAptosDataClient {
         Ok(create_data_client_response(state_value_chunk_with_proof))
     }
 
+    async fn get_state_value_with_proof(
+        &self,
+        state_key: StateKey,
+        version: Version,
+        request_timeout_ms: u64,
+    ) -> Result<Response<StateValueWithProof>, aptos_data_client::error::Error> {
+        // Verify the request timeout
+        let data_request =
+            DataRequest::GetStateValueWithProof(StateValueWithProofRequest { state_key, version });
+        self.verify_request_timeout_value(request_timeout_ms, false, false, data_request);
+
+        // Emulate network latencies
+        self.emulate_network_latencies().await;
+
+        // Create a state value with proof
+        let state_value_with_proof = StateValueWithProof::new(
+            Some(StateValue::from(vec![])),
+            SparseMerkleProof::new(None, vec![]),
+        );
+
+        // Create and send a data client response
+        Ok(create_data_client_response(state_value_with_proof))
+    }
+
     async fn get_epoch_ending_ledger_infos(
         &self,
         start_epoch: Epoch,
@@ -0,0 +1,17 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::error::Error;
+use aptos_types::transaction::Version;
+use async_trait::async_trait;
+
+/// The interface used by the bootstrapper to restore the node from a backup
+/// store (i.e., when the bootstrapping mode is `RestoreFromBackup`). This
+/// allows state sync to remain agnostic of the backup storage and format.
+#[async_trait]
+pub trait BackupRestorerInterface: Send + Sync {
+    /// Restores the latest state snapshot in the backup store to storage,
+    /// along with the epoch ending ledger infos and the transactions required
+    /// to verify it. Returns the version of the restored state snapshot.
+    async fn restore_latest_state_snapshot(&self) -> Result<Version, Error>;
+}
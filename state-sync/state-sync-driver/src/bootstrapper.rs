@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    backup_restorer::BackupRestorerInterface,
     driver::DriverConfiguration,
     error::Error,
     logging::{LogEntry, LogSchema},
@@ -32,6 +33,7 @@ use aptos_types::{
 };
 use futures::channel::oneshot;
 use std::{collections::BTreeMap, sync::Arc, time::Duration};
+use tokio::task::JoinHandle;
 
 // Useful bootstrapper constants
 const BOOTSTRAPPER_LOG_INTERVAL_SECS: u64 = 3;
@@ -291,6 +293,12 @@ pub struct Bootstrapper<MetadataStorage, StorageSyncer, StreamingClient> {
     // The currently active data stream (provided by the data streaming service)
     active_data_stream: Option<DataStreamListener>,
 
+    // The restorer used to bootstrap the node from a backup store (if configured)
+    backup_restorer: Option<Arc<dyn BackupRestorerInterface>>,
+
+    // The task restoring the node from the backup store (if a restore is in progress)
+    backup_restore_task: Option<JoinHandle<Result<Version, Error>>>,
+
     // The channel used to notify a listener of successful bootstrapping
     bootstrap_notifier_channel: Option<oneshot::Sender<Result<(), Error>>>,
 
@@ -306,6 +314,9 @@ pub struct Bootstrapper<MetadataStorage, StorageSyncer, StreamingClient> {
     // The handler for output fallback behaviour
     output_fallback_handler: OutputFallbackHandler,
 
+    // If the node has already been restored from the backup store (if required)
+    restored_from_backup: bool,
+
     // The speculative state tracking the active data stream
     speculative_stream_state: Option<SpeculativeStreamState>,
 
@@ -332,6 +343,7 @@ impl<
     > Bootstrapper<MetadataStorage, StorageSyncer, StreamingClient>
 {
     pub fn new(
+        backup_restorer: Option<Arc<dyn BackupRestorerInterface>>,
         driver_configuration: DriverConfiguration,
         metadata_storage: MetadataStorage,
         output_fallback_handler: OutputFallbackHandler,
@@ -347,11 +359,14 @@ impl<
         Self {
             state_value_syncer: StateValueSyncer::new(),
             active_data_stream: None,
+            backup_restorer,
+            backup_restore_task: None,
             bootstrap_notifier_channel: None,
             bootstrapped: false,
             driver_configuration,
             metadata_storage,
             output_fallback_handler,
+            restored_from_backup: false,
             speculative_stream_state: None,
             streaming_client,
             storage,
@@ -425,6 +440,9 @@ impl<
         if self.active_data_stream.is_some() {
             // We have an active data stream. Process any notifications!
             self.process_active_stream_notifications().await?;
+        } else if self.backup_restore_task.is_some() {
+            // We're restoring from the backup store. Check if the restore is complete!
+            self.check_backup_restore_progress().await?;
         } else if self.storage_synchronizer.pending_storage_data() {
             // Wait for any pending data to be processed
             sample!(
@@ -458,6 +476,11 @@ impl<
         // Reset the chunk executor to flush any invalid state currently held in-memory
         self.storage_synchronizer.reset_chunk_executor()?;
 
+        // If we're restoring from a backup, restore the latest state snapshot first
+        if self.get_bootstrapping_mode().is_restore_from_backup() && !self.restored_from_backup {
+            return self.restore_from_backup().await;
+        }
+
         // Always fetch the new epoch ending ledger infos first
         if self.should_fetch_epoch_ending_ledger_infos() {
             return self
@@ -512,6 +535,85 @@ impl<
         }
     }
 
+    /// Starts restoring the latest state snapshot from the backup store (if the
+    /// node has not yet synced any state beyond genesis). The restore can take
+    /// a long time, so it runs in a separate task that is polled by
+    /// `check_backup_restore_progress()`. The remaining ledger history is then
+    /// synced from the network, as when syncing from genesis.
+    async fn restore_from_backup(&mut self) -> Result<(), Error> {
+        let backup_restorer = self.backup_restorer.clone().ok_or_else(|| {
+            Error::UnexpectedError(
+                "The node is restoring from a backup, but no backup restorer was provided!".into(),
+            )
+        })?;
+
+        // Only restore if the state hasn't moved beyond genesis. Otherwise, the
+        // node was either already restored, or it has synced from the network.
+        let latest_state_checkpoint_version = self
+            .storage
+            .get_latest_state_checkpoint_version()
+            .map_err(|error| {
+                Error::StorageError(format!(
+                    "Failed to get the latest state checkpoint version: {:?}",
+                    error
+                ))
+            })?;
+        if latest_state_checkpoint_version == Some(GENESIS_TRANSACTION_VERSION) {
+            info!(LogSchema::new(LogEntry::Bootstrapper)
+                .message("Restoring the latest state snapshot from the backup store!"));
+            self.backup_restore_task = Some(tokio::spawn(async move {
+                backup_restorer.restore_latest_state_snapshot().await
+            }));
+        } else {
+            info!(LogSchema::new(LogEntry::Bootstrapper).message(&format!(
+                "Skipping the restore from the backup store! Latest state checkpoint version: {:?}.",
+                latest_state_checkpoint_version
+            )));
+            self.restored_from_backup = true;
+        }
+
+        Ok(())
+    }
+
+    /// Checks if the restore from the backup store has completed, and if so,
+    /// reloads the epoch state from storage. If the restore failed, it will be
+    /// retried the next time progress is driven.
+    async fn check_backup_restore_progress(&mut self) -> Result<(), Error> {
+        // Check if the restore is still in progress
+        let backup_restore_task = match self.backup_restore_task.take() {
+            Some(backup_restore_task) => backup_restore_task,
+            None => return Ok(()), // There's no restore in progress
+        };
+        if !backup_restore_task.is_finished() {
+            sample!(
+                SampleRate::Duration(Duration::from_secs(PENDING_DATA_LOG_FREQ_SECS)),
+                info!(LogSchema::new(LogEntry::Bootstrapper)
+                    .message("Waiting for the restore from the backup store to complete!"))
+            );
+            self.backup_restore_task = Some(backup_restore_task);
+            return Ok(());
+        }
+
+        // The restore has completed. Process the result.
+        let restored_version = backup_restore_task.await.map_err(|error| {
+            Error::BackupRestoreError(format!("The restore task failed: {:?}", error))
+        })??;
+        info!(LogSchema::new(LogEntry::Bootstrapper).message(&format!(
+            "Restored the state snapshot at version {} from the backup store!",
+            restored_version
+        )));
+
+        // The restore verified the epoch ending ledger infos (and the waypoint,
+        // if it wasn't beyond the snapshot), so we can trust the latest epoch
+        // state in storage. Any newer epochs are fetched from the network.
+        let latest_epoch_state = utils::fetch_latest_epoch_state(self.storage.clone())?;
+        self.verified_epoch_states = VerifiedEpochStates::new(latest_epoch_state);
+        utils::initialize_sync_gauges(self.storage.clone())?;
+        self.restored_from_backup = true;
+
+        Ok(())
+    }
+
     /// Fetches all missing state snapshot data in order to bootstrap the node
     async fn fetch_missing_state_snapshot_data(
         &mut self,
@@ -763,7 +865,7 @@ impl<
                     )
                     .await?
             },
-            BootstrappingMode::ExecuteOrApplyFromGenesis => {
+            BootstrappingMode::ExecuteOrApplyFromGenesis | BootstrappingMode::RestoreFromBackup => {
                 if self.output_fallback_handler.in_fallback_mode() {
                     metrics::set_gauge(
                         &metrics::DRIVER_FALLBACK_MODE,
@@ -1214,7 +1316,7 @@ impl<
                     ));
                 }
             },
-            BootstrappingMode::ExecuteOrApplyFromGenesis => {
+            BootstrappingMode::ExecuteOrApplyFromGenesis | BootstrappingMode::RestoreFromBackup => {
                 if let Some(transaction_list_with_proof) = transaction_list_with_proof {
                     utils::execute_transactions(
                         self.storage_synchronizer.clone(),
@@ -1401,7 +1503,7 @@ impl<
                     ));
                 }
             },
-            BootstrappingMode::ExecuteOrApplyFromGenesis => {
+            BootstrappingMode::ExecuteOrApplyFromGenesis | BootstrappingMode::RestoreFromBackup => {
                 if let Some(transaction_list_with_proof) = transaction_list_with_proof {
                     transaction_list_with_proof.transactions.len()
                 } else if let Some(output_list_with_proof) = transaction_outputs_with_proof {
@@ -1514,7 +1616,9 @@ impl<
             .await?;
 
         // Fallback to output syncing if we need to
-        if let BootstrappingMode::ExecuteOrApplyFromGenesis = self.get_bootstrapping_mode() {
+        if let BootstrappingMode::ExecuteOrApplyFromGenesis | BootstrappingMode::RestoreFromBackup =
+            self.get_bootstrapping_mode()
+        {
             self.output_fallback_handler.fallback_to_outputs();
             metrics::set_gauge(
                 &metrics::DRIVER_FALLBACK_MODE,
@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    backup_restorer::BackupRestorerInterface,
     bootstrapper::Bootstrapper,
     continuous_syncer::ContinuousSyncer,
     driver_client::{ClientNotificationListener, DriverNotification},
@@ -20,7 +21,7 @@ use crate::{
     utils,
     utils::{OutputFallbackHandler, PENDING_DATA_LOG_FREQ_SECS},
 };
-use aptos_config::config::{RoleType, StateSyncDriverConfig};
+use aptos_config::config::{ConsensusObserverConfig, RoleType, StateSyncDriverConfig};
 use aptos_consensus_notifications::{
     ConsensusCommitNotification, ConsensusNotification, ConsensusSyncNotification,
 };
@@ -53,6 +54,9 @@ pub struct DriverConfiguration {
     // The config file of the driver
     pub config: StateSyncDriverConfig,
 
+    // The config of the consensus observer (running on fullnodes)
+    pub consensus_observer_config: ConsensusObserverConfig,
+
     // The role of the node
     pub role: RoleType,
 
@@ -61,9 +65,15 @@ pub struct DriverConfiguration {
 }
 
 impl DriverConfiguration {
-    pub fn new(config: StateSyncDriverConfig, role: RoleType, waypoint: Waypoint) -> Self {
+    pub fn new(
+        config: StateSyncDriverConfig,
+        consensus_observer_config: ConsensusObserverConfig,
+        role: RoleType,
+        waypoint: Waypoint,
+    ) -> Self {
         Self {
             config,
+            consensus_observer_config,
             role,
             waypoint,
         }
@@ -106,6 +116,9 @@ pub struct StateSyncDriver<
     // The event subscription service to notify listeners of on-chain events
     event_subscription_service: Arc<Mutex<EventSubscriptionService>>,
 
+    // The time of the last commit notification sent by consensus
+    last_consensus_commit_time: Option<Instant>,
+
     // The handler for notifications to mempool
     mempool_notification_handler: MempoolNotificationHandler<MempoolNotifier>,
 
@@ -143,6 +156,7 @@ impl<
     >
 {
     pub fn new(
+        backup_restorer: Option<Arc<dyn BackupRestorerInterface>>,
         client_notification_listener: ClientNotificationListener,
         commit_notification_listener: CommitNotificationListener,
         consensus_notification_handler: ConsensusNotificationHandler,
@@ -163,6 +177,7 @@ impl<
         let output_fallback_handler =
             OutputFallbackHandler::new(driver_configuration.clone(), time_service.clone());
         let bootstrapper = Bootstrapper::new(
+            backup_restorer,
             driver_configuration.clone(),
             metadata_storage,
             output_fallback_handler.clone(),
@@ -188,6 +203,7 @@ impl<
             driver_configuration,
             error_notification_listener,
             event_subscription_service,
+            last_consensus_commit_time: None,
             mempool_notification_handler,
             start_time: None,
             storage,
@@ -232,9 +248,12 @@ impl<
 
     /// Handles a notification sent by consensus
     async fn handle_consensus_notification(&mut self, notification: ConsensusNotification) {
-        // Verify the notification: full nodes shouldn't receive notifications
-        // and consensus should only send notifications after bootstrapping!
-        let result = if self.driver_configuration.role == RoleType::FullNode {
+        // Verify the notification: full nodes shouldn't receive notifications (unless
+        // they run the consensus observer) and consensus should only send notifications
+        // after bootstrapping!
+        let result = if self.driver_configuration.role == RoleType::FullNode
+            && !self.is_consensus_observer_enabled()
+        {
             Err(Error::FullNodeConsensusNotification(format!(
                 "Received consensus notification: {:?}",
                 notification
@@ -303,6 +322,7 @@ impl<
             ))
         );
         self.update_consensus_commit_metrics(&consensus_commit_notification);
+        self.last_consensus_commit_time = Some(self.time_service.now());
 
         // TODO(joshlind): can we get consensus to forward the events?
 
@@ -540,9 +560,38 @@ impl<
         self.driver_configuration.role == RoleType::Validator
     }
 
+    /// Returns true iff this node runs the consensus observer
+    fn is_consensus_observer_enabled(&self) -> bool {
+        self.driver_configuration
+            .consensus_observer_config
+            .observer_enabled
+    }
+
     /// Returns true iff consensus is currently executing
     fn check_if_consensus_executing(&self) -> bool {
-        self.is_validator() && self.bootstrapper.is_bootstrapped() && !self.active_sync_request()
+        (self.is_validator() || self.check_if_consensus_observer_executing())
+            && self.bootstrapper.is_bootstrapped()
+            && !self.active_sync_request()
+    }
+
+    /// Returns true iff the consensus observer has committed recently. Otherwise,
+    /// the observer has likely fallen behind (or lost its upstream peers), and the
+    /// continuous syncer should take over until the observer resumes.
+    fn check_if_consensus_observer_executing(&self) -> bool {
+        if !self.is_consensus_observer_enabled() {
+            return false;
+        }
+
+        let fallback_duration = Duration::from_millis(
+            self.driver_configuration
+                .consensus_observer_config
+                .observer_fallback_duration_ms,
+        );
+        consensus_observer_committed_recently(
+            self.last_consensus_commit_time,
+            self.time_service.now(),
+            fallback_duration,
+        )
     }
 
     /// Checks if the connection deadline has passed. If so, validators with
@@ -648,3 +697,16 @@ impl<
         };
     }
 }
+
+/// Returns true iff the consensus observer committed within the fallback duration
+/// (i.e., the continuous syncer should not take over yet).
+pub(crate) fn consensus_observer_committed_recently(
+    last_commit_time: Option<Instant>,
+    now: Instant,
+    fallback_duration: Duration,
+) -> bool {
+    match last_commit_time {
+        Some(last_commit_time) => now.duration_since(last_commit_time) < fallback_duration,
+        None => false,
+    }
+}
@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    backup_restorer::BackupRestorerInterface,
     driver::{DriverConfiguration, StateSyncDriver},
     driver_client::{ClientNotificationListener, DriverClient, DriverNotification},
     metadata_storage::MetadataStorageInterface,
@@ -10,9 +11,11 @@ use crate::{
         CommitNotification, CommitNotificationListener, ConsensusNotificationHandler,
         ErrorNotificationListener, MempoolNotificationHandler, StorageServiceNotificationHandler,
     },
+    partial_state_storage::PartialStateStorage,
+    partial_state_syncer::PartialStateSyncer,
     storage_synchronizer::StorageSynchronizer,
 };
-use aptos_config::config::NodeConfig;
+use aptos_config::config::{NodeConfig, TrackedStateFilter};
 use aptos_consensus_notifications::ConsensusNotificationListener;
 use aptos_data_client::client::AptosDataClient;
 use aptos_data_streaming_service::streaming_client::StreamingServiceClient;
@@ -20,7 +23,7 @@ use aptos_event_notifications::{EventNotificationSender, EventSubscriptionServic
 use aptos_executor_types::ChunkExecutorTrait;
 use aptos_infallible::Mutex;
 use aptos_mempool_notifications::MempoolNotificationSender;
-use aptos_storage_interface::DbReaderWriter;
+use aptos_storage_interface::{DbReader, DbReaderWriter};
 use aptos_storage_service_notifications::StorageServiceNotificationSender;
 use aptos_time_service::TimeService;
 use aptos_types::waypoint::Waypoint;
@@ -53,6 +56,7 @@ impl DriverFactory {
         mempool_notification_sender: MempoolNotifier,
         storage_service_notification_sender: StorageServiceNotifier,
         metadata_storage: MetadataStorage,
+        backup_restorer: Option<Arc<dyn BackupRestorerInterface>>,
         consensus_listener: ConsensusNotificationListener,
         event_subscription_service: EventSubscriptionService,
         aptos_data_client: AptosDataClient,
@@ -68,6 +72,7 @@ impl DriverFactory {
             mempool_notification_sender,
             storage_service_notification_sender,
             metadata_storage,
+            backup_restorer,
             consensus_listener,
             event_subscription_service,
             aptos_data_client,
@@ -94,6 +99,7 @@ impl DriverFactory {
         mempool_notification_sender: MempoolNotifier,
         storage_service_notification_sender: StorageServiceNotifier,
         metadata_storage: MetadataStorage,
+        backup_restorer: Option<Arc<dyn BackupRestorerInterface>>,
         consensus_listener: ConsensusNotificationListener,
         mut event_subscription_service: EventSubscriptionService,
         aptos_data_client: AptosDataClient,
@@ -158,12 +164,14 @@ impl DriverFactory {
         // Create the driver configuration
         let driver_configuration = DriverConfiguration::new(
             node_config.state_sync.state_sync_driver,
+            node_config.consensus_observer,
             node_config.base.role,
             waypoint,
         );
 
         // Create the state sync driver
         let state_sync_driver = StateSyncDriver::new(
+            backup_restorer,
             client_notification_listener,
             commit_notification_listener,
             consensus_notification_handler,
@@ -196,6 +204,56 @@ impl DriverFactory {
         (driver_factory, commit_notification_sender)
     }
 
+    /// Creates and spawns a partial state syncer (in place of the state sync
+    /// driver) and returns the factory. This is used by partial state ("light")
+    /// fullnodes that only sync the tracked state.
+    pub fn create_and_spawn_partial_state_syncer(
+        create_runtime: bool,
+        node_config: &NodeConfig,
+        waypoint: Waypoint,
+        storage: Arc<dyn DbReader>,
+        partial_state_storage: PartialStateStorage,
+        tracked_state_filter: TrackedStateFilter,
+        aptos_data_client: AptosDataClient,
+    ) -> Self {
+        // Create the client notification handler
+        let (client_notification_sender, client_notification_receiver) = mpsc::unbounded();
+        let client_notification_listener =
+            ClientNotificationListener::new(client_notification_receiver);
+
+        // Create a new runtime (if required)
+        let driver_runtime = if create_runtime {
+            let runtime = aptos_runtimes::spawn_named_runtime("partial-sync".into(), None);
+            Some(runtime)
+        } else {
+            None
+        };
+
+        // Create the partial state syncer
+        let partial_state_syncer = PartialStateSyncer::new(
+            aptos_data_client,
+            client_notification_listener,
+            node_config.partial_state.clone(),
+            partial_state_storage,
+            node_config.state_sync.aptos_data_client.response_timeout_ms,
+            storage,
+            tracked_state_filter,
+            waypoint,
+        );
+
+        // Spawn the syncer
+        if let Some(driver_runtime) = &driver_runtime {
+            driver_runtime.spawn(partial_state_syncer.start_syncer());
+        } else {
+            tokio::spawn(partial_state_syncer.start_syncer());
+        }
+
+        Self {
+            client_notification_sender,
+            _driver_runtime: driver_runtime,
+        }
+    }
+
     /// Returns a new client that can be used to communicate with the driver
     pub fn create_driver_client(&self) -> DriverClient {
         DriverClient::new(self.client_notification_sender.clone())
@@ -13,6 +13,8 @@ pub enum Error {
     AlreadyBootstrapped(String),
     #[error("Advertised data error: {0}")]
     AdvertisedDataError(String),
+    #[error("Failed to restore from the backup store: {0}")]
+    BackupRestoreError(String),
     #[error("State sync has not yet finished bootstrapping! Error: {0}")]
     BootstrapNotComplete(String),
     #[error("Failed to send callback: {0}")]
@@ -55,6 +57,7 @@ impl Error {
         match self {
             Error::AlreadyBootstrapped(_) => "already_boostrapped",
             Error::AdvertisedDataError(_) => "advertised_data_error",
+            Error::BackupRestoreError(_) => "backup_restore_error",
             Error::BootstrapNotComplete(_) => "bootstrap_not_complete",
             Error::CallbackSendFailed(_) => "callback_send_failed",
             Error::CriticalDataStreamTimeout(_) => "critical_data_stream_timeout",
@@ -82,6 +85,12 @@ impl From<Canceled> for Error {
     }
 }
 
+impl From<aptos_data_client::error::Error> for Error {
+    fn from(error: aptos_data_client::error::Error) -> Self {
+        Error::UnexpectedError(error.to_string())
+    }
+}
+
 impl From<aptos_data_streaming_service::error::Error> for Error {
     fn from(error: aptos_data_streaming_service::error::Error) -> Self {
         Error::UnexpectedError(error.to_string())
@@ -4,16 +4,20 @@
 
 #![forbid(unsafe_code)]
 
+pub mod backup_restorer;
 mod bootstrapper;
 mod continuous_syncer;
 mod driver;
 mod driver_client;
 pub mod driver_factory;
-mod error;
+pub mod error;
 mod logging;
 pub mod metadata_storage;
 pub mod metrics;
 mod notification_handlers;
+pub mod partial_state_reader;
+pub mod partial_state_storage;
+mod partial_state_syncer;
 mod storage_synchronizer;
 mod utils;
 
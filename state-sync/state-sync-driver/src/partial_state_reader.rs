@@ -0,0 +1,157 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::partial_state_storage::{PartialStateProgress, PartialStateStorage};
+use aptos_config::config::TrackedStateFilter;
+use aptos_storage_interface::{db_other_bail, AptosDbError, BlockHeight, DbReader, Result};
+use aptos_types::{
+    account_config::NewBlockEvent,
+    epoch_state::EpochState,
+    ledger_info::LedgerInfoWithSignatures,
+    proof::SparseMerkleProofExt,
+    state_store::{state_key::StateKey, state_key_prefix::StateKeyPrefix, state_value::StateValue},
+    transaction::Version,
+};
+use std::sync::Arc;
+
+/// A DB reader for partial state ("light") fullnodes. The ledger view (i.e.,
+/// the latest ledger info and block) and all tracked state are served from
+/// the partial state storage, at the synced version. Only the latest synced
+/// version is readable, and reads of untracked state keys fail. All other
+/// reads are delegated to the node's storage.
+pub struct PartialStateReader {
+    partial_state_storage: PartialStateStorage,
+    storage: Arc<dyn DbReader>,
+    tracked_state_filter: TrackedStateFilter,
+}
+
+impl PartialStateReader {
+    pub fn new(
+        partial_state_storage: PartialStateStorage,
+        storage: Arc<dyn DbReader>,
+        tracked_state_filter: TrackedStateFilter,
+    ) -> Self {
+        Self {
+            partial_state_storage,
+            storage,
+            tracked_state_filter,
+        }
+    }
+
+    /// Returns the synced progress, along with the latest synced block.
+    /// If no block has been synced yet, an error is returned.
+    fn get_synced_block(&self) -> Result<(PartialStateProgress, Version, NewBlockEvent)> {
+        let progress = self
+            .partial_state_storage
+            .get_progress()
+            .map_err(|error| AptosDbError::Other(error.to_string()))?
+            .ok_or_else(|| AptosDbError::NotFound("Partial state progress".into()))?;
+        match progress.latest_block.clone() {
+            Some((block_start_version, new_block_event)) => {
+                Ok((progress, block_start_version, new_block_event))
+            },
+            None => Err(AptosDbError::NotFound(
+                "Latest synced block of the partial state".into(),
+            )),
+        }
+    }
+
+    /// Returns the synced version, iff the given version is readable
+    fn ensure_synced_version(&self, version: Version) -> Result<Version> {
+        let (progress, _, _) = self.get_synced_block()?;
+        let synced_version = progress.synced_version();
+        if version != synced_version {
+            db_other_bail!(
+                "Only the synced version ({}) of the partial state is readable! Requested version: {}",
+                synced_version,
+                version
+            );
+        }
+        Ok(synced_version)
+    }
+
+    /// Ensures that the given state key is tracked
+    fn ensure_tracked(&self, state_key: &StateKey) -> Result<()> {
+        if !self.tracked_state_filter.is_tracked(state_key) {
+            db_other_bail!("The state key is not tracked: {:?}", state_key);
+        }
+        Ok(())
+    }
+}
+
+impl DbReader for PartialStateReader {
+    fn get_read_delegatee(&self) -> &dyn DbReader {
+        self.storage.as_ref()
+    }
+
+    fn get_first_viable_block(&self) -> Result<(Version, BlockHeight)> {
+        let (progress, _, new_block_event) = self.get_synced_block()?;
+        Ok((progress.synced_version(), new_block_event.height()))
+    }
+
+    fn get_block_info_by_version(
+        &self,
+        version: Version,
+    ) -> Result<(Version, Version, NewBlockEvent)> {
+        let synced_version = self.ensure_synced_version(version)?;
+        let (_, block_start_version, new_block_event) = self.get_synced_block()?;
+        Ok((block_start_version, synced_version, new_block_event))
+    }
+
+    fn get_latest_epoch_state(&self) -> Result<EpochState> {
+        let (progress, _, _) = self.get_synced_block()?;
+        Ok(progress.epoch_state)
+    }
+
+    fn get_latest_ledger_info_option(&self) -> Result<Option<LedgerInfoWithSignatures>> {
+        let (progress, _, _) = self.get_synced_block()?;
+        Ok(Some(progress.synced_ledger_info))
+    }
+
+    fn get_latest_version(&self) -> Result<Version> {
+        let (progress, _, _) = self.get_synced_block()?;
+        Ok(progress.synced_version())
+    }
+
+    fn get_latest_state_checkpoint_version(&self) -> Result<Option<Version>> {
+        let (progress, _, _) = self.get_synced_block()?;
+        Ok(Some(progress.synced_version()))
+    }
+
+    fn get_prefixed_state_value_iterator(
+        &self,
+        _key_prefix: &StateKeyPrefix,
+        _cursor: Option<&StateKey>,
+        _version: Version,
+    ) -> Result<Box<dyn Iterator<Item = Result<(StateKey, StateValue)>> + '_>> {
+        db_other_bail!("State iteration is not supported by the partial state!");
+    }
+
+    fn get_state_value_by_version(
+        &self,
+        state_key: &StateKey,
+        version: Version,
+    ) -> Result<Option<StateValue>> {
+        self.ensure_tracked(state_key)?;
+        self.ensure_synced_version(version)?;
+        self.partial_state_storage
+            .get_state_value(state_key)
+            .map_err(|error| AptosDbError::Other(error.to_string()))
+    }
+
+    fn get_state_value_with_version_by_version(
+        &self,
+        _state_key: &StateKey,
+        _version: Version,
+    ) -> Result<Option<(Version, StateValue)>> {
+        db_other_bail!("State value versions are not tracked by the partial state!");
+    }
+
+    fn get_state_value_with_proof_by_version_ext(
+        &self,
+        _state_key: &StateKey,
+        _version: Version,
+    ) -> Result<(Option<StateValue>, SparseMerkleProofExt)> {
+        db_other_bail!("State value proofs are not stored by the partial state!");
+    }
+}
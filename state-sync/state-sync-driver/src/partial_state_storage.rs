@@ -0,0 +1,305 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    error::Error,
+    partial_state_storage::database_schema::{
+        MetadataKey, MetadataValue, PartialStateMetadataSchema, PartialStateValueSchema,
+    },
+};
+use anyhow::{anyhow, Result};
+use aptos_logger::prelude::*;
+use aptos_schemadb::{
+    define_schema,
+    schema::{KeyCodec, ValueCodec},
+    ColumnFamilyName, Options, SchemaBatch, DB,
+};
+use aptos_types::{
+    account_config::NewBlockEvent,
+    epoch_state::EpochState,
+    ledger_info::LedgerInfoWithSignatures,
+    state_store::{state_key::StateKey, state_value::StateValue},
+    transaction::Version,
+};
+use serde::{Deserialize, Serialize};
+use std::{path::Path, sync::Arc, time::Instant};
+
+/// The name of the partial state db file
+pub const PARTIAL_STATE_DB_NAME: &str = "partial_state_db";
+
+/// The name of the metadata column family
+const METADATA_CF_NAME: ColumnFamilyName = "metadata";
+
+/// The name of the state value column family
+const STATE_VALUE_CF_NAME: ColumnFamilyName = "state_value";
+
+/// The storage used by partial state ("light") fullnodes. It holds the
+/// verified values of all tracked state keys, along with the ledger info
+/// (and epoch state) that the values were verified against. The progress
+/// and values are always committed atomically, so the values in storage
+/// are consistent with the synced ledger info.
+#[derive(Clone)]
+pub struct PartialStateStorage {
+    database: Arc<DB>,
+}
+
+impl PartialStateStorage {
+    pub fn new<P: AsRef<Path> + Clone>(db_root_path: P) -> Self {
+        // Set the options to create the database if it's missing
+        let mut options = Options::default();
+        options.create_if_missing(true);
+        options.create_missing_column_families(true);
+
+        // Open the database
+        let partial_state_db_path = db_root_path.as_ref().join(PARTIAL_STATE_DB_NAME);
+        let instant = Instant::now();
+        let database = DB::open(
+            partial_state_db_path.clone(),
+            "partial_state",
+            vec![METADATA_CF_NAME, STATE_VALUE_CF_NAME],
+            &options,
+        )
+        .unwrap_or_else(|error| {
+            panic!(
+                "Failed to open/create the partial state database at: {:?}. Error: {:?}",
+                partial_state_db_path, error
+            )
+        });
+        info!(
+            "Opened the partial state database at: {:?}, in {:?} ms",
+            partial_state_db_path,
+            instant.elapsed().as_millis()
+        );
+
+        let database = Arc::new(database);
+        Self { database }
+    }
+
+    /// Returns the partial state sync progress. Returns None if nothing
+    /// has been synced yet.
+    pub fn get_progress(&self) -> Result<Option<PartialStateProgress>, Error> {
+        let metadata_key = MetadataKey::PartialStateSync;
+        let maybe_metadata_value = self
+            .database
+            .get::<PartialStateMetadataSchema>(&metadata_key)
+            .map_err(|error| {
+                Error::StorageError(format!(
+                    "Failed to read metadata value for key: {:?}. Error: {:?}",
+                    metadata_key, error
+                ))
+            })?;
+        Ok(maybe_metadata_value.map(|metadata_value| {
+            let MetadataValue::PartialStateSync(progress) = metadata_value;
+            progress
+        }))
+    }
+
+    /// Returns the value of the given tracked state key at the synced version
+    pub fn get_state_value(&self, state_key: &StateKey) -> Result<Option<StateValue>, Error> {
+        self.database
+            .get::<PartialStateValueSchema>(state_key)
+            .map_err(|error| {
+                Error::StorageError(format!(
+                    "Failed to read the state value for key: {:?}. Error: {:?}",
+                    state_key, error
+                ))
+            })
+    }
+
+    /// Atomically commits the given progress along with the updates to the
+    /// tracked state values (a value of None deletes the key).
+    pub fn commit_progress(
+        &self,
+        progress: PartialStateProgress,
+        state_updates: Vec<(StateKey, Option<StateValue>)>,
+    ) -> Result<(), Error> {
+        // Create the schema batch
+        let batch = SchemaBatch::new();
+        for (state_key, state_value) in state_updates {
+            let result = match &state_value {
+                Some(state_value) => batch.put::<PartialStateValueSchema>(&state_key, state_value),
+                None => batch.delete::<PartialStateValueSchema>(&state_key),
+            };
+            result.map_err(|error| {
+                Error::StorageError(format!(
+                    "Failed to batch the state value update for key: {:?}. Error: {:?}",
+                    state_key, error
+                ))
+            })?;
+        }
+        batch
+            .put::<PartialStateMetadataSchema>(
+                &MetadataKey::PartialStateSync,
+                &MetadataValue::PartialStateSync(progress),
+            )
+            .map_err(|error| {
+                Error::StorageError(format!(
+                    "Failed to batch put the partial state progress. Error: {:?}",
+                    error
+                ))
+            })?;
+
+        // Write the schema batch to the database
+        self.database.write_schemas(batch).map_err(|error| {
+            Error::StorageError(format!(
+                "Failed to write the partial state schemas. Error: {:?}",
+                error
+            ))
+        })
+    }
+}
+
+/// The progress of a partial state sync, i.e., the (verified) ledger info
+/// the tracked state was last synced to.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PartialStateProgress {
+    pub synced_ledger_info: LedgerInfoWithSignatures,
+    pub epoch_state: EpochState,
+    pub latest_block: Option<(Version, NewBlockEvent)>, // The start version and event of the latest block
+}
+
+impl PartialStateProgress {
+    pub fn new(
+        synced_ledger_info: LedgerInfoWithSignatures,
+        epoch_state: EpochState,
+        latest_block: Option<(Version, NewBlockEvent)>,
+    ) -> Self {
+        Self {
+            synced_ledger_info,
+            epoch_state,
+            latest_block,
+        }
+    }
+
+    /// Returns the version the tracked state was last synced to
+    pub fn synced_version(&self) -> Version {
+        self.synced_ledger_info.ledger_info().version()
+    }
+}
+
+/// The raw schema format used by the database
+pub mod database_schema {
+    use super::*;
+
+    // This defines a physical storage schema for the partial state metadata.
+    //
+    // The key will be a bcs serialized MetadataKey type.
+    // The value will be a bcs serialized MetadataValue type.
+    //
+    // |<-------key------->|<-----value----->|
+    // |   metadata key    | metadata value  |
+    define_schema!(
+        PartialStateMetadataSchema,
+        MetadataKey,
+        MetadataValue,
+        METADATA_CF_NAME
+    );
+
+    // This defines a physical storage schema for the tracked state values.
+    //
+    // The key will be a bcs serialized StateKey type.
+    // The value will be a bcs serialized StateValue type.
+    //
+    // |<-----key----->|<-----value----->|
+    // |   state key   |   state value   |
+    define_schema!(
+        PartialStateValueSchema,
+        StateKey,
+        StateValue,
+        STATE_VALUE_CF_NAME
+    );
+
+    /// A metadata key that can be inserted into the database
+    #[derive(Debug, Deserialize, Eq, PartialEq, Serialize)]
+    #[repr(u8)]
+    pub enum MetadataKey {
+        PartialStateSync, // The progress of the partial state sync
+    }
+
+    /// A metadata value that can be inserted into the database
+    #[derive(Debug, Deserialize, Serialize)]
+    #[repr(u8)]
+    pub enum MetadataValue {
+        PartialStateSync(PartialStateProgress), // The partial state sync progress
+    }
+
+    impl KeyCodec<PartialStateMetadataSchema> for MetadataKey {
+        fn encode_key(&self) -> Result<Vec<u8>> {
+            bcs::to_bytes(self).map_err(|error| {
+                anyhow!(
+                    "Failed to encode metadata key: {:?}. Error: {:?}",
+                    self,
+                    error
+                )
+            })
+        }
+
+        fn decode_key(data: &[u8]) -> Result<Self> {
+            bcs::from_bytes::<MetadataKey>(data).map_err(|error| {
+                anyhow!(
+                    "Failed to decode metadata key: {:?}. Error: {:?}",
+                    data,
+                    error
+                )
+            })
+        }
+    }
+
+    impl ValueCodec<PartialStateMetadataSchema> for MetadataValue {
+        fn encode_value(&self) -> Result<Vec<u8>> {
+            bcs::to_bytes(self).map_err(|error| {
+                anyhow!(
+                    "Failed to encode metadata value: {:?}. Error: {:?}",
+                    self,
+                    error
+                )
+            })
+        }
+
+        fn decode_value(data: &[u8]) -> Result<Self> {
+            bcs::from_bytes::<MetadataValue>(data).map_err(|error| {
+                anyhow!(
+                    "Failed to decode metadata value: {:?}. Error: {:?}",
+                    data,
+                    error
+                )
+            })
+        }
+    }
+
+    impl KeyCodec<PartialStateValueSchema> for StateKey {
+        fn encode_key(&self) -> Result<Vec<u8>> {
+            bcs::to_bytes(self).map_err(|error| {
+                anyhow!("Failed to encode state key: {:?}. Error: {:?}", self, error)
+            })
+        }
+
+        fn decode_key(data: &[u8]) -> Result<Self> {
+            bcs::from_bytes::<StateKey>(data).map_err(|error| {
+                anyhow!("Failed to decode state key: {:?}. Error: {:?}", data, error)
+            })
+        }
+    }
+
+    impl ValueCodec<PartialStateValueSchema> for StateValue {
+        fn encode_value(&self) -> Result<Vec<u8>> {
+            bcs::to_bytes(self).map_err(|error| {
+                anyhow!(
+                    "Failed to encode state value: {:?}. Error: {:?}",
+                    self,
+                    error
+                )
+            })
+        }
+
+        fn decode_value(data: &[u8]) -> Result<Self> {
+            bcs::from_bytes::<StateValue>(data).map_err(|error| {
+                anyhow!(
+                    "Failed to decode state value: {:?}. Error: {:?}",
+                    data,
+                    error
+                )
+            })
+        }
+    }
+}
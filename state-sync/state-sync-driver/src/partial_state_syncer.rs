@@ -0,0 +1,483 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    driver_client::{ClientNotificationListener, DriverNotification},
+    error::Error,
+    logging::{LogEntry, LogSchema},
+    partial_state_storage::{PartialStateProgress, PartialStateStorage},
+};
+use aptos_config::config::{PartialStateConfig, TrackedStateFilter};
+use aptos_crypto::{hash::CryptoHash, HashValue};
+use aptos_data_client::interface::{AptosDataClientInterface, ResponseError};
+use aptos_logger::prelude::*;
+use aptos_storage_interface::DbReader;
+use aptos_types::{
+    account_config::{new_block_event_key, NewBlockEvent},
+    epoch_change::Verifier,
+    epoch_state::EpochState,
+    ledger_info::LedgerInfoWithSignatures,
+    state_store::{state_key::StateKey, state_value::StateValue},
+    transaction::Version,
+    waypoint::Waypoint,
+    write_set::TransactionWrite,
+};
+use futures::{
+    channel::oneshot,
+    stream::{self, StreamExt, TryStreamExt},
+};
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::time::interval;
+use tokio_stream::wrappers::IntervalStream;
+
+// Useful constants for the partial state syncer
+const SYNC_ERROR_LOG_FREQ_SECS: u64 = 3;
+
+/// The partial state syncer runs (in place of the state sync driver) on
+/// partial state ("light") fullnodes. It follows the ledger by verifying
+/// ledger infos (and epoch changes) and transaction outputs, but only
+/// persists the values of the tracked state keys. When the node first
+/// starts, the tracked values are fetched (and verified against the state
+/// root) using sparse merkle proofs. Thereafter, the tracked values are
+/// updated using the (verified) write sets of the synced transactions.
+pub struct PartialStateSyncer<DataClient> {
+    // The data client used to fetch (and verify) data from peers
+    aptos_data_client: DataClient,
+
+    // The listener for client notifications (e.g., bootstrap notifications)
+    client_notification_listener: ClientNotificationListener,
+
+    // The channel to notify once the tracked state has been synced
+    bootstrap_notifier_channel: Option<oneshot::Sender<Result<(), Error>>>,
+
+    // The config of the partial state syncer
+    partial_state_config: PartialStateConfig,
+
+    // The storage holding the tracked state
+    partial_state_storage: PartialStateStorage,
+
+    // The timeout (ms) for each data request
+    request_timeout_ms: u64,
+
+    // The node's storage (holding the trusted genesis)
+    storage: Arc<dyn DbReader>,
+
+    // The filter identifying the tracked state
+    tracked_state_filter: TrackedStateFilter,
+
+    // The waypoint the synced ledger must satisfy
+    waypoint: Waypoint,
+}
+
+impl<DataClient: AptosDataClientInterface + Send + Clone + 'static> PartialStateSyncer<DataClient> {
+    pub fn new(
+        aptos_data_client: DataClient,
+        client_notification_listener: ClientNotificationListener,
+        partial_state_config: PartialStateConfig,
+        partial_state_storage: PartialStateStorage,
+        request_timeout_ms: u64,
+        storage: Arc<dyn DbReader>,
+        tracked_state_filter: TrackedStateFilter,
+        waypoint: Waypoint,
+    ) -> Self {
+        Self {
+            aptos_data_client,
+            client_notification_listener,
+            bootstrap_notifier_channel: None,
+            partial_state_config,
+            partial_state_storage,
+            request_timeout_ms,
+            storage,
+            tracked_state_filter,
+            waypoint,
+        }
+    }
+
+    /// Starts the partial state syncer to periodically sync the tracked state
+    pub async fn start_syncer(mut self) {
+        let mut sync_interval = IntervalStream::new(interval(Duration::from_millis(
+            self.partial_state_config.sync_interval_ms,
+        )))
+        .fuse();
+
+        // Start the syncer
+        info!(LogSchema::new(LogEntry::PartialStateSyncer)
+            .message("Started the partial state syncer!"));
+        loop {
+            ::futures::select! {
+                notification = self.client_notification_listener.select_next_some() => {
+                    self.handle_client_notification(notification);
+                },
+                _ = sync_interval.select_next_some() => {
+                    if let Err(error) = self.sync_tracked_state().await {
+                        sample!(
+                            SampleRate::Duration(Duration::from_secs(SYNC_ERROR_LOG_FREQ_SECS)),
+                            warn!(LogSchema::new(LogEntry::PartialStateSyncer)
+                                .error(&error)
+                                .message("Error found when syncing the tracked state!"))
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Handles a notification sent by a driver client
+    fn handle_client_notification(&mut self, notification: DriverNotification) {
+        let DriverNotification::NotifyOnceBootstrapped(notifier_channel) = notification;
+        if self.bootstrap_notifier_channel.is_some() {
+            warn!(LogSchema::new(LogEntry::ClientNotification)
+                .message("Only one bootstrap subscriber is supported at a time!"));
+            return;
+        }
+
+        self.bootstrap_notifier_channel = Some(notifier_channel);
+        self.notify_listeners_if_bootstrapped();
+    }
+
+    /// Notifies any listeners once the tracked state has been synced
+    fn notify_listeners_if_bootstrapped(&mut self) {
+        let is_bootstrapped = matches!(self.partial_state_storage.get_progress(), Ok(Some(_)));
+        if is_bootstrapped {
+            if let Some(notifier_channel) = self.bootstrap_notifier_channel.take() {
+                if notifier_channel.send(Ok(())).is_err() {
+                    warn!(LogSchema::new(LogEntry::ClientNotification)
+                        .message("Failed to send the bootstrap notification!"));
+                }
+            }
+        }
+    }
+
+    /// Syncs the tracked state to the highest ledger info advertised by peers
+    pub(crate) async fn sync_tracked_state(&mut self) -> Result<(), Error> {
+        // Identify the ledger info and epoch state to sync from. If nothing
+        // has been synced, start from the latest ledger info in storage.
+        let (mut progress, is_initial_sync) = match self.partial_state_storage.get_progress()? {
+            Some(progress) => (progress, false),
+            None => {
+                let synced_ledger_info =
+                    self.storage.get_latest_ledger_info().map_err(|error| {
+                        Error::StorageError(format!(
+                            "Failed to get the latest ledger info from storage: {:?}",
+                            error
+                        ))
+                    })?;
+                let epoch_state = self.storage.get_latest_epoch_state().map_err(|error| {
+                    Error::StorageError(format!(
+                        "Failed to get the latest epoch state from storage: {:?}",
+                        error
+                    ))
+                })?;
+                (
+                    PartialStateProgress::new(synced_ledger_info, epoch_state, None),
+                    true,
+                )
+            },
+        };
+
+        // Identify the sync target
+        let target_ledger_info = self
+            .aptos_data_client
+            .get_global_data_summary()
+            .advertised_data
+            .highest_synced_ledger_info()
+            .ok_or_else(|| {
+                Error::AdvertisedDataError("No highest synced ledger info found!".into())
+            })?;
+        let target_version = target_ledger_info.ledger_info().version();
+        if target_version < self.waypoint.version() {
+            return Err(Error::AdvertisedDataError(format!(
+                "The highest advertised version ({}) is lower than the waypoint version ({})!",
+                target_version,
+                self.waypoint.version()
+            )));
+        }
+        if target_version <= progress.synced_version() {
+            return Ok(()); // We're already up-to-date
+        }
+
+        // Move through each epoch until we reach the epoch of the target. For
+        // the initial sync, only the ledger infos are verified (the tracked
+        // state is fetched directly at the target).
+        let mut state_updates = HashMap::new();
+        while progress.epoch_state.epoch < target_ledger_info.ledger_info().epoch() {
+            let epoch_ending_ledger_info = self
+                .fetch_epoch_ending_ledger_info(&progress.epoch_state)
+                .await?;
+            if !is_initial_sync {
+                self.sync_transaction_outputs(
+                    &mut progress,
+                    &epoch_ending_ledger_info,
+                    &mut state_updates,
+                )
+                .await?;
+            }
+            progress = create_progress(
+                epoch_ending_ledger_info,
+                &progress.epoch_state,
+                progress.latest_block,
+            );
+        }
+
+        // Verify the target and sync the tracked state to it
+        self.verify_ledger_info(&progress.epoch_state, &target_ledger_info)?;
+        if is_initial_sync {
+            state_updates = self.fetch_tracked_state(&target_ledger_info).await?;
+        } else {
+            self.sync_transaction_outputs(&mut progress, &target_ledger_info, &mut state_updates)
+                .await?;
+        }
+        let progress = create_progress(
+            target_ledger_info,
+            &progress.epoch_state,
+            progress.latest_block,
+        );
+
+        // Commit the new progress and tracked state
+        let synced_version = progress.synced_version();
+        self.partial_state_storage
+            .commit_progress(progress, state_updates.into_iter().collect())?;
+        debug!(
+            LogSchema::new(LogEntry::PartialStateSyncer).message(&format!(
+                "Synced the tracked state to version: {}",
+                synced_version
+            ))
+        );
+
+        // Notify any listeners that we've synced
+        self.notify_listeners_if_bootstrapped();
+
+        Ok(())
+    }
+
+    /// Fetches (and verifies) the epoch ending ledger info for the given epoch
+    async fn fetch_epoch_ending_ledger_info(
+        &self,
+        epoch_state: &EpochState,
+    ) -> Result<LedgerInfoWithSignatures, Error> {
+        let epoch = epoch_state.epoch;
+        let response = self
+            .aptos_data_client
+            .get_epoch_ending_ledger_infos(epoch, epoch, self.request_timeout_ms)
+            .await?;
+        let (context, ledger_infos) = response.into_parts();
+
+        // Verify the epoch ending ledger info
+        let result = match ledger_infos.first() {
+            Some(ledger_info) if ledger_info.ledger_info().ends_epoch() => self
+                .verify_ledger_info(epoch_state, ledger_info)
+                .map(|()| ledger_info.clone()),
+            _ => Err(Error::VerificationError(format!(
+                "No epoch ending ledger info was found for epoch: {}",
+                epoch
+            ))),
+        };
+        if result.is_err() {
+            context
+                .response_callback
+                .notify_bad_response(ResponseError::ProofVerificationError);
+        }
+        result
+    }
+
+    /// Verifies the given ledger info against the epoch state and waypoint
+    fn verify_ledger_info(
+        &self,
+        epoch_state: &EpochState,
+        ledger_info: &LedgerInfoWithSignatures,
+    ) -> Result<(), Error> {
+        epoch_state.verify(ledger_info).map_err(|error| {
+            Error::VerificationError(format!(
+                "Failed to verify the ledger info: {:?}. Error: {:?}",
+                ledger_info, error
+            ))
+        })?;
+        if ledger_info.ledger_info().version() == self.waypoint.version() {
+            self.waypoint
+                .verify(ledger_info.ledger_info())
+                .map_err(|error| {
+                    Error::VerificationError(format!(
+                        "Failed to verify the waypoint: {:?}. Error: {:?}",
+                        self.waypoint, error
+                    ))
+                })?;
+        }
+        Ok(())
+    }
+
+    /// Syncs (and verifies) the transaction outputs between the synced version
+    /// and the given target, and collects the updates to the tracked state
+    /// (and the latest block) along the way.
+    async fn sync_transaction_outputs(
+        &self,
+        progress: &mut PartialStateProgress,
+        target_ledger_info: &LedgerInfoWithSignatures,
+        state_updates: &mut HashMap<StateKey, Option<StateValue>>,
+    ) -> Result<(), Error> {
+        let target_version = target_ledger_info.ledger_info().version();
+        let mut next_version = progress.synced_version() + 1;
+        while next_version <= target_version {
+            // Fetch the next chunk of outputs (the chunk may be truncated)
+            let response = self
+                .aptos_data_client
+                .get_transaction_outputs_with_proof(
+                    target_version,
+                    next_version,
+                    target_version,
+                    self.request_timeout_ms,
+                )
+                .await?;
+            let (context, output_list_with_proof) = response.into_parts();
+
+            // Verify the outputs against the target
+            let num_outputs = output_list_with_proof.transactions_and_outputs.len() as u64;
+            if let Err(error) = output_list_with_proof
+                .verify(target_ledger_info.ledger_info(), Some(next_version))
+                .map_err(|error| error.to_string())
+                .and_then(|()| {
+                    if num_outputs == 0 {
+                        Err("The transaction output list is empty!".into())
+                    } else {
+                        Ok(())
+                    }
+                })
+            {
+                context
+                    .response_callback
+                    .notify_bad_response(ResponseError::ProofVerificationError);
+                return Err(Error::VerificationError(format!(
+                    "Failed to verify the transaction outputs at version: {}. Error: {}",
+                    next_version, error
+                )));
+            }
+
+            // Collect the tracked state updates and the latest block
+            for (index, (_, output)) in output_list_with_proof
+                .transactions_and_outputs
+                .iter()
+                .enumerate()
+            {
+                for (state_key, write_op) in output.write_set() {
+                    if self.tracked_state_filter.is_tracked(state_key) {
+                        state_updates.insert(state_key.clone(), write_op.as_state_value());
+                    }
+                }
+                for event in output.events() {
+                    if event.event_key() == Some(&new_block_event_key()) {
+                        let new_block_event = event.expect_new_block_event().map_err(|error| {
+                            Error::VerificationError(format!(
+                                "Failed to decode the new block event: {:?}",
+                                error
+                            ))
+                        })?;
+                        progress.latest_block =
+                            Some((next_version + index as u64, new_block_event));
+                    }
+                }
+            }
+            next_version += num_outputs;
+        }
+
+        Ok(())
+    }
+
+    /// Fetches (and verifies) the values of all tracked state keys at the
+    /// given ledger info, using sparse merkle proofs.
+    async fn fetch_tracked_state(
+        &self,
+        ledger_info: &LedgerInfoWithSignatures,
+    ) -> Result<HashMap<StateKey, Option<StateValue>>, Error> {
+        // Fetch (and verify) the state checkpoint hash at the ledger info
+        let version = ledger_info.ledger_info().version();
+        let state_root_hash = self.fetch_state_checkpoint_hash(ledger_info).await?;
+
+        // Fetch (and verify) all tracked state values
+        stream::iter(self.tracked_state_filter.tracked_state_keys())
+            .map(|state_key| self.fetch_state_value(state_key, version, state_root_hash))
+            .buffer_unordered(self.partial_state_config.max_concurrent_state_requests as usize)
+            .try_collect()
+            .await
+    }
+
+    /// Fetches (and verifies) the state checkpoint hash at the given ledger info
+    async fn fetch_state_checkpoint_hash(
+        &self,
+        ledger_info: &LedgerInfoWithSignatures,
+    ) -> Result<HashValue, Error> {
+        let version = ledger_info.ledger_info().version();
+        let response = self
+            .aptos_data_client
+            .get_transaction_outputs_with_proof(version, version, version, self.request_timeout_ms)
+            .await?;
+        let (context, output_list_with_proof) = response.into_parts();
+
+        // Verify the output and extract the state checkpoint hash
+        let result = output_list_with_proof
+            .verify(ledger_info.ledger_info(), Some(version))
+            .map_err(|error| error.to_string())
+            .and_then(|()| {
+                output_list_with_proof
+                    .proof
+                    .transaction_infos
+                    .first()
+                    .and_then(|transaction_info| transaction_info.state_checkpoint_hash())
+                    .ok_or_else(|| "No state checkpoint hash was found!".into())
+            });
+        result.map_err(|error| {
+            context
+                .response_callback
+                .notify_bad_response(ResponseError::ProofVerificationError);
+            Error::VerificationError(format!(
+                "Failed to verify the state checkpoint at version: {}. Error: {}",
+                version, error
+            ))
+        })
+    }
+
+    /// Fetches (and verifies) the value of the given state key at the version
+    async fn fetch_state_value(
+        &self,
+        state_key: StateKey,
+        version: Version,
+        state_root_hash: HashValue,
+    ) -> Result<(StateKey, Option<StateValue>), Error> {
+        let response = self
+            .aptos_data_client
+            .get_state_value_with_proof(state_key.clone(), version, self.request_timeout_ms)
+            .await?;
+        let (context, state_value_with_proof) = response.into_parts();
+
+        // Verify the state value against the state root
+        let state_value = state_value_with_proof.state_value;
+        if let Err(error) = state_value_with_proof.proof.verify(
+            state_root_hash,
+            CryptoHash::hash(&state_key),
+            state_value.as_ref(),
+        ) {
+            context
+                .response_callback
+                .notify_bad_response(ResponseError::ProofVerificationError);
+            return Err(Error::VerificationError(format!(
+                "Failed to verify the state value for key: {:?}. Error: {:?}",
+                state_key, error
+            )));
+        }
+
+        Ok((state_key, state_value))
+    }
+}
+
+/// Creates the progress for the given (verified) ledger info. If the ledger
+/// info ends the epoch, the epoch state is that of the next epoch.
+fn create_progress(
+    ledger_info: LedgerInfoWithSignatures,
+    epoch_state: &EpochState,
+    latest_block: Option<(Version, NewBlockEvent)>,
+) -> PartialStateProgress {
+    let epoch_state = ledger_info
+        .ledger_info()
+        .next_epoch_state()
+        .cloned()
+        .unwrap_or_else(|| epoch_state.clone());
+    PartialStateProgress::new(ledger_info, epoch_state, latest_block)
+}
@@ -2,13 +2,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    backup_restorer::BackupRestorerInterface,
     bootstrapper::{Bootstrapper, GENESIS_TRANSACTION_VERSION},
     driver::DriverConfiguration,
     error::Error,
     tests::{
         mocks::{
-            create_mock_db_reader, create_mock_streaming_client, create_ready_storage_synchronizer,
-            MockMetadataStorage, MockStorageSynchronizer, MockStreamingClient,
+            create_mock_backup_restorer, create_mock_db_reader, create_mock_streaming_client,
+            create_ready_storage_synchronizer, MockMetadataStorage, MockStorageSynchronizer,
+            MockStreamingClient,
         },
         utils::{
             create_data_stream_listener, create_empty_epoch_state, create_epoch_ending_ledger_info,
@@ -31,10 +33,12 @@ use aptos_types::{
     transaction::{TransactionOutputListWithProof, Version},
     waypoint::Waypoint,
 };
+use async_trait::async_trait;
 use claims::{assert_matches, assert_none, assert_ok};
 use futures::{channel::oneshot, FutureExt, SinkExt};
 use mockall::{predicate::eq, Sequence};
 use std::{sync::Arc, time::Duration};
+use tokio::sync::Notify;
 
 #[tokio::test]
 async fn test_bootstrap_genesis_waypoint() {
@@ -1581,6 +1585,171 @@ async fn test_waypoint_satisfiable() {
     assert_matches!(error, Error::UnsatisfiableWaypoint(_));
 }
 
+#[tokio::test]
+async fn test_restore_from_backup() {
+    // Create a driver configuration with a genesis waypoint and backup restores
+    let mut driver_configuration = create_full_node_driver_configuration();
+    driver_configuration.config.bootstrapping_mode = BootstrappingMode::RestoreFromBackup;
+
+    // Create the mock backup restorer and expect a single restore
+    let mut mock_backup_restorer = create_mock_backup_restorer();
+    mock_backup_restorer
+        .expect_restore_latest_state_snapshot()
+        .times(1)
+        .returning(|| Ok(GENESIS_TRANSACTION_VERSION));
+
+    // Create the bootstrapper with only genesis in storage
+    let mut bootstrapper = create_bootstrapper_with_backup_restorer(
+        driver_configuration,
+        Arc::new(mock_backup_restorer),
+        Some(GENESIS_TRANSACTION_VERSION),
+    );
+
+    // Create a global data summary where only epoch 0 has ended
+    let global_data_summary = create_global_summary(0);
+
+    // Drive progress and verify the node is restored, but not yet bootstrapped
+    drive_progress(&mut bootstrapper, &global_data_summary, false)
+        .await
+        .unwrap();
+    assert!(!bootstrapper.is_bootstrapped());
+
+    // Drive progress until we're bootstrapped (without restoring again)
+    drive_progress(&mut bootstrapper, &global_data_summary, true)
+        .await
+        .unwrap();
+    assert!(bootstrapper.is_bootstrapped());
+}
+
+#[tokio::test]
+async fn test_restore_from_backup_existing_state() {
+    // Create a driver configuration with a genesis waypoint and backup restores
+    let mut driver_configuration = create_full_node_driver_configuration();
+    driver_configuration.config.bootstrapping_mode = BootstrappingMode::RestoreFromBackup;
+
+    // Create the mock backup restorer (no restores are expected)
+    let mock_backup_restorer = create_mock_backup_restorer();
+
+    // Create the bootstrapper with existing state in storage
+    let mut bootstrapper = create_bootstrapper_with_backup_restorer(
+        driver_configuration,
+        Arc::new(mock_backup_restorer),
+        Some(100),
+    );
+
+    // Create a global data summary where only epoch 0 has ended
+    let global_data_summary = create_global_summary(0);
+
+    // Drive progress until we're bootstrapped and verify no restore took place
+    drive_progress(&mut bootstrapper, &global_data_summary, true)
+        .await
+        .unwrap();
+    assert!(bootstrapper.is_bootstrapped());
+}
+
+#[tokio::test]
+async fn test_restore_from_backup_in_progress() {
+    // Create a driver configuration with a genesis waypoint and backup restores
+    let mut driver_configuration = create_full_node_driver_configuration();
+    driver_configuration.config.bootstrapping_mode = BootstrappingMode::RestoreFromBackup;
+
+    // Create a backup restorer that only completes the restore once notified
+    let restore_notifier = Arc::new(Notify::new());
+    let backup_restorer = Arc::new(NotifiedBackupRestorer {
+        restore_notifier: restore_notifier.clone(),
+    });
+
+    // Create the bootstrapper with only genesis in storage
+    let mut bootstrapper = create_bootstrapper_with_backup_restorer(
+        driver_configuration,
+        backup_restorer,
+        Some(GENESIS_TRANSACTION_VERSION),
+    );
+
+    // Create a global data summary where only epoch 0 has ended
+    let global_data_summary = create_global_summary(0);
+
+    // Drive progress several times and verify the bootstrapper isn't blocked by the restore
+    for _ in 0..5 {
+        drive_progress(&mut bootstrapper, &global_data_summary, false)
+            .await
+            .unwrap();
+        tokio::task::yield_now().await;
+        assert!(!bootstrapper.is_bootstrapped());
+    }
+
+    // Complete the restore and drive progress until we're bootstrapped
+    restore_notifier.notify_one();
+    drive_progress(&mut bootstrapper, &global_data_summary, true)
+        .await
+        .unwrap();
+    assert!(bootstrapper.is_bootstrapped());
+}
+
+#[tokio::test]
+async fn test_restore_from_backup_retry() {
+    // Create a driver configuration with a genesis waypoint and backup restores
+    let mut driver_configuration = create_full_node_driver_configuration();
+    driver_configuration.config.bootstrapping_mode = BootstrappingMode::RestoreFromBackup;
+
+    // Create the mock backup restorer and expect a failed restore (followed by a retry)
+    let mut mock_backup_restorer = create_mock_backup_restorer();
+    let mut expectation_sequence = Sequence::new();
+    mock_backup_restorer
+        .expect_restore_latest_state_snapshot()
+        .times(1)
+        .returning(|| {
+            Err(Error::BackupRestoreError(
+                "Failed to read the backup!".into(),
+            ))
+        })
+        .in_sequence(&mut expectation_sequence);
+    mock_backup_restorer
+        .expect_restore_latest_state_snapshot()
+        .times(1)
+        .returning(|| Ok(GENESIS_TRANSACTION_VERSION))
+        .in_sequence(&mut expectation_sequence);
+
+    // Create the bootstrapper with only genesis in storage
+    let mut bootstrapper = create_bootstrapper_with_backup_restorer(
+        driver_configuration,
+        Arc::new(mock_backup_restorer),
+        Some(GENESIS_TRANSACTION_VERSION),
+    );
+
+    // Create a global data summary where only epoch 0 has ended
+    let global_data_summary = create_global_summary(0);
+
+    // Drive progress until the failed restore is reported
+    let error = loop {
+        if let Err(error) = drive_progress(&mut bootstrapper, &global_data_summary, false).await {
+            break error;
+        }
+        tokio::task::yield_now().await;
+    };
+    assert_matches!(error, Error::BackupRestoreError(_));
+    assert!(!bootstrapper.is_bootstrapped());
+
+    // Drive progress until we're bootstrapped (the restore should be retried)
+    drive_progress(&mut bootstrapper, &global_data_summary, true)
+        .await
+        .unwrap();
+    assert!(bootstrapper.is_bootstrapped());
+}
+
+/// A backup restorer that only completes the restore once notified
+struct NotifiedBackupRestorer {
+    restore_notifier: Arc<Notify>,
+}
+
+#[async_trait]
+impl BackupRestorerInterface for NotifiedBackupRestorer {
+    async fn restore_latest_state_snapshot(&self) -> Result<Version, Error> {
+        self.restore_notifier.notified().await;
+        Ok(GENESIS_TRANSACTION_VERSION)
+    }
+}
+
 /// Creates a bootstrapper for testing
 fn create_bootstrapper(
     driver_configuration: DriverConfiguration,
@@ -1622,6 +1791,7 @@ fn create_bootstrapper(
 
     // Create the bootstrapper
     let bootstrapper = Bootstrapper::new(
+        None,
         driver_configuration,
         metadata_storage,
         output_fallback_handler.clone(),
@@ -1677,6 +1847,7 @@ fn create_bootstrapper_with_storage(
         OutputFallbackHandler::new(driver_configuration.clone(), TimeService::mock());
 
     Bootstrapper::new(
+        None,
         driver_configuration,
         mock_metadata_storage,
         output_fallback_handler,
@@ -1686,6 +1857,48 @@ fn create_bootstrapper_with_storage(
     )
 }
 
+/// Creates a bootstrapper for testing with the given backup restorer
+fn create_bootstrapper_with_backup_restorer(
+    driver_configuration: DriverConfiguration,
+    backup_restorer: Arc<dyn BackupRestorerInterface>,
+    latest_state_checkpoint_version: Option<Version>,
+) -> Bootstrapper<MockMetadataStorage, MockStorageSynchronizer, MockStreamingClient> {
+    // Initialize the logger for tests
+    aptos_logger::Logger::init_for_testing();
+
+    // Create the mock storage synchronizer
+    let mock_storage_synchronizer = create_ready_storage_synchronizer(true);
+
+    // Create the mock db reader with only genesis ledger infos
+    let mut mock_database_reader = create_mock_db_reader();
+    mock_database_reader
+        .expect_get_latest_epoch_state()
+        .returning(|| Ok(create_empty_epoch_state()));
+    mock_database_reader
+        .expect_get_latest_ledger_info()
+        .returning(|| Ok(create_epoch_ending_ledger_info()));
+    mock_database_reader
+        .expect_get_latest_version()
+        .returning(|| Ok(0));
+    mock_database_reader
+        .expect_get_latest_state_checkpoint_version()
+        .returning(move || Ok(latest_state_checkpoint_version));
+
+    // Create the output fallback handler
+    let output_fallback_handler =
+        OutputFallbackHandler::new(driver_configuration.clone(), TimeService::mock());
+
+    Bootstrapper::new(
+        Some(backup_restorer),
+        driver_configuration,
+        MockMetadataStorage::new(),
+        output_fallback_handler,
+        create_mock_streaming_client(),
+        Arc::new(mock_database_reader),
+        mock_storage_synchronizer,
+    )
+}
+
 /// Drives progress for the given bootstrapper. If `until_bootstrapped`
 /// is true this method will continue to drive the bootstrapper until
 /// bootstrapping is complete.
@@ -1706,6 +1919,9 @@ async fn drive_progress(
         if !until_bootstrapped || bootstrapper.is_bootstrapped() {
             return Ok(());
         }
+
+        // Yield to allow any spawned tasks (e.g., backup restores) to make progress
+        tokio::task::yield_now().await;
     }
 }
 
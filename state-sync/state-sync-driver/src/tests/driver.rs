@@ -3,6 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    driver::consensus_observer_committed_recently,
     driver_factory::DriverFactory,
     metadata_storage::PersistentMetadataStorage,
     notification_handlers::CommitNotification,
@@ -10,7 +11,7 @@ use crate::{
         create_event, create_ledger_info_at_version, create_transaction, verify_commit_notification,
     },
 };
-use aptos_config::config::{NodeConfig, RoleType, StateSyncDriverConfig};
+use aptos_config::config::{ConsensusObserverConfig, NodeConfig, RoleType, StateSyncDriverConfig};
 use aptos_consensus_notifications::{ConsensusNotificationSender, ConsensusNotifier};
 use aptos_data_client::client::AptosDataClient;
 use aptos_data_streaming_service::streaming_client::new_streaming_service_client_listener_pair;
@@ -38,7 +39,11 @@ use aptos_vm::AptosVM;
 use claims::{assert_err, assert_none};
 use futures::{channel::mpsc::UnboundedSender, FutureExt, SinkExt, StreamExt};
 use ntest::timeout;
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use tokio::time::sleep;
 
 #[tokio::test(flavor = "multi_thread")]
@@ -82,6 +87,62 @@ async fn test_consensus_commit_notification() {
     assert_err!(result);
 }
 
+#[tokio::test]
+#[timeout(120_000)]
+async fn test_consensus_observer_commit_notification() {
+    // Create a driver for a full node that runs the consensus observer
+    let mut node_config = NodeConfig::default();
+    node_config.base.role = RoleType::FullNode;
+    node_config.consensus_observer.observer_enabled = true;
+    let (_full_node_driver, _, consensus_notifier, _, _, _, _, _) =
+        create_driver_for_tests(node_config, Waypoint::default(), None).await;
+
+    // Verify that the commit notification is only rejected because the node isn't bootstrapped
+    let error = consensus_notifier
+        .notify_new_commit(vec![create_transaction()], vec![])
+        .await
+        .unwrap_err();
+    let error = format!("{:?}", error);
+    assert!(error.contains("BootstrapNotComplete"));
+    assert!(!error.contains("FullNodeConsensusNotification"));
+}
+
+#[test]
+fn test_consensus_observer_fallback_duration() {
+    let fallback_duration =
+        Duration::from_millis(ConsensusObserverConfig::default().observer_fallback_duration_ms);
+    let last_commit_time = Instant::now();
+
+    // Verify that the observer is not executing if it never committed
+    assert!(!consensus_observer_committed_recently(
+        None,
+        last_commit_time,
+        fallback_duration
+    ));
+
+    // Verify that the observer is executing until the fallback duration elapses
+    for elapsed_time in [
+        Duration::ZERO,
+        fallback_duration / 2,
+        fallback_duration - Duration::from_millis(1),
+    ] {
+        assert!(consensus_observer_committed_recently(
+            Some(last_commit_time),
+            last_commit_time + elapsed_time,
+            fallback_duration
+        ));
+    }
+
+    // Verify that the continuous syncer takes over once the fallback duration elapses
+    for elapsed_time in [fallback_duration, fallback_duration * 2] {
+        assert!(!consensus_observer_committed_recently(
+            Some(last_commit_time),
+            last_commit_time + elapsed_time,
+            fallback_duration
+        ));
+    }
+}
+
 #[tokio::test(flavor = "multi_thread")]
 #[timeout(120_000)]
 async fn test_snapshot_commit_notifications() {
@@ -401,6 +462,7 @@ async fn create_driver_for_tests(
             mempool_notifier,
             storage_service_notifier,
             metadata_storage,
+            None,
             consensus_listener,
             event_subscription_service,
             aptos_data_client,
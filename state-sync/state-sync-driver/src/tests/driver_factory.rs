@@ -94,6 +94,7 @@ fn test_new_initialized_configs() {
         mempool_notifier,
         storage_service_notifier,
         metadata_storage,
+        None,
         consensus_listener,
         event_subscription_service,
         aptos_data_client,
@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
+    backup_restorer::BackupRestorerInterface,
     error::Error,
     metadata_storage::MetadataStorageInterface,
     storage_synchronizer::{NotificationMetadata, StorageSynchronizerInterface},
@@ -49,6 +50,11 @@ use tokio::task::JoinHandle;
 // TODO(joshlind): if we see these as generally useful, we should
 // modify the definitions in the rest of the code.
 
+/// Creates a mock backup restorer
+pub fn create_mock_backup_restorer() -> MockBackupRestorer {
+    MockBackupRestorer::new()
+}
+
 /// Creates a mock chunk executor
 pub fn create_mock_executor() -> MockChunkExecutor {
     MockChunkExecutor::new()
@@ -131,6 +137,15 @@ pub fn create_ready_storage_synchronizer(expect_reset_executor: bool) -> MockSto
     mock_storage_synchronizer
 }
 
+// This automatically creates a MockBackupRestorer.
+mock! {
+    pub BackupRestorer {}
+    #[async_trait]
+    impl BackupRestorerInterface for BackupRestorer {
+        async fn restore_latest_state_snapshot(&self) -> AnyhowResult<Version, Error>;
+    }
+}
+
 // This automatically creates a MockChunkExecutor.
 mock! {
     pub ChunkExecutor {}
@@ -237,6 +252,8 @@ mock! {
 
         fn get_latest_version(&self) -> Result<Version>;
 
+        fn get_latest_state_checkpoint_version(&self) -> Result<Option<Version>>;
+
         fn get_latest_commit_metadata(&self) -> Result<(Version, u64)>;
 
         fn get_account_transaction(
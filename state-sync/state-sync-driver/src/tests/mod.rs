@@ -8,5 +8,7 @@ mod driver;
 mod driver_factory;
 mod metadata_storage;
 mod mocks;
+mod partial_state_storage;
+mod partial_state_syncer;
 mod storage_synchronizer;
 mod utils;
@@ -0,0 +1,77 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    partial_state_storage::{
+        database_schema::PartialStateValueSchema, PartialStateProgress, PartialStateStorage,
+    },
+    tests::utils::{create_epoch_state, create_ledger_info_at_version},
+};
+use aptos_schemadb::schema::fuzzing::assert_encode_decode;
+use aptos_temppath::TempPath;
+use aptos_types::state_store::{state_key::StateKey, state_value::StateValue};
+use claims::assert_none;
+
+#[test]
+fn test_create_then_open() {
+    // Create a new partial state storage
+    let tmp_dir = TempPath::new();
+    let partial_state_storage = PartialStateStorage::new(tmp_dir.path());
+
+    // Verify the storage is empty
+    let state_key = StateKey::raw(vec![1, 2, 3]);
+    assert_none!(partial_state_storage.get_progress().unwrap());
+    assert_none!(partial_state_storage.get_state_value(&state_key).unwrap());
+
+    // Commit the progress along with a new state value
+    let state_value = StateValue::from(vec![4, 5, 6]);
+    let progress = create_progress(100, 1);
+    partial_state_storage
+        .commit_progress(progress, vec![(
+            state_key.clone(),
+            Some(state_value.clone()),
+        )])
+        .unwrap();
+
+    // Drop the handle to the storage (mimic a reboot)
+    drop(partial_state_storage);
+
+    // Create another storage (it should reopen the existing file) and verify the state
+    let partial_state_storage = PartialStateStorage::new(tmp_dir.path());
+    let progress = partial_state_storage.get_progress().unwrap().unwrap();
+    assert_eq!(progress.synced_version(), 100);
+    assert_eq!(progress.epoch_state, create_epoch_state(1));
+    assert_eq!(
+        partial_state_storage.get_state_value(&state_key).unwrap(),
+        Some(state_value)
+    );
+
+    // Commit the next progress along with the deletion of the state value
+    let progress = create_progress(200, 2);
+    partial_state_storage
+        .commit_progress(progress, vec![(state_key.clone(), None)])
+        .unwrap();
+
+    // Verify the progress was updated and the state value was deleted
+    let progress = partial_state_storage.get_progress().unwrap().unwrap();
+    assert_eq!(progress.synced_version(), 200);
+    assert_eq!(progress.epoch_state, create_epoch_state(2));
+    assert_none!(partial_state_storage.get_state_value(&state_key).unwrap());
+}
+
+#[test]
+fn test_state_value_schema_encode_decode() {
+    assert_encode_decode::<PartialStateValueSchema>(
+        &StateKey::raw(vec![1, 2, 3]),
+        &StateValue::from(vec![4, 5, 6]),
+    );
+}
+
+/// Creates a partial state progress at the given version and epoch
+fn create_progress(version: u64, epoch: u64) -> PartialStateProgress {
+    PartialStateProgress::new(
+        create_ledger_info_at_version(version),
+        create_epoch_state(epoch),
+        None,
+    )
+}
@@ -0,0 +1,1005 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    driver_client::ClientNotificationListener,
+    error::Error,
+    partial_state_storage::{PartialStateProgress, PartialStateStorage},
+    partial_state_syncer::PartialStateSyncer,
+    tests::{
+        mocks::create_mock_db_reader,
+        utils::{create_event, create_ledger_info_at_version, create_transaction},
+    },
+};
+use aptos_accumulator::{HashReader, MerkleAccumulator};
+use aptos_config::config::{PartialStateConfig, TrackedStateFilter};
+use aptos_crypto::{
+    hash::{CryptoHash, TransactionAccumulatorHasher},
+    HashValue,
+};
+use aptos_data_client::{
+    error,
+    global_summary::GlobalDataSummary,
+    interface::{
+        AptosDataClientInterface, Response, ResponseCallback, ResponseContext, ResponseError,
+        SubscriptionRequestMetadata,
+    },
+};
+use aptos_infallible::Mutex;
+use aptos_scratchpad::test_utils::naive_smt::NaiveSmt;
+use aptos_storage_service_types::{
+    responses::{StateValueWithProof, TransactionOrOutputListWithProof},
+    Epoch,
+};
+use aptos_temppath::TempPath;
+use aptos_types::{
+    access_path::AccessPath,
+    account_address::AccountAddress,
+    account_config::{new_block_event_key, NewBlockEvent},
+    aggregate_signature::AggregateSignature,
+    block_info::BlockInfo,
+    contract_event::ContractEvent,
+    epoch_state::EpochState,
+    ledger_info::{generate_ledger_info_with_sig, LedgerInfo, LedgerInfoWithSignatures},
+    proof::{
+        accumulator::InMemoryEventAccumulator, position::Position, TransactionInfoListWithProof,
+    },
+    state_store::{
+        state_key::StateKey,
+        state_value::{StateValue, StateValueChunkWithProof},
+    },
+    transaction::{
+        ExecutionStatus, Transaction, TransactionAuxiliaryData, TransactionInfo,
+        TransactionListWithProof, TransactionOutput, TransactionOutputListWithProof,
+        TransactionStatus, Version,
+    },
+    validator_signer::ValidatorSigner,
+    validator_verifier::{ValidatorConsensusInfo, ValidatorVerifier},
+    waypoint::Waypoint,
+    write_set::{TransactionWrite, WriteOp, WriteSet, WriteSetMut},
+};
+use async_trait::async_trait;
+use claims::{assert_matches, assert_none, assert_ok};
+use futures::channel::mpsc;
+use move_core_types::{
+    language_storage::{StructTag, TypeTag},
+    move_resource::MoveStructType,
+};
+use std::{collections::HashMap, str::FromStr, sync::Arc};
+
+// Useful test constants
+const ACCOUNT_TYPE: &str = "0x1::account::Account";
+const COIN_STORE_TYPE: &str = "0x1::coin::CoinStore<0x1::aptos_coin::AptosCoin>";
+const MAX_OUTPUT_CHUNK_SIZE: u64 = 3;
+const NUM_VALIDATORS: usize = 4;
+
+#[tokio::test]
+async fn test_initial_sync() {
+    // Create a ledger that writes both tracked and untracked state
+    let state_keys = TestStateKeys::new();
+    let mut ledger = TestLedger::new();
+    ledger.commit_writes(&state_keys, 5);
+    ledger.commit_ledger_info();
+
+    // Sync the tracked state and verify the tracked values were fetched
+    let mut test_syncer = TestSyncer::new(ledger, &state_keys, Waypoint::default());
+    assert_ok!(test_syncer.sync().await);
+    test_syncer.verify_synced_state(&state_keys);
+
+    // Verify that the untracked state was not persisted
+    test_syncer.verify_untracked_state(&state_keys);
+    assert_none!(test_syncer.get_state_value(&state_keys.tracked_group_key));
+
+    // Verify that only the state values were fetched (no outputs were synced)
+    assert_none!(test_syncer.get_progress().latest_block);
+    test_syncer.verify_bad_responses(0);
+}
+
+#[tokio::test]
+async fn test_initial_sync_epoch_changes() {
+    // Create a ledger with several epoch changes
+    let state_keys = TestStateKeys::new();
+    let mut ledger = TestLedger::new();
+    for _ in 0..3 {
+        ledger.commit_writes(&state_keys, 2);
+        ledger.end_epoch();
+    }
+    ledger.commit_writes(&state_keys, 2);
+    ledger.commit_ledger_info();
+
+    // Sync the tracked state and verify the epochs were walked to the target
+    let mut test_syncer = TestSyncer::new(ledger, &state_keys, Waypoint::default());
+    assert_ok!(test_syncer.sync().await);
+    assert_eq!(test_syncer.get_progress().epoch_state.epoch, 4);
+    test_syncer.verify_synced_state(&state_keys);
+    test_syncer.verify_untracked_state(&state_keys);
+    test_syncer.verify_bad_responses(0);
+}
+
+#[tokio::test]
+async fn test_sync_transaction_outputs() {
+    // Create a ledger and sync the initial tracked state
+    let state_keys = TestStateKeys::new();
+    let mut ledger = TestLedger::new();
+    ledger.commit_writes(&state_keys, 2);
+    ledger.commit_ledger_info();
+    let mut test_syncer = TestSyncer::new(ledger, &state_keys, Waypoint::default());
+    assert_ok!(test_syncer.sync().await);
+
+    // Commit more writes (spanning several output chunks) and a new block
+    let block_version = test_syncer.update_ledger(|ledger| {
+        ledger.commit_writes(&state_keys, 4);
+        let block_version = ledger.commit_new_block(7);
+        ledger.commit_writes(&state_keys, 3);
+        ledger.commit_ledger_info();
+        block_version
+    });
+
+    // Sync the outputs and verify the tracked state and latest block
+    assert_ok!(test_syncer.sync().await);
+    test_syncer.verify_synced_state(&state_keys);
+    test_syncer.verify_untracked_state(&state_keys);
+    let (latest_block_version, new_block_event) = test_syncer.get_progress().latest_block.unwrap();
+    assert_eq!(latest_block_version, block_version);
+    assert_eq!(new_block_event.round(), 7);
+
+    // Delete the tracked resource and verify the deletion is synced
+    test_syncer.update_ledger(|ledger| {
+        ledger.commit_transaction(
+            create_write_set(vec![(
+                state_keys.tracked_key.clone(),
+                WriteOp::legacy_deletion(),
+            )]),
+            vec![],
+        );
+        ledger.commit_ledger_info();
+    });
+    assert_ok!(test_syncer.sync().await);
+    assert_none!(test_syncer.get_state_value(&state_keys.tracked_key));
+    test_syncer.verify_bad_responses(0);
+
+    // Verify that syncing again (with no new data) is a no-op
+    let synced_version = test_syncer.get_progress().synced_version();
+    assert_ok!(test_syncer.sync().await);
+    assert_eq!(test_syncer.get_progress().synced_version(), synced_version);
+}
+
+#[tokio::test]
+async fn test_sync_transaction_outputs_epoch_changes() {
+    // Create a ledger and sync the initial tracked state
+    let state_keys = TestStateKeys::new();
+    let mut ledger = TestLedger::new();
+    ledger.commit_writes(&state_keys, 2);
+    ledger.commit_ledger_info();
+    let mut test_syncer = TestSyncer::new(ledger, &state_keys, Waypoint::default());
+    assert_ok!(test_syncer.sync().await);
+
+    // Commit writes across several epoch changes
+    test_syncer.update_ledger(|ledger| {
+        for _ in 0..3 {
+            ledger.commit_writes(&state_keys, 4);
+            ledger.end_epoch();
+        }
+        ledger.commit_writes(&state_keys, 1);
+        ledger.commit_ledger_info();
+    });
+
+    // Sync the outputs and verify the epochs were walked to the target
+    assert_ok!(test_syncer.sync().await);
+    assert_eq!(test_syncer.get_progress().epoch_state.epoch, 4);
+    test_syncer.verify_synced_state(&state_keys);
+    test_syncer.verify_untracked_state(&state_keys);
+    test_syncer.verify_bad_responses(0);
+}
+
+#[tokio::test]
+async fn test_waypoint_verification() {
+    // Create a ledger with several epoch changes
+    let state_keys = TestStateKeys::new();
+    let mut ledger = TestLedger::new();
+    for _ in 0..2 {
+        ledger.commit_writes(&state_keys, 2);
+        ledger.end_epoch();
+    }
+    ledger.commit_writes(&state_keys, 2);
+    ledger.commit_ledger_info();
+
+    // Verify that a waypoint on an epoch ending ledger info is satisfied
+    let epoch_ending_ledger_info = ledger.epoch_ending_ledger_infos[1].ledger_info().clone();
+    let waypoint = Waypoint::new_epoch_boundary(&epoch_ending_ledger_info).unwrap();
+    let mut test_syncer = TestSyncer::new(ledger.clone(), &state_keys, waypoint);
+    assert_ok!(test_syncer.sync().await);
+    test_syncer.verify_synced_state(&state_keys);
+
+    // Verify that a mismatched waypoint fails verification
+    let waypoint_version = epoch_ending_ledger_info.version();
+    let waypoint = Waypoint::new_any(create_ledger_info_at_version(waypoint_version).ledger_info());
+    let mut test_syncer = TestSyncer::new(ledger.clone(), &state_keys, waypoint);
+    let error = test_syncer.sync().await.unwrap_err();
+    assert_matches!(error, Error::VerificationError(_));
+    assert_none!(test_syncer.partial_state_storage.get_progress().unwrap());
+
+    // Verify that a target below the waypoint version is rejected
+    let waypoint_version = ledger.latest_version() + 1;
+    let waypoint = Waypoint::new_any(create_ledger_info_at_version(waypoint_version).ledger_info());
+    let mut test_syncer = TestSyncer::new(ledger, &state_keys, waypoint);
+    let error = test_syncer.sync().await.unwrap_err();
+    assert_matches!(error, Error::AdvertisedDataError(_));
+    assert_none!(test_syncer.partial_state_storage.get_progress().unwrap());
+    test_syncer.verify_bad_responses(0);
+}
+
+#[tokio::test]
+async fn test_invalid_epoch_ending_ledger_info() {
+    // Create a ledger with several epoch changes
+    let state_keys = TestStateKeys::new();
+    let mut ledger = TestLedger::new();
+    for _ in 0..2 {
+        ledger.commit_writes(&state_keys, 2);
+        ledger.end_epoch();
+    }
+    ledger.commit_ledger_info();
+
+    for invalid_response in [
+        InvalidResponse::InvalidSignatures,
+        InvalidResponse::WrongEpoch,
+    ] {
+        // Serve invalid epoch ending ledger infos to the syncer
+        let mut test_syncer = TestSyncer::new(ledger.clone(), &state_keys, Waypoint::default());
+        test_syncer.set_invalid_response(Some(invalid_response));
+
+        // Verify that the ledger infos are rejected and the peer is penalized
+        let error = test_syncer.sync().await.unwrap_err();
+        assert_matches!(error, Error::VerificationError(_));
+        assert_none!(test_syncer.partial_state_storage.get_progress().unwrap());
+        test_syncer.verify_bad_responses(1);
+
+        // Verify that the sync succeeds once valid ledger infos are served
+        test_syncer.set_invalid_response(None);
+        assert_ok!(test_syncer.sync().await);
+        test_syncer.verify_synced_state(&state_keys);
+        test_syncer.verify_bad_responses(1);
+    }
+}
+
+#[tokio::test]
+async fn test_invalid_transaction_outputs() {
+    // Create a ledger and sync the initial tracked state
+    let state_keys = TestStateKeys::new();
+    let mut ledger = TestLedger::new();
+    ledger.commit_writes(&state_keys, 2);
+    ledger.commit_ledger_info();
+    let mut test_syncer = TestSyncer::new(ledger, &state_keys, Waypoint::default());
+    assert_ok!(test_syncer.sync().await);
+
+    // Commit more writes and serve tampered outputs to the syncer
+    let synced_progress = test_syncer.get_progress();
+    let synced_value = test_syncer.get_state_value(&state_keys.tracked_key);
+    test_syncer.update_ledger(|ledger| {
+        ledger.commit_writes(&state_keys, 2);
+        ledger.commit_ledger_info();
+    });
+    test_syncer.set_invalid_response(Some(InvalidResponse::TamperedOutputs));
+
+    // Verify that the outputs are rejected and the peer is penalized
+    let error = test_syncer.sync().await.unwrap_err();
+    assert_matches!(error, Error::VerificationError(_));
+    test_syncer.verify_bad_responses(1);
+
+    // Verify that neither the progress nor the tracked state was updated
+    let progress = test_syncer.get_progress();
+    assert_eq!(progress.synced_version(), synced_progress.synced_version());
+    assert_eq!(
+        test_syncer.get_state_value(&state_keys.tracked_key),
+        synced_value
+    );
+
+    // Verify that the sync succeeds once valid outputs are served
+    test_syncer.set_invalid_response(None);
+    assert_ok!(test_syncer.sync().await);
+    test_syncer.verify_synced_state(&state_keys);
+    test_syncer.verify_bad_responses(1);
+}
+
+#[tokio::test]
+async fn test_invalid_state_value_proof() {
+    // Create a ledger that writes the tracked state
+    let state_keys = TestStateKeys::new();
+    let mut ledger = TestLedger::new();
+    ledger.commit_writes(&state_keys, 2);
+    ledger.commit_ledger_info();
+
+    // Serve tampered state values (with valid proofs) to the syncer
+    let mut test_syncer = TestSyncer::new(ledger, &state_keys, Waypoint::default());
+    test_syncer.set_invalid_response(Some(InvalidResponse::TamperedStateValues));
+
+    // Verify that the state values are rejected and the peers are penalized
+    let error = test_syncer.sync().await.unwrap_err();
+    assert_matches!(error, Error::VerificationError(_));
+    assert_none!(test_syncer.partial_state_storage.get_progress().unwrap());
+    assert_none!(test_syncer.get_state_value(&state_keys.tracked_key));
+    assert!(!test_syncer.bad_responses.lock().is_empty());
+
+    // Verify that the sync succeeds once valid state values are served
+    test_syncer.bad_responses.lock().clear();
+    test_syncer.set_invalid_response(None);
+    assert_ok!(test_syncer.sync().await);
+    test_syncer.verify_synced_state(&state_keys);
+    test_syncer.verify_bad_responses(0);
+}
+
+/// The state keys written by the test ledger
+struct TestStateKeys {
+    tracked_key: StateKey,
+    tracked_group_key: StateKey,
+    untracked_address_key: StateKey,
+    untracked_type_key: StateKey,
+    tracked_state_filter: TrackedStateFilter,
+}
+
+impl TestStateKeys {
+    fn new() -> Self {
+        // Track the coin store of a single address
+        let tracked_address = AccountAddress::random();
+        let partial_state_config = PartialStateConfig {
+            enabled: true,
+            tracked_addresses: vec![tracked_address],
+            tracked_resource_types: vec![COIN_STORE_TYPE.into()],
+            ..Default::default()
+        };
+        let tracked_state_filter = partial_state_config
+            .tracked_state_filter()
+            .unwrap()
+            .unwrap();
+
+        // Create the tracked and untracked state keys
+        let coin_store_type = StructTag::from_str(COIN_STORE_TYPE).unwrap();
+        let account_type = StructTag::from_str(ACCOUNT_TYPE).unwrap();
+        Self {
+            tracked_key: StateKey::resource(&tracked_address, &coin_store_type),
+            tracked_group_key: StateKey::access_path(AccessPath::resource_group_access_path(
+                tracked_address,
+                coin_store_type.clone(),
+            )),
+            untracked_address_key: StateKey::resource(&AccountAddress::random(), &coin_store_type),
+            untracked_type_key: StateKey::resource(&tracked_address, &account_type),
+            tracked_state_filter,
+        }
+    }
+
+    /// Returns all state keys written by the test ledger
+    fn written_keys(&self) -> Vec<StateKey> {
+        vec![
+            self.tracked_key.clone(),
+            self.untracked_address_key.clone(),
+            self.untracked_type_key.clone(),
+        ]
+    }
+}
+
+/// A simple in-memory ledger that serves verifiable data (i.e., ledger
+/// infos, transaction outputs and state values) to the syncer.
+#[derive(Clone)]
+struct TestLedger {
+    // The frozen nodes of the transaction accumulator
+    accumulator_store: AccumulatorStore,
+
+    // The committed transactions, outputs and infos (indexed by version)
+    transactions_and_outputs: Vec<(Transaction, TransactionOutput)>,
+    transaction_infos: Vec<TransactionInfo>,
+
+    // The state at each version (indexed by version)
+    state_snapshots: Vec<HashMap<StateKey, StateValue>>,
+
+    // The epoch ending ledger infos (indexed by epoch)
+    epoch_ending_ledger_infos: Vec<LedgerInfoWithSignatures>,
+
+    // The epoch state and validator signers of the current epoch
+    epoch_state: EpochState,
+    signers: Vec<ValidatorSigner>,
+
+    // The highest ledger info advertised to the syncer
+    synced_ledger_info: Option<LedgerInfoWithSignatures>,
+}
+
+impl TestLedger {
+    /// Creates a ledger holding only the genesis transaction (which ends epoch 0)
+    fn new() -> Self {
+        let mut ledger = Self {
+            accumulator_store: AccumulatorStore::default(),
+            transactions_and_outputs: vec![],
+            transaction_infos: vec![],
+            state_snapshots: vec![],
+            epoch_ending_ledger_infos: vec![],
+            epoch_state: EpochState::empty(),
+            signers: vec![],
+            synced_ledger_info: None,
+        };
+        ledger.commit_transaction(WriteSet::default(), vec![]);
+        ledger.end_epoch();
+        ledger
+    }
+
+    /// Commits a transaction with the given write set and events
+    fn commit_transaction(&mut self, write_set: WriteSet, events: Vec<ContractEvent>) {
+        // Apply the write set to the latest state
+        let mut state = self.state_snapshots.last().cloned().unwrap_or_default();
+        for (state_key, write_op) in &write_set {
+            match write_op.as_state_value() {
+                Some(state_value) => state.insert(state_key.clone(), state_value),
+                None => state.remove(state_key),
+            };
+        }
+        let state_checkpoint_hash = create_sparse_merkle_tree(&state).get_root_hash();
+
+        // Create the transaction info and append it to the accumulator
+        let transaction = create_transaction();
+        let event_hashes: Vec<_> = events.iter().map(CryptoHash::hash).collect();
+        let transaction_info = TransactionInfo::new(
+            transaction.hash(),
+            CryptoHash::hash(&write_set),
+            InMemoryEventAccumulator::from_leaves(&event_hashes).root_hash(),
+            Some(state_checkpoint_hash),
+            0,
+            ExecutionStatus::Success,
+        );
+        let (_, frozen_nodes) = TransactionAccumulator::append(
+            &self.accumulator_store,
+            self.transaction_infos.len() as u64,
+            &[CryptoHash::hash(&transaction_info)],
+        )
+        .unwrap();
+        self.accumulator_store.0.extend(frozen_nodes);
+
+        // Store the transaction, output, info and state
+        let output = TransactionOutput::new(
+            write_set,
+            events,
+            0,
+            TransactionStatus::Keep(ExecutionStatus::Success),
+            TransactionAuxiliaryData::default(),
+        );
+        self.transactions_and_outputs.push((transaction, output));
+        self.transaction_infos.push(transaction_info);
+        self.state_snapshots.push(state);
+    }
+
+    /// Commits the given number of transactions, each writing new values
+    /// for both the tracked and untracked state keys.
+    fn commit_writes(&mut self, state_keys: &TestStateKeys, num_transactions: u64) {
+        for _ in 0..num_transactions {
+            let write_set = create_write_set(
+                state_keys
+                    .written_keys()
+                    .into_iter()
+                    .map(|state_key| {
+                        let value = HashValue::random().to_vec();
+                        (state_key, WriteOp::legacy_modification(value.into()))
+                    })
+                    .collect(),
+            );
+            self.commit_transaction(write_set, vec![create_event(None)]);
+        }
+    }
+
+    /// Commits a transaction that emits a new block event for the given
+    /// round, and returns the version of the transaction.
+    fn commit_new_block(&mut self, round: u64) -> Version {
+        let new_block_event = NewBlockEvent::new(
+            AccountAddress::random(),
+            self.epoch_state.epoch,
+            round,
+            round,
+            vec![],
+            AccountAddress::random(),
+            vec![],
+            0,
+        );
+        let event = ContractEvent::new_v1(
+            new_block_event_key(),
+            round,
+            TypeTag::Struct(Box::new(NewBlockEvent::struct_tag())),
+            bcs::to_bytes(&new_block_event).unwrap(),
+        );
+        self.commit_transaction(WriteSet::default(), vec![event]);
+        self.latest_version()
+    }
+
+    /// Commits (and advertises) a ledger info at the latest version
+    fn commit_ledger_info(&mut self) {
+        let ledger_info = self.create_ledger_info(None);
+        self.synced_ledger_info = Some(self.sign_ledger_info(ledger_info));
+    }
+
+    /// Ends the current epoch at the latest version (and advertises the epoch
+    /// ending ledger info). The next epoch has a new set of validators.
+    fn end_epoch(&mut self) {
+        // Create the validators for the next epoch
+        let signers = create_validator_signers();
+        let next_epoch_state = EpochState::new(
+            self.epoch_state.epoch + 1,
+            create_validator_verifier(&signers),
+        );
+
+        // Create the epoch ending ledger info
+        let ledger_info = self.create_ledger_info(Some(next_epoch_state.clone()));
+        let ledger_info = self.sign_ledger_info(ledger_info);
+        self.epoch_ending_ledger_infos.push(ledger_info.clone());
+        self.synced_ledger_info = Some(ledger_info);
+
+        // Move to the next epoch
+        self.epoch_state = next_epoch_state;
+        self.signers = signers;
+    }
+
+    /// Returns the latest version of the ledger
+    fn latest_version(&self) -> Version {
+        self.transaction_infos.len() as u64 - 1
+    }
+
+    /// Returns the epoch state of the first epoch after genesis
+    fn genesis_epoch_state(&self) -> EpochState {
+        self.epoch_ending_ledger_infos[0]
+            .ledger_info()
+            .next_epoch_state()
+            .cloned()
+            .unwrap()
+    }
+
+    /// Creates a ledger info (for the current epoch) at the latest version
+    fn create_ledger_info(&self, next_epoch_state: Option<EpochState>) -> LedgerInfo {
+        let version = self.latest_version();
+        let root_hash =
+            TransactionAccumulator::get_root_hash(&self.accumulator_store, version + 1).unwrap();
+        let block_info = BlockInfo::new(
+            self.epoch_state.epoch,
+            0,
+            HashValue::random(),
+            root_hash,
+            version,
+            0,
+            next_epoch_state,
+        );
+        LedgerInfo::new(block_info, HashValue::zero())
+    }
+
+    /// Signs the given ledger info using the validators of the current
+    /// epoch. The genesis ledger info is trusted (and isn't signed).
+    fn sign_ledger_info(&self, ledger_info: LedgerInfo) -> LedgerInfoWithSignatures {
+        if self.signers.is_empty() {
+            LedgerInfoWithSignatures::new(ledger_info, AggregateSignature::empty())
+        } else {
+            generate_ledger_info_with_sig(&self.signers, ledger_info)
+        }
+    }
+
+    /// Returns the transaction outputs (with proof) for the given range,
+    /// truncated to the max chunk size.
+    fn get_transaction_outputs_with_proof(
+        &self,
+        proof_version: Version,
+        start_version: Version,
+        end_version: Version,
+    ) -> TransactionOutputListWithProof {
+        let end_version = end_version.min(start_version + MAX_OUTPUT_CHUNK_SIZE - 1);
+        let num_outputs = end_version - start_version + 1;
+        let range_proof = TransactionAccumulator::get_range_proof(
+            &self.accumulator_store,
+            proof_version + 1,
+            Some(start_version),
+            num_outputs,
+        )
+        .unwrap();
+
+        let range = start_version as usize..=end_version as usize;
+        TransactionOutputListWithProof::new(
+            self.transactions_and_outputs[range.clone()].to_vec(),
+            Some(start_version),
+            TransactionInfoListWithProof::new(range_proof, self.transaction_infos[range].to_vec()),
+        )
+    }
+
+    /// Returns the value (with a sparse merkle proof) of the given state
+    /// key at the specified version.
+    fn get_state_value_with_proof(
+        &self,
+        state_key: &StateKey,
+        version: Version,
+    ) -> StateValueWithProof {
+        let state = &self.state_snapshots[version as usize];
+        let proof = create_sparse_merkle_tree(state).get_proof(&CryptoHash::hash(state_key));
+        StateValueWithProof::new(state.get(state_key).cloned(), proof.into())
+    }
+}
+
+/// The type of the transaction accumulator of the test ledger
+type TransactionAccumulator = MerkleAccumulator<AccumulatorStore, TransactionAccumulatorHasher>;
+
+/// An in-memory store holding the frozen nodes of the transaction accumulator
+#[derive(Clone, Default)]
+struct AccumulatorStore(HashMap<Position, HashValue>);
+
+impl HashReader for AccumulatorStore {
+    fn get(&self, position: Position) -> anyhow::Result<HashValue> {
+        self.0
+            .get(&position)
+            .cloned()
+            .ok_or_else(|| anyhow::format_err!("Position {:?} is missing!", position))
+    }
+}
+
+/// The invalid responses the test data client can serve
+#[derive(Clone, Copy, Debug)]
+enum InvalidResponse {
+    InvalidSignatures,   // Epoch ending ledger infos signed by the wrong validators
+    WrongEpoch,          // Epoch ending ledger infos for the next epoch
+    TamperedOutputs,     // Transaction outputs with a modified write set
+    TamperedStateValues, // State values that don't match the proofs
+}
+
+/// A data client that serves data from the test ledger, and records the
+/// responses that are reported as bad.
+#[derive(Clone)]
+struct TestDataClient {
+    ledger: Arc<Mutex<TestLedger>>,
+    invalid_response: Arc<Mutex<Option<InvalidResponse>>>,
+    bad_responses: Arc<Mutex<Vec<ResponseError>>>,
+}
+
+impl TestDataClient {
+    /// Creates a data client response that records bad response notifications
+    fn create_response<T>(&self, payload: T) -> error::Result<Response<T>> {
+        let response_callback = TestResponseCallback {
+            bad_responses: self.bad_responses.clone(),
+        };
+        let context = ResponseContext::new(0, Box::new(response_callback));
+        Ok(Response::new(context, payload))
+    }
+}
+
+#[async_trait]
+impl AptosDataClientInterface for TestDataClient {
+    fn get_global_data_summary(&self) -> GlobalDataSummary {
+        let mut global_data_summary = GlobalDataSummary::empty();
+        global_data_summary.advertised_data.synced_ledger_infos = self
+            .ledger
+            .lock()
+            .synced_ledger_info
+            .iter()
+            .cloned()
+            .collect();
+        global_data_summary
+    }
+
+    async fn get_epoch_ending_ledger_infos(
+        &self,
+        start_epoch: Epoch,
+        expected_end_epoch: Epoch,
+        _request_timeout_ms: u64,
+    ) -> error::Result<Response<Vec<LedgerInfoWithSignatures>>> {
+        let ledger = self.ledger.lock();
+        let mut ledger_infos = ledger.epoch_ending_ledger_infos
+            [start_epoch as usize..=expected_end_epoch as usize]
+            .to_vec();
+
+        // Tamper with the ledger infos (if required)
+        match *self.invalid_response.lock() {
+            Some(InvalidResponse::InvalidSignatures) => {
+                let signers = create_validator_signers();
+                for ledger_info in ledger_infos.iter_mut() {
+                    *ledger_info =
+                        generate_ledger_info_with_sig(&signers, ledger_info.ledger_info().clone());
+                }
+            },
+            Some(InvalidResponse::WrongEpoch) => {
+                let next_epoch = expected_end_epoch as usize + 1;
+                ledger_infos = ledger.epoch_ending_ledger_infos[next_epoch..=next_epoch].to_vec();
+            },
+            _ => {},
+        }
+
+        self.create_response(ledger_infos)
+    }
+
+    async fn get_new_transaction_outputs_with_proof(
+        &self,
+        _known_version: Version,
+        _known_epoch: Epoch,
+        _request_timeout_ms: u64,
+    ) -> error::Result<Response<(TransactionOutputListWithProof, LedgerInfoWithSignatures)>> {
+        unimplemented!()
+    }
+
+    async fn get_new_transactions_with_proof(
+        &self,
+        _known_version: Version,
+        _known_epoch: Epoch,
+        _include_events: bool,
+        _request_timeout_ms: u64,
+    ) -> error::Result<Response<(TransactionListWithProof, LedgerInfoWithSignatures)>> {
+        unimplemented!()
+    }
+
+    async fn get_new_transactions_or_outputs_with_proof(
+        &self,
+        _known_version: Version,
+        _known_epoch: Epoch,
+        _include_events: bool,
+        _request_timeout_ms: u64,
+    ) -> error::Result<Response<(TransactionOrOutputListWithProof, LedgerInfoWithSignatures)>> {
+        unimplemented!()
+    }
+
+    async fn get_number_of_states(
+        &self,
+        _version: Version,
+        _request_timeout_ms: u64,
+    ) -> error::Result<Response<u64>> {
+        unimplemented!()
+    }
+
+    async fn get_state_values_with_proof(
+        &self,
+        _version: u64,
+        _start_index: u64,
+        _end_index: u64,
+        _request_timeout_ms: u64,
+    ) -> error::Result<Response<StateValueChunkWithProof>> {
+        unimplemented!()
+    }
+
+    async fn get_state_value_with_proof(
+        &self,
+        state_key: StateKey,
+        version: Version,
+        _request_timeout_ms: u64,
+    ) -> error::Result<Response<StateValueWithProof>> {
+        let mut state_value_with_proof = self
+            .ledger
+            .lock()
+            .get_state_value_with_proof(&state_key, version);
+
+        // Tamper with the state value (if required)
+        if let Some(InvalidResponse::TamperedStateValues) = *self.invalid_response.lock() {
+            state_value_with_proof.state_value = Some(StateValue::from(b"invalid".to_vec()));
+        }
+
+        self.create_response(state_value_with_proof)
+    }
+
+    async fn get_transaction_outputs_with_proof(
+        &self,
+        proof_version: Version,
+        start_version: Version,
+        end_version: Version,
+        _request_timeout_ms: u64,
+    ) -> error::Result<Response<TransactionOutputListWithProof>> {
+        let mut output_list_with_proof = self.ledger.lock().get_transaction_outputs_with_proof(
+            proof_version,
+            start_version,
+            end_version,
+        );
+
+        // Tamper with the first output (if required)
+        if let Some(InvalidResponse::TamperedOutputs) = *self.invalid_response.lock() {
+            let (_, output) = &mut output_list_with_proof.transactions_and_outputs[0];
+            *output = TransactionOutput::new(
+                create_write_set(vec![(
+                    StateKey::raw(b"invalid".to_vec()),
+                    WriteOp::legacy_deletion(),
+                )]),
+                output.events().to_vec(),
+                output.gas_used(),
+                output.status().clone(),
+                TransactionAuxiliaryData::default(),
+            );
+        }
+
+        self.create_response(output_list_with_proof)
+    }
+
+    async fn get_transactions_with_proof(
+        &self,
+        _proof_version: Version,
+        _start_version: Version,
+        _end_version: Version,
+        _include_events: bool,
+        _request_timeout_ms: u64,
+    ) -> error::Result<Response<TransactionListWithProof>> {
+        unimplemented!()
+    }
+
+    async fn get_transactions_or_outputs_with_proof(
+        &self,
+        _proof_version: Version,
+        _start_version: Version,
+        _end_version: Version,
+        _include_events: bool,
+        _request_timeout_ms: u64,
+    ) -> error::Result<Response<TransactionOrOutputListWithProof>> {
+        unimplemented!()
+    }
+
+    async fn subscribe_to_transaction_outputs_with_proof(
+        &self,
+        _subscription_request_metadata: SubscriptionRequestMetadata,
+        _request_timeout_ms: u64,
+    ) -> error::Result<Response<(TransactionOutputListWithProof, LedgerInfoWithSignatures)>> {
+        unimplemented!()
+    }
+
+    async fn subscribe_to_transactions_with_proof(
+        &self,
+        _subscription_request_metadata: SubscriptionRequestMetadata,
+        _include_events: bool,
+        _request_timeout_ms: u64,
+    ) -> error::Result<Response<(TransactionListWithProof, LedgerInfoWithSignatures)>> {
+        unimplemented!()
+    }
+
+    async fn subscribe_to_transactions_or_outputs_with_proof(
+        &self,
+        _subscription_request_metadata: SubscriptionRequestMetadata,
+        _include_events: bool,
+        _request_timeout_ms: u64,
+    ) -> error::Result<Response<(TransactionOrOutputListWithProof, LedgerInfoWithSignatures)>> {
+        unimplemented!()
+    }
+}
+
+/// A response callback that records the bad response notifications
+#[derive(Debug)]
+struct TestResponseCallback {
+    bad_responses: Arc<Mutex<Vec<ResponseError>>>,
+}
+
+impl ResponseCallback for TestResponseCallback {
+    fn notify_bad_response(&self, error: ResponseError) {
+        self.bad_responses.lock().push(error);
+    }
+}
+
+/// A partial state syncer (and its storage) that syncs from the test ledger
+struct TestSyncer {
+    partial_state_syncer: PartialStateSyncer<TestDataClient>,
+    partial_state_storage: PartialStateStorage,
+    ledger: Arc<Mutex<TestLedger>>,
+    invalid_response: Arc<Mutex<Option<InvalidResponse>>>,
+    bad_responses: Arc<Mutex<Vec<ResponseError>>>,
+    _tmp_dir: TempPath,
+}
+
+impl TestSyncer {
+    fn new(ledger: TestLedger, state_keys: &TestStateKeys, waypoint: Waypoint) -> Self {
+        // Create the data client
+        let data_client = TestDataClient {
+            ledger: Arc::new(Mutex::new(ledger.clone())),
+            invalid_response: Arc::new(Mutex::new(None)),
+            bad_responses: Arc::new(Mutex::new(vec![])),
+        };
+
+        // Create the storage holding the (trusted) genesis
+        let mut db_reader = create_mock_db_reader();
+        let genesis_ledger_info = ledger.epoch_ending_ledger_infos[0].clone();
+        db_reader
+            .expect_get_latest_ledger_info()
+            .returning(move || Ok(genesis_ledger_info.clone()));
+        let genesis_epoch_state = ledger.genesis_epoch_state();
+        db_reader
+            .expect_get_latest_epoch_state()
+            .returning(move || Ok(genesis_epoch_state.clone()));
+
+        // Create the partial state syncer
+        let tmp_dir = TempPath::new();
+        let partial_state_storage = PartialStateStorage::new(tmp_dir.path());
+        let (_, client_notifications) = mpsc::unbounded();
+        let partial_state_syncer = PartialStateSyncer::new(
+            data_client.clone(),
+            ClientNotificationListener::new(client_notifications),
+            PartialStateConfig::default(),
+            partial_state_storage.clone(),
+            1000,
+            Arc::new(db_reader),
+            state_keys.tracked_state_filter.clone(),
+            waypoint,
+        );
+
+        Self {
+            partial_state_syncer,
+            partial_state_storage,
+            ledger: data_client.ledger,
+            invalid_response: data_client.invalid_response,
+            bad_responses: data_client.bad_responses,
+            _tmp_dir: tmp_dir,
+        }
+    }
+
+    /// Syncs the tracked state to the ledger
+    async fn sync(&mut self) -> Result<(), Error> {
+        self.partial_state_syncer.sync_tracked_state().await
+    }
+
+    /// Updates the ledger served to the syncer
+    fn update_ledger<T>(&self, update: impl FnOnce(&mut TestLedger) -> T) -> T {
+        update(&mut self.ledger.lock())
+    }
+
+    /// Sets the invalid response served to the syncer
+    fn set_invalid_response(&self, invalid_response: Option<InvalidResponse>) {
+        *self.invalid_response.lock() = invalid_response;
+    }
+
+    /// Returns the synced progress
+    fn get_progress(&self) -> PartialStateProgress {
+        self.partial_state_storage.get_progress().unwrap().unwrap()
+    }
+
+    /// Returns the synced value of the given state key
+    fn get_state_value(&self, state_key: &StateKey) -> Option<StateValue> {
+        self.partial_state_storage
+            .get_state_value(state_key)
+            .unwrap()
+    }
+
+    /// Verifies that the syncer reached the target of the ledger and that
+    /// the tracked state matches the ledger state.
+    fn verify_synced_state(&self, state_keys: &TestStateKeys) {
+        let ledger = self.ledger.lock();
+        let progress = self.get_progress();
+        assert_eq!(
+            Some(&progress.synced_ledger_info),
+            ledger.synced_ledger_info.as_ref()
+        );
+
+        let state = ledger.state_snapshots.last().unwrap();
+        let tracked_value = self.get_state_value(&state_keys.tracked_key);
+        assert_eq!(tracked_value.as_ref(), state.get(&state_keys.tracked_key));
+        assert!(tracked_value.is_some());
+    }
+
+    /// Verifies that none of the untracked state was persisted
+    fn verify_untracked_state(&self, state_keys: &TestStateKeys) {
+        assert_none!(self.get_state_value(&state_keys.untracked_address_key));
+        assert_none!(self.get_state_value(&state_keys.untracked_type_key));
+    }
+
+    /// Verifies the number of bad responses reported by the syncer
+    fn verify_bad_responses(&self, num_bad_responses: usize) {
+        let bad_responses = self.bad_responses.lock();
+        assert_eq!(bad_responses.len(), num_bad_responses);
+        for bad_response in bad_responses.iter() {
+            assert_eq!(*bad_response, ResponseError::ProofVerificationError);
+        }
+    }
+}
+
+/// Creates a sparse merkle tree holding the given state
+fn create_sparse_merkle_tree(state: &HashMap<StateKey, StateValue>) -> NaiveSmt {
+    let leaves: Vec<_> = state
+        .iter()
+        .map(|(state_key, state_value)| (CryptoHash::hash(state_key), state_value))
+        .collect();
+    NaiveSmt::new(&leaves)
+}
+
+/// Creates a new (random) set of validator signers
+fn create_validator_signers() -> Vec<ValidatorSigner> {
+    (0..NUM_VALIDATORS)
+        .map(|_| ValidatorSigner::random(None))
+        .collect()
+}
+
+/// Creates a validator verifier for the given signers (each with equal voting power)
+fn create_validator_verifier(signers: &[ValidatorSigner]) -> ValidatorVerifier {
+    ValidatorVerifier::new(
+        signers
+            .iter()
+            .map(|signer| ValidatorConsensusInfo::new(signer.author(), signer.public_key(), 1))
+            .collect(),
+    )
+}
+
+/// Creates a write set with the given write ops
+fn create_write_set(write_ops: Vec<(StateKey, WriteOp)>) -> WriteSet {
+    WriteSetMut::new(write_ops).freeze().unwrap()
+}
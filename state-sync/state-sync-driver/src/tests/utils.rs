@@ -3,7 +3,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::driver::DriverConfiguration;
-use aptos_config::config::{RoleType, StateSyncDriverConfig};
+use aptos_config::config::{ConsensusObserverConfig, RoleType, StateSyncDriverConfig};
 use aptos_crypto::{
     ed25519::{Ed25519PrivateKey, Ed25519Signature},
     HashValue, PrivateKey, Uniform,
@@ -31,7 +31,7 @@ use aptos_types::{
     },
     state_store::state_value::StateValueChunkWithProof,
     transaction::{
-        ExecutionStatus, RawTransaction, Script, SignedTransaction, Transaction,
+        ExecutionStatus, RawTransaction, ReplayProtector, Script, SignedTransaction, Transaction,
         TransactionAuxiliaryData, TransactionInfo, TransactionListWithProof, TransactionOutput,
         TransactionOutputListWithProof, TransactionPayload, TransactionStatus, Version,
     },
@@ -89,6 +89,7 @@ pub fn create_full_node_driver_configuration() -> DriverConfiguration {
 
     DriverConfiguration {
         config,
+        consensus_observer_config: ConsensusObserverConfig::default(),
         role,
         waypoint,
     }
@@ -278,7 +279,7 @@ pub async fn verify_commit_notification(
         .into_iter()
         .map(|txn| CommittedTransaction {
             sender: txn.try_as_signed_user_txn().unwrap().sender(),
-            sequence_number: 0,
+            replay_protector: ReplayProtector::SequenceNumber(0),
         })
         .collect();
     assert_eq!(mempool_notification.transactions, committed_transactions);
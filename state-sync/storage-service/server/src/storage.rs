@@ -6,12 +6,17 @@ use aptos_config::config::StorageServiceConfig;
 use aptos_logger::debug;
 use aptos_storage_interface::{AptosDbError, DbReader, Result as StorageResult};
 use aptos_storage_service_types::responses::{
-    CompleteDataRange, DataResponse, DataSummary, TransactionOrOutputListWithProof,
+    CompleteDataRange, DataResponse, DataSummary, StateValueWithProof,
+    TransactionOrOutputListWithProof,
 };
 use aptos_types::{
     epoch_change::EpochChangeProof,
     ledger_info::LedgerInfoWithSignatures,
-    state_store::state_value::StateValueChunkWithProof,
+    proof::SparseMerkleProof,
+    state_store::{
+        state_key::StateKey,
+        state_value::{StateValue, StateValueChunkWithProof},
+    },
     transaction::{TransactionListWithProof, TransactionOutputListWithProof, Version},
 };
 use serde::Serialize;
@@ -89,6 +94,14 @@ pub trait StorageReaderInterface: Clone + Send + 'static {
         start_index: u64,
         end_index: u64,
     ) -> aptos_storage_service_types::Result<StateValueChunkWithProof, Error>;
+
+    /// Returns the state value (if any) for the specified key at the given
+    /// version, along with a proof relative to the state root at that version.
+    fn get_state_value_with_proof(
+        &self,
+        version: u64,
+        state_key: &StateKey,
+    ) -> aptos_storage_service_types::Result<StateValueWithProof, Error>;
 }
 
 /// The underlying implementation of the StorageReaderInterface, used by the
@@ -498,6 +511,18 @@ impl StorageReaderInterface for StorageReader {
             version, start_index, end_index
         )))
     }
+
+    fn get_state_value_with_proof(
+        &self,
+        version: u64,
+        state_key: &StateKey,
+    ) -> aptos_storage_service_types::Result<StateValueWithProof, Error> {
+        let (state_value, proof) = self
+            .storage
+            .get_state_value_with_proof_by_version(state_key, version)
+            .map_err(|error| Error::StorageErrorEncountered(error.to_string()))?;
+        Ok(StateValueWithProof::new(state_value, proof))
+    }
 }
 
 // A simple macro that wraps each storage read call with a timer
@@ -578,6 +603,12 @@ impl DbReader for TimedStorageReader {
             start_idx: usize,
             chunk_size: usize,
         ) -> StorageResult<StateValueChunkWithProof>;
+
+        fn get_state_value_with_proof_by_version(
+            &self,
+            state_key: &StateKey,
+            version: Version,
+        ) -> StorageResult<(Option<StateValue>, SparseMerkleProof)>;
     );
 }
 
@@ -0,0 +1,107 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::tests::{
+    mock,
+    mock::{MockClient, MockDatabaseReader},
+    utils,
+};
+use aptos_crypto::hash::HashValue;
+use aptos_storage_service_types::{
+    requests::{DataRequest, StateValueWithProofRequest},
+    responses::{DataResponse, StateValueWithProof, StorageServiceResponse},
+    StorageServiceError,
+};
+use aptos_types::{
+    proof::SparseMerkleProof,
+    state_store::{state_key::StateKey, state_value::StateValue},
+};
+use claims::assert_matches;
+use mockall::predicate::eq;
+
+#[tokio::test]
+async fn test_get_state_value_with_proof() {
+    // Test both existing and missing state values
+    for state_value in [Some(StateValue::new_legacy(vec![1, 2, 3].into())), None] {
+        // Create test data
+        let version = 101;
+        let state_key = StateKey::raw(vec![10, 20]);
+        let proof = SparseMerkleProof::new(None, vec![HashValue::random()]);
+
+        // Create the mock db reader
+        let mut db_reader = mock::create_mock_db_reader();
+        expect_get_state_value_with_proof(
+            &mut db_reader,
+            version,
+            state_key.clone(),
+            state_value.clone(),
+            proof.clone(),
+        );
+
+        // Create the storage client and server
+        let (mut mock_client, mut service, _, _, _) = MockClient::new(Some(db_reader), None);
+        utils::update_storage_server_summary(&mut service, version, 10);
+        tokio::spawn(service.start());
+
+        // Process a request to fetch the state value with a proof
+        let response = get_state_value_with_proof(&mut mock_client, version, &state_key, false)
+            .await
+            .unwrap();
+
+        // Verify the response is correct
+        assert_matches!(response, StorageServiceResponse::RawResponse(_));
+        assert_eq!(
+            response.get_data_response().unwrap(),
+            DataResponse::StateValueWithProof(StateValueWithProof::new(state_value, proof))
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_get_state_value_with_proof_not_serviceable() {
+    // Create test data
+    let version = 101;
+    let state_key = StateKey::raw(vec![10, 20]);
+
+    // Create the storage client and server (that cannot service the request)
+    let (mut mock_client, mut service, _, _, _) = MockClient::new(None, None);
+    utils::update_storage_server_summary(&mut service, version - 1, 10);
+    tokio::spawn(service.start());
+
+    // Process a request to fetch the state value with a proof
+    let response = get_state_value_with_proof(&mut mock_client, version, &state_key, false)
+        .await
+        .unwrap_err();
+
+    // Verify the request is not serviceable
+    assert_matches!(response, StorageServiceError::InvalidRequest(_));
+}
+
+/// Sets an expectation on the given mock db for a call to fetch a state value with proof
+fn expect_get_state_value_with_proof(
+    mock_db: &mut MockDatabaseReader,
+    version: u64,
+    state_key: StateKey,
+    state_value: Option<StateValue>,
+    proof: SparseMerkleProof,
+) {
+    mock_db
+        .expect_get_state_value_with_proof_by_version()
+        .times(1)
+        .with(eq(state_key), eq(version))
+        .returning(move |_, _| Ok((state_value.clone(), proof.clone())));
+}
+
+/// Sends a state value with proof request and processes the response
+async fn get_state_value_with_proof(
+    mock_client: &mut MockClient,
+    version: u64,
+    state_key: &StateKey,
+    use_compression: bool,
+) -> Result<StorageServiceResponse, StorageServiceError> {
+    let data_request = DataRequest::GetStateValueWithProof(StateValueWithProofRequest {
+        state_key: state_key.clone(),
+        version,
+    });
+    utils::send_storage_request(mock_client, use_compression, data_request).await
+}
@@ -5,7 +5,8 @@ use crate::{
     requests::{
         DataRequest, EpochEndingLedgerInfoRequest, NewTransactionOutputsWithProofRequest,
         NewTransactionsOrOutputsWithProofRequest, NewTransactionsWithProofRequest,
-        StateValuesWithProofRequest, SubscribeTransactionOutputsWithProofRequest,
+        StateValueWithProofRequest, StateValuesWithProofRequest,
+        SubscribeTransactionOutputsWithProofRequest,
         SubscribeTransactionsOrOutputsWithProofRequest, SubscribeTransactionsWithProofRequest,
         SubscriptionStreamMetadata, TransactionOutputsWithProofRequest,
         TransactionsOrOutputsWithProofRequest, TransactionsWithProofRequest,
@@ -20,6 +21,7 @@ use aptos_types::{
     aggregate_signature::AggregateSignature,
     block_info::BlockInfo,
     ledger_info::{LedgerInfo, LedgerInfoWithSignatures},
+    state_store::state_key::StateKey,
     transaction::Version,
 };
 use claims::{assert_err, assert_ok};
@@ -466,6 +468,32 @@ fn test_data_summary_can_service_state_chunk_request() {
     }
 }
 
+#[test]
+fn test_data_summary_can_service_state_value_request() {
+    // Create a data client config and data summary
+    let data_client_config = AptosDataClientConfig::default();
+    let data_summary = DataSummary {
+        synced_ledger_info: Some(create_ledger_info_at_version(250)),
+        states: Some(create_data_range(100, 300)),
+        ..Default::default()
+    };
+
+    // Verify the different requests that can be serviced
+    for compression in [true, false] {
+        // Test the valid request versions
+        for version in [100, 200, 250] {
+            let request = create_state_value_request(version, compression);
+            verify_serviceability(&data_client_config, &data_summary, None, request, true);
+        }
+
+        // Test the invalid request versions
+        for version in [50, 99, 251, 300] {
+            let request = create_state_value_request(version, compression);
+            verify_serviceability(&data_client_config, &data_summary, None, request, false);
+        }
+    }
+}
+
 #[test]
 fn test_protocol_metadata_service() {
     // Create the protocol metadata
@@ -683,6 +711,15 @@ fn create_state_values_request(
     StorageServiceRequest::new(data_request, use_compression)
 }
 
+/// Creates a request for a single state value at a given version
+fn create_state_value_request(version: Version, use_compression: bool) -> StorageServiceRequest {
+    let data_request = DataRequest::GetStateValueWithProof(StateValueWithProofRequest {
+        state_key: StateKey::raw(vec![1, 2, 3]),
+        version,
+    });
+    StorageServiceRequest::new(data_request, use_compression)
+}
+
 /// Creates a request for state values at a given version
 fn create_state_values_request_at_version(
     version: Version,
@@ -0,0 +1,170 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    backup_types::{
+        epoch_ending::restore::EpochHistoryRestoreController,
+        state_snapshot::restore::{StateSnapshotRestoreController, StateSnapshotRestoreOpt},
+        transaction::restore::TransactionRestoreBatchController,
+    },
+    metadata,
+    metadata::cache::MetadataCacheOpt,
+    storage::BackupStorage,
+    utils::GlobalRestoreOptions,
+};
+use anyhow::{anyhow, ensure, Result};
+use aptos_db::state_restore::StateSnapshotRestoreMode;
+use aptos_executor_types::VerifyExecutionMode;
+use aptos_logger::prelude::*;
+use aptos_types::transaction::Version;
+use std::sync::Arc;
+
+/// Bootstraps a DB that only holds genesis (e.g., that of a freshly started
+/// node) by restoring the latest epoch ending state snapshot in the backup
+/// storage, along with the epoch history and the transactions leading up to
+/// the snapshot. Unlike the `RestoreCoordinator`, the DB is not required to be
+/// empty, and no transactions are replayed past the snapshot: the node is
+/// expected to sync the remaining ledger history from its peers.
+pub struct BootstrapCoordinator {
+    storage: Arc<dyn BackupStorage>,
+    global_opt: GlobalRestoreOptions,
+    metadata_cache_opt: MetadataCacheOpt,
+}
+
+impl BootstrapCoordinator {
+    pub fn new(
+        metadata_cache_opt: MetadataCacheOpt,
+        global_opt: GlobalRestoreOptions,
+        storage: Arc<dyn BackupStorage>,
+    ) -> Self {
+        Self {
+            storage,
+            global_opt,
+            metadata_cache_opt,
+        }
+    }
+
+    /// Runs the bootstrap and returns the version of the restored state snapshot
+    pub async fn run(self) -> Result<Version> {
+        info!("Bootstrap coordinator started.");
+
+        let ret = self.run_impl().await;
+
+        match &ret {
+            Ok(version) => info!(
+                version = *version,
+                "Bootstrap coordinator exiting with success."
+            ),
+            Err(e) => error!(
+                error = ?e,
+                "Bootstrap coordinator failed."
+            ),
+        }
+
+        ret
+    }
+
+    async fn run_impl(self) -> Result<Version> {
+        let metadata_view = metadata::cache::sync_and_load(
+            &self.metadata_cache_opt,
+            Arc::clone(&self.storage),
+            self.global_opt.concurrent_downloads,
+        )
+        .await?;
+
+        let max_txn_ver = metadata_view
+            .max_transaction_version()?
+            .ok_or_else(|| anyhow!("No transaction backup found."))?;
+        let target_version = std::cmp::min(self.global_opt.target_version, max_txn_ver);
+        let snapshot = metadata_view
+            .select_state_snapshot(target_version)?
+            .ok_or_else(|| {
+                anyhow!(
+                    "No state snapshot found at or before version {}.",
+                    target_version
+                )
+            })?;
+
+        let db_next_version = self
+            .global_opt
+            .run_mode
+            .get_next_expected_transaction_version()?;
+        ensure!(
+            db_next_version <= snapshot.version,
+            "DB is already ahead of the latest state snapshot in the backup storage. \
+            next expected version: {}, snapshot version: {}",
+            db_next_version,
+            snapshot.version,
+        );
+        info!(
+            "Bootstrapping DB from state snapshot at version {}, db_next_version {}.",
+            snapshot.version, db_next_version,
+        );
+
+        // Restore (and verify) the epoch history up to the snapshot. The history is
+        // anchored by the trusted waypoints, so the ledger info certifying the
+        // snapshot is verified all the way from genesis.
+        let epoch_handles = metadata_view
+            .select_epoch_ending_backups(snapshot.version)?
+            .into_iter()
+            .map(|backup| backup.manifest)
+            .collect();
+        let epoch_history = Arc::new(
+            EpochHistoryRestoreController::new(
+                epoch_handles,
+                self.global_opt.clone(),
+                Arc::clone(&self.storage),
+            )
+            .run()
+            .await?,
+        );
+        ensure!(
+            epoch_history
+                .epoch_endings
+                .iter()
+                .any(|li| li.version() == snapshot.version),
+            "State snapshot at version {} is not at an epoch ending.",
+            snapshot.version,
+        );
+
+        StateSnapshotRestoreController::new(
+            StateSnapshotRestoreOpt {
+                manifest_handle: snapshot.manifest,
+                version: snapshot.version,
+                validate_modules: false,
+                restore_mode: StateSnapshotRestoreMode::Default,
+            },
+            self.global_opt.clone(),
+            Arc::clone(&self.storage),
+            Some(epoch_history.clone()),
+        )
+        .run()
+        .await?;
+
+        // Save the transaction chunk ending at the snapshot, so that the transaction
+        // accumulator (and the transaction info at the snapshot version) is in place.
+        let txn_manifests = metadata_view
+            .select_transaction_backups(snapshot.version, snapshot.version)?
+            .into_iter()
+            .map(|backup| backup.manifest)
+            .collect();
+        let mut transaction_restore_opt = self.global_opt.clone();
+        transaction_restore_opt.target_version = snapshot.version;
+        TransactionRestoreBatchController::new(
+            transaction_restore_opt,
+            Arc::clone(&self.storage),
+            txn_manifests,
+            None,
+            Some((snapshot.version + 1, false)),
+            Some(epoch_history),
+            VerifyExecutionMode::NoVerify,
+            None,
+        )
+        .run()
+        .await?;
+
+        self.global_opt.run_mode.finish();
+
+        Ok(snapshot.version)
+    }
+}
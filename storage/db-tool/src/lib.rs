@@ -28,6 +28,9 @@ pub enum DBTool {
 
     Bootstrap(bootstrap::Command),
 
+    #[clap(subcommand)]
+    Consensus(aptos_consensus::util::db_debugger::Cmd),
+
     #[clap(subcommand)]
     Debug(db_debugger::Cmd),
 
@@ -43,6 +46,7 @@ impl DBTool {
             DBTool::Backup(cmd) => cmd.run().await,
             DBTool::BackupMaintenance(cmd) => cmd.run().await,
             DBTool::Bootstrap(cmd) => cmd.run(),
+            DBTool::Consensus(cmd) => cmd.run(),
             DBTool::Debug(cmd) => Ok(cmd.run()?),
             DBTool::ReplayVerify(cmd) => {
                 let ret = cmd.run().await;
@@ -214,7 +214,8 @@ async fn test_onchain_config_change() {
                     panic!()
                 };
             let proposer_and_voter_config = match &leader_reputation_type {
-                LeaderReputationType::ProposerAndVoter(_) => panic!(),
+                LeaderReputationType::ProposerAndVoter(_)
+                | LeaderReputationType::LatencyAware(_) => panic!(),
                 LeaderReputationType::ProposerAndVoterV2(proposer_and_voter_config) => {
                     proposer_and_voter_config
                 },
@@ -271,7 +272,9 @@ async fn test_onchain_config_change() {
             panic!()
         };
     let proposer_and_voter_config = match &leader_reputation_type {
-        LeaderReputationType::ProposerAndVoterV2(_) => panic!(),
+        LeaderReputationType::ProposerAndVoterV2(_) | LeaderReputationType::LatencyAware(_) => {
+            panic!()
+        },
         LeaderReputationType::ProposerAndVoter(proposer_and_voter_config) => {
             proposer_and_voter_config
         },
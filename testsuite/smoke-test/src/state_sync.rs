@@ -777,7 +777,9 @@ async fn test_validator_sync_and_participate(fast_sync: bool, epoch_changes: boo
             let consensus_config = match genesis_config.consensus_config.clone() {
                 OnChainConsensusConfig::V1(consensus_config) => consensus_config,
                 OnChainConsensusConfig::V2(consensus_config) => consensus_config,
-                OnChainConsensusConfig::V3 { alg, .. } => alg.unwrap_jolteon_config_v1().clone(),
+                OnChainConsensusConfig::V3 { alg, .. } | OnChainConsensusConfig::V4 { alg, .. } => {
+                    alg.unwrap_jolteon_config_v1().clone()
+                },
             };
             let leader_reputation_type = match &consensus_config.proposer_election_type {
                 ProposerElectionType::LeaderReputation(leader_reputation_type) => {
@@ -7,13 +7,17 @@ use clap::Parser;
 use crossbeam::channel::{bounded, select};
 use lsp_server::{Connection, Message, Notification, Request, Response};
 use lsp_types::{
-    notification::Notification as _, request::Request as _, CompletionOptions, Diagnostic,
-    HoverProviderCapability, OneOf, SaveOptions, TextDocumentSyncCapability, TextDocumentSyncKind,
+    notification::Notification as _, request::Request as _, CodeActionProviderCapability,
+    CompletionOptions, Diagnostic, HoverProviderCapability, OneOf, SaveOptions,
+    SignatureHelpOptions, TextDocumentSyncCapability, TextDocumentSyncKind,
     TextDocumentSyncOptions, TypeDefinitionProviderCapability, WorkDoneProgressOptions,
 };
 use move_analyzer::{
+    code_action::on_code_action_request,
     completion::on_completion_request,
     context::Context,
+    formatting::on_formatting_request,
+    signature_help::on_signature_help_request,
     symbols,
     vfs::{on_text_document_sync_notification, VirtualFileSystem},
 };
@@ -53,6 +57,7 @@ fn main() {
         connection,
         files: VirtualFileSystem::default(),
         symbols: symbols.clone(),
+        workspace_dirs: vec![],
     };
 
     let (id, client_response) = context
@@ -60,7 +65,7 @@ fn main() {
         .initialize_start()
         .expect("could not start connection initialization");
 
-    let capabilities = serde_json::to_value(lsp_types::ServerCapabilities {
+    let mut capabilities = serde_json::to_value(lsp_types::ServerCapabilities {
         // The server receives notifications from the client as users open, close,
         // and modify documents.
         text_document_sync: Some(TextDocumentSyncCapability::Options(
@@ -106,9 +111,25 @@ fn main() {
         )),
         references_provider: Some(OneOf::Left(symbols::DEFS_AND_REFS_SUPPORT)),
         document_symbol_provider: Some(OneOf::Left(true)),
+        // The server formats whole documents with the Move formatter.
+        document_formatting_provider: Some(OneOf::Left(true)),
+        rename_provider: Some(OneOf::Left(symbols::DEFS_AND_REFS_SUPPORT)),
+        // Signature help is shown when the argument list of a call is opened and updated as
+        // arguments are added.
+        signature_help_provider: Some(SignatureHelpOptions {
+            trigger_characters: Some(vec!["(".to_string(), ",".to_string()]),
+            retrigger_characters: None,
+            work_done_progress_options: WorkDoneProgressOptions {
+                work_done_progress: None,
+            },
+        }),
+        // The server offers quick fixes for some of the compiler diagnostics.
+        code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
         ..Default::default()
     })
     .expect("could not serialize server capabilities");
+    // Inlay hints are not yet supported by the `lsp_types` version in use.
+    capabilities["inlayHintProvider"] = serde_json::Value::Bool(true);
 
     let (diag_sender, diag_receiver) = bounded::<Result<BTreeMap<Symbol, Vec<Diagnostic>>>>(0);
     let mut symbolicator_runner = symbols::SymbolicatorRunner::idle();
@@ -117,14 +138,29 @@ fn main() {
             serde_json::from_value(client_response)
                 .expect("could not deserialize client capabilities");
 
-        symbolicator_runner = symbols::SymbolicatorRunner::new(symbols.clone(), diag_sender);
+        // Packages in the workspace are symbolicated on demand, as files in them (or in the
+        // packages they depend on) are opened.
+        let workspace_dirs: Vec<_> = match &initialize_params.workspace_folders {
+            Some(folders) => folders.iter().map(|folder| &folder.uri).collect(),
+            None => initialize_params.root_uri.iter().collect(),
+        };
+        context.workspace_dirs = workspace_dirs
+            .into_iter()
+            .filter_map(|uri| uri.to_file_path().ok())
+            .map(|dir| dunce::canonicalize(&dir).unwrap_or(dir))
+            .collect();
+        symbolicator_runner = symbols::SymbolicatorRunner::new(
+            symbols.clone(),
+            diag_sender,
+            context.workspace_dirs.clone(),
+        );
 
         // If initialization information from the client contains a path to the directory being
         // opened, try to initialize symbols before sending response to the client. Do not bother
         // with diagnostics as they will be recomputed whenever the first source file is opened. The
         // main reason for this is to enable unit tests that rely on the symbolication information
         // to be available right after the client is initialized.
-        if let Some(uri) = initialize_params.root_uri {
+        if let Some(uri) = &initialize_params.root_uri {
             if let Some(p) = symbols::SymbolicatorRunner::root_dir(&uri.to_file_path().unwrap()) {
                 // need to evaluate in a separate thread to allow for a larger stack size (needed on
                 // Windows)
@@ -240,6 +276,19 @@ fn on_request(context: &Context, request: &Request) {
         lsp_types::request::DocumentSymbolRequest::METHOD => {
             symbols::on_document_symbol_request(context, request, &context.symbols.lock().unwrap());
         },
+        lsp_types::request::Formatting::METHOD => on_formatting_request(context, request),
+        lsp_types::request::Rename::METHOD => {
+            symbols::on_rename_request(context, request, &context.symbols.lock().unwrap());
+        },
+        lsp_types::request::SignatureHelpRequest::METHOD => {
+            on_signature_help_request(context, request, &context.symbols.lock().unwrap());
+        },
+        lsp_types::request::CodeActionRequest::METHOD => {
+            on_code_action_request(context, request, &context.symbols.lock().unwrap());
+        },
+        symbols::INLAY_HINT_METHOD => {
+            symbols::on_inlay_hint_request(context, request, &context.symbols.lock().unwrap());
+        },
         _ => eprintln!("handle request '{}' from client", request.method),
     }
 }
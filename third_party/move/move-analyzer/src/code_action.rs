@@ -0,0 +1,194 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Handles code action requests, offering quick fixes for some of the compiler diagnostics. The
+//! diagnostics are recognized by their messages:
+//!
+//! - an unused local variable or parameter is prefixed with an underscore,
+//! - an unused `use` declaring a single alias is removed,
+//! - a module alias which is not bound is imported, if a module with this name is known.
+
+use crate::{
+    context::Context,
+    symbols::{addr_to_ide_string, Symbols},
+};
+use lsp_server::Request;
+use lsp_types::{
+    CodeAction, CodeActionKind, CodeActionOrCommand, CodeActionParams, Diagnostic, Position, Range,
+    TextEdit, WorkspaceEdit,
+};
+use move_compiler::shared::Identifier;
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::Path,
+};
+
+/// Handles a `textDocument/codeAction` request.
+pub fn on_code_action_request(context: &Context, request: &Request, symbols: &Symbols) {
+    let parameters = serde_json::from_value::<CodeActionParams>(request.params.clone())
+        .expect("could not deserialize code action request");
+
+    let uri = parameters.text_document.uri;
+    let fpath = uri.to_file_path().unwrap();
+    let actions = match context.files.get(&fpath) {
+        Some(buffer) => parameters
+            .context
+            .diagnostics
+            .iter()
+            .flat_map(|diag| quick_fixes(symbols, &fpath, buffer, diag))
+            .map(|(title, edit)| {
+                CodeActionOrCommand::CodeAction(CodeAction {
+                    title,
+                    kind: Some(CodeActionKind::QUICKFIX),
+                    edit: Some(WorkspaceEdit::new(HashMap::from([(uri.clone(), vec![
+                        edit,
+                    ])]))),
+                    ..Default::default()
+                })
+            })
+            .collect(),
+        None => vec![],
+    };
+
+    let result = serde_json::to_value(actions).expect("could not serialize code action response");
+    let response = lsp_server::Response::new_ok(request.id.clone(), result);
+    if let Err(err) = context
+        .connection
+        .sender
+        .send(lsp_server::Message::Response(response))
+    {
+        eprintln!("could not send code action response: {:?}", err);
+    }
+}
+
+/// Returns the quick fixes for a diagnostic, as pairs of a title and an edit of the file.
+fn quick_fixes(
+    symbols: &Symbols,
+    fpath: &Path,
+    buffer: &str,
+    diag: &Diagnostic,
+) -> Vec<(String, TextEdit)> {
+    let msg = diag.message.as_str();
+    let start = diag.range.start;
+    let Some(line) = buffer.lines().nth(start.line as usize) else {
+        return vec![];
+    };
+    if let Some(name) =
+        quoted_name(msg, "Unused local variable ").or_else(|| quoted_name(msg, "Unused parameter "))
+    {
+        // make sure the source has not changed since the diagnostic was reported
+        let at_name = line
+            .chars()
+            .skip(start.character as usize)
+            .collect::<String>();
+        if !at_name.starts_with(name) {
+            return vec![];
+        }
+        vec![(
+            format!("Prefix '{}' with an underscore", name),
+            TextEdit::new(Range::new(start, start), "_".to_string()),
+        )]
+    } else if let Some(name) = quoted_name(msg, "Unused 'use' of alias ") {
+        // only remove declarations of a single alias which are on a line of their own
+        let decl = line.trim();
+        if !decl.starts_with("use ") || !decl.ends_with(';') || decl.contains('{') {
+            return vec![];
+        }
+        let line_range = Range::new(
+            Position::new(start.line, 0),
+            Position::new(start.line + 1, 0),
+        );
+        vec![(
+            format!("Remove unused alias '{}'", name),
+            TextEdit::new(line_range, String::new()),
+        )]
+    } else if let Some(name) = quoted_name(msg, "Unbound module alias ") {
+        let Some(insert_line) = use_insertion_line(symbols, fpath, buffer, start.line) else {
+            return vec![];
+        };
+        let indent = buffer
+            .lines()
+            .skip(insert_line as usize)
+            .find(|line| !line.trim().is_empty())
+            .map(|line| &line[..line.len() - line.trim_start().len()])
+            .filter(|indent| !indent.is_empty())
+            .unwrap_or("    ");
+        let insert_pos = Position::new(insert_line, 0);
+        known_modules(symbols, name)
+            .into_iter()
+            .map(|module| {
+                (
+                    format!("Import '{}'", module),
+                    TextEdit::new(
+                        Range::new(insert_pos, insert_pos),
+                        format!("{}use {};\n", indent, module),
+                    ),
+                )
+            })
+            .collect()
+    } else {
+        vec![]
+    }
+}
+
+/// If the diagnostic message starts with the prefix, returns the name in single quotes following
+/// the prefix.
+fn quoted_name<'a>(msg: &'a str, prefix: &str) -> Option<&'a str> {
+    let (name, _) = msg
+        .strip_prefix(prefix)?
+        .strip_prefix('\'')?
+        .split_once('\'')?;
+    Some(name)
+}
+
+/// Returns the fully qualified names of all modules with the given name.
+fn known_modules(symbols: &Symbols, name: &str) -> BTreeSet<String> {
+    symbols
+        .file_mods()
+        .values()
+        .flatten()
+        .filter(|m| m.name().module.value().as_str() == name)
+        .map(|m| format!("{}::{}", addr_to_ide_string(&m.name().address), name))
+        .collect()
+}
+
+/// Returns the line at which a `use` declaration is inserted into the module enclosing the given
+/// line, which is the line after the opening brace of the module.
+fn use_insertion_line(symbols: &Symbols, fpath: &Path, buffer: &str, line: u32) -> Option<u32> {
+    let fpath = dunce::canonicalize(fpath).unwrap_or_else(|_| fpath.to_path_buf());
+    let module_start = symbols
+        .file_mods()
+        .get(&fpath)?
+        .iter()
+        .map(|m| m.start().line)
+        .filter(|start| *start <= line)
+        .max()?;
+    buffer
+        .lines()
+        .enumerate()
+        .skip(module_start as usize)
+        .find(|(_, line)| line.contains('{'))
+        .map(|(idx, _)| idx as u32 + 1)
+}
+
+#[test]
+fn quoted_name_test() {
+    assert_eq!(
+        quoted_name(
+            "Unused local variable 'x'. Consider removing or prefixing with an underscore: '_x'",
+            "Unused local variable "
+        ),
+        Some("x")
+    );
+    assert_eq!(
+        quoted_name(
+            "Unused 'use' of alias 'vector'. Consider removing it",
+            "Unused 'use' of alias "
+        ),
+        Some("vector")
+    );
+    assert_eq!(
+        quoted_name("Unbound module alias 'vector'", "Unused parameter "),
+        None
+    );
+}
@@ -4,7 +4,10 @@
 
 use crate::{symbols::Symbols, vfs::VirtualFileSystem};
 use lsp_server::Connection;
-use std::sync::{Arc, Mutex};
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
 
 /// The context within which the language server is running.
 pub struct Context {
@@ -14,4 +17,6 @@ pub struct Context {
     pub files: VirtualFileSystem,
     /// Symbolication information
     pub symbols: Arc<Mutex<Symbols>>,
+    /// Directories opened in the IDE; edits (e.g. renames) are only made to files within them
+    pub workspace_dirs: Vec<PathBuf>,
 }
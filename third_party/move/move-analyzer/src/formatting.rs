@@ -0,0 +1,82 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Handles requests to format a document, using the Move formatter. The formatting options are
+//! read from the `[fmt]` section of the manifest of the package containing the document; the
+//! options sent by the client are not used.
+
+use crate::{context::Context, symbols::SymbolicatorRunner};
+use lsp_server::Request;
+use lsp_types::{DocumentFormattingParams, Position, Range, TextEdit};
+use move_fmt::{format_source, FormatConfig};
+use std::path::Path;
+
+/// Handles a `textDocument/formatting` request. If the document can be formatted, the response
+/// consists of a single edit replacing the whole document, otherwise no edits are sent.
+pub fn on_formatting_request(context: &Context, request: &Request) {
+    eprintln!("handling formatting request");
+    let parameters = serde_json::from_value::<DocumentFormattingParams>(request.params.clone())
+        .expect("could not deserialize formatting request");
+
+    let path = parameters.text_document.uri.to_file_path().unwrap();
+    let edits = match context.files.get(&path) {
+        Some(buffer) => format_buffer(&path, buffer),
+        None => {
+            eprintln!(
+                "Could not read '{:?}' when handling formatting request",
+                path
+            );
+            None
+        },
+    };
+
+    let result = serde_json::to_value(edits).expect("could not serialize formatting response");
+    let response = lsp_server::Response::new_ok(request.id.clone(), result);
+    if let Err(err) = context
+        .connection
+        .sender
+        .send(lsp_server::Message::Response(response))
+    {
+        eprintln!("could not send formatting response: {:?}", err);
+    }
+}
+
+fn format_buffer(path: &Path, buffer: &str) -> Option<Vec<TextEdit>> {
+    let config = match path.parent().and_then(SymbolicatorRunner::root_dir) {
+        Some(root) => match FormatConfig::from_manifest(&root.join("Move.toml")) {
+            Ok(config) => config,
+            Err(err) => {
+                eprintln!("could not read formatting options: {:#}", err);
+                return None;
+            },
+        },
+        None => FormatConfig::default(),
+    };
+    let formatted = match format_source(&path.to_string_lossy(), buffer, &config) {
+        Ok(formatted) => formatted,
+        Err(err) => {
+            eprintln!("could not format '{:?}': {:#}", path, err);
+            return None;
+        },
+    };
+    if formatted == buffer {
+        return Some(vec![]);
+    }
+    Some(vec![TextEdit {
+        range: Range {
+            start: Position::new(0, 0),
+            end: end_position(buffer),
+        },
+        new_text: formatted,
+    }])
+}
+
+/// Returns the position of the end of the text. Positions count characters in UTF-16 code
+/// units.
+fn end_position(text: &str) -> Position {
+    let last_line = text.rsplit('\n').next().unwrap_or_default();
+    Position::new(
+        text.matches('\n').count() as u32,
+        last_line.encode_utf16().count() as u32,
+    )
+}
@@ -5,9 +5,12 @@
 #[macro_use(sp)]
 extern crate move_ir_types;
 
+pub mod code_action;
 pub mod completion;
 pub mod context;
 pub mod diagnostics;
+pub mod formatting;
+pub mod signature_help;
 pub mod symbols;
 pub mod utils;
 pub mod vfs;
@@ -0,0 +1,290 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Handles signature help requests, showing the signature of the function whose arguments are
+//! being typed together with the argument the cursor is at.
+//!
+//! Signature help is typically requested while a call is being written, when the source does not
+//! parse (let alone type check). The enclosing call is therefore found by lexing the buffer up to
+//! the cursor, and the called function is looked up in the symbolication information computed for
+//! an earlier version of the source.
+
+use crate::{
+    context::Context,
+    symbols::{addr_to_ide_string, type_to_ide_string, IdentType, Symbols},
+};
+use lsp_server::Request;
+use lsp_types::{
+    ParameterInformation, ParameterLabel, Position, SignatureHelp, SignatureHelpParams,
+    SignatureInformation,
+};
+use move_command_line_common::files::FileHash;
+use move_compiler::{
+    naming::ast::Type_,
+    parser::lexer::{Lexer, Tok},
+    shared::Identifier,
+};
+use std::path::Path;
+
+/// A call whose arguments are being typed.
+struct EnclosingCall<'a> {
+    /// The module the function is qualified with, if any
+    module: Option<&'a str>,
+    /// The name of the function
+    name: &'a str,
+    /// Where the name of the function starts
+    name_start: Position,
+    /// The index of the argument the cursor is at
+    active_param: u32,
+}
+
+/// Handles a `textDocument/signatureHelp` request.
+pub fn on_signature_help_request(context: &Context, request: &Request, symbols: &Symbols) {
+    let parameters = serde_json::from_value::<SignatureHelpParams>(request.params.clone())
+        .expect("could not deserialize signature help request");
+
+    let fpath = parameters
+        .text_document_position_params
+        .text_document
+        .uri
+        .to_file_path()
+        .unwrap();
+    let pos = parameters.text_document_position_params.position;
+
+    let help = context.files.get(&fpath).and_then(|buffer| {
+        let call = enclosing_call(buffer, pos)?;
+        let ident_type = match symbols.use_type(&fpath, call.name_start) {
+            Some(t @ IdentType::FunctionType(..)) => t,
+            _ => find_function(symbols, &fpath, call.module, call.name)?,
+        };
+        signature_help(&ident_type, call.active_param)
+    });
+
+    let result = serde_json::to_value(help).expect("could not serialize signature help response");
+    let response = lsp_server::Response::new_ok(request.id.clone(), result);
+    if let Err(err) = context
+        .connection
+        .sender
+        .send(lsp_server::Message::Response(response))
+    {
+        eprintln!("could not send signature help response: {:?}", err);
+    }
+}
+
+/// Finds the innermost call enclosing the position whose argument list is not closed yet.
+fn enclosing_call(buffer: &str, pos: Position) -> Option<EnclosingCall<'_>> {
+    let prefix = &buffer[..byte_offset(buffer, pos)?];
+    let mut lexer = Lexer::new(prefix, FileHash::new(prefix));
+    // All tokens up to the position, with their start offsets
+    let mut tokens = vec![];
+    // For each open delimiter, the index of the name of the called function if the delimiter
+    // opens an argument list, and the number of arguments seen so far
+    let mut open = vec![];
+    loop {
+        lexer.advance().ok()?;
+        let tok = lexer.peek();
+        match tok {
+            Tok::EOF => break,
+            Tok::LParen => open.push((called_function(&tokens), 0)),
+            Tok::LBrace | Tok::LBracket => open.push((None, 0)),
+            Tok::RParen | Tok::RBrace | Tok::RBracket => {
+                open.pop();
+            },
+            Tok::Comma => {
+                if let Some((_, args)) = open.last_mut() {
+                    *args += 1
+                }
+            },
+            _ => (),
+        }
+        tokens.push((tok, lexer.content(), lexer.start_loc()));
+    }
+
+    let (name_idx, active_param) = open.pop()?;
+    let name_idx = name_idx?;
+    let (_, name, name_offset) = tokens[name_idx];
+    let module = match name_idx.checked_sub(2).map(|idx| &tokens[idx..name_idx]) {
+        Some([(Tok::Identifier, module, _), (Tok::ColonColon, _, _)]) => Some(*module),
+        _ => None,
+    };
+    Some(EnclosingCall {
+        module,
+        name,
+        name_start: position(prefix, name_offset),
+        active_param,
+    })
+}
+
+/// If the next token opens the argument list of a call, returns the index of the name of the
+/// called function, skipping explicit type arguments as in `f<u64>(..)`.
+fn called_function(tokens: &[(Tok, &str, usize)]) -> Option<usize> {
+    let mut idx = tokens.len().checked_sub(1)?;
+    if matches!(tokens[idx].0, Tok::Greater | Tok::GreaterGreater) {
+        let mut depth = 0;
+        loop {
+            match tokens[idx].0 {
+                Tok::Greater => depth += 1,
+                Tok::GreaterGreater => depth += 2,
+                Tok::Less => depth -= 1,
+                _ => (),
+            }
+            if depth <= 0 {
+                break;
+            }
+            idx = idx.checked_sub(1)?;
+        }
+        idx = idx.checked_sub(1)?;
+    }
+    (tokens[idx].0 == Tok::Identifier).then_some(idx)
+}
+
+/// Looks up a function by name, in the given module or, if there is none, in the modules of the
+/// given file.
+fn find_function(
+    symbols: &Symbols,
+    fpath: &Path,
+    module: Option<&str>,
+    name: &str,
+) -> Option<IdentType> {
+    let mut mods = symbols.file_mods().iter().flat_map(|(path, mods)| {
+        mods.iter().filter(move |m| match module {
+            Some(module) => m.name().module.value().as_str() == module,
+            None => path.as_path() == fpath,
+        })
+    });
+    mods.find_map(|m| {
+        m.functions()
+            .iter()
+            .find(|(fun_name, _)| fun_name.as_str() == name)
+            .map(|(_, fun_def)| fun_def.ident_type().clone())
+    })
+}
+
+/// Builds the signature help for a function, with offsets of the parameters in the signature.
+fn signature_help(ident_type: &IdentType, active_param: u32) -> Option<SignatureHelp> {
+    let IdentType::FunctionType(mod_ident, name, type_args, arg_names, arg_types, ret, _) =
+        ident_type
+    else {
+        return None;
+    };
+    let mut label = format!(
+        "fun {}::{}::{}",
+        addr_to_ide_string(&mod_ident.address),
+        mod_ident.module.value(),
+        name
+    );
+    if !type_args.is_empty() {
+        let type_args = type_args
+            .iter()
+            .map(type_to_ide_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        label.push_str(&format!("<{}>", type_args));
+    }
+    label.push('(');
+    let mut parameters = vec![];
+    for (idx, (arg_name, arg_type)) in arg_names.iter().zip(arg_types).enumerate() {
+        if idx > 0 {
+            label.push_str(", ");
+        }
+        // offsets are counted in UTF-16 code units
+        let start = label.encode_utf16().count() as u32;
+        label.push_str(&format!("{}: {}", arg_name, type_to_ide_string(arg_type)));
+        let end = label.encode_utf16().count() as u32;
+        parameters.push(ParameterInformation {
+            label: ParameterLabel::LabelOffsets([start, end]),
+            documentation: None,
+        });
+    }
+    label.push(')');
+    if !matches!(ret.value, Type_::Unit) {
+        label.push_str(&format!(": {}", type_to_ide_string(ret)));
+    }
+    Some(SignatureHelp {
+        signatures: vec![SignatureInformation {
+            label,
+            documentation: None,
+            parameters: Some(parameters),
+            active_parameter: None,
+        }],
+        active_signature: Some(0),
+        active_parameter: Some(active_param),
+    })
+}
+
+/// Converts a position into a byte offset in the text, or returns `None` if the position is
+/// outside of the text.
+fn byte_offset(text: &str, pos: Position) -> Option<usize> {
+    let mut line_start = 0;
+    for _ in 0..pos.line {
+        line_start += text[line_start..].find('\n')? + 1;
+    }
+    let line = text[line_start..].split('\n').next().unwrap_or_default();
+    let col = match line.char_indices().nth(pos.character as usize) {
+        Some((col, _)) => col,
+        None => line.len(),
+    };
+    Some(line_start + col)
+}
+
+/// Converts a byte offset in the text into a position.
+fn position(text: &str, offset: usize) -> Position {
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+    Position::new(
+        before.matches('\n').count() as u32,
+        before[line_start..].chars().count() as u32,
+    )
+}
+
+#[test]
+fn enclosing_call_test() {
+    let source = "fun f() {\n    m::g<u64>(1, h(2), \n}";
+    let call = enclosing_call(source, Position::new(1, 22)).unwrap();
+    assert_eq!(call.module, Some("m"));
+    assert_eq!(call.name, "g");
+    assert_eq!(call.name_start, Position::new(1, 7));
+    assert_eq!(call.active_param, 2);
+
+    let call = enclosing_call(source, Position::new(1, 19)).unwrap();
+    assert_eq!(call.module, None);
+    assert_eq!(call.name, "h");
+    assert_eq!(call.active_param, 0);
+
+    assert!(enclosing_call(source, Position::new(0, 9)).is_none());
+}
+
+#[test]
+fn signature_help_test() {
+    use crate::{
+        symbols::{handle_test_request, test_package},
+        vfs::VirtualFileSystem,
+    };
+    use url::Url;
+
+    let (path, symbols) = test_package("symbols");
+    let fpath = path.join("sources/M1.move");
+    let mut files = VirtualFileSystem::default();
+    // the call being typed is not complete yet
+    let source = std::fs::read_to_string(&fpath).unwrap().replace(
+        "M2::multi_arg(SOME_CONST, SOME_CONST)",
+        "M2::multi_arg(SOME_CONST, ",
+    );
+    files.update(fpath.clone(), &source);
+
+    let params = serde_json::json!({
+        "textDocument": { "uri": Url::from_file_path(&fpath).unwrap() },
+        "position": Position::new(40, 34),
+    });
+    let response = handle_test_request(on_signature_help_request, &symbols, files, vec![], params);
+    let help = serde_json::from_value::<SignatureHelp>(response.result.unwrap()).unwrap();
+    assert_eq!(
+        help.signatures[0].label,
+        "fun Symbols::M2::multi_arg(p1: u64, p2: u64): u64"
+    );
+    assert_eq!(
+        help.signatures[0].parameters.as_ref().unwrap()[1].label,
+        ParameterLabel::LabelOffsets([36, 43])
+    );
+    assert_eq!(help.active_parameter, Some(1));
+}
@@ -62,13 +62,14 @@ use lsp_server::{Request, RequestId};
 use lsp_types::{
     request::GotoTypeDefinitionParams, Diagnostic, DocumentSymbol, DocumentSymbolParams,
     GotoDefinitionParams, Hover, HoverContents, HoverParams, LanguageString, Location,
-    MarkedString, Position, Range, ReferenceParams, SymbolKind,
+    MarkedString, Position, Range, ReferenceParams, RenameParams, SymbolKind,
+    TextDocumentIdentifier, TextEdit, WorkspaceEdit,
 };
-use move_command_line_common::files::FileHash;
+use move_command_line_common::{env::MOVE_HOME, files::FileHash};
 use move_compiler::{
     expansion::ast::{Address, Fields, ModuleIdent, ModuleIdent_},
     naming::ast::{StructDefinition, StructFields, TParam, Type, TypeName_, Type_},
-    parser::ast::StructName,
+    parser::{ast::StructName, keywords::KEYWORDS},
     shared::Identifier,
     typing::ast::{
         BuiltinFunction_, Exp, ExpListItem, Function, FunctionBody_, LValue, LValueList, LValue_,
@@ -79,6 +80,7 @@ use move_compiler::{
 use move_ir_types::location::*;
 use move_package::{
     compilation::{build_plan::BuildPlan, compiled_package::unimplemented_v2_driver},
+    source_package::manifest_parser::parse_move_manifest_from_file,
     CompilerConfig,
 };
 use move_symbol_pool::Symbol;
@@ -96,6 +98,9 @@ use url::Url;
 /// Enabling/disabling the language server reporting readiness to support go-to-def and
 /// go-to-references to the IDE.
 pub const DEFS_AND_REFS_SUPPORT: bool = true;
+/// The method of the inlay hint request, which is not yet supported by the `lsp_types` version in
+/// use.
+pub const INLAY_HINT_METHOD: &str = "textDocument/inlayHint";
 // Building Move code requires a larger stack size on Windows (16M has been chosen somewhat
 // arbitrarily)
 pub const STACK_SIZE_BYTES: usize = 16 * 1024 * 1024;
@@ -218,7 +223,12 @@ pub struct Symbolicator {
 /// Maps a line number to a list of use-def pairs on a given line (use-def set is sorted by
 /// col_start)
 #[derive(Debug, Clone, Eq, PartialEq)]
-struct UseDefMap(BTreeMap<u32, BTreeSet<UseDef>>);
+struct UseDefMap {
+    use_defs: BTreeMap<u32, BTreeSet<UseDef>>,
+    /// Types of local variables defined without a type annotation, keyed by the position right
+    /// after the variable name (used to display inlay type hints)
+    type_hints: BTreeMap<Position, Type>,
+}
 
 /// Maps a function name to its usage definition
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -238,7 +248,8 @@ pub struct Symbols {
 
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd)]
 enum RunnerState {
-    Run(PathBuf),
+    /// Paths (files or directories) whose packages are to be symbolicated
+    Run(BTreeSet<PathBuf>),
     Wait,
     Quit,
 }
@@ -248,12 +259,34 @@ pub struct SymbolicatorRunner {
     mtx_cvar: Arc<(Mutex<RunnerState>, Condvar)>,
 }
 
+/// Packages found in the workspace together with the local packages they depend on, used to find
+/// the packages which reference definitions of a given one.
+struct WorkspacePackages {
+    /// Maps the (canonical) root directory of each package to the root directories of all of its
+    /// local dependencies, direct or transitive
+    deps: BTreeMap<PathBuf, BTreeSet<PathBuf>>,
+}
+
 impl ModuleDefs {
+    pub fn name(&self) -> &ModuleIdent_ {
+        &self.name
+    }
+
+    pub fn start(&self) -> Position {
+        self.start
+    }
+
     pub fn functions(&self) -> &BTreeMap<Symbol, FunctionDef> {
         &self.functions
     }
 }
 
+impl FunctionDef {
+    pub fn ident_type(&self) -> &IdentType {
+        &self.ident_type
+    }
+}
+
 impl fmt::Display for IdentType {
     fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -313,7 +346,7 @@ fn arg_list_to_ide_string(names: &[Symbol], types: &[Type]) -> String {
         .join(", ")
 }
 
-fn type_to_ide_string(sp!(_, t): &Type) -> String {
+pub(crate) fn type_to_ide_string(sp!(_, t): &Type) -> String {
     match t {
         Type_::Unit => "()".to_string(),
         Type_::Ref(m, r) => format!("&{} {}", if *m { "mut" } else { "" }, type_to_ide_string(r)),
@@ -352,7 +385,7 @@ fn type_to_ide_string(sp!(_, t): &Type) -> String {
     }
 }
 
-fn addr_to_ide_string(addr: &Address) -> String {
+pub(crate) fn addr_to_ide_string(addr: &Address) -> String {
     match addr {
         Address::Numerical(None, sp!(_, bytes)) => format!("{}", bytes),
         Address::Numerical(Some(name), _) => format!("{}", name),
@@ -375,10 +408,13 @@ impl SymbolicatorRunner {
         SymbolicatorRunner { mtx_cvar }
     }
 
-    /// Create a new runner
+    /// Create a new runner. Packages are symbolicated when files in them are opened; the first time
+    /// a package is symbolicated, the packages in the workspace directories which depend on it are
+    /// symbolicated as well so that references to its definitions can be found (and renamed).
     pub fn new(
         symbols: Arc<Mutex<Symbols>>,
         sender: Sender<Result<BTreeMap<Symbol, Vec<Diagnostic>>>>,
+        workspace_dirs: Vec<PathBuf>,
     ) -> Self {
         let mtx_cvar = Arc::new((Mutex::new(RunnerState::Wait), Condvar::new()));
         let thread_mtx_cvar = mtx_cvar.clone();
@@ -392,32 +428,37 @@ impl SymbolicatorRunner {
                 let mut missing_manifests = BTreeSet::new();
                 // infinite loop to wait for symbolication requests
                 eprintln!("starting symbolicator runner loop");
+                // Files compiled as part of each package symbolicated so far, used to re-symbolicate
+                // dependent packages when a file of one of their dependencies changes
+                let mut package_files: BTreeMap<PathBuf, BTreeSet<PathBuf>> = BTreeMap::new();
+                let workspace = WorkspacePackages::new(&workspace_dirs);
                 loop {
-                    let starting_path_opt = {
+                    let starting_paths = {
                         // hold the lock only as long as it takes to get the data, rather than through
                         // the whole symbolication process (hence a separate scope here)
                         let mut symbolicate = mtx.lock().unwrap();
                         match symbolicate.clone() {
                             RunnerState::Quit => break,
-                            RunnerState::Run(root_dir) => {
+                            RunnerState::Run(paths) => {
                                 *symbolicate = RunnerState::Wait;
-                                Some(root_dir)
+                                paths
                             },
                             RunnerState::Wait => {
                                 // wait for next request
                                 symbolicate = cvar.wait(symbolicate).unwrap();
                                 match symbolicate.clone() {
                                     RunnerState::Quit => break,
-                                    RunnerState::Run(root_dir) => {
+                                    RunnerState::Run(paths) => {
                                         *symbolicate = RunnerState::Wait;
-                                        Some(root_dir)
+                                        paths
                                     },
-                                    RunnerState::Wait => None,
+                                    RunnerState::Wait => BTreeSet::new(),
                                 }
                             },
                         }
                     };
-                    if let Some(starting_path) = starting_path_opt {
+                    let mut root_dirs = BTreeSet::new();
+                    for starting_path in starting_paths {
                         let root_dir = Self::root_dir(&starting_path);
                         if root_dir.is_none() && !missing_manifests.contains(&starting_path) {
                             eprintln!("reporting missing manifest");
@@ -435,11 +476,36 @@ impl SymbolicatorRunner {
                             }
                             continue;
                         }
+                        // packages which depend on the changed file need to be re-symbolicated as
+                        // well so that their references to its definitions stay up-to-date
+                        let canonical_path = dunce::canonicalize(&starting_path)
+                            .unwrap_or_else(|_| starting_path.clone());
+                        root_dirs.extend(
+                            package_files
+                                .iter()
+                                .filter(|(_, files)| files.contains(&canonical_path))
+                                .map(|(pkg, _)| pkg.clone()),
+                        );
+                        if let Some(root_dir) = root_dir {
+                            let root_dir = dunce::canonicalize(&root_dir).unwrap_or(root_dir);
+                            if !package_files.contains_key(&root_dir) {
+                                root_dirs.extend(
+                                    workspace
+                                        .dependents(&root_dir)
+                                        .into_iter()
+                                        .filter(|pkg| !package_files.contains_key(pkg)),
+                                );
+                            }
+                            root_dirs.insert(root_dir);
+                        }
+                    }
+                    for root_dir in root_dirs {
                         eprintln!("symbolication started");
-                        match Symbolicator::get_symbols(root_dir.unwrap().as_path()) {
+                        match Symbolicator::get_symbols(root_dir.as_path()) {
                             Ok((symbols_opt, lsp_diagnostics)) => {
                                 eprintln!("symbolication finished");
                                 if let Some(new_symbols) = symbols_opt {
+                                    package_files.insert(root_dir, new_symbols.file_paths());
                                     // merge the new symbols with the old ones to support a
                                     // (potentially) new project/package that symbolication information
                                     // was built for
@@ -475,7 +541,14 @@ impl SymbolicatorRunner {
         eprintln!("scheduling run for {:?}", starting_path);
         let (mtx, cvar) = &*self.mtx_cvar;
         let mut symbolicate = mtx.lock().unwrap();
-        *symbolicate = RunnerState::Run(starting_path);
+        // requests arriving before the runner picks up the previous ones are accumulated
+        match &mut *symbolicate {
+            RunnerState::Run(paths) => {
+                paths.insert(starting_path);
+            },
+            RunnerState::Wait => *symbolicate = RunnerState::Run(BTreeSet::from([starting_path])),
+            RunnerState::Quit => (),
+        }
         cvar.notify_one();
         eprintln!("scheduled run");
     }
@@ -500,6 +573,86 @@ impl SymbolicatorRunner {
         }
         None
     }
+
+    /// Finds all packages (directories containing a manifest file) in the given workspace
+    /// directory, skipping hidden directories and build output.
+    fn workspace_packages(workspace_dir: &Path) -> Vec<PathBuf> {
+        let mut packages = vec![];
+        let mut dirs = vec![workspace_dir.to_path_buf()];
+        while let Some(dir) = dirs.pop() {
+            if dir.join("Move.toml").is_file() {
+                packages.push(dir.clone());
+            }
+            let Ok(entries) = std::fs::read_dir(&dir) else {
+                continue;
+            };
+            for entry in entries.flatten() {
+                let path = entry.path();
+                let skip = match entry.file_name().to_str() {
+                    Some(name) => name.starts_with('.') || name == "build",
+                    None => true,
+                };
+                if !skip && path.is_dir() {
+                    dirs.push(path);
+                }
+            }
+        }
+        packages.sort();
+        packages
+    }
+}
+
+impl WorkspacePackages {
+    fn new(workspace_dirs: &[PathBuf]) -> Self {
+        let direct_deps: BTreeMap<_, _> = workspace_dirs
+            .iter()
+            .flat_map(|dir| SymbolicatorRunner::workspace_packages(dir))
+            .map(|pkg| {
+                let pkg = dunce::canonicalize(&pkg).unwrap_or(pkg);
+                let deps = Self::local_deps(&pkg);
+                (pkg, deps)
+            })
+            .collect();
+        let deps = direct_deps
+            .iter()
+            .map(|(pkg, direct)| {
+                let mut all = BTreeSet::new();
+                let mut worklist: Vec<_> = direct.iter().cloned().collect();
+                while let Some(dep) = worklist.pop() {
+                    if let Some(dep_deps) = direct_deps.get(&dep) {
+                        worklist.extend(dep_deps.iter().filter(|d| !all.contains(*d)).cloned());
+                    }
+                    all.insert(dep);
+                }
+                (pkg.clone(), all)
+            })
+            .collect();
+        WorkspacePackages { deps }
+    }
+
+    /// Returns root directories of the local (i.e., neither git nor on-chain) dependencies of a
+    /// package, as declared in its manifest
+    fn local_deps(pkg: &Path) -> BTreeSet<PathBuf> {
+        let Ok(manifest) = parse_move_manifest_from_file(pkg) else {
+            return BTreeSet::new();
+        };
+        manifest
+            .dependencies
+            .values()
+            .chain(manifest.dev_dependencies.values())
+            .filter(|dep| dep.git_info.is_none() && dep.node_info.is_none())
+            .filter_map(|dep| dunce::canonicalize(pkg.join(&dep.local)).ok())
+            .collect()
+    }
+
+    /// Returns root directories of the workspace packages depending on the given one
+    fn dependents(&self, pkg: &Path) -> BTreeSet<PathBuf> {
+        self.deps
+            .iter()
+            .filter(|(_, deps)| deps.contains(pkg))
+            .map(|(dependent, _)| dependent.clone())
+            .collect()
+    }
 }
 
 impl UseDef {
@@ -557,23 +710,27 @@ impl PartialEq for UseDef {
 
 impl UseDefMap {
     fn new() -> Self {
-        Self(BTreeMap::new())
+        Self {
+            use_defs: BTreeMap::new(),
+            type_hints: BTreeMap::new(),
+        }
     }
 
     fn insert(&mut self, key: u32, val: UseDef) {
-        self.0.entry(key).or_default().insert(val);
+        self.use_defs.entry(key).or_default().insert(val);
     }
 
     fn get(&self, key: u32) -> Option<BTreeSet<UseDef>> {
-        self.0.get(&key).cloned()
+        self.use_defs.get(&key).cloned()
     }
 
-    fn elements(self) -> BTreeMap<u32, BTreeSet<UseDef>> {
-        self.0
+    fn insert_type_hint(&mut self, pos: Position, typ: Type) {
+        self.type_hints.insert(pos, typ);
     }
 
-    fn extend(&mut self, use_defs: BTreeMap<u32, BTreeSet<UseDef>>) {
-        self.0.extend(use_defs);
+    fn extend(&mut self, other: UseDefMap) {
+        self.use_defs.extend(other.use_defs);
+        self.type_hints.extend(other.type_hints);
     }
 }
 
@@ -604,6 +761,26 @@ impl Symbols {
     pub fn file_mods(&self) -> &BTreeMap<PathBuf, BTreeSet<ModuleDefs>> {
         &self.file_mods
     }
+
+    /// Returns the type of the identifier used at the given position in the file, if any.
+    pub fn use_type(&self, fpath: &Path, pos: Position) -> Option<IdentType> {
+        self.file_use_defs
+            .get(fpath)?
+            .get(pos.line)?
+            .into_iter()
+            .find(|u| pos.character >= u.col_start && pos.character <= u.col_end)
+            .map(|u| u.use_type)
+    }
+
+    /// Returns the (canonical) paths of all files symbolication information was computed for.
+    pub fn file_paths(&self) -> BTreeSet<PathBuf> {
+        self.file_name_mapping
+            .values()
+            .map(|name| {
+                dunce::canonicalize(name.as_str()).unwrap_or_else(|_| PathBuf::from(name.as_str()))
+            })
+            .collect()
+    }
 }
 
 impl Symbolicator {
@@ -770,7 +947,7 @@ impl Symbolicator {
             file_use_defs
                 .entry(fpath_buffer)
                 .or_insert_with(UseDefMap::new)
-                .extend(use_defs.elements());
+                .extend(use_defs);
         }
 
         let symbols = Symbols {
@@ -1263,6 +1440,7 @@ impl Symbolicator {
                         use_defs,
                         *t.clone(),
                     );
+                    self.add_type_hint(&var.loc(), &var.value(), t, use_defs);
                 } else {
                     self.add_local_use_def(
                         &var.value(),
@@ -1948,6 +2126,48 @@ impl Symbolicator {
         }
     }
 
+    /// Add an inlay type hint for a local variable definition, unless the type of the variable is
+    /// already given in the source or the variable does not appear in the source at all (as is the
+    /// case for variables introduced by the compiler)
+    fn add_type_hint(&self, pos: &Loc, name: &Symbol, typ: &Type, use_defs: &mut UseDefMap) {
+        if matches!(
+            typ.value,
+            Type_::Anything | Type_::Var(_) | Type_::UnresolvedError
+        ) {
+            return;
+        }
+        let Some(name_start) = Self::get_start_loc(pos, &self.files, &self.file_id_mapping) else {
+            return;
+        };
+        let Some(line) = self
+            .file_id_mapping
+            .get(&pos.file_hash())
+            .and_then(|id| self.file_id_to_lines.get(id))
+            .and_then(|lines| lines.get(name_start.line as usize))
+        else {
+            return;
+        };
+        let rest = line
+            .chars()
+            .skip(name_start.character as usize)
+            .collect::<String>();
+        let Some(after_name) = rest.strip_prefix(name.as_str()) else {
+            return;
+        };
+        if after_name.starts_with(|c: char| c.is_ascii_alphanumeric() || c == '_') {
+            return;
+        }
+        let after_name = after_name.trim_start();
+        if after_name.starts_with(':') && !after_name.starts_with("::") {
+            return;
+        }
+        let name_end = Position {
+            line: name_start.line,
+            character: name_start.character + name.len() as u32,
+        };
+        use_defs.insert_type_hint(name_end, typ.clone());
+    }
+
     /// Add a use for and identifier whose definition is expected to be local to a function, and
     /// pair it with an appropriate definition
     fn add_local_use_def(
@@ -2168,6 +2388,189 @@ pub fn on_references_request(context: &Context, request: &Request, symbols: &Sym
     );
 }
 
+/// Handles rename request of the language server. All references to the renamed identifier
+/// (including its definition) are renamed, across all packages for which symbolication
+/// information is available. Only files in the workspace are edited, and identifiers defined
+/// outside of it cannot be renamed.
+pub fn on_rename_request(context: &Context, request: &Request, symbols: &Symbols) {
+    let parameters = serde_json::from_value::<RenameParams>(request.params.clone())
+        .expect("could not deserialize rename request");
+
+    let fpath = parameters
+        .text_document_position
+        .text_document
+        .uri
+        .to_file_path()
+        .unwrap();
+    let loc = parameters.text_document_position.position;
+    let new_name = parameters.new_name;
+
+    if !is_valid_identifier(&new_name) {
+        send_rename_error(
+            context,
+            request.id.clone(),
+            format!("'{}' is not a valid identifier", new_name),
+        );
+        return;
+    }
+
+    // definitions in dependencies which are not part of the workspace (e.g. downloaded to the
+    // package cache) must not be modified
+    let def_path = symbols
+        .file_use_defs
+        .get(&fpath)
+        .and_then(|use_defs| use_defs.get(loc.line))
+        .and_then(|uses| {
+            uses.into_iter()
+                .filter(|u| loc.character >= u.col_start && loc.character <= u.col_end)
+                .last()
+        })
+        .and_then(|u| symbols.file_name_mapping.get(&u.def_loc.fhash));
+    if let Some(def_path) = def_path {
+        let def_path = dunce::canonicalize(def_path.as_str())
+            .unwrap_or_else(|_| PathBuf::from(def_path.as_str()));
+        if !is_workspace_file(context, &def_path) {
+            send_rename_error(
+                context,
+                request.id.clone(),
+                format!(
+                    "cannot rename an identifier defined outside of the workspace (in {})",
+                    def_path.display()
+                ),
+            );
+            return;
+        }
+    }
+
+    on_use_request(
+        context,
+        symbols,
+        &fpath,
+        loc.line,
+        loc.character,
+        request.id.clone(),
+        |u| {
+            // references are accumulated over time, so skip the ones recorded for an earlier
+            // version of a file as they no longer correspond to its content
+            let mut ref_locs: BTreeMap<PathBuf, BTreeSet<(u32, u32, u32)>> = BTreeMap::new();
+            for ref_loc in symbols.references.get(&u.def_loc).into_iter().flatten() {
+                let path = symbols.file_name_mapping.get(&ref_loc.fhash).unwrap();
+                let path = dunce::canonicalize(path.as_str())
+                    .unwrap_or_else(|_| PathBuf::from(path.as_str()));
+                let is_current = symbols
+                    .file_use_defs
+                    .get(&path)
+                    .and_then(|use_defs| use_defs.get(ref_loc.start.line))
+                    .is_some_and(|uses| {
+                        uses.iter().any(|use_def| {
+                            use_def.col_start == ref_loc.start.character
+                                && use_def.def_loc == u.def_loc
+                        })
+                    });
+                if is_current && is_workspace_file(context, &path) {
+                    ref_locs.entry(path).or_default().insert((
+                        ref_loc.start.line,
+                        ref_loc.start.character,
+                        ref_loc.col_end,
+                    ));
+                }
+            }
+            let changes = ref_locs
+                .into_iter()
+                .map(|(path, locs)| {
+                    let edits = locs
+                        .into_iter()
+                        .map(|(line, col_start, col_end)| {
+                            TextEdit::new(
+                                Range::new(
+                                    Position::new(line, col_start),
+                                    Position::new(line, col_end),
+                                ),
+                                new_name.clone(),
+                            )
+                        })
+                        .collect();
+                    (Url::from_file_path(path).unwrap(), edits)
+                })
+                .collect::<HashMap<_, Vec<_>>>();
+            Some(serde_json::to_value(WorkspaceEdit::new(changes)).unwrap())
+        },
+    );
+}
+
+fn send_rename_error(context: &Context, id: RequestId, message: String) {
+    let response =
+        lsp_server::Response::new_err(id, lsp_server::ErrorCode::InvalidParams as i32, message);
+    if let Err(err) = context
+        .connection
+        .sender
+        .send(lsp_server::Message::Response(response))
+    {
+        eprintln!("could not send rename response: {:?}", err);
+    }
+}
+
+/// Checks if the file is located in one of the workspace directories (if there are any) rather than
+/// in the package cache
+fn is_workspace_file(context: &Context, path: &Path) -> bool {
+    let move_home = PathBuf::from(MOVE_HOME.as_str());
+    let move_home = dunce::canonicalize(&move_home).unwrap_or(move_home);
+    !path.starts_with(move_home)
+        && (context.workspace_dirs.is_empty()
+            || context
+                .workspace_dirs
+                .iter()
+                .any(|dir| path.starts_with(dir)))
+}
+
+/// Checks if the name can be used as an identifier (a keyword cannot)
+fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && !KEYWORDS.contains(&name)
+}
+
+/// Handles inlay hint request of the language server, showing the types of local variables which
+/// are defined without a type annotation.
+///
+/// Inlay hints were added in version 3.17 of the protocol which is more recent than the one
+/// supported by the `lsp_types` version in use, so the request and the response are (de)serialized
+/// here directly.
+pub fn on_inlay_hint_request(context: &Context, request: &Request, symbols: &Symbols) {
+    let text_document =
+        serde_json::from_value::<TextDocumentIdentifier>(request.params["textDocument"].clone())
+            .expect("could not deserialize inlay hint request");
+    let range = serde_json::from_value::<Range>(request.params["range"].clone())
+        .expect("could not deserialize inlay hint request");
+    let fpath = text_document.uri.to_file_path().unwrap();
+
+    let hints = match symbols.file_use_defs.get(&fpath) {
+        Some(use_defs) => use_defs
+            .type_hints
+            .range(range.start..=range.end)
+            .map(|(pos, typ)| {
+                serde_json::json!({
+                    "position": pos,
+                    "label": format!(": {}", type_to_ide_string(typ)),
+                    // the kind of a hint for a type annotation
+                    "kind": 1,
+                })
+            })
+            .collect(),
+        None => vec![],
+    };
+
+    let response = lsp_server::Response::new_ok(request.id.clone(), hints);
+    if let Err(err) = context
+        .connection
+        .sender
+        .send(lsp_server::Message::Response(response))
+    {
+        eprintln!("could not send inlay hint response: {:?}", err);
+    }
+}
+
 /// Handles hover request of the language server
 pub fn on_hover_request(context: &Context, request: &Request, symbols: &Symbols) {
     let parameters = serde_json::from_value::<HoverParams>(request.params.clone())
@@ -3674,3 +4077,224 @@ fn symbols_test() {
         None,
     );
 }
+
+/// Passes a request to the handler and returns the response it sends to the client.
+#[cfg(test)]
+pub(crate) fn handle_test_request(
+    handler: impl Fn(&Context, &Request, &Symbols),
+    symbols: &Symbols,
+    files: crate::vfs::VirtualFileSystem,
+    workspace_dirs: Vec<PathBuf>,
+    params: serde_json::Value,
+) -> lsp_server::Response {
+    let (connection, client) = lsp_server::Connection::memory();
+    let context = Context {
+        connection,
+        files,
+        symbols: Arc::new(Mutex::new(Symbolicator::empty_symbols())),
+        workspace_dirs,
+    };
+    handler(
+        &context,
+        &Request::new(1.into(), String::new(), params),
+        symbols,
+    );
+    match client.receiver.try_recv() {
+        Ok(lsp_server::Message::Response(response)) => response,
+        msg => panic!("unexpected message: {:?}", msg),
+    }
+}
+
+/// Symbolicates a package in the `tests` directory.
+#[cfg(test)]
+pub(crate) fn test_package(name: &str) -> (PathBuf, Symbols) {
+    let path = dunce::canonicalize(
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("tests")
+            .join(name),
+    )
+    .unwrap();
+    let (symbols_opt, _) = Symbolicator::get_symbols(path.as_path()).unwrap();
+    (path, symbols_opt.unwrap())
+}
+
+#[cfg(test)]
+fn text_document_position(path: &Path, line: u32, col: u32) -> serde_json::Value {
+    serde_json::json!({
+        "textDocument": { "uri": Url::from_file_path(path).unwrap() },
+        "position": Position::new(line, col),
+    })
+}
+
+/// Returns (file name, line, start column, end column) of each edit in a workspace edit.
+#[cfg(test)]
+fn edit_locs(response: lsp_server::Response) -> Vec<(String, u32, u32, u32)> {
+    let edit = serde_json::from_value::<WorkspaceEdit>(response.result.unwrap()).unwrap();
+    let mut locs = edit
+        .changes
+        .unwrap()
+        .into_iter()
+        .flat_map(|(uri, edits)| {
+            let file = uri.path_segments().unwrap().last().unwrap().to_string();
+            edits.into_iter().map(move |edit| {
+                let range = edit.range;
+                assert_eq!(range.start.line, range.end.line);
+                (
+                    file.clone(),
+                    range.start.line,
+                    range.start.character,
+                    range.end.character,
+                )
+            })
+        })
+        .collect::<Vec<_>>();
+    locs.sort();
+    locs
+}
+
+#[test]
+/// Tests if references to a function are found across modules.
+fn references_test() {
+    let (path, symbols) = test_package("symbols");
+
+    let mut params = text_document_position(&path.join("sources/M2.move"), 10, 15);
+    params["context"] = serde_json::json!({ "includeDeclaration": true });
+    let response = handle_test_request(
+        on_references_request,
+        &symbols,
+        Default::default(),
+        vec![],
+        params.clone(),
+    );
+    let locs = serde_json::from_value::<Vec<Location>>(response.result.unwrap()).unwrap();
+    let mut locs = locs
+        .into_iter()
+        .map(|loc| {
+            let file = loc.uri.path_segments().unwrap().last().unwrap().to_string();
+            (file, loc.range.start.line, loc.range.start.character)
+        })
+        .collect::<Vec<_>>();
+    locs.sort();
+    assert_eq!(locs, vec![
+        ("M1.move".to_string(), 40, 12),
+        ("M2.move".to_string(), 10, 15)
+    ]);
+
+    params["context"] = serde_json::json!({ "includeDeclaration": false });
+    let response = handle_test_request(
+        on_references_request,
+        &symbols,
+        Default::default(),
+        vec![],
+        params,
+    );
+    let locs = serde_json::from_value::<Vec<Location>>(response.result.unwrap()).unwrap();
+    assert_eq!(locs.len(), 1);
+    assert_eq!(locs[0].range.start, Position::new(40, 12));
+}
+
+#[test]
+/// Tests if renaming a function renames its definition and its uses in other modules.
+fn rename_test() {
+    let (path, symbols) = test_package("symbols");
+
+    let mut params = text_document_position(&path.join("sources/M1.move"), 40, 14);
+    params["newName"] = serde_json::json!("add");
+    let response = handle_test_request(
+        on_rename_request,
+        &symbols,
+        Default::default(),
+        vec![path.clone()],
+        params.clone(),
+    );
+    assert_eq!(edit_locs(response), vec![
+        ("M1.move".to_string(), 40, 12, 21),
+        ("M2.move".to_string(), 10, 15, 24)
+    ]);
+
+    params["newName"] = serde_json::json!("fun");
+    let response = handle_test_request(
+        on_rename_request,
+        &symbols,
+        Default::default(),
+        vec![path],
+        params,
+    );
+    assert!(response.error.is_some());
+}
+
+#[test]
+/// Tests if only files in the workspace are modified by a rename.
+fn rename_workspace_test() {
+    let (path, symbols) = test_package("workspace/App");
+    let workspace = path.parent().unwrap().to_path_buf();
+
+    let mut params = text_document_position(&path.join("sources/app.move"), 4, 13);
+    params["newName"] = serde_json::json!("get");
+    let response = handle_test_request(
+        on_rename_request,
+        &symbols,
+        Default::default(),
+        vec![workspace],
+        params.clone(),
+    );
+    assert_eq!(edit_locs(response), vec![
+        ("app.move".to_string(), 4, 13, 18),
+        ("app.move".to_string(), 4, 28, 33),
+        ("dep.move".to_string(), 1, 15, 20)
+    ]);
+
+    // the function is defined in a package outside of the workspace
+    let response = handle_test_request(
+        on_rename_request,
+        &symbols,
+        Default::default(),
+        vec![path],
+        params,
+    );
+    assert!(response.error.is_some());
+}
+
+#[test]
+/// Tests if the workspace packages depending on a package are found.
+fn workspace_packages_test() {
+    let workspace =
+        dunce::canonicalize(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/workspace"))
+            .unwrap();
+    let packages = WorkspacePackages::new(&[workspace.clone()]);
+    assert_eq!(
+        packages.dependents(&workspace.join("Dep")),
+        BTreeSet::from([workspace.join("App")])
+    );
+    assert!(packages.dependents(&workspace.join("App")).is_empty());
+}
+
+#[test]
+/// Tests if types of local variables defined without a type annotation are hinted.
+fn inlay_hint_test() {
+    let (path, symbols) = test_package("symbols");
+
+    let params = serde_json::json!({
+        "textDocument": { "uri": Url::from_file_path(path.join("sources/M1.move")).unwrap() },
+        "range": Range::new(Position::new(14, 0), Position::new(23, 0)),
+    });
+    let response = handle_test_request(
+        on_inlay_hint_request,
+        &symbols,
+        Default::default(),
+        vec![],
+        params,
+    );
+    let hints = response.result.unwrap();
+    assert_eq!(
+        hints,
+        serde_json::json!([
+            { "position": Position::new(15, 15), "label": ": u64", "kind": 1 },
+            {
+                "position": Position::new(20, 15),
+                "label": ": Symbols::M1::SomeStruct",
+                "kind": 1
+            },
+        ])
+    );
+}
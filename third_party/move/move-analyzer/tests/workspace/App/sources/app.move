@@ -0,0 +1,7 @@
+module App::app {
+    use Dep::dep;
+
+    public fun twice(): u64 {
+        dep::value() + dep::value()
+    }
+}
@@ -12,7 +12,9 @@ mod file_format_generator;
 pub mod flow_insensitive_checkers;
 pub mod function_checker;
 pub mod inliner;
+pub mod lint;
 pub mod logging;
+pub mod mutation_generator;
 pub mod options;
 pub mod pipeline;
 pub mod plan_builder;
@@ -24,6 +26,7 @@ use crate::{
         lambda_lifter, lambda_lifter::LambdaLiftingOptions, rewrite_target::RewritingScope,
         spec_checker, spec_rewriter, EnvProcessorPipeline,
     },
+    lint::BytecodeLintProcessor,
     pipeline::{
         ability_processor::AbilityProcessor, avail_copies_analysis::AvailCopiesAnalysisProcessor,
         copy_propagation::CopyPropagation, dead_store_elimination::DeadStoreElimination,
@@ -346,6 +349,10 @@ pub fn check_and_rewrite_pipeline<'a, 'b>(
         );
     }
 
+    if !for_v1_model && options.experiment_on(Experiment::LINT_CHECKS) {
+        env_pipeline.add("lint checks", |env| lint::run_exp_lints(env));
+    }
+
     if options.experiment_on(Experiment::INLINING) {
         let keep_inline_funs = options.experiment_on(Experiment::KEEP_INLINE_FUNS);
         env_pipeline.add("inlining", {
@@ -427,6 +434,11 @@ pub fn bytecode_pipeline(env: &GlobalEnv) -> FunctionTargetPipeline {
     pipeline.add_processor(Box::new(LiveVarAnalysisProcessor::new(false)));
     pipeline.add_processor(Box::new(ReferenceSafetyProcessor {}));
 
+    // Bytecode lints rely on the live variable annotation computed above.
+    if options.experiment_on(Experiment::LINT_CHECKS) {
+        pipeline.add_processor(Box::new(BytecodeLintProcessor {}));
+    }
+
     if options.experiment_on(Experiment::ABILITY_CHECK) {
         pipeline.add_processor(Box::new(ExitStateAnalysisProcessor {}));
         pipeline.add_processor(Box::new(AbilityProcessor {}));
@@ -0,0 +1,49 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implements lints which are checked on the stackless bytecode.
+
+use crate::{
+    lint::{BytecodeLint, LintReporter},
+    pipeline::livevar_analysis_processor::LiveVarAnnotation,
+};
+use move_stackless_bytecode::{
+    function_target::FunctionTarget,
+    stackless_bytecode::{AssignKind, Bytecode},
+};
+
+/// Reports an explicit `copy x` where `x` is not used afterwards, so that it could be
+/// moved instead.
+pub struct UnnecessaryCopy;
+
+impl BytecodeLint for UnnecessaryCopy {
+    fn name(&self) -> &'static str {
+        "unnecessary_copy"
+    }
+
+    fn check(
+        &self,
+        reporter: &LintReporter,
+        target: &FunctionTarget,
+        live_vars: &LiveVarAnnotation,
+    ) {
+        for (offset, bytecode) in target.get_bytecode().iter().enumerate() {
+            // Only an explicit `copy` produces an assignment of kind `Copy`; implicit copies
+            // are inferred later in the pipeline.
+            if let Bytecode::Assign(attr_id, _, src, AssignKind::Copy) = bytecode {
+                let Some(info) = live_vars.get_live_var_info_at(offset as u16) else {
+                    continue;
+                };
+                if !info.after.contains_key(src) {
+                    reporter.report(
+                        &target.get_bytecode_loc(*attr_id),
+                        &format!(
+                            "unnecessary `copy`: {} is not used afterwards and can be moved",
+                            target.get_local_name_for_error_message(*src)
+                        ),
+                    )
+                }
+            }
+        }
+    }
+}
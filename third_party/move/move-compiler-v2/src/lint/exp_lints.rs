@@ -0,0 +1,168 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implements lints which are checked on the AST.
+//!
+//! Some constructs are also synthesized by the compiler (e.g. `for` loops are desugared into
+//! `while (true)` loops). To avoid reporting those, the lints here look at the source text
+//! of a construct to make sure it was actually written by the user.
+
+use crate::lint::{ExpLint, LintReporter};
+use move_model::{
+    ast::{ExpData, Operation, Pattern, Value},
+    model::{GlobalEnv, NodeId},
+    ty::Type,
+};
+
+/// Returns true if the source text of the node starts with the given prefix.
+fn source_starts_with(env: &GlobalEnv, id: NodeId, prefix: &str) -> bool {
+    env.get_source(&env.get_node_loc(id))
+        .is_ok_and(|text| text.starts_with(prefix))
+}
+
+/// Reports `*&e`, which is the same as `e`, and `&*r` or `&mut *r` where `r` already is a
+/// reference of the same kind, which is the same as `r`.
+pub struct NeedlessBorrow;
+
+impl ExpLint for NeedlessBorrow {
+    fn name(&self) -> &'static str {
+        "needless_borrow"
+    }
+
+    fn check_exp(&self, reporter: &LintReporter, exp: &ExpData) {
+        let env = reporter.env();
+        match exp {
+            ExpData::Call(id, Operation::Deref, args) => {
+                if matches!(args[0].as_ref(), ExpData::Call(_, Operation::Borrow(_), _))
+                    && source_starts_with(env, *id, "*")
+                {
+                    reporter.report(
+                        &env.get_node_loc(*id),
+                        "needless borrow: dereferencing a borrow is the same as the borrowed value",
+                    )
+                }
+            },
+            ExpData::Call(id, Operation::Borrow(kind), args) => {
+                if let ExpData::Call(_, Operation::Deref, inner) = args[0].as_ref() {
+                    if matches!(
+                        env.get_node_type(inner[0].node_id()),
+                        Type::Reference(inner_kind, _) if inner_kind == *kind
+                    ) && source_starts_with(env, *id, "&")
+                    {
+                        reporter.report(
+                            &env.get_node_loc(*id),
+                            "needless borrow: borrowing a dereferenced reference is the same \
+                            as the reference",
+                        )
+                    }
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+/// Reports `while (true) { .. }`, which is better written as `loop { .. }`.
+pub struct WhileTrue;
+
+impl ExpLint for WhileTrue {
+    fn name(&self) -> &'static str {
+        "while_true"
+    }
+
+    fn check_exp(&self, reporter: &LintReporter, exp: &ExpData) {
+        let env = reporter.env();
+        // A `while (c) body` is represented as `loop { if (c) body else break }`.
+        if let ExpData::Loop(id, body) = exp {
+            if let ExpData::IfElse(_, cond, _, els) = body.as_ref() {
+                if let (ExpData::Value(cond_id, Value::Bool(true)), ExpData::LoopCont(_, false)) =
+                    (cond.as_ref(), els.as_ref())
+                {
+                    if env
+                        .get_source(&env.get_node_loc(*cond_id))
+                        .is_ok_and(|text| text == "true")
+                    {
+                        reporter.report(
+                            &env.get_node_loc(*id),
+                            "`while (true)` can be written as `loop`",
+                        )
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Reports assignments of a variable to itself, as in `x = x`, which have no effect.
+pub struct SelfAssignment;
+
+impl ExpLint for SelfAssignment {
+    fn name(&self) -> &'static str {
+        "self_assignment"
+    }
+
+    fn check_exp(&self, reporter: &LintReporter, exp: &ExpData) {
+        if let ExpData::Assign(id, Pattern::Var(_, lhs), rhs) = exp {
+            let rhs = match rhs.as_ref() {
+                ExpData::LocalVar(_, name) => Some(*name),
+                ExpData::Temporary(_, idx) => reporter
+                    .fun()
+                    .get_parameters()
+                    .get(*idx)
+                    .map(|param| param.0),
+                _ => None,
+            };
+            if rhs == Some(*lhs) {
+                let env = reporter.env();
+                reporter.report(
+                    &env.get_node_loc(*id),
+                    &format!(
+                        "variable `{}` is assigned to itself, which has no effect",
+                        lhs.display(env.symbol_pool())
+                    ),
+                )
+            }
+        }
+    }
+}
+
+/// Reports conditionals with an empty branch, as in `if (c) {} else { .. }`.
+pub struct EmptyBranch;
+
+impl ExpLint for EmptyBranch {
+    fn name(&self) -> &'static str {
+        "empty_branch"
+    }
+
+    fn check_exp(&self, reporter: &LintReporter, exp: &ExpData) {
+        let env = reporter.env();
+        if let ExpData::IfElse(id, _, then, els) = exp {
+            // Skip conditionals which are not written by the user, like the one a `while`
+            // loop is represented with.
+            if matches!(els.as_ref(), ExpData::LoopCont(..)) || !source_starts_with(env, *id, "if")
+            {
+                return;
+            }
+            // An empty block is represented as the unit value, like a missing `else`, so
+            // the source text is used to tell them apart.
+            let is_empty = |branch: &ExpData| {
+                env.get_source(&env.get_node_loc(branch.node_id()))
+                    .ok()
+                    .and_then(|text| text.strip_prefix('{')?.strip_suffix('}'))
+                    .is_some_and(|inner| inner.trim().is_empty())
+            };
+            let report = |branch_id: NodeId, branch: &str| {
+                reporter.report(
+                    &env.get_node_loc(branch_id),
+                    &format!("empty {} branch of conditional", branch),
+                )
+            };
+            if is_empty(then) {
+                report(then.node_id(), "`if`")
+            }
+            if is_empty(els) {
+                report(els.node_id(), "`else`")
+            }
+        }
+    }
+}
@@ -0,0 +1,322 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implements a framework for named, individually configurable lints.
+//!
+//! A lint is a check which reports code that is legal but likely unintended or unidiomatic.
+//! Lints come in two flavors:
+//!
+//! - `ExpLint`s inspect the AST of a function body, as produced by the model builder and
+//!   before any rewriting (e.g. inlining) happens.
+//! - `BytecodeLint`s inspect the stackless bytecode of a function, together with the
+//!   annotations computed by the bytecode pipeline up to the point the lints run.
+//!
+//! New lints are added by implementing one of the traits and registering the lint in
+//! `exp_lints` or `bytecode_lints`. Lints only run if the `lint-checks` experiment is on.
+//!
+//! Each lint has a name which can be used to configure its level (`allow`, `warn` or `deny`)
+//! via `Options::lint_levels`, which is populated from the `[lints]` section of `Move.toml`.
+//! A lint can also be suppressed for a function or a whole module with the attribute
+//! `#[lint::skip(<lint name>, ..)]`.
+
+mod bytecode_lints;
+mod exp_lints;
+
+use crate::{pipeline::livevar_analysis_processor::LiveVarAnnotation, Options};
+use codespan_reporting::diagnostic::Severity;
+use move_compiler::shared::known_attributes::LintAttribute;
+use move_model::{
+    ast::{Attribute, ExpData},
+    model::{FunctionEnv, GlobalEnv, Loc},
+    symbol::SymbolPool,
+};
+use move_stackless_bytecode::{
+    function_target::{FunctionData, FunctionTarget},
+    function_target_pipeline::{FunctionTargetProcessor, FunctionTargetsHolder},
+};
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
+/// The level at which a lint is reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LintLevel {
+    /// The lint is not run.
+    Allow,
+    /// Findings of the lint are reported as warnings.
+    Warn,
+    /// Findings of the lint are reported as errors.
+    Deny,
+}
+
+impl FromStr for LintLevel {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "allow" => Ok(LintLevel::Allow),
+            "warn" => Ok(LintLevel::Warn),
+            "deny" => Ok(LintLevel::Deny),
+            _ => Err(format!(
+                "invalid lint level `{}`: must be `allow`, `warn` or `deny`",
+                s
+            )),
+        }
+    }
+}
+
+impl Display for LintLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LintLevel::Allow => "allow",
+            LintLevel::Warn => "warn",
+            LintLevel::Deny => "deny",
+        })
+    }
+}
+
+/// A lint which inspects the AST of function bodies.
+pub trait ExpLint {
+    /// The name of the lint, as used in `Move.toml` and `#[lint::skip(..)]`.
+    fn name(&self) -> &'static str;
+
+    /// Checks a single expression, reporting any findings to the reporter. This is called
+    /// for each sub-expression of a function body, except those in specification blocks.
+    fn check_exp(&self, reporter: &LintReporter, exp: &ExpData);
+}
+
+/// A lint which inspects the stackless bytecode of functions. At the point these lints run,
+/// the live variable annotation is available.
+pub trait BytecodeLint {
+    /// The name of the lint, as used in `Move.toml` and `#[lint::skip(..)]`.
+    fn name(&self) -> &'static str;
+
+    /// Checks the given function target, reporting any findings to the reporter.
+    fn check(
+        &self,
+        reporter: &LintReporter,
+        target: &FunctionTarget,
+        live_vars: &LiveVarAnnotation,
+    );
+}
+
+/// Returns all registered AST lints.
+pub fn exp_lints() -> Vec<Box<dyn ExpLint>> {
+    vec![
+        Box::new(exp_lints::NeedlessBorrow),
+        Box::new(exp_lints::WhileTrue),
+        Box::new(exp_lints::SelfAssignment),
+        Box::new(exp_lints::EmptyBranch),
+    ]
+}
+
+/// Returns all registered bytecode lints.
+pub fn bytecode_lints() -> Vec<Box<dyn BytecodeLint>> {
+    vec![Box::new(bytecode_lints::UnnecessaryCopy)]
+}
+
+/// Returns the names of all registered lints.
+pub fn all_lint_names() -> BTreeSet<&'static str> {
+    exp_lints()
+        .iter()
+        .map(|lint| lint.name())
+        .chain(bytecode_lints().iter().map(|lint| lint.name()))
+        .collect()
+}
+
+/// Reports findings of a lint for a given function, at the configured level.
+pub struct LintReporter<'a, 'env> {
+    fun: &'a FunctionEnv<'env>,
+    lint_name: &'static str,
+    severity: Severity,
+}
+
+impl<'a, 'env> LintReporter<'a, 'env> {
+    /// Returns the function the lint is run on.
+    pub fn fun(&self) -> &'a FunctionEnv<'env> {
+        self.fun
+    }
+
+    /// Returns the global environment.
+    pub fn env(&self) -> &'env GlobalEnv {
+        self.fun.module_env.env
+    }
+
+    /// Reports a finding of the lint at the given location.
+    pub fn report(&self, loc: &Loc, msg: &str) {
+        self.env()
+            .diag_with_notes(self.severity, loc, msg, vec![format!(
+                "this diagnostic is reported by the lint `{}`, which can be suppressed \
+            with `#[{}({})]`",
+                self.lint_name,
+                LintAttribute::SKIP,
+                self.lint_name
+            )])
+    }
+}
+
+/// Returns the severity at which the named lint is reported for the given function, or `None`
+/// if the lint should not run.
+fn lint_severity(options: &Options, fun: &FunctionEnv, lint_name: &str) -> Option<Severity> {
+    let level = options
+        .lint_levels
+        .get(lint_name)
+        .copied()
+        .unwrap_or(LintLevel::Warn);
+    let skipped = skip_attribute_args(fun.symbol_pool(), fun.get_attributes())
+        .chain(skip_attribute_args(
+            fun.symbol_pool(),
+            fun.module_env.get_attributes(),
+        ))
+        .any(|arg| fun.symbol_pool().string(arg.name()).as_str() == lint_name);
+    match level {
+        _ if skipped => None,
+        LintLevel::Allow => None,
+        LintLevel::Warn => Some(Severity::Warning),
+        LintLevel::Deny => Some(Severity::Error),
+    }
+}
+
+/// Returns the arguments of all `#[lint::skip(..)]` attributes in the list, which are the
+/// names of the suppressed lints.
+fn skip_attribute_args<'a>(
+    pool: &'a SymbolPool,
+    attrs: &'a [Attribute],
+) -> impl Iterator<Item = &'a Attribute> {
+    attrs
+        .iter()
+        .filter_map(move |attr| match attr {
+            Attribute::Apply(_, name, args)
+                if pool.string(*name).as_str() == LintAttribute::SKIP =>
+            {
+                Some(args)
+            },
+            _ => None,
+        })
+        .flatten()
+}
+
+/// Warns about lint names in the configuration and in `#[lint::skip(..)]` attributes which
+/// do not denote a registered lint.
+fn check_lint_names(env: &GlobalEnv, options: &Options) {
+    let known = all_lint_names();
+    for name in options.lint_levels.keys() {
+        if !known.contains(name.as_str()) {
+            env.diag(
+                Severity::Warning,
+                &env.unknown_loc(),
+                &format!("unknown lint `{}` in lint configuration", name),
+            )
+        }
+    }
+    let check_attributes = |attrs: &[Attribute]| {
+        for arg in skip_attribute_args(env.symbol_pool(), attrs) {
+            let name = env.symbol_pool().string(arg.name());
+            if !known.contains(name.as_str()) {
+                env.diag(
+                    Severity::Warning,
+                    &env.get_node_loc(arg.node_id()),
+                    &format!("unknown lint `{}`", name),
+                )
+            }
+        }
+    };
+    for module in env.get_modules() {
+        if module.is_target() {
+            check_attributes(module.get_attributes());
+            for fun in module.get_functions() {
+                check_attributes(fun.get_attributes())
+            }
+        }
+    }
+}
+
+/// Runs all enabled AST lints on the target functions of the environment.
+pub fn run_exp_lints(env: &GlobalEnv) {
+    let options = env.get_extension::<Options>().unwrap_or_default();
+    check_lint_names(env, &options);
+    let lints = exp_lints();
+    for module in env.get_modules() {
+        if !module.is_target() {
+            continue;
+        }
+        for fun in module.get_functions() {
+            let Some(def) = fun.get_def() else {
+                continue;
+            };
+            let reporters = lints
+                .iter()
+                .filter_map(|lint| {
+                    let severity = lint_severity(&options, &fun, lint.name())?;
+                    Some((lint, LintReporter {
+                        fun: &fun,
+                        lint_name: lint.name(),
+                        severity,
+                    }))
+                })
+                .collect::<Vec<_>>();
+            if reporters.is_empty() {
+                continue;
+            }
+            let mut spec_depth = 0;
+            def.visit_pre_post(&mut |post, exp| {
+                if let ExpData::SpecBlock(..) = exp {
+                    if post {
+                        spec_depth -= 1
+                    } else {
+                        spec_depth += 1
+                    }
+                } else if !post && spec_depth == 0 {
+                    for (lint, reporter) in &reporters {
+                        lint.check_exp(reporter, exp)
+                    }
+                }
+                true
+            });
+        }
+    }
+}
+
+/// Runs all enabled bytecode lints. This processor must run at a point in the pipeline
+/// where the live variable annotation is available.
+pub struct BytecodeLintProcessor {}
+
+impl FunctionTargetProcessor for BytecodeLintProcessor {
+    fn process(
+        &self,
+        _targets: &mut FunctionTargetsHolder,
+        func_env: &FunctionEnv,
+        data: FunctionData,
+        _scc_opt: Option<&[FunctionEnv]>,
+    ) -> FunctionData {
+        if func_env.is_native() {
+            return data;
+        }
+        let options = func_env
+            .module_env
+            .env
+            .get_extension::<Options>()
+            .unwrap_or_default();
+        let target = FunctionTarget::new(func_env, &data);
+        let Some(live_vars) = target.get_annotations().get::<LiveVarAnnotation>() else {
+            return data;
+        };
+        for lint in bytecode_lints() {
+            if let Some(severity) = lint_severity(&options, func_env, lint.name()) {
+                let reporter = LintReporter {
+                    fun: func_env,
+                    lint_name: lint.name(),
+                    severity,
+                };
+                lint.check(&reporter, &target, live_vars)
+            }
+        }
+        data
+    }
+
+    fn name(&self) -> String {
+        "bytecode_lints".to_string()
+    }
+}
@@ -0,0 +1,362 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implements generation of source-level mutants for mutation testing of Move packages.
+//!
+//! Mutation sites are discovered by walking the AST of the target functions in the model.
+//! Each mutation is described as a replacement of a byte range in the original source file,
+//! so it can be applied to the source text without re-printing the program. The following
+//! mutation operators are supported:
+//!
+//! - comparison operators are flipped (e.g. `<` becomes `>=`),
+//! - arithmetic operators are swapped (e.g. `+` becomes `-`),
+//! - numeric and boolean literals are changed,
+//! - `assert!` calls are dropped.
+//!
+//! Code in specification blocks and test-only code is never mutated.
+
+use move_model::{
+    ast::{ExpData, Operation, Value},
+    model::{FunctionEnv, GlobalEnv, Loc},
+};
+use std::{
+    collections::BTreeSet,
+    fmt::{self, Display, Formatter},
+};
+
+/// The kind of a mutation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MutationKind {
+    /// A comparison operator has been replaced by its negation.
+    FlipComparison,
+    /// An arithmetic operator has been replaced by another one.
+    SwapArithmetic,
+    /// A numeric or boolean literal has been changed.
+    ChangeLiteral,
+    /// An `assert!` has been removed.
+    DropAssert,
+}
+
+impl Display for MutationKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        use MutationKind::*;
+        f.write_str(match self {
+            FlipComparison => "flip comparison",
+            SwapArithmetic => "swap arithmetic operator",
+            ChangeLiteral => "change literal",
+            DropAssert => "drop assert",
+        })
+    }
+}
+
+/// A single mutation of a source file, replacing the text in the byte range
+/// `start..end` by `replacement`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Mutation {
+    /// The name of the source file this mutation applies to.
+    pub file_name: String,
+    /// Start byte offset of the mutated text.
+    pub start: usize,
+    /// End byte offset (exclusive) of the mutated text.
+    pub end: usize,
+    /// The line number (1-based) of the mutated text.
+    pub line: usize,
+    /// The column number (1-based) of the mutated text.
+    pub column: usize,
+    /// The kind of mutation.
+    pub kind: MutationKind,
+    /// The original source text.
+    pub original: String,
+    /// The text replacing the original one.
+    pub replacement: String,
+    /// The full name of the function the mutation is in.
+    pub function: String,
+}
+
+impl Mutation {
+    /// Applies the mutation to the given contents of the source file.
+    pub fn apply(&self, source: &str) -> String {
+        let mut result = String::with_capacity(source.len() + self.replacement.len());
+        result.push_str(&source[..self.start]);
+        result.push_str(&self.replacement);
+        result.push_str(&source[self.end..]);
+        result
+    }
+}
+
+impl Display for Mutation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}: {} `{}` -> `{}` in `{}`",
+            self.file_name,
+            self.line,
+            self.column,
+            self.kind,
+            self.original,
+            self.replacement,
+            self.function
+        )
+    }
+}
+
+/// Generates all mutations for the non-test functions of the target modules in the
+/// environment. The result is ordered by source location.
+pub fn generate_mutations(env: &GlobalEnv) -> Vec<Mutation> {
+    let mut mutations = BTreeSet::new();
+    for module in env.get_modules() {
+        if !module.is_target() || module.is_test_only() {
+            continue;
+        }
+        let file_id = module.get_loc().file_id();
+        for fun in module.get_functions() {
+            if fun.is_test_only() || fun.is_verify_only() {
+                continue;
+            }
+            MutationGenerator {
+                env,
+                fun: &fun,
+                source: env.get_file_source(file_id),
+                file_name: env.get_file(file_id).to_string_lossy().to_string(),
+                mutations: &mut mutations,
+            }
+            .run();
+        }
+    }
+    // Inlined code appears at multiple places in the AST, but is only mutated once.
+    let mut mutations = mutations.into_iter().collect::<Vec<_>>();
+    mutations.dedup_by(|m1, m2| {
+        m1.file_name == m2.file_name
+            && m1.start == m2.start
+            && m1.end == m2.end
+            && m1.replacement == m2.replacement
+    });
+    mutations
+}
+
+struct MutationGenerator<'a, 'env> {
+    env: &'env GlobalEnv,
+    fun: &'a FunctionEnv<'env>,
+    source: &'env str,
+    file_name: String,
+    mutations: &'a mut BTreeSet<Mutation>,
+}
+
+impl<'a, 'env> MutationGenerator<'a, 'env> {
+    fn run(&mut self) {
+        let fun = self.fun;
+        let Some(def) = fun.get_def() else {
+            return;
+        };
+        let file_id = fun.module_env.get_loc().file_id();
+        let mut spec_depth = 0;
+        def.visit_pre_post(&mut |post, e| {
+            if let ExpData::SpecBlock(..) = e {
+                if post {
+                    spec_depth -= 1
+                } else {
+                    spec_depth += 1
+                }
+            } else if !post && spec_depth == 0 {
+                let loc = self.env.get_node_loc(e.node_id());
+                // Code inlined from other files is mutated where it is defined.
+                if loc.file_id() == file_id {
+                    self.visit(&loc, e)
+                }
+            }
+            true
+        });
+    }
+
+    fn visit(&mut self, loc: &Loc, e: &ExpData) {
+        match e {
+            ExpData::Call(_, op, args) if args.len() == 2 => {
+                let replacements: &[&str] = match op {
+                    Operation::Eq => &["!="],
+                    Operation::Neq => &["=="],
+                    Operation::Lt => &[">="],
+                    Operation::Ge => &["<"],
+                    Operation::Gt => &["<="],
+                    Operation::Le => &[">"],
+                    Operation::Add => &["-", "*"],
+                    Operation::Sub => &["+"],
+                    Operation::Mul => &["+", "/"],
+                    Operation::Div => &["*", "%"],
+                    Operation::Mod => &["/"],
+                    _ => return,
+                };
+                let kind = if matches!(
+                    op,
+                    Operation::Add
+                        | Operation::Sub
+                        | Operation::Mul
+                        | Operation::Div
+                        | Operation::Mod
+                ) {
+                    MutationKind::SwapArithmetic
+                } else {
+                    MutationKind::FlipComparison
+                };
+                let lhs = self.env.get_node_loc(args[0].node_id()).span().end();
+                let rhs = self.env.get_node_loc(args[1].node_id()).span().start();
+                if let Some((start, end)) =
+                    operator_range(self.source, lhs.to_usize(), rhs.to_usize(), operator(op))
+                {
+                    for replacement in replacements {
+                        self.add(kind, start, end, replacement.to_string())
+                    }
+                }
+            },
+            ExpData::Value(_, value) => {
+                let (start, end) = range(loc);
+                let text = &self.source[start..end];
+                let replacement = match value {
+                    Value::Number(_) => mutate_number_literal(text),
+                    Value::Bool(b) if text == b.to_string() => Some((!b).to_string()),
+                    _ => None,
+                };
+                if let Some(replacement) = replacement {
+                    self.add(MutationKind::ChangeLiteral, start, end, replacement)
+                }
+            },
+            ExpData::IfElse(..) => {
+                // The `assert!` macro is expanded into a conditional which has the location
+                // of the macro call.
+                let (start, end) = range(loc);
+                if self.source[start..end].starts_with("assert!") {
+                    self.add(MutationKind::DropAssert, start, end, "()".to_string())
+                }
+            },
+            _ => {},
+        }
+    }
+
+    fn add(&mut self, kind: MutationKind, start: usize, end: usize, replacement: String) {
+        let (line, column) = line_and_column(self.source, start);
+        self.mutations.insert(Mutation {
+            file_name: self.file_name.clone(),
+            start,
+            end,
+            line,
+            column,
+            kind,
+            original: self.source[start..end].to_string(),
+            replacement,
+            function: self.fun.get_full_name_str(),
+        });
+    }
+}
+
+fn range(loc: &Loc) -> (usize, usize) {
+    (loc.span().start().to_usize(), loc.span().end().to_usize())
+}
+
+/// Returns the source text of a binary operator.
+fn operator(op: &Operation) -> &'static str {
+    match op {
+        Operation::Eq => "==",
+        Operation::Neq => "!=",
+        Operation::Lt => "<",
+        Operation::Ge => ">=",
+        Operation::Gt => ">",
+        Operation::Le => "<=",
+        Operation::Add => "+",
+        Operation::Sub => "-",
+        Operation::Mul => "*",
+        Operation::Div => "/",
+        Operation::Mod => "%",
+        _ => unreachable!("not a mutated binary operator"),
+    }
+}
+
+/// Locates the operator token between the end of the left operand and the start of the right
+/// one. Only whitespace and parentheses may surround the operator; anything else (e.g. comments)
+/// makes the site ineligible for mutation.
+fn operator_range(
+    source: &str,
+    lhs_end: usize,
+    rhs_start: usize,
+    op: &str,
+) -> Option<(usize, usize)> {
+    let gap = source.get(lhs_end..rhs_start)?;
+    let token = gap.trim_matches(|c: char| c.is_whitespace() || c == '(' || c == ')');
+    if token != op {
+        return None;
+    }
+    let start = lhs_end + gap.find(op)?;
+    Some((start, start + op.len()))
+}
+
+/// Computes a replacement for a numeric literal, preserving any type suffix. Literals which
+/// are not spelled out in the source (e.g. named constants) are not mutated.
+fn mutate_number_literal(text: &str) -> Option<String> {
+    let (digits, suffix) = match text.find('u') {
+        Some(pos) => text.split_at(pos),
+        None => (text, ""),
+    };
+    if !matches!(suffix, "" | "u8" | "u16" | "u32" | "u64" | "u128" | "u256") {
+        return None;
+    }
+    let is_zero = if let Some(hex) = digits.strip_prefix("0x") {
+        if hex.is_empty() || !hex.chars().all(|c| c.is_ascii_hexdigit() || c == '_') {
+            return None;
+        }
+        hex.chars().all(|c| c == '0' || c == '_')
+    } else {
+        if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit() || c == '_') {
+            return None;
+        }
+        digits.chars().all(|c| c == '0' || c == '_')
+    };
+    // Mutating to `0` or `1` keeps the literal within range of any integer type.
+    Some(format!("{}{}", if is_zero { "1" } else { "0" }, suffix))
+}
+
+/// Returns the 1-based line and column of the byte offset in the source.
+fn line_and_column(source: &str, offset: usize) -> (usize, usize) {
+    let prefix = &source[..offset];
+    let line = prefix.matches('\n').count() + 1;
+    let column = offset - prefix.rfind('\n').map(|pos| pos + 1).unwrap_or(0) + 1;
+    (line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mutate_number_literal() {
+        assert_eq!(mutate_number_literal("42"), Some("0".to_string()));
+        assert_eq!(mutate_number_literal("0"), Some("1".to_string()));
+        assert_eq!(mutate_number_literal("1_000u64"), Some("0u64".to_string()));
+        assert_eq!(mutate_number_literal("0x00u8"), Some("1u8".to_string()));
+        assert_eq!(mutate_number_literal("0xFF"), Some("0".to_string()));
+        assert_eq!(mutate_number_literal("MAX_SUPPLY"), None);
+        assert_eq!(mutate_number_literal("10u7"), None);
+    }
+
+    #[test]
+    fn test_operator_range() {
+        let source = "(a + 1) <= (b)";
+        assert_eq!(operator_range(source, 6, 12, "<="), Some((8, 10)));
+        assert_eq!(operator_range(source, 6, 12, "<"), None);
+        assert_eq!(operator_range("a /* < */ < b", 1, 12, "<"), None);
+    }
+
+    #[test]
+    fn test_apply() {
+        let mutation = Mutation {
+            file_name: "test.move".to_string(),
+            start: 2,
+            end: 3,
+            line: 1,
+            column: 3,
+            kind: MutationKind::SwapArithmetic,
+            original: "+".to_string(),
+            replacement: "-".to_string(),
+            function: "m::f".to_string(),
+        };
+        assert_eq!(mutation.apply("a + b"), "a - b");
+        assert_eq!(line_and_column("a\nbc + d", 5), (2, 4));
+    }
+}
@@ -2,7 +2,10 @@
 // Parts of the project are originally copyright © Meta Platforms, Inc.
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::experiments::{DefaultValue, EXPERIMENTS};
+use crate::{
+    experiments::{DefaultValue, EXPERIMENTS},
+    lint::LintLevel,
+};
 use clap::Parser;
 use codespan_reporting::diagnostic::Severity;
 use itertools::Itertools;
@@ -71,6 +74,10 @@ pub struct Options {
     /// Whether to compile #[test] and #[test_only] code
     #[clap(skip)]
     pub compile_test_code: bool,
+    /// Levels of lints, by lint name. Lints not in this map are reported as warnings.
+    /// Only used if the `lint-checks` experiment is on.
+    #[clap(skip)]
+    pub lint_levels: BTreeMap<String, LintLevel>,
 }
 
 impl Default for Options {
@@ -159,6 +166,13 @@ impl Options {
             ..self
         }
     }
+
+    pub fn set_lint_levels(self, lint_levels: BTreeMap<String, LintLevel>) -> Self {
+        Self {
+            lint_levels,
+            ..self
+        }
+    }
 }
 
 /// Finds the experiment in the list of definitions. A definition
@@ -0,0 +1,28 @@
+module 0x42::empty_branch {
+    fun empty_then(c: bool): u64 {
+        let x = 0;
+        if (c) {} else { x = 1 };
+        x
+    }
+
+    fun empty_else(c: bool): u64 {
+        let x = 0;
+        if (c) { x = 1 } else {};
+        x
+    }
+
+    fun empty_if(c: bool) {
+        if (c) {}
+    }
+
+    // Not reported: the body of a `while` loop is not a branch.
+    fun empty_while(c: bool) {
+        while (c) {}
+    }
+
+    fun no_else(c: bool): u64 {
+        let x = 0;
+        if (c) x = 1;
+        x
+    }
+}
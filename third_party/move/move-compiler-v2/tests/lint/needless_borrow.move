@@ -0,0 +1,30 @@
+module 0x42::needless_borrow {
+    struct S has copy, drop { x: u64 }
+
+    fun deref_of_borrow(s: S): u64 {
+        let t = *&s;
+        t.x
+    }
+
+    fun borrow_of_deref(r: &S): u64 {
+        let r = &*r;
+        r.x
+    }
+
+    fun borrow_mut_of_deref(r: &mut S) {
+        let r = &mut *r;
+        r.x = 1
+    }
+
+    // Not reported: this freezes the mutable reference.
+    fun freeze_of_deref(r: &mut S): u64 {
+        let r = &*r;
+        r.x
+    }
+
+    #[lint::skip(needless_borrow)]
+    fun skipped(s: S): u64 {
+        let t = *&s;
+        t.x
+    }
+}
@@ -0,0 +1,17 @@
+module 0x42::self_assignment {
+    fun local(): u64 {
+        let x = 1;
+        x = x;
+        x
+    }
+
+    fun param(x: u64): u64 {
+        x = x;
+        x
+    }
+
+    fun not_self(x: u64, y: u64): u64 {
+        x = y;
+        x
+    }
+}
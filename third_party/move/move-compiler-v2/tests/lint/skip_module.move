@@ -0,0 +1,16 @@
+#[lint::skip(while_true, self_assignment)]
+module 0x42::skip_module {
+    fun while_true(): u64 {
+        let x = 1;
+        x = x;
+        while (true) {
+            break
+        };
+        x
+    }
+
+    #[lint::skip(no_such_lint)]
+    fun unknown(x: u64): u64 {
+        copy x
+    }
+}
@@ -0,0 +1,22 @@
+module 0x42::unnecessary_copy {
+    fun last_use(x: u64): u64 {
+        copy x
+    }
+
+    fun used_later(x: u64): u64 {
+        let y = copy x;
+        x + y
+    }
+
+    fun used_in_loop(x: u64): u64 {
+        let sum = 0;
+        while (sum < 100) {
+            sum = sum + copy x;
+        };
+        sum
+    }
+
+    fun implicit(x: u64): u64 {
+        x
+    }
+}
@@ -0,0 +1,28 @@
+module 0x42::while_true {
+    fun while_true(): u64 {
+        let i = 0;
+        while (true) {
+            i = i + 1;
+            if (i > 10) break;
+        };
+        i
+    }
+
+    // Not reported: a `for` loop is desugared into a `while (true)` loop.
+    fun for_loop(): u64 {
+        let sum = 0;
+        for (i in 0..10) {
+            sum = sum + i;
+        };
+        sum
+    }
+
+    fun loop_(): u64 {
+        let i = 0;
+        loop {
+            i = i + 1;
+            if (i > 10) break;
+        };
+        i
+    }
+}
@@ -342,6 +342,19 @@ const TEST_CONFIGS: Lazy<BTreeMap<&str, TestConfig>> = Lazy::new(|| {
             dump_bytecode: DumpLevel::None,
             dump_bytecode_filter: None,
         },
+        TestConfig {
+            name: "lint",
+            runner: |p| run_test(p, get_config_by_name("lint")),
+            include: vec!["/lint/"],
+            exclude: vec![],
+            exp_suffix: None,
+            options: opts.clone().set_experiment(Experiment::LINT_CHECKS, true),
+            // Run the full compiler pipeline, as lints are checked on both AST and bytecode.
+            stop_after: StopAfter::FileFormat,
+            dump_ast: DumpLevel::None,
+            dump_bytecode: DumpLevel::None,
+            dump_bytecode_filter: None,
+        },
         // Bytecode verifier tests
         TestConfig {
             name: "bytecode-verify",
@@ -577,14 +577,35 @@ fn parse_attribute_value(context: &mut Context) -> Result<AttributeValue, Box<Di
     Ok(sp(ma.loc, AttributeValue_::ModuleAccess(ma)))
 }
 
+// Parse the name of an attribute. Only lint attributes are qualified, as in `lint::skip`
+//      AttributeName = <Identifier> | "lint" "::" <Identifier>
+fn parse_attribute_name(context: &mut Context) -> Result<Name, Box<Diagnostic>> {
+    let start_loc = context.tokens.start_loc();
+    let n = parse_identifier(context)?;
+    if n.value.as_str() != known_attributes::LintAttribute::NAMESPACE
+        || context.tokens.peek() != Tok::ColonColon
+    {
+        return Ok(n);
+    }
+    context.tokens.advance()?;
+    let member = parse_identifier(context)?;
+    let end_loc = context.tokens.previous_end_loc();
+    Ok(spanned(
+        context.tokens.file_hash(),
+        start_loc,
+        end_loc,
+        Symbol::from(format!("{}::{}", n.value, member.value)),
+    ))
+}
+
 // Parse a single attribute
 //      Attribute =
-//          <Identifier>
-//          | <Identifier> "=" <AttributeValue>
-//          | <Identifier> "(" Comma<Attribute> ")"
+//          <AttributeName>
+//          | <AttributeName> "=" <AttributeValue>
+//          | <AttributeName> "(" Comma<Attribute> ")"
 fn parse_attribute(context: &mut Context) -> Result<Attribute, Box<Diagnostic>> {
     let start_loc = context.tokens.start_loc();
-    let n = parse_identifier(context)?;
+    let n = parse_attribute_name(context)?;
     let attr_ = match context.tokens.peek() {
         Tok::Equal => {
             context.tokens.advance()?;
@@ -0,0 +1,5 @@
+module 0x42::M {
+    // Errors expecting a ']', as only lint attributes can have qualified names
+    #[foo::bar]
+    fun foo() {}
+}
@@ -585,10 +585,15 @@ pub enum StatusCode {
     GAS_PAYER_ACCOUNT_MISSING = 36,
     INSUFFICIENT_BALANCE_FOR_REQUIRED_DEPOSIT = 37,
     GAS_PARAMS_MISSING = 38,
+    // The nonce of an orderless transaction has already been used by a transaction that has not
+    // expired yet.
+    NONCE_ALREADY_USED = 39,
+    // An orderless transaction expires further in the future than the nonce history covers.
+    TRANSACTION_EXPIRATION_TOO_FAR_IN_FUTURE = 40,
+    // An orderless transaction does not carry `ORDERLESS_TRANSACTION_SEQUENCE_NUMBER` as its
+    // sequence number.
+    INVALID_ORDERLESS_SEQUENCE_NUMBER = 41,
     // Reserved error code for future use
-    RESERVED_VALIDATION_ERROR_4 = 39,
-    RESERVED_VALIDATION_ERROR_5 = 40,
-    RESERVED_VALIDATION_ERROR_6 = 41,
     RESERVED_VALIDATION_ERROR_7 = 42,
 
     // When a code module/script is published it is verified. These are the
@@ -12,8 +12,9 @@ use std::path::PathBuf;
 pub struct Build;
 
 impl Build {
-    pub fn execute(self, path: Option<PathBuf>, config: BuildConfig) -> anyhow::Result<()> {
+    pub fn execute(self, path: Option<PathBuf>, mut config: BuildConfig) -> anyhow::Result<()> {
         let rerooted_path = reroot_path(path)?;
+        config.update_lock_file = true;
         if config.fetch_deps_only {
             let mut config = config;
             if config.test_mode {
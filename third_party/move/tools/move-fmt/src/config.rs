@@ -0,0 +1,60 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use anyhow::Context;
+use move_package::source_package::manifest_parser::FMT_NAME;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// The name of the section in `Move.toml` which configures the formatter.
+pub const FMT_SECTION: &str = FMT_NAME;
+
+/// Options for formatting Move source code.
+///
+/// The options can be set in the `[fmt]` section of a package's `Move.toml`, as in
+///
+/// ```toml
+/// [fmt]
+/// indent_size = 2
+/// max_blank_lines = 1
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct FormatConfig {
+    /// The number of spaces used for one level of indentation.
+    pub indent_size: usize,
+    /// The maximal number of consecutive blank lines. Longer runs of blank lines are collapsed.
+    pub max_blank_lines: usize,
+}
+
+impl Default for FormatConfig {
+    fn default() -> Self {
+        Self {
+            indent_size: 4,
+            max_blank_lines: 1,
+        }
+    }
+}
+
+impl FormatConfig {
+    /// Reads the configuration from the `[fmt]` section of the given manifest, falling back
+    /// to the defaults if there is no such section.
+    pub fn from_manifest(manifest_path: &Path) -> anyhow::Result<Self> {
+        let contents = std::fs::read_to_string(manifest_path)
+            .with_context(|| format!("Unable to read `{}`", manifest_path.display()))?;
+        Self::from_manifest_string(&contents)
+            .with_context(|| format!("Unable to parse `{}`", manifest_path.display()))
+    }
+
+    /// Reads the configuration from the `[fmt]` section of the given manifest contents.
+    pub fn from_manifest_string(contents: &str) -> anyhow::Result<Self> {
+        let manifest = toml::from_str::<toml::Value>(contents)?;
+        match manifest.get(FMT_SECTION) {
+            Some(section) => section
+                .clone()
+                .try_into()
+                .context("Error parsing '[fmt]' section of manifest"),
+            None => Ok(Self::default()),
+        }
+    }
+}
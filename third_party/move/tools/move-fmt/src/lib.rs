@@ -0,0 +1,550 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! A formatter for Move source code.
+//!
+//! The formatter works on the tokens produced by the Move lexer and keeps the line structure
+//! of its input: it never joins or breaks lines. Within this structure, it
+//!
+//! - indents each line according to the nesting of braces, parentheses and brackets, with
+//!   one extra level for lines continuing a statement or declaration,
+//! - normalizes the whitespace between the tokens of a line,
+//! - collapses runs of blank lines, and removes trailing whitespace.
+//!
+//! Comments stay where they are. Where the spacing around a token depends on how the token
+//! is used (e.g. `<` can be a comparison or start a type argument list, `*` can be a
+//! multiplication or a dereference), the formatter keeps whether there was whitespace in
+//! the input.
+//!
+//! The input is required to parse with the Move parser, and the output is checked to consist
+//! of the same tokens and comments as the input.
+
+mod config;
+
+use anyhow::{anyhow, bail};
+pub use config::{FormatConfig, FMT_SECTION};
+use move_command_line_common::files::FileHash;
+use move_compiler::{
+    diagnostics::{report_diagnostics_to_buffer, FilesSourceText},
+    parser::{
+        lexer::{Lexer, Tok},
+        parse_file_string,
+    },
+    shared::{known_attributes::KnownAttribute, CompilationEnv, Flags},
+};
+use move_symbol_pool::Symbol;
+use std::collections::HashMap;
+
+/// Formats the given Move source code. The `file_name` is used in error messages.
+pub fn format_source(
+    file_name: &str,
+    source: &str,
+    config: &FormatConfig,
+) -> anyhow::Result<String> {
+    check_parses(file_name, source)?;
+    let tokens = tokenize(source)?;
+    let formatted = Formatter::new(source, config).format(&tokens);
+    let formatted_tokens = tokenize(&formatted)?;
+    if !tokens
+        .iter()
+        .map(|token| token.content)
+        .eq(formatted_tokens.iter().map(|token| token.content))
+    {
+        bail!(
+            "internal error: formatting `{}` did not preserve its tokens",
+            file_name
+        )
+    }
+    if comments(source, &tokens) != comments(&formatted, &formatted_tokens) {
+        bail!(
+            "internal error: formatting `{}` did not preserve its comments",
+            file_name
+        )
+    }
+    Ok(formatted)
+}
+
+/// Returns the comments of the source, each with the index of the token it precedes. The
+/// lines of the comments are trimmed, as the formatter may re-indent them.
+fn comments(source: &str, tokens: &[Token]) -> Vec<(usize, String)> {
+    let mut comments = vec![];
+    let mut pos = 0;
+    let token_starts = tokens.iter().map(|token| token.start);
+    for (index, end) in token_starts.chain([source.len()]).enumerate() {
+        for item in scan_trivia(source, pos, end) {
+            if let Trivia::Comment(_, text) = item {
+                let text = text.lines().map(str::trim).collect::<Vec<_>>().join("\n");
+                comments.push((index, text))
+            }
+        }
+        pos = tokens.get(index).map_or(end, Token::end);
+    }
+    comments
+}
+
+/// Checks that the source parses, returning the rendered diagnostics as an error otherwise.
+fn check_parses(file_name: &str, source: &str) -> anyhow::Result<()> {
+    let file_hash = FileHash::new(source);
+    let mut env = CompilationEnv::new(
+        Flags::empty(),
+        KnownAttribute::get_all_attribute_names().clone(),
+    );
+    if let Err(diags) = parse_file_string(&mut env, file_hash, source) {
+        let files: FilesSourceText =
+            HashMap::from([(file_hash, (Symbol::from(file_name), source.to_string()))]);
+        let buffer = report_diagnostics_to_buffer(&files, diags);
+        bail!(
+            "unable to parse `{}`:\n{}",
+            file_name,
+            String::from_utf8_lossy(&buffer)
+        )
+    }
+    Ok(())
+}
+
+/// A token of the source, together with its text and its start offset.
+struct Token<'a> {
+    tok: Tok,
+    content: &'a str,
+    start: usize,
+}
+
+impl<'a> Token<'a> {
+    fn end(&self) -> usize {
+        self.start + self.content.len()
+    }
+}
+
+fn tokenize(source: &str) -> anyhow::Result<Vec<Token<'_>>> {
+    let mut lexer = Lexer::new(source, FileHash::new(source));
+    let mut tokens = vec![];
+    loop {
+        lexer
+            .advance()
+            .map_err(|_| anyhow!("unable to tokenize source"))?;
+        if lexer.peek() == Tok::EOF {
+            return Ok(tokens);
+        }
+        tokens.push(Token {
+            tok: lexer.peek(),
+            content: lexer.content(),
+            start: lexer.start_loc(),
+        })
+    }
+}
+
+/// Returns true if the two tokens are still lexed as the same tokens if there is no
+/// whitespace between them.
+fn joins_safely(first: &str, second: &str) -> bool {
+    let joined = format!("{}{}", first, second);
+    tokenize(&joined).is_ok_and(|tokens| {
+        tokens.len() == 2 && tokens[0].content == first && tokens[1].content == second
+    })
+}
+
+/// An item in the text between two tokens.
+enum Trivia<'a> {
+    Newline,
+    /// A comment and its start offset in the source.
+    Comment(usize, &'a str),
+}
+
+/// Splits the text between two tokens, which only consists of whitespace and comments, into
+/// newlines and comments.
+fn scan_trivia(source: &str, start: usize, end: usize) -> Vec<Trivia<'_>> {
+    let mut items = vec![];
+    let mut pos = start;
+    while pos < end {
+        let rest = &source[pos..end];
+        if rest.starts_with("//") {
+            let len = rest.find('\n').unwrap_or(rest.len());
+            items.push(Trivia::Comment(pos, &rest[..len]));
+            pos += len;
+        } else if rest.starts_with("/*") {
+            // Block comments can be nested.
+            let mut depth = 0;
+            let mut len = 0;
+            while len < rest.len() {
+                if rest[len..].starts_with("/*") {
+                    depth += 1;
+                    len += 2;
+                } else if rest[len..].starts_with("*/") {
+                    depth -= 1;
+                    len += 2;
+                    if depth == 0 {
+                        break;
+                    }
+                } else {
+                    len += rest[len..].chars().next().map_or(1, char::len_utf8);
+                }
+            }
+            items.push(Trivia::Comment(pos, &rest[..len]));
+            pos += len;
+        } else {
+            let c = rest.chars().next().expect("non-empty text");
+            if c == '\n' {
+                items.push(Trivia::Newline)
+            }
+            pos += c.len_utf8();
+        }
+    }
+    items
+}
+
+/// How two adjacent tokens on the same line are separated.
+enum Spacing {
+    Space,
+    NoSpace,
+    /// Keep a single space if the tokens were separated by whitespace in the input.
+    Keep,
+}
+
+fn is_keyword(tok: Tok) -> bool {
+    use Tok::*;
+    matches!(
+        tok,
+        Abort
+            | Acquires
+            | As
+            | Break
+            | Continue
+            | Copy
+            | Else
+            | If
+            | Invariant
+            | Let
+            | Loop
+            | Module
+            | Move
+            | Native
+            | Public
+            | Return
+            | Spec
+            | Struct
+            | Use
+            | While
+            | Fun
+            | Script
+            | Const
+            | Friend
+            | Inline
+    )
+}
+
+/// Returns true for tokens which can only be binary operators.
+fn is_binary_op(tok: Tok) -> bool {
+    use Tok::*;
+    matches!(
+        tok,
+        Equal
+            | EqualEqual
+            | ExclaimEqual
+            | LessEqual
+            | GreaterEqual
+            | AmpAmp
+            | Plus
+            | Minus
+            | Slash
+            | Percent
+            | Caret
+            | LessLess
+            | EqualEqualGreater
+            | LessEqualEqualGreater
+    )
+}
+
+/// Returns true if a `&` following the given token must be a borrow rather than a bitwise and.
+fn is_prefix_context(prev: Option<Tok>) -> bool {
+    use Tok::*;
+    match prev {
+        None => true,
+        Some(tok) => {
+            is_binary_op(tok)
+                || is_keyword(tok)
+                || matches!(
+                    tok,
+                    LParen
+                        | LBracket
+                        | LBrace
+                        | RBrace
+                        | Comma
+                        | Semicolon
+                        | Colon
+                        | Exclaim
+                        | Amp
+                        | AmpMut
+                        | Star
+                        | Pipe
+                        | PipePipe
+                        | Less
+                )
+        },
+    }
+}
+
+/// Determines the spacing between `prev` and `next`, where `before_prev` is the token
+/// preceding `prev`.
+fn spacing(before_prev: Option<Tok>, prev: Tok, next: Tok) -> Spacing {
+    use Spacing::*;
+    use Tok::*;
+    match (prev, next) {
+        (_, Comma | Semicolon | Colon | ColonColon | Period | RParen | RBracket) => NoSpace,
+        (LParen | LBracket | ColonColon | Period | AtSign | NumSign | Exclaim, _) => NoSpace,
+        // A macro call like `assert!(..)`, and `public(friend)`.
+        (Identifier, Exclaim) | (Public, LParen) => NoSpace,
+        (Comma | Semicolon | Colon | AmpMut, _) => Space,
+        (LBrace, RBrace) => NoSpace,
+        (LBrace, _) | (_, RBrace) => Keep,
+        (RBrace, _) | (_, LBrace) => Space,
+        (Amp, _) if is_prefix_context(before_prev) => NoSpace,
+        // Besides multiplication and dereference, `*` is used as a wildcard in name patterns
+        // of specifications, as in `apply I to *foo* except bar`, where spacing matters.
+        (Amp | Star | Less | Greater | GreaterGreater | PeriodPeriod | Pipe | PipePipe, _)
+        | (_, Less | Greater | GreaterGreater | PeriodPeriod | Pipe | PipePipe) => Keep,
+        (op, _) if is_binary_op(op) => Space,
+        (_, op) if is_binary_op(op) => Space,
+        (kw, _) if is_keyword(kw) => Space,
+        (_, kw) if is_keyword(kw) => Space,
+        _ => Keep,
+    }
+}
+
+/// An open brace, parenthesis or bracket.
+struct Delimiter {
+    tok: Tok,
+    /// The indentation level of the statement or declaration in which the delimiter was
+    /// opened. The contents of the delimiter are indented one more level.
+    indent: usize,
+    /// Whether this is the bracket of an attribute, as in `#[test]`.
+    is_attribute: bool,
+}
+
+struct Formatter<'a> {
+    source: &'a str,
+    config: &'a FormatConfig,
+    out: String,
+    /// The currently open delimiters.
+    delimiters: Vec<Delimiter>,
+    /// The indentation level of the statement or declaration the current position belongs
+    /// to. Delimiters opened at the current position indent their contents relative to it.
+    base_indent: usize,
+    /// The last two tokens written, the last one first.
+    last: Option<(Tok, &'a str)>,
+    before_last: Option<Tok>,
+    /// Whether the last token closed an attribute.
+    after_attribute: bool,
+}
+
+impl<'a> Formatter<'a> {
+    fn new(source: &'a str, config: &'a FormatConfig) -> Self {
+        Self {
+            source,
+            config,
+            out: String::new(),
+            delimiters: vec![],
+            base_indent: 0,
+            last: None,
+            before_last: None,
+            after_attribute: false,
+        }
+    }
+
+    fn format(mut self, tokens: &[Token<'a>]) -> String {
+        let mut pos = 0;
+        for token in tokens {
+            let (newlines, after_comment) = self.write_trivia(pos, token.start);
+            if newlines > 0 || self.out.is_empty() {
+                let indent = self.line_indent(token.tok);
+                let continued = self.continues_line(token.tok);
+                self.start_line(newlines, indent + usize::from(continued));
+                self.base_indent = indent;
+            } else if after_comment {
+                if self.source[..token.start].ends_with(char::is_whitespace) {
+                    self.out.push(' ')
+                }
+            } else if self.space_before(token.tok, token.content, token.start > pos) {
+                self.out.push(' ')
+            }
+            // The lexer includes the whitespace following `&mut` in the token.
+            self.out.push_str(token.content.trim_end());
+            self.update_delimiters(token.tok);
+            self.before_last = self.last.map(|(tok, _)| tok);
+            self.last = Some((token.tok, token.content));
+            pos = token.end();
+        }
+        self.write_trivia(pos, self.source.len());
+        self.trim_line_end();
+        if !self.out.is_empty() {
+            self.out.push('\n')
+        }
+        self.out
+    }
+
+    /// Writes the comments in the text between `start` and `end`. Returns the number of
+    /// newlines after the last comment, and whether a comment was written on the current line.
+    fn write_trivia(&mut self, start: usize, end: usize) -> (usize, bool) {
+        let mut newlines = 0;
+        let mut after_comment = false;
+        for item in scan_trivia(self.source, start, end) {
+            match item {
+                Trivia::Newline => newlines += 1,
+                Trivia::Comment(offset, text) => {
+                    if newlines > 0 || self.out.is_empty() {
+                        let indent = self.block_indent();
+                        self.start_line(newlines, indent);
+                    } else if text.starts_with("//")
+                        || self.source[..offset].ends_with(char::is_whitespace)
+                    {
+                        self.out.push(' ')
+                    }
+                    self.write_comment(offset, text);
+                    newlines = 0;
+                    after_comment = true;
+                },
+            }
+        }
+        (newlines, after_comment)
+    }
+
+    /// Writes a comment. The lines of a multi-line comment are shifted by as many columns
+    /// as the comment itself moved.
+    fn write_comment(&mut self, offset: usize, text: &str) {
+        let column =
+            |text: &str, offset: usize| offset - text[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let old_column = column(self.source, offset);
+        let new_column = column(&self.out, self.out.len());
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                self.trim_line_end();
+                self.out.push('\n');
+                if new_column >= old_column {
+                    self.out.push_str(&" ".repeat(new_column - old_column));
+                    self.out.push_str(line)
+                } else {
+                    let shift = line
+                        .chars()
+                        .take(old_column - new_column)
+                        .take_while(|c| *c == ' ')
+                        .count();
+                    self.out.push_str(&line[shift..])
+                }
+            } else {
+                self.out.push_str(line)
+            }
+        }
+        self.trim_line_end()
+    }
+
+    /// Ends the current line, keeping at most the configured number of blank lines, and
+    /// indents the new line.
+    fn start_line(&mut self, newlines: usize, indent: usize) {
+        if !self.out.is_empty() {
+            self.trim_line_end();
+            let newlines = newlines.clamp(1, self.config.max_blank_lines + 1);
+            self.out.push_str(&"\n".repeat(newlines));
+        }
+        self.out
+            .push_str(&" ".repeat(indent * self.config.indent_size));
+    }
+
+    fn trim_line_end(&mut self) {
+        let len = self.out.trim_end_matches([' ', '\t', '\r']).len();
+        self.out.truncate(len)
+    }
+
+    /// The indentation level of lines directly inside the innermost open delimiter.
+    fn block_indent(&self) -> usize {
+        self.delimiters.last().map_or(0, |delim| delim.indent + 1)
+    }
+
+    /// The indentation level of a line starting with the given token, not counting the
+    /// extra level of a continued line.
+    fn line_indent(&self, tok: Tok) -> usize {
+        if matches!(tok, Tok::RBrace | Tok::RParen | Tok::RBracket) {
+            self.delimiters.last().map_or(0, |delim| delim.indent)
+        } else {
+            self.block_indent()
+        }
+    }
+
+    /// Returns true if a line starting with the given token continues the statement or
+    /// declaration of the previous line. This is the case for lines inside a block, or at
+    /// the top level, which do not start with a new statement or item.
+    fn continues_line(&self, tok: Tok) -> bool {
+        use Tok::*;
+        let in_block = matches!(
+            self.delimiters.last(),
+            None | Some(Delimiter { tok: LBrace, .. })
+        );
+        let after_item = match self.last {
+            Some((last, _)) => {
+                matches!(last, Semicolon | Comma | LBrace | RBrace) || self.after_attribute
+            },
+            None => true,
+        };
+        // By convention, `acquires` is aligned with the function declaration.
+        in_block && !after_item && !matches!(tok, LBrace | RBrace | RParen | RBracket | Acquires)
+    }
+
+    /// Returns true if a space should be written before the given token.
+    fn space_before(&self, tok: Tok, content: &str, had_whitespace: bool) -> bool {
+        let Some((last, last_content)) = self.last else {
+            return false;
+        };
+        match spacing(self.before_last, last, tok) {
+            Spacing::Space => true,
+            Spacing::NoSpace => had_whitespace && !joins_safely(last_content, content),
+            Spacing::Keep => had_whitespace,
+        }
+    }
+
+    fn update_delimiters(&mut self, tok: Tok) {
+        use Tok::*;
+        self.after_attribute = false;
+        match tok {
+            LBrace | LParen | LBracket => self.delimiters.push(Delimiter {
+                tok,
+                indent: self.base_indent,
+                is_attribute: tok == LBracket && matches!(self.last, Some((NumSign, _))),
+            }),
+            RBrace | RParen | RBracket => {
+                if let Some(delim) = self.delimiters.pop() {
+                    // If the delimiter was opened on a previous line, the rest of the line
+                    // belongs to the statement or declaration it was opened in, as in
+                    // `if (a &&\n b) {`.
+                    self.base_indent = self.base_indent.min(delim.indent);
+                    self.after_attribute = delim.is_attribute
+                }
+            },
+            _ => {},
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comments_are_compared() {
+        let source =
+            "module 0x42::m {\n    // a comment\n    fun f() {} /* another\n    one */\n}\n";
+        let tokens = tokenize(source).unwrap();
+        let reindented = "module 0x42::m {\n  // a comment\n  fun f() {} /* another\n  one */\n}\n";
+        let dropped = "module 0x42::m {\n    fun f() {} /* another\n    one */\n}\n";
+        let moved =
+            "module 0x42::m {\n    fun f() {} // a comment\n    /* another\n    one */\n}\n";
+        assert_eq!(
+            comments(source, &tokens),
+            comments(reindented, &tokenize(reindented).unwrap())
+        );
+        assert_ne!(
+            comments(source, &tokens),
+            comments(dropped, &tokenize(dropped).unwrap())
+        );
+        assert_ne!(
+            comments(source, &tokens),
+            comments(moved, &tokenize(moved).unwrap())
+        );
+    }
+}
@@ -0,0 +1,23 @@
+// A file header.
+
+/// A doc comment for the module.
+module 0x42::comments {   // trailing comment after brace
+        /* A block comment
+           spanning two lines. */
+    fun f(/* inline */ x: u64): u64 {
+        // A comment before a statement.
+        let y = x  +  1;    // trailing comment
+          /*
+            * A nested /* block */ comment.
+            */
+
+
+
+        y
+        // A comment before the closing brace.
+    }
+}
+
+
+
+// A comment at the end of the file.
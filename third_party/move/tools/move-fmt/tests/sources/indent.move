@@ -0,0 +1,38 @@
+address 0x42 {
+module indent {
+#[test_only]
+use std::signer;
+
+  #[test]
+fun test_something() {
+let x = 1;
+        if (x > 0) {
+    x = x +
+    1;
+      } else {
+   x = 0;
+}
+;
+}
+
+fun long_call(a: u64, b: u64): u64
+acquires R {
+    let v = vector[
+        a,
+      b,
+    ];
+    let s = S {
+    a,
+          b,
+    };
+    if (a > 0 &&
+            b > 0) {
+            a = b;
+    };
+    call(
+        a,
+  b
+    )
+}
+}
+}
@@ -0,0 +1,5 @@
+module 0x42::parse_error {
+    fun f() {
+        let x = ;
+    }
+}
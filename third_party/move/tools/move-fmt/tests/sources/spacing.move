@@ -0,0 +1,27 @@
+module 0x42::spacing {
+    use std::vector ;
+    use 0x1 :: signer;
+
+    struct S<T:copy+drop> has copy,drop{x:u64,y:T}
+
+    public ( friend ) fun add(a:u64,b:u64):u64{
+        let c=a+b*2;
+        if(c>=10){c=c-1}else{c=c+1};
+        while(c < 100) c=c<<1;
+        let lt = c <d && d  <  e;
+        assert !(c!=0,1);
+        c
+    }
+
+    fun refs(v:&mut vector<u64>,r:&u64):u64{
+        let x=*r;
+        let y = x*2;
+        let z = &mut  x;
+        *z=y;
+        vector::push_back(v,*r);
+        let s=S<u64>{x:1,y:2};
+        s.x+ * & s.y
+    }
+
+    fun cast(x:u8):u64{ (x as u64) }
+}
@@ -0,0 +1,31 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use move_fmt::{format_source, FormatConfig};
+use move_prover_test_utils::baseline_test::verify_or_update_baseline;
+use std::path::Path;
+
+fn test_runner(path: &Path) -> datatest_stable::Result<()> {
+    let source = std::fs::read_to_string(path)?;
+    let file_name = path.to_string_lossy();
+    let config = FormatConfig::default();
+    let output = match format_source(&file_name, &source, &config) {
+        Ok(formatted) => {
+            // Formatting must be idempotent.
+            let reformatted = format_source(&file_name, &formatted, &config)?;
+            if reformatted != formatted {
+                return Err(format!(
+                    "formatting is not idempotent, formatting again produces:\n{}",
+                    reformatted
+                )
+                .into());
+            }
+            formatted
+        },
+        Err(err) => format!("{:#}\n", err),
+    };
+    verify_or_update_baseline(&path.with_extension("exp"), &output)?;
+    Ok(())
+}
+
+datatest_stable::harness!(test_runner, "tests/sources", r".*\.move$");
@@ -686,6 +686,13 @@ impl CompiledPackage {
                     known_attributes: known_attributes.clone(),
                     language_version: Some(effective_language_version),
                     compile_test_code: flags.keep_testing_functions(),
+                    experiments: config.experiments.clone(),
+                    lint_levels: resolved_package
+                        .source_package
+                        .lints
+                        .iter()
+                        .map(|(name, level)| (name.to_string(), *level))
+                        .collect(),
                     ..Default::default()
                 };
                 compiler_driver_v2(options)?
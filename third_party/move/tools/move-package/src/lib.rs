@@ -14,13 +14,17 @@ use crate::{
         build_plan::BuildPlan, compiled_package::CompiledPackage, model_builder::ModelBuilder,
     },
     package_lock::PackageLock,
-    resolution::resolution_graph::{ResolutionGraph, ResolvedGraph},
-    source_package::manifest_parser,
+    resolution::{
+        lock_file::LockFile,
+        resolution_graph::{ResolutionGraph, ResolvedGraph},
+    },
+    source_package::{manifest_parser, parsed_manifest::PackageName},
 };
 use anyhow::{bail, Result};
 use clap::*;
 use move_compiler::{
-    command_line::SKIP_ATTRIBUTE_CHECKS, shared::known_attributes::KnownAttribute,
+    command_line::{DEFAULT_OUTPUT_DIR, SKIP_ATTRIBUTE_CHECKS},
+    shared::known_attributes::KnownAttribute,
 };
 use move_core_types::account_address::AccountAddress;
 use move_model::{
@@ -31,7 +35,7 @@ use serde::{Deserialize, Serialize};
 use source_package::layout::SourcePackageLayout;
 use std::{
     collections::{BTreeMap, BTreeSet},
-    fmt,
+    fmt, fs,
     io::Write,
     path::{Path, PathBuf},
 };
@@ -148,6 +152,23 @@ pub struct BuildConfig {
     #[clap(long = "skip-fetch-latest-git-deps", global = true)]
     pub skip_fetch_latest_git_deps: bool,
 
+    /// Require the lock file to be up to date, failing instead of updating it
+    #[clap(long = "locked", global = true)]
+    #[serde(default)]
+    pub locked: bool,
+
+    /// Resolve git and on-chain dependencies from the package's `vendor` directory instead of
+    /// downloading them
+    #[clap(long = "vendored", global = true)]
+    #[serde(default)]
+    pub vendored: bool,
+
+    /// Whether to write the resolved dependencies to the lock file. Only set by commands which
+    /// build the package or update its dependencies, other tools never modify the lock file.
+    #[clap(skip)]
+    #[serde(skip)]
+    pub update_lock_file: bool,
+
     #[clap(flatten)]
     pub compiler_config: CompilerConfig,
 }
@@ -175,6 +196,12 @@ pub struct CompilerConfig {
     #[clap(long = "language-version", global = true,
            value_parser = clap::value_parser!(LanguageVersion))]
     pub language_version: Option<LanguageVersion>,
+
+    /// Experiments for compiler v2, as in `name[=on/off]`. These are set per invocation
+    /// (e.g., to run the lint checks), so they are not recorded in the build info.
+    #[clap(skip)]
+    #[serde(skip)]
+    pub experiments: Vec<String>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, PartialOrd)]
@@ -276,13 +303,66 @@ impl BuildConfig {
         // possibly be set by a different process in parallel.
         let manifest = manifest_parser::parse_source_manifest(toml_manifest)?;
         let resolution_graph = ResolutionGraph::new(manifest, path, self, writer)?;
-        let ret = resolution_graph.resolve();
+        let ret = resolution_graph.resolve().and_then(|resolved_graph| {
+            LockFile::check_and_update(&resolved_graph)?;
+            Ok(resolved_graph)
+        });
         mutx.unlock();
         ret
     }
 
+    /// Copies the git and on-chain dependencies of the package at `path` into the `vendor`
+    /// directory of the package, from which they are resolved if `vendored` is set. Returns the
+    /// names of the vendored packages.
+    pub fn vendor_dependencies<W: Write>(
+        mut self,
+        path: &Path,
+        writer: &mut W,
+    ) -> Result<Vec<PackageName>> {
+        self.vendored = false;
+        self.update_lock_file = true;
+        let resolved_graph = self.resolution_graph_for_package(path, writer)?;
+        let vendor_dir = resolved_graph
+            .root_package_path
+            .join(SourcePackageLayout::Vendor.path());
+        let mut vendored = vec![];
+        for (name, package) in &resolved_graph.package_table {
+            if !package.source.is_remote() {
+                continue;
+            }
+            let target = vendor_dir.join(name.as_str());
+            if target.exists() {
+                fs::remove_dir_all(&target)?;
+            }
+            copy_package(&package.package_path, &target)?;
+            vendored.push(*name);
+        }
+        Ok(vendored)
+    }
+
     fn parse_toml_manifest(&self, path: PathBuf) -> Result<toml::Value> {
         let manifest_string = std::fs::read_to_string(path)?;
         manifest_parser::parse_move_manifest_string(manifest_string)
     }
 }
+
+/// Copies a package directory, skipping its build output and hidden files.
+fn copy_package(from: &Path, to: &Path) -> Result<()> {
+    let entries = walkdir::WalkDir::new(from)
+        .into_iter()
+        .filter_entry(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            entry.depth() == 0
+                || !(name.starts_with('.') || entry.depth() == 1 && name == DEFAULT_OUTPUT_DIR)
+        });
+    for entry in entries {
+        let entry = entry?;
+        let target = to.join(entry.path().strip_prefix(from)?);
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target)?;
+        } else {
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,346 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+//! The lock file (`Move.lock`) of a package records where each package in its dependency graph
+//! was resolved from. For dependencies which are downloaded (from git or an on-chain package
+//! registry), it also records the git revision they were resolved to and the digest of their
+//! sources, so that a later build can detect dependencies which moved or were tampered with.
+
+use crate::{
+    resolution::{digest::compute_digest, resolution_graph::ResolvedGraph},
+    source_package::{
+        layout::SourcePackageLayout,
+        parsed_manifest::{Dependency, PackageName},
+    },
+};
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Component, Path, PathBuf},
+    process::Command,
+};
+
+/// The version of the lock file format written by this package system.
+pub const LOCK_FILE_VERSION: u64 = 1;
+
+const LOCK_FILE_HEADER: &str =
+    "# This file is generated by the Move package system. It is not intended for manual editing.\n";
+
+/// Where the sources of a package come from.
+#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum PackageSource {
+    /// A package on the local file system, at a path relative to the root package
+    Local { path: String },
+    /// A package in a git repository, in a sub-directory of the repository
+    Git {
+        url: String,
+        rev: String,
+        subdir: String,
+    },
+    /// A package published on chain, downloaded from the package registry at the address
+    Registry {
+        node_url: String,
+        address: String,
+        package_name: String,
+    },
+}
+
+/// The lock file entry for a package in the dependency graph.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LockedPackage {
+    pub name: String,
+    /// The git commit the package was resolved to, if it comes from git
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+    /// The digest of the sources and manifest of the package, if it is downloaded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub digest: Option<String>,
+    pub source: PackageSource,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct LockFile {
+    pub version: u64,
+    #[serde(default)]
+    pub package: Vec<LockedPackage>,
+}
+
+impl PackageSource {
+    /// The source of the root package.
+    pub fn root() -> Self {
+        Self::Local {
+            path: ".".to_string(),
+        }
+    }
+
+    /// Returns whether packages from this source are downloaded.
+    pub fn is_remote(&self) -> bool {
+        !matches!(self, Self::Local { .. })
+    }
+
+    /// Returns the source of a dependency of a package from this source. A local dependency of a
+    /// package in a git repository comes from the same repository.
+    pub fn of_dependency(&self, dep: &Dependency) -> Self {
+        if let Some(git_info) = &dep.git_info {
+            return Self::Git {
+                url: git_info.git_url.to_string(),
+                rev: git_info.git_rev.to_string(),
+                subdir: normalize_path(&git_info.subdir),
+            };
+        }
+        if let Some(node_info) = &dep.node_info {
+            return Self::Registry {
+                node_url: node_info.node_url.to_string(),
+                address: node_info.package_address.to_string(),
+                package_name: node_info.package_name.to_string(),
+            };
+        }
+        match self {
+            Self::Local { path } => Self::Local {
+                path: normalize_path(&Path::new(path).join(&dep.local)),
+            },
+            Self::Git { url, rev, subdir } => Self::Git {
+                url: url.clone(),
+                rev: rev.clone(),
+                subdir: normalize_path(&Path::new(subdir).join(&dep.local)),
+            },
+            Self::Registry { .. } => Self::Local {
+                path: normalize_path(&dep.local),
+            },
+        }
+    }
+}
+
+impl LockedPackage {
+    /// Creates the lock file entry for a resolved package at the given path. The revision of a
+    /// git package is only looked up if `with_revision` is set, as vendored packages are not in
+    /// a checkout of their repository.
+    fn new(
+        name: PackageName,
+        source: PackageSource,
+        package_path: &Path,
+        with_revision: bool,
+    ) -> Result<Self> {
+        let (revision, digest) = if source.is_remote() {
+            let revision = match &source {
+                PackageSource::Git { .. } if with_revision => Some(git_revision(package_path)?),
+                _ => None,
+            };
+            (revision, Some(published_digest(package_path)?))
+        } else {
+            (None, None)
+        };
+        Ok(Self {
+            name: name.to_string(),
+            revision,
+            digest,
+            source,
+        })
+    }
+}
+
+impl LockFile {
+    /// Reads the lock file of the package at `root_path`, if there is one.
+    pub fn read(root_path: &Path) -> Result<Option<Self>> {
+        let path = root_path.join(SourcePackageLayout::LockFile.path());
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let contents = fs::read_to_string(&path)?;
+        let lock_file: Self = toml::from_str(&contents)
+            .with_context(|| format!("Unable to parse lock file {}", path.display()))?;
+        if lock_file.version > LOCK_FILE_VERSION {
+            bail!(
+                "Lock file {} has version {}, but only versions up to {} are supported",
+                path.display(),
+                lock_file.version,
+                LOCK_FILE_VERSION
+            )
+        }
+        Ok(Some(lock_file))
+    }
+
+    /// Writes the lock file into the package at `root_path`, if its contents changed.
+    pub fn write(&self, root_path: &Path) -> Result<()> {
+        let path = root_path.join(SourcePackageLayout::LockFile.path());
+        let contents = format!("{}{}", LOCK_FILE_HEADER, toml::to_string(self)?);
+        if fs::read_to_string(&path).ok().as_deref() != Some(contents.as_str()) {
+            fs::write(&path, contents)?;
+        }
+        Ok(())
+    }
+
+    /// Checks the resolved graph against the lock file of the root package and, if
+    /// `update_lock_file` is set in the build options, updates the lock file. The lock file is
+    /// only created if the root package has remote dependencies.
+    ///
+    /// A remote package with the same revision as recorded in the lock file but a different
+    /// digest is always an error. If `locked` is set in the build options, any other difference
+    /// between the lock file and the resolved graph is an error as well.
+    pub fn check_and_update(graph: &ResolvedGraph) -> Result<()> {
+        let root_path = &graph.root_package_path;
+        let root_name = graph.root_package.package.name;
+        let build_options = &graph.build_options;
+        let resolved = graph
+            .package_table
+            .iter()
+            .filter(|(name, _)| **name != root_name)
+            .map(|(name, package)| {
+                LockedPackage::new(
+                    *name,
+                    package.source.clone(),
+                    &package.package_path,
+                    !build_options.vendored,
+                )
+                .with_context(|| format!("Unable to lock package '{}'", name))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let old = Self::read(root_path)?;
+        if old.is_none() && !resolved.iter().any(|pkg| pkg.source.is_remote()) {
+            return Ok(());
+        }
+        let old = old.unwrap_or(Self {
+            version: LOCK_FILE_VERSION,
+            package: vec![],
+        });
+        // Only in dev mode all dependencies are resolved, so packages which are not resolved
+        // can only be removed from the lock file then.
+        let new = old.update(resolved, build_options.dev_mode, build_options.locked)?;
+        if build_options.update_lock_file {
+            new.write(root_path)?;
+        }
+        Ok(())
+    }
+
+    /// Merges the resolved packages into the lock file.
+    fn update(&self, resolved: Vec<LockedPackage>, prune: bool, locked: bool) -> Result<Self> {
+        let mut old_packages = self
+            .package
+            .iter()
+            .map(|pkg| (pkg.name.as_str(), pkg))
+            .collect::<BTreeMap<_, _>>();
+        let mut packages = vec![];
+        let mut changes = vec![];
+        for mut pkg in resolved {
+            match old_packages.remove(pkg.name.as_str()) {
+                Some(old) if old.source == pkg.source => {
+                    // The revision of a vendored package is not known and stays as locked
+                    if pkg.revision.is_none() {
+                        pkg.revision = old.revision.clone();
+                    }
+                    if old.revision != pkg.revision {
+                        changes.push(format!(
+                            "package '{}' moved from revision {} to {}",
+                            pkg.name,
+                            old.revision.as_deref().unwrap_or("none"),
+                            pkg.revision.as_deref().unwrap_or("none")
+                        ));
+                    } else if old.digest != pkg.digest {
+                        if let Some(revision) = &pkg.revision {
+                            bail!(
+                                "Source digest mismatch in package '{}' at revision {}. The lock \
+                                 file expects '{}' but got '{}'",
+                                pkg.name,
+                                revision,
+                                old.digest.as_deref().unwrap_or("none"),
+                                pkg.digest.as_deref().unwrap_or("none")
+                            )
+                        }
+                        changes.push(format!(
+                            "source digest of package '{}' changed from '{}' to '{}'",
+                            pkg.name,
+                            old.digest.as_deref().unwrap_or("none"),
+                            pkg.digest.as_deref().unwrap_or("none")
+                        ));
+                    }
+                },
+                Some(_) => changes.push(format!("source of package '{}' changed", pkg.name)),
+                None => changes.push(format!("package '{}' is not locked", pkg.name)),
+            }
+            packages.push(pkg);
+        }
+        for (name, old) in old_packages {
+            if prune {
+                changes.push(format!("package '{}' is no longer a dependency", name));
+            } else {
+                packages.push(old.clone());
+            }
+        }
+        if locked && !changes.is_empty() {
+            bail!(
+                "The lock file {} needs to be updated but `--locked` was passed:\n{}",
+                SourcePackageLayout::LockFile.location_str(),
+                changes.join("\n")
+            )
+        }
+        packages.sort_by(|p1, p2| p1.name.cmp(&p2.name));
+        Ok(Self {
+            version: LOCK_FILE_VERSION,
+            package: packages,
+        })
+    }
+}
+
+/// Computes the digest of the files of a package which are published: its sources, its scripts
+/// and its manifest. This is the source digest of the package when built outside of dev mode,
+/// and the digest stored in the on-chain package registry.
+pub fn published_digest(package_path: &Path) -> Result<String> {
+    let mut paths = vec![];
+    for layout in [SourcePackageLayout::Sources, SourcePackageLayout::Scripts] {
+        let path = package_path.join(layout.path());
+        if path.exists() {
+            paths.push(path);
+        }
+    }
+    paths.push(package_path.join(SourcePackageLayout::Manifest.path()));
+    Ok(compute_digest(&paths)?.to_string())
+}
+
+/// Returns the commit checked out in the git repository containing the path.
+fn git_revision(path: &Path) -> Result<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(path)
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .with_context(|| format!("Failed to run git in {}", path.display()))?;
+    if !output.status.success() {
+        bail!(
+            "Failed to find the checked out git revision in {}",
+            path.display()
+        )
+    }
+    Ok(String::from_utf8(output.stdout)?.trim().to_string())
+}
+
+/// Normalizes a path lexically, removing `.` components and resolving `..` components where
+/// possible, and renders it with `/` as the separator.
+fn normalize_path(path: &Path) -> String {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => (),
+            Component::ParentDir
+                if matches!(normalized.components().last(), Some(Component::Normal(_))) =>
+            {
+                normalized.pop();
+            },
+            _ => normalized.push(component),
+        }
+    }
+    let components = normalized
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+    if components.is_empty() {
+        ".".to_string()
+    } else if normalized.has_root() && components[0] == "/" {
+        format!("/{}", components[1..].join("/"))
+    } else {
+        components.join("/")
+    }
+}
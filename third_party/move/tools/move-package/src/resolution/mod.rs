@@ -2,5 +2,6 @@
 // Copyright (c) The Move Contributors
 // SPDX-License-Identifier: Apache-2.0
 
-mod digest;
+pub mod digest;
+pub mod lock_file;
 pub mod resolution_graph;
@@ -4,7 +4,7 @@
 
 use crate::{
     package_hooks,
-    resolution::digest::compute_digest,
+    resolution::{digest::compute_digest, lock_file::PackageSource},
     source_package::{
         layout::SourcePackageLayout,
         manifest_parser::{parse_move_manifest_string, parse_source_manifest},
@@ -85,6 +85,8 @@ pub struct ResolutionPackage<T> {
     pub source_package: SourceManifest,
     /// Where this package is located on the filesystem
     pub package_path: PathBuf,
+    /// Where the sources of this package come from
+    pub source: PackageSource,
     /// The renaming of addresses performed by this package
     pub renaming: Renaming,
     /// The mapping of addresses for this package (and that are in scope for it)
@@ -114,7 +116,13 @@ impl ResolvingGraph {
         };
 
         resolution_graph
-            .build_resolution_graph(root_package.clone(), root_package_path, true, writer)
+            .build_resolution_graph(
+                root_package.clone(),
+                root_package_path,
+                PackageSource::root(),
+                true,
+                writer,
+            )
             .with_context(|| {
                 format!(
                     "Unable to resolve packages for package '{}'",
@@ -142,6 +150,7 @@ impl ResolvingGraph {
                     resolution_graph_index,
                     source_package,
                     package_path,
+                    source,
                     renaming,
                     resolution_table,
                     source_digest,
@@ -166,6 +175,7 @@ impl ResolvingGraph {
                     resolution_graph_index,
                     source_package,
                     package_path,
+                    source,
                     renaming,
                     resolution_table: resolved_table,
                     source_digest,
@@ -198,6 +208,7 @@ impl ResolvingGraph {
         &mut self,
         package: SourceManifest,
         package_path: PathBuf,
+        source: PackageSource,
         is_root_package: bool,
         writer: &mut W,
     ) -> Result<()> {
@@ -252,7 +263,7 @@ impl ResolvingGraph {
             self.graph.add_edge(package_node_id, dep_node_id, ());
 
             let (dep_renaming, dep_resolution_table) = self
-                .process_dependency(dep_name, dep, package_path.clone(), writer)
+                .process_dependency(dep_name, dep, package_path.clone(), &source, writer)
                 .with_context(|| {
                     format!(
                         "While resolving dependency '{}' in package '{}'",
@@ -291,6 +302,7 @@ impl ResolvingGraph {
             resolution_graph_index: package_node_id,
             source_package: package,
             package_path,
+            source,
             renaming,
             resolution_table,
             source_digest,
@@ -391,10 +403,22 @@ impl ResolvingGraph {
     fn process_dependency<W: Write>(
         &mut self,
         dep_name_in_pkg: PackageName,
-        dep: Dependency,
+        mut dep: Dependency,
         root_path: PathBuf,
+        package_source: &PackageSource,
         writer: &mut W,
     ) -> Result<(Renaming, ResolvingTable)> {
+        let dep_source = package_source.of_dependency(&dep);
+        if self.build_options.vendored && dep_source.is_remote() {
+            // Vendored packages are resolved from the vendor directory of the root package,
+            // which is laid out by package name
+            dep.local = self
+                .root_package_path
+                .join(SourcePackageLayout::Vendor.path())
+                .join(dep_name_in_pkg.as_str());
+            dep.git_info = None;
+            dep.node_info = None;
+        }
         Self::download_and_update_if_remote(
             dep_name_in_pkg,
             &dep,
@@ -404,10 +428,14 @@ impl ResolvingGraph {
         let (dep_package, dep_package_dir) =
             Self::parse_package_manifest(&dep, &dep_name_in_pkg, root_path)
                 .with_context(|| format!("While processing dependency '{}'", dep_name_in_pkg))?;
-        self.build_resolution_graph(dep_package.clone(), dep_package_dir, false, writer)
-            .with_context(|| {
-                format!("Unable to resolve package dependency '{}'", dep_name_in_pkg)
-            })?;
+        self.build_resolution_graph(
+            dep_package.clone(),
+            dep_package_dir,
+            dep_source,
+            false,
+            writer,
+        )
+        .with_context(|| format!("Unable to resolve package dependency '{}'", dep_name_in_pkg))?;
 
         if dep_name_in_pkg != dep_package.package.name {
             bail!("Name of dependency declared in package '{}' does not match dependency's package name '{}'",
@@ -529,6 +557,10 @@ impl ResolvingGraph {
         root_path: &Path,
         writer: &mut W,
     ) -> Result<()> {
+        // vendored dependencies are never downloaded
+        if build_options.vendored {
+            return Ok(());
+        }
         // include dev dependencies if in dev mode
         let empty_deps;
         let additional_deps = if build_options.dev_mode {
@@ -17,6 +17,8 @@ pub enum SourcePackageLayout {
     Examples,
     Manifest,
     DocTemplates,
+    LockFile,
+    Vendor,
 }
 
 impl SourcePackageLayout {
@@ -28,7 +30,9 @@ impl SourcePackageLayout {
     /// ├── scripts        (optional)
     /// ├── specifications (optional)
     /// ├── doc_templates      (optional)
-    /// └── tests          (optional, test mode)
+    /// ├── tests          (optional, test mode)
+    /// ├── Move.lock      (optional, generated)
+    /// └── vendor         (optional, vendored dependencies)
     pub fn path(&self) -> &Path {
         Path::new(self.location_str())
     }
@@ -57,6 +61,8 @@ impl SourcePackageLayout {
             Self::Examples => "examples",
             Self::Specifications => "specifications",
             Self::DocTemplates => "doc_templates",
+            Self::LockFile => "Move.lock",
+            Self::Vendor => "vendor",
         }
     }
 
@@ -67,7 +73,9 @@ impl SourcePackageLayout {
             | Self::Scripts
             | Self::Examples
             | Self::Specifications
-            | Self::DocTemplates => true,
+            | Self::DocTemplates
+            | Self::LockFile
+            | Self::Vendor => true,
         }
     }
 }
@@ -0,0 +1,187 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use move_package::{
+    resolution::lock_file::{LockFile, PackageSource},
+    BuildConfig,
+};
+use once_cell::sync::Lazy;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+use tempfile::{tempdir, TempDir};
+
+/// Git dependencies are downloaded into `MOVE_HOME`, which is read once per process
+static MOVE_HOME: Lazy<TempDir> = Lazy::new(|| {
+    let dir = tempdir().unwrap();
+    std::env::set_var("MOVE_HOME", dir.path());
+    dir
+});
+
+fn write_package(path: &Path, name: &str, deps: &str, source: &str) {
+    fs::create_dir_all(path.join("sources")).unwrap();
+    fs::write(
+        path.join("Move.toml"),
+        format!(
+            "[package]\nname = \"{}\"\nversion = \"0.0.0\"\n\n[dependencies]\n{}",
+            name, deps
+        ),
+    )
+    .unwrap();
+    fs::write(path.join("sources").join("m.move"), source).unwrap();
+}
+
+fn git(repo: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo)
+        .args(["-c", "user.name=test", "-c", "user.email=test@test"])
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "git {:?} failed", args);
+    String::from_utf8(output.stdout).unwrap().trim().to_string()
+}
+
+/// Creates a git repository with a package `Dep` and a package `Root` depending on it.
+fn setup() -> (TempDir, PathBuf, PathBuf) {
+    Lazy::force(&MOVE_HOME);
+    let dir = tempdir().unwrap();
+    let repo = dir.path().join("repo");
+    write_package(&repo, "Dep", "", "module 0x1::dep { public fun f() {} }");
+    git(&repo, &["init", "-q", "-b", "main"]);
+    git(&repo, &["add", "-A"]);
+    git(&repo, &["commit", "-q", "-m", "init"]);
+
+    let root = dir.path().join("root");
+    write_package(
+        &root,
+        "Root",
+        &format!("Dep = {{ git = \"{}\", rev = \"main\" }}\n", repo.display()),
+        "module 0x1::root { public fun g() { 0x1::dep::f() } }",
+    );
+    (dir, repo, root)
+}
+
+/// Resolves the dependencies of the package as a build does, updating the lock file.
+fn resolve(root: &Path, locked: bool, vendored: bool) -> anyhow::Result<()> {
+    BuildConfig {
+        locked,
+        vendored,
+        update_lock_file: true,
+        install_dir: Some(tempdir().unwrap().path().to_path_buf()),
+        ..Default::default()
+    }
+    .resolution_graph_for_package(root, &mut Vec::new())
+    .map(|_| ())
+}
+
+#[test]
+fn lock_file_records_and_verifies_git_dependencies() {
+    let (_dir, repo, root) = setup();
+    // Without a lock file, a locked build fails
+    assert!(resolve(&root, true, false).is_err());
+
+    resolve(&root, false, false).unwrap();
+    let lock = LockFile::read(&root).unwrap().unwrap();
+    assert_eq!(lock.package.len(), 1);
+    let dep = &lock.package[0];
+    assert_eq!(dep.name, "Dep");
+    assert_eq!(
+        dep.revision.as_deref(),
+        Some(git(&repo, &["rev-parse", "HEAD"]).as_str())
+    );
+    assert!(dep.digest.is_some());
+    assert!(matches!(&dep.source, PackageSource::Git { rev, .. } if rev == "main"));
+
+    // The lock file is up to date
+    resolve(&root, true, false).unwrap();
+
+    // A new commit on the branch is picked up, but not in a locked build
+    fs::write(
+        repo.join("sources").join("m.move"),
+        "module 0x1::dep { public fun f() {} public fun h() {} }",
+    )
+    .unwrap();
+    git(&repo, &["commit", "-q", "-a", "-m", "update"]);
+    let err = resolve(&root, true, false).unwrap_err();
+    assert!(format!("{:#}", err).contains("moved from revision"));
+    assert_eq!(LockFile::read(&root).unwrap().unwrap(), lock);
+    resolve(&root, false, false).unwrap();
+    let updated = LockFile::read(&root).unwrap().unwrap();
+    assert_ne!(updated.package[0].revision, dep.revision);
+    assert_ne!(updated.package[0].digest, dep.digest);
+
+    // Modifying the downloaded sources without changing the revision is always an error
+    let head = git(&repo, &["rev-parse", "HEAD"]);
+    let checkout = fs::read_dir(MOVE_HOME.path())
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .find(|path| {
+            Command::new("git")
+                .arg("-C")
+                .arg(path)
+                .args(["rev-parse", "HEAD"])
+                .output()
+                .is_ok_and(|output| String::from_utf8_lossy(&output.stdout).trim() == head)
+        })
+        .unwrap();
+    fs::write(
+        checkout.join("sources").join("m.move"),
+        "module 0x1::dep {}",
+    )
+    .unwrap();
+    let err = BuildConfig {
+        skip_fetch_latest_git_deps: true,
+        ..Default::default()
+    }
+    .resolution_graph_for_package(&root, &mut Vec::new())
+    .unwrap_err();
+    assert!(format!("{:#}", err).contains("Source digest mismatch in package 'Dep'"));
+}
+
+#[test]
+fn lock_file_is_only_written_by_builds() {
+    let (_dir, _repo, root) = setup();
+    BuildConfig {
+        install_dir: Some(tempdir().unwrap().path().to_path_buf()),
+        ..Default::default()
+    }
+    .resolution_graph_for_package(&root, &mut Vec::new())
+    .unwrap();
+    assert!(LockFile::read(&root).unwrap().is_none());
+
+    resolve(&root, false, false).unwrap();
+    assert!(LockFile::read(&root).unwrap().is_some());
+}
+
+#[test]
+fn vendored_dependencies_are_verified_against_lock_file() {
+    let (_dir, repo, root) = setup();
+    let vendored = BuildConfig::default()
+        .vendor_dependencies(&root, &mut Vec::new())
+        .unwrap();
+    assert_eq!(vendored.len(), 1);
+    assert!(root.join("vendor/Dep/sources/m.move").is_file());
+    assert!(!root.join("vendor/Dep/.git").exists());
+    let lock = LockFile::read(&root).unwrap().unwrap();
+
+    // Vendored builds do not need the repository
+    fs::remove_dir_all(&repo).unwrap();
+    resolve(&root, true, true).unwrap();
+    assert_eq!(LockFile::read(&root).unwrap().unwrap(), lock);
+
+    // Vendored sources which differ from the locked ones are rejected
+    fs::write(root.join("vendor/Dep/sources/m.move"), "module 0x1::dep {}").unwrap();
+    let err = resolve(&root, false, true).unwrap_err();
+    assert!(format!("{:#}", err).contains("Source digest mismatch in package 'Dep'"));
+}
+
+#[test]
+fn no_lock_file_for_local_dependencies() {
+    let path = Path::new("tests/test_sources/resolution/dep_good_digest");
+    resolve(path, false, false).unwrap();
+    assert!(LockFile::read(path).unwrap().is_none());
+}
@@ -7,7 +7,7 @@ pub mod extensions;
 pub mod test_reporter;
 pub mod test_runner;
 
-use crate::test_runner::TestRunner;
+use crate::test_runner::{TestExecutionHook, TestRunner};
 use clap::*;
 use move_command_line_common::files::verify_and_create_named_address_mapping;
 use move_compiler::{
@@ -107,6 +107,10 @@ pub struct UnitTestingConfig {
     #[clap(short = 'v', long = "verbose")]
     pub verbose: bool,
 
+    /// Executes the test functions in place of the test runner, e.g. to profile their gas usage
+    #[clap(skip)]
+    pub execution_hook: Option<TestExecutionHook>,
+
     /// Use the EVM-based execution backend.
     /// Does not work with --stackless.
     #[cfg(feature = "evm-backend")]
@@ -139,6 +143,7 @@ impl UnitTestingConfig {
             verbose: false,
             list: false,
             named_address_values: vec![],
+            execution_hook: None,
 
             #[cfg(feature = "evm-backend")]
             evm: false,
@@ -246,6 +251,7 @@ impl UnitTestingConfig {
             genesis_state,
             cost_table,
             self.verbose,
+            self.execution_hook.clone(),
             #[cfg(feature = "evm-backend")]
             self.evm,
         )
@@ -17,20 +17,29 @@ use move_core_types::{
     account_address::AccountAddress,
     effects::{ChangeSet, Op},
     identifier::IdentStr,
+    language_storage::ModuleId,
     value::serialize_values,
     vm_status::StatusCode,
 };
 use move_resource_viewer::MoveValueAnnotator;
 use move_vm_runtime::{
-    move_vm::MoveVM, native_extensions::NativeContextExtensions,
+    move_vm::MoveVM,
+    native_extensions::NativeContextExtensions,
     native_functions::NativeFunctionTable,
+    session::{SerializedReturnValues, Session},
 };
 use move_vm_test_utils::{
     gas_schedule::{zero_cost_schedule, CostTable, Gas, GasCost, GasStatus},
     InMemoryStorage,
 };
 use rayon::prelude::*;
-use std::{io::Write, marker::Send, sync::Mutex, time::Instant};
+use std::{
+    fmt,
+    io::Write,
+    marker::Send,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
 #[cfg(feature = "evm-backend")]
 use {
     evm::{backend::MemoryVicinity, ExitReason},
@@ -52,6 +61,7 @@ pub struct SharedTestingConfig {
     #[allow(dead_code)] // used by some features
     source_files: Vec<String>,
     record_writeset: bool,
+    execution_hook: Option<TestExecutionHook>,
 
     #[cfg(feature = "evm-backend")]
     evm: bool,
@@ -63,6 +73,51 @@ pub struct TestRunner {
     tests: TestPlan,
 }
 
+/// A hook which executes test functions in place of the test runner.
+///
+/// The hook is given the session to execute a test function in, along with the function and its
+/// serialized arguments. It returns the result of the function and the amount of gas it used,
+/// which is reported in the test statistics in place of the number of instructions executed.
+///
+/// This can be used to run tests with a custom gas meter, e.g. to charge them according to a real
+/// gas schedule and profile their gas usage. The execution bound and cost table of the test
+/// runner are not used when a hook is set.
+#[derive(Clone)]
+pub struct TestExecutionHook(
+    Arc<
+        dyn Fn(
+                &mut Session,
+                &ModuleId,
+                &IdentStr,
+                Vec<Vec<u8>>,
+            ) -> (VMResult<SerializedReturnValues>, u64)
+            + Send
+            + Sync,
+    >,
+);
+
+impl TestExecutionHook {
+    pub fn new(
+        hook: impl Fn(
+                &mut Session,
+                &ModuleId,
+                &IdentStr,
+                Vec<Vec<u8>>,
+            ) -> (VMResult<SerializedReturnValues>, u64)
+            + Send
+            + Sync
+            + 'static,
+    ) -> Self {
+        Self(Arc::new(hook))
+    }
+}
+
+impl fmt::Debug for TestExecutionHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TestExecutionHook")
+    }
+}
+
 /// A gas schedule where every instruction has a cost of "1". This is used to bound execution of a
 /// test to a certain number of ticks.
 fn unit_cost_table() -> CostTable {
@@ -133,6 +188,7 @@ impl TestRunner {
         genesis_state: Option<ChangeSet>,
         cost_table: Option<CostTable>,
         record_writeset: bool,
+        execution_hook: Option<TestExecutionHook>,
         #[cfg(feature = "evm-backend")] evm: bool,
     ) -> Result<Self> {
         let source_files = tests
@@ -166,6 +222,7 @@ impl TestRunner {
                 cost_table: cost_table.unwrap_or_else(unit_cost_table),
                 source_files,
                 record_writeset,
+                execution_hook,
                 #[cfg(feature = "evm-backend")]
                 evm,
             },
@@ -267,17 +324,32 @@ impl SharedTestingConfig {
         let extensions = extensions::new_extensions();
         let mut session =
             move_vm.new_session_with_extensions(&self.starting_storage_state, extensions);
-        let mut gas_meter = GasStatus::new(&self.cost_table, Gas::new(self.execution_bound));
+        let function_name = IdentStr::new(function_name).unwrap();
+        let args = serialize_values(test_info.arguments.iter());
         // TODO: collect VM logs if the verbose flag (i.e, `self.verbose`) is set
 
         let now = Instant::now();
-        let serialized_return_values_result = session.execute_function_bypass_visibility(
-            &test_plan.module_id,
-            IdentStr::new(function_name).unwrap(),
-            vec![], // no ty args, at least for now
-            serialize_values(test_info.arguments.iter()),
-            &mut gas_meter,
-        );
+        let (serialized_return_values_result, instructions_executed) = match &self.execution_hook {
+            Some(hook) => (hook.0)(&mut session, &test_plan.module_id, function_name, args),
+            None => {
+                let mut gas_meter =
+                    GasStatus::new(&self.cost_table, Gas::new(self.execution_bound));
+                let result = session.execute_function_bypass_visibility(
+                    &test_plan.module_id,
+                    function_name,
+                    vec![], // no ty args, at least for now
+                    args,
+                    &mut gas_meter,
+                );
+                // TODO(Gas): This doesn't look quite right...
+                //            We're not computing the number of instructions executed even with a unit gas schedule.
+                let instructions_executed = Gas::new(self.execution_bound)
+                    .checked_sub(gas_meter.remaining_gas())
+                    .unwrap()
+                    .into();
+                (result, instructions_executed)
+            },
+        };
         let mut return_result = serialized_return_values_result.map(|res| {
             res.return_values
                 .into_iter()
@@ -292,12 +364,7 @@ impl SharedTestingConfig {
         let test_run_info = TestRunInfo::new(
             function_name.to_string(),
             now.elapsed(),
-            // TODO(Gas): This doesn't look quite right...
-            //            We're not computing the number of instructions executed even with a unit gas schedule.
-            Gas::new(self.execution_bound)
-                .checked_sub(gas_meter.remaining_gas())
-                .unwrap()
-                .into(),
+            instructions_executed,
         );
         match session.finish_with_extensions() {
             Ok((cs, extensions)) => (Ok(cs), Ok(extensions), return_result, test_run_info),
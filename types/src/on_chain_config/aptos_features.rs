@@ -70,6 +70,10 @@ pub enum FeatureFlag {
     DELEGATION_POOL_ALLOWLISTING = 56,
     MODULE_EVENT_MIGRATION = 57,
     REJECT_UNSTABLE_BYTECODE = 58,
+    SECP256R1_ECDSA_SIGNATURE = 59,
+    SECP256R1_NATIVES = 60,
+    POSEIDON_BN254_NATIVES = 61,
+    ORDERLESS_TRANSACTIONS = 62,
 }
 
 impl FeatureFlag {
@@ -126,6 +130,10 @@ impl FeatureFlag {
             FeatureFlag::DELEGATION_POOL_ALLOWLISTING,
             FeatureFlag::MODULE_EVENT_MIGRATION,
             FeatureFlag::REJECT_UNSTABLE_BYTECODE,
+            FeatureFlag::SECP256R1_ECDSA_SIGNATURE,
+            FeatureFlag::SECP256R1_NATIVES,
+            FeatureFlag::POSEIDON_BN254_NATIVES,
+            FeatureFlag::ORDERLESS_TRANSACTIONS,
         ]
     }
 }
@@ -263,6 +271,10 @@ impl Features {
     pub fn is_refundable_bytes_enabled(&self) -> bool {
         self.is_enabled(FeatureFlag::REFUNDABLE_BYTES)
     }
+
+    pub fn is_orderless_transactions_enabled(&self) -> bool {
+        self.is_enabled(FeatureFlag::ORDERLESS_TRANSACTIONS)
+    }
 }
 
 pub fn aptos_test_feature_flags_genesis() -> ChangeSet {
@@ -162,6 +162,12 @@ pub enum OnChainConsensusConfig {
         alg: ConsensusAlgorithmConfig,
         vtxn: ValidatorTxnConfig,
     },
+    V4 {
+        alg: ConsensusAlgorithmConfig,
+        vtxn: ValidatorTxnConfig,
+        // Disseminate quorum store batches as Reed-Solomon encoded chunks
+        batch_erasure_coding: bool,
+    },
 }
 
 /// The public interface that exposes all values with safe fallback.
@@ -179,7 +185,9 @@ impl OnChainConsensusConfig {
             OnChainConsensusConfig::V1(config) | OnChainConsensusConfig::V2(config) => {
                 config.exclude_round
             },
-            OnChainConsensusConfig::V3 { alg, .. } => alg.leader_reputation_exclude_round(),
+            OnChainConsensusConfig::V3 { alg, .. } | OnChainConsensusConfig::V4 { alg, .. } => {
+                alg.leader_reputation_exclude_round()
+            },
         }
     }
 
@@ -195,7 +203,9 @@ impl OnChainConsensusConfig {
             OnChainConsensusConfig::V1(config) | OnChainConsensusConfig::V2(config) => {
                 config.max_failed_authors_to_store
             },
-            OnChainConsensusConfig::V3 { alg, .. } => alg.max_failed_authors_to_store(),
+            OnChainConsensusConfig::V3 { alg, .. } | OnChainConsensusConfig::V4 { alg, .. } => {
+                alg.max_failed_authors_to_store()
+            },
         }
     }
 
@@ -205,7 +215,9 @@ impl OnChainConsensusConfig {
             OnChainConsensusConfig::V1(config) | OnChainConsensusConfig::V2(config) => {
                 &config.proposer_election_type
             },
-            OnChainConsensusConfig::V3 { alg, .. } => alg.proposer_election_type(),
+            OnChainConsensusConfig::V3 { alg, .. } | OnChainConsensusConfig::V4 { alg, .. } => {
+                alg.proposer_election_type()
+            },
         }
     }
 
@@ -213,7 +225,9 @@ impl OnChainConsensusConfig {
         match &self {
             OnChainConsensusConfig::V1(_config) => false,
             OnChainConsensusConfig::V2(_) => true,
-            OnChainConsensusConfig::V3 { alg, .. } => alg.quorum_store_enabled(),
+            OnChainConsensusConfig::V3 { alg, .. } | OnChainConsensusConfig::V4 { alg, .. } => {
+                alg.quorum_store_enabled()
+            },
         }
     }
 
@@ -221,13 +235,17 @@ impl OnChainConsensusConfig {
         match self {
             OnChainConsensusConfig::V1(_) => false,
             OnChainConsensusConfig::V2(_) => false,
-            OnChainConsensusConfig::V3 { alg, .. } => alg.is_dag_enabled(),
+            OnChainConsensusConfig::V3 { alg, .. } | OnChainConsensusConfig::V4 { alg, .. } => {
+                alg.is_dag_enabled()
+            },
         }
     }
 
     pub fn unwrap_dag_config_v1(&self) -> &DagConsensusConfigV1 {
         match &self {
-            OnChainConsensusConfig::V3 { alg, .. } => alg.unwrap_dag_config_v1(),
+            OnChainConsensusConfig::V3 { alg, .. } | OnChainConsensusConfig::V4 { alg, .. } => {
+                alg.unwrap_dag_config_v1()
+            },
             _ => unreachable!("not a dag config"),
         }
     }
@@ -237,7 +255,9 @@ impl OnChainConsensusConfig {
             OnChainConsensusConfig::V1(_) | OnChainConsensusConfig::V2(_) => {
                 ValidatorTxnConfig::default_disabled()
             },
-            OnChainConsensusConfig::V3 { vtxn, .. } => vtxn.clone(),
+            OnChainConsensusConfig::V3 { vtxn, .. } | OnChainConsensusConfig::V4 { vtxn, .. } => {
+                vtxn.clone()
+            },
         }
     }
 
@@ -245,12 +265,26 @@ impl OnChainConsensusConfig {
         self.effective_validator_txn_config().enabled()
     }
 
+    /// Whether quorum store batches are disseminated as erasure coded chunks (instead of
+    /// in full). All validators must agree on it, so it's only set on chain.
+    pub fn is_batch_erasure_coding_enabled(&self) -> bool {
+        match self {
+            OnChainConsensusConfig::V1(_)
+            | OnChainConsensusConfig::V2(_)
+            | OnChainConsensusConfig::V3 { .. } => false,
+            OnChainConsensusConfig::V4 {
+                batch_erasure_coding,
+                ..
+            } => *batch_erasure_coding,
+        }
+    }
+
     pub fn disable_validator_txns(&mut self) {
         match self {
             OnChainConsensusConfig::V1(_) | OnChainConsensusConfig::V2(_) => {
                 // vtxn not supported. No-op.
             },
-            OnChainConsensusConfig::V3 { vtxn, .. } => {
+            OnChainConsensusConfig::V3 { vtxn, .. } | OnChainConsensusConfig::V4 { vtxn, .. } => {
                 *vtxn = ValidatorTxnConfig::V0;
             },
         }
@@ -283,6 +317,19 @@ impl OnChainConsensusConfig {
                 vtxn: ValidatorTxnConfig::V1 { .. },
                 ..
             } => item,
+            OnChainConsensusConfig::V4 {
+                vtxn: ValidatorTxnConfig::V0,
+                alg,
+                batch_erasure_coding,
+            } => OnChainConsensusConfig::V4 {
+                alg,
+                vtxn: ValidatorTxnConfig::default_enabled(),
+                batch_erasure_coding,
+            },
+            item @ OnChainConsensusConfig::V4 {
+                vtxn: ValidatorTxnConfig::V1 { .. },
+                ..
+            } => item,
         };
         *self = new_self;
     }
@@ -384,6 +431,10 @@ pub enum LeaderReputationType {
     // * use reputation window from recent end
     // * unpredictable seed, based on root hash
     ProposerAndVoterV2(ProposerAndVoterConfig),
+    // Same as version 2, but additionally reduces the weight of active
+    // validators that are slow to propose, or whose votes rarely make it
+    // into the quorum certificates.
+    LatencyAware(LatencyAwareConfig),
 }
 
 impl LeaderReputationType {
@@ -396,6 +447,13 @@ impl LeaderReputationType {
         // all versions after V1 shouldn't use from stale end
         matches!(self, Self::ProposerAndVoter(_))
     }
+
+    pub fn proposer_and_voter_config(&self) -> &ProposerAndVoterConfig {
+        match self {
+            Self::ProposerAndVoter(config) | Self::ProposerAndVoterV2(config) => config,
+            Self::LatencyAware(config) => &config.proposer_and_voter_config,
+        }
+    }
 }
 
 #[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -423,6 +481,22 @@ pub struct ProposerAndVoterConfig {
     pub use_history_from_previous_epoch_max_count: u32,
 }
 
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct LatencyAwareConfig {
+    pub proposer_and_voter_config: ProposerAndVoterConfig,
+    // Selection weight for active validators that are slow to propose or to vote
+    // (only applied if it is lower than the weight from the proposer and voter config)
+    pub slow_weight: u64,
+    // Threshold on the median time between the parent block and the blocks of a proposer,
+    // relative to the median across all proposers in the proposer window.
+    // integer values representing percentages, i.e. 150 is 1.5x the median.
+    pub proposal_latency_threshold_percent: u32,
+    // Threshold on the fraction of the blocks in the voter window that include the
+    // vote of a validator, below which the validator is considered slow.
+    // integer values representing percentages, i.e. 50 is 50%.
+    pub vote_participation_threshold_percent: u32,
+}
+
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AnchorElectionMode {
@@ -478,6 +552,28 @@ mod test {
         bcs::from_bytes::<OnChainConsensusConfig>(&s).unwrap();
     }
 
+    #[test]
+    fn test_batch_erasure_coding() {
+        assert!(!OnChainConsensusConfig::default().is_batch_erasure_coding_enabled());
+
+        let mut config = OnChainConsensusConfig::V4 {
+            alg: ConsensusAlgorithmConfig::default_for_genesis(),
+            vtxn: ValidatorTxnConfig::default_disabled(),
+            batch_erasure_coding: true,
+        };
+        let s = bcs::to_bytes(&config).unwrap();
+        assert_eq!(
+            bcs::from_bytes::<OnChainConsensusConfig>(&s).unwrap(),
+            config
+        );
+        assert!(config.is_batch_erasure_coding_enabled());
+        assert!(config.quorum_store_enabled());
+
+        config.enable_validator_txns();
+        assert!(config.is_vtxn_enabled());
+        assert!(config.is_batch_erasure_coding_enabled());
+    }
+
     #[test]
     fn test_config_serialization_non_default() {
         let config = OnChainConsensusConfig::V1(ConsensusConfigV1 {
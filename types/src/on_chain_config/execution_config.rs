@@ -151,6 +151,11 @@ pub enum TransactionShufflerType {
         module_conflict_window_size: u32,
         entry_fun_conflict_window_size: u32,
     },
+    FeeMarket {
+        sender_conflict_window_size: u32,
+        module_conflict_window_size: u32,
+        max_txns_per_hot_resource: u32,
+    },
 }
 
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -972,6 +972,9 @@ pub enum AnySignature {
     Keyless {
         signature: KeylessSignature,
     },
+    Secp256r1Ecdsa {
+        signature: secp256r1_ecdsa::Signature,
+    },
 }
 
 impl AnySignature {
@@ -983,6 +986,10 @@ impl AnySignature {
         Self::Secp256k1Ecdsa { signature }
     }
 
+    pub fn secp256r1_ecdsa(signature: secp256r1_ecdsa::Signature) -> Self {
+        Self::Secp256r1Ecdsa { signature }
+    }
+
     pub fn webauthn(signature: PartialAuthenticatorAssertionResponse) -> Self {
         Self::WebAuthn { signature }
     }
@@ -1003,6 +1010,9 @@ impl AnySignature {
             (Self::Secp256k1Ecdsa { signature }, AnyPublicKey::Secp256k1Ecdsa { public_key }) => {
                 signature.verify(message, public_key)
             },
+            (Self::Secp256r1Ecdsa { signature }, AnyPublicKey::Secp256r1Ecdsa { public_key }) => {
+                signature.verify(message, public_key)
+            },
             (Self::WebAuthn { signature }, _) => signature.verify(message, public_key),
             (Self::Keyless { signature }, AnyPublicKey::Keyless { public_key: _ }) => {
                 // Verifies the ephemeral signature on the TXN and, if present, the ZKP. The rest of
@@ -1309,6 +1319,63 @@ mod tests {
         signed_txn.verify_signature().unwrap();
     }
 
+    #[test]
+    fn verify_secp256r1_ecdsa_single_key_auth() {
+        let fake_sender = Ed25519PrivateKey::generate_for_testing();
+        let fake_sender_pub = fake_sender.public_key();
+
+        let sender = secp256r1_ecdsa::PrivateKey::generate_for_testing();
+        let sender_pub = sender.public_key();
+
+        let single_sender_auth =
+            AuthenticationKey::any_key(AnyPublicKey::secp256r1_ecdsa(sender_pub.clone()));
+        let single_sender_addr = single_sender_auth.account_address();
+
+        let raw_txn = crate::test_helpers::transaction_test_helpers::get_test_signed_transaction(
+            single_sender_addr,
+            0,
+            &fake_sender,
+            fake_sender_pub.clone(),
+            None,
+            0,
+            0,
+            None,
+        )
+        .into_raw_transaction();
+
+        let signature = sender.sign(&raw_txn).unwrap();
+        let sk_auth = SingleKeyAuthenticator::new(
+            AnyPublicKey::secp256r1_ecdsa(sender_pub),
+            AnySignature::secp256r1_ecdsa(signature.clone()),
+        );
+        let account_auth = AccountAuthenticator::single_key(sk_auth);
+        let signed_txn = SignedTransaction::new_single_sender(raw_txn.clone(), account_auth);
+        signed_txn.verify_signature().unwrap();
+
+        // A signature by a different key does not verify
+        let other_pub = secp256r1_ecdsa::PrivateKey::generate_for_testing().public_key();
+        let sk_auth = SingleKeyAuthenticator::new(
+            AnyPublicKey::secp256r1_ecdsa(other_pub),
+            AnySignature::secp256r1_ecdsa(signature.clone()),
+        );
+        let signed_txn = SignedTransaction::new_single_sender(
+            raw_txn.clone(),
+            AccountAuthenticator::single_key(sk_auth),
+        );
+        assert!(signed_txn.verify_signature().is_err());
+
+        // A raw secp256r1 signature only verifies against a secp256r1 public key
+        let sk_auth = SingleKeyAuthenticator::new(
+            AnyPublicKey::ed25519(fake_sender_pub),
+            AnySignature::secp256r1_ecdsa(signature),
+        );
+        let signed_txn = SignedTransaction::new_single_sender(
+            raw_txn,
+            AccountAuthenticator::single_key(sk_auth),
+        );
+        assert!(signed_txn.verify_signature().is_err());
+    }
+
     #[test]
     fn verify_multi_key_auth() {
         let sender0 = Ed25519PrivateKey::generate_for_testing();
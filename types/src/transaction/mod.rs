@@ -26,7 +26,7 @@ use aptos_crypto::{
     ed25519::*,
     hash::CryptoHash,
     multi_ed25519::{MultiEd25519PublicKey, MultiEd25519Signature},
-    secp256k1_ecdsa,
+    secp256k1_ecdsa, secp256r1_ecdsa,
     traits::{signing_message, SigningKey},
     CryptoMaterialError, HashValue,
 };
@@ -46,6 +46,7 @@ mod block_output;
 mod change_set;
 mod module;
 mod multisig;
+mod orderless;
 mod script;
 pub mod signature_verified_transaction;
 pub mod webauthn;
@@ -67,6 +68,9 @@ use move_vm_types::delayed_values::delayed_field_id::{
 };
 pub use multisig::{ExecutionError, Multisig, MultisigTransactionPayload};
 use once_cell::sync::OnceCell;
+pub use orderless::{
+    OrderlessPayload, ReplayProtector, TransactionExecutable, ORDERLESS_TRANSACTION_SEQUENCE_NUMBER,
+};
 pub use script::{
     ArgumentABI, EntryABI, EntryFunction, EntryFunctionABI, Script, TransactionScriptABI,
     TypeArgumentABI,
@@ -87,6 +91,8 @@ pub struct RawTransaction {
 
     /// Sequence number of this transaction. This must match the sequence number
     /// stored in the sender's account at the time the transaction executes.
+    /// Orderless transactions set it to `ORDERLESS_TRANSACTION_SEQUENCE_NUMBER`
+    /// and are protected against replay by the nonce in their payload instead.
     sequence_number: u64,
 
     /// The transaction payload, e.g., a script to execute.
@@ -199,6 +205,28 @@ impl RawTransaction {
         }
     }
 
+    /// Create a new orderless `RawTransaction`, which is protected against replay by `nonce`
+    /// instead of the sender's sequence number.
+    pub fn new_orderless(
+        sender: AccountAddress,
+        nonce: u64,
+        executable: TransactionExecutable,
+        max_gas_amount: u64,
+        gas_unit_price: u64,
+        expiration_timestamp_secs: u64,
+        chain_id: ChainId,
+    ) -> Self {
+        RawTransaction {
+            sender,
+            sequence_number: ORDERLESS_TRANSACTION_SEQUENCE_NUMBER,
+            payload: TransactionPayload::Orderless(OrderlessPayload { nonce, executable }),
+            max_gas_amount,
+            gas_unit_price,
+            expiration_timestamp_secs,
+            chain_id,
+        }
+    }
+
     /// Signs the given `RawTransaction`. Note that this consumes the `RawTransaction` and turns it
     /// into a `SignatureCheckedTransaction`.
     ///
@@ -330,6 +358,21 @@ impl RawTransaction {
         ))
     }
 
+    /// Signs the given `RawTransaction` with a raw secp256r1 (P-256) ECDSA key. Note that this
+    /// consumes the `RawTransaction` and turns it into a `SignatureCheckedTransaction`.
+    ///
+    /// For a transaction that has just been signed, its signature is expected to be valid.
+    pub fn sign_secp256r1_ecdsa(
+        self,
+        private_key: &secp256r1_ecdsa::PrivateKey,
+        public_key: secp256r1_ecdsa::PublicKey,
+    ) -> Result<SignatureCheckedTransaction> {
+        let signature = private_key.sign(&self)?;
+        Ok(SignatureCheckedTransaction(
+            SignedTransaction::new_secp256r1_ecdsa(self, public_key, signature),
+        ))
+    }
+
     #[cfg(any(test, feature = "fuzzing"))]
     pub fn multi_sign_for_testing(
         self,
@@ -351,6 +394,13 @@ impl RawTransaction {
         self.sender
     }
 
+    /// Return how this transaction is protected against replay.
+    pub fn replay_protector(&self) -> ReplayProtector {
+        self.payload
+            .replay_protector()
+            .unwrap_or(ReplayProtector::SequenceNumber(self.sequence_number))
+    }
+
     /// Return the signing message for creating transaction signature.
     pub fn signing_message(&self) -> Result<Vec<u8>, CryptoMaterialError> {
         signing_message(self)
@@ -416,9 +466,21 @@ pub enum TransactionPayload {
     /// A multisig transaction that allows an owner of a multisig account to execute a pre-approved
     /// transaction as the multisig account.
     Multisig(Multisig),
+    /// A script or entry function transaction that is protected against replay by a nonce rather
+    /// than by the sender's sequence number.
+    Orderless(OrderlessPayload),
 }
 
 impl TransactionPayload {
+    /// Returns the nonce-based replay protector of an orderless payload, or `None` if the
+    /// transaction is ordered by the sender's sequence number.
+    pub fn replay_protector(&self) -> Option<ReplayProtector> {
+        match self {
+            Self::Orderless(OrderlessPayload { nonce, .. }) => Some(ReplayProtector::Nonce(*nonce)),
+            _ => None,
+        }
+    }
+
     pub fn into_entry_function(self) -> EntryFunction {
         match self {
             Self::EntryFunction(f) => f,
@@ -486,7 +548,8 @@ impl PartialEq for SignedTransaction {
 }
 
 /// A transaction for which the signature has been verified. Created by
-/// [`SignedTransaction::check_signature`] and [`RawTransaction::sign`].
+/// [`SignedTransaction::check_signature`], [`signature_verified_transaction::check_signatures`]
+/// and [`RawTransaction::sign`].
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct SignatureCheckedTransaction(SignedTransaction);
 
@@ -624,6 +687,25 @@ impl SignedTransaction {
         }
     }
 
+    pub fn new_secp256r1_ecdsa(
+        raw_txn: RawTransaction,
+        public_key: secp256r1_ecdsa::PublicKey,
+        signature: secp256r1_ecdsa::Signature,
+    ) -> SignedTransaction {
+        let authenticator = TransactionAuthenticator::single_sender(
+            AccountAuthenticator::single_key(SingleKeyAuthenticator::new(
+                AnyPublicKey::secp256r1_ecdsa(public_key),
+                AnySignature::secp256r1_ecdsa(signature),
+            )),
+        );
+        SignedTransaction {
+            raw_txn,
+            authenticator,
+            raw_txn_size: OnceCell::new(),
+            authenticator_size: OnceCell::new(),
+        }
+    }
+
     pub fn new_keyless(
         raw_txn: RawTransaction,
         public_key: KeylessPublicKey,
@@ -691,6 +773,10 @@ impl SignedTransaction {
         self.raw_txn.sequence_number
     }
 
+    pub fn replay_protector(&self) -> ReplayProtector {
+        self.raw_txn.replay_protector()
+    }
+
     pub fn chain_id(&self) -> ChainId {
         self.raw_txn.chain_id
     }
@@ -0,0 +1,59 @@
+// Copyright © Aptos Foundation
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::transaction::{EntryFunction, Script};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The sequence number that orderless transactions carry in their `RawTransaction`. The field is
+/// ignored for replay protection, but a fixed value keeps it from being mistaken for a real one,
+/// so the VM rejects orderless transactions carrying any other value.
+pub const ORDERLESS_TRANSACTION_SEQUENCE_NUMBER: u64 = u64::MAX;
+
+/// A transaction that is protected against replay by a sender-chosen nonce, tracked on chain until
+/// the transaction expires, instead of by the sender's sequence number. Orderless transactions from
+/// the same sender can therefore be submitted and executed in any order.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub struct OrderlessPayload {
+    pub nonce: u64,
+    pub executable: TransactionExecutable,
+}
+
+/// The code executed by an orderless transaction.
+#[derive(Clone, Debug, Hash, Eq, PartialEq, Serialize, Deserialize)]
+pub enum TransactionExecutable {
+    Script(Script),
+    EntryFunction(EntryFunction),
+}
+
+/// How a transaction is protected against being executed more than once.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+pub enum ReplayProtector {
+    SequenceNumber(u64),
+    Nonce(u64),
+}
+
+impl ReplayProtector {
+    pub fn is_nonce(&self) -> bool {
+        matches!(self, Self::Nonce(_))
+    }
+
+    /// Returns the sequence number, if the transaction is ordered by one.
+    pub fn sequence_number(&self) -> Option<u64> {
+        match self {
+            Self::SequenceNumber(sequence_number) => Some(*sequence_number),
+            Self::Nonce(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for ReplayProtector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::SequenceNumber(sequence_number) => {
+                write!(f, "sequence number {}", sequence_number)
+            },
+            Self::Nonce(nonce) => write!(f, "nonce {}", nonce),
+        }
+    }
+}
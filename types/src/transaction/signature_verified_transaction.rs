@@ -4,10 +4,18 @@
 use crate::{
     contract_event::ContractEvent,
     state_store::state_key::StateKey,
-    transaction::{BlockExecutableTransaction, Transaction},
+    transaction::{
+        authenticator::TransactionAuthenticator, BlockExecutableTransaction,
+        SignatureCheckedTransaction, SignedTransaction, Transaction,
+    },
     write_set::WriteOp,
 };
-use aptos_crypto::{hash::CryptoHash, HashValue};
+use aptos_crypto::{
+    ed25519::Ed25519Signature,
+    hash::CryptoHash,
+    traits::{signing_message, Signature},
+    HashValue,
+};
 use move_core_types::{account_address::AccountAddress, language_storage::StructTag};
 use move_vm_types::delayed_values::delayed_field_id::DelayedFieldID;
 use serde::{Deserialize, Serialize};
@@ -88,8 +96,104 @@ impl From<Transaction> for SignatureVerifiedTransaction {
     }
 }
 
+/// Verifies the signatures of the user transactions in a block, or a chunk of it. The outcome
+/// is the same as converting each transaction with `From<Transaction>`.
 pub fn into_signature_verified_block(txns: Vec<Transaction>) -> Vec<SignatureVerifiedTransaction> {
-    txns.into_iter().map(|t| t.into()).collect()
+    let valid = {
+        let user_txns: Vec<_> = txns
+            .iter()
+            .filter_map(Transaction::try_as_signed_user_txn)
+            .collect();
+        verify_signatures(&user_txns)
+    };
+    let mut valid = valid.into_iter();
+    txns.into_iter()
+        .map(|txn| match txn {
+            Transaction::UserTransaction(_) if !valid.next().unwrap_or(false) => {
+                SignatureVerifiedTransaction::Invalid(txn)
+            },
+            _ => SignatureVerifiedTransaction::Valid(txn),
+        })
+        .collect()
+}
+
+/// The minimum number of transactions with a single Ed25519 signature for them to be verified
+/// as a batch. Batch verification has a fixed cost of about 10ms, for the 128 torsion checks of
+/// `Ed25519Signature::batch_verify_arbitrary_msgs`, which it only makes up for in large batches.
+/// Measured on one core, in microseconds per signature (individual / batch):
+///
+/// | signatures | run 1        | run 2        |
+/// |------------|--------------|--------------|
+/// | 128        | 60.6 / 96.4  | 77.3 / 109.8 |
+/// | 256        | 83.6 / 111.4 | 67.6 / 94.4  |
+/// | 512        | 89.9 / 79.0  | 75.4 / 86.2  |
+/// | 1024       | 58.0 / 54.8  | 84.9 / 66.8  |
+/// | 2048       | 77.1 / 57.9  | 71.5 / 57.4  |
+/// | 4096       | 62.5 / 47.5  | 84.9 / 77.7  |
+///
+/// Batches break even between 512 and 1024 signatures, and save 10-25% above.
+pub const MIN_ED25519_SIGNATURE_BATCH_SIZE: usize = 1024;
+
+/// Checks the signatures of the transactions, with the same outcome as
+/// `SignedTransaction::check_signature`, returning the transactions with an invalid signature as
+/// errors.
+pub fn check_signatures(
+    txns: Vec<SignedTransaction>,
+) -> Vec<Result<SignatureCheckedTransaction, SignedTransaction>> {
+    let valid = verify_signatures(&txns.iter().collect::<Vec<_>>());
+    txns.into_iter()
+        .zip(valid)
+        .map(|(txn, valid)| {
+            if valid {
+                Ok(SignatureCheckedTransaction(txn))
+            } else {
+                Err(txn)
+            }
+        })
+        .collect()
+}
+
+/// Returns for each transaction whether its signature is valid, with the same outcome as
+/// `SignedTransaction::verify_signature`. The signatures of transactions with a single Ed25519
+/// signature, by far the most common, are verified as a batch if there are at least
+/// `MIN_ED25519_SIGNATURE_BATCH_SIZE` of them. If the batch does not verify, all signatures are
+/// verified one by one to find the invalid ones. All other signatures are always verified one by
+/// one, as there is no batch verification for them: this includes secp256k1 and secp256r1 ECDSA,
+/// WebAuthn, keyless and multi-Ed25519 signatures, Ed25519 signatures in single-key
+/// authenticators, and the signatures of multi-agent and fee payer transactions.
+fn verify_signatures(txns: &[&SignedTransaction]) -> Vec<bool> {
+    let ed25519_txns: Vec<_> = txns
+        .iter()
+        .filter_map(|txn| match txn.authenticator_ref() {
+            TransactionAuthenticator::Ed25519 {
+                public_key,
+                signature,
+            } => Some((
+                signing_message(txn.raw_transaction_ref()),
+                public_key,
+                signature,
+            )),
+            _ => None,
+        })
+        .collect();
+    let batch_verified = ed25519_txns.len() >= MIN_ED25519_SIGNATURE_BATCH_SIZE
+        && ed25519_txns
+            .iter()
+            .map(|(message, public_key, signature)| {
+                Some((message.as_ref().ok()?.as_slice(), *public_key, *signature))
+            })
+            .collect::<Option<Vec<_>>>()
+            .is_some_and(|batch| Ed25519Signature::batch_verify_arbitrary_msgs(&batch).is_ok());
+    txns.iter()
+        .map(|txn| {
+            (batch_verified
+                && matches!(
+                    txn.authenticator_ref(),
+                    TransactionAuthenticator::Ed25519 { .. }
+                ))
+                || txn.verify_signature().is_ok()
+        })
+        .collect()
 }
 
 pub trait TransactionProvider: Debug {
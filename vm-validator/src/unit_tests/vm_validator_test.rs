@@ -12,7 +12,10 @@ use aptos_types::{
     account_address, account_config,
     chain_id::ChainId,
     test_helpers::transaction_test_helpers,
-    transaction::{Script, TransactionPayload},
+    transaction::{
+        signature_verified_transaction::MIN_ED25519_SIGNATURE_BATCH_SIZE, Script,
+        TransactionPayload,
+    },
     vm_status::StatusCode,
 };
 use aptos_vm::AptosVM;
@@ -113,6 +116,44 @@ fn test_validate_invalid_signature() {
     assert_eq!(ret.status().unwrap(), StatusCode::INVALID_SIGNATURE);
 }
 
+#[test]
+fn test_validate_transactions_with_batched_signatures() {
+    let vm_validator = TestValidator::new();
+
+    let mut rng = ::rand::rngs::StdRng::from_seed([1u8; 32]);
+    let other_private_key = Ed25519PrivateKey::generate(&mut rng);
+
+    let address = account_config::aptos_test_root_address();
+    let mut transactions: Vec<_> = (0..MIN_ED25519_SIGNATURE_BATCH_SIZE as u64)
+        .map(|sequence_number| {
+            transaction_test_helpers::get_test_signed_txn(
+                address,
+                sequence_number + 1,
+                &aptos_vm_genesis::GENESIS_KEYPAIR.0,
+                aptos_vm_genesis::GENESIS_KEYPAIR.1.clone(),
+                Some(aptos_stdlib::aptos_coin_mint(address, 100)),
+            )
+        })
+        .collect();
+    // The batch of signatures fails, and the invalid signature is found
+    transactions.insert(
+        7,
+        transaction_test_helpers::get_test_unchecked_txn(
+            address,
+            8,
+            &other_private_key,
+            aptos_vm_genesis::GENESIS_KEYPAIR.1.clone(),
+            aptos_stdlib::aptos_coin_transfer(address, 100),
+        ),
+    );
+    let rets = vm_validator.validate_transactions(transactions);
+    assert_eq!(rets.len(), MIN_ED25519_SIGNATURE_BATCH_SIZE + 1);
+    for (idx, ret) in rets.into_iter().enumerate() {
+        let expected = (idx == 7).then_some(StatusCode::INVALID_SIGNATURE);
+        assert_eq!(ret.unwrap().status(), expected);
+    }
+}
+
 #[test]
 fn test_validate_known_script_too_large_args() {
     let vm_validator = TestValidator::new();
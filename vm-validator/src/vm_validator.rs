@@ -13,7 +13,9 @@ use aptos_types::{
     account_address::AccountAddress,
     account_config::AccountResource,
     state_store::{MoveResourceExt, StateView},
-    transaction::{SignedTransaction, VMValidatorResult},
+    transaction::{
+        signature_verified_transaction::check_signatures, SignedTransaction, VMValidatorResult,
+    },
 };
 use aptos_vm::{data_cache::AsMoveResolver, AptosVM};
 use aptos_vm_logging::log_schema::AdapterLogSchema;
@@ -30,6 +32,16 @@ pub trait TransactionValidation: Send + Sync + Clone {
     /// Validate a txn from client
     fn validate_transaction(&self, _txn: SignedTransaction) -> Result<VMValidatorResult>;
 
+    /// Validate a batch of txns from clients
+    fn validate_transactions(
+        &self,
+        txns: Vec<SignedTransaction>,
+    ) -> Vec<Result<VMValidatorResult>> {
+        txns.into_iter()
+            .map(|txn| self.validate_transaction(txn))
+            .collect()
+    }
+
     /// Restart the transaction validation instance
     fn restart(&mut self) -> Result<()>;
 
@@ -89,6 +101,31 @@ impl TransactionValidation for VMValidator {
         Ok(self.vm.validate_transaction(txn, &self.state_view))
     }
 
+    /// Verifies the signatures of the txns as a batch before validating them one by one.
+    fn validate_transactions(
+        &self,
+        txns: Vec<SignedTransaction>,
+    ) -> Vec<Result<VMValidatorResult>> {
+        fail_point!("vm_validator::validate_transaction", |_| {
+            txns.iter()
+                .map(|_| {
+                    Err(anyhow::anyhow!(
+                        "Injected error in vm_validator::validate_transaction"
+                    ))
+                })
+                .collect()
+        });
+
+        check_signatures(txns)
+            .into_iter()
+            .map(|txn| {
+                Ok(self
+                    .vm
+                    .validate_transaction_with_checked_signature(txn, &self.state_view))
+            })
+            .collect()
+    }
+
     fn restart(&mut self) -> Result<()> {
         self.notify_commit();
 